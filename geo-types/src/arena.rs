@@ -0,0 +1,329 @@
+//! Line strings and polygons allocated in a bump arena, backed by the
+//! [`bumpalo`](https://docs.rs/bumpalo) crate.
+//!
+//! A pipeline building millions of temporary geometries, such as one clipping features to tiles,
+//! spends much of its time allocating and freeing their `Vec`s one at a time.
+//! [`ArenaLineString`](struct.ArenaLineString.html) and
+//! [`ArenaPolygon`](struct.ArenaPolygon.html) allocate their coordinates and rings in a
+//! [`Bump`](struct.Bump.html) instead, by bumping a pointer, and everything in the arena is freed
+//! at once when it's reset or dropped, however many geometries there were.
+//!
+//! `LineString` and `Polygon` can't be parameterized over an allocator themselves: `Vec`'s own
+//! allocator parameter needs the unstable `allocator_api`, and a storage type parameter couldn't
+//! be inferred from a call such as `LineString(coords)`. So the geometries in the arena are
+//! separate types, built with `_in` constructors taking the arena. Their coordinates can be
+//! iterated over in place, as `geo`'s `CoordsIter` does with its `bumpalo` feature, and they're
+//! converted to `LineString`, `Polygon`, or `Geometry`, on the heap, to use the rest of the
+//! algorithms in `geo`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::arena::{ArenaPolygon, Bump};
+//! use geo_types::{Polygon, Rect};
+//!
+//! let mut bump = Bump::new();
+//! for tile in 0..3 {
+//!     let cells: Vec<ArenaPolygon<f64>> = (0..100)
+//!         .map(|i| {
+//!             let min = (i as f64, tile as f64);
+//!             ArenaPolygon::from_rect_in(Rect::new(min, (min.0 + 1., min.1 + 1.)), &bump)
+//!         })
+//!         .collect();
+//!     assert_eq!(
+//!         cells[0].to_polygon(),
+//!         Polygon::from(Rect::new((0., tile as f64), (1., tile as f64 + 1.)))
+//!     );
+//!     // free every cell at once
+//!     drop(cells);
+//!     bump.reset();
+//! }
+//! ```
+use crate::{Coordinate, CoordinateType, Geometry, Line, LineString, Polygon, Rect};
+use bumpalo::collections::Vec as BumpVec;
+pub use bumpalo::Bump;
+use core::slice;
+
+/// A [`LineString`](../struct.LineString.html) whose coordinates are allocated in a
+/// [`Bump`](struct.Bump.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::arena::{ArenaLineString, Bump};
+/// use geo_types::line_string;
+///
+/// let bump = Bump::new();
+/// let mut line_string = ArenaLineString::from_coords_in(vec![(0., 0.), (3., 4.)], &bump);
+/// line_string.0.push((3., 0.).into());
+/// assert_eq!(line_string.num_coords(), 3);
+/// assert_eq!(
+///     line_string.to_line_string(),
+///     line_string![(x: 0., y: 0.), (x: 3., y: 4.), (x: 3., y: 0.)]
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub struct ArenaLineString<'bump, T: CoordinateType>(pub BumpVec<'bump, Coordinate<T>>);
+
+impl<'bump, T: CoordinateType> ArenaLineString<'bump, T> {
+    /// Create an empty `ArenaLineString` in `bump`
+    pub fn new_in(bump: &'bump Bump) -> Self {
+        ArenaLineString(BumpVec::new_in(bump))
+    }
+
+    /// Create an `ArenaLineString` in `bump` from `Coordinate`-like things
+    pub fn from_coords_in<IC: Into<Coordinate<T>>>(
+        coords: impl IntoIterator<Item = IC>,
+        bump: &'bump Bump,
+    ) -> Self {
+        ArenaLineString(BumpVec::from_iter_in(
+            coords.into_iter().map(Into::into),
+            bump,
+        ))
+    }
+
+    /// Copy the coordinates of a `LineString` into `bump`
+    pub fn from_line_string_in(line_string: &LineString<T>, bump: &'bump Bump) -> Self {
+        ArenaLineString::from_coords_in(line_string.0.iter().copied(), bump)
+    }
+
+    /// Copy the coordinates onto the heap, as a `LineString`
+    pub fn to_line_string(&self) -> LineString<T> {
+        LineString(self.0.to_vec())
+    }
+
+    /// Return the number of coordinates
+    pub fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return an iterator yielding one `Line` for each line segment
+    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.0.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// Return the bounding rectangle, or `None` if there are no coordinates
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(self.0.iter().cloned())
+    }
+
+    // Append the first coordinate if it differs from the last, as `LineString::close` does
+    fn close(&mut self) {
+        if let (Some(first), Some(last)) = (self.0.first().copied(), self.0.last().copied()) {
+            if first != last {
+                self.0.push(first);
+            }
+        }
+    }
+}
+
+impl<'a, 'bump, T: CoordinateType> IntoIterator for &'a ArenaLineString<'bump, T> {
+    type Item = &'a Coordinate<T>;
+    type IntoIter = slice::Iter<'a, Coordinate<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'bump, T: CoordinateType> From<&ArenaLineString<'bump, T>> for LineString<T> {
+    fn from(line_string: &ArenaLineString<'bump, T>) -> Self {
+        line_string.to_line_string()
+    }
+}
+
+impl<'bump, T: CoordinateType> From<&ArenaLineString<'bump, T>> for Geometry<T> {
+    fn from(line_string: &ArenaLineString<'bump, T>) -> Self {
+        Geometry::LineString(line_string.into())
+    }
+}
+
+/// A [`Polygon`](../struct.Polygon.html) whose rings, and their coordinates, are allocated in a
+/// [`Bump`](struct.Bump.html).
+///
+/// Like `Polygon`, its rings are always closed.
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub struct ArenaPolygon<'bump, T: CoordinateType> {
+    exterior: ArenaLineString<'bump, T>,
+    interiors: BumpVec<'bump, ArenaLineString<'bump, T>>,
+}
+
+impl<'bump, T: CoordinateType> ArenaPolygon<'bump, T> {
+    /// Create a new `ArenaPolygon` from its exterior and interior rings, closing each of them as
+    /// [`Polygon::new`](../struct.Polygon.html#method.new) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::arena::{ArenaLineString, ArenaPolygon, Bump};
+    /// use geo_types::polygon;
+    ///
+    /// let bump = Bump::new();
+    /// let exterior = ArenaLineString::from_coords_in(vec![(0., 0.), (4., 0.), (4., 4.)], &bump);
+    /// let hole = ArenaLineString::from_coords_in(vec![(1., 1.), (2., 1.), (2., 2.)], &bump);
+    /// let polygon = ArenaPolygon::new(exterior, vec![hole]);
+    /// assert_eq!(
+    ///     polygon.to_polygon(),
+    ///     polygon![
+    ///         exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+    ///         interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+    ///     ]
+    /// );
+    /// ```
+    pub fn new(
+        mut exterior: ArenaLineString<'bump, T>,
+        interiors: impl IntoIterator<Item = ArenaLineString<'bump, T>>,
+    ) -> Self {
+        exterior.close();
+        let bump = exterior.0.bump();
+        let mut interiors = BumpVec::from_iter_in(interiors, bump);
+        for interior in interiors.iter_mut() {
+            interior.close();
+        }
+        ArenaPolygon {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// Create the `ArenaPolygon` covering a `Rect` in `bump`
+    pub fn from_rect_in(r: Rect<T>, bump: &'bump Bump) -> Self {
+        ArenaPolygon::new(
+            ArenaLineString::from_coords_in(
+                [
+                    (r.min().x, r.min().y),
+                    (r.max().x, r.min().y),
+                    (r.max().x, r.max().y),
+                    (r.min().x, r.max().y),
+                    (r.min().x, r.min().y),
+                ]
+                .iter()
+                .copied(),
+                bump,
+            ),
+            None,
+        )
+    }
+
+    /// Copy the rings of a `Polygon` into `bump`
+    pub fn from_polygon_in(polygon: &Polygon<T>, bump: &'bump Bump) -> Self {
+        ArenaPolygon {
+            exterior: ArenaLineString::from_line_string_in(polygon.exterior(), bump),
+            interiors: BumpVec::from_iter_in(
+                polygon
+                    .interiors()
+                    .iter()
+                    .map(|interior| ArenaLineString::from_line_string_in(interior, bump)),
+                bump,
+            ),
+        }
+    }
+
+    /// Copy the rings onto the heap, as a `Polygon`
+    pub fn to_polygon(&self) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.to_line_string(),
+            self.interiors
+                .iter()
+                .map(ArenaLineString::to_line_string)
+                .collect(),
+        )
+    }
+
+    /// Return a reference to the exterior ring
+    pub fn exterior(&self) -> &ArenaLineString<'bump, T> {
+        &self.exterior
+    }
+
+    /// Return a slice of the interior rings
+    pub fn interiors(&self) -> &[ArenaLineString<'bump, T>] {
+        &self.interiors
+    }
+
+    /// Return the bounding rectangle, or `None` if it's empty.
+    ///
+    /// Only the exterior ring is considered, since the interior rings lie within it.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.exterior.bounding_rect()
+    }
+}
+
+impl<'bump, T: CoordinateType> From<&ArenaPolygon<'bump, T>> for Polygon<T> {
+    fn from(polygon: &ArenaPolygon<'bump, T>) -> Self {
+        polygon.to_polygon()
+    }
+}
+
+impl<'bump, T: CoordinateType> From<&ArenaPolygon<'bump, T>> for Geometry<T> {
+    fn from(polygon: &ArenaPolygon<'bump, T>) -> Self {
+        Geometry::Polygon(polygon.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn round_trips() {
+        let bump = Bump::new();
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let in_arena = ArenaPolygon::from_polygon_in(&polygon, &bump);
+        assert_eq!(in_arena.interiors().len(), 1);
+        assert_eq!(in_arena.bounding_rect(), polygon.bounding_rect());
+        assert_eq!(in_arena.to_polygon(), polygon);
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 2.)];
+        let in_arena = ArenaLineString::from_line_string_in(&line_string, &bump);
+        assert_eq!(
+            in_arena.lines().collect::<Vec<_>>(),
+            line_string.lines().collect::<Vec<_>>()
+        );
+        assert_eq!(in_arena.to_line_string(), line_string);
+        assert!(ArenaLineString::<f64>::new_in(&bump)
+            .bounding_rect()
+            .is_none());
+    }
+
+    #[test]
+    fn allocates_in_the_arena() {
+        let mut bump = Bump::with_capacity(4096);
+        let rect = Rect::new((0, 0), (2, 3));
+        let polygon = ArenaPolygon::from_rect_in(rect, &bump);
+        assert_eq!(polygon.to_polygon(), Polygon::from(rect));
+        assert!(bump.allocated_bytes() > 0);
+        drop(polygon);
+        bump.reset();
+
+        let before = bump.allocated_bytes();
+        for _ in 0..10 {
+            ArenaPolygon::from_rect_in(rect, &bump);
+        }
+        // the chunk kept by `reset` has room for them all
+        assert_eq!(bump.allocated_bytes(), before);
+    }
+
+    #[test]
+    fn iterates_and_converts_in_place() {
+        let bump = Bump::new();
+        let line_string = ArenaLineString::from_coords_in(vec![(0., 0.), (1., 2.)], &bump);
+        let coords: Vec<Coordinate<f64>> = line_string.into_iter().copied().collect();
+        assert_eq!(coords, vec![(0., 0.).into(), (1., 2.).into()]);
+        assert_eq!(
+            Geometry::from(&line_string),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 2.)])
+        );
+
+        let rect = Rect::new((0., 0.), (1., 1.));
+        let polygon = ArenaPolygon::from_rect_in(rect, &bump);
+        assert_eq!(Polygon::from(&polygon), Polygon::from(rect));
+        assert_eq!(
+            Geometry::from(&polygon),
+            Geometry::Polygon(Polygon::from(rect))
+        );
+    }
+}
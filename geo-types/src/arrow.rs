@@ -0,0 +1,398 @@
+//! Converting geometries to and from [GeoArrow](https://geoarrow.org) arrays, for moving many
+//! geometries between [Apache Arrow](https://arrow.apache.org) based tools.
+//!
+//! With the `arrow` feature enabled, slices of `Point`s, `LineString`s and `Polygon`s can be
+//! written to, and read from, arrays in GeoArrow's native layouts with interleaved
+//! coordinates:
+//!
+//! * points are a `FixedSizeList<xy: Float64>[2]`
+//! * line strings are a `List<vertices: FixedSizeList<xy: Float64>[2]>`
+//! * polygons are a `List<rings: List<vertices: FixedSizeList<xy: Float64>[2]>>`, with the
+//!   exterior first in each polygon
+//!
+//! Writing allocates a single coordinate buffer, and a single offset buffer for each level of
+//! nesting, which are moved into the Arrow array without copying, rather than anything per
+//! geometry. Reading copies the coordinates straight out of the Arrow buffers into the
+//! geometries, and accepts sliced arrays, such as the columns of a sliced `RecordBatch`.
+//! Arrays containing nulls can't be read. Use [`point_field`](fn.point_field.html),
+//! [`line_string_field`](fn.line_string_field.html) and
+//! [`polygon_field`](fn.polygon_field.html) to describe the arrays in a schema, with the
+//! metadata GeoArrow readers expect.
+//!
+//! # Examples
+//!
+//! ```
+//! use arrow_array::Array;
+//! use geo_types::arrow::{line_strings_from_arrow, line_strings_to_arrow};
+//! use geo_types::line_string;
+//!
+//! let line_strings = vec![
+//!     line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+//!     line_string![(x: 2., y: 2.), (x: 3., y: 3.), (x: 4., y: 2.)],
+//! ];
+//! let array = line_strings_to_arrow(&line_strings);
+//!
+//! assert_eq!(array.len(), 2);
+//! assert_eq!(line_strings_from_arrow(&array).unwrap(), line_strings);
+//! ```
+use crate::{Coordinate, LineString, Point, Polygon};
+use arrow_array::{Array, FixedSizeListArray, Float64Array, ListArray};
+use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, FieldRef};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+/// The error returned when reading a GeoArrow array fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoArrowError {
+    /// The array doesn't have the layout of the geometry type: `expected` describes the layout,
+    /// and `found` is the data type of the array.
+    UnexpectedType {
+        expected: &'static str,
+        found: DataType,
+    },
+    /// The array, or one of its children, contains a null.
+    UnexpectedNull,
+}
+
+impl fmt::Display for GeoArrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoArrowError::UnexpectedType { expected, found } => {
+                write!(f, "Expected {}, but found {}", expected, found)
+            }
+            GeoArrowError::UnexpectedNull => write!(f, "Unexpected null in GeoArrow array"),
+        }
+    }
+}
+
+impl Error for GeoArrowError {}
+
+const POINT_LAYOUT: &str = "a FixedSizeList of 2 Float64 for points";
+const LINE_STRING_LAYOUT: &str = "a List of points for line strings";
+const POLYGON_LAYOUT: &str = "a List of line strings for polygons";
+
+fn xy_field() -> FieldRef {
+    Arc::new(Field::new("xy", DataType::Float64, false))
+}
+
+fn point_type() -> DataType {
+    DataType::FixedSizeList(xy_field(), 2)
+}
+
+fn vertices_field() -> FieldRef {
+    Arc::new(Field::new("vertices", point_type(), false))
+}
+
+fn rings_field() -> FieldRef {
+    Arc::new(Field::new("rings", DataType::List(vertices_field()), false))
+}
+
+fn extension_field(name: &str, data_type: DataType, extension: &str) -> Field {
+    let metadata: HashMap<String, String> =
+        vec![("ARROW:extension:name".to_owned(), extension.to_owned())]
+            .into_iter()
+            .collect();
+    Field::new(name, data_type, false).with_metadata(metadata)
+}
+
+/// A non-nullable field named `name` for the arrays of
+/// [`points_to_arrow`](fn.points_to_arrow.html), tagged as a `geoarrow.point`.
+pub fn point_field(name: &str) -> Field {
+    extension_field(name, point_type(), "geoarrow.point")
+}
+
+/// A non-nullable field named `name` for the arrays of
+/// [`line_strings_to_arrow`](fn.line_strings_to_arrow.html), tagged as a
+/// `geoarrow.linestring`.
+pub fn line_string_field(name: &str) -> Field {
+    extension_field(
+        name,
+        DataType::List(vertices_field()),
+        "geoarrow.linestring",
+    )
+}
+
+/// A non-nullable field named `name` for the arrays of
+/// [`polygons_to_arrow`](fn.polygons_to_arrow.html), tagged as a `geoarrow.polygon`.
+pub fn polygon_field(name: &str) -> Field {
+    extension_field(name, DataType::List(rings_field()), "geoarrow.polygon")
+}
+
+// Interleave the coordinates into a buffer
+fn push_coords(xy: &mut Vec<f64>, coords: &[Coordinate<f64>]) {
+    for coord in coords {
+        xy.push(coord.x);
+        xy.push(coord.y);
+    }
+}
+
+fn to_points(xy: Vec<f64>) -> FixedSizeListArray {
+    FixedSizeListArray::new(xy_field(), 2, Arc::new(Float64Array::from(xy)), None)
+}
+
+// The offset of the end of a list, panicking if it doesn't fit in Arrow's 32-bit offsets
+fn offset(len: usize) -> i32 {
+    i32::try_from(len).expect("too many values for a GeoArrow array with 32-bit offsets")
+}
+
+fn to_list(field: FieldRef, offsets: Vec<i32>, values: Arc<dyn Array>) -> ListArray {
+    ListArray::new(
+        field,
+        OffsetBuffer::new(ScalarBuffer::from(offsets)),
+        values,
+        None,
+    )
+}
+
+/// Write points as a GeoArrow point array.
+pub fn points_to_arrow(points: &[Point<f64>]) -> FixedSizeListArray {
+    let mut xy = Vec::with_capacity(2 * points.len());
+    for point in points {
+        push_coords(&mut xy, &[point.0]);
+    }
+    to_points(xy)
+}
+
+/// Write line strings as a GeoArrow line string array.
+///
+/// # Panics
+///
+/// Panics if there are more than `i32::MAX` coordinates in total.
+pub fn line_strings_to_arrow(line_strings: &[LineString<f64>]) -> ListArray {
+    let len = line_strings.iter().map(|ls| ls.0.len()).sum::<usize>();
+    let mut xy = Vec::with_capacity(2 * len);
+    let mut offsets = Vec::with_capacity(line_strings.len() + 1);
+    offsets.push(0);
+    for line_string in line_strings {
+        push_coords(&mut xy, &line_string.0);
+        offsets.push(offset(xy.len() / 2));
+    }
+    to_list(vertices_field(), offsets, Arc::new(to_points(xy)))
+}
+
+// The rings of a polygon, which has none if its exterior is empty
+fn rings(polygon: &Polygon<f64>) -> impl Iterator<Item = &LineString<f64>> {
+    let len = if polygon.exterior().0.is_empty() {
+        0
+    } else {
+        1 + polygon.interiors().len()
+    };
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .take(len)
+}
+
+/// Write polygons as a GeoArrow polygon array.
+///
+/// A polygon with an empty exterior is written with no rings.
+///
+/// # Panics
+///
+/// Panics if there are more than `i32::MAX` coordinates or rings in total.
+pub fn polygons_to_arrow(polygons: &[Polygon<f64>]) -> ListArray {
+    let len = polygons
+        .iter()
+        .flat_map(rings)
+        .map(|ring| ring.0.len())
+        .sum::<usize>();
+    let mut xy = Vec::with_capacity(2 * len);
+    let mut ring_offsets = vec![0];
+    let mut polygon_offsets = Vec::with_capacity(polygons.len() + 1);
+    polygon_offsets.push(0);
+    for polygon in polygons {
+        for ring in rings(polygon) {
+            push_coords(&mut xy, &ring.0);
+            ring_offsets.push(offset(xy.len() / 2));
+        }
+        polygon_offsets.push(offset(ring_offsets.len() - 1));
+    }
+    let rings = to_list(vertices_field(), ring_offsets, Arc::new(to_points(xy)));
+    to_list(rings_field(), polygon_offsets, Arc::new(rings))
+}
+
+fn no_nulls(array: &dyn Array) -> Result<(), GeoArrowError> {
+    if array.null_count() == 0 {
+        Ok(())
+    } else {
+        Err(GeoArrowError::UnexpectedNull)
+    }
+}
+
+// The interleaved coordinates of a point array
+fn coords(array: &dyn Array) -> Result<&[f64], GeoArrowError> {
+    let unexpected = || GeoArrowError::UnexpectedType {
+        expected: POINT_LAYOUT,
+        found: array.data_type().clone(),
+    };
+    let points = array
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .filter(|points| points.value_length() == 2)
+        .ok_or_else(unexpected)?;
+    let xy = points
+        .values()
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(unexpected)?;
+    no_nulls(points)?;
+    no_nulls(xy)?;
+    Ok(&xy.values()[..2 * points.len()])
+}
+
+fn to_coords(xy: &[f64]) -> Vec<Coordinate<f64>> {
+    xy.chunks(2)
+        .map(|pair| Coordinate {
+            x: pair[0],
+            y: pair[1],
+        })
+        .collect()
+}
+
+fn list<'a>(array: &'a dyn Array, expected: &'static str) -> Result<&'a ListArray, GeoArrowError> {
+    let list = array.as_any().downcast_ref::<ListArray>().ok_or_else(|| {
+        GeoArrowError::UnexpectedType {
+            expected,
+            found: array.data_type().clone(),
+        }
+    })?;
+    no_nulls(list)?;
+    Ok(list)
+}
+
+// The start and end of each list, as indexes into its values
+fn ranges(list: &ListArray) -> impl Iterator<Item = (usize, usize)> + '_ {
+    list.offsets()
+        .windows(2)
+        .map(|pair| (pair[0] as usize, pair[1] as usize))
+}
+
+/// Read the points of a GeoArrow point array, such as a `FixedSizeListArray` from
+/// [`points_to_arrow`](fn.points_to_arrow.html).
+pub fn points_from_arrow(array: &dyn Array) -> Result<Vec<Point<f64>>, GeoArrowError> {
+    Ok(coords(array)?
+        .chunks(2)
+        .map(|pair| Point::new(pair[0], pair[1]))
+        .collect())
+}
+
+/// Read the line strings of a GeoArrow line string array, such as a `ListArray` from
+/// [`line_strings_to_arrow`](fn.line_strings_to_arrow.html).
+pub fn line_strings_from_arrow(array: &dyn Array) -> Result<Vec<LineString<f64>>, GeoArrowError> {
+    let line_strings = list(array, LINE_STRING_LAYOUT)?;
+    let xy = coords(line_strings.values().as_ref())?;
+    Ok(ranges(line_strings)
+        .map(|(start, end)| LineString(to_coords(&xy[2 * start..2 * end])))
+        .collect())
+}
+
+/// Read the polygons of a GeoArrow polygon array, such as a `ListArray` from
+/// [`polygons_to_arrow`](fn.polygons_to_arrow.html).
+///
+/// A polygon with no rings is read with an empty exterior.
+pub fn polygons_from_arrow(array: &dyn Array) -> Result<Vec<Polygon<f64>>, GeoArrowError> {
+    let polygons = list(array, POLYGON_LAYOUT)?;
+    let rings = list(polygons.values().as_ref(), POLYGON_LAYOUT)?;
+    let xy = coords(rings.values().as_ref())?;
+    let rings = ranges(rings)
+        .map(|(start, end)| LineString(to_coords(&xy[2 * start..2 * end])))
+        .collect::<Vec<_>>();
+    Ok(ranges(polygons)
+        .map(|(start, end)| match rings[start..end].split_first() {
+            Some((exterior, interiors)) => Polygon::new(exterior.clone(), interiors.to_vec()),
+            None => Polygon::new(LineString(vec![]), vec![]),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn points_round_trip() {
+        let points = vec![point!(x: 1., y: 2.), point!(x: -3.5, y: 4.)];
+        let array = points_to_arrow(&points);
+        assert_eq!(array.data_type(), point_field("geometry").data_type());
+        assert_eq!(array.len(), 2);
+        assert_eq!(points_from_arrow(&array).unwrap(), points);
+        assert_eq!(points_from_arrow(&array.slice(1, 1)).unwrap(), &points[1..]);
+        assert!(points_from_arrow(&points_to_arrow(&[])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn line_strings_round_trip() {
+        let line_strings = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            LineString(vec![]),
+            line_string![(x: 2., y: 2.), (x: 3., y: 3.), (x: 4., y: 2.)],
+        ];
+        let array = line_strings_to_arrow(&line_strings);
+        assert_eq!(array.data_type(), line_string_field("geometry").data_type());
+        assert_eq!(array.value_offsets(), &[0, 2, 2, 5]);
+        assert_eq!(line_strings_from_arrow(&array).unwrap(), line_strings);
+        assert_eq!(
+            line_strings_from_arrow(&array.slice(1, 2)).unwrap(),
+            &line_strings[1..]
+        );
+    }
+
+    #[test]
+    fn polygons_round_trip() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        ];
+        let triangle = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)];
+        let empty = Polygon::new(LineString(vec![]), vec![]);
+        let polygons = vec![square, empty, triangle];
+        let array = polygons_to_arrow(&polygons);
+        assert_eq!(array.data_type(), polygon_field("geometry").data_type());
+        assert_eq!(array.value_offsets(), &[0, 2, 2, 3]);
+        assert_eq!(polygons_from_arrow(&array).unwrap(), polygons);
+        assert_eq!(
+            polygons_from_arrow(&array.slice(2, 1)).unwrap(),
+            &polygons[2..]
+        );
+    }
+
+    #[test]
+    fn invalid_arrays() {
+        let points = points_to_arrow(&[point!(x: 1., y: 2.)]);
+        assert_eq!(
+            line_strings_from_arrow(&points),
+            Err(GeoArrowError::UnexpectedType {
+                expected: LINE_STRING_LAYOUT,
+                found: point_type(),
+            })
+        );
+        let floats = Float64Array::from(vec![1., 2.]);
+        assert!(points_from_arrow(&floats).is_err());
+
+        let with_null = FixedSizeListArray::new(
+            Arc::new(Field::new("xy", DataType::Float64, true)),
+            2,
+            Arc::new(Float64Array::from(vec![Some(1.), None])),
+            None,
+        );
+        assert_eq!(
+            points_from_arrow(&with_null),
+            Err(GeoArrowError::UnexpectedNull)
+        );
+    }
+
+    #[test]
+    fn fields_are_tagged() {
+        assert_eq!(
+            polygon_field("geometry")
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("geoarrow.polygon")
+        );
+    }
+}
@@ -0,0 +1,835 @@
+//! Geometries with a fixed capacity, backed by arrays, for environments without an allocator.
+//!
+//! [`BoundedLineString`](struct.BoundedLineString.html) holds up to `N` coordinates, and
+//! [`BoundedPolygon`](struct.BoundedPolygon.html) up to `H` interiors of up to `N` coordinates
+//! each, inline, so neither allocates, and both are `Copy`. They convert to and from the heap-backed
+//! `LineString` and `Polygon` with `TryFrom` and `From`, failing with
+//! [`Error::CapacityExceeded`](../enum.Error.html#variant.CapacityExceeded) if a geometry
+//! doesn't fit.
+//!
+//! With the `scale-codec` feature, both have a `MaxEncodedLen`, and are encoded like the types
+//! they bound, and there's [`BoundedGeometryCollection`](struct.BoundedGeometryCollection.html),
+//! a heap-backed `GeometryCollection` with bounded numbers of parts and coordinates, for storage
+//! in Substrate runtimes.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::bounded::{BoundedLineString, BoundedPolygon};
+//! use geo_types::{polygon, Error, Polygon};
+//! use core::convert::TryFrom;
+//!
+//! let mut exterior = BoundedLineString::<f64, 5>::new();
+//! exterior.push((0., 0.).into())?;
+//! exterior.push((1., 0.).into())?;
+//! exterior.push((1., 1.).into())?;
+//! let triangle = BoundedPolygon::<f64, 5, 0>::new(exterior, &[])?;
+//! // the exterior was closed
+//! assert_eq!(triangle.exterior().len(), 4);
+//! assert_eq!(
+//!     Polygon::from(triangle),
+//!     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]
+//! );
+//!
+//! let hexagon = polygon![
+//!     (x: 0., y: 0.), (x: 2., y: 0.), (x: 3., y: 1.), (x: 2., y: 2.), (x: 0., y: 2.), (x: -1., y: 1.)
+//! ];
+//! assert_eq!(
+//!     BoundedPolygon::<f64, 5, 0>::try_from(&hexagon),
+//!     Err(Error::CapacityExceeded { capacity: 5, needed: 7 })
+//! );
+//! # Ok::<(), Error>(())
+//! ```
+use crate::{Coordinate, CoordinateType, Error, Line, LineString, Polygon, Rect};
+#[cfg(feature = "scale-codec")]
+use crate::{Geometry, GeometryCollection};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// A `LineString` of at most `N` coordinates, stored inline.
+#[derive(Clone, Copy)]
+pub struct BoundedLineString<T: CoordinateType, const N: usize> {
+    coords: [Coordinate<T>; N],
+    len: usize,
+}
+
+impl<T: CoordinateType, const N: usize> BoundedLineString<T, N> {
+    /// An empty `BoundedLineString`.
+    pub fn new() -> Self {
+        let zero = Coordinate {
+            x: T::zero(),
+            y: T::zero(),
+        };
+        BoundedLineString {
+            coords: [zero; N],
+            len: 0,
+        }
+    }
+
+    /// A `BoundedLineString` of these coordinates, or an error if there are more than `N`.
+    pub fn from_slice(coords: &[Coordinate<T>]) -> Result<Self, Error> {
+        let mut line_string = Self::new();
+        if coords.len() > N {
+            return Err(Error::CapacityExceeded {
+                capacity: N,
+                needed: coords.len(),
+            });
+        }
+        line_string.coords[..coords.len()].copy_from_slice(coords);
+        line_string.len = coords.len();
+        Ok(line_string)
+    }
+
+    /// Add a coordinate to the end, or return an error if there are `N` already.
+    pub fn push(&mut self, coord: Coordinate<T>) -> Result<(), Error> {
+        if self.len == N {
+            return Err(Error::CapacityExceeded {
+                capacity: N,
+                needed: N + 1,
+            });
+        }
+        self.coords[self.len] = coord;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove the last coordinate, and return it, or `None` if there are none.
+    pub fn pop(&mut self) -> Option<Coordinate<T>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.coords[self.len])
+    }
+
+    /// The coordinates.
+    pub fn coords(&self) -> &[Coordinate<T>] {
+        &self.coords[..self.len]
+    }
+
+    /// The coordinates, mutably.
+    pub fn coords_mut(&mut self) -> &mut [Coordinate<T>] {
+        &mut self.coords[..self.len]
+    }
+
+    /// The number of coordinates.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of coordinates there is room for, `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Whether the first and last coordinates are the same, or there are none.
+    pub fn is_closed(&self) -> bool {
+        self.coords().first() == self.coords().last()
+    }
+
+    /// Return an iterator yielding one `Line` for each line segment.
+    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.coords()
+            .windows(2)
+            .map(|window| Line::new(window[0], window[1]))
+    }
+
+    /// The bounding rectangle of the coordinates, or `None` if there are none.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(self.coords().iter().cloned())
+    }
+
+    // Like `LineString::close`, but failing if there's no room for the closing coordinate
+    fn close(&mut self) -> Result<(), Error> {
+        if !self.is_closed() {
+            self.push(self.coords[0])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: CoordinateType, const N: usize> Default for BoundedLineString<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Only the coordinates in use are compared and shown
+impl<T: CoordinateType, const N: usize> PartialEq for BoundedLineString<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coords() == other.coords()
+    }
+}
+
+impl<T: CoordinateType + fmt::Debug, const N: usize> fmt::Debug for BoundedLineString<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BoundedLineString")
+            .field(&self.coords())
+            .finish()
+    }
+}
+
+impl<T: CoordinateType, const N: usize> TryFrom<&LineString<T>> for BoundedLineString<T, N> {
+    type Error = Error;
+
+    fn try_from(line_string: &LineString<T>) -> Result<Self, Error> {
+        Self::from_slice(&line_string.0)
+    }
+}
+
+impl<T: CoordinateType, const N: usize> TryFrom<LineString<T>> for BoundedLineString<T, N> {
+    type Error = Error;
+
+    fn try_from(line_string: LineString<T>) -> Result<Self, Error> {
+        Self::from_slice(&line_string.0)
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<BoundedLineString<T, N>> for LineString<T> {
+    fn from(line_string: BoundedLineString<T, N>) -> Self {
+        LineString(line_string.coords().to_vec())
+    }
+}
+
+/// A `Polygon` with at most `H` interiors, and at most `N` coordinates in each ring, counting
+/// the closing coordinate, stored inline.
+#[derive(Clone, Copy)]
+pub struct BoundedPolygon<T: CoordinateType, const N: usize, const H: usize> {
+    exterior: BoundedLineString<T, N>,
+    interiors: [BoundedLineString<T, N>; H],
+    interiors_len: usize,
+}
+
+impl<T: CoordinateType, const N: usize, const H: usize> BoundedPolygon<T, N, H> {
+    /// A `BoundedPolygon` of an exterior and interiors, closing any rings which aren't closed, like
+    /// `Polygon::new`, or an error if there are more than `H` interiors, or there isn't room in a
+    /// ring for its closing coordinate.
+    pub fn new(
+        mut exterior: BoundedLineString<T, N>,
+        interiors: &[BoundedLineString<T, N>],
+    ) -> Result<Self, Error> {
+        if interiors.len() > H {
+            return Err(Error::CapacityExceeded {
+                capacity: H,
+                needed: interiors.len(),
+            });
+        }
+        exterior.close()?;
+        let mut polygon = BoundedPolygon {
+            exterior,
+            interiors: [BoundedLineString::new(); H],
+            interiors_len: interiors.len(),
+        };
+        for (slot, interior) in polygon.interiors.iter_mut().zip(interiors) {
+            *slot = *interior;
+            slot.close()?;
+        }
+        Ok(polygon)
+    }
+
+    pub fn exterior(&self) -> &BoundedLineString<T, N> {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[BoundedLineString<T, N>] {
+        &self.interiors[..self.interiors_len]
+    }
+
+    /// The bounding rectangle of the exterior, or `None` if it's empty.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.exterior.bounding_rect()
+    }
+}
+
+impl<T: CoordinateType, const N: usize, const H: usize> PartialEq for BoundedPolygon<T, N, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.exterior() == other.exterior() && self.interiors() == other.interiors()
+    }
+}
+
+impl<T: CoordinateType + fmt::Debug, const N: usize, const H: usize> fmt::Debug
+    for BoundedPolygon<T, N, H>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BoundedPolygon")
+            .field("exterior", self.exterior())
+            .field("interiors", &self.interiors())
+            .finish()
+    }
+}
+
+impl<T: CoordinateType, const N: usize, const H: usize> TryFrom<&Polygon<T>>
+    for BoundedPolygon<T, N, H>
+{
+    type Error = Error;
+
+    fn try_from(polygon: &Polygon<T>) -> Result<Self, Error> {
+        if polygon.interiors().len() > H {
+            return Err(Error::CapacityExceeded {
+                capacity: H,
+                needed: polygon.interiors().len(),
+            });
+        }
+        let mut interiors = [BoundedLineString::new(); H];
+        for (slot, interior) in interiors.iter_mut().zip(polygon.interiors()) {
+            *slot = BoundedLineString::try_from(interior)?;
+        }
+        BoundedPolygon::new(
+            BoundedLineString::try_from(polygon.exterior())?,
+            &interiors[..polygon.interiors().len()],
+        )
+    }
+}
+
+impl<T: CoordinateType, const N: usize, const H: usize> TryFrom<Polygon<T>>
+    for BoundedPolygon<T, N, H>
+{
+    type Error = Error;
+
+    fn try_from(polygon: Polygon<T>) -> Result<Self, Error> {
+        BoundedPolygon::try_from(&polygon)
+    }
+}
+
+impl<T: CoordinateType, const N: usize, const H: usize> From<BoundedPolygon<T, N, H>>
+    for Polygon<T>
+{
+    fn from(polygon: BoundedPolygon<T, N, H>) -> Self {
+        Polygon::new(
+            polygon.exterior.into(),
+            polygon
+                .interiors()
+                .iter()
+                .map(|&ring| ring.into())
+                .collect(),
+        )
+    }
+}
+
+/// Encoded like a `LineString`, so either can be decoded as the other, if it fits.
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize> codec::Encode for BoundedLineString<T, N>
+where
+    T: CoordinateType + codec::Encode,
+{
+    fn size_hint(&self) -> usize {
+        self.coords().size_hint()
+    }
+
+    fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+        self.coords().encode_to(dest)
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize> codec::EncodeLike for BoundedLineString<T, N> where
+    T: CoordinateType + codec::Encode
+{
+}
+
+/// Decoding fails if there are more than `N` coordinates, before any of them are read.
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize> codec::Decode for BoundedLineString<T, N>
+where
+    T: CoordinateType + codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let len = decode_len(input, N)?;
+        let mut line_string = Self::new();
+        for coord in &mut line_string.coords[..len] {
+            *coord = Coordinate::decode(input)?;
+        }
+        line_string.len = len;
+        Ok(line_string)
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize> codec::MaxEncodedLen for BoundedLineString<T, N>
+where
+    T: CoordinateType + codec::MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        <codec::Compact<u32>>::max_encoded_len()
+            .saturating_add(N.saturating_mul(Coordinate::<T>::max_encoded_len()))
+    }
+}
+
+/// Encoded like a `Polygon`, so either can be decoded as the other, if it fits.
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize, const H: usize> codec::Encode for BoundedPolygon<T, N, H>
+where
+    T: CoordinateType + codec::Encode,
+{
+    fn size_hint(&self) -> usize {
+        self.exterior.size_hint() + self.interiors().size_hint()
+    }
+
+    fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+        self.exterior.encode_to(dest);
+        self.interiors().encode_to(dest);
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize, const H: usize> codec::EncodeLike for BoundedPolygon<T, N, H> where
+    T: CoordinateType + codec::Encode
+{
+}
+
+/// Decoding goes through [`BoundedPolygon::new`](#method.new), so decoded rings are closed, and
+/// fails if there are more than `H` interiors, or a ring doesn't fit.
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize, const H: usize> codec::Decode for BoundedPolygon<T, N, H>
+where
+    T: CoordinateType + codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let exterior = BoundedLineString::decode(input)?;
+        let len = decode_len(input, H)?;
+        let mut interiors = [BoundedLineString::new(); H];
+        for interior in &mut interiors[..len] {
+            *interior = BoundedLineString::decode(input)?;
+        }
+        BoundedPolygon::new(exterior, &interiors[..len])
+            .map_err(|_| "BoundedPolygon ring has no room to be closed".into())
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const N: usize, const H: usize> codec::MaxEncodedLen for BoundedPolygon<T, N, H>
+where
+    T: CoordinateType + codec::MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        BoundedLineString::<T, N>::max_encoded_len()
+            .saturating_mul(H.saturating_add(1))
+            .saturating_add(<codec::Compact<u32>>::max_encoded_len())
+    }
+}
+
+// Decode the length of a sequence, failing if it's more than `capacity`
+#[cfg(feature = "scale-codec")]
+fn decode_len<I: codec::Input>(input: &mut I, capacity: usize) -> Result<usize, codec::Error> {
+    let len = <codec::Compact<u32> as codec::Decode>::decode(input)?.0 as usize;
+    if len > capacity {
+        return Err("bounded geometry has more members than its capacity".into());
+    }
+    Ok(len)
+}
+
+/// A `GeometryCollection` of at most `MAX_PARTS` parts, with at most `MAX_COORDS` coordinates in
+/// all, so that it has a [`MaxEncodedLen`], for storage in a Substrate runtime.
+///
+/// Every geometry is a part, including nested ones, as are the interiors of polygons, and the line
+/// strings and polygons of multi-geometries. The bounds are checked whenever a collection is
+/// made, or decoded; it's stored on the heap, and encoded like a `GeometryCollection`.
+///
+/// [`MaxEncodedLen`]: https://docs.rs/parity-scale-codec/latest/parity_scale_codec/trait.MaxEncodedLen.html
+///
+/// # Examples
+///
+/// ```
+/// use codec::{Decode, Encode, MaxEncodedLen};
+/// use geo_types::bounded::BoundedGeometryCollection;
+/// use geo_types::{polygon, Error, Geometry, GeometryCollection, Point};
+/// use core::convert::TryFrom;
+///
+/// type Zones = BoundedGeometryCollection<i32, 4, 16>;
+///
+/// let mut zones = Zones::new();
+/// zones.push(Geometry::Polygon(polygon![(x: 0, y: 0), (x: 10, y: 0), (x: 0, y: 10)]))?;
+/// zones.push(Geometry::Point(Point::new(20, 20)))?;
+/// assert_eq!((zones.num_parts(), zones.num_coords()), (2, 5));
+///
+/// let encoded = zones.encode();
+/// assert!(encoded.len() <= Zones::max_encoded_len());
+/// assert_eq!(Zones::decode(&mut &encoded[..]).unwrap(), zones);
+///
+/// let too_many = GeometryCollection(vec![Geometry::Point(Point::new(0, 0)); 5]);
+/// assert!(Zones::try_from(too_many.clone()).is_err());
+/// assert!(Zones::decode(&mut &too_many.encode()[..]).is_err());
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "scale-codec")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundedGeometryCollection<T, const MAX_PARTS: u32, const MAX_COORDS: u32>
+where
+    T: CoordinateType,
+{
+    collection: GeometryCollection<T>,
+    parts: u32,
+    coords: u32,
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32>
+    BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType,
+{
+    /// An empty `BoundedGeometryCollection`.
+    pub fn new() -> Self {
+        BoundedGeometryCollection {
+            collection: GeometryCollection::new(),
+            parts: 0,
+            coords: 0,
+        }
+    }
+
+    /// Add a geometry, or return an error, leaving the collection as it was, if it would have
+    /// more than `MAX_PARTS` parts, or more than `MAX_COORDS` coordinates.
+    pub fn push(&mut self, geometry: Geometry<T>) -> Result<(), Error> {
+        let (parts, coords) = geometry_size(&geometry);
+        let parts = check_bound(self.parts as usize + parts, MAX_PARTS)?;
+        let coords = check_bound(self.coords as usize + coords, MAX_COORDS)?;
+        self.collection.0.push(geometry);
+        self.parts = parts;
+        self.coords = coords;
+        Ok(())
+    }
+
+    pub fn geometries(&self) -> &[Geometry<T>] {
+        &self.collection.0
+    }
+
+    pub fn into_inner(self) -> GeometryCollection<T> {
+        self.collection
+    }
+
+    /// The number of parts: geometries, interiors, and members of multi-geometries.
+    pub fn num_parts(&self) -> u32 {
+        self.parts
+    }
+
+    pub fn num_coords(&self) -> u32 {
+        self.coords
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> Default
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> TryFrom<GeometryCollection<T>>
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType,
+{
+    type Error = Error;
+
+    fn try_from(collection: GeometryCollection<T>) -> Result<Self, Error> {
+        let (parts, coords) = collection
+            .0
+            .iter()
+            .map(geometry_size)
+            .fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
+        Ok(BoundedGeometryCollection {
+            collection,
+            parts: check_bound(parts, MAX_PARTS)?,
+            coords: check_bound(coords, MAX_COORDS)?,
+        })
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32>
+    From<BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    fn from(collection: BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>) -> Self {
+        collection.collection
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> codec::Encode
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType + codec::Encode,
+{
+    fn size_hint(&self) -> usize {
+        self.collection.size_hint()
+    }
+
+    fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+        self.collection.encode_to(dest)
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> codec::EncodeLike
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType + codec::Encode,
+{
+}
+
+/// Decoding fails if the decoded collection is over either bound.
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> codec::Decode
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType + codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let collection = GeometryCollection::decode(input)?;
+        Self::try_from(collection).map_err(|_| {
+            "BoundedGeometryCollection has more parts or coordinates than allowed".into()
+        })
+    }
+}
+
+/// Every part adds at most a variant byte, and two compact lengths, like the exterior and the
+/// interiors of a `Polygon`, on top of its coordinates.
+#[cfg(feature = "scale-codec")]
+impl<T, const MAX_PARTS: u32, const MAX_COORDS: u32> codec::MaxEncodedLen
+    for BoundedGeometryCollection<T, MAX_PARTS, MAX_COORDS>
+where
+    T: CoordinateType + codec::MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        let len = <codec::Compact<u32>>::max_encoded_len();
+        let part = 1 + 2 * len;
+        len.saturating_add((MAX_PARTS as usize).saturating_mul(part))
+            .saturating_add(
+                (MAX_COORDS as usize).saturating_mul(Coordinate::<T>::max_encoded_len()),
+            )
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+fn check_bound(count: usize, bound: u32) -> Result<u32, Error> {
+    if count > bound as usize {
+        return Err(Error::CapacityExceeded {
+            capacity: bound as usize,
+            needed: count,
+        });
+    }
+    Ok(count as u32)
+}
+
+// The number of parts and coordinates of a geometry, as `BoundedGeometryCollection` counts them
+#[cfg(feature = "scale-codec")]
+fn geometry_size<T: CoordinateType>(geometry: &Geometry<T>) -> (usize, usize) {
+    let polygon_size = |polygon: &Polygon<T>| {
+        let coords = polygon.exterior().0.len()
+            + polygon
+                .interiors()
+                .iter()
+                .map(|ring| ring.0.len())
+                .sum::<usize>();
+        (1 + polygon.interiors().len(), coords)
+    };
+    let (parts, coords) = match geometry {
+        Geometry::Point(_) => (0, 1),
+        Geometry::Line(_) | Geometry::Rect(_) => (0, 2),
+        Geometry::Triangle(_) => (0, 3),
+        Geometry::LineString(line_string) => (0, line_string.0.len()),
+        Geometry::Polygon(polygon) => {
+            let (parts, coords) = polygon_size(polygon);
+            (parts - 1, coords)
+        }
+        Geometry::MultiPoint(points) => (0, points.0.len()),
+        Geometry::MultiLineString(line_strings) => (
+            line_strings.0.len(),
+            line_strings.0.iter().map(|line| line.0.len()).sum(),
+        ),
+        Geometry::MultiPolygon(polygons) => polygons
+            .0
+            .iter()
+            .map(polygon_size)
+            .fold((0, 0), |(a, b), (c, d)| (a + c, b + d)),
+        Geometry::GeometryCollection(collection) => collection
+            .0
+            .iter()
+            .map(geometry_size)
+            .fold((0, 0), |(a, b), (c, d)| (a + c, b + d)),
+    };
+    // the geometry itself is a part too
+    (parts + 1, coords)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+    #[cfg(feature = "scale-codec")]
+    use crate::{MultiLineString, Point};
+
+    #[test]
+    fn line_strings() {
+        let line_string = line_string![(x: 0, y: 0), (x: 5, y: 0), (x: 7, y: 9)];
+        let mut bounded = BoundedLineString::<i32, 4>::try_from(&line_string).unwrap();
+        assert_eq!(bounded.len(), 3);
+        assert_eq!(bounded.capacity(), 4);
+        assert!(!bounded.is_closed());
+        assert_eq!(bounded.lines().len(), 2);
+        assert_eq!(bounded.bounding_rect(), Some(Rect::new((0, 0), (7, 9))));
+        assert_eq!(LineString::from(bounded), line_string);
+
+        bounded.push((0, 0).into()).unwrap();
+        assert!(bounded.is_closed());
+        assert_eq!(
+            bounded.push((1, 1).into()),
+            Err(Error::CapacityExceeded {
+                capacity: 4,
+                needed: 5
+            })
+        );
+        assert_eq!(bounded.pop(), Some((0, 0).into()));
+        assert_eq!(LineString::from(bounded), line_string);
+
+        // the coordinates beyond the length don't matter
+        let mut other = BoundedLineString::<i32, 4>::try_from(&line_string).unwrap();
+        other.push((8, 8).into()).unwrap();
+        other.pop();
+        assert_eq!(other, bounded);
+        assert_eq!(
+            format!("{:?}", BoundedLineString::<i32, 4>::new()),
+            "BoundedLineString([])"
+        );
+
+        assert_eq!(
+            BoundedLineString::<i32, 2>::try_from(line_string),
+            Err(Error::CapacityExceeded {
+                capacity: 2,
+                needed: 3
+            })
+        );
+        assert!(BoundedLineString::<i32, 0>::new().bounding_rect().is_none());
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+                [(x: 3., y: 3.), (x: 3.5, y: 3.), (x: 3.5, y: 3.5)],
+            ],
+        );
+        let bounded = BoundedPolygon::<f64, 5, 2>::try_from(&polygon).unwrap();
+        assert_eq!(bounded.interiors().len(), 2);
+        assert_eq!(bounded.bounding_rect(), Some(Rect::new((0., 0.), (4., 4.))));
+        assert_eq!(Polygon::from(bounded), polygon);
+
+        assert_eq!(
+            BoundedPolygon::<f64, 5, 1>::try_from(&polygon),
+            Err(Error::CapacityExceeded {
+                capacity: 1,
+                needed: 2
+            })
+        );
+        assert_eq!(
+            BoundedPolygon::<f64, 4, 2>::try_from(polygon),
+            Err(Error::CapacityExceeded {
+                capacity: 4,
+                needed: 5
+            })
+        );
+
+        // there has to be room to close the rings
+        let ring = BoundedLineString::<f64, 3>::from_slice(&[
+            (0., 0.).into(),
+            (1., 0.).into(),
+            (1., 1.).into(),
+        ])
+        .unwrap();
+        assert!(BoundedPolygon::<f64, 3, 0>::new(ring, &[]).is_err());
+        let ring = BoundedLineString::<f64, 4>::from_slice(ring.coords()).unwrap();
+        let closed = BoundedPolygon::<f64, 4, 1>::new(ring, &[ring]).unwrap();
+        assert!(closed.exterior().is_closed());
+        assert!(closed.interiors()[0].is_closed());
+    }
+
+    #[cfg(feature = "scale-codec")]
+    #[test]
+    fn scale_codec() {
+        use codec::{Decode, Encode, MaxEncodedLen};
+
+        // encoded like the heap-backed types
+        let polygon = polygon!(
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+        );
+        let bounded = BoundedPolygon::<i32, 4, 1>::try_from(&polygon).unwrap();
+        assert_eq!(bounded.encode(), polygon.encode());
+        assert_eq!(
+            BoundedPolygon::<i32, 4, 1>::decode(&mut &polygon.encode()[..]).unwrap(),
+            bounded
+        );
+        assert_eq!(
+            Polygon::<i32>::decode(&mut &bounded.encode()[..]).unwrap(),
+            polygon
+        );
+        assert!(BoundedPolygon::<i32, 4, 0>::decode(&mut &polygon.encode()[..]).is_err());
+        assert!(BoundedPolygon::<i32, 3, 1>::decode(&mut &polygon.encode()[..]).is_err());
+        // an open ring decodes, unless there's no room to close it
+        let open = (
+            line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4)],
+            Vec::<LineString<i32>>::new(),
+        );
+        assert!(BoundedPolygon::<i32, 4, 0>::decode(&mut &open.encode()[..]).is_ok());
+        assert!(BoundedPolygon::<i32, 3, 0>::decode(&mut &open.encode()[..]).is_err());
+
+        // a full ring only has a one byte length, rather than at most five
+        let ring = bounded.exterior();
+        assert_eq!(
+            ring.encode().len() + 4,
+            BoundedLineString::<i32, 4>::max_encoded_len()
+        );
+        assert!(bounded.encode().len() <= BoundedPolygon::<i32, 4, 1>::max_encoded_len());
+
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygon.clone()),
+            Geometry::MultiPolygon(vec![polygon.clone(), polygon.clone()].into()),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Line(Line::new((0, 0), (1, 1))),
+                Geometry::MultiLineString(MultiLineString(vec![line_string![(x: 0, y: 0)]])),
+            ])),
+        ]);
+        type Collection = BoundedGeometryCollection<i32, 11, 27>;
+        let bounded = Collection::try_from(collection.clone()).unwrap();
+        assert_eq!((bounded.num_parts(), bounded.num_coords()), (11, 27));
+        let encoded = bounded.encode();
+        assert_eq!(encoded, collection.encode());
+        assert!(encoded.len() <= Collection::max_encoded_len());
+        assert_eq!(Collection::decode(&mut &encoded[..]).unwrap(), bounded);
+        assert_eq!(GeometryCollection::from(bounded), collection);
+
+        assert_eq!(
+            BoundedGeometryCollection::<i32, 10, 27>::try_from(collection.clone()),
+            Err(Error::CapacityExceeded {
+                capacity: 10,
+                needed: 11
+            })
+        );
+        assert!(BoundedGeometryCollection::<i32, 11, 26>::decode(&mut &encoded[..]).is_err());
+
+        let mut bounded = BoundedGeometryCollection::<i32, 2, 3>::new();
+        bounded
+            .push(Geometry::Line(Line::new((0, 0), (1, 1))))
+            .unwrap();
+        assert!(bounded
+            .push(Geometry::Line(Line::new((0, 0), (1, 1))))
+            .is_err());
+        assert_eq!(bounded.geometries().len(), 1);
+        bounded.push(Geometry::Point(Point::new(0, 0))).unwrap();
+        assert!(bounded.push(Geometry::Point(Point::new(0, 0))).is_err());
+        assert_eq!((bounded.num_parts(), bounded.num_coords()), (2, 3));
+    }
+}
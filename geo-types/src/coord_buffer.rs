@@ -0,0 +1,332 @@
+use crate::{Coordinate, CoordinateType, LineString, Rect};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A sequence of [`Coordinate`s](struct.Coordinate.html) stored as a struct of arrays: the x
+/// values in one contiguous `Vec`, and the y values in another.
+///
+/// A `LineString` stores each coordinate's x and y next to each other. Algorithms which only need
+/// one of them, such as finding the extent of the x values, touch half as much memory with a
+/// `CoordBuffer`, and arithmetic over whole slices of x values or y values can be vectorized
+/// without shuffling the coordinates apart first.
+///
+/// The two `Vec`s always have the same length, so the buffer can only be resized through methods
+/// taking or returning whole coordinates; [`parts_mut`](#method.parts_mut) gives mutable slices of
+/// the values in place.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, CoordBuffer, LineString, Rect};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 5., y: -2.), (x: 7., y: 9.)];
+/// let mut buffer = CoordBuffer::from(line_string.clone());
+/// assert_eq!(buffer.xs(), &[0., 5., 7.]);
+/// assert_eq!(buffer.ys(), &[0., -2., 9.]);
+/// assert_eq!(buffer.bounding_rect(), Some(Rect::new((0., -2.), (7., 9.))));
+///
+/// // shift every coordinate to the right
+/// let (xs, _) = buffer.parts_mut();
+/// xs.iter_mut().for_each(|x| *x += 1.);
+///
+/// let shifted: LineString<f64> = buffer.into();
+/// assert_eq!(shifted, line_string![(x: 1., y: 0.), (x: 6., y: -2.), (x: 8., y: 9.)]);
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Hash)]
+pub struct CoordBuffer<T: CoordinateType> {
+    xs: Vec<T>,
+    ys: Vec<T>,
+}
+
+impl<T: CoordinateType> CoordBuffer<T> {
+    /// Create an empty buffer
+    pub fn new() -> Self {
+        CoordBuffer {
+            xs: Vec::new(),
+            ys: Vec::new(),
+        }
+    }
+
+    /// Create an empty buffer with room for `capacity` coordinates
+    pub fn with_capacity(capacity: usize) -> Self {
+        CoordBuffer {
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Create a buffer from its x values and y values, or return `None` if there aren't as many of
+    /// one as of the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, CoordBuffer};
+    ///
+    /// let buffer = CoordBuffer::from_parts(vec![1, 2], vec![3, 4]).unwrap();
+    /// assert_eq!(buffer.get(1), Some(Coordinate { x: 2, y: 4 }));
+    ///
+    /// assert!(CoordBuffer::from_parts(vec![1, 2], vec![3]).is_none());
+    /// ```
+    pub fn from_parts(xs: Vec<T>, ys: Vec<T>) -> Option<Self> {
+        if xs.len() == ys.len() {
+            Some(CoordBuffer { xs, ys })
+        } else {
+            None
+        }
+    }
+
+    /// Return the x values and y values
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self.xs, self.ys)
+    }
+
+    /// The x values, in order
+    pub fn xs(&self) -> &[T] {
+        &self.xs
+    }
+
+    /// The y values, in order
+    pub fn ys(&self) -> &[T] {
+        &self.ys
+    }
+
+    /// The x values and y values, which can be changed in place but not resized
+    pub fn parts_mut(&mut self) -> (&mut [T], &mut [T]) {
+        (&mut self.xs, &mut self.ys)
+    }
+
+    /// The number of coordinates
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// The number of coordinates the buffer can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.xs.capacity().min(self.ys.capacity())
+    }
+
+    /// Whether there are no coordinates
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Return the coordinate at `index`, or `None` if it's out of bounds
+    pub fn get(&self, index: usize) -> Option<Coordinate<T>> {
+        Some(Coordinate {
+            x: *self.xs.get(index)?,
+            y: self.ys[index],
+        })
+    }
+
+    /// Replace the coordinate at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, coord: Coordinate<T>) {
+        self.xs[index] = coord.x;
+        self.ys[index] = coord.y;
+    }
+
+    /// Add a coordinate to the end
+    pub fn push(&mut self, coord: Coordinate<T>) {
+        self.xs.push(coord.x);
+        self.ys.push(coord.y);
+    }
+
+    /// Remove the last coordinate and return it, or `None` if there are none
+    pub fn pop(&mut self) -> Option<Coordinate<T>> {
+        Some(Coordinate {
+            x: self.xs.pop()?,
+            y: self.ys.pop()?,
+        })
+    }
+
+    /// Remove every coordinate, keeping the memory allocated
+    pub fn clear(&mut self) {
+        self.xs.clear();
+        self.ys.clear();
+    }
+
+    /// Return an iterator yielding the coordinates in order
+    pub fn iter(&self) -> CoordBufferIter<'_, T> {
+        CoordBufferIter {
+            xs: self.xs.iter(),
+            ys: self.ys.iter(),
+        }
+    }
+
+    /// Return the smallest `Rect` containing every coordinate, or `None` if there are none.
+    ///
+    /// The x values and y values are each scanned on their own, which is where the layout of the
+    /// buffer helps.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        let (min_x, max_x) = extent(&self.xs)?;
+        let (min_y, max_y) = extent(&self.ys)?;
+        Some(Rect::new(
+            Coordinate { x: min_x, y: min_y },
+            Coordinate { x: max_x, y: max_y },
+        ))
+    }
+}
+
+// The least and greatest of some values, ignoring NaN unless it's the first value, as
+// `LineString`'s `bounding_rect` does
+fn extent<T: CoordinateType>(values: &[T]) -> Option<(T, T)> {
+    let (first, rest) = values.split_first()?;
+    Some(rest.iter().fold((*first, *first), |(min, max), &value| {
+        (
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        )
+    }))
+}
+
+/// An iterator over the coordinates of a [`CoordBuffer`](struct.CoordBuffer.html)
+#[derive(Clone, Debug)]
+pub struct CoordBufferIter<'a, T: CoordinateType + 'a> {
+    xs: ::core::slice::Iter<'a, T>,
+    ys: ::core::slice::Iter<'a, T>,
+}
+
+impl<'a, T: CoordinateType> Iterator for CoordBufferIter<'a, T> {
+    type Item = Coordinate<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(Coordinate {
+            x: *self.xs.next()?,
+            y: *self.ys.next()?,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.xs.size_hint()
+    }
+}
+
+impl<'a, T: CoordinateType> DoubleEndedIterator for CoordBufferIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(Coordinate {
+            x: *self.xs.next_back()?,
+            y: *self.ys.next_back()?,
+        })
+    }
+}
+
+impl<'a, T: CoordinateType> ExactSizeIterator for CoordBufferIter<'a, T> {}
+
+impl<'a, T: CoordinateType> IntoIterator for &'a CoordBuffer<T> {
+    type Item = Coordinate<T>;
+    type IntoIter = CoordBufferIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: CoordinateType> FromIterator<Coordinate<T>> for CoordBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = Coordinate<T>>>(iter: I) -> Self {
+        let mut buffer = CoordBuffer::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl<T: CoordinateType> Extend<Coordinate<T>> for CoordBuffer<T> {
+    fn extend<I: IntoIterator<Item = Coordinate<T>>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.xs.reserve(lower);
+        self.ys.reserve(lower);
+        for coord in iter {
+            self.push(coord);
+        }
+    }
+}
+
+impl<T: CoordinateType> From<&[Coordinate<T>]> for CoordBuffer<T> {
+    fn from(coords: &[Coordinate<T>]) -> Self {
+        coords.iter().copied().collect()
+    }
+}
+
+impl<T: CoordinateType> From<Vec<Coordinate<T>>> for CoordBuffer<T> {
+    fn from(coords: Vec<Coordinate<T>>) -> Self {
+        coords.as_slice().into()
+    }
+}
+
+impl<T: CoordinateType> From<LineString<T>> for CoordBuffer<T> {
+    fn from(line_string: LineString<T>) -> Self {
+        line_string.0.into()
+    }
+}
+
+impl<T: CoordinateType> From<&LineString<T>> for CoordBuffer<T> {
+    fn from(line_string: &LineString<T>) -> Self {
+        line_string.0.as_slice().into()
+    }
+}
+
+impl<T: CoordinateType> From<CoordBuffer<T>> for Vec<Coordinate<T>> {
+    fn from(buffer: CoordBuffer<T>) -> Self {
+        buffer.iter().collect()
+    }
+}
+
+impl<T: CoordinateType> From<CoordBuffer<T>> for LineString<T> {
+    fn from(buffer: CoordBuffer<T>) -> Self {
+        LineString(buffer.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn round_trips() {
+        let line_string = line_string![(x: 1, y: 2), (x: 3, y: 4), (x: 5, y: 6)];
+        let buffer = CoordBuffer::from(&line_string);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.xs(), &[1, 3, 5]);
+        assert_eq!(buffer.ys(), &[2, 4, 6]);
+        assert_eq!(LineString::from(buffer.clone()), line_string);
+        assert_eq!(
+            buffer.iter().rev().collect::<Vec<_>>(),
+            vec![(5, 6).into(), (3, 4).into(), (1, 2).into()]
+        );
+        assert_eq!(buffer.iter().len(), 3);
+        assert_eq!(buffer.into_parts(), (vec![1, 3, 5], vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn editing() {
+        let mut buffer = CoordBuffer::with_capacity(2);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 2);
+        assert_eq!(buffer.pop(), None);
+        buffer.push(Coordinate { x: 1., y: 1. });
+        buffer.extend(vec![(2., 2.).into(), (3., 3.).into()]);
+        buffer.set(0, Coordinate { x: 0., y: -1. });
+        assert_eq!(buffer.get(0), Some(Coordinate { x: 0., y: -1. }));
+        assert_eq!(buffer.get(3), None);
+        assert_eq!(buffer.pop(), Some(Coordinate { x: 3., y: 3. }));
+        assert_eq!(buffer.len(), 2);
+        buffer.clear();
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn bounding_rect() {
+        assert_eq!(CoordBuffer::<f64>::new().bounding_rect(), None);
+        let buffer: CoordBuffer<f64> =
+            vec![(1., 2.).into(), (f64::NAN, -1.).into(), (-3., 5.).into()].into();
+        assert_eq!(
+            buffer.bounding_rect(),
+            Some(Rect::new((-3., -1.), (1., 5.)))
+        );
+    }
+}
@@ -9,6 +9,10 @@ use crate::{CoordinateType, Point};
 /// methods.
 #[derive(PartialEq, Clone, Copy, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "scale-codec",
+    derive(codec::Encode, codec::Decode, codec::MaxEncodedLen)
+)]
 pub struct Coordinate<T>
 where
     T: CoordinateType,
@@ -48,6 +52,21 @@ impl<T> Coordinate<T>
 where
     T: CoordinateType,
 {
+    /// Creates a new coordinate, in a `const` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Coordinate;
+    ///
+    /// const ORIGIN: Coordinate<f64> = Coordinate::new(0., 0.);
+    ///
+    /// assert_eq!(ORIGIN, Coordinate { x: 0., y: 0. });
+    /// ```
+    pub const fn new(x: T, y: T) -> Self {
+        Coordinate { x, y }
+    }
+
     /// Returns a tuple that contains the x/horizontal & y/vertical component of the coordinate.
     ///
     /// # Examples
@@ -64,7 +83,53 @@ where
     /// assert_eq!(y, 116.34);
     /// assert_eq!(x, 40.02f64);
     /// ```
-    pub fn x_y(&self) -> (T, T) {
+    pub const fn x_y(&self) -> (T, T) {
         (self.x, self.y)
     }
 }
+
+/// Coordinates are approximately equal if both their `x`s and their `y`s are.
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Coordinate<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Coordinate<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Coordinate<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps) && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Coordinate, CoordinateType, PointM};
+
+/// A 2D coordinate carrying an additional _measure_ (`m`) value.
+///
+/// Measures are used for linear referencing: the `m` value of a vertex records a position along
+/// a feature (e.g. a mile post) or some other value attached to it (e.g. a GPS timestamp).
+/// Unlike `z`, the measure is not a spatial dimension, and algorithms ignore it.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{Coordinate, CoordinateM};
+///
+/// let c = CoordinateM { x: 1., y: 2., m: 100. };
+///
+/// assert_eq!(c.x_y(), (1., 2.));
+/// assert_eq!(c.to_2d(), Coordinate { x: 1., y: 2. });
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoordinateM<T>
+where
+    T: CoordinateType,
+{
+    pub x: T,
+    pub y: T,
+    pub m: T,
+}
+
+impl<T: CoordinateType> From<PointM<T>> for CoordinateM<T> {
+    fn from(point: PointM<T>) -> Self {
+        point.0
+    }
+}
+
+impl<T> CoordinateM<T>
+where
+    T: CoordinateType,
+{
+    /// Create a `CoordinateM` from a 2D `Coordinate` and a measure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, CoordinateM};
+    ///
+    /// let c = CoordinateM::from_2d(Coordinate { x: 1., y: 2. }, 100.);
+    ///
+    /// assert_eq!(c, CoordinateM { x: 1., y: 2., m: 100. });
+    /// ```
+    pub fn from_2d(coord: Coordinate<T>, m: T) -> CoordinateM<T> {
+        CoordinateM {
+            x: coord.x,
+            y: coord.y,
+            m,
+        }
+    }
+
+    /// Returns a tuple that contains the x/horizontal & y/vertical component of the coordinate.
+    pub const fn x_y(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+
+    /// Drop the measure, returning the 2D `Coordinate`.
+    pub fn to_2d(&self) -> Coordinate<T> {
+        Coordinate {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
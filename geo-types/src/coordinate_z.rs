@@ -0,0 +1,94 @@
+use crate::{Coordinate, CoordinateType, PointZ};
+
+/// A lightweight struct used to store coordinates in 3-dimensional space,
+/// i.e. a [`Coordinate`](struct.Coordinate.html) with an additional `z`
+/// (typically elevation) ordinate.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{Coordinate, CoordinateZ};
+///
+/// let c = CoordinateZ { x: 1., y: 2., z: 3. };
+///
+/// assert_eq!(c.x_y_z(), (1., 2., 3.));
+/// assert_eq!(c.to_2d(), Coordinate { x: 1., y: 2. });
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoordinateZ<T>
+where
+    T: CoordinateType,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: CoordinateType> From<(T, T, T)> for CoordinateZ<T> {
+    fn from(coords: (T, T, T)) -> Self {
+        CoordinateZ {
+            x: coords.0,
+            y: coords.1,
+            z: coords.2,
+        }
+    }
+}
+
+impl<T: CoordinateType> From<[T; 3]> for CoordinateZ<T> {
+    fn from(coords: [T; 3]) -> Self {
+        CoordinateZ {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+        }
+    }
+}
+
+impl<T: CoordinateType> From<PointZ<T>> for CoordinateZ<T> {
+    fn from(point: PointZ<T>) -> Self {
+        point.0
+    }
+}
+
+impl<T> CoordinateZ<T>
+where
+    T: CoordinateType,
+{
+    /// Create a `CoordinateZ` from a 2D `Coordinate` and a `z` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, CoordinateZ};
+    ///
+    /// let c = CoordinateZ::from_2d(Coordinate { x: 1., y: 2. }, 3.);
+    ///
+    /// assert_eq!(c, CoordinateZ { x: 1., y: 2., z: 3. });
+    /// ```
+    pub fn from_2d(coord: Coordinate<T>, z: T) -> CoordinateZ<T> {
+        CoordinateZ {
+            x: coord.x,
+            y: coord.y,
+            z,
+        }
+    }
+
+    /// Returns a tuple that contains the x/horizontal & y/vertical component of the coordinate.
+    pub const fn x_y(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+
+    /// Returns a tuple that contains the x, y, and z components of the coordinate.
+    pub const fn x_y_z(&self) -> (T, T, T) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Drop the `z` component, returning the 2D `Coordinate`.
+    pub fn to_2d(&self) -> Coordinate<T> {
+        Coordinate {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use core::fmt;
+
+/// The errors that can be returned by `geo-types`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A [`Geometry`](enum.Geometry.html) couldn't be converted to a concrete geometry type,
+    /// because it holds a different variant.
+    MismatchedGeometry {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A geometry didn't fit in a [`bounded`](bounded/index.html) geometry, because it
+    /// `needed` more coordinates, or rings, than its `capacity`.
+    CapacityExceeded { capacity: usize, needed: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MismatchedGeometry { expected, found } => {
+                write!(f, "Expected a {}, but found a {}", expected, found)
+            }
+            Error::CapacityExceeded { capacity, needed } => {
+                write!(
+                    f,
+                    "Needed room for {}, but the capacity is {}",
+                    needed, capacity
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,391 @@
+//! Fixed-point coordinates, for deterministic geometry math.
+//!
+//! The fixed-point numbers of the [`fixed`](https://docs.rs/fixed) crate (e.g. `I64F64` or
+//! `I32F32`) don't implement `NumCast`, so they can't be used as a
+//! [`CoordinateType`](../trait.CoordinateType.html) directly. [`Fixed`](struct.Fixed.html) is a
+//! thin wrapper around such a number which implements the `num-traits` traits required by
+//! `CoordinateType` (as well as `Signed` and `Bounded`), so it can be used anywhere an integer
+//! coordinate can.
+//!
+//! Only enabled with the `fixed-point` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::fixed_point::FixedI64F64;
+//! use geo_types::{Coordinate, Rect};
+//!
+//! let rect = Rect::new(
+//!     Coordinate { x: FixedI64F64::from_f64(0.5).unwrap(), y: FixedI64F64::from_f64(1.).unwrap() },
+//!     Coordinate { x: FixedI64F64::from_f64(2.).unwrap(), y: FixedI64F64::from_f64(3.25).unwrap() },
+//! );
+//!
+//! assert_eq!(rect.width().to_f64(), 1.5);
+//! assert_eq!(rect.height().to_f64(), 2.25);
+//! ```
+use core::fmt;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+pub use ::fixed;
+use fixed::traits::{Fixed as FixedNum, FixedSigned};
+
+/// A `Fixed` wrapping a signed 128-bit number with 64 integer and 64 fractional bits.
+pub type FixedI64F64 = Fixed<fixed::types::I64F64>;
+
+/// A `Fixed` wrapping a signed 64-bit number with 32 integer and 32 fractional bits.
+pub type FixedI32F32 = Fixed<fixed::types::I32F32>;
+
+/// A fixed-point number which can be used as a [`CoordinateType`](../trait.CoordinateType.html).
+///
+/// Arithmetic behaves like that of the wrapped number: on overflow, or on division by zero, it
+/// panics.
+///
+/// Casting from another number (`NumCast::from`) is exact for integers, and goes through `f64`
+/// otherwise. It returns `None` if the value isn't representable.
+///
+/// # Panics
+///
+/// `One::one` (and therefore `Signed::signum`) panics if the wrapped type can't represent 1,
+/// e.g. `I0F64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<F: FixedNum>(pub F);
+
+impl<F: FixedNum> Fixed<F> {
+    /// Convert an `f64` to a `Fixed`, rounding to the nearest representable value.
+    ///
+    /// Returns `None` if the value is NaN, infinite, or out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::fixed_point::FixedI32F32;
+    ///
+    /// assert_eq!(FixedI32F32::from_f64(1.25).unwrap().to_f64(), 1.25);
+    /// assert!(FixedI32F32::from_f64(1e12).is_none());
+    /// assert!(FixedI32F32::from_f64(f64::NAN).is_none());
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        F::checked_from_num(value).map(Fixed)
+    }
+
+    /// Convert to the nearest `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.0.to_num()
+    }
+
+    /// Return the wrapped fixed-point number.
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<F: FixedNum> crate::FractionalCoordinateType for Fixed<F> {}
+
+impl<F: FixedNum> From<F> for Fixed<F> {
+    fn from(value: F) -> Self {
+        Fixed(value)
+    }
+}
+
+impl<F: FixedNum> fmt::Display for Fixed<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl<F: FixedNum> $op for Fixed<F> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Fixed($op::$method(self.0, rhs.0))
+            }
+        }
+
+        impl<F: FixedNum> $op_assign for Fixed<F> {
+            fn $method_assign(&mut self, rhs: Self) {
+                $op_assign::$method_assign(&mut self.0, rhs.0)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+impl<F: FixedSigned> Neg for Fixed<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(-self.0)
+    }
+}
+
+impl<F: FixedNum> Zero for Fixed<F> {
+    fn zero() -> Self {
+        Fixed(F::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == F::ZERO
+    }
+}
+
+impl<F: FixedNum> One for Fixed<F> {
+    fn one() -> Self {
+        Fixed(F::TRY_ONE.expect("fixed-point type can't represent 1"))
+    }
+}
+
+/// The error returned when parsing a [`Fixed`](struct.Fixed.html) from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFixedError {
+    /// Only radixes 2, 8, 10 and 16 are supported.
+    UnsupportedRadix(u32),
+    /// The string isn't a valid number, or the number is out of range.
+    Invalid(fixed::ParseFixedError),
+}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseFixedError::UnsupportedRadix(radix) => {
+                write!(f, "unsupported radix {} for fixed-point number", radix)
+            }
+            ParseFixedError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedError {}
+
+impl<F: FixedNum> Num for Fixed<F> {
+    type FromStrRadixErr = ParseFixedError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFixedError> {
+        let parsed = match radix {
+            2 => F::from_str_binary(s),
+            8 => F::from_str_octal(s),
+            10 => s.parse::<F>(),
+            16 => F::from_str_hex(s),
+            _ => return Err(ParseFixedError::UnsupportedRadix(radix)),
+        };
+        parsed.map(Fixed).map_err(ParseFixedError::Invalid)
+    }
+}
+
+impl<F: FixedNum> ToPrimitive for Fixed<F> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.checked_to_num()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.checked_to_num()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.checked_to_num()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.0.checked_to_num()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.0.to_num())
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0.to_num())
+    }
+}
+
+impl<F: FixedNum> NumCast for Fixed<F> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let float = n.to_f64()?;
+        // Integers (even ones too large to be exactly represented by an `f64`) are converted
+        // exactly
+        if Float::fract(float) == 0. {
+            if let Some(int) = n.to_i128() {
+                return F::checked_from_num(int).map(Fixed);
+            }
+        }
+        F::checked_from_num(float).map(Fixed)
+    }
+}
+
+impl<F: FixedNum> FromPrimitive for Fixed<F> {
+    fn from_i64(n: i64) -> Option<Self> {
+        F::checked_from_num(n).map(Fixed)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        F::checked_from_num(n).map(Fixed)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        F::checked_from_num(n).map(Fixed)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        F::checked_from_num(n).map(Fixed)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        F::checked_from_num(n).map(Fixed)
+    }
+}
+
+impl<F: FixedNum> Bounded for Fixed<F> {
+    fn min_value() -> Self {
+        Fixed(F::MIN)
+    }
+
+    fn max_value() -> Self {
+        Fixed(F::MAX)
+    }
+}
+
+impl<F: FixedSigned> Signed for Fixed<F> {
+    fn abs(&self) -> Self {
+        Fixed(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.0 <= other.0 {
+            Self::zero()
+        } else {
+            Fixed(self.0 - other.0)
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Fixed(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+/// Fixed-point numbers are encoded as their underlying bits.
+#[cfg(feature = "scale-codec")]
+impl<F: FixedNum> codec::Encode for Fixed<F>
+where
+    F::Bits: codec::Encode,
+{
+    fn size_hint(&self) -> usize {
+        self.0.to_bits().size_hint()
+    }
+
+    fn encode_to<W: codec::Output + ?Sized>(&self, dest: &mut W) {
+        self.0.to_bits().encode_to(dest)
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<F: FixedNum> codec::Decode for Fixed<F>
+where
+    F::Bits: codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        F::Bits::decode(input).map(|bits| Fixed(F::from_bits(bits)))
+    }
+}
+
+#[cfg(feature = "scale-codec")]
+impl<F: FixedNum> codec::MaxEncodedLen for Fixed<F>
+where
+    F::Bits: codec::MaxEncodedLen,
+{
+    fn max_encoded_len() -> usize {
+        F::Bits::max_encoded_len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Coordinate, Line, Point};
+
+    #[test]
+    fn numcast() {
+        let big: FixedI64F64 = NumCast::from(i64::MAX).unwrap();
+        assert_eq!(big.to_i64(), Some(i64::MAX));
+        let half: FixedI64F64 = NumCast::from(0.5f64).unwrap();
+        assert_eq!(half.to_f64(), 0.5);
+        let too_big: Option<FixedI32F32> = NumCast::from(1e10f64);
+        assert!(too_big.is_none());
+        let nan: Option<FixedI32F32> = NumCast::from(f64::NAN);
+        assert!(nan.is_none());
+    }
+
+    #[test]
+    fn arithmetic_is_exact() {
+        let tenth = FixedI64F64::from_str_radix("0.1", 10).unwrap();
+        let mut sum = FixedI64F64::zero();
+        for _ in 0..10 {
+            sum += tenth;
+        }
+        // 0.1 isn't exactly representable, but repeating the same operations always gives the
+        // same result
+        assert_eq!(sum, tenth * NumCast::from(10).unwrap());
+        assert_eq!(-tenth, FixedI64F64::zero() - tenth);
+        assert_eq!((-tenth).abs(), tenth);
+        assert_eq!((-tenth).signum(), -FixedI64F64::one());
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(FixedI32F32::from_str_radix("1.1", 2).unwrap().to_f64(), 1.5);
+        assert_eq!(
+            FixedI32F32::from_str_radix("1", 3),
+            Err(ParseFixedError::UnsupportedRadix(3))
+        );
+        assert!(FixedI32F32::from_str_radix("one", 10).is_err());
+    }
+
+    #[test]
+    fn geometries() {
+        let f = |v: f64| FixedI32F32::from_f64(v).unwrap();
+        let line = Line::new(
+            Coordinate {
+                x: f(1.5),
+                y: f(-2.),
+            },
+            Coordinate {
+                x: f(3.),
+                y: f(0.25),
+            },
+        );
+        assert_eq!(line.dx().to_f64(), 1.5);
+        assert_eq!(line.dy().to_f64(), 2.25);
+        assert_eq!(line.end_point(), Point::new(f(3.), f(0.25)));
+    }
+
+    #[cfg(feature = "scale-codec")]
+    #[test]
+    fn scale_codec_roundtrip() {
+        use codec::{Decode, Encode, MaxEncodedLen};
+
+        let coord = Coordinate {
+            x: FixedI64F64::from_f64(-12.75).unwrap(),
+            y: FixedI64F64::from_f64(0.125).unwrap(),
+        };
+        let encoded = coord.encode();
+        assert_eq!(encoded.len(), Coordinate::<FixedI64F64>::max_encoded_len());
+        assert_eq!(Coordinate::decode(&mut &encoded[..]).unwrap(), coord);
+    }
+}
@@ -0,0 +1,1915 @@
+//! Reading and writing [FlatGeobuf](https://flatgeobuf.org) files.
+//!
+//! With the `flatgeobuf` feature enabled, a [`Reader`](struct.Reader.html) reads the
+//! [`Header`](struct.Header.html) of a FlatGeobuf file from any `std::io::Read`, then streams
+//! its [`Feature`](struct.Feature.html)s one at a time, optionally only those whose bounding
+//! rectangles intersect a `Rect`, using the file's packed Hilbert R-tree index to skip the
+//! others. A [`Writer`](struct.Writer.html) writes features, with an index, to any
+//! `std::io::Write`.
+//!
+//! Like [WKB](../wkb/index.html), FlatGeobuf coordinates are always `f64`s: other coordinate
+//! types are converted using `NumCast`. FlatGeobuf has no `Line`, `Rect`, or `Triangle` type,
+//! so a `Geometry::Line` is written as a `LineString`, and a `Geometry::Rect` or
+//! `Geometry::Triangle` as a `Polygon`. Any Z or M coordinates in a file are ignored, and
+//! curved geometry types can't be read.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::flatgeobuf::{
+//!     Column, ColumnType, FgbError, Feature, GeometryType, Header, Reader, Value, Writer,
+//! };
+//! use geo_types::{point, Coordinate, Geometry, Rect};
+//! use std::collections::BTreeMap;
+//!
+//! let mut header = Header::new(GeometryType::Point);
+//! header.columns.push(Column::new("name", ColumnType::String));
+//! let mut writer = Writer::new(header);
+//! for &(name, x, y) in &[("origin", 0., 0.), ("far", 10., 10.)] {
+//!     let mut properties = BTreeMap::new();
+//!     properties.insert("name".to_string(), Value::String(name.to_string()));
+//!     writer.add_feature(&Feature {
+//!         geometry: Some(Geometry::Point(point!(x: x, y: y))),
+//!         properties,
+//!     })?;
+//! }
+//! let mut fgb = vec![];
+//! writer.write(&mut fgb)?;
+//!
+//! let bbox = Rect::new(Coordinate { x: 5., y: 5. }, Coordinate { x: 15., y: 15. });
+//! let mut reader = Reader::with_bbox(&fgb[..], bbox)?;
+//! assert_eq!(reader.header().features_count, 2);
+//!
+//! let features = reader.features::<f64>().collect::<Result<Vec<_>, _>>()?;
+//! assert_eq!(features.len(), 1);
+//! assert_eq!(features[0].properties["name"], Value::String("far".to_string()));
+//! # Ok::<(), FgbError>(())
+//! ```
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect,
+};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+// "fgb", the major version, "fgb", the patch version
+const MAGIC: [u8; 8] = [0x66, 0x67, 0x62, 0x03, 0x66, 0x67, 0x62, 0x00];
+
+/// The error returned when reading or writing FlatGeobuf fails.
+#[derive(Debug)]
+pub enum FgbError {
+    /// Reading or writing failed, or the input ended early.
+    Io(io::Error),
+    /// The input doesn't start with the FlatGeobuf magic bytes of a supported version.
+    NotFlatGeobuf,
+    /// The header, index, or a feature is malformed.
+    InvalidFlatBuffer(&'static str),
+    /// The geometry type code isn't supported (e.g. curved geometries).
+    UnsupportedGeometryType(u8),
+    /// A geometry can't be represented (e.g. a point without coordinates).
+    InvalidGeometry(&'static str),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+    /// A geometry of a different type than the one in the header was written.
+    MismatchedGeometryType {
+        expected: GeometryType,
+        found: GeometryType,
+    },
+    /// A property was written which isn't one of the header's columns.
+    UnknownColumn(String),
+    /// A property was written with a different type than its column's.
+    MismatchedProperty(String),
+    /// The file has no index, so features can't be selected by bounding rectangle.
+    NoIndex,
+}
+
+impl fmt::Display for FgbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FgbError::Io(e) => write!(f, "{}", e),
+            FgbError::NotFlatGeobuf => write!(f, "Not a supported FlatGeobuf file"),
+            FgbError::InvalidFlatBuffer(reason) => write!(f, "Invalid FlatGeobuf: {}", reason),
+            FgbError::UnsupportedGeometryType(geometry_type) => {
+                write!(f, "Unsupported geometry type {}", geometry_type)
+            }
+            FgbError::InvalidGeometry(reason) => write!(f, "Invalid geometry: {}", reason),
+            FgbError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+            FgbError::MismatchedGeometryType { expected, found } => write!(
+                f,
+                "Expected a {:?} geometry, but found a {:?}",
+                expected, found
+            ),
+            FgbError::UnknownColumn(name) => write!(f, "Unknown column '{}'", name),
+            FgbError::MismatchedProperty(name) => {
+                write!(f, "Property '{}' doesn't match its column's type", name)
+            }
+            FgbError::NoIndex => write!(f, "FlatGeobuf file has no index"),
+        }
+    }
+}
+
+impl Error for FgbError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FgbError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FgbError {
+    fn from(e: io::Error) -> Self {
+        FgbError::Io(e)
+    }
+}
+
+/// The type of the geometries of a FlatGeobuf file, or `Unknown` if they may have any type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeometryType {
+    Unknown,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl GeometryType {
+    fn code(self) -> u8 {
+        self as u8
+    }
+
+    fn from_code(code: u8) -> Result<GeometryType, FgbError> {
+        Ok(match code {
+            0 => GeometryType::Unknown,
+            1 => GeometryType::Point,
+            2 => GeometryType::LineString,
+            3 => GeometryType::Polygon,
+            4 => GeometryType::MultiPoint,
+            5 => GeometryType::MultiLineString,
+            6 => GeometryType::MultiPolygon,
+            7 => GeometryType::GeometryCollection,
+            _ => return Err(FgbError::UnsupportedGeometryType(code)),
+        })
+    }
+
+    // The type a geometry is written as
+    fn of<T: CoordinateType>(geometry: &Geometry<T>) -> GeometryType {
+        match geometry {
+            Geometry::Point(_) => GeometryType::Point,
+            Geometry::Line(_) | Geometry::LineString(_) => GeometryType::LineString,
+            Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => {
+                GeometryType::Polygon
+            }
+            Geometry::MultiPoint(_) => GeometryType::MultiPoint,
+            Geometry::MultiLineString(_) => GeometryType::MultiLineString,
+            Geometry::MultiPolygon(_) => GeometryType::MultiPolygon,
+            Geometry::GeometryCollection(_) => GeometryType::GeometryCollection,
+        }
+    }
+}
+
+/// The type of the values of a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    Byte,
+    UByte,
+    Bool,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Long,
+    ULong,
+    Float,
+    Double,
+    String,
+    Json,
+    DateTime,
+    Binary,
+}
+
+impl ColumnType {
+    fn from_code(code: u8) -> Result<ColumnType, FgbError> {
+        Ok(match code {
+            0 => ColumnType::Byte,
+            1 => ColumnType::UByte,
+            2 => ColumnType::Bool,
+            3 => ColumnType::Short,
+            4 => ColumnType::UShort,
+            5 => ColumnType::Int,
+            6 => ColumnType::UInt,
+            7 => ColumnType::Long,
+            8 => ColumnType::ULong,
+            9 => ColumnType::Float,
+            10 => ColumnType::Double,
+            11 => ColumnType::String,
+            12 => ColumnType::Json,
+            13 => ColumnType::DateTime,
+            14 => ColumnType::Binary,
+            _ => return Err(FgbError::InvalidFlatBuffer("unknown column type")),
+        })
+    }
+}
+
+/// A property value of a feature. `Json` and `DateTime` (ISO 8601) values are strings.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Byte(i8),
+    UByte(u8),
+    Bool(bool),
+    Short(i16),
+    UShort(u16),
+    Int(i32),
+    UInt(u32),
+    Long(i64),
+    ULong(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Json(String),
+    DateTime(String),
+    Binary(Vec<u8>),
+}
+
+impl Value {
+    /// The type of the column this value belongs in.
+    pub fn column_type(&self) -> ColumnType {
+        match self {
+            Value::Byte(_) => ColumnType::Byte,
+            Value::UByte(_) => ColumnType::UByte,
+            Value::Bool(_) => ColumnType::Bool,
+            Value::Short(_) => ColumnType::Short,
+            Value::UShort(_) => ColumnType::UShort,
+            Value::Int(_) => ColumnType::Int,
+            Value::UInt(_) => ColumnType::UInt,
+            Value::Long(_) => ColumnType::Long,
+            Value::ULong(_) => ColumnType::ULong,
+            Value::Float(_) => ColumnType::Float,
+            Value::Double(_) => ColumnType::Double,
+            Value::String(_) => ColumnType::String,
+            Value::Json(_) => ColumnType::Json,
+            Value::DateTime(_) => ColumnType::DateTime,
+            Value::Binary(_) => ColumnType::Binary,
+        }
+    }
+}
+
+/// A column of the properties of features.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub column_type: ColumnType,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// The width of the values, or -1 if unknown.
+    pub width: i32,
+    /// The number of significant digits of the values, or -1 if unknown.
+    pub precision: i32,
+    /// The number of digits after the decimal point of the values, or -1 if unknown.
+    pub scale: i32,
+    pub nullable: bool,
+    pub unique: bool,
+    pub primary_key: bool,
+    pub metadata: Option<String>,
+}
+
+impl Column {
+    /// A nullable column with no further details.
+    pub fn new(name: &str, column_type: ColumnType) -> Column {
+        Column {
+            name: name.to_owned(),
+            column_type,
+            title: None,
+            description: None,
+            width: -1,
+            precision: -1,
+            scale: -1,
+            nullable: true,
+            unique: false,
+            primary_key: false,
+            metadata: None,
+        }
+    }
+}
+
+/// The coordinate reference system of a FlatGeobuf file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crs {
+    /// The organization that assigned `code`, such as `EPSG`.
+    pub org: Option<String>,
+    pub code: i32,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The definition of the system, as WKT.
+    pub wkt: Option<String>,
+    /// The code, for codes that aren't numbers.
+    pub code_string: Option<String>,
+}
+
+impl Crs {
+    /// The system with the given EPSG code, such as `4326` for WGS 84.
+    pub fn epsg(code: i32) -> Crs {
+        Crs {
+            org: Some("EPSG".to_owned()),
+            code,
+            name: None,
+            description: None,
+            wkt: None,
+            code_string: None,
+        }
+    }
+}
+
+/// The header of a FlatGeobuf file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    pub name: Option<String>,
+    /// The bounding rectangle of every feature. Computed by the `Writer`.
+    pub envelope: Option<Rect<f64>>,
+    pub geometry_type: GeometryType,
+    pub columns: Vec<Column>,
+    /// The number of features, or 0 if unknown. Computed by the `Writer`.
+    pub features_count: u64,
+    /// The number of children of each node of the index, or 0 for no index.
+    pub index_node_size: u16,
+    pub crs: Option<Crs>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<String>,
+}
+
+impl Header {
+    /// A header for features of `geometry_type`, with no columns, and an index with nodes of
+    /// 16 children.
+    pub fn new(geometry_type: GeometryType) -> Header {
+        Header {
+            name: None,
+            envelope: None,
+            geometry_type,
+            columns: vec![],
+            features_count: 0,
+            index_node_size: 16,
+            crs: None,
+            title: None,
+            description: None,
+            metadata: None,
+        }
+    }
+}
+
+/// A feature of a FlatGeobuf file: an optional geometry, and the values of some of the
+/// columns, by name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature<T>
+where
+    T: CoordinateType,
+{
+    pub geometry: Option<Geometry<T>>,
+    pub properties: BTreeMap<String, Value>,
+}
+
+// Reading FlatBuffers
+
+fn bytes(buf: &[u8], position: usize, len: usize) -> Result<&[u8], FgbError> {
+    position
+        .checked_add(len)
+        .and_then(|end| buf.get(position..end))
+        .ok_or(FgbError::InvalidFlatBuffer("offset out of bounds"))
+}
+
+macro_rules! read_le {
+    ($name:ident, $type:ty) => {
+        fn $name(buf: &[u8], position: usize) -> Result<$type, FgbError> {
+            let mut le = [0; std::mem::size_of::<$type>()];
+            le.copy_from_slice(bytes(buf, position, std::mem::size_of::<$type>())?);
+            Ok(<$type>::from_le_bytes(le))
+        }
+    };
+}
+
+read_le!(read_u8, u8);
+read_le!(read_i8, i8);
+read_le!(read_u16, u16);
+read_le!(read_i16, i16);
+read_le!(read_u32, u32);
+read_le!(read_i32, i32);
+read_le!(read_u64, u64);
+read_le!(read_i64, i64);
+read_le!(read_f32, f32);
+read_le!(read_f64, f64);
+
+// A FlatBuffers table, at `position` in `buf`
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    buf: &'a [u8],
+    position: usize,
+    vtable: usize,
+    vtable_len: usize,
+}
+
+impl<'a> Table<'a> {
+    fn root(buf: &'a [u8]) -> Result<Table<'a>, FgbError> {
+        Table::at(buf, read_u32(buf, 0)? as usize)
+    }
+
+    fn at(buf: &'a [u8], position: usize) -> Result<Table<'a>, FgbError> {
+        let vtable = i64::try_from(position)
+            .ok()
+            .and_then(|position| {
+                position.checked_sub(read_i32(buf, position as usize).ok()?.into())
+            })
+            .and_then(|vtable| usize::try_from(vtable).ok())
+            .ok_or(FgbError::InvalidFlatBuffer("vtable out of bounds"))?;
+        Ok(Table {
+            buf,
+            position,
+            vtable,
+            vtable_len: read_u16(buf, vtable)?.into(),
+        })
+    }
+
+    // The position of a field, if present
+    fn field(&self, index: usize) -> Result<Option<usize>, FgbError> {
+        let entry = 4 + 2 * index;
+        if entry + 2 > self.vtable_len {
+            return Ok(None);
+        }
+        match read_u16(self.buf, self.vtable + entry)? {
+            0 => Ok(None),
+            offset => Ok(Some(self.position + usize::from(offset))),
+        }
+    }
+
+    fn scalar<S>(
+        &self,
+        index: usize,
+        default: S,
+        read: fn(&[u8], usize) -> Result<S, FgbError>,
+    ) -> Result<S, FgbError> {
+        match self.field(index)? {
+            Some(position) => read(self.buf, position),
+            None => Ok(default),
+        }
+    }
+
+    // The position of the string, vector, or table a field refers to
+    fn offset(&self, index: usize) -> Result<Option<usize>, FgbError> {
+        match self.field(index)? {
+            Some(position) => Ok(Some(position + read_u32(self.buf, position)? as usize)),
+            None => Ok(None),
+        }
+    }
+
+    fn vector(&self, index: usize, element_size: usize) -> Result<Option<&'a [u8]>, FgbError> {
+        match self.offset(index)? {
+            Some(position) => {
+                let len = read_u32(self.buf, position)? as usize;
+                let size = len
+                    .checked_mul(element_size)
+                    .ok_or(FgbError::InvalidFlatBuffer("vector out of bounds"))?;
+                Ok(Some(bytes(self.buf, position + 4, size)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn string(&self, index: usize) -> Result<Option<String>, FgbError> {
+        match self.vector(index, 1)? {
+            Some(utf8) => String::from_utf8(utf8.to_vec())
+                .map(Some)
+                .map_err(|_| FgbError::InvalidFlatBuffer("invalid UTF-8 string")),
+            None => Ok(None),
+        }
+    }
+
+    fn table(&self, index: usize) -> Result<Option<Table<'a>>, FgbError> {
+        match self.offset(index)? {
+            Some(position) => Ok(Some(Table::at(self.buf, position)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn tables(&self, index: usize) -> Result<Vec<Table<'a>>, FgbError> {
+        let position = match self.offset(index)? {
+            Some(position) => position,
+            None => return Ok(vec![]),
+        };
+        let len = read_u32(self.buf, position)? as usize;
+        // each element is an offset from itself
+        bytes(self.buf, position + 4, len.saturating_mul(4))?;
+        (0..len)
+            .map(|i| {
+                let element = position + 4 + 4 * i;
+                Table::at(self.buf, element + read_u32(self.buf, element)? as usize)
+            })
+            .collect()
+    }
+}
+
+// Writing FlatBuffers, front to back, aligned relative to the start of the buffer
+
+enum FieldValue {
+    Scalar { le: [u8; 8], size: usize },
+    String(String),
+    Vector { bytes: Vec<u8>, element_size: usize },
+    Table(TableBuilder),
+    Tables(Vec<TableBuilder>),
+}
+
+macro_rules! scalar_field {
+    ($name:ident, $type:ty) => {
+        fn $name(&mut self, index: usize, value: $type) {
+            let mut le = [0; 8];
+            let size = std::mem::size_of::<$type>();
+            le[..size].copy_from_slice(&value.to_le_bytes());
+            self.fields.push((index, FieldValue::Scalar { le, size }));
+        }
+    };
+}
+
+#[derive(Default)]
+struct TableBuilder {
+    fields: Vec<(usize, FieldValue)>,
+}
+
+impl TableBuilder {
+    scalar_field!(u8, u8);
+    scalar_field!(u16, u16);
+    scalar_field!(i32, i32);
+    scalar_field!(u64, u64);
+
+    fn bool(&mut self, index: usize, value: bool) {
+        self.u8(index, value as u8)
+    }
+
+    fn string(&mut self, index: usize, value: &Option<String>) {
+        if let Some(value) = value {
+            self.fields.push((index, FieldValue::String(value.clone())));
+        }
+    }
+
+    fn vector(&mut self, index: usize, bytes: Vec<u8>, element_size: usize) {
+        self.fields.push((
+            index,
+            FieldValue::Vector {
+                bytes,
+                element_size,
+            },
+        ));
+    }
+
+    fn table(&mut self, index: usize, table: TableBuilder) {
+        self.fields.push((index, FieldValue::Table(table)));
+    }
+
+    fn tables(&mut self, index: usize, tables: Vec<TableBuilder>) {
+        self.fields.push((index, FieldValue::Tables(tables)));
+    }
+
+    // A size-prefixed buffer, with this table as its root
+    fn finish(&self) -> Vec<u8> {
+        let mut buf = vec![0; 8];
+        let table = self.write(&mut buf);
+        patch_u32(&mut buf, 4, table - 4);
+        let size = buf.len() - 4;
+        patch_u32(&mut buf, 0, size);
+        buf
+    }
+
+    // Write the vtable, then the table, then what it refers to, returning its position
+    fn write(&self, buf: &mut Vec<u8>) -> usize {
+        let mut inline = self
+            .fields
+            .iter()
+            .map(|(index, value)| match value {
+                FieldValue::Scalar { size, .. } => (*index, *size, value),
+                _ => (*index, 4, value),
+            })
+            .collect::<Vec<_>>();
+        inline.sort_by_key(|&(_, size, _)| Reverse(size));
+        let mut offsets = vec![
+            0u16;
+            inline
+                .iter()
+                .map(|&(index, ..)| index + 1)
+                .max()
+                .unwrap_or(0)
+        ];
+        let mut table_size = 4;
+        for &(index, size, _) in &inline {
+            table_size = align(table_size, size);
+            offsets[index] = table_size as u16;
+            table_size += size;
+        }
+
+        pad(buf, 2);
+        let vtable = buf.len();
+        buf.extend_from_slice(&(4 + 2 * offsets.len() as u16).to_le_bytes());
+        buf.extend_from_slice(&(table_size as u16).to_le_bytes());
+        for offset in &offsets {
+            buf.extend_from_slice(&offset.to_le_bytes());
+        }
+        pad(buf, 8);
+        let table = buf.len();
+        buf.extend_from_slice(&((table - vtable) as i32).to_le_bytes());
+        buf.resize(table + table_size, 0);
+
+        for &(index, _, value) in &inline {
+            let position = table + usize::from(offsets[index]);
+            let target = match value {
+                FieldValue::Scalar { le, size } => {
+                    buf[position..position + size].copy_from_slice(&le[..*size]);
+                    continue;
+                }
+                FieldValue::String(string) => {
+                    pad(buf, 4);
+                    let target = buf.len();
+                    buf.extend_from_slice(&(string.len() as u32).to_le_bytes());
+                    buf.extend_from_slice(string.as_bytes());
+                    buf.push(0);
+                    target
+                }
+                FieldValue::Vector {
+                    bytes,
+                    element_size,
+                } => {
+                    // the elements follow the length, aligned to their size
+                    pad(buf, 4);
+                    while (buf.len() + 4) & ((*element_size).max(4) - 1) != 0 {
+                        buf.push(0);
+                    }
+                    let target = buf.len();
+                    buf.extend_from_slice(&((bytes.len() / element_size) as u32).to_le_bytes());
+                    buf.extend_from_slice(bytes);
+                    target
+                }
+                FieldValue::Table(child) => child.write(buf),
+                FieldValue::Tables(children) => {
+                    pad(buf, 4);
+                    let target = buf.len();
+                    buf.extend_from_slice(&(children.len() as u32).to_le_bytes());
+                    buf.resize(target + 4 + 4 * children.len(), 0);
+                    for (i, child) in children.iter().enumerate() {
+                        let element = target + 4 + 4 * i;
+                        let child = child.write(buf);
+                        patch_u32(buf, element, child - element);
+                    }
+                    target
+                }
+            };
+            patch_u32(buf, position, target - position);
+        }
+        table
+    }
+}
+
+fn align(position: usize, alignment: usize) -> usize {
+    position.div_ceil(alignment) * alignment
+}
+
+fn pad(buf: &mut Vec<u8>, alignment: usize) {
+    buf.resize(align(buf.len(), alignment), 0);
+}
+
+fn patch_u32(buf: &mut [u8], position: usize, value: usize) {
+    buf[position..position + 4].copy_from_slice(&(value as u32).to_le_bytes());
+}
+
+// Headers
+
+fn read_header(buf: &[u8]) -> Result<Header, FgbError> {
+    let table = Table::root(buf)?;
+    // an envelope which isn't a valid rectangle (e.g. of NaNs) is ignored
+    let envelope = match table.vector(1, 8)?.filter(|envelope| envelope.len() >= 32) {
+        Some(envelope) => {
+            let min = Coordinate {
+                x: read_f64(envelope, 0)?,
+                y: read_f64(envelope, 8)?,
+            };
+            let max = Coordinate {
+                x: read_f64(envelope, 16)?,
+                y: read_f64(envelope, 24)?,
+            };
+            Some(min)
+                .filter(|min| min.x <= max.x && min.y <= max.y)
+                .map(|min| Rect::new(min, max))
+        }
+        None => None,
+    };
+    let crs = match table.table(10)? {
+        Some(crs) => Some(Crs {
+            org: crs.string(0)?,
+            code: crs.scalar(1, 0, read_i32)?,
+            name: crs.string(2)?,
+            description: crs.string(3)?,
+            wkt: crs.string(4)?,
+            code_string: crs.string(5)?,
+        }),
+        None => None,
+    };
+    Ok(Header {
+        name: table.string(0)?,
+        envelope,
+        geometry_type: GeometryType::from_code(table.scalar(2, 0, read_u8)?)?,
+        columns: read_columns(&table, 7)?,
+        features_count: table.scalar(8, 0, read_u64)?,
+        index_node_size: table.scalar(9, 16, read_u16)?,
+        crs,
+        title: table.string(11)?,
+        description: table.string(12)?,
+        metadata: table.string(13)?,
+    })
+}
+
+fn read_columns(table: &Table, index: usize) -> Result<Vec<Column>, FgbError> {
+    table
+        .tables(index)?
+        .iter()
+        .map(|column| {
+            Ok(Column {
+                name: column
+                    .string(0)?
+                    .ok_or(FgbError::InvalidFlatBuffer("column without a name"))?,
+                column_type: ColumnType::from_code(column.scalar(1, 0, read_u8)?)?,
+                title: column.string(2)?,
+                description: column.string(3)?,
+                width: column.scalar(4, -1, read_i32)?,
+                precision: column.scalar(5, -1, read_i32)?,
+                scale: column.scalar(6, -1, read_i32)?,
+                nullable: column.scalar(7, 1, read_u8)? != 0,
+                unique: column.scalar(8, 0, read_u8)? != 0,
+                primary_key: column.scalar(9, 0, read_u8)? != 0,
+                metadata: column.string(10)?,
+            })
+        })
+        .collect()
+}
+
+fn header_table(header: &Header) -> TableBuilder {
+    let mut table = TableBuilder::default();
+    table.string(0, &header.name);
+    if let Some(envelope) = header.envelope {
+        let bounds = [
+            envelope.min().x,
+            envelope.min().y,
+            envelope.max().x,
+            envelope.max().y,
+        ];
+        table.vector(1, bounds.iter().flat_map(|v| v.to_le_bytes()).collect(), 8);
+    }
+    table.u8(2, header.geometry_type.code());
+    if !header.columns.is_empty() {
+        let columns = header
+            .columns
+            .iter()
+            .map(|column| {
+                let mut table = TableBuilder::default();
+                table.string(0, &Some(column.name.clone()));
+                table.u8(1, column.column_type as u8);
+                table.string(2, &column.title);
+                table.string(3, &column.description);
+                table.i32(4, column.width);
+                table.i32(5, column.precision);
+                table.i32(6, column.scale);
+                table.bool(7, column.nullable);
+                table.bool(8, column.unique);
+                table.bool(9, column.primary_key);
+                table.string(10, &column.metadata);
+                table
+            })
+            .collect();
+        table.tables(7, columns);
+    }
+    table.u64(8, header.features_count);
+    table.u16(9, header.index_node_size);
+    if let Some(crs) = &header.crs {
+        let mut crs_table = TableBuilder::default();
+        crs_table.string(0, &crs.org);
+        crs_table.i32(1, crs.code);
+        crs_table.string(2, &crs.name);
+        crs_table.string(3, &crs.description);
+        crs_table.string(4, &crs.wkt);
+        crs_table.string(5, &crs.code_string);
+        table.table(10, crs_table);
+    }
+    table.string(11, &header.title);
+    table.string(12, &header.description);
+    table.string(13, &header.metadata);
+    table
+}
+
+// Geometries
+
+fn read_coords<T: CoordinateType>(table: &Table) -> Result<Vec<Coordinate<T>>, FgbError> {
+    let xy = table.vector(1, 8)?.unwrap_or(&[]);
+    let ordinate = |position| -> Result<T, FgbError> {
+        let value = read_f64(xy, position)?;
+        T::from(value).ok_or(FgbError::InvalidCoordinate(value))
+    };
+    (0..xy.len() / 16)
+        .map(|i| {
+            Ok(Coordinate {
+                x: ordinate(16 * i)?,
+                y: ordinate(16 * i + 8)?,
+            })
+        })
+        .collect()
+}
+
+// The coordinates of each ring or line string, which end at each of the `ends`
+fn read_parts<T: CoordinateType>(table: &Table) -> Result<Vec<LineString<T>>, FgbError> {
+    let coords = read_coords(table)?;
+    let ends = match table.vector(0, 4)? {
+        Some(ends) if !ends.is_empty() => ends,
+        _ if coords.is_empty() => return Ok(vec![]),
+        _ => return Ok(vec![LineString(coords)]),
+    };
+    let mut parts = Vec::with_capacity(ends.len() / 4);
+    let mut start = 0;
+    for i in 0..ends.len() / 4 {
+        let end = read_u32(ends, 4 * i)? as usize;
+        if end < start || end > coords.len() {
+            return Err(FgbError::InvalidFlatBuffer(
+                "invalid ends of geometry parts",
+            ));
+        }
+        parts.push(end - start);
+        start = end;
+    }
+    let mut rest = coords.into_iter();
+    Ok(parts
+        .into_iter()
+        .map(|len| LineString(rest.by_ref().take(len).collect()))
+        .collect())
+}
+
+fn read_polygon<T: CoordinateType>(table: &Table) -> Result<Polygon<T>, FgbError> {
+    let mut rings = read_parts(table)?.into_iter();
+    let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+    Ok(Polygon::new(exterior, rings.collect()))
+}
+
+// How deeply geometry collections may be nested, so that malicious input can't overflow the
+// stack
+const MAX_DEPTH: usize = 64;
+
+fn read_geometry<T: CoordinateType>(
+    table: &Table,
+    geometry_type: GeometryType,
+    depth: usize,
+) -> Result<Geometry<T>, FgbError> {
+    Ok(match geometry_type {
+        GeometryType::Unknown => {
+            return Err(FgbError::InvalidFlatBuffer("geometry without a type"));
+        }
+        GeometryType::Point => match read_coords(table)?.first() {
+            Some(coord) => Geometry::Point(Point(*coord)),
+            None => return Err(FgbError::InvalidGeometry("point without coordinates")),
+        },
+        GeometryType::LineString => Geometry::LineString(LineString(read_coords(table)?)),
+        GeometryType::Polygon => Geometry::Polygon(read_polygon(table)?),
+        GeometryType::MultiPoint => Geometry::MultiPoint(MultiPoint(
+            read_coords(table)?.into_iter().map(Point).collect(),
+        )),
+        GeometryType::MultiLineString => {
+            Geometry::MultiLineString(MultiLineString(read_parts(table)?))
+        }
+        GeometryType::MultiPolygon => Geometry::MultiPolygon(MultiPolygon(
+            table
+                .tables(7)?
+                .iter()
+                .map(read_polygon)
+                .collect::<Result<_, _>>()?,
+        )),
+        GeometryType::GeometryCollection if depth == MAX_DEPTH => {
+            return Err(FgbError::InvalidGeometry(
+                "geometry collections nested too deeply",
+            ));
+        }
+        GeometryType::GeometryCollection => Geometry::GeometryCollection(GeometryCollection(
+            table
+                .tables(7)?
+                .iter()
+                .map(|part| {
+                    let part_type = GeometryType::from_code(part.scalar(6, 0, read_u8)?)?;
+                    read_geometry(part, part_type, depth + 1)
+                })
+                .collect::<Result<_, _>>()?,
+        )),
+    })
+}
+
+// Builds the table of a geometry, tracking the bounding rectangle of its coordinates
+struct GeometryWriter {
+    bounds: NodeItem,
+}
+
+impl GeometryWriter {
+    fn xy<T: CoordinateType>(&mut self, coords: &[Coordinate<T>], xy: &mut Vec<u8>) {
+        for coord in coords {
+            let x = coord
+                .x
+                .to_f64()
+                .expect("coordinate can't be converted to f64");
+            let y = coord
+                .y
+                .to_f64()
+                .expect("coordinate can't be converted to f64");
+            self.bounds.expand(&NodeItem {
+                min_x: x,
+                min_y: y,
+                max_x: x,
+                max_y: y,
+                offset: 0,
+            });
+            xy.extend_from_slice(&x.to_le_bytes());
+            xy.extend_from_slice(&y.to_le_bytes());
+        }
+    }
+
+    fn coords<T: CoordinateType>(
+        &mut self,
+        geometry_type: GeometryType,
+        coords: &[Coordinate<T>],
+    ) -> TableBuilder {
+        let mut xy = Vec::with_capacity(16 * coords.len());
+        self.xy(coords, &mut xy);
+        let mut table = TableBuilder::default();
+        table.vector(1, xy, 8);
+        table.u8(6, geometry_type.code());
+        table
+    }
+
+    // Several line strings or rings, with their ends if there are more than one
+    fn parts<'a, T: CoordinateType + 'a>(
+        &mut self,
+        geometry_type: GeometryType,
+        parts: impl Iterator<Item = &'a LineString<T>>,
+    ) -> TableBuilder {
+        let mut xy = vec![];
+        let mut ends = vec![];
+        let mut end = 0u32;
+        for part in parts {
+            self.xy(&part.0, &mut xy);
+            end += part.0.len() as u32;
+            ends.extend_from_slice(&end.to_le_bytes());
+        }
+        let mut table = TableBuilder::default();
+        if ends.len() > 4 {
+            table.vector(0, ends, 4);
+        }
+        table.vector(1, xy, 8);
+        table.u8(6, geometry_type.code());
+        table
+    }
+
+    fn polygon<T: CoordinateType>(&mut self, polygon: &Polygon<T>) -> TableBuilder {
+        let rings = if polygon.exterior().0.is_empty() {
+            0
+        } else {
+            1 + polygon.interiors().len()
+        };
+        let rings = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .take(rings);
+        self.parts(GeometryType::Polygon, rings)
+    }
+
+    fn geometry<T: CoordinateType>(&mut self, geometry: &Geometry<T>) -> TableBuilder {
+        match geometry {
+            Geometry::Point(p) => self.coords(GeometryType::Point, &[p.0]),
+            Geometry::Line(l) => self.coords(GeometryType::LineString, &[l.start, l.end]),
+            Geometry::LineString(ls) => self.coords(GeometryType::LineString, &ls.0),
+            Geometry::Polygon(p) => self.polygon(p),
+            Geometry::MultiPoint(mp) => {
+                let coords = mp.0.iter().map(|p| p.0).collect::<Vec<_>>();
+                self.coords(GeometryType::MultiPoint, &coords)
+            }
+            Geometry::MultiLineString(mls) => {
+                self.parts(GeometryType::MultiLineString, mls.0.iter())
+            }
+            Geometry::MultiPolygon(mp) => {
+                let parts = mp.0.iter().map(|p| self.polygon(p)).collect();
+                let mut table = TableBuilder::default();
+                table.u8(6, GeometryType::MultiPolygon.code());
+                table.tables(7, parts);
+                table
+            }
+            Geometry::Rect(r) => self.polygon(&Polygon::from(*r)),
+            Geometry::Triangle(t) => self.polygon(&Polygon::from(*t)),
+            Geometry::GeometryCollection(gc) => {
+                let parts = gc.0.iter().map(|g| self.geometry(g)).collect();
+                let mut table = TableBuilder::default();
+                table.u8(6, GeometryType::GeometryCollection.code());
+                table.tables(7, parts);
+                table
+            }
+        }
+    }
+}
+
+// Properties
+
+fn read_properties(
+    mut properties: &[u8],
+    columns: &[Column],
+) -> Result<BTreeMap<String, Value>, FgbError> {
+    let mut values = BTreeMap::new();
+    while !properties.is_empty() {
+        let column = columns
+            .get(usize::from(read_u16(properties, 0)?))
+            .ok_or(FgbError::InvalidFlatBuffer("property of an unknown column"))?;
+        let (value, size) = match column.column_type {
+            ColumnType::Byte => (Value::Byte(read_i8(properties, 2)?), 1),
+            ColumnType::UByte => (Value::UByte(read_u8(properties, 2)?), 1),
+            ColumnType::Bool => (Value::Bool(read_u8(properties, 2)? != 0), 1),
+            ColumnType::Short => (Value::Short(read_i16(properties, 2)?), 2),
+            ColumnType::UShort => (Value::UShort(read_u16(properties, 2)?), 2),
+            ColumnType::Int => (Value::Int(read_i32(properties, 2)?), 4),
+            ColumnType::UInt => (Value::UInt(read_u32(properties, 2)?), 4),
+            ColumnType::Long => (Value::Long(read_i64(properties, 2)?), 8),
+            ColumnType::ULong => (Value::ULong(read_u64(properties, 2)?), 8),
+            ColumnType::Float => (Value::Float(read_f32(properties, 2)?), 4),
+            ColumnType::Double => (Value::Double(read_f64(properties, 2)?), 8),
+            ColumnType::String | ColumnType::Json | ColumnType::DateTime | ColumnType::Binary => {
+                let len = read_u32(properties, 2)? as usize;
+                let data = bytes(properties, 6, len)?.to_vec();
+                let string = || {
+                    String::from_utf8(data.clone())
+                        .map_err(|_| FgbError::InvalidFlatBuffer("invalid UTF-8 property"))
+                };
+                let value = match column.column_type {
+                    ColumnType::String => Value::String(string()?),
+                    ColumnType::Json => Value::Json(string()?),
+                    ColumnType::DateTime => Value::DateTime(string()?),
+                    _ => Value::Binary(data.clone()),
+                };
+                (value, 4 + len)
+            }
+        };
+        values.insert(column.name.clone(), value);
+        properties = &properties[2 + size..];
+    }
+    Ok(values)
+}
+
+fn write_properties(
+    properties: &BTreeMap<String, Value>,
+    columns: &[Column],
+) -> Result<Vec<u8>, FgbError> {
+    if let Some(name) = properties
+        .keys()
+        .find(|&name| columns.iter().all(|column| &column.name != name))
+    {
+        return Err(FgbError::UnknownColumn(name.clone()));
+    }
+    let mut bytes = vec![];
+    for (i, column) in columns.iter().enumerate() {
+        let value = match properties.get(&column.name) {
+            Some(value) => value,
+            None => continue,
+        };
+        let index = u16::try_from(i).map_err(|_| FgbError::UnknownColumn(column.name.clone()))?;
+        if value.column_type() != column.column_type {
+            return Err(FgbError::MismatchedProperty(column.name.clone()));
+        }
+        bytes.extend_from_slice(&index.to_le_bytes());
+        match value {
+            Value::Byte(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::UByte(v) => bytes.push(*v),
+            Value::Bool(v) => bytes.push(*v as u8),
+            Value::Short(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::UShort(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::Int(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::UInt(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::Long(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::ULong(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::Float(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::Double(v) => bytes.extend_from_slice(&v.to_le_bytes()),
+            Value::String(v) | Value::Json(v) | Value::DateTime(v) => {
+                bytes.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(v.as_bytes());
+            }
+            Value::Binary(v) => {
+                bytes.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(v);
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+// The packed Hilbert R-tree index
+
+const NODE_ITEM_SIZE: usize = 40;
+
+// A node of the index: the bounding rectangle of its children, and the position of its first
+// child in the index, or, for leaves, of its feature after the index
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct NodeItem {
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    offset: u64,
+}
+
+impl NodeItem {
+    fn empty(offset: u64) -> NodeItem {
+        NodeItem {
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+            offset,
+        }
+    }
+
+    fn expand(&mut self, other: &NodeItem) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+
+    fn is_empty(&self) -> bool {
+        !(self.min_x <= self.max_x && self.min_y <= self.max_y)
+    }
+
+    fn intersects(&self, rect: &Rect<f64>) -> bool {
+        self.min_x <= rect.max().x
+            && self.min_y <= rect.max().y
+            && self.max_x >= rect.min().x
+            && self.max_y >= rect.min().y
+    }
+
+    fn read(buf: &[u8]) -> Result<NodeItem, FgbError> {
+        Ok(NodeItem {
+            min_x: read_f64(buf, 0)?,
+            min_y: read_f64(buf, 8)?,
+            max_x: read_f64(buf, 16)?,
+            max_y: read_f64(buf, 24)?,
+            offset: read_u64(buf, 32)?,
+        })
+    }
+
+    fn write(&self, buf: &mut Vec<u8>) {
+        for value in &[self.min_x, self.min_y, self.max_x, self.max_y] {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        buf.extend_from_slice(&self.offset.to_le_bytes());
+    }
+}
+
+// The range of nodes of each level of the index, from the leaves to the root, which come
+// first. `None` if the index would be too large to address.
+fn level_bounds(items: u64, node_size: u16) -> Option<Vec<Range<usize>>> {
+    let node_size = usize::from(node_size.max(2));
+    let mut n = usize::try_from(items).ok()?;
+    let mut level_sizes = vec![n];
+    let mut nodes = n;
+    loop {
+        n = n.div_ceil(node_size);
+        nodes = nodes.checked_add(n)?;
+        level_sizes.push(n);
+        if n <= 1 {
+            break;
+        }
+    }
+    nodes.checked_mul(NODE_ITEM_SIZE)?;
+    let mut end = nodes;
+    Some(
+        level_sizes
+            .into_iter()
+            .map(|size| {
+                end -= size;
+                end..end + size
+            })
+            .collect(),
+    )
+}
+
+// The distance along a Hilbert curve of a point on a 2^16 × 2^16 grid
+fn hilbert(x: u32, y: u32) -> u32 {
+    let mut a = x ^ y;
+    let mut b = 0xFFFF ^ a;
+    let mut c = 0xFFFF ^ (x | y);
+    let mut d = x & (y ^ 0xFFFF);
+
+    let mut aa = a | (b >> 1);
+    let mut bb = (a >> 1) ^ a;
+    let mut cc = ((c >> 1) ^ (b & (d >> 1))) ^ c;
+    let mut dd = ((a & (c >> 1)) ^ (d >> 1)) ^ d;
+
+    for &shift in &[2, 4] {
+        a = aa;
+        b = bb;
+        c = cc;
+        d = dd;
+        aa = (a & (a >> shift)) ^ (b & (b >> shift));
+        bb = (a & (b >> shift)) ^ (b & ((a ^ b) >> shift));
+        cc ^= (a & (c >> shift)) ^ (b & (d >> shift));
+        dd ^= (b & (c >> shift)) ^ ((a ^ b) & (d >> shift));
+    }
+
+    a = aa;
+    b = bb;
+    c = cc;
+    d = dd;
+    cc ^= (a & (c >> 8)) ^ (b & (d >> 8));
+    dd ^= (b & (c >> 8)) ^ ((a ^ b) & (d >> 8));
+
+    a = cc ^ (cc >> 1);
+    b = dd ^ (dd >> 1);
+
+    let mut i0 = x ^ y;
+    let mut i1 = b | (0xFFFF ^ (i0 | a));
+    for &(shift, mask) in &[
+        (8, 0x00FF_00FF),
+        (4, 0x0F0F_0F0F),
+        (2, 0x3333_3333),
+        (1, 0x5555_5555),
+    ] {
+        i0 = (i0 | (i0 << shift)) & mask;
+        i1 = (i1 | (i1 << shift)) & mask;
+    }
+    (i1 << 1) | i0
+}
+
+// The Hilbert value of the centre of a node, within `extent`
+fn hilbert_value(node: &NodeItem, extent: &NodeItem) -> u32 {
+    let scale = |min: f64, max: f64, extent_min: f64, extent_max: f64| {
+        let width = extent_max - extent_min;
+        if width > 0. {
+            (f64::from(0xFFFF) * ((min + max) / 2. - extent_min) / width).floor() as u32
+        } else {
+            0
+        }
+    };
+    hilbert(
+        scale(node.min_x, node.max_x, extent.min_x, extent.max_x),
+        scale(node.min_y, node.max_y, extent.min_y, extent.max_y),
+    )
+}
+
+// The offsets of the features whose leaves intersect `rect`, in ascending order
+fn search(
+    index: &[u8],
+    items: u64,
+    node_size: u16,
+    rect: &Rect<f64>,
+) -> Result<Vec<u64>, FgbError> {
+    let invalid = FgbError::InvalidFlatBuffer("invalid index");
+    let level_bounds = level_bounds(items, node_size).ok_or(invalid)?;
+    let node_size = usize::from(node_size.max(2));
+    let leaves = level_bounds[0].start;
+    let node = |i: usize| NodeItem::read(bytes(index, NODE_ITEM_SIZE * i, NODE_ITEM_SIZE)?);
+    let mut offsets = vec![];
+    let mut stack = vec![(0, level_bounds.len() - 1)];
+    while let Some((first, level)) = stack.pop() {
+        let end = (first + node_size).min(level_bounds[level].end);
+        for i in first..end {
+            let node = node(i)?;
+            if !node.intersects(rect) {
+                continue;
+            }
+            if first >= leaves {
+                offsets.push(node.offset);
+            } else if level > 0 {
+                let child = usize::try_from(node.offset)
+                    .ok()
+                    .filter(|child| level_bounds[level - 1].contains(child))
+                    .ok_or(FgbError::InvalidFlatBuffer("invalid index"))?;
+                stack.push((child, level - 1));
+            }
+        }
+    }
+    offsets.sort_unstable();
+    offsets.dedup();
+    Ok(offsets)
+}
+
+// Reading and writing files
+
+fn ended_early() -> FgbError {
+    FgbError::Io(io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "FlatGeobuf ended early",
+    ))
+}
+
+// Skip exactly `len` bytes
+fn skip<R: Read>(reader: &mut R, len: u64) -> Result<(), FgbError> {
+    if io::copy(&mut reader.take(len), &mut io::sink())? < len {
+        return Err(ended_early());
+    }
+    Ok(())
+}
+
+// Read exactly `len` bytes, without trusting `len` enough to allocate it up front
+fn read_bytes<R: Read>(reader: &mut R, len: u64) -> Result<Vec<u8>, FgbError> {
+    let mut buf = vec![];
+    reader.take(len).read_to_end(&mut buf)?;
+    if (buf.len() as u64) < len {
+        return Err(ended_early());
+    }
+    Ok(buf)
+}
+
+// Read a size prefix, or `None` at the end of the input
+fn read_size<R: Read>(reader: &mut R) -> Result<Option<u32>, FgbError> {
+    let mut le = [0; 4];
+    let mut read = 0;
+    while read < le.len() {
+        match reader.read(&mut le[read..]) {
+            Ok(0) if read == 0 => return Ok(None),
+            Ok(0) => return Err(ended_early()),
+            Ok(n) => read += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(Some(u32::from_le_bytes(le)))
+}
+
+/// Streams the features of a FlatGeobuf file from a `Read`.
+///
+/// The header is read when the `Reader` is created, and each feature in turn as it's
+/// requested. Nothing is buffered, so wrap files in a `BufReader`.
+pub struct Reader<R: Read> {
+    reader: R,
+    header: Header,
+    // The offsets of the selected features, if they were selected using the index
+    selected: Option<std::vec::IntoIter<u64>>,
+    // The number of bytes read since the end of the index
+    position: u64,
+    read: u64,
+}
+
+impl<R: Read> Reader<R> {
+    /// Read the header of a FlatGeobuf file, and skip its index, to read every feature.
+    pub fn new(reader: R) -> Result<Reader<R>, FgbError> {
+        let mut reader = Reader::open(reader)?;
+        if let Some(size) = reader.index_size()? {
+            skip(&mut reader.reader, size)?;
+        }
+        Ok(reader)
+    }
+
+    /// Read the header and index of a FlatGeobuf file, to read the features whose bounding
+    /// rectangles intersect `bbox`, in the order they're stored in.
+    ///
+    /// Fails with `FgbError::NoIndex` if the file has no index.
+    pub fn with_bbox(reader: R, bbox: Rect<f64>) -> Result<Reader<R>, FgbError> {
+        let mut reader = Reader::open(reader)?;
+        let size = reader.index_size()?.ok_or(FgbError::NoIndex)?;
+        let index = read_bytes(&mut reader.reader, size)?;
+        let offsets = search(
+            &index,
+            reader.header.features_count,
+            reader.header.index_node_size,
+            &bbox,
+        )?;
+        reader.selected = Some(offsets.into_iter());
+        Ok(reader)
+    }
+
+    fn open(mut reader: R) -> Result<Reader<R>, FgbError> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if magic[..7] != MAGIC[..7] {
+            return Err(FgbError::NotFlatGeobuf);
+        }
+        let size = read_size(&mut reader)?.ok_or(FgbError::NotFlatGeobuf)?;
+        let header = read_header(&read_bytes(&mut reader, size.into())?)?;
+        Ok(Reader {
+            reader,
+            header,
+            selected: None,
+            position: 0,
+            read: 0,
+        })
+    }
+
+    fn index_size(&self) -> Result<Option<u64>, FgbError> {
+        if self.header.index_node_size == 0 || self.header.features_count == 0 {
+            return Ok(None);
+        }
+        let level_bounds = level_bounds(self.header.features_count, self.header.index_node_size)
+            .ok_or(FgbError::InvalidFlatBuffer("index too large"))?;
+        Ok(Some((level_bounds[0].end * NODE_ITEM_SIZE) as u64))
+    }
+
+    /// The header of the file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Read the next feature, or `None` after the last one.
+    pub fn next_feature<T: CoordinateType>(&mut self) -> Result<Option<Feature<T>>, FgbError> {
+        if let Some(selected) = &mut self.selected {
+            let offset = match selected.next() {
+                Some(offset) => offset,
+                None => return Ok(None),
+            };
+            let skip_len = offset
+                .checked_sub(self.position)
+                .ok_or(FgbError::InvalidFlatBuffer("invalid index"))?;
+            skip(&mut self.reader, skip_len)?;
+            self.position = offset;
+        } else if self.header.features_count != 0 && self.read == self.header.features_count {
+            return Ok(None);
+        }
+        let size = match read_size(&mut self.reader)? {
+            Some(size) => size,
+            None if self.header.features_count == 0 => return Ok(None),
+            None => return Err(ended_early()),
+        };
+        let buf = read_bytes(&mut self.reader, size.into())?;
+        self.position += 4 + u64::from(size);
+        self.read += 1;
+        self.feature(&buf).map(Some)
+    }
+
+    fn feature<T: CoordinateType>(&self, buf: &[u8]) -> Result<Feature<T>, FgbError> {
+        let table = Table::root(buf)?;
+        let geometry = match table.table(0)? {
+            Some(geometry) => {
+                let geometry_type = match self.header.geometry_type {
+                    GeometryType::Unknown => {
+                        GeometryType::from_code(geometry.scalar(6, 0, read_u8)?)?
+                    }
+                    geometry_type => geometry_type,
+                };
+                Some(read_geometry(&geometry, geometry_type, 0)?)
+            }
+            None => None,
+        };
+        let columns = read_columns(&table, 2)?;
+        let columns = if columns.is_empty() {
+            &self.header.columns
+        } else {
+            &columns
+        };
+        let properties = read_properties(table.vector(1, 1)?.unwrap_or(&[]), columns)?;
+        Ok(Feature {
+            geometry,
+            properties,
+        })
+    }
+
+    /// An iterator over the remaining features.
+    pub fn features<T: CoordinateType>(&mut self) -> Features<'_, R, T> {
+        Features {
+            reader: self,
+            failed: false,
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the features of a [`Reader`](struct.Reader.html), which ends after the
+/// first error.
+pub struct Features<'a, R: Read, T: CoordinateType> {
+    reader: &'a mut Reader<R>,
+    failed: bool,
+    coordinate_type: PhantomData<T>,
+}
+
+impl<'a, R: Read, T: CoordinateType> Iterator for Features<'a, R, T> {
+    type Item = Result<Feature<T>, FgbError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.reader.next_feature().transpose();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+/// Writes features to a FlatGeobuf file.
+///
+/// Since the index comes before the features, and orders them along a Hilbert curve, features
+/// are encoded as they're added, and kept until they're all written out by
+/// [`write`](#method.write).
+pub struct Writer {
+    header: Header,
+    features: Vec<(NodeItem, Vec<u8>)>,
+}
+
+impl Writer {
+    /// Write features with `header`, which gives the type of their geometries, their
+    /// columns, and whether to write an index. Its envelope and number of features are
+    /// computed from the features.
+    pub fn new(header: Header) -> Writer {
+        Writer {
+            header,
+            features: vec![],
+        }
+    }
+
+    /// Encode a feature, to be written with the others.
+    ///
+    /// Fails if the feature's geometry doesn't have the header's geometry type, or its
+    /// properties aren't in the header's columns or have different types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn add_feature<T: CoordinateType>(&mut self, feature: &Feature<T>) -> Result<(), FgbError> {
+        let mut table = TableBuilder::default();
+        let mut geometry_writer = GeometryWriter {
+            bounds: NodeItem::empty(0),
+        };
+        if let Some(geometry) = &feature.geometry {
+            let found = GeometryType::of(geometry);
+            if self.header.geometry_type != GeometryType::Unknown
+                && self.header.geometry_type != found
+            {
+                return Err(FgbError::MismatchedGeometryType {
+                    expected: self.header.geometry_type,
+                    found,
+                });
+            }
+            table.table(0, geometry_writer.geometry(geometry));
+        }
+        let properties = write_properties(&feature.properties, &self.header.columns)?;
+        if !properties.is_empty() {
+            table.vector(1, properties, 1);
+        }
+        self.features.push((geometry_writer.bounds, table.finish()));
+        Ok(())
+    }
+
+    /// Write the header, index, and features.
+    pub fn write<W: Write>(mut self, mut writer: W) -> Result<(), FgbError> {
+        let mut extent = NodeItem::empty(0);
+        for (bounds, _) in &self.features {
+            extent.expand(bounds);
+        }
+        self.header.features_count = self.features.len() as u64;
+        self.header.envelope = if extent.is_empty() {
+            None
+        } else {
+            Some(Rect::new(
+                Coordinate {
+                    x: extent.min_x,
+                    y: extent.min_y,
+                },
+                Coordinate {
+                    x: extent.max_x,
+                    y: extent.max_y,
+                },
+            ))
+        };
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&header_table(&self.header).finish())?;
+
+        if self.header.index_node_size > 0 && !self.features.is_empty() {
+            self.features
+                .sort_by_cached_key(|(bounds, _)| Reverse(hilbert_value(bounds, &extent)));
+            writer.write_all(&self.index())?;
+        }
+        for (_, feature) in &self.features {
+            writer.write_all(feature)?;
+        }
+        Ok(())
+    }
+
+    // The nodes of the index, with the features in their final order
+    fn index(&self) -> Vec<u8> {
+        let node_size = usize::from(self.header.index_node_size.max(2));
+        let level_bounds = level_bounds(self.features.len() as u64, self.header.index_node_size)
+            .expect("too many features for an index");
+        let mut nodes = vec![NodeItem::empty(0); level_bounds[0].end];
+        let mut offset = 0;
+        for (i, (bounds, feature)) in self.features.iter().enumerate() {
+            nodes[level_bounds[0].start + i] = NodeItem { offset, ..*bounds };
+            offset += feature.len() as u64;
+        }
+        for levels in level_bounds.windows(2) {
+            let (children, parents) = (&levels[0], &levels[1]);
+            for (parent, first) in parents.clone().zip(children.clone().step_by(node_size)) {
+                let mut node = NodeItem::empty(first as u64);
+                for child in &nodes[first..(first + node_size).min(children.end)] {
+                    node.expand(child);
+                }
+                nodes[parent] = node;
+            }
+        }
+        let mut index = Vec::with_capacity(nodes.len() * NODE_ITEM_SIZE);
+        for node in &nodes {
+            node.write(&mut index);
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Triangle};
+
+    fn feature(geometry: Geometry<f64>) -> Feature<f64> {
+        Feature {
+            geometry: Some(geometry),
+            properties: BTreeMap::new(),
+        }
+    }
+
+    fn round_trip(header: Header, features: &[Feature<f64>]) -> (Header, Vec<Feature<f64>>) {
+        let mut writer = Writer::new(header);
+        for feature in features {
+            writer.add_feature(feature).unwrap();
+        }
+        let mut fgb = vec![];
+        writer.write(&mut fgb).unwrap();
+        let mut reader = Reader::new(&fgb[..]).unwrap();
+        let features = reader.features().collect::<Result<Vec<_>, _>>().unwrap();
+        (reader.header().clone(), features)
+    }
+
+    fn sorted(features: &[Feature<f64>]) -> Vec<String> {
+        let mut debug = features
+            .iter()
+            .map(|f| format!("{:?}", f))
+            .collect::<Vec<_>>();
+        debug.sort();
+        debug
+    }
+
+    #[test]
+    fn every_geometry_type() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        ];
+        let triangle = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)];
+        let features = vec![
+            feature(point!(x: 1., y: 2.).into()),
+            feature(line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()),
+            feature(square.clone().into()),
+            feature(Polygon::new(LineString(vec![]), vec![]).into()),
+            feature(MultiPoint(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into()),
+            feature(
+                MultiLineString(vec![
+                    line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                    line_string![(x: 2., y: 2.), (x: 3., y: 3.), (x: 4., y: 2.)],
+                ])
+                .into(),
+            ),
+            feature(MultiPolygon(vec![square.clone(), triangle.clone()]).into()),
+            feature(Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 1., y: 2.).into(),
+                Geometry::GeometryCollection(GeometryCollection(vec![square.into()])),
+            ]))),
+            Feature {
+                geometry: None,
+                properties: BTreeMap::new(),
+            },
+        ];
+        let (header, read) = round_trip(Header::new(GeometryType::Unknown), &features);
+        assert_eq!(header.features_count, features.len() as u64);
+        assert_eq!(
+            header.envelope,
+            Some(Rect::new(
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 6., y: 6. }
+            ))
+        );
+        assert_eq!(sorted(&read), sorted(&features));
+
+        // without an index, features keep their order
+        let mut header = Header::new(GeometryType::Unknown);
+        header.index_node_size = 0;
+        let (_, read) = round_trip(header, &features);
+        assert_eq!(read, features);
+    }
+
+    #[test]
+    fn geometries_without_a_flatgeobuf_type() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 2. });
+        let triangle = Triangle(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 1., y: 1. },
+        );
+        let mut header = Header::new(GeometryType::Unknown);
+        header.index_node_size = 0;
+        let (_, read) = round_trip(
+            header,
+            &[
+                feature(line.into()),
+                feature(rect.into()),
+                feature(triangle.into()),
+            ],
+        );
+        assert_eq!(
+            read,
+            vec![
+                feature(line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()),
+                feature(Polygon::from(rect).into()),
+                feature(Polygon::from(triangle).into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn header_and_properties() {
+        let mut header = Header::new(GeometryType::Point);
+        header.name = Some("places".to_owned());
+        header.crs = Some(Crs::epsg(4326));
+        header.description = Some("Places of interest".to_owned());
+        let columns = vec![
+            ("byte", Value::Byte(-1)),
+            ("ubyte", Value::UByte(255)),
+            ("bool", Value::Bool(true)),
+            ("short", Value::Short(-300)),
+            ("ushort", Value::UShort(60000)),
+            ("int", Value::Int(-70000)),
+            ("uint", Value::UInt(4_000_000_000)),
+            ("long", Value::Long(-5_000_000_000)),
+            ("ulong", Value::ULong(u64::MAX)),
+            ("float", Value::Float(1.5)),
+            ("double", Value::Double(-2.25)),
+            ("string", Value::String("Zürich".to_owned())),
+            ("json", Value::Json(r#"{"a":1}"#.to_owned())),
+            (
+                "datetime",
+                Value::DateTime("2020-01-01T00:00:00Z".to_owned()),
+            ),
+            ("binary", Value::Binary(vec![0, 1, 2])),
+        ];
+        for (name, value) in &columns {
+            header.columns.push(Column::new(name, value.column_type()));
+        }
+        header.columns[0].nullable = false;
+        header.columns[0].title = Some("A byte".to_owned());
+
+        let all = Feature {
+            geometry: Some(point!(x: 8.5, y: 47.4).into()),
+            properties: columns
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+        };
+        let mut some = feature(point!(x: 1., y: 1.).into());
+        some.properties.insert("int".to_owned(), Value::Int(3));
+        let (read_header, read) = round_trip(header.clone(), &[all.clone(), some.clone()]);
+
+        assert_eq!(read_header.name, header.name);
+        assert_eq!(read_header.crs, header.crs);
+        assert_eq!(read_header.description, header.description);
+        assert_eq!(read_header.columns, header.columns);
+        assert_eq!(read_header.index_node_size, 16);
+        assert_eq!(sorted(&read), sorted(&[all, some]));
+    }
+
+    #[test]
+    fn invalid_features() {
+        let mut header = Header::new(GeometryType::Point);
+        header.columns.push(Column::new("name", ColumnType::String));
+        let mut writer = Writer::new(header);
+        assert!(matches!(
+            writer.add_feature(&feature(
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()
+            )),
+            Err(FgbError::MismatchedGeometryType {
+                expected: GeometryType::Point,
+                found: GeometryType::LineString
+            })
+        ));
+        let mut unknown = feature(point!(x: 0., y: 0.).into());
+        unknown.properties.insert("id".to_owned(), Value::Int(1));
+        assert!(matches!(
+            writer.add_feature(&unknown),
+            Err(FgbError::UnknownColumn(_))
+        ));
+        let mut mismatched = feature(point!(x: 0., y: 0.).into());
+        mismatched
+            .properties
+            .insert("name".to_owned(), Value::Int(1));
+        assert!(matches!(
+            writer.add_feature(&mismatched),
+            Err(FgbError::MismatchedProperty(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_files() {
+        assert!(matches!(
+            Reader::new(&b"not a flatgeobuf file"[..]),
+            Err(FgbError::NotFlatGeobuf)
+        ));
+        let mut writer = Writer::new(Header::new(GeometryType::Point));
+        writer
+            .add_feature(&feature(point!(x: 0., y: 0.).into()))
+            .unwrap();
+        let mut fgb = vec![];
+        writer.write(&mut fgb).unwrap();
+        for len in 0..fgb.len() {
+            let result = Reader::new(&fgb[..len])
+                .and_then(|mut reader| reader.features::<f64>().collect::<Result<Vec<_>, _>>());
+            assert!(result.is_err());
+        }
+        // corrupting any byte may fail, but doesn't panic
+        for i in 0..fgb.len() {
+            let mut corrupt = fgb.clone();
+            corrupt[i] ^= 0xFF;
+            if let Ok(mut reader) = Reader::new(&corrupt[..]) {
+                let _ = reader.features::<f64>().collect::<Vec<_>>();
+            }
+        }
+    }
+
+    #[test]
+    fn bbox_search() {
+        let mut features = vec![];
+        for x in 0..30 {
+            for y in 0..30 {
+                let mut feature = feature(point!(x: f64::from(x), y: f64::from(y)).into());
+                feature
+                    .properties
+                    .insert("id".to_owned(), Value::Int(x * 100 + y));
+                features.push(feature);
+            }
+        }
+        features.push(Feature {
+            geometry: None,
+            properties: BTreeMap::new(),
+        });
+        let mut header = Header::new(GeometryType::Point);
+        header.columns.push(Column::new("id", ColumnType::Int));
+        for &node_size in &[2, 16] {
+            header.index_node_size = node_size;
+            let mut writer = Writer::new(header.clone());
+            for feature in &features {
+                writer.add_feature(feature).unwrap();
+            }
+            let mut fgb = vec![];
+            writer.write(&mut fgb).unwrap();
+
+            let bbox = Rect::new(Coordinate { x: 2.5, y: 10. }, Coordinate { x: 5., y: 12.5 });
+            let mut reader = Reader::with_bbox(&fgb[..], bbox).unwrap();
+            let mut ids = reader
+                .features::<f64>()
+                .map(|f| f.unwrap().properties["id"].clone())
+                .collect::<Vec<_>>();
+            ids.sort_by_key(|id| format!("{:?}", id));
+            let expected = [3, 4, 5]
+                .iter()
+                .flat_map(|x| (10..=12).map(move |y| Value::Int(x * 100 + y)))
+                .collect::<Vec<_>>();
+            assert_eq!(ids, expected);
+
+            let nowhere = Rect::new(Coordinate { x: 50., y: 50. }, Coordinate { x: 60., y: 60. });
+            let mut reader = Reader::with_bbox(&fgb[..], nowhere).unwrap();
+            assert!(reader.next_feature::<f64>().unwrap().is_none());
+        }
+
+        header.index_node_size = 0;
+        let mut writer = Writer::new(header);
+        writer.add_feature(&features[0]).unwrap();
+        let mut fgb = vec![];
+        writer.write(&mut fgb).unwrap();
+        let bbox = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert!(matches!(
+            Reader::with_bbox(&fgb[..], bbox),
+            Err(FgbError::NoIndex)
+        ));
+    }
+
+    #[test]
+    fn hilbert_curve() {
+        // values from the reference implementation
+        assert_eq!(hilbert(0, 0), 0);
+        assert_eq!(hilbert(1, 0), 1);
+        assert_eq!(hilbert(1, 1), 2);
+        assert_eq!(hilbert(0, 1), 3);
+        assert_eq!(hilbert(0xFFFF, 0), 0xFFFF_FFFF);
+        assert_eq!(hilbert(0, 0xFFFF), 0x5555_5555);
+        assert_eq!(hilbert(0xFFFF, 0xFFFF), 0xAAAA_AAAA);
+        assert_eq!(hilbert(12345, 54321), 0x5CB0_0A42);
+    }
+
+    #[test]
+    fn level_bounds_of_the_index() {
+        assert_eq!(level_bounds(1, 16), Some(vec![1..2, 0..1]));
+        assert_eq!(level_bounds(20, 16), Some(vec![3..23, 1..3, 0..1]));
+        assert_eq!(level_bounds(u64::MAX, 16), None);
+    }
+}
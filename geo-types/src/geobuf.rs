@@ -0,0 +1,906 @@
+//! Reading and writing geometries as [Geobuf](https://github.com/mapbox/geobuf), a compact
+//! protocol buffers encoding of GeoJSON.
+//!
+//! With the `geobuf` feature enabled, [`Geometry`](../enum.Geometry.html) and
+//! [`GeometryCollection`](../struct.GeometryCollection.html) can be converted to and from
+//! Geobuf `Data` messages holding a geometry, as written and read by Mapbox's `geobuf`
+//! library, without depending on a protocol buffers crate.
+//!
+//! Coordinates are rounded to a number of decimal places (the precision, 6 by default), and
+//! stored as the differences between consecutive positions, as `sint64`s. They are converted to
+//! and from `f64`s using `NumCast`. As in GeoJSON, any dimensions after the first two are
+//! ignored when reading, a `Line` is written as a `LineString`, and a `Rect` or `Triangle` as a
+//! `Polygon`. Features and their properties aren't supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{point, Geometry, GeometryCollection};
+//!
+//! let gc = GeometryCollection(vec![Geometry::Point(point!(x: 1.5, y: 2.))]);
+//! let geobuf = gc.to_geobuf().unwrap();
+//!
+//! assert_eq!(GeometryCollection::from_geobuf(&geobuf), Ok(gc));
+//! ```
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
+};
+use std::error::Error;
+use std::fmt;
+
+const POINT: u64 = 0;
+const MULTI_POINT: u64 = 1;
+const LINE_STRING: u64 = 2;
+const MULTI_LINE_STRING: u64 = 3;
+const POLYGON: u64 = 4;
+const MULTI_POLYGON: u64 = 5;
+const GEOMETRY_COLLECTION: u64 = 6;
+
+const VARINT: u8 = 0;
+const FIXED64: u8 = 1;
+const LENGTH_DELIMITED: u8 = 2;
+const FIXED32: u8 = 5;
+
+/// The precision Geobuf uses when a `Data` message doesn't specify one.
+pub const DEFAULT_PRECISION: u32 = 6;
+/// The most decimal places coordinates can be rounded to.
+pub const MAX_PRECISION: u32 = 12;
+
+/// The error returned when reading or writing Geobuf fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeobufError {
+    /// The input ended in the middle of a message.
+    UnexpectedEnd,
+    /// An unknown protocol buffers wire type was found at the given byte offset.
+    InvalidWireType { wire_type: u8, position: usize },
+    /// A number starting at the given byte offset is too large to read.
+    Overflow(usize),
+    /// The `Data` message holds a feature or feature collection, or nothing, rather than a
+    /// geometry.
+    NotAGeometry,
+    /// The geometry type is unknown.
+    UnsupportedGeometryType(u64),
+    /// The number of dimensions is less than 2.
+    UnsupportedDimensions(u64),
+    /// The precision is more than [`MAX_PRECISION`](constant.MAX_PRECISION.html).
+    InvalidPrecision(u64),
+    /// The coordinates don't match the geometry's type and ring or part lengths.
+    InvalidLengths,
+    /// A valid geometry was read, but it has a different type than the one requested.
+    MismatchedType {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A coordinate value can't be written, or can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+}
+
+impl fmt::Display for GeobufError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeobufError::UnexpectedEnd => write!(f, "Unexpected end of Geobuf"),
+            GeobufError::InvalidWireType {
+                wire_type,
+                position,
+            } => write!(
+                f,
+                "Invalid wire type {} at position {}",
+                wire_type, position
+            ),
+            GeobufError::Overflow(position) => {
+                write!(f, "Number at position {} is too large", position)
+            }
+            GeobufError::NotAGeometry => write!(f, "Geobuf data doesn't hold a geometry"),
+            GeobufError::UnsupportedGeometryType(geometry_type) => {
+                write!(f, "Unsupported geometry type {}", geometry_type)
+            }
+            GeobufError::UnsupportedDimensions(dimensions) => {
+                write!(f, "Unsupported number of dimensions {}", dimensions)
+            }
+            GeobufError::InvalidPrecision(precision) => {
+                write!(f, "Precision {} is more than {}", precision, MAX_PRECISION)
+            }
+            GeobufError::InvalidLengths => {
+                write!(f, "Coordinates don't match the geometry's lengths")
+            }
+            GeobufError::MismatchedType { expected, found } => {
+                write!(f, "Expected a {}, but found a {}", expected, found)
+            }
+            GeobufError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+        }
+    }
+}
+
+impl Error for GeobufError {}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Write this geometry as a Geobuf `Data` message, with coordinates rounded to 6 decimal
+    /// places.
+    ///
+    /// # Errors
+    ///
+    /// See [`to_geobuf_with`](#method.to_geobuf_with).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn to_geobuf(&self) -> Result<Vec<u8>, GeobufError> {
+        self.to_geobuf_with(DEFAULT_PRECISION)
+    }
+
+    /// Write this geometry as a Geobuf `Data` message, with coordinates rounded to `precision`
+    /// decimal places.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the precision is more than
+    /// [`MAX_PRECISION`](geobuf/constant.MAX_PRECISION.html), or if a coordinate isn't finite,
+    /// or is too large to write at that precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// let geometry = Geometry::Point(point!(x: 1.25, y: -0.5));
+    /// let geobuf = geometry.to_geobuf_with(1).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Geometry::from_geobuf(&geobuf),
+    ///     Ok(Geometry::Point(point!(x: 1.3, y: -0.5)))
+    /// );
+    /// ```
+    pub fn to_geobuf_with(&self, precision: u32) -> Result<Vec<u8>, GeobufError> {
+        write_data(precision, |factor| write_geometry(self, factor))
+    }
+
+    /// Read a geometry from a Geobuf `Data` message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// // a Point with the coordinates [1500000, 2000000], at the default precision
+    /// let geobuf = [
+    ///     0x32, 0x0c, 0x08, 0x00, 0x1a, 0x08, 0xc0, 0x8d, 0xb7, 0x01, 0x80, 0x92, 0xf4, 0x01,
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Geometry::from_geobuf(&geobuf),
+    ///     Ok(Geometry::Point(point!(x: 1.5, y: 2.)))
+    /// );
+    /// ```
+    pub fn from_geobuf(bytes: &[u8]) -> Result<Geometry<T>, GeobufError> {
+        let mut dimensions = 2;
+        let mut precision = u64::from(DEFAULT_PRECISION);
+        let mut geometry = None;
+        let mut reader = Reader {
+            bytes,
+            position: 0,
+            end: bytes.len(),
+        };
+        // The dimensions and precision may follow the geometry, so read them all first
+        while let Some((field, wire_type)) = reader.key()? {
+            match (field, wire_type) {
+                (2, VARINT) => dimensions = reader.varint()?,
+                (3, VARINT) => precision = reader.varint()?,
+                (4, LENGTH_DELIMITED) | (5, LENGTH_DELIMITED) => {
+                    reader.message()?;
+                    geometry = None;
+                }
+                (6, LENGTH_DELIMITED) => geometry = Some(reader.message()?),
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        if dimensions < 2 {
+            return Err(GeobufError::UnsupportedDimensions(dimensions));
+        }
+        if precision > u64::from(MAX_PRECISION) {
+            return Err(GeobufError::InvalidPrecision(precision));
+        }
+        let decoder = Decoder {
+            dimensions: dimensions as usize,
+            factor: 10f64.powi(precision as i32),
+        };
+        decoder.geometry(geometry.ok_or(GeobufError::NotAGeometry)?)
+    }
+}
+
+impl<T: CoordinateType> GeometryCollection<T> {
+    /// Write this collection as a Geobuf `Data` message holding a `GeometryCollection`, with
+    /// coordinates rounded to 6 decimal places.
+    ///
+    /// # Errors
+    ///
+    /// See [`Geometry::to_geobuf_with`](enum.Geometry.html#method.to_geobuf_with).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn to_geobuf(&self) -> Result<Vec<u8>, GeobufError> {
+        self.to_geobuf_with(DEFAULT_PRECISION)
+    }
+
+    /// Write this collection as a Geobuf `Data` message holding a `GeometryCollection`, with
+    /// coordinates rounded to `precision` decimal places.
+    ///
+    /// # Errors
+    ///
+    /// See [`Geometry::to_geobuf_with`](enum.Geometry.html#method.to_geobuf_with).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn to_geobuf_with(&self, precision: u32) -> Result<Vec<u8>, GeobufError> {
+        write_data(precision, |factor| write_geometry_collection(self, factor))
+    }
+
+    /// Read a collection from a Geobuf `Data` message holding a `GeometryCollection`.
+    pub fn from_geobuf(bytes: &[u8]) -> Result<GeometryCollection<T>, GeobufError> {
+        match Geometry::from_geobuf(bytes)? {
+            Geometry::GeometryCollection(gc) => Ok(gc),
+            other => Err(GeobufError::MismatchedType {
+                expected: "GeometryCollection",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn varint(mut value: u64, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn key(field: u64, wire_type: u8, bytes: &mut Vec<u8>) {
+    varint((field << 3) | u64::from(wire_type), bytes)
+}
+
+fn message(field: u64, message: &[u8], bytes: &mut Vec<u8>) {
+    key(field, LENGTH_DELIMITED, bytes);
+    varint(message.len() as u64, bytes);
+    bytes.extend_from_slice(message);
+}
+
+fn packed<I: IntoIterator<Item = u64>>(field: u64, values: I, bytes: &mut Vec<u8>) {
+    let mut packed = vec![];
+    for value in values {
+        varint(value, &mut packed);
+    }
+    message(field, &packed, bytes);
+}
+
+/// Write a `Data` message holding the `Geometry` message written by `write`
+fn write_data<W>(precision: u32, write: W) -> Result<Vec<u8>, GeobufError>
+where
+    W: FnOnce(f64) -> Result<Vec<u8>, GeobufError>,
+{
+    if precision > MAX_PRECISION {
+        return Err(GeobufError::InvalidPrecision(precision.into()));
+    }
+    let mut bytes = vec![];
+    if precision != DEFAULT_PRECISION {
+        key(3, VARINT, &mut bytes);
+        varint(precision.into(), &mut bytes);
+    }
+    message(6, &write(10f64.powi(precision as i32))?, &mut bytes);
+    Ok(bytes)
+}
+
+fn scale<T: CoordinateType>(coord: &Coordinate<T>, factor: f64) -> Result<[i64; 2], GeobufError> {
+    let scale = |ordinate: T| -> Result<i64, GeobufError> {
+        let value = ordinate
+            .to_f64()
+            .expect("coordinate can't be converted to f64");
+        let rounded = (value * factor).round();
+        // leaving room for the differences between values; NaNs aren't finite either
+        if !rounded.is_finite() || rounded.abs() >= (1u64 << 62) as f64 {
+            return Err(GeobufError::InvalidCoordinate(value));
+        }
+        Ok(rounded as i64)
+    };
+    Ok([scale(coord.x)?, scale(coord.y)?])
+}
+
+/// The pieces of a `Geometry` message being written
+struct Parts {
+    factor: f64,
+    lengths: Option<Vec<u64>>,
+    coords: Vec<i64>,
+}
+
+impl Parts {
+    /// Append `coords` as differences from the previous one, starting from the origin,
+    /// returning how many were appended. The closing coordinate of a ring is left out.
+    fn line<T: CoordinateType>(
+        &mut self,
+        coords: &[Coordinate<T>],
+        closed: bool,
+    ) -> Result<u64, GeobufError> {
+        let coords = match (closed, coords.split_last()) {
+            (true, Some((_, open))) => open,
+            _ => coords,
+        };
+        let mut last = [0, 0];
+        for coord in coords {
+            let scaled = scale(coord, self.factor)?;
+            self.coords.push(scaled[0] - last[0]);
+            self.coords.push(scaled[1] - last[1]);
+            last = scaled;
+        }
+        Ok(coords.len() as u64)
+    }
+
+    /// Append the rings of `polygon`, returning their lengths
+    fn rings<T: CoordinateType>(&mut self, polygon: &Polygon<T>) -> Result<Vec<u64>, GeobufError> {
+        if polygon.exterior().0.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut lengths = vec![self.line(&polygon.exterior().0, true)?];
+        for interior in polygon.interiors() {
+            lengths.push(self.line(&interior.0, true)?);
+        }
+        Ok(lengths)
+    }
+
+    fn polygon<T: CoordinateType>(&mut self, polygon: &Polygon<T>) -> Result<(), GeobufError> {
+        let lengths = self.rings(polygon)?;
+        // A single ring needs no lengths
+        if lengths.len() != 1 {
+            self.lengths = Some(lengths);
+        }
+        Ok(())
+    }
+
+    /// Write a `Geometry` message of the given type, with these parts and `geometries`
+    fn finish(self, geometry_type: u64, geometries: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = vec![];
+        key(1, VARINT, &mut bytes);
+        varint(geometry_type, &mut bytes);
+        if let Some(lengths) = self.lengths {
+            packed(2, lengths, &mut bytes);
+        }
+        if !self.coords.is_empty() {
+            packed(3, self.coords.into_iter().map(zigzag), &mut bytes);
+        }
+        for geometry in geometries {
+            message(4, geometry, &mut bytes);
+        }
+        bytes
+    }
+}
+
+fn write_geometry<T: CoordinateType>(
+    geometry: &Geometry<T>,
+    factor: f64,
+) -> Result<Vec<u8>, GeobufError> {
+    let mut parts = Parts {
+        factor,
+        lengths: None,
+        coords: vec![],
+    };
+    let geometry_type = match geometry {
+        Geometry::Point(p) => {
+            parts.coords.extend(&scale(&p.0, factor)?);
+            POINT
+        }
+        Geometry::Line(l) => {
+            parts.line(&[l.start, l.end], false)?;
+            LINE_STRING
+        }
+        Geometry::LineString(ls) => {
+            parts.line(&ls.0, false)?;
+            LINE_STRING
+        }
+        Geometry::Polygon(p) => {
+            parts.polygon(p)?;
+            POLYGON
+        }
+        Geometry::MultiPoint(mp) => {
+            let coords: Vec<_> = mp.0.iter().map(|p| p.0).collect();
+            parts.line(&coords, false)?;
+            MULTI_POINT
+        }
+        Geometry::MultiLineString(mls) => {
+            let mut lengths = vec![];
+            for ls in &mls.0 {
+                lengths.push(parts.line(&ls.0, false)?);
+            }
+            // A single line string needs no lengths
+            if lengths.len() != 1 {
+                parts.lengths = Some(lengths);
+            }
+            MULTI_LINE_STRING
+        }
+        Geometry::MultiPolygon(mp) => {
+            let mut lengths = vec![mp.0.len() as u64];
+            for p in &mp.0 {
+                let rings = parts.rings(p)?;
+                lengths.push(rings.len() as u64);
+                lengths.extend(rings);
+            }
+            // A single polygon with a single ring needs no lengths
+            if lengths.get(..2) != Some(&[1, 1][..]) {
+                parts.lengths = Some(lengths);
+            }
+            MULTI_POLYGON
+        }
+        Geometry::Rect(r) => {
+            parts.polygon(&Polygon::from(*r))?;
+            POLYGON
+        }
+        Geometry::Triangle(t) => {
+            parts.polygon(&Polygon::from(*t))?;
+            POLYGON
+        }
+        Geometry::GeometryCollection(gc) => return write_geometry_collection(gc, factor),
+    };
+    Ok(parts.finish(geometry_type, &[]))
+}
+
+fn write_geometry_collection<T: CoordinateType>(
+    gc: &GeometryCollection<T>,
+    factor: f64,
+) -> Result<Vec<u8>, GeobufError> {
+    let geometries =
+        gc.0.iter()
+            .map(|g| write_geometry(g, factor))
+            .collect::<Result<Vec<_>, _>>()?;
+    let parts = Parts {
+        factor,
+        lengths: None,
+        coords: vec![],
+    };
+    Ok(parts.finish(GEOMETRY_COLLECTION, &geometries))
+}
+
+/// A protocol buffers message, from `position` up to `end` of `bytes`
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    end: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn varint(&mut self) -> Result<u64, GeobufError> {
+        let start = self.position;
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            if self.position == self.end {
+                return Err(GeobufError::UnexpectedEnd);
+            }
+            let byte = self.bytes[self.position];
+            self.position += 1;
+            // only 1 more bit fits once 63 have been read
+            if shift > 63 || (shift == 63 && byte & 0x7e != 0) {
+                return Err(GeobufError::Overflow(start));
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Read the next field number and wire type, if there are any more fields
+    fn key(&mut self) -> Result<Option<(u64, u8)>, GeobufError> {
+        if self.position == self.end {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        Ok(Some((key >> 3, (key & 0x07) as u8)))
+    }
+
+    /// Skip `len` bytes, returning the position they started at
+    fn take(&mut self, len: u64) -> Result<usize, GeobufError> {
+        let start = self.position;
+        if len > (self.end - start) as u64 {
+            return Err(GeobufError::UnexpectedEnd);
+        }
+        self.position += len as usize;
+        Ok(start)
+    }
+
+    /// Read a length-delimited field
+    fn message(&mut self) -> Result<Reader<'a>, GeobufError> {
+        let len = self.varint()?;
+        let position = self.take(len)?;
+        Ok(Reader {
+            bytes: self.bytes,
+            position,
+            end: self.position,
+        })
+    }
+
+    /// Read a packed repeated varint field, or one unpacked element of it
+    fn varints(&mut self, wire_type: u8, values: &mut Vec<u64>) -> Result<(), GeobufError> {
+        if wire_type == VARINT {
+            values.push(self.varint()?);
+            return Ok(());
+        }
+        let mut packed = self.message()?;
+        while packed.position < packed.end {
+            values.push(packed.varint()?);
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, wire_type: u8) -> Result<(), GeobufError> {
+        match wire_type {
+            VARINT => self.varint().map(drop),
+            FIXED64 => self.take(8).map(drop),
+            LENGTH_DELIMITED => self.message().map(drop),
+            FIXED32 => self.take(4).map(drop),
+            _ => Err(GeobufError::InvalidWireType {
+                wire_type,
+                position: self.position - 1,
+            }),
+        }
+    }
+}
+
+struct Decoder {
+    dimensions: usize,
+    factor: f64,
+}
+
+/// The coordinates of a `Geometry` message being read, as differences from the previous one in
+/// each line
+struct Coords<'a> {
+    values: &'a [i64],
+    dimensions: usize,
+    factor: f64,
+}
+
+impl<'a> Coords<'a> {
+    fn coordinate<T: CoordinateType>(
+        &self,
+        scaled: [i64; 2],
+    ) -> Result<Coordinate<T>, GeobufError> {
+        let ordinate = |scaled: i64| {
+            let value = scaled as f64 / self.factor;
+            T::from(value).ok_or(GeobufError::InvalidCoordinate(value))
+        };
+        Ok(Coordinate {
+            x: ordinate(scaled[0])?,
+            y: ordinate(scaled[1])?,
+        })
+    }
+
+    /// Read `len` coordinates, or all of those left, repeating the first if the line is closed
+    fn line<T: CoordinateType>(
+        &mut self,
+        len: Option<u64>,
+        closed: bool,
+    ) -> Result<LineString<T>, GeobufError> {
+        let len = match len {
+            Some(len) => len as usize,
+            None => self.values.len() / self.dimensions,
+        };
+        let end = len
+            .checked_mul(self.dimensions)
+            .filter(|&end| end <= self.values.len())
+            .ok_or(GeobufError::InvalidLengths)?;
+        let (line, rest) = self.values.split_at(end);
+        self.values = rest;
+
+        let mut coords = Vec::with_capacity(len + 1);
+        let mut last = [0i64; 2];
+        for position in line.chunks(self.dimensions) {
+            for (last, delta) in last.iter_mut().zip(position) {
+                *last = last
+                    .checked_add(*delta)
+                    .ok_or(GeobufError::InvalidLengths)?;
+            }
+            coords.push(self.coordinate(last)?);
+        }
+        if closed && !coords.is_empty() {
+            coords.push(coords[0]);
+        }
+        Ok(LineString(coords))
+    }
+
+    /// Read lines with the given lengths, or a single line if there are none
+    fn lines<T: CoordinateType>(
+        &mut self,
+        lengths: Option<&[u64]>,
+        closed: bool,
+    ) -> Result<Vec<LineString<T>>, GeobufError> {
+        match lengths {
+            Some(lengths) => lengths
+                .iter()
+                .map(|&len| self.line(Some(len), closed))
+                .collect(),
+            None => Ok(vec![self.line(None, closed)?]),
+        }
+    }
+
+    fn polygon<T: CoordinateType>(
+        &mut self,
+        lengths: Option<&[u64]>,
+    ) -> Result<Polygon<T>, GeobufError> {
+        let mut rings = self.lines(lengths, true)?.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    /// Read polygons with lengths in the order written for a `MultiPolygon`: the number of
+    /// polygons, then for each, the number of rings followed by their lengths
+    fn polygons<T: CoordinateType>(
+        &mut self,
+        lengths: &[u64],
+    ) -> Result<Vec<Polygon<T>>, GeobufError> {
+        let (&count, mut lengths) = lengths.split_first().ok_or(GeobufError::InvalidLengths)?;
+        let mut polygons = Vec::with_capacity((count as usize).min(lengths.len()));
+        for _ in 0..count {
+            let (&rings, rest) = lengths.split_first().ok_or(GeobufError::InvalidLengths)?;
+            if rings > rest.len() as u64 {
+                return Err(GeobufError::InvalidLengths);
+            }
+            let (rings, rest) = rest.split_at(rings as usize);
+            polygons.push(self.polygon(Some(rings))?);
+            lengths = rest;
+        }
+        Ok(polygons)
+    }
+}
+
+impl Decoder {
+    fn geometry<T: CoordinateType>(&self, mut reader: Reader) -> Result<Geometry<T>, GeobufError> {
+        let mut geometry_type = POINT;
+        let mut lengths = None;
+        let mut values = vec![];
+        let mut geometries = vec![];
+        while let Some((field, wire_type)) = reader.key()? {
+            match (field, wire_type) {
+                (1, VARINT) => geometry_type = reader.varint()?,
+                (2, VARINT) | (2, LENGTH_DELIMITED) => {
+                    reader.varints(wire_type, lengths.get_or_insert_with(Vec::new))?
+                }
+                (3, VARINT) | (3, LENGTH_DELIMITED) => reader.varints(wire_type, &mut values)?,
+                (4, LENGTH_DELIMITED) => geometries.push(reader.message()?),
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        let values: Vec<i64> = values.into_iter().map(unzigzag).collect();
+        let mut coords = Coords {
+            values: &values,
+            dimensions: self.dimensions,
+            factor: self.factor,
+        };
+        let lengths = lengths.as_deref();
+
+        let geometry = match geometry_type {
+            POINT => {
+                if values.len() != self.dimensions {
+                    return Err(GeobufError::InvalidLengths);
+                }
+                coords.values = &[];
+                Geometry::Point(Point(coords.coordinate([values[0], values[1]])?))
+            }
+            MULTI_POINT => Geometry::MultiPoint(MultiPoint(
+                coords.line(None, false)?.0.into_iter().map(Point).collect(),
+            )),
+            LINE_STRING => Geometry::LineString(coords.line(None, false)?),
+            MULTI_LINE_STRING => {
+                Geometry::MultiLineString(MultiLineString(coords.lines(lengths, false)?))
+            }
+            POLYGON => Geometry::Polygon(coords.polygon(lengths)?),
+            MULTI_POLYGON => Geometry::MultiPolygon(MultiPolygon(match lengths {
+                Some(lengths) => coords.polygons(lengths)?,
+                None => vec![coords.polygon(None)?],
+            })),
+            GEOMETRY_COLLECTION => Geometry::GeometryCollection(GeometryCollection(
+                geometries
+                    .into_iter()
+                    .map(|g| self.geometry(g))
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => return Err(GeobufError::UnsupportedGeometryType(geometry_type)),
+        };
+        if !coords.values.is_empty() {
+            return Err(GeobufError::InvalidLengths);
+        }
+        Ok(geometry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Rect};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn known_geobuf() {
+        // {"type":"Point","coordinates":[1.5,2]}, at the default precision
+        let point = Geometry::Point(point!(x: 1.5, y: 2.));
+        assert_eq!(point.to_geobuf(), Ok(hex("320c08001a08c08db7018092f401")));
+
+        // a LineString followed by the precision 1, 3 dimensions, and an unknown field
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("320b08021a071e14c8010201001801100378")),
+            Err(GeobufError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Geometry::from_geobuf(&hex("320b08021a071e14c801020100180110037805")),
+            Ok(Geometry::LineString(line_string![
+                (x: 1.5, y: 1.),
+                (x: 1.6, y: 0.9),
+            ]))
+        );
+
+        // a Polygon with one ring, whose closing coordinate isn't written
+        let polygon: Geometry<i32> = Geometry::Polygon(polygon![
+            (x: 0, y: 0),
+            (x: 1, y: 0),
+            (x: 1, y: 1),
+        ]);
+        assert_eq!(
+            polygon.to_geobuf_with(0),
+            Ok(hex("1800320a08041a06000002000002"))
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let gc: Geometry<f64> = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 1.5, y: -2.)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            Geometry::LineString(LineString(vec![])),
+            Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            )),
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+            Geometry::Polygon(Polygon::new(LineString(vec![]), vec![])),
+            Geometry::MultiPoint(MultiPoint(vec![
+                point!(x: 0., y: 1.),
+                point!(x: -2.5, y: 3.),
+            ])),
+            Geometry::MultiPoint(MultiPoint(vec![])),
+            Geometry::MultiLineString(MultiLineString(vec![LineString(vec![])])),
+            Geometry::MultiLineString(MultiLineString(vec![])),
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 5., y: 5.), (x: 6., y: 7.5)],
+            ])),
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 1., y: 1.)
+            ]])),
+            Geometry::MultiPolygon(MultiPolygon(vec![
+                polygon!(
+                    exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                    interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+                ),
+                Polygon::new(LineString(vec![]), vec![]),
+                polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.)],
+            ])),
+            Geometry::MultiPolygon(MultiPolygon(vec![])),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(
+                point!(x: 3., y: 4.),
+            )])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]));
+        for &precision in &[1, 6, MAX_PRECISION] {
+            let geobuf = gc.to_geobuf_with(precision).unwrap();
+            assert_eq!(Geometry::from_geobuf(&geobuf), Ok(gc.clone()));
+        }
+
+        let gc = GeometryCollection(vec![
+            Geometry::Line(Line::new((0, 0), (1, 2))),
+            Geometry::Rect(Rect::new((0, 0), (1, 2))),
+        ]);
+        assert_eq!(
+            GeometryCollection::from_geobuf(&gc.to_geobuf().unwrap()),
+            Ok(GeometryCollection(vec![
+                Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 2)]),
+                Geometry::Polygon(Polygon::from(Rect::new((0, 0), (1, 2)))),
+            ]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let point = Geometry::Point(point!(x: 1.5, y: 2.));
+        assert_eq!(
+            point.to_geobuf_with(13),
+            Err(GeobufError::InvalidPrecision(13))
+        );
+        assert_eq!(
+            Geometry::Point(point!(x: f64::INFINITY, y: 0.)).to_geobuf(),
+            Err(GeobufError::InvalidCoordinate(f64::INFINITY))
+        );
+        assert_eq!(
+            GeometryCollection::<f64>::from_geobuf(&point.to_geobuf().unwrap()),
+            Err(GeobufError::MismatchedType {
+                expected: "GeometryCollection",
+                found: "Point"
+            })
+        );
+
+        let geobuf = point.to_geobuf().unwrap();
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&geobuf[..geobuf.len() - 1]),
+            Err(GeobufError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Geometry::<u8>::from_geobuf(&geobuf),
+            Ok(Geometry::Point(point!(x: 1, y: 2)))
+        );
+        assert_eq!(
+            Geometry::<u8>::from_geobuf(&Geometry::Point(point!(x: -1, y: 0)).to_geobuf().unwrap()),
+            Err(GeobufError::InvalidCoordinate(-1.))
+        );
+        // feature data, and no data
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("2a00")),
+            Err(GeobufError::NotAGeometry)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&[]),
+            Err(GeobufError::NotAGeometry)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("10013200")),
+            Err(GeobufError::UnsupportedDimensions(1))
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("18203200")),
+            Err(GeobufError::InvalidPrecision(32))
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("32020807")),
+            Err(GeobufError::UnsupportedGeometryType(7))
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("0f")),
+            Err(GeobufError::InvalidWireType {
+                wire_type: 7,
+                position: 0
+            })
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("10ffffffffffffffffff7f")),
+            Err(GeobufError::Overflow(1))
+        );
+        // a Point with 3 values, and a MultiLineString whose lengths are too long
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("320708001a03020202")),
+            Err(GeobufError::InvalidLengths)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_geobuf(&hex("320a0803120202031a020202")),
+            Err(GeobufError::InvalidLengths)
+        );
+    }
+}
@@ -0,0 +1,444 @@
+//! Encoding points as [geohashes](https://en.wikipedia.org/wiki/Geohash), and decoding
+//! geohashes to the cells they name.
+//!
+//! With the `geohash` feature enabled, [`Point::geohash`](../struct.Point.html#method.geohash)
+//! finds the geohash of the cell a point is in, reading its `x` as longitude and `y` as
+//! latitude, and [`Rect::from_geohash`](../struct.Rect.html#method.from_geohash) returns the
+//! bounds of a cell. [`neighbor`](fn.neighbor.html) and [`neighbors`](fn.neighbors.html) find
+//! the cells next to one.
+//!
+//! Cells are found by repeatedly halving the range of longitudes and latitudes in the
+//! coordinate type itself, without converting to `f64`, so the result is exact and the same on
+//! every platform for floating point and [fixed-point](../fixed_point/index.html) coordinates.
+//! A point on the boundary between cells is in the cell to its north or east.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::geohash::{neighbor, Direction};
+//! use geo_types::{point, Coordinate, Rect};
+//!
+//! let point = point!(x: -5.6, y: 42.6);
+//! assert_eq!(point.geohash(5), Ok("ezs42".to_string()));
+//!
+//! let cell = Rect::<f64>::from_geohash("ezs42").unwrap();
+//! assert_eq!(cell.min(), Coordinate { x: -5.625, y: 42.5830078125 });
+//! assert_eq!(cell.max(), Coordinate { x: -5.5810546875, y: 42.626953125 });
+//!
+//! assert_eq!(neighbor("ezs42", Direction::North), Ok(Some("ezs48".to_string())));
+//! ```
+use crate::{Coordinate, CoordinateType, Point, Rect};
+use std::error::Error;
+use std::fmt;
+
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest supported geohash, naming cells a few centimetres across.
+pub const MAX_PRECISION: usize = 12;
+
+/// The error returned when encoding or decoding a geohash fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeohashError {
+    /// The geohash, or the requested precision, has no characters or more than
+    /// [`MAX_PRECISION`](constant.MAX_PRECISION.html).
+    InvalidLength(usize),
+    /// A character which isn't in the geohash alphabet was found at the given position.
+    InvalidCharacter { found: char, position: usize },
+    /// The point isn't a valid longitude and latitude, or the coordinate type can't represent
+    /// the range of longitudes.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeohashError::InvalidLength(length) => write!(
+                f,
+                "Geohash length {} isn't between 1 and {}",
+                length, MAX_PRECISION
+            ),
+            GeohashError::InvalidCharacter { found, position } => write!(
+                f,
+                "Invalid geohash character {:?} at position {}",
+                found, position
+            ),
+            GeohashError::InvalidCoordinate => write!(f, "Invalid longitude or latitude"),
+        }
+    }
+}
+
+impl Error for GeohashError {}
+
+/// A direction from a geohash cell to one of its neighbors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    // The steps east and north to the neighbor
+    fn offsets(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, -1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+}
+
+// The position of a cell along each axis, among the `1 << bits` cells of that axis
+#[derive(Clone, Copy)]
+struct Cell {
+    x: u64,
+    y: u64,
+    x_bits: u32,
+    y_bits: u32,
+}
+
+impl Cell {
+    fn new(precision: usize) -> Cell {
+        let bits = 5 * precision as u32;
+        // the bits alternate between longitude and latitude, starting with longitude
+        Cell {
+            x: 0,
+            y: 0,
+            x_bits: bits - bits / 2,
+            y_bits: bits / 2,
+        }
+    }
+
+    fn decode(geohash: &str) -> Result<Cell, GeohashError> {
+        let length = geohash.chars().count();
+        if length == 0 || length > MAX_PRECISION {
+            return Err(GeohashError::InvalidLength(length));
+        }
+        let mut cell = Cell::new(length);
+        let mut bit = 0;
+        for (position, found) in geohash.chars().enumerate() {
+            let value = ALPHABET
+                .iter()
+                .position(|&c| c as char == found.to_ascii_lowercase())
+                .ok_or(GeohashError::InvalidCharacter { found, position })?;
+            for shift in (0..5).rev() {
+                let set = (value >> shift) & 1 == 1;
+                if bit % 2 == 0 {
+                    cell.x = (cell.x << 1) | set as u64;
+                } else {
+                    cell.y = (cell.y << 1) | set as u64;
+                }
+                bit += 1;
+            }
+        }
+        Ok(cell)
+    }
+
+    fn encode(&self) -> String {
+        let bits = self.x_bits + self.y_bits;
+        let mut geohash = String::with_capacity(bits as usize / 5);
+        let (mut x_shift, mut y_shift) = (self.x_bits, self.y_bits);
+        let mut value = 0;
+        for bit in 0..bits {
+            let set = if bit % 2 == 0 {
+                x_shift -= 1;
+                (self.x >> x_shift) & 1
+            } else {
+                y_shift -= 1;
+                (self.y >> y_shift) & 1
+            };
+            value = (value << 1) | set as usize;
+            if bit % 5 == 4 {
+                geohash.push(ALPHABET[value] as char);
+                value = 0;
+            }
+        }
+        geohash
+    }
+}
+
+// Narrow the range from `min` to `max` down to the cell at `index`, among `1 << bits` cells
+fn cell_range<T: CoordinateType>(index: u64, bits: u32, mut min: T, mut max: T) -> (T, T) {
+    let two = T::one() + T::one();
+    for shift in (0..bits).rev() {
+        let middle = min + (max - min) / two;
+        if (index >> shift) & 1 == 1 {
+            min = middle;
+        } else {
+            max = middle;
+        }
+    }
+    (min, max)
+}
+
+// The index of the cell holding `value`, among `1 << bits` cells from `min` to `max`
+fn cell_index<T: CoordinateType>(value: T, bits: u32, mut min: T, mut max: T) -> u64 {
+    let two = T::one() + T::one();
+    let mut index = 0;
+    for _ in 0..bits {
+        let middle = min + (max - min) / two;
+        if value >= middle {
+            index = (index << 1) | 1;
+            min = middle;
+        } else {
+            index <<= 1;
+            max = middle;
+        }
+    }
+    index
+}
+
+// The range of longitudes and latitudes, if the coordinate type can represent it
+fn world<T: CoordinateType>() -> Result<Rect<T>, GeohashError> {
+    let value = |degrees: i32| T::from(degrees).ok_or(GeohashError::InvalidCoordinate);
+    Ok(Rect::new(
+        Coordinate {
+            x: value(-180)?,
+            y: value(-90)?,
+        },
+        Coordinate {
+            x: value(180)?,
+            y: value(90)?,
+        },
+    ))
+}
+
+impl<T: CoordinateType> Point<T> {
+    /// Return the geohash, with `precision` characters, of the cell this point is in, reading
+    /// `x` as longitude and `y` as latitude.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the precision is `0` or more than
+    /// [`MAX_PRECISION`](geohash/constant.MAX_PRECISION.html), or if the point is outside the
+    /// range of longitudes and latitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::point;
+    ///
+    /// let point = point!(x: 112.5584, y: 37.8324);
+    /// assert_eq!(point.geohash(9), Ok("ww8p1r4t8".to_string()));
+    /// ```
+    pub fn geohash(&self, precision: usize) -> Result<String, GeohashError> {
+        if precision == 0 || precision > MAX_PRECISION {
+            return Err(GeohashError::InvalidLength(precision));
+        }
+        let world = world()?;
+        let (lon, lat) = self.x_y();
+        // written to also reject NaNs
+        let in_range = |value: T, min: T, max: T| value >= min && value <= max;
+        if !in_range(lon, world.min().x, world.max().x)
+            || !in_range(lat, world.min().y, world.max().y)
+        {
+            return Err(GeohashError::InvalidCoordinate);
+        }
+        let mut cell = Cell::new(precision);
+        cell.x = cell_index(lon, cell.x_bits, world.min().x, world.max().x);
+        cell.y = cell_index(lat, cell.y_bits, world.min().y, world.max().y);
+        Ok(cell.encode())
+    }
+}
+
+impl<T: CoordinateType> Rect<T> {
+    /// Return the cell named by a geohash, with longitudes as `x` and latitudes as `y`.
+    ///
+    /// Upper case letters are read as lower case ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let cell = Rect::<f64>::from_geohash("u4").unwrap();
+    /// assert_eq!(cell.min(), Coordinate { x: 0., y: 56.25 });
+    /// assert_eq!(cell.max(), Coordinate { x: 11.25, y: 61.875 });
+    /// ```
+    pub fn from_geohash(geohash: &str) -> Result<Rect<T>, GeohashError> {
+        let cell = Cell::decode(geohash)?;
+        let world = world()?;
+        let (min_x, max_x) = cell_range(cell.x, cell.x_bits, world.min().x, world.max().x);
+        let (min_y, max_y) = cell_range(cell.y, cell.y_bits, world.min().y, world.max().y);
+        Ok(Rect::new(
+            Coordinate { x: min_x, y: min_y },
+            Coordinate { x: max_x, y: max_y },
+        ))
+    }
+}
+
+/// Return the geohash of the cell next to `geohash` in `direction`, with the same precision.
+///
+/// Cells wrap around the antimeridian, but there is no cell north of the northernmost ones, or
+/// south of the southernmost ones, so `None` is returned for those.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::geohash::{neighbor, Direction};
+///
+/// assert_eq!(neighbor("dqcjq", Direction::North), Ok(Some("dqcjw".to_string())));
+/// // across the antimeridian
+/// assert_eq!(neighbor("8", Direction::West), Ok(Some("x".to_string())));
+/// assert_eq!(neighbor("z", Direction::North), Ok(None));
+/// ```
+pub fn neighbor(geohash: &str, direction: Direction) -> Result<Option<String>, GeohashError> {
+    let cell = Cell::decode(geohash)?;
+    let (east, north) = direction.offsets();
+    let y = cell.y as i64 + north;
+    if y < 0 || y >= 1 << cell.y_bits {
+        return Ok(None);
+    }
+    let x_count = 1i64 << cell.x_bits;
+    Ok(Some(
+        Cell {
+            x: (cell.x as i64 + east).rem_euclid(x_count) as u64,
+            y: y as u64,
+            ..cell
+        }
+        .encode(),
+    ))
+}
+
+/// Return the geohashes of the eight cells around `geohash`, clockwise from north, in the
+/// order of [`Direction::ALL`](enum.Direction.html#associatedconstant.ALL).
+///
+/// As for [`neighbor`](fn.neighbor.html), cells past the poles are `None`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::geohash::neighbors;
+///
+/// let around = neighbors("ezs42").unwrap();
+/// assert_eq!(around[0], Some("ezs48".to_string()));
+/// assert_eq!(around[4], Some("ezs40".to_string()));
+/// ```
+pub fn neighbors(geohash: &str) -> Result<[Option<String>; 8], GeohashError> {
+    let mut around: [Option<String>; 8] = Default::default();
+    for (slot, &direction) in around.iter_mut().zip(Direction::ALL.iter()) {
+        *slot = neighbor(geohash, direction)?;
+    }
+    Ok(around)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn round_trips() {
+        for &(lon, lat, geohash) in &[
+            (-5.6, 42.6, "ezs42"),
+            (112.5584, 37.8324, "ww8p1r4t8"),
+            (-180., -90., "000000000000"),
+            (180., 90., "zzzzzzzzzzzz"),
+            (0., 0., "s00000000000"),
+        ] {
+            let point = point!(x: lon, y: lat);
+            assert_eq!(point.geohash(geohash.len()), Ok(geohash.to_string()));
+            let cell = Rect::<f64>::from_geohash(geohash).unwrap();
+            assert!(cell.min().x <= lon && lon <= cell.max().x);
+            assert!(cell.min().y <= lat && lat <= cell.max().y);
+            let center = point!(
+                x: (cell.min().x + cell.max().x) / 2.,
+                y: (cell.min().y + cell.max().y) / 2.
+            );
+            assert_eq!(center.geohash(geohash.len()), Ok(geohash.to_string()));
+        }
+        assert_eq!(
+            Rect::<f64>::from_geohash("EZS42"),
+            Rect::<f64>::from_geohash("ezs42")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let point = point!(x: 0., y: 0.);
+        assert_eq!(point.geohash(0), Err(GeohashError::InvalidLength(0)));
+        assert_eq!(point.geohash(13), Err(GeohashError::InvalidLength(13)));
+        assert_eq!(
+            point!(x: 0., y: 90.5).geohash(5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            point!(x: f64::NAN, y: 0.).geohash(5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            point!(x: 0i8, y: 0i8).geohash(5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            Rect::<f64>::from_geohash("ezsa2"),
+            Err(GeohashError::InvalidCharacter {
+                found: 'a',
+                position: 3
+            })
+        );
+        assert_eq!(
+            Rect::<f64>::from_geohash(""),
+            Err(GeohashError::InvalidLength(0))
+        );
+    }
+
+    #[test]
+    fn neighbors_surround_the_cell() {
+        let geohash = "dqcjq";
+        let cell = Rect::<f64>::from_geohash(geohash).unwrap();
+        let around = neighbors(geohash).unwrap();
+        for (neighbor, direction) in around.iter().zip(Direction::ALL.iter()) {
+            let other = Rect::<f64>::from_geohash(neighbor.as_ref().unwrap()).unwrap();
+            let (east, north) = direction.offsets();
+            let width = cell.max().x - cell.min().x;
+            let height = cell.max().y - cell.min().y;
+            assert_eq!(other.min().x, cell.min().x + east as f64 * width);
+            assert_eq!(other.min().y, cell.min().y + north as f64 * height);
+        }
+        let at_pole = neighbors("bp").unwrap();
+        assert!(at_pole[0].is_none() && at_pole[1].is_none() && at_pole[7].is_none());
+        assert_eq!(at_pole[2], Some("br".to_string()));
+        assert_eq!(at_pole[6], Some("zz".to_string()));
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use crate::fixed_point::FixedI64F64;
+        use num_traits::NumCast;
+
+        let fixed = |value: f64| <FixedI64F64 as NumCast>::from(value).unwrap();
+        let point = Point::new(fixed(112.5584), fixed(37.8324));
+        assert_eq!(
+            point.geohash(12),
+            point!(x: 112.5584, y: 37.8324).geohash(12)
+        );
+        let cell = Rect::<FixedI64F64>::from_geohash("ww8p1r4t8").unwrap();
+        let float_cell = Rect::<f64>::from_geohash("ww8p1r4t8").unwrap();
+        assert_eq!(cell.min().x, fixed(float_cell.min().x));
+        assert_eq!(cell.max().y, fixed(float_cell.max().y));
+    }
+}
@@ -0,0 +1,1187 @@
+//! Reading and writing geometries as [GeoJSON](https://tools.ietf.org/html/rfc7946).
+//!
+//! With the `geojson` feature enabled, [`Geometry`](../enum.Geometry.html) and
+//! [`GeometryCollection`](../struct.GeometryCollection.html) can be converted to and from GeoJSON
+//! geometry objects, and [`Feature`](struct.Feature.html) and
+//! [`FeatureCollection`](struct.FeatureCollection.html) add GeoJSON features, whose properties
+//! can be any type implementing `serde`'s `Serialize` and `Deserialize`.
+//!
+//! Unlike converting through the `geojson` crate, which always uses `f64` coordinates, the
+//! coordinate type is preserved: positions are converted to and from `T` using `NumCast`, and
+//! integer positions are read exactly. Any altitude in a position is ignored, a `Line` is
+//! written as a `LineString`, and a `Rect` or `Triangle` as a `Polygon`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{point, Geometry, GeometryCollection};
+//!
+//! let gc = GeometryCollection(vec![Geometry::Point(point!(x: 1.5, y: 2.))]);
+//! let geojson = gc.to_geojson();
+//!
+//! assert_eq!(
+//!     geojson,
+//!     r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1.5,2]}]}"#
+//! );
+//! assert_eq!(GeometryCollection::from_geojson(&geojson).unwrap(), gc);
+//! ```
+use crate::stream::{BuildError, GeomEventSink, GeomEventSource, GeometryBuilder, StreamError};
+use crate::{Coordinate, CoordinateType, Geometry, GeometryCollection};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess,
+    Visitor,
+};
+use serde::Serialize;
+use serde_json::de::IoRead;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+
+/// The error returned when reading or writing GeoJSON fails.
+#[derive(Debug)]
+pub enum GeoJsonError {
+    /// The input isn't valid JSON, or couldn't be read, a member has the wrong JSON type, or
+    /// feature properties couldn't be (de)serialized.
+    Json(serde_json::Error),
+    /// A required member (e.g. `coordinates`) is missing, or has the wrong number of values.
+    InvalidMember(&'static str),
+    /// The `type` of a GeoJSON object is unknown.
+    UnsupportedType(String),
+    /// A valid GeoJSON object was read, but it has a different `type` than the one requested.
+    MismatchedType {
+        expected: &'static str,
+        found: String,
+    },
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoJsonError::Json(e) => write!(f, "{}", e),
+            GeoJsonError::InvalidMember(member) => {
+                write!(f, "Missing or invalid GeoJSON member '{}'", member)
+            }
+            GeoJsonError::UnsupportedType(found) => {
+                write!(f, "Unsupported GeoJSON type '{}'", found)
+            }
+            GeoJsonError::MismatchedType { expected, found } => {
+                write!(f, "Expected a GeoJSON {}, but found a {}", expected, found)
+            }
+            GeoJsonError::InvalidCoordinate => write!(f, "Coordinate can't be represented"),
+        }
+    }
+}
+
+impl Error for GeoJsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GeoJsonError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for GeoJsonError {
+    fn from(e: serde_json::Error) -> Self {
+        GeoJsonError::Json(e)
+    }
+}
+
+/// A GeoJSON Feature: an optional geometry, along with optional properties of type `P`, and an
+/// optional `id`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::geojson::Feature;
+/// use geo_types::{point, Geometry};
+/// use std::collections::HashMap;
+///
+/// let mut properties = HashMap::new();
+/// properties.insert("name".to_string(), "Null Island".to_string());
+/// let feature = Feature {
+///     id: None,
+///     geometry: Some(Geometry::Point(point!(x: 0, y: 0))),
+///     properties: Some(properties),
+/// };
+/// let geojson = feature.to_geojson().unwrap();
+///
+/// assert_eq!(
+///     geojson,
+///     r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[0,0]},"properties":{"name":"Null Island"}}"#
+/// );
+/// assert_eq!(Feature::from_geojson(&geojson).unwrap(), feature);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature<T, P>
+where
+    T: CoordinateType,
+{
+    pub id: Option<Value>,
+    pub geometry: Option<Geometry<T>>,
+    pub properties: Option<P>,
+}
+
+/// A GeoJSON FeatureCollection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureCollection<T, P>
+where
+    T: CoordinateType,
+{
+    pub features: Vec<Feature<T, P>>,
+}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Write this geometry as a GeoJSON geometry object.
+    ///
+    /// NaN and infinite coordinates are written as `null`, except that an empty point, whose
+    /// coordinates are both NaN, is written with no coordinates.
+    pub fn to_geojson(&self) -> String {
+        let mut writer = GeoJsonWriter::new(String::new());
+        write_geojson(self, &mut writer);
+        writer.into_inner()
+    }
+
+    /// Read a geometry from a GeoJSON geometry object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry};
+    ///
+    /// let geojson = r#"{"type": "LineString", "coordinates": [[0, 0], [1.5, 2, 100]]}"#;
+    ///
+    /// assert_eq!(
+    ///     Geometry::from_geojson(geojson).unwrap(),
+    ///     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1.5, y: 2.)])
+    /// );
+    /// ```
+    pub fn from_geojson(s: &str) -> Result<Geometry<T>, GeoJsonError> {
+        let mut input = serde_json::Deserializer::from_str(s);
+        let geometry = build_geometry(|builder| read_geometry(&mut input, builder))?;
+        input.end()?;
+        Ok(geometry)
+    }
+}
+
+impl<T: CoordinateType> GeometryCollection<T> {
+    /// Write this collection as a GeoJSON `GeometryCollection`.
+    pub fn to_geojson(&self) -> String {
+        let mut writer = GeoJsonWriter::new(String::new());
+        write_geojson(self, &mut writer);
+        writer.into_inner()
+    }
+
+    /// Read a collection from a GeoJSON `GeometryCollection`.
+    pub fn from_geojson(s: &str) -> Result<GeometryCollection<T>, GeoJsonError> {
+        match Geometry::from_geojson(s)? {
+            Geometry::GeometryCollection(gc) => Ok(gc),
+            other => Err(GeoJsonError::MismatchedType {
+                expected: "GeometryCollection",
+                found: other.type_name().to_string(),
+            }),
+        }
+    }
+}
+
+impl<T: CoordinateType, P: Serialize + DeserializeOwned> Feature<T, P> {
+    /// Write this feature as a GeoJSON `Feature`.
+    pub fn to_geojson(&self) -> Result<String, GeoJsonError> {
+        let mut out = String::new();
+        self.write(&mut out)?;
+        Ok(out)
+    }
+
+    /// Read a feature from a GeoJSON `Feature`.
+    pub fn from_geojson(s: &str) -> Result<Feature<T, P>, GeoJsonError> {
+        Self::from_value(&serde_json::from_str(s)?)
+    }
+
+    fn write(&self, out: &mut String) -> Result<(), GeoJsonError> {
+        out.push_str(r#"{"type":"Feature""#);
+        if let Some(id) = &self.id {
+            out.push_str(r#","id":"#);
+            out.push_str(&id.to_string());
+        }
+        out.push_str(r#","geometry":"#);
+        match &self.geometry {
+            Some(geometry) => write_geojson(geometry, &mut GeoJsonWriter::new(&mut *out)),
+            None => out.push_str("null"),
+        }
+        out.push_str(r#","properties":"#);
+        match &self.properties {
+            Some(properties) => out.push_str(&serde_json::to_string(properties)?),
+            None => out.push_str("null"),
+        }
+        out.push('}');
+        Ok(())
+    }
+
+    fn from_value(value: &Value) -> Result<Feature<T, P>, GeoJsonError> {
+        let object = typed_object(value, "Feature")?;
+        let geometry = match object.get("geometry") {
+            None | Some(Value::Null) => None,
+            Some(geometry) => Some(build_geometry(|builder| read_geometry(geometry, builder))?),
+        };
+        let properties = match object.get("properties") {
+            None | Some(Value::Null) => None,
+            Some(properties) => Some(serde_json::from_value(properties.clone())?),
+        };
+        Ok(Feature {
+            id: object.get("id").cloned(),
+            geometry,
+            properties,
+        })
+    }
+}
+
+impl<T: CoordinateType, P: Serialize + DeserializeOwned> FeatureCollection<T, P> {
+    /// Write this collection as a GeoJSON `FeatureCollection`.
+    pub fn to_geojson(&self) -> Result<String, GeoJsonError> {
+        let mut out = r#"{"type":"FeatureCollection","features":["#.to_string();
+        for (i, feature) in self.features.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            feature.write(&mut out)?;
+        }
+        out.push_str("]}");
+        Ok(out)
+    }
+
+    /// Read a collection from a GeoJSON `FeatureCollection`.
+    pub fn from_geojson(s: &str) -> Result<FeatureCollection<T, P>, GeoJsonError> {
+        let value = serde_json::from_str(s)?;
+        let object = typed_object(&value, "FeatureCollection")?;
+        let features = object
+            .get("features")
+            .and_then(Value::as_array)
+            .ok_or(GeoJsonError::InvalidMember("features"))?
+            .iter()
+            .map(Feature::from_value)
+            .collect::<Result<_, _>>()?;
+        Ok(FeatureCollection { features })
+    }
+}
+
+// Writing
+
+/// Integral values are written as JSON integers, everything else as a float
+fn number_to_value<T: CoordinateType>(n: T) -> Value {
+    if let Some(int) = n.to_i64() {
+        if T::from(int) == Some(n) {
+            return int.into();
+        }
+    }
+    n.to_f64()
+        .and_then(serde_json::Number::from_f64)
+        .map_or(Value::Null, Value::Number)
+}
+
+/// Write a geometry to a `String`, which can't fail
+fn write_geojson<T, G>(geometry: &G, writer: &mut GeoJsonWriter<impl fmt::Write>)
+where
+    T: CoordinateType,
+    G: GeomEventSource<T, Error = Infallible> + ?Sized,
+{
+    geometry
+        .process(writer)
+        .expect("writing GeoJSON to a String can't fail");
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Point,
+    Coordinates,
+    Collection,
+}
+
+struct WriterFrame {
+    kind: Kind,
+    // whether this is a geometry object, rather than the coordinates of a member
+    object: bool,
+    children: usize,
+}
+
+/// A [`GeomEventSink`](../stream/trait.GeomEventSink.html) which writes the geometries it's given
+/// as GeoJSON geometry objects to a `fmt::Write`, such as a `String`, one per line.
+///
+/// This is how geometries are written with `to_geojson`, so the GeoJSON is the same.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::geojson::GeoJsonWriter;
+/// use geo_types::stream::GeomEventSource;
+/// use geo_types::{point, Line};
+///
+/// let mut writer = GeoJsonWriter::new(String::new());
+/// point!(x: 1.5, y: 2.).process(&mut writer).unwrap();
+/// Line::new((0, 0), (1, 1)).process(&mut writer).unwrap();
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     r#"{"type":"Point","coordinates":[1.5,2]}
+/// {"type":"LineString","coordinates":[[0,0],[1,1]]}"#
+/// );
+/// ```
+pub struct GeoJsonWriter<W: fmt::Write> {
+    out: W,
+    stack: Vec<WriterFrame>,
+    written: bool,
+}
+
+impl<W: fmt::Write> GeoJsonWriter<W> {
+    /// Write GeoJSON to `out`.
+    pub fn new(out: W) -> Self {
+        GeoJsonWriter {
+            out,
+            stack: vec![],
+            written: false,
+        }
+    }
+
+    /// Return the output written to.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Separate a member of the geometry being written from the last one, and return whether it's
+    /// a geometry object of its own
+    fn member(&mut self) -> Result<bool, fmt::Error> {
+        match self.stack.last_mut() {
+            Some(parent) => {
+                parent.children += 1;
+                if parent.children > 1 {
+                    self.out.write_str(",")?;
+                }
+                Ok(parent.kind == Kind::Collection)
+            }
+            None => {
+                if self.written {
+                    self.out.write_str("\n")?;
+                }
+                self.written = true;
+                Ok(true)
+            }
+        }
+    }
+
+    fn begin(&mut self, geometry_type: &str, kind: Kind) -> fmt::Result {
+        let object = self.member()?;
+        if object {
+            let member = match kind {
+                Kind::Collection => "geometries",
+                _ => "coordinates",
+            };
+            write!(self.out, r#"{{"type":"{}","{}":"#, geometry_type, member)?;
+        }
+        self.out.write_str("[")?;
+        self.stack.push(WriterFrame {
+            kind,
+            object,
+            children: 0,
+        });
+        Ok(())
+    }
+
+    fn end(&mut self) -> fmt::Result {
+        match self.stack.pop() {
+            Some(WriterFrame { object: true, .. }) => self.out.write_str("]}"),
+            Some(_) => self.out.write_str("]"),
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+impl<T: CoordinateType, W: fmt::Write> GeomEventSink<T> for GeoJsonWriter<W> {
+    type Error = fmt::Error;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> fmt::Result {
+        let in_point = match self.stack.last() {
+            Some(frame) => frame.kind == Kind::Point,
+            None => false,
+        };
+        self.member()?;
+        let (x, y) = (number_to_value(coord.x), number_to_value(coord.y));
+        if in_point {
+            write!(self.out, "{},{}", x, y)
+        } else {
+            write!(self.out, "[{},{}]", x, y)
+        }
+    }
+
+    fn begin_point(&mut self) -> fmt::Result {
+        self.begin("Point", Kind::Point)
+    }
+
+    /// An empty point is written with no coordinates, except in a `MultiPoint`, where its
+    /// coordinates are written as `null`
+    fn end_point(&mut self) -> fmt::Result {
+        if let Some(WriterFrame {
+            object: false,
+            children: 0,
+            ..
+        }) = self.stack.last()
+        {
+            self.out.write_str("null,null")?;
+        }
+        self.end()
+    }
+
+    fn begin_line_string(&mut self) -> fmt::Result {
+        self.begin("LineString", Kind::Coordinates)
+    }
+
+    fn end_line_string(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_polygon(&mut self) -> fmt::Result {
+        self.begin("Polygon", Kind::Coordinates)
+    }
+
+    fn end_polygon(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_ring(&mut self) -> fmt::Result {
+        self.begin("", Kind::Coordinates)
+    }
+
+    fn end_ring(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_point(&mut self) -> fmt::Result {
+        self.begin("MultiPoint", Kind::Coordinates)
+    }
+
+    fn end_multi_point(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_line_string(&mut self) -> fmt::Result {
+        self.begin("MultiLineString", Kind::Coordinates)
+    }
+
+    fn end_multi_line_string(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_polygon(&mut self) -> fmt::Result {
+        self.begin("MultiPolygon", Kind::Coordinates)
+    }
+
+    fn end_multi_polygon(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_collection(&mut self) -> fmt::Result {
+        self.begin("GeometryCollection", Kind::Collection)
+    }
+
+    fn end_collection(&mut self) -> fmt::Result {
+        self.end()
+    }
+}
+
+// Reading
+
+/// Return the JSON object, checking that its type is `expected`
+fn typed_object<'a>(
+    value: &'a Value,
+    expected: &'static str,
+) -> Result<&'a Map<String, Value>, GeoJsonError> {
+    let object = value
+        .as_object()
+        .ok_or(GeoJsonError::InvalidMember(expected))?;
+    match object.get("type").and_then(Value::as_str) {
+        Some(found) if found == expected => Ok(object),
+        Some(found) => Err(GeoJsonError::MismatchedType {
+            expected,
+            found: found.to_string(),
+        }),
+        None => Err(GeoJsonError::InvalidMember("type")),
+    }
+}
+
+fn value_to_number<T: CoordinateType>(value: &Value) -> Result<T, GeoJsonError> {
+    let n = match value.as_i64() {
+        Some(int) => T::from(int),
+        None => value
+            .as_f64()
+            .ok_or(GeoJsonError::InvalidMember("coordinates"))
+            .map(T::from)?,
+    };
+    n.ok_or(GeoJsonError::InvalidCoordinate)
+}
+
+impl<W> From<GeoJsonError> for StreamError<GeoJsonError, W> {
+    fn from(e: GeoJsonError) -> Self {
+        StreamError::Source(e)
+    }
+}
+
+type ReadResult<W> = Result<(), StreamError<GeoJsonError, W>>;
+
+/// Read a GeoJSON geometry object from `input`, writing its events to `sink` as its members are
+/// parsed.
+///
+/// The `coordinates` or `geometries` of an object are streamed if its `type` comes first, as
+/// GeoJSON writers (including this one) put it; otherwise they're parsed as a `Value`, then read.
+fn read_geometry<'de, D, T, S>(input: D, sink: &mut S) -> ReadResult<S::Error>
+where
+    D: Deserializer<'de, Error = serde_json::Error>,
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    let mut events = Events {
+        sink,
+        error: None,
+        coordinate_type: PhantomData,
+    };
+    let result = GeometryObject {
+        events: &mut events,
+    }
+    .deserialize(input);
+    result.map_err(|e| {
+        events
+            .error
+            .take()
+            .unwrap_or_else(|| GeoJsonError::Json(e).into())
+    })
+}
+
+/// Build the geometry which `read` writes the events of
+fn build_geometry<T, F>(read: F) -> Result<Geometry<T>, GeoJsonError>
+where
+    T: CoordinateType,
+    F: FnOnce(&mut GeometryBuilder<T>) -> ReadResult<BuildError>,
+{
+    let mut builder = GeometryBuilder::new();
+    read(&mut builder).map_err(|e| match e {
+        StreamError::Source(e) => e,
+        // the events are always well-formed, but may hold an empty point
+        StreamError::Sink(_) => GeoJsonError::InvalidCoordinate,
+    })?;
+    let mut geometries = builder
+        .finish()
+        .map_err(|_| GeoJsonError::InvalidMember("geometry"))?;
+    geometries
+        .pop()
+        .ok_or(GeoJsonError::InvalidMember("geometry"))
+}
+
+// The sink being read into, and the error which stopped reading, if it wasn't a JSON error: since
+// `serde` can only return its own errors, a custom one is returned in its place.
+struct Events<'s, T: CoordinateType, S: GeomEventSink<T> + ?Sized> {
+    sink: &'s mut S,
+    error: Option<StreamError<GeoJsonError, S::Error>>,
+    coordinate_type: PhantomData<T>,
+}
+
+impl<'s, T: CoordinateType, S: GeomEventSink<T> + ?Sized> Events<'s, T, S> {
+    fn fail<E: de::Error>(&mut self, error: StreamError<GeoJsonError, S::Error>) -> E {
+        self.error = Some(error);
+        E::custom("reading GeoJSON stopped")
+    }
+
+    fn invalid<E: de::Error>(&mut self, member: &'static str) -> E {
+        self.fail(GeoJsonError::InvalidMember(member).into())
+    }
+
+    fn emit<E, F>(&mut self, event: F) -> Result<(), E>
+    where
+        E: de::Error,
+        F: FnOnce(&mut S) -> Result<(), S::Error>,
+    {
+        event(self.sink).map_err(|e| self.fail(StreamError::Sink(e)))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GeometryType {
+    Collection,
+    Coordinates(Shape),
+}
+
+impl GeometryType {
+    fn from_name(name: String) -> Result<Self, GeoJsonError> {
+        Ok(match name.as_str() {
+            "GeometryCollection" => GeometryType::Collection,
+            "Point" => GeometryType::Coordinates(Shape::Point),
+            "LineString" => GeometryType::Coordinates(Shape::LineString),
+            "Polygon" => GeometryType::Coordinates(Shape::Polygon),
+            "MultiPoint" => GeometryType::Coordinates(Shape::MultiPoint),
+            "MultiLineString" => GeometryType::Coordinates(Shape::MultiLineString),
+            "MultiPolygon" => GeometryType::Coordinates(Shape::MultiPolygon),
+            _ => return Err(GeoJsonError::UnsupportedType(name)),
+        })
+    }
+
+    /// The member holding the geometry's parts
+    fn member(self) -> &'static str {
+        match self {
+            GeometryType::Collection => "geometries",
+            GeometryType::Coordinates(_) => "coordinates",
+        }
+    }
+}
+
+/// What an array of `coordinates` holds
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    /// A position, which may be empty
+    Point,
+    /// A position, which is a point of a `MultiPoint`
+    MemberPoint,
+    Position,
+    LineString,
+    Ring,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+}
+
+struct GeometryObject<'e, 's, T: CoordinateType, S: GeomEventSink<T> + ?Sized> {
+    events: &'e mut Events<'s, T, S>,
+}
+
+impl<'de, 'e, 's, T, S> DeserializeSeed<'de> for GeometryObject<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, input: D) -> Result<(), D::Error> {
+        input.deserialize_map(self)
+    }
+}
+
+impl<'de, 'e, 's, T, S> Visitor<'de> for GeometryObject<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a GeoJSON geometry object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let events = self.events;
+        let mut geometry_type = None;
+        // the members read before the type was known
+        let mut unread: Vec<(String, Value)> = vec![];
+        let mut read = false;
+        while let Some(key) = map.next_key::<String>()? {
+            match (key.as_str(), geometry_type) {
+                ("type", _) => {
+                    let name = map.next_value()?;
+                    let found = GeometryType::from_name(name).map_err(|e| events.fail(e.into()))?;
+                    geometry_type = Some(found);
+                }
+                (member, Some(found)) if member == found.member() && !read => {
+                    map.next_value_seed(Parts {
+                        geometry_type: found,
+                        events: &mut *events,
+                    })?;
+                    read = true;
+                }
+                ("coordinates", None) | ("geometries", None) => {
+                    unread.push((key, map.next_value()?));
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let geometry_type = geometry_type.ok_or_else(|| events.invalid("type"))?;
+        if !read {
+            let member = geometry_type.member();
+            let (_, parts) = unread
+                .into_iter()
+                .find(|(key, _)| key == member)
+                .ok_or_else(|| events.invalid(member))?;
+            Parts {
+                geometry_type,
+                events,
+            }
+            .deserialize(parts)
+            .map_err(de::Error::custom)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `coordinates` or `geometries` of a geometry
+struct Parts<'e, 's, T: CoordinateType, S: GeomEventSink<T> + ?Sized> {
+    geometry_type: GeometryType,
+    events: &'e mut Events<'s, T, S>,
+}
+
+impl<'de, 'e, 's, T, S> DeserializeSeed<'de> for Parts<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, input: D) -> Result<(), D::Error> {
+        match self.geometry_type {
+            GeometryType::Collection => input.deserialize_seq(Geometries {
+                events: self.events,
+            }),
+            GeometryType::Coordinates(shape) => input.deserialize_seq(Coordinates {
+                shape,
+                events: self.events,
+            }),
+        }
+    }
+}
+
+struct Geometries<'e, 's, T: CoordinateType, S: GeomEventSink<T> + ?Sized> {
+    events: &'e mut Events<'s, T, S>,
+}
+
+impl<'de, 'e, 's, T, S> Visitor<'de> for Geometries<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of GeoJSON geometry objects")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let events = self.events;
+        events.emit(|sink| sink.begin_collection())?;
+        while seq
+            .next_element_seed(GeometryObject {
+                events: &mut *events,
+            })?
+            .is_some()
+        {}
+        events.emit(|sink| sink.end_collection())
+    }
+}
+
+struct Coordinates<'e, 's, T: CoordinateType, S: GeomEventSink<T> + ?Sized> {
+    shape: Shape,
+    events: &'e mut Events<'s, T, S>,
+}
+
+impl<'de, 'e, 's, T, S> DeserializeSeed<'de> for Coordinates<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, input: D) -> Result<(), D::Error> {
+        input.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'e, 's, T, S> Visitor<'de> for Coordinates<'e, 's, T, S>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of GeoJSON coordinates")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let (shape, events) = (self.shape, self.events);
+        let members = match shape {
+            Shape::Point => {
+                events.emit(|sink| sink.begin_point())?;
+                position(events, &mut seq, true)?;
+                return events.emit(|sink| sink.end_point());
+            }
+            Shape::MemberPoint => {
+                events.emit(|sink| sink.begin_point())?;
+                position(events, &mut seq, false)?;
+                return events.emit(|sink| sink.end_point());
+            }
+            Shape::Position => return position(events, &mut seq, false),
+            Shape::LineString => {
+                events.emit(|sink| sink.begin_line_string())?;
+                Shape::Position
+            }
+            Shape::Ring => {
+                events.emit(|sink| sink.begin_ring())?;
+                Shape::Position
+            }
+            Shape::Polygon => {
+                events.emit(|sink| sink.begin_polygon())?;
+                Shape::Ring
+            }
+            Shape::MultiPoint => {
+                events.emit(|sink| sink.begin_multi_point())?;
+                Shape::MemberPoint
+            }
+            Shape::MultiLineString => {
+                events.emit(|sink| sink.begin_multi_line_string())?;
+                Shape::LineString
+            }
+            Shape::MultiPolygon => {
+                events.emit(|sink| sink.begin_multi_polygon())?;
+                Shape::Polygon
+            }
+        };
+        while seq
+            .next_element_seed(Coordinates {
+                shape: members,
+                events: &mut *events,
+            })?
+            .is_some()
+        {}
+        events.emit(|sink| match shape {
+            Shape::LineString => sink.end_line_string(),
+            Shape::Ring => sink.end_ring(),
+            Shape::Polygon => sink.end_polygon(),
+            Shape::MultiPoint => sink.end_multi_point(),
+            Shape::MultiLineString => sink.end_multi_line_string(),
+            _ => sink.end_multi_polygon(),
+        })
+    }
+}
+
+/// Read the values of a position, which may be `empty` if that's allowed, ignoring any altitude
+fn position<'de, A, T, S>(
+    events: &mut Events<T, S>,
+    seq: &mut A,
+    empty: bool,
+) -> Result<(), A::Error>
+where
+    A: SeqAccess<'de>,
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    let x: Option<Value> = seq.next_element()?;
+    if x.is_none() && empty {
+        return Ok(());
+    }
+    let y: Option<Value> = seq.next_element()?;
+    while seq.next_element::<IgnoredAny>()?.is_some() {}
+    let coord = match (x, y) {
+        (Some(x), Some(y)) => match (value_to_number(&x), value_to_number(&y)) {
+            (Ok(x), Ok(y)) => Coordinate { x, y },
+            (Err(e), _) | (_, Err(e)) => return Err(events.fail(e.into())),
+        },
+        _ => return Err(events.invalid("coordinates")),
+    };
+    events.emit(|sink| sink.coord(coord))
+}
+
+/// A [`GeomEventSource`](../stream/trait.GeomEventSource.html) which reads geometries from
+/// GeoJSON geometry objects, without building the geometries.
+///
+/// The JSON is read from a `Read`, such as a file, and each geometry's events are written as its
+/// coordinates are parsed, so even a huge geometry is never held in memory, as long as its `type`
+/// comes before them. The reader reads one byte at a time, so wrap files in a `BufReader`. The
+/// input may hold several geometry objects, separated by whitespace, as in newline-delimited
+/// GeoJSON; each is written to the sink in turn, up to the end of the input. Arrays and objects
+/// can be nested at most 128 deep, so untrusted input can't overflow the stack.
+///
+/// The coordinate type is chosen when the reader is created, since sinks such as
+/// [`GeoJsonWriter`](struct.GeoJsonWriter.html) accept any of them.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::geojson::{GeoJsonReader, GeoJsonWriter};
+/// use geo_types::stream::GeomEventSource;
+///
+/// // drop the altitudes
+/// let geojson = r#"{"type": "MultiPoint", "coordinates": [[1, 2, 10], [3, 4, 20]]}"#;
+/// let mut writer = GeoJsonWriter::new(String::new());
+/// GeoJsonReader::<_, i32>::new(geojson.as_bytes())
+///     .process(&mut writer)
+///     .unwrap();
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     r#"{"type":"MultiPoint","coordinates":[[1,2],[3,4]]}"#
+/// );
+/// ```
+pub struct GeoJsonReader<R: io::Read, T> {
+    input: RefCell<serde_json::Deserializer<IoRead<R>>>,
+    coordinate_type: PhantomData<T>,
+}
+
+impl<R: io::Read, T: CoordinateType> GeoJsonReader<R, T> {
+    /// Read GeoJSON from `input`, with coordinates of type `T`.
+    pub fn new(input: R) -> Self {
+        GeoJsonReader {
+            input: RefCell::new(serde_json::Deserializer::from_reader(input)),
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<R: io::Read, T: CoordinateType> GeomEventSource<T> for GeoJsonReader<R, T> {
+    type Error = GeoJsonError;
+
+    /// Write the events of the geometries which haven't been read yet.
+    fn process<S>(&self, sink: &mut S) -> ReadResult<S::Error>
+    where
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let mut input = self.input.borrow_mut();
+        // `end` succeeds once only whitespace is left
+        while input.end().is_err() {
+            read_geometry(&mut *input, sink)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+        Point, Polygon,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn roundtrip() {
+        let gc: GeometryCollection<f64> = GeometryCollection(vec![
+            Geometry::Point(point!(x: 1.5, y: -2.)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            )),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 0., y: 1.)])),
+            Geometry::MultiLineString(MultiLineString(vec![LineString(vec![])])),
+            Geometry::MultiPolygon(MultiPolygon(vec![Polygon::new(LineString(vec![]), vec![])])),
+            Geometry::GeometryCollection(GeometryCollection::new()),
+        ]);
+        assert_eq!(
+            GeometryCollection::from_geojson(&gc.to_geojson()).unwrap(),
+            gc
+        );
+    }
+
+    #[test]
+    fn write() {
+        let line = Geometry::Line(Line::new((0, 0), (1, 2)));
+        assert_eq!(
+            line.to_geojson(),
+            r#"{"type":"LineString","coordinates":[[0,0],[1,2]]}"#
+        );
+        let nan = Geometry::Point(point!(x: f64::NAN, y: 0.));
+        assert_eq!(
+            nan.to_geojson(),
+            r#"{"type":"Point","coordinates":[null,0]}"#
+        );
+    }
+
+    #[test]
+    fn empty_points() {
+        let empty = Geometry::Point(Point::<f64>::empty());
+        let geojson = r#"{"type":"Point","coordinates":[]}"#;
+        assert_eq!(empty.to_geojson(), geojson);
+        assert!(matches!(
+            Geometry::<f32>::from_geojson(geojson),
+            Ok(Geometry::Point(point)) if point.is_empty()
+        ));
+        assert!(matches!(
+            Geometry::<i32>::from_geojson(geojson),
+            Err(GeoJsonError::InvalidCoordinate)
+        ));
+    }
+
+    #[test]
+    fn integer_coordinates_are_exact() {
+        let geojson = r#"{"type":"Point","coordinates":[9007199254740993,1]}"#;
+        assert_eq!(
+            Geometry::<i64>::from_geojson(geojson).unwrap(),
+            Geometry::Point(point!(x: 9_007_199_254_740_993, y: 1))
+        );
+        assert!(matches!(
+            Geometry::<u8>::from_geojson(r#"{"type":"Point","coordinates":[-1,1]}"#),
+            Err(GeoJsonError::InvalidCoordinate)
+        ));
+    }
+
+    #[test]
+    fn feature_collection() {
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Properties {
+            name: String,
+        }
+
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "id": 1,
+                    "geometry": {"type": "Point", "coordinates": [1, 2]},
+                    "properties": {"name": "a"}
+                },
+                {"type": "Feature", "geometry": null, "properties": null}
+            ]
+        }"#;
+        let fc: FeatureCollection<f64, Properties> =
+            FeatureCollection::from_geojson(geojson).unwrap();
+        assert_eq!(
+            fc.features,
+            vec![
+                Feature {
+                    id: Some(1.into()),
+                    geometry: Some(Geometry::Point(point!(x: 1., y: 2.))),
+                    properties: Some(Properties {
+                        name: "a".to_string()
+                    }),
+                },
+                Feature {
+                    id: None,
+                    geometry: None,
+                    properties: None,
+                },
+            ]
+        );
+        assert_eq!(
+            FeatureCollection::from_geojson(&fc.to_geojson().unwrap()).unwrap(),
+            fc
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        let gc = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(Point::empty()),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 0.5, y: 1.), Point::empty()])),
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+            Geometry::MultiLineString(MultiLineString(vec![LineString(vec![])])),
+        ]));
+        let geojson = concat!(
+            r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[]},"#,
+            r#"{"type":"MultiPoint","coordinates":[[0.5,1],[null,null]]},"#,
+            r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1],[0,0]]]},"#,
+            r#"{"type":"MultiLineString","coordinates":[[]]}]}"#
+        );
+        assert_eq!(gc.to_geojson(), geojson);
+
+        let mut writer = GeoJsonWriter::new(String::new());
+        GeoJsonReader::<_, f64>::new(geojson.replace("[null,null]", "[2,3]").as_bytes())
+            .process(&mut writer)
+            .unwrap();
+        assert_eq!(writer.into_inner(), geojson.replace("[null,null]", "[2,3]"));
+
+        let mut builder = GeometryBuilder::<f64>::new();
+        assert!(matches!(
+            GeoJsonReader::new(geojson.as_bytes()).process(&mut builder),
+            Err(StreamError::Source(GeoJsonError::InvalidMember(
+                "coordinates"
+            )))
+        ));
+    }
+
+    #[test]
+    fn stream_of_geometries() {
+        // newline-delimited, with the type of the second after its coordinates, and a member
+        // which isn't part of a geometry
+        let geojson = concat!(
+            r#"{"type":"Point","coordinates":[1,2]}"#,
+            "\n",
+            r#"{"coordinates":[[0,0],[1,1]],"bbox":[0,0,1,1],"type":"LineString"}"#,
+            "\n\n",
+            r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[3,4]}]}"#,
+            "\n"
+        );
+        let reader = GeoJsonReader::new(io::BufReader::with_capacity(4, geojson.as_bytes()));
+        assert_eq!(
+            GeometryBuilder::build(&reader).unwrap(),
+            vec![
+                Geometry::Point(point!(x: 1, y: 2)),
+                Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 1)]),
+                Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(
+                    point!(x: 3, y: 4)
+                )])),
+            ]
+        );
+        assert!(
+            GeometryBuilder::<f64>::build(&GeoJsonReader::new(io::empty()))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            GeometryBuilder::<f64>::build(&GeoJsonReader::new(&geojson.as_bytes()[..50])),
+            Err(StreamError::Source(GeoJsonError::Json(e))) if e.is_eof()
+        ));
+
+        struct Failing;
+        impl io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+        assert!(matches!(
+            GeometryBuilder::<f64>::build(&GeoJsonReader::new(Failing)),
+            Err(StreamError::Source(GeoJsonError::Json(e))) if e.is_io()
+        ));
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let nested = |depth: usize| {
+            r#"{"type":"GeometryCollection","geometries":["#.repeat(depth) + &"]}".repeat(depth)
+        };
+        assert!(Geometry::<f64>::from_geojson(&nested(60)).is_ok());
+        // deeply enough to overflow the stack, if serde_json didn't limit it
+        let geojson = nested(200_000);
+        assert!(matches!(
+            Geometry::<f64>::from_geojson(&geojson),
+            Err(GeoJsonError::Json(_))
+        ));
+        assert!(GeometryBuilder::<f64>::build(&GeoJsonReader::new(geojson.as_bytes())).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            Geometry::<f64>::from_geojson("{"),
+            Err(GeoJsonError::Json(_))
+        ));
+        assert!(matches!(
+            Geometry::<f64>::from_geojson(r#"{"type":"Circle","coordinates":[0,0]}"#),
+            Err(GeoJsonError::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            Geometry::<f64>::from_geojson(r#"{"type":"Point","coordinates":[0]}"#),
+            Err(GeoJsonError::InvalidMember("coordinates"))
+        ));
+        assert!(matches!(
+            GeometryCollection::<f64>::from_geojson(r#"{"type":"Point","coordinates":[0,0]}"#),
+            Err(GeoJsonError::MismatchedType { .. })
+        ));
+        assert!(matches!(
+            Feature::<f64, HashMap<String, i32>>::from_geojson(
+                r#"{"type":"Feature","geometry":null,"properties":{"a":"b"}}"#
+            ),
+            Err(GeoJsonError::Json(_))
+        ));
+    }
+}
@@ -1,11 +1,9 @@
 use crate::{
-    CoordinateType, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon,
+    CoordinateType, Error, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use num_traits::Float;
-use std::convert::TryFrom;
-use std::error::Error;
-use std::fmt;
+use core::convert::TryFrom;
+use core::fmt;
 
 /// An enum representing any possible geometry type.
 ///
@@ -16,14 +14,32 @@ use std::fmt;
 /// # Example
 ///
 /// ```
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// use geo_types::{Point, point, Geometry, GeometryCollection};
 /// let p = point!(x: 1.0, y: 1.0);
 /// let pe: Geometry<f64> = p.into();
 /// let pn = Point::try_from(pe).unwrap();
 /// ```
 ///
+/// Converting to the wrong type returns an [`Error`](enum.Error.html) naming both types:
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use geo_types::{Error, Geometry, Line, Point, Rect};
+/// let le: Geometry<i32> = Line::new((0, 0), (1, 1)).into();
+/// assert_eq!(
+///     Point::try_from(le),
+///     Err(Error::MismatchedGeometry { expected: "Point", found: "Line" })
+/// );
+///
+/// let re: Geometry<i32> = Rect::new((0, 0), (1, 1)).into();
+/// assert!(Rect::try_from(re).is_ok());
+/// ```
+///
 #[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
+#[cfg_attr(feature = "scale-codec", codec(encode_bound(T: codec::Encode)))]
+#[cfg_attr(feature = "scale-codec", codec(decode_bound(T: codec::Decode)))]
 pub enum Geometry<T>
 where
     T: CoordinateType,
@@ -36,6 +52,8 @@ where
     MultiLineString(MultiLineString<T>),
     MultiPolygon(MultiPolygon<T>),
     GeometryCollection(GeometryCollection<T>),
+    Rect(Rect<T>),
+    Triangle(Triangle<T>),
 }
 
 impl<T: CoordinateType> From<Point<T>> for Geometry<T> {
@@ -73,8 +91,88 @@ impl<T: CoordinateType> From<MultiPolygon<T>> for Geometry<T> {
         Geometry::MultiPolygon(x)
     }
 }
+impl<T: CoordinateType> From<Rect<T>> for Geometry<T> {
+    fn from(x: Rect<T>) -> Geometry<T> {
+        Geometry::Rect(x)
+    }
+}
+impl<T: CoordinateType> From<Triangle<T>> for Geometry<T> {
+    fn from(x: Triangle<T>) -> Geometry<T> {
+        Geometry::Triangle(x)
+    }
+}
 
 impl<T: CoordinateType> Geometry<T> {
+    /// Return the bounding rectangle of this geometry, or `None` if it has no coordinates, as
+    /// for an [empty point](struct.Point.html#method.is_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, point, Coordinate, Geometry};
+    ///
+    /// let g: Geometry<i32> = line_string![(x: 1, y: 4), (x: -2, y: 3)].into();
+    /// let rect = g.bounding_rect().unwrap();
+    ///
+    /// assert_eq!(rect.min(), Coordinate { x: -2, y: 3 });
+    /// assert_eq!(rect.max(), Coordinate { x: 1, y: 4 });
+    /// ```
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        match self {
+            Geometry::Point(g) if g.is_empty() => None,
+            Geometry::Point(g) => Some(g.bounding_rect()),
+            Geometry::Line(g) => Some(g.bounding_rect()),
+            Geometry::LineString(g) => g.bounding_rect(),
+            Geometry::Polygon(g) => g.bounding_rect(),
+            Geometry::MultiPoint(g) => g.bounding_rect(),
+            Geometry::MultiLineString(g) => g.bounding_rect(),
+            Geometry::MultiPolygon(g) => g.bounding_rect(),
+            Geometry::GeometryCollection(g) => g.bounding_rect(),
+            Geometry::Rect(g) => Some(g.bounding_rect()),
+            Geometry::Triangle(g) => Some(g.bounding_rect()),
+        }
+    }
+
+    /// The name of the type of geometry this holds, e.g. `"Point"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// assert_eq!(Geometry::Point(point!(x: 1., y: 2.)).type_name(), "Point");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        self.geometry_type().name()
+    }
+
+    /// The type of geometry this holds, without the geometry itself, for matching on or logging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry, GeometryType};
+    ///
+    /// let g: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+    ///
+    /// assert_eq!(g.geometry_type(), GeometryType::LineString);
+    /// assert_eq!(g.geometry_type().to_string(), "LineString");
+    /// ```
+    pub fn geometry_type(&self) -> GeometryType {
+        match self {
+            Geometry::Point(_) => GeometryType::Point,
+            Geometry::Line(_) => GeometryType::Line,
+            Geometry::LineString(_) => GeometryType::LineString,
+            Geometry::Polygon(_) => GeometryType::Polygon,
+            Geometry::MultiPoint(_) => GeometryType::MultiPoint,
+            Geometry::MultiLineString(_) => GeometryType::MultiLineString,
+            Geometry::MultiPolygon(_) => GeometryType::MultiPolygon,
+            Geometry::GeometryCollection(_) => GeometryType::GeometryCollection,
+            Geometry::Rect(_) => GeometryType::Rect,
+            Geometry::Triangle(_) => GeometryType::Triangle,
+        }
+    }
+
     /// If this Geometry is a Point, then return that, else None.
     ///
     /// # Examples
@@ -169,115 +267,385 @@ impl<T: CoordinateType> Geometry<T> {
     }
 }
 
-#[derive(Debug)]
-pub struct FailedToConvertError;
+macro_rules! geometry_accessors_impl {
+    ($($variant:ident, $type_name:literal, $as:ident, $as_mut:ident;)*) => {
+        impl<T: CoordinateType> Geometry<T> {
+            $(
+                #[doc = concat!("If this Geometry is a ", $type_name, ", then return a reference to it, else None.")]
+                pub fn $as(&self) -> Option<&$variant<T>> {
+                    match self {
+                        Geometry::$variant(g) => Some(g),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("If this Geometry is a ", $type_name, ", then return a mutable reference to it, else None.")]
+                pub fn $as_mut(&mut self) -> Option<&mut $variant<T>> {
+                    match self {
+                        Geometry::$variant(g) => Some(g),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
 
-impl fmt::Display for FailedToConvertError {
+geometry_accessors_impl!(
+    Point, "Point", as_point, as_point_mut;
+    Line, "Line", as_line, as_line_mut;
+    LineString, "LineString", as_line_string, as_line_string_mut;
+    Polygon, "Polygon", as_polygon, as_polygon_mut;
+    MultiPoint, "MultiPoint", as_multi_point, as_multi_point_mut;
+    MultiLineString, "MultiLineString", as_multi_line_string, as_multi_line_string_mut;
+    MultiPolygon, "MultiPolygon", as_multi_polygon, as_multi_polygon_mut;
+    GeometryCollection, "GeometryCollection", as_geometry_collection, as_geometry_collection_mut;
+    Rect, "Rect", as_rect, as_rect_mut;
+    Triangle, "Triangle", as_triangle, as_triangle_mut;
+);
+
+/// The type of a [`Geometry`](enum.Geometry.html), without the geometry, as returned by
+/// [`Geometry::geometry_type`](enum.Geometry.html#method.geometry_type).
+///
+/// It displays as the name of the type, e.g. `"Point"`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeometryType {
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+}
+
+impl GeometryType {
+    /// The name of the type, e.g. `"Point"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            GeometryType::Point => "Point",
+            GeometryType::Line => "Line",
+            GeometryType::LineString => "LineString",
+            GeometryType::Polygon => "Polygon",
+            GeometryType::MultiPoint => "MultiPoint",
+            GeometryType::MultiLineString => "MultiLineString",
+            GeometryType::MultiPolygon => "MultiPolygon",
+            GeometryType::GeometryCollection => "GeometryCollection",
+            GeometryType::Rect => "Rect",
+            GeometryType::Triangle => "Triangle",
+        }
+    }
+}
+
+impl fmt::Display for GeometryType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Could not convert from enum member to concrete type")
+        f.write_str(self.name())
     }
 }
 
-impl Error for FailedToConvertError {
-    fn description(&self) -> &str {
-        "Could not convert from enum member to concrete type"
+macro_rules! try_from_geometry_impl {
+    ($($type:ident),*) => {
+        $(
+            /// Convert a Geometry enum into its inner type.
+            ///
+            /// Fails if the enum case does not match the type you are trying to convert it to.
+            impl<T: CoordinateType> TryFrom<Geometry<T>> for $type<T> {
+                type Error = Error;
+
+                fn try_from(geom: Geometry<T>) -> Result<$type<T>, Self::Error> {
+                    match geom {
+                        Geometry::$type(g) => Ok(g),
+                        other => Err(Error::MismatchedGeometry {
+                            expected: stringify!($type),
+                            found: other.type_name(),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// `GeometryCollection` is left out: every `Geometry` can already be converted into a
+// (single-member) `GeometryCollection` using `From`, which provides an infallible `TryFrom`, so
+// `Geometry::into_geometry_collection` unwraps one instead
+try_from_geometry_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle
+);
+
+impl<T: CoordinateType> Geometry<T> {
+    /// If this Geometry is a GeometryCollection, then return that, else fail, as `TryFrom` does
+    /// for the other geometry types.
+    ///
+    /// Beware that `GeometryCollection::try_from` (and `try_into`) doesn't do this: as any
+    /// `Geometry` can be converted into a `GeometryCollection` using `From`, it always succeeds,
+    /// wrapping the geometry in a new collection, even if it's a collection already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    /// use core::convert::TryFrom;
+    ///
+    /// let gc = GeometryCollection(vec![Geometry::Point(point!(x: 1., y: 2.))]);
+    /// let geometry = Geometry::GeometryCollection(gc.clone());
+    ///
+    /// assert_eq!(geometry.clone().into_geometry_collection().unwrap(), gc);
+    /// // wrapped, rather than unwrapped
+    /// assert_eq!(
+    ///     GeometryCollection::try_from(geometry.clone()).unwrap(),
+    ///     GeometryCollection(vec![geometry])
+    /// );
+    ///
+    /// assert!(Geometry::Point(point!(x: 1., y: 2.))
+    ///     .into_geometry_collection()
+    ///     .is_err());
+    /// ```
+    pub fn into_geometry_collection(self) -> Result<GeometryCollection<T>, Error> {
+        match self {
+            Geometry::GeometryCollection(gc) => Ok(gc),
+            other => Err(Error::MismatchedGeometry {
+                expected: "GeometryCollection",
+                found: other.type_name(),
+            }),
+        }
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for Point<T>
+/// A `Geometry` stored alongside its envelope, so that geometries of mixed types can be loaded
+/// into an [`rstar::RTree`](https://docs.rs/rstar/0.7.1/rstar/struct.RTree.html).
+///
+/// The envelope is computed once, when the cell is created, rather than each time the tree asks
+/// for it. Points inside a `Polygon`, `MultiPolygon`, `Rect`, or `Triangle` are at a distance of
+/// zero from it, and the distance to a multi-geometry or collection is the distance to its
+/// nearest member.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, polygon, Geometry, GeometryCell, Line};
+/// use rstar::RTree;
+///
+/// let tree = RTree::bulk_load(vec![
+///     GeometryCell::new(Geometry::Line(Line::new((0., 0.), (1., 1.)))),
+///     GeometryCell::new(Geometry::Polygon(polygon![
+///         (x: 4., y: 0.),
+///         (x: 6., y: 0.),
+///         (x: 6., y: 2.),
+///         (x: 4., y: 2.),
+///     ])),
+/// ]);
+///
+/// let nearest = tree.nearest_neighbor(&point!(x: 5., y: 1.)).unwrap();
+/// assert_eq!(nearest.geometry().type_name(), "Polygon");
+/// ```
+#[cfg(feature = "rstar")]
+#[derive(PartialEq, Clone, Debug)]
+pub struct GeometryCell<T>
+where
+    T: num_traits::Float + rstar::RTreeNum,
+{
+    geometry: Geometry<T>,
+    envelope: rstar::AABB<Point<T>>,
+}
+
+#[cfg(feature = "rstar")]
+impl<T> GeometryCell<T>
 where
-    T: Float,
+    T: num_traits::Float + rstar::RTreeNum,
 {
-    type Error = FailedToConvertError;
+    pub fn new(geometry: Geometry<T>) -> Self {
+        let envelope = crate::private_utils::rect_to_envelope(geometry.bounding_rect());
+        GeometryCell { geometry, envelope }
+    }
 
-    fn try_from(geom: Geometry<T>) -> Result<Point<T>, Self::Error> {
-        match geom {
-            Geometry::Point(p) => Ok(p),
-            _ => Err(FailedToConvertError),
-        }
+    pub fn geometry(&self) -> &Geometry<T> {
+        &self.geometry
+    }
+
+    pub fn into_geometry(self) -> Geometry<T> {
+        self.geometry
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for Line<T>
+#[cfg(feature = "rstar")]
+impl<T> From<Geometry<T>> for GeometryCell<T>
 where
-    T: Float,
+    T: num_traits::Float + rstar::RTreeNum,
 {
-    type Error = FailedToConvertError;
-
-    fn try_from(geom: Geometry<T>) -> Result<Line<T>, Self::Error> {
-        match geom {
-            Geometry::Line(l) => Ok(l),
-            _ => Err(FailedToConvertError),
-        }
+    fn from(geometry: Geometry<T>) -> Self {
+        GeometryCell::new(geometry)
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for LineString<T>
+#[cfg(feature = "rstar")]
+impl<T> rstar::RTreeObject for GeometryCell<T>
 where
-    T: Float,
+    T: num_traits::Float + rstar::RTreeNum,
 {
-    type Error = FailedToConvertError;
+    type Envelope = rstar::AABB<Point<T>>;
 
-    fn try_from(geom: Geometry<T>) -> Result<LineString<T>, Self::Error> {
-        match geom {
-            Geometry::LineString(ls) => Ok(ls),
-            _ => Err(FailedToConvertError),
-        }
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for Polygon<T>
+#[cfg(feature = "rstar")]
+impl<T> rstar::PointDistance for GeometryCell<T>
 where
-    T: Float,
+    T: num_traits::Float + rstar::RTreeNum,
 {
-    type Error = FailedToConvertError;
+    fn distance_2(&self, point: &Point<T>) -> T {
+        geometry_distance_2(&self.geometry, point)
+    }
+}
 
-    fn try_from(geom: Geometry<T>) -> Result<Polygon<T>, Self::Error> {
-        match geom {
-            Geometry::Polygon(ls) => Ok(ls),
-            _ => Err(FailedToConvertError),
+// The squared distance to the nearest member of the geometry, or the largest value if it's empty
+#[cfg(feature = "rstar")]
+fn geometry_distance_2<T>(geometry: &Geometry<T>, point: &Point<T>) -> T
+where
+    T: num_traits::Float + rstar::RTreeNum,
+{
+    use rstar::PointDistance;
+    let nearest = |distances: &mut dyn Iterator<Item = T>| {
+        distances.fold(<T as num_traits::Float>::max_value(), |accum, val| {
+            accum.min(val)
+        })
+    };
+    match geometry {
+        Geometry::Point(g) => g.distance_2(point),
+        Geometry::Line(g) => g.distance_2(point),
+        Geometry::LineString(g) if g.0.is_empty() => <T as num_traits::Float>::max_value(),
+        Geometry::LineString(g) => g.distance_2(point),
+        Geometry::Polygon(g) => g.distance_2(point),
+        Geometry::MultiPoint(g) => nearest(&mut g.0.iter().map(|p| p.distance_2(point))),
+        Geometry::MultiLineString(g) => nearest(
+            &mut g
+                .0
+                .iter()
+                .filter(|line_string| !line_string.0.is_empty())
+                .map(|line_string| line_string.distance_2(point)),
+        ),
+        Geometry::MultiPolygon(g) => nearest(&mut g.0.iter().map(|p| p.distance_2(point))),
+        Geometry::GeometryCollection(g) => {
+            nearest(&mut g.0.iter().map(|g| geometry_distance_2(g, point)))
         }
+        Geometry::Rect(g) => g.distance_2(point),
+        Geometry::Triangle(g) => g.distance_2(point),
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for MultiPoint<T>
+/// Geometries of different types are never approximately equal, even if, like a `Rect` and
+/// the `Polygon` of it, they cover the same points.
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Geometry<T>
 where
-    T: Float,
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
 {
-    type Error = FailedToConvertError;
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
 
-    fn try_from(geom: Geometry<T>) -> Result<MultiPoint<T>, Self::Error> {
-        match geom {
-            Geometry::MultiPoint(mp) => Ok(mp),
-            _ => Err(FailedToConvertError),
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        match (self, other) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::Line(a), Geometry::Line(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::LineString(a), Geometry::LineString(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::Polygon(a), Geometry::Polygon(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+                a.abs_diff_eq(b, epsilon)
+            }
+            (Geometry::Rect(a), Geometry::Rect(b)) => a.abs_diff_eq(b, epsilon),
+            (Geometry::Triangle(a), Geometry::Triangle(b)) => a.abs_diff_eq(b, epsilon),
+            _ => false,
         }
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for MultiLineString<T>
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Geometry<T>
 where
-    T: Float,
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
 {
-    type Error = FailedToConvertError;
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
 
-    fn try_from(geom: Geometry<T>) -> Result<MultiLineString<T>, Self::Error> {
-        match geom {
-            Geometry::MultiLineString(mls) => Ok(mls),
-            _ => Err(FailedToConvertError),
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        match (self, other) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Geometry::Line(a), Geometry::Line(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Geometry::LineString(a), Geometry::LineString(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Geometry::Polygon(a), Geometry::Polygon(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            (Geometry::Rect(a), Geometry::Rect(b)) => a.relative_eq(b, epsilon, max_relative),
+            (Geometry::Triangle(a), Geometry::Triangle(b)) => {
+                a.relative_eq(b, epsilon, max_relative)
+            }
+            _ => false,
         }
     }
 }
 
-impl<T> TryFrom<Geometry<T>> for MultiPolygon<T>
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Geometry<T>
 where
-    T: Float,
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
 {
-    type Error = FailedToConvertError;
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
 
-    fn try_from(geom: Geometry<T>) -> Result<MultiPolygon<T>, Self::Error> {
-        match geom {
-            Geometry::MultiPolygon(mp) => Ok(mp),
-            _ => Err(FailedToConvertError),
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        match (self, other) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::Line(a), Geometry::Line(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::LineString(a), Geometry::LineString(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::Polygon(a), Geometry::Polygon(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+                a.ulps_eq(b, epsilon, max_ulps)
+            }
+            (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+                a.ulps_eq(b, epsilon, max_ulps)
+            }
+            (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+                a.ulps_eq(b, epsilon, max_ulps)
+            }
+            (Geometry::Rect(a), Geometry::Rect(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            (Geometry::Triangle(a), Geometry::Triangle(b)) => a.ulps_eq(b, epsilon, max_ulps),
+            _ => false,
         }
     }
 }
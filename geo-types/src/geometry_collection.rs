@@ -1,6 +1,10 @@
-use crate::{CoordinateType, Geometry};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use crate::{
+    CoordinateType, Geometry, GeometryCollectionIndex, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// A collection of [`Geometry`](enum.Geometry.html) types.
 ///
@@ -15,7 +19,7 @@ use std::ops::{Index, IndexMut};
 /// ## Looping
 ///
 /// ```
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// use geo_types::{Point, point, Geometry, GeometryCollection};
 /// let p = point!(x: 1.0, y: 1.0);
 /// let pe = Geometry::Point(p);
@@ -27,7 +31,7 @@ use std::ops::{Index, IndexMut};
 /// ## Implements `iter()`
 ///
 /// ```
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// use geo_types::{Point, point, Geometry, GeometryCollection};
 /// let p = point!(x: 1.0, y: 1.0);
 /// let pe = Geometry::Point(p);
@@ -38,7 +42,7 @@ use std::ops::{Index, IndexMut};
 /// ## Mutable Iteration
 ///
 /// ```
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// use geo_types::{Point, point, Geometry, GeometryCollection};
 /// let p = point!(x: 1.0, y: 1.0);
 /// let pe = Geometry::Point(p);
@@ -52,10 +56,27 @@ use std::ops::{Index, IndexMut};
 /// assert_eq!(Point::try_from(updated).unwrap().x(), 0.2);
 /// ```
 ///
+/// ## Iterating over one kind of geometry
+///
+/// ```
+/// use geo_types::{line_string, point, GeometryCollection};
+/// let mut gc = GeometryCollection::new();
+/// gc.push(point!(x: 1.0, y: 1.0));
+/// gc.push(line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]);
+/// gc.push(point!(x: 2.0, y: 2.0));
+/// assert_eq!(gc.points().count(), 2);
+/// assert_eq!(gc.line_strings().count(), 1);
+///
+/// for p in gc.points_mut() {
+///     p.set_x(0.0);
+/// }
+/// assert!(gc.points().all(|p| p.x() == 0.0));
+/// ```
+///
 /// ## Indexing
 ///
 /// ```
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// use geo_types::{Point, point, Geometry, GeometryCollection};
 /// let p = point!(x: 1.0, y: 1.0);
 /// let pe = Geometry::Point(p);
@@ -64,6 +85,9 @@ use std::ops::{Index, IndexMut};
 /// ```
 ///
 #[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
+#[cfg_attr(feature = "scale-codec", codec(encode_bound(T: codec::Encode)))]
+#[cfg_attr(feature = "scale-codec", codec(decode_bound(T: codec::Decode)))]
 pub struct GeometryCollection<T>(pub Vec<Geometry<T>>)
 where
     T: CoordinateType;
@@ -83,10 +107,224 @@ impl<T: CoordinateType> GeometryCollection<T> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Append a Geometry (or anything that can be converted to a Geometry) to the end of this
+    /// GeometryCollection
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let mut gc = GeometryCollection::new();
+    /// gc.push(point!(x: 1.0, y: 1.0));
+    ///
+    /// assert_eq!(gc.len(), 1);
+    /// assert_eq!(gc[0], Geometry::Point(point!(x: 1.0, y: 1.0)));
+    /// ```
+    pub fn push(&mut self, geometry: impl Into<Geometry<T>>) {
+        self.0.push(geometry.into());
+    }
+
+    /// Insert a Geometry (or anything that can be converted to a Geometry) at position `index`,
+    /// shifting all geometries after it to the right
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let mut gc = GeometryCollection::from(point!(x: 1.0, y: 1.0));
+    /// gc.insert(0, point!(x: 0.0, y: 0.0));
+    ///
+    /// assert_eq!(gc[0], Geometry::Point(point!(x: 0.0, y: 0.0)));
+    /// assert_eq!(gc[1], Geometry::Point(point!(x: 1.0, y: 1.0)));
+    /// ```
+    pub fn insert(&mut self, index: usize, geometry: impl Into<Geometry<T>>) {
+        self.0.insert(index, geometry.into());
+    }
+
+    /// Remove and return the Geometry at position `index`, shifting all geometries after it to
+    /// the left
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let mut gc: GeometryCollection<f64> =
+    ///     vec![point!(x: 0.0, y: 0.0), point!(x: 1.0, y: 1.0)].into_iter().collect();
+    ///
+    /// assert_eq!(gc.remove(0), Geometry::Point(point!(x: 0.0, y: 0.0)));
+    /// assert_eq!(gc.len(), 1);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Geometry<T> {
+        self.0.remove(index)
+    }
+
+    /// Remove the last Geometry and return it, or `None` if this GeometryCollection is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let mut gc = GeometryCollection::from(point!(x: 1.0, y: 1.0));
+    ///
+    /// assert_eq!(gc.pop(), Some(Geometry::Point(point!(x: 1.0, y: 1.0))));
+    /// assert_eq!(gc.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<Geometry<T>> {
+        self.0.pop()
+    }
+
+    /// Remove all geometries from this GeometryCollection
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Return the bounding rectangle of all the geometries in this GeometryCollection, or `None`
+    /// if they have no coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, point, Coordinate, GeometryCollection};
+    ///
+    /// let gc: GeometryCollection<i32> = GeometryCollection(vec![
+    ///     point!(x: 5, y: 5).into(),
+    ///     line_string![(x: 0, y: 1), (x: 2, y: 3)].into(),
+    /// ]);
+    /// let rect = gc.bounding_rect().unwrap();
+    ///
+    /// assert_eq!(rect.min(), Coordinate { x: 0, y: 1 });
+    /// assert_eq!(rect.max(), Coordinate { x: 5, y: 5 });
+    /// assert!(GeometryCollection::<i32>::new().bounding_rect().is_none());
+    /// ```
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(
+            self.0
+                .iter()
+                .filter_map(|g| g.bounding_rect())
+                .flat_map(|r| vec![r.min(), r.max()]),
+        )
+    }
+
+    /// Build a [`GeometryCollectionIndex`](struct.GeometryCollectionIndex.html) of the bounding
+    /// rectangles of the geometries in this GeometryCollection, for fast spatial queries
+    ///
+    /// Building the index takes `O(n log n)` time, after which each query only visits the parts
+    /// of the collection near the query.
+    pub fn build_index(&self) -> GeometryCollectionIndex<'_, T> {
+        GeometryCollectionIndex::new(self)
+    }
+
+    /// Iterate over all the geometries in this GeometryCollection and any GeometryCollections
+    /// nested inside it, at any depth, skipping the nested collections themselves
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let inner = GeometryCollection::from(point!(x: 1, y: 1));
+    /// let gc = GeometryCollection(vec![
+    ///     point!(x: 0, y: 0).into(),
+    ///     Geometry::GeometryCollection(GeometryCollection::from(inner)),
+    ///     point!(x: 2, y: 2).into(),
+    /// ]);
+    ///
+    /// let leaves: Vec<_> = gc.flatten().collect();
+    /// assert_eq!(leaves.len(), 3);
+    /// assert_eq!(leaves[1], &Geometry::Point(point!(x: 1, y: 1)));
+    /// ```
+    pub fn flatten(&self) -> impl Iterator<Item = &Geometry<T>> {
+        Flatten {
+            stack: vec![self.0.iter()],
+        }
+    }
+
+    /// Consume this GeometryCollection, returning a single-level GeometryCollection of all the
+    /// geometries in it and in any GeometryCollections nested inside it, in the same order as
+    /// [`flatten`](#method.flatten)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let inner = GeometryCollection::from(point!(x: 1, y: 1));
+    /// let gc = GeometryCollection(vec![
+    ///     Geometry::GeometryCollection(inner),
+    ///     point!(x: 2, y: 2).into(),
+    /// ]);
+    ///
+    /// let flat = gc.flatten_into();
+    /// assert_eq!(flat, GeometryCollection(vec![
+    ///     point!(x: 1, y: 1).into(),
+    ///     point!(x: 2, y: 2).into(),
+    /// ]));
+    /// ```
+    pub fn flatten_into(self) -> GeometryCollection<T> {
+        fn push_leaves<T: CoordinateType>(gc: GeometryCollection<T>, out: &mut Vec<Geometry<T>>) {
+            for geometry in gc.0 {
+                match geometry {
+                    Geometry::GeometryCollection(inner) => push_leaves(inner, out),
+                    leaf => out.push(leaf),
+                }
+            }
+        }
+        let mut leaves = Vec::with_capacity(self.len());
+        push_leaves(self, &mut leaves);
+        GeometryCollection(leaves)
+    }
+
+    /// Shorten this GeometryCollection, keeping the first `len` geometries and dropping the rest
+    ///
+    /// If `len` is greater than the current length, this has no effect.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+impl<T: CoordinateType> Default for GeometryCollection<T> {
+    fn default() -> Self {
+        GeometryCollection::new()
+    }
+}
+
+/// Append Geometries (or what can be converted to a Geometry) to a GeometryCollection
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, point, GeometryCollection};
+///
+/// let mut gc = GeometryCollection::from(point!(x: 1.0, y: 1.0));
+/// gc.extend(vec![point!(x: 2.0, y: 2.0), point!(x: 3.0, y: 3.0)]);
+/// gc.extend(vec![line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)]]);
+///
+/// assert_eq!(gc.len(), 4);
+/// ```
+impl<T: CoordinateType, IG: Into<Geometry<T>>> Extend<IG> for GeometryCollection<T> {
+    fn extend<I: IntoIterator<Item = IG>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(|g| g.into()));
+    }
 }
 
 /// Convert any Geometry (or anything that can be converted to a Geometry) into a
 /// GeometryCollection
+///
+/// A `Geometry::GeometryCollection` is wrapped too, becoming the only member of a new
+/// collection: use `Geometry::into_geometry_collection` to unwrap it instead.
 impl<T: CoordinateType, IG: Into<Geometry<T>>> From<IG> for GeometryCollection<T> {
     fn from(x: IG) -> Self {
         GeometryCollection(vec![x.into()])
@@ -114,9 +352,31 @@ impl<T: CoordinateType> IndexMut<usize> for GeometryCollection<T> {
     }
 }
 
+// depth-first iterator over the non-collection members of nested GeometryCollections
+struct Flatten<'a, T: CoordinateType> {
+    stack: Vec<::core::slice::Iter<'a, Geometry<T>>>,
+}
+
+impl<'a, T: CoordinateType> Iterator for Flatten<'a, T> {
+    type Item = &'a Geometry<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(iter) = self.stack.last_mut() {
+            match iter.next() {
+                Some(Geometry::GeometryCollection(inner)) => self.stack.push(inner.0.iter()),
+                Some(leaf) => return Some(leaf),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
 // structure helper for consuming iterator
 pub struct IntoIteratorHelper<T: CoordinateType> {
-    iter: ::std::vec::IntoIter<Geometry<T>>,
+    iter: ::alloc::vec::IntoIter<Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a consuming iterator. Iteration will
@@ -145,7 +405,7 @@ impl<T: CoordinateType> Iterator for IntoIteratorHelper<T> {
 
 // structure helper for non-consuming iterator
 pub struct IterHelper<'a, T: CoordinateType> {
-    iter: ::std::slice::Iter<'a, Geometry<T>>,
+    iter: ::core::slice::Iter<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a non-consuming iterator. Iteration will
@@ -174,7 +434,7 @@ impl<'a, T: CoordinateType> Iterator for IterHelper<'a, T> {
 
 // structure helper for mutable non-consuming iterator
 pub struct IterMutHelper<'a, T: CoordinateType> {
-    iter: ::std::slice::IterMut<'a, Geometry<T>>,
+    iter: ::core::slice::IterMut<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a mutable non-consuming iterator. Iteration will
@@ -210,3 +470,103 @@ impl<'a, T: CoordinateType> GeometryCollection<T> {
         self.into_iter()
     }
 }
+
+macro_rules! typed_iterators {
+    ($($variant:ident, $type_name:literal, $iter:ident, $iter_mut:ident;)*) => {
+        impl<T: CoordinateType> GeometryCollection<T> {
+            $(
+                #[doc = concat!("Iterate over the `", $type_name, "` members of this GeometryCollection, skipping all others")]
+                ///
+                /// Nested GeometryCollections are not searched.
+                pub fn $iter(&self) -> impl Iterator<Item = &$variant<T>> {
+                    self.0.iter().filter_map(|g| match g {
+                        Geometry::$variant(x) => Some(x),
+                        _ => None,
+                    })
+                }
+
+                #[doc = concat!("Mutably iterate over the `", $type_name, "` members of this GeometryCollection, skipping all others")]
+                ///
+                /// Nested GeometryCollections are not searched.
+                pub fn $iter_mut(&mut self) -> impl Iterator<Item = &mut $variant<T>> {
+                    self.0.iter_mut().filter_map(|g| match g {
+                        Geometry::$variant(x) => Some(x),
+                        _ => None,
+                    })
+                }
+            )*
+        }
+    };
+}
+
+typed_iterators! {
+    Point, "Point", points, points_mut;
+    Line, "Line", lines, lines_mut;
+    LineString, "LineString", line_strings, line_strings_mut;
+    Polygon, "Polygon", polygons, polygons_mut;
+    MultiPoint, "MultiPoint", multi_points, multi_points_mut;
+    MultiLineString, "MultiLineString", multi_line_strings, multi_line_strings_mut;
+    MultiPolygon, "MultiPolygon", multi_polygons, multi_polygons_mut;
+    GeometryCollection, "GeometryCollection", geometry_collections, geometry_collections_mut;
+    Rect, "Rect", rects, rects_mut;
+    Triangle, "Triangle", triangles, triangles_mut;
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for GeometryCollection<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for GeometryCollection<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for GeometryCollection<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Coordinate, CoordinateType, Geometry, GeometryCollection, PackedRTree, Rect};
+
+/// A static, packed tree of the bounding rectangles of the geometries in a
+/// [`GeometryCollection`](struct.GeometryCollection.html), for finding the geometries near a
+/// rectangle or point without scanning the whole collection.
+///
+/// It's created by [`GeometryCollection::build_index`](struct.GeometryCollection.html#method.build_index),
+/// and borrows the collection, so the collection can't be modified while the index exists.
+///
+/// Queries only compare bounding rectangles: they return every geometry whose bounding rectangle
+/// intersects the query, which may include geometries that don't intersect it themselves.
+/// Geometries without any coordinates are never returned. The index is a
+/// [`PackedRTree`](struct.PackedRTree.html) of the collection's geometries.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, Coordinate, GeometryCollection, Rect};
+///
+/// let gc: GeometryCollection<i32> = (0..1000).map(|i| point!(x: i, y: i)).collect();
+/// let index = gc.build_index();
+///
+/// let rect = Rect::new(Coordinate { x: 10, y: 10 }, Coordinate { x: 12, y: 20 });
+/// assert_eq!(index.query_rect(&rect).count(), 3);
+/// assert_eq!(index.query_point(&Coordinate { x: 5, y: 5 }).next(), Some(&gc[5]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GeometryCollectionIndex<'a, T>
+where
+    T: CoordinateType,
+{
+    tree: PackedRTree<'a, T, Geometry<T>>,
+}
+
+impl<'a, T: CoordinateType> GeometryCollectionIndex<'a, T> {
+    pub(crate) fn new(collection: &'a GeometryCollection<T>) -> Self {
+        GeometryCollectionIndex {
+            tree: PackedRTree::new(&collection.0),
+        }
+    }
+
+    /// Return the geometries whose bounding rectangle intersects `rect`, in the order they
+    /// appear in the collection.
+    pub fn query_rect(&self, rect: &Rect<T>) -> impl Iterator<Item = &'a Geometry<T>> {
+        self.tree.query_rect(rect)
+    }
+
+    /// Return the geometries whose bounding rectangle contains `coord`, in the order they appear
+    /// in the collection.
+    pub fn query_point(&self, coord: &Coordinate<T>) -> impl Iterator<Item = &'a Geometry<T>> {
+        self.tree.query_point(coord)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::packed_rtree::intersects;
+    use crate::{line_string, point, LineString};
+
+    #[test]
+    fn matches_linear_scan() {
+        // deterministic pseudo-random line strings
+        let mut seed = 12345u64;
+        let mut random = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as i64 % 1000
+        };
+        let mut gc = GeometryCollection::new();
+        for _ in 0..2000 {
+            let (x, y) = (random(), random());
+            gc.push(line_string![(x: x, y: y), (x: x + random() / 20, y: y - random() / 20)]);
+        }
+        gc.push(LineString::<i64>(vec![]));
+        let index = gc.build_index();
+        for _ in 0..100 {
+            let (x, y) = (random(), random());
+            let rect = Rect::new((x, y), (x + random() / 10, y + random() / 10));
+            let expected: Vec<_> = gc
+                .iter()
+                .filter(|g| g.bounding_rect().is_some_and(|r| intersects(&r, &rect)))
+                .collect();
+            assert_eq!(index.query_rect(&rect).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn small_collections() {
+        let empty = GeometryCollection::<f64>::new();
+        let rect = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(empty.build_index().query_rect(&rect).count(), 0);
+
+        let single = GeometryCollection::from(point!(x: 0.5, y: 0.5));
+        let index = single.build_index();
+        assert_eq!(index.query_rect(&rect).count(), 1);
+        assert_eq!(index.query_point(&Coordinate { x: 0.5, y: 0.5 }).count(), 1);
+        assert_eq!(index.query_point(&Coordinate { x: 0.6, y: 0.5 }).count(), 0);
+    }
+}
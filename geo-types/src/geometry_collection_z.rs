@@ -0,0 +1,103 @@
+use crate::{CoordinateType, GeometryCollection, GeometryZ};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
+
+/// A collection of [`GeometryZ`](enum.GeometryZ.html) types.
+///
+/// This is the `z`-aware counterpart of [`GeometryCollection`](struct.GeometryCollection.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{GeometryCollectionZ, GeometryZ, PointZ};
+///
+/// let mut gc = GeometryCollectionZ::new();
+/// gc.push(PointZ::new(1., 2., 3.));
+///
+/// assert_eq!(gc[0], GeometryZ::Point(PointZ::new(1., 2., 3.)));
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub struct GeometryCollectionZ<T>(pub Vec<GeometryZ<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> GeometryCollectionZ<T> {
+    /// Return an empty GeometryCollectionZ
+    pub fn new() -> GeometryCollectionZ<T> {
+        GeometryCollectionZ(Vec::new())
+    }
+
+    /// Number of geometries in this GeometryCollectionZ
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Is this GeometryCollectionZ empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Append a GeometryZ (or anything that can be converted to a GeometryZ) to the end of this
+    /// GeometryCollectionZ
+    pub fn push(&mut self, geometry: impl Into<GeometryZ<T>>) {
+        self.0.push(geometry.into());
+    }
+
+    pub fn iter(&self) -> ::core::slice::Iter<'_, GeometryZ<T>> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> ::core::slice::IterMut<'_, GeometryZ<T>> {
+        self.0.iter_mut()
+    }
+
+    /// Drop the `z` component of every coordinate, returning the 2D `GeometryCollection`.
+    pub fn to_2d(&self) -> GeometryCollection<T> {
+        GeometryCollection(self.0.iter().map(|g| g.to_2d()).collect())
+    }
+}
+
+impl<T: CoordinateType> Default for GeometryCollectionZ<T> {
+    fn default() -> Self {
+        GeometryCollectionZ::new()
+    }
+}
+
+/// Convert any GeometryZ (or anything that can be converted to a GeometryZ) into a
+/// GeometryCollectionZ
+impl<T: CoordinateType, IG: Into<GeometryZ<T>>> From<IG> for GeometryCollectionZ<T> {
+    fn from(x: IG) -> Self {
+        GeometryCollectionZ(vec![x.into()])
+    }
+}
+
+/// Collect GeometryZs (or what can be converted to a GeometryZ) into a GeometryCollectionZ
+impl<T: CoordinateType, IG: Into<GeometryZ<T>>> FromIterator<IG> for GeometryCollectionZ<T> {
+    fn from_iter<I: IntoIterator<Item = IG>>(iter: I) -> Self {
+        GeometryCollectionZ(iter.into_iter().map(|g| g.into()).collect())
+    }
+}
+
+impl<T: CoordinateType> Index<usize> for GeometryCollectionZ<T> {
+    type Output = GeometryZ<T>;
+
+    fn index(&self, index: usize) -> &GeometryZ<T> {
+        self.0.index(index)
+    }
+}
+
+impl<T: CoordinateType> IndexMut<usize> for GeometryCollectionZ<T> {
+    fn index_mut(&mut self, index: usize) -> &mut GeometryZ<T> {
+        self.0.index_mut(index)
+    }
+}
+
+impl<T: CoordinateType> IntoIterator for GeometryCollectionZ<T> {
+    type Item = GeometryZ<T>;
+    type IntoIter = ::alloc::vec::IntoIter<GeometryZ<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    CoordinateType, Error, Geometry, GeometryCollectionZ, LineStringZ, MultiLineStringZ,
+    MultiPointZ, MultiPolygonZ, PointZ, PolygonZ,
+};
+use core::convert::TryFrom;
+
+/// An enum representing any possible 3D geometry type.
+///
+/// This is the `z`-aware counterpart of [`Geometry`](enum.Geometry.html). All `*Z` types can be
+/// converted to a `GeometryZ` member using `.into()`, and implement `TryFrom` in order to
+/// convert _back_ from enum members.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use geo_types::{GeometryZ, PointZ};
+/// let p = PointZ::new(1.0, 1.0, 1.0);
+/// let pe: GeometryZ<f64> = p.into();
+/// let pn = PointZ::try_from(pe).unwrap();
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub enum GeometryZ<T>
+where
+    T: CoordinateType,
+{
+    Point(PointZ<T>),
+    LineString(LineStringZ<T>),
+    Polygon(PolygonZ<T>),
+    MultiPoint(MultiPointZ<T>),
+    MultiLineString(MultiLineStringZ<T>),
+    MultiPolygon(MultiPolygonZ<T>),
+    GeometryCollection(GeometryCollectionZ<T>),
+}
+
+impl<T: CoordinateType> GeometryZ<T> {
+    /// The name of the type of geometry this holds, e.g. `"PointZ"`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            GeometryZ::Point(_) => "PointZ",
+            GeometryZ::LineString(_) => "LineStringZ",
+            GeometryZ::Polygon(_) => "PolygonZ",
+            GeometryZ::MultiPoint(_) => "MultiPointZ",
+            GeometryZ::MultiLineString(_) => "MultiLineStringZ",
+            GeometryZ::MultiPolygon(_) => "MultiPolygonZ",
+            GeometryZ::GeometryCollection(_) => "GeometryCollectionZ",
+        }
+    }
+
+    /// Drop the `z` component of every coordinate, returning the 2D `Geometry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Geometry, GeometryZ, Point, PointZ};
+    ///
+    /// let g = GeometryZ::Point(PointZ::new(1., 2., 3.));
+    ///
+    /// assert_eq!(g.to_2d(), Geometry::Point(Point::new(1., 2.)));
+    /// ```
+    pub fn to_2d(&self) -> Geometry<T> {
+        match self {
+            GeometryZ::Point(x) => Geometry::Point(x.to_2d()),
+            GeometryZ::LineString(x) => Geometry::LineString(x.to_2d()),
+            GeometryZ::Polygon(x) => Geometry::Polygon(x.to_2d()),
+            GeometryZ::MultiPoint(x) => Geometry::MultiPoint(x.to_2d()),
+            GeometryZ::MultiLineString(x) => Geometry::MultiLineString(x.to_2d()),
+            GeometryZ::MultiPolygon(x) => Geometry::MultiPolygon(x.to_2d()),
+            GeometryZ::GeometryCollection(x) => Geometry::GeometryCollection(x.to_2d()),
+        }
+    }
+}
+
+macro_rules! geometry_z_conversions {
+    ($($variant:ident($ty:ident)),*) => {
+        $(
+            impl<T: CoordinateType> From<$ty<T>> for GeometryZ<T> {
+                fn from(x: $ty<T>) -> GeometryZ<T> {
+                    GeometryZ::$variant(x)
+                }
+            }
+
+            impl<T: CoordinateType> TryFrom<GeometryZ<T>> for $ty<T> {
+                type Error = Error;
+
+                fn try_from(geom: GeometryZ<T>) -> Result<$ty<T>, Self::Error> {
+                    match geom {
+                        GeometryZ::$variant(x) => Ok(x),
+                        other => Err(Error::MismatchedGeometry {
+                            expected: stringify!($ty),
+                            found: other.type_name(),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+geometry_z_conversions!(
+    Point(PointZ),
+    LineString(LineStringZ),
+    Polygon(PolygonZ),
+    MultiPoint(MultiPointZ),
+    MultiLineString(MultiLineStringZ),
+    MultiPolygon(MultiPolygonZ)
+);
@@ -0,0 +1,361 @@
+//! Conversions to and from the geometries of [GEOS](https://libgeos.org), through the
+//! [`geos`](https://docs.rs/geos) crate, for operations this crate doesn't offer.
+//!
+//! With the `geos` feature enabled, every geometry with `f64` coordinates converts to a
+//! `geos::Geometry` using `TryFrom`, and a `geos::Geometry` or `geos::ConstGeometry` converts
+//! back to a [`Geometry`](../enum.Geometry.html), including nested geometry collections. GEOS
+//! has no line, rectangle, or triangle types, so a `Line` converts to a line string, and a
+//! `Rect` or `Triangle` to a polygon. GEOS requires the rings of polygons to be closed, so
+//! rings are closed when converting them, and a GEOS linear ring converts to a `LineString`.
+//! An empty GEOS point converts to a `Point` with NaN coordinates, and back.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{polygon, Geometry};
+//! use geos::Geom;
+//! use core::convert::TryFrom;
+//!
+//! let square: Geometry<f64> =
+//!     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into();
+//! let geos_square = geos::Geometry::try_from(&square).unwrap();
+//! let buffered = geos_square.buffer(1., 8).unwrap();
+//!
+//! let back = Geometry::try_from(&buffered).unwrap();
+//! assert!(matches!(back, Geometry::Polygon(_)));
+//! ```
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use ::geos::{
+    ConstGeometry, CoordDimensions, CoordSeq, Error, GResult, Geom, Geometry as GGeometry,
+    GeometryTypes,
+};
+use core::convert::TryFrom;
+
+fn to_coord_seq<'a>(coords: &[Coordinate<f64>]) -> GResult<CoordSeq<'a>> {
+    let mut coord_seq = CoordSeq::new(coords.len() as u32, CoordDimensions::TwoD)?;
+    for (i, coord) in coords.iter().enumerate() {
+        coord_seq.set_x(i, coord.x)?;
+        coord_seq.set_y(i, coord.y)?;
+    }
+    Ok(coord_seq)
+}
+
+fn to_geos_point<'a>(point: &Point<f64>) -> GResult<GGeometry<'a>> {
+    if point.is_empty() {
+        return GGeometry::create_empty_point();
+    }
+    GGeometry::create_point(to_coord_seq(&[point.0])?)
+}
+
+fn to_geos_line_string<'a>(coords: &[Coordinate<f64>]) -> GResult<GGeometry<'a>> {
+    GGeometry::create_line_string(to_coord_seq(coords)?)
+}
+
+fn to_geos_linear_ring<'a>(ring: &LineString<f64>) -> GResult<GGeometry<'a>> {
+    match (ring.0.first(), ring.0.last()) {
+        (Some(first), Some(last)) if first != last => {
+            let mut coords = ring.0.clone();
+            coords.push(*first);
+            GGeometry::create_linear_ring(to_coord_seq(&coords)?)
+        }
+        _ => GGeometry::create_linear_ring(to_coord_seq(&ring.0)?),
+    }
+}
+
+fn to_geos_polygon<'a>(polygon: &Polygon<f64>) -> GResult<GGeometry<'a>> {
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(to_geos_linear_ring)
+        .collect::<GResult<Vec<_>>>()?;
+    GGeometry::create_polygon(to_geos_linear_ring(polygon.exterior())?, interiors)
+}
+
+fn to_geos_triangle<'a>(triangle: &Triangle<f64>) -> GResult<GGeometry<'a>> {
+    to_geos_polygon(&Polygon::from(*triangle))
+}
+
+fn to_geos_multi_point<'a>(multi_point: &MultiPoint<f64>) -> GResult<GGeometry<'a>> {
+    let points = multi_point
+        .0
+        .iter()
+        .map(to_geos_point)
+        .collect::<GResult<Vec<_>>>()?;
+    GGeometry::create_multipoint(points)
+}
+
+fn to_geos_multi_line_string<'a>(
+    multi_line_string: &MultiLineString<f64>,
+) -> GResult<GGeometry<'a>> {
+    let line_strings = multi_line_string
+        .0
+        .iter()
+        .map(|line_string| to_geos_line_string(&line_string.0))
+        .collect::<GResult<Vec<_>>>()?;
+    GGeometry::create_multiline_string(line_strings)
+}
+
+fn to_geos_multi_polygon<'a>(multi_polygon: &MultiPolygon<f64>) -> GResult<GGeometry<'a>> {
+    let polygons = multi_polygon
+        .0
+        .iter()
+        .map(to_geos_polygon)
+        .collect::<GResult<Vec<_>>>()?;
+    GGeometry::create_multipolygon(polygons)
+}
+
+fn to_geos_geometry_collection<'a>(
+    geometry_collection: &GeometryCollection<f64>,
+) -> GResult<GGeometry<'a>> {
+    let geometries = geometry_collection
+        .0
+        .iter()
+        .map(to_geos_geometry)
+        .collect::<GResult<Vec<_>>>()?;
+    GGeometry::create_geometry_collection(geometries)
+}
+
+fn to_geos_geometry<'a>(geometry: &Geometry<f64>) -> GResult<GGeometry<'a>> {
+    match geometry {
+        Geometry::Point(g) => to_geos_point(g),
+        Geometry::Line(g) => to_geos_line_string(&[g.start, g.end]),
+        Geometry::LineString(g) => to_geos_line_string(&g.0),
+        Geometry::Polygon(g) => to_geos_polygon(g),
+        Geometry::MultiPoint(g) => to_geos_multi_point(g),
+        Geometry::MultiLineString(g) => to_geos_multi_line_string(g),
+        Geometry::MultiPolygon(g) => to_geos_multi_polygon(g),
+        Geometry::GeometryCollection(g) => to_geos_geometry_collection(g),
+        Geometry::Rect(g) => to_geos_polygon(&Polygon::from(*g)),
+        Geometry::Triangle(g) => to_geos_triangle(g),
+    }
+}
+
+macro_rules! impl_try_from_geo {
+    ($($type:ident => $convert:expr),*) => {
+        $(
+            impl<'a, 'b> TryFrom<&'b $type<f64>> for GGeometry<'a> {
+                type Error = Error;
+
+                fn try_from(geometry: &'b $type<f64>) -> GResult<GGeometry<'a>> {
+                    $convert(geometry)
+                }
+            }
+
+            impl<'a> TryFrom<$type<f64>> for GGeometry<'a> {
+                type Error = Error;
+
+                fn try_from(geometry: $type<f64>) -> GResult<GGeometry<'a>> {
+                    GGeometry::try_from(&geometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_geo!(
+    Point => to_geos_point,
+    Line => |line: &Line<f64>| to_geos_line_string(&[line.start, line.end]),
+    LineString => |line_string: &LineString<f64>| to_geos_line_string(&line_string.0),
+    Polygon => to_geos_polygon,
+    MultiPoint => to_geos_multi_point,
+    MultiLineString => to_geos_multi_line_string,
+    MultiPolygon => to_geos_multi_polygon,
+    GeometryCollection => to_geos_geometry_collection,
+    Rect => |rect: &Rect<f64>| to_geos_polygon(&Polygon::from(*rect)),
+    Triangle => to_geos_triangle,
+    Geometry => to_geos_geometry
+);
+
+fn from_coord_seq<'a, G: Geom<'a>>(geometry: &G) -> GResult<Vec<Coordinate<f64>>> {
+    let coord_seq = geometry.get_coord_seq()?;
+    (0..coord_seq.size()?)
+        .map(|i| {
+            Ok(Coordinate {
+                x: coord_seq.get_x(i)?,
+                y: coord_seq.get_y(i)?,
+            })
+        })
+        .collect()
+}
+
+fn from_geos_point<'a, G: Geom<'a>>(geometry: &G) -> GResult<Point<f64>> {
+    if geometry.is_empty()? {
+        return Ok(Point::empty());
+    }
+    Ok(Point(from_coord_seq(geometry)?[0]))
+}
+
+fn from_geos_polygon<'a, G: Geom<'a>>(geometry: &G) -> GResult<Polygon<f64>> {
+    if geometry.is_empty()? {
+        return Ok(Polygon::new(LineString(vec![]), vec![]));
+    }
+    let exterior = LineString(from_coord_seq(&geometry.get_exterior_ring()?)?);
+    let interiors = (0..geometry.get_num_interior_rings()?)
+        .map(|i| {
+            Ok(LineString(from_coord_seq(
+                &geometry.get_interior_ring_n(i as u32)?,
+            )?))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(Polygon::new(exterior, interiors))
+}
+
+// Convert each member of a GEOS collection
+fn from_geos_members<'a, G, T, F>(geometry: &G, convert: F) -> GResult<Vec<T>>
+where
+    G: Geom<'a>,
+    F: Fn(&ConstGeometry<'a, '_>) -> GResult<T>,
+{
+    (0..geometry.get_num_geometries()?)
+        .map(|i| convert(&geometry.get_geometry_n(i)?))
+        .collect()
+}
+
+fn from_geos_geometry<'a, G: Geom<'a>>(geometry: &G) -> GResult<Geometry<f64>> {
+    Ok(match geometry.geometry_type() {
+        GeometryTypes::Point => Geometry::Point(from_geos_point(geometry)?),
+        GeometryTypes::LineString | GeometryTypes::LinearRing => {
+            Geometry::LineString(LineString(from_coord_seq(geometry)?))
+        }
+        GeometryTypes::Polygon => Geometry::Polygon(from_geos_polygon(geometry)?),
+        GeometryTypes::MultiPoint => {
+            Geometry::MultiPoint(MultiPoint(from_geos_members(geometry, |point| {
+                from_geos_point(point)
+            })?))
+        }
+        GeometryTypes::MultiLineString => Geometry::MultiLineString(MultiLineString(
+            from_geos_members(geometry, |line_string| {
+                Ok(LineString(from_coord_seq(line_string)?))
+            })?,
+        )),
+        GeometryTypes::MultiPolygon => {
+            Geometry::MultiPolygon(MultiPolygon(from_geos_members(geometry, |polygon| {
+                from_geos_polygon(polygon)
+            })?))
+        }
+        GeometryTypes::GeometryCollection => Geometry::GeometryCollection(GeometryCollection(
+            from_geos_members(geometry, |member| from_geos_geometry(member))?,
+        )),
+        _ => {
+            return Err(Error::ConversionError(
+                "unknown GEOS geometry type".to_owned(),
+            ))
+        }
+    })
+}
+
+impl<'a, 'b> TryFrom<&'b GGeometry<'a>> for Geometry<f64> {
+    type Error = Error;
+
+    fn try_from(geometry: &'b GGeometry<'a>) -> GResult<Geometry<f64>> {
+        from_geos_geometry(geometry)
+    }
+}
+
+impl<'a> TryFrom<GGeometry<'a>> for Geometry<f64> {
+    type Error = Error;
+
+    fn try_from(geometry: GGeometry<'a>) -> GResult<Geometry<f64>> {
+        from_geos_geometry(&geometry)
+    }
+}
+
+impl<'a, 'b, 'c> TryFrom<&'b ConstGeometry<'a, 'c>> for Geometry<f64> {
+    type Error = Error;
+
+    fn try_from(geometry: &'b ConstGeometry<'a, 'c>) -> GResult<Geometry<f64>> {
+        from_geos_geometry(geometry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn round_trip(geometry: Geometry<f64>) -> Geometry<f64> {
+        let geos_geometry = GGeometry::try_from(&geometry).unwrap();
+        Geometry::try_from(&geos_geometry).unwrap()
+    }
+
+    #[test]
+    fn every_variant_round_trips() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)],
+            interiors: [[(x: 5., y: 2.), (x: 8., y: 2.), (x: 8., y: 5.), (x: 5., y: 2.)]],
+        ];
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 2.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)].into(),
+            polygon.clone().into(),
+            MultiPoint(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into(),
+            MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]).into(),
+            MultiPolygon(vec![polygon.clone(), polygon]).into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 1., y: 2.).into(),
+                Geometry::GeometryCollection(GeometryCollection(vec![line_string![
+                    (x: 0., y: 0.),
+                    (x: 1., y: 1.)
+                ]
+                .into()])),
+            ])),
+        ];
+        for geometry in geometries {
+            assert_eq!(round_trip(geometry.clone()), geometry);
+        }
+    }
+
+    #[test]
+    fn geometries_without_a_geos_type() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(
+            round_trip(line.into()),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()
+        );
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 2. });
+        assert_eq!(round_trip(rect.into()), Polygon::from(rect).into());
+
+        // open rings are closed
+        let open = Polygon::new(LineString::from(vec![(0., 0.), (1., 0.), (1., 1.)]), vec![]);
+        let closed: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)].into();
+        assert_eq!(round_trip(open.into()), closed);
+        let triangle = Triangle(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 1., y: 1. },
+        );
+        assert_eq!(round_trip(triangle.into()), closed);
+    }
+
+    #[test]
+    fn reading_geos_geometries() {
+        let geos_geometry = GGeometry::new_from_wkt("LINEARRING (0 0, 1 0, 1 1, 0 0)").unwrap();
+        assert_eq!(
+            Geometry::try_from(geos_geometry).unwrap(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)].into()
+        );
+        let empty = GGeometry::new_from_wkt("POLYGON EMPTY").unwrap();
+        assert_eq!(
+            Geometry::try_from(&empty).unwrap(),
+            Polygon::new(LineString(vec![]), vec![]).into()
+        );
+        let empty_point = GGeometry::new_from_wkt("POINT EMPTY").unwrap();
+        let point = match Geometry::try_from(&empty_point).unwrap() {
+            Geometry::Point(point) => point,
+            geometry => panic!("expected a point, got {:?}", geometry),
+        };
+        assert!(point.is_empty());
+        assert!(GGeometry::try_from(&point).unwrap().is_empty().unwrap());
+
+        let collection = GGeometry::new_from_wkt("MULTIPOINT ((1 2), (3 4))").unwrap();
+        let member = collection.get_geometry_n(1).unwrap();
+        assert_eq!(
+            Geometry::try_from(&member).unwrap(),
+            point!(x: 3., y: 4.).into()
+        );
+    }
+}
@@ -0,0 +1,846 @@
+//! Reading and writing [GML](https://www.ogc.org/standards/gml/) 3 geometries.
+//!
+//! With the `gml` feature enabled, [`read_geometries`](fn.read_geometries.html) reads the
+//! geometries of a GML document, such as a WFS feature collection or a cadastral data set,
+//! wherever they are in it, and
+//! [`Geometry::from_gml`](../enum.Geometry.html#method.from_gml) and
+//! [`Geometry::to_gml`](../enum.Geometry.html#method.to_gml) read and write a single geometry
+//! element.
+//!
+//! Geometries are read from GML 3.1 and 3.2, and, where they share elements, GML 2:
+//!
+//! * `Point`s convert to `Point`s, with their `pos`, or GML 2 `coordinates`.
+//! * `LineString`s, `LinearRing`s, and `Curve`s with `LineStringSegment`s convert to
+//!   `LineString`s, with their `posList`, `pos`, or `coordinates`.
+//! * `Polygon`s, and `Surface`s with a single `PolygonPatch`, convert to `Polygon`s, with
+//!   `LinearRing`s or `Ring`s as their exterior and interiors.
+//! * `MultiPoint`s, `MultiCurve`s and `MultiLineString`s, and `MultiSurface`s and
+//!   `MultiPolygon`s convert to `MultiPoint`s, `MultiLineString`s, and `MultiPolygon`s.
+//! * `MultiGeometry`s convert to `GeometryCollection`s, which can nest.
+//!
+//! Other geometries, such as arcs and solids, aren't supported. Geometries are written as GML
+//! 3.1, in the `http://www.opengis.net/gml` namespace, with the `gml` prefix: a `Line` is
+//! written as a `LineString`, a `Rect` or `Triangle` as a `Polygon`, multi-line strings as
+//! `MultiCurve`s, and multi-polygons as `MultiSurface`s.
+//!
+//! Coordinates are read in the order they are written in, as `x` and then `y`, whatever the
+//! axis order of their coordinate reference system, which isn't read: a geometry in EPSG:4326,
+//! for example, has its latitudes as `x`. Any dimensions after the first two, as given by
+//! `srsDimension` attributes, are ignored. Coordinates are converted to and from `f64`s using
+//! `NumCast`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::gml::{self, GmlError};
+//! use geo_types::{polygon, Geometry, MultiPolygon};
+//!
+//! let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+//! <wfs:FeatureCollection xmlns:wfs="http://www.opengis.net/wfs/2.0"
+//!     xmlns:gml="http://www.opengis.net/gml/3.2" xmlns:cp="urn:example:cadastre">
+//!   <wfs:member>
+//!     <cp:Parcel gml:id="parcel.1">
+//!       <cp:label>12/3</cp:label>
+//!       <cp:geometry>
+//!         <gml:MultiSurface gml:id="parcel.1.geometry" srsName="EPSG:2056">
+//!           <gml:surfaceMember>
+//!             <gml:Polygon gml:id="parcel.1.polygon">
+//!               <gml:exterior>
+//!                 <gml:LinearRing>
+//!                   <gml:posList>2600000 1200000 2600010 1200000 2600010 1200010 2600000 1200000</gml:posList>
+//!                 </gml:LinearRing>
+//!               </gml:exterior>
+//!             </gml:Polygon>
+//!           </gml:surfaceMember>
+//!         </gml:MultiSurface>
+//!       </cp:geometry>
+//!     </cp:Parcel>
+//!   </wfs:member>
+//! </wfs:FeatureCollection>"#;
+//!
+//! let parcel = Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+//!     (x: 2600000., y: 1200000.),
+//!     (x: 2600010., y: 1200000.),
+//!     (x: 2600010., y: 1200010.),
+//! ]]));
+//! assert_eq!(gml::read_geometries(xml.as_bytes())?, vec![parcel.clone()]);
+//! assert_eq!(Geometry::from_gml(&parcel.to_gml())?, parcel);
+//! # Ok::<(), GmlError>(())
+//! ```
+use crate::xml::{self, Element, Namespace, XmlError};
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
+};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// The error returned when reading GML fails.
+#[derive(Debug)]
+pub enum GmlError {
+    /// Reading failed.
+    Io(io::Error),
+    /// The input isn't well-formed XML.
+    Xml(String),
+    /// The element with the given name isn't a GML geometry.
+    NotAGeometry(String),
+    /// The element with the given name is a GML geometry, or a part of one, which isn't
+    /// supported, such as an `Arc`.
+    UnsupportedGeometry(String),
+    /// An element is missing a child it needs, such as the `pos` of a `Point`.
+    MissingElement {
+        element: &'static str,
+        parent: String,
+    },
+    /// A member of a multi-geometry, or a part of a geometry, is a different kind of geometry
+    /// than it should be, such as a `Point` as a `curveMember`.
+    MismatchedType {
+        expected: &'static str,
+        found: String,
+    },
+    /// An `srsDimension` attribute isn't a number more than 1.
+    InvalidDimension(String),
+    /// The coordinates of a geometry can't be parsed, or don't have the right number of
+    /// values.
+    InvalidCoordinates(String),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+}
+
+impl fmt::Display for GmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GmlError::Io(e) => write!(f, "{}", e),
+            GmlError::Xml(e) => write!(f, "Invalid XML: {}", e),
+            GmlError::NotAGeometry(name) => write!(f, "'{}' isn't a GML geometry", name),
+            GmlError::UnsupportedGeometry(name) => {
+                write!(f, "Unsupported GML geometry '{}'", name)
+            }
+            GmlError::MissingElement { element, parent } => {
+                write!(f, "'{}' without a '{}' element", parent, element)
+            }
+            GmlError::MismatchedType { expected, found } => {
+                write!(f, "Expected a {}, found a '{}'", expected, found)
+            }
+            GmlError::InvalidDimension(dimension) => {
+                write!(f, "Invalid srsDimension '{}'", dimension)
+            }
+            GmlError::InvalidCoordinates(coordinates) => {
+                write!(f, "Invalid coordinates '{}'", coordinates)
+            }
+            GmlError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+        }
+    }
+}
+
+impl Error for GmlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GmlError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<XmlError> for GmlError {
+    fn from(e: XmlError) -> Self {
+        match e {
+            XmlError::Io(e) => GmlError::Io(e),
+            XmlError::Syntax(e) => GmlError::Xml(e),
+        }
+    }
+}
+
+/// The namespace geometries are written in.
+pub const NAMESPACE: &str = "http://www.opengis.net/gml";
+
+/// Read the supported geometries of a GML document, in the order they appear in it.
+///
+/// Only the outermost geometries are read, so the members of a multi-geometry aren't read
+/// separately. Elements which aren't supported geometries, such as features, their other
+/// properties, and envelopes, are skipped.
+///
+/// Geometries must be in a GML namespace, such as `http://www.opengis.net/gml/3.2`, or, in
+/// documents without namespaces, have the `gml` prefix or none.
+pub fn read_geometries<T: CoordinateType, R: BufRead>(
+    reader: R,
+) -> Result<Vec<Geometry<T>>, GmlError> {
+    let mut geometries = vec![];
+    xml::read(
+        reader,
+        |element| is_gml(element) && is_geometry(&element.name),
+        |element| {
+            geometries.push(read_geometry(&element, None)?);
+            Ok::<_, GmlError>(())
+        },
+    )?;
+    Ok(geometries)
+}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Read a GML geometry element, such as `<gml:Point><gml:pos>1 2</gml:pos></gml:Point>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry};
+    ///
+    /// let gml = r#"<gml:LineString xmlns:gml="http://www.opengis.net/gml/3.2" srsDimension="3">
+    ///     <gml:posList>1 2 0 3 4 0</gml:posList>
+    /// </gml:LineString>"#;
+    /// assert_eq!(
+    ///     Geometry::from_gml(gml).unwrap(),
+    ///     Geometry::LineString(line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+    /// );
+    /// ```
+    pub fn from_gml(gml: &str) -> Result<Geometry<T>, GmlError> {
+        let mut geometry = None;
+        xml::read(
+            gml.as_bytes(),
+            |_| true,
+            |element| {
+                geometry = Some(read_geometry(&element, None)?);
+                Ok::<_, GmlError>(())
+            },
+        )?;
+        geometry.ok_or_else(|| GmlError::Xml("no root element".to_string()))
+    }
+
+    /// Write this geometry as a GML 3.1 geometry element, declaring the `gml` prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// assert_eq!(
+    ///     Geometry::Point(point!(x: 1.5, y: -2.)).to_gml(),
+    ///     r#"<gml:Point xmlns:gml="http://www.opengis.net/gml"><gml:pos>1.5 -2</gml:pos></gml:Point>"#
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> String {
+        let mut gml = String::new();
+        write_geometry(&mut gml, self, true);
+        gml
+    }
+}
+
+// Reading
+
+fn is_gml(element: &Element) -> bool {
+    match &element.namespace {
+        Namespace::Uri(uri) => uri.starts_with(NAMESPACE),
+        Namespace::Unknown(prefix) => prefix == "gml",
+        Namespace::None => true,
+    }
+}
+
+fn is_geometry(name: &str) -> bool {
+    matches!(
+        name,
+        "Point"
+            | "LineString"
+            | "LinearRing"
+            | "Curve"
+            | "Polygon"
+            | "Surface"
+            | "MultiPoint"
+            | "MultiCurve"
+            | "MultiLineString"
+            | "MultiSurface"
+            | "MultiPolygon"
+            | "MultiGeometry"
+    )
+}
+
+fn missing(element: &'static str, parent: &Element) -> GmlError {
+    GmlError::MissingElement {
+        element,
+        parent: parent.name.clone(),
+    }
+}
+
+// The number of values of each position, from the element's `srsDimension`, or its ancestors'
+fn dimension(element: &Element, inherited: Option<usize>) -> Result<Option<usize>, GmlError> {
+    match element.attribute("srsDimension") {
+        Some(dimension) => match dimension.trim().parse() {
+            Ok(dimension) if dimension >= 2 => Ok(Some(dimension)),
+            _ => Err(GmlError::InvalidDimension(dimension.to_string())),
+        },
+        None => Ok(inherited),
+    }
+}
+
+fn coordinate<T: CoordinateType>(x: f64, y: f64) -> Result<Coordinate<T>, GmlError> {
+    let value = |v: f64| T::from(v).ok_or(GmlError::InvalidCoordinate(v));
+    Ok(Coordinate {
+        x: value(x)?,
+        y: value(y)?,
+    })
+}
+
+// The positions of a `pos` or `posList`, which have `srsDimension` values each, or, if it isn't
+// given, 2, or, for a `pos`, as many as there are
+fn read_positions<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<Vec<Coordinate<T>>, GmlError> {
+    let invalid = || GmlError::InvalidCoordinates(element.text.clone());
+    let numbers = element
+        .text
+        .split_whitespace()
+        .map(|number| number.parse().ok())
+        .collect::<Option<Vec<f64>>>()
+        .ok_or_else(invalid)?;
+    let is_pos = element.name == "pos";
+    let dimension = match dimension(element, inherited)? {
+        Some(dimension) => dimension,
+        None if is_pos => numbers.len().max(2),
+        None => 2,
+    };
+    if numbers.len() % dimension != 0 || (is_pos && numbers.len() != dimension) {
+        return Err(invalid());
+    }
+    numbers
+        .chunks(dimension)
+        .map(|position| coordinate(position[0], position[1]))
+        .collect()
+}
+
+// The coordinates of a point, line string, linear ring, or line string segment
+fn read_coordinates<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<Vec<Coordinate<T>>, GmlError> {
+    let dimension = dimension(element, inherited)?;
+    if let Some(pos_list) = element.child("posList") {
+        return read_positions(pos_list, dimension);
+    }
+    if let Some(coordinates) = element.child("coordinates") {
+        return xml::parse_tuples(&coordinates.text)
+            .ok_or_else(|| GmlError::InvalidCoordinates(coordinates.text.clone()))?
+            .into_iter()
+            .map(|(x, y)| coordinate(x, y))
+            .collect();
+    }
+    let mut coords = vec![];
+    for child in &element.children {
+        match child.name.as_str() {
+            "pos" => coords.extend(read_positions(child, dimension)?),
+            "pointProperty" | "pointRep" => {
+                let point = child
+                    .child("Point")
+                    .ok_or_else(|| missing("Point", child))?;
+                coords.push(read_point(point, dimension)?.0);
+            }
+            _ => {}
+        }
+    }
+    Ok(coords)
+}
+
+// Append the coordinates of a part of a line, without repeating the position it starts at
+fn join<T: CoordinateType>(coords: &mut Vec<Coordinate<T>>, part: Vec<Coordinate<T>>) {
+    let skip = match (coords.last(), part.first()) {
+        (Some(last), Some(first)) => (last == first) as usize,
+        _ => 0,
+    };
+    coords.extend(part.into_iter().skip(skip));
+}
+
+// The only geometry in a property, such as a `pointMember` or an `exterior`
+fn geometry_of<'a>(property: &'a Element, element: &'static str) -> Result<&'a Element, GmlError> {
+    property
+        .children
+        .first()
+        .ok_or_else(|| missing(element, property))
+}
+
+fn read_point<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<Point<T>, GmlError> {
+    if element.name != "Point" {
+        return Err(GmlError::MismatchedType {
+            expected: "point",
+            found: element.name.clone(),
+        });
+    }
+    match read_coordinates(element, inherited)?[..] {
+        [coord] => Ok(Point(coord)),
+        [] => Err(missing("pos", element)),
+        _ => {
+            let coordinates = element
+                .child("coordinates")
+                .or_else(|| element.child("pos"))
+                .map_or_else(String::new, |coordinates| coordinates.text.clone());
+            Err(GmlError::InvalidCoordinates(coordinates))
+        }
+    }
+}
+
+fn read_line<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<LineString<T>, GmlError> {
+    match element.name.as_str() {
+        "LineString" | "LinearRing" => read_coordinates(element, inherited).map(LineString),
+        "Curve" => {
+            let dimension = dimension(element, inherited)?;
+            let segments = element
+                .child("segments")
+                .ok_or_else(|| missing("segments", element))?;
+            let mut coords = vec![];
+            for segment in &segments.children {
+                if segment.name != "LineStringSegment" {
+                    return Err(GmlError::UnsupportedGeometry(segment.name.clone()));
+                }
+                join(&mut coords, read_coordinates(segment, dimension)?);
+            }
+            Ok(LineString(coords))
+        }
+        name => Err(GmlError::MismatchedType {
+            expected: "line string",
+            found: name.to_string(),
+        }),
+    }
+}
+
+// The ring of an `exterior` or `interior`
+fn read_ring<T: CoordinateType>(
+    boundary: &Element,
+    inherited: Option<usize>,
+) -> Result<LineString<T>, GmlError> {
+    let ring = geometry_of(boundary, "LinearRing")?;
+    if ring.name != "Ring" {
+        return read_line(ring, inherited);
+    }
+    let dimension = dimension(ring, inherited)?;
+    let mut coords = vec![];
+    for member in ring.children_named("curveMember") {
+        join(
+            &mut coords,
+            read_line(geometry_of(member, "Curve")?, dimension)?.0,
+        );
+    }
+    Ok(LineString(coords))
+}
+
+fn read_polygon<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<Polygon<T>, GmlError> {
+    let dimension = dimension(element, inherited)?;
+    match element.name.as_str() {
+        "Polygon" | "PolygonPatch" => {
+            let exterior = element
+                .children
+                .iter()
+                .find(|child| child.name == "exterior" || child.name == "outerBoundaryIs")
+                .ok_or_else(|| missing("exterior", element))?;
+            let interiors = element
+                .children
+                .iter()
+                .filter(|child| child.name == "interior" || child.name == "innerBoundaryIs")
+                .map(|interior| read_ring(interior, dimension))
+                .collect::<Result<_, _>>()?;
+            Ok(Polygon::new(read_ring(exterior, dimension)?, interiors))
+        }
+        "Surface" => {
+            let patches = element
+                .child("patches")
+                .ok_or_else(|| missing("patches", element))?;
+            match &patches.children[..] {
+                [patch] if patch.name == "PolygonPatch" => read_polygon(patch, dimension),
+                [patch] => Err(GmlError::UnsupportedGeometry(patch.name.clone())),
+                [] => Err(missing("PolygonPatch", patches)),
+                _ => Err(GmlError::UnsupportedGeometry(
+                    "Surface with several patches".to_string(),
+                )),
+            }
+        }
+        name => Err(GmlError::MismatchedType {
+            expected: "polygon",
+            found: name.to_string(),
+        }),
+    }
+}
+
+// The geometries of the members of a multi-geometry, in `member` elements with one each, or
+// `members` elements with any number
+fn members<'a>(
+    element: &'a Element,
+    member: &str,
+    members: &str,
+) -> Result<Vec<&'a Element>, GmlError> {
+    let mut geometries = vec![];
+    for child in &element.children {
+        if child.name == member {
+            geometries.push(geometry_of(child, "geometry")?);
+        } else if child.name == members {
+            geometries.extend(&child.children);
+        }
+    }
+    Ok(geometries)
+}
+
+fn read_geometry<T: CoordinateType>(
+    element: &Element,
+    inherited: Option<usize>,
+) -> Result<Geometry<T>, GmlError> {
+    let dimension = dimension(element, inherited)?;
+    Ok(match element.name.as_str() {
+        "Point" => Geometry::Point(read_point(element, dimension)?),
+        "LineString" | "LinearRing" | "Curve" => {
+            Geometry::LineString(read_line(element, dimension)?)
+        }
+        "Polygon" | "Surface" => Geometry::Polygon(read_polygon(element, dimension)?),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint(
+            members(element, "pointMember", "pointMembers")?
+                .into_iter()
+                .map(|point| read_point(point, dimension))
+                .collect::<Result<_, _>>()?,
+        )),
+        "MultiCurve" | "MultiLineString" => Geometry::MultiLineString(MultiLineString(
+            members(element, "curveMember", "curveMembers")?
+                .into_iter()
+                .chain(members(element, "lineStringMember", "lineStringMembers")?)
+                .map(|line| read_line(line, dimension))
+                .collect::<Result<_, _>>()?,
+        )),
+        "MultiSurface" | "MultiPolygon" => Geometry::MultiPolygon(MultiPolygon(
+            members(element, "surfaceMember", "surfaceMembers")?
+                .into_iter()
+                .chain(members(element, "polygonMember", "polygonMembers")?)
+                .map(|polygon| read_polygon(polygon, dimension))
+                .collect::<Result<_, _>>()?,
+        )),
+        "MultiGeometry" => Geometry::GeometryCollection(GeometryCollection(
+            members(element, "geometryMember", "geometryMembers")?
+                .into_iter()
+                .map(|geometry| read_geometry(geometry, dimension))
+                .collect::<Result<_, _>>()?,
+        )),
+        name => return Err(GmlError::NotAGeometry(name.to_string())),
+    })
+}
+
+// Writing
+
+fn open(gml: &mut String, name: &str, root: bool) {
+    gml.push_str("<gml:");
+    gml.push_str(name);
+    if root {
+        gml.push_str(&format!(r#" xmlns:gml="{}""#, NAMESPACE));
+    }
+    gml.push('>');
+}
+
+fn close(gml: &mut String, name: &str) {
+    gml.push_str(&format!("</gml:{}>", name));
+}
+
+fn write_positions<T: CoordinateType>(gml: &mut String, element: &str, coords: &[Coordinate<T>]) {
+    let value = |v: T| v.to_f64().expect("coordinate can't be converted to f64");
+    open(gml, element, false);
+    for (i, coord) in coords.iter().enumerate() {
+        if i > 0 {
+            gml.push(' ');
+        }
+        gml.push_str(&format!("{} {}", value(coord.x), value(coord.y)));
+    }
+    close(gml, element);
+}
+
+fn write_line_string<T: CoordinateType>(gml: &mut String, coords: &[Coordinate<T>], root: bool) {
+    open(gml, "LineString", root);
+    write_positions(gml, "posList", coords);
+    close(gml, "LineString");
+}
+
+fn write_polygon<T: CoordinateType>(gml: &mut String, polygon: &Polygon<T>, root: bool) {
+    open(gml, "Polygon", root);
+    let rings = std::iter::once(("exterior", polygon.exterior())).chain(
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| ("interior", interior)),
+    );
+    for (boundary, ring) in rings {
+        open(gml, boundary, false);
+        open(gml, "LinearRing", false);
+        write_positions(gml, "posList", &ring.0);
+        close(gml, "LinearRing");
+        close(gml, boundary);
+    }
+    close(gml, "Polygon");
+}
+
+fn write_geometry<T: CoordinateType>(gml: &mut String, geometry: &Geometry<T>, root: bool) {
+    match geometry {
+        Geometry::Point(p) => {
+            open(gml, "Point", root);
+            write_positions(gml, "pos", &[p.0]);
+            close(gml, "Point");
+        }
+        Geometry::Line(l) => write_line_string(gml, &[l.start, l.end], root),
+        Geometry::LineString(ls) => write_line_string(gml, &ls.0, root),
+        Geometry::Polygon(p) => write_polygon(gml, p, root),
+        Geometry::Rect(r) => write_polygon(gml, &Polygon::from(*r), root),
+        Geometry::Triangle(t) => write_polygon(gml, &Polygon::from(*t), root),
+        Geometry::MultiPoint(mp) => {
+            open(gml, "MultiPoint", root);
+            for p in &mp.0 {
+                open(gml, "pointMember", false);
+                write_geometry(gml, &Geometry::Point(*p), false);
+                close(gml, "pointMember");
+            }
+            close(gml, "MultiPoint");
+        }
+        Geometry::MultiLineString(mls) => {
+            open(gml, "MultiCurve", root);
+            for ls in &mls.0 {
+                open(gml, "curveMember", false);
+                write_line_string(gml, &ls.0, false);
+                close(gml, "curveMember");
+            }
+            close(gml, "MultiCurve");
+        }
+        Geometry::MultiPolygon(mp) => {
+            open(gml, "MultiSurface", root);
+            for p in &mp.0 {
+                open(gml, "surfaceMember", false);
+                write_polygon(gml, p, false);
+                close(gml, "surfaceMember");
+            }
+            close(gml, "MultiSurface");
+        }
+        Geometry::GeometryCollection(gc) => {
+            open(gml, "MultiGeometry", root);
+            for g in &gc.0 {
+                open(gml, "geometryMember", false);
+                write_geometry(gml, g, false);
+                close(gml, "geometryMember");
+            }
+            close(gml, "MultiGeometry");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Rect};
+
+    const GML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cp:FeatureCollection xmlns:cp="urn:example:cadastre" xmlns:gml="http://www.opengis.net/gml/3.2">
+  <gml:boundedBy>
+    <gml:Envelope><gml:lowerCorner>0 0</gml:lowerCorner><gml:upperCorner>9 9</gml:upperCorner></gml:Envelope>
+  </gml:boundedBy>
+  <cp:member>
+    <cp:Parcel gml:id="p1">
+      <cp:Polygon>Not a geometry</cp:Polygon>
+      <cp:geometry>
+        <gml:MultiSurface srsDimension="3">
+          <gml:surfaceMember>
+            <gml:Surface>
+              <gml:patches>
+                <gml:PolygonPatch>
+                  <gml:exterior>
+                    <gml:Ring>
+                      <gml:curveMember>
+                        <gml:Curve>
+                          <gml:segments>
+                            <gml:LineStringSegment><gml:posList>0 0 1 4 0 1</gml:posList></gml:LineStringSegment>
+                            <gml:LineStringSegment><gml:posList>4 0 1 4 4 1</gml:posList></gml:LineStringSegment>
+                          </gml:segments>
+                        </gml:Curve>
+                      </gml:curveMember>
+                      <gml:curveMember>
+                        <gml:LineString><gml:posList>4 4 1 0 0 1</gml:posList></gml:LineString>
+                      </gml:curveMember>
+                    </gml:Ring>
+                  </gml:exterior>
+                </gml:PolygonPatch>
+              </gml:patches>
+            </gml:Surface>
+          </gml:surfaceMember>
+          <gml:surfaceMembers>
+            <gml:Polygon>
+              <gml:exterior><gml:LinearRing><gml:posList srsDimension="2">5 5 9 5 9 9 5 5</gml:posList></gml:LinearRing></gml:exterior>
+              <gml:interior>
+                <gml:LinearRing>
+                  <gml:pos>6 6 0</gml:pos><gml:pos>7 6 0</gml:pos>
+                  <gml:pointProperty><gml:Point><gml:pos>7 7 0</gml:pos></gml:Point></gml:pointProperty>
+                </gml:LinearRing>
+              </gml:interior>
+            </gml:Polygon>
+          </gml:surfaceMembers>
+        </gml:MultiSurface>
+      </cp:geometry>
+      <cp:entrance><gml:Point><gml:pos>1 2</gml:pos></gml:Point></cp:entrance>
+    </cp:Parcel>
+  </cp:member>
+  <cp:member>
+    <cp:Boundary>
+      <cp:geometry>
+        <gml:MultiGeometry>
+          <gml:geometryMember>
+            <gml:MultiCurve>
+              <gml:curveMember><gml:LineString><gml:posList>0 0 1 1</gml:posList></gml:LineString></gml:curveMember>
+            </gml:MultiCurve>
+          </gml:geometryMember>
+          <gml:geometryMember>
+            <gml:MultiPoint>
+              <gml:pointMembers><gml:Point><gml:pos>1 1</gml:pos></gml:Point><gml:Point><gml:pos>2 2</gml:pos></gml:Point></gml:pointMembers>
+            </gml:MultiPoint>
+          </gml:geometryMember>
+        </gml:MultiGeometry>
+      </cp:geometry>
+    </cp:Boundary>
+  </cp:member>
+</cp:FeatureCollection>
+"#;
+
+    #[test]
+    fn read() {
+        assert_eq!(
+            read_geometries::<f64, _>(GML.as_bytes()).unwrap(),
+            vec![
+                Geometry::MultiPolygon(MultiPolygon(vec![
+                    polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+                    polygon!(
+                        exterior: [(x: 5., y: 5.), (x: 9., y: 5.), (x: 9., y: 9.)],
+                        interiors: [[(x: 6., y: 6.), (x: 7., y: 6.), (x: 7., y: 7.)]],
+                    ),
+                ])),
+                Geometry::Point(point!(x: 1., y: 2.)),
+                Geometry::GeometryCollection(GeometryCollection(vec![
+                    Geometry::MultiLineString(MultiLineString(vec![
+                        line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+                    ])),
+                    Geometry::MultiPoint(MultiPoint(vec![
+                        point!(x: 1., y: 1.),
+                        point!(x: 2., y: 2.)
+                    ])),
+                ])),
+            ]
+        );
+
+        // GML 2, and GML 3.1 without namespaces
+        let gml2 = r#"<gml:MultiPolygon xmlns:gml="http://www.opengis.net/gml">
+            <gml:polygonMember><gml:Polygon>
+              <gml:outerBoundaryIs><gml:LinearRing>
+                <gml:coordinates>0,0 1,0 1,1 0,0</gml:coordinates>
+              </gml:LinearRing></gml:outerBoundaryIs>
+            </gml:Polygon></gml:polygonMember>
+        </gml:MultiPolygon>"#;
+        assert_eq!(
+            Geometry::from_gml(gml2).unwrap(),
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+                (x: 0, y: 0),
+                (x: 1, y: 0),
+                (x: 1, y: 1)
+            ]]))
+        );
+        assert_eq!(
+            Geometry::from_gml("<gml:Point><gml:pos>1 2</gml:pos></gml:Point>").unwrap(),
+            Geometry::Point(point!(x: 1, y: 2))
+        );
+        assert_eq!(
+            read_geometries::<f64, _>(&b"<doc><LineString><posList/></LineString></doc>"[..])
+                .unwrap(),
+            vec![Geometry::LineString(LineString(vec![]))]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let geometries: Vec<Geometry<f64>> = read_geometries(GML.as_bytes()).unwrap();
+        for geometry in geometries {
+            assert_eq!(Geometry::from_gml(&geometry.to_gml()).unwrap(), geometry);
+        }
+
+        let write_read = |geometry: Geometry<i32>| Geometry::from_gml(&geometry.to_gml()).unwrap();
+        assert_eq!(
+            write_read(Geometry::Line(Line::new((0, 0), (1, 2)))),
+            Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 2)])
+        );
+        assert_eq!(
+            write_read(Geometry::Rect(Rect::new((0, 0), (1, 2)))),
+            Geometry::Polygon(Polygon::from(Rect::new((0, 0), (1, 2))))
+        );
+        let empty = Geometry::GeometryCollection(GeometryCollection(vec![]));
+        assert_eq!(write_read(empty.clone()), empty);
+        assert_eq!(
+            Geometry::<f64>::LineString(line_string![(x: 0.5, y: 1.), (x: -2., y: 3.25)]).to_gml(),
+            r#"<gml:LineString xmlns:gml="http://www.opengis.net/gml"><gml:posList>0.5 1 -2 3.25</gml:posList></gml:LineString>"#
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let from_gml = |gml: &str| Geometry::<f64>::from_gml(gml);
+        assert!(matches!(from_gml(""), Err(GmlError::Xml(_))));
+        assert!(matches!(from_gml("<Point>"), Err(GmlError::Xml(_))));
+        assert!(matches!(
+            from_gml("<Envelope/>"),
+            Err(GmlError::NotAGeometry(name)) if name == "Envelope"
+        ));
+        assert!(matches!(
+            from_gml("<Point/>"),
+            Err(GmlError::MissingElement { element: "pos", .. })
+        ));
+        assert!(matches!(
+            from_gml("<Polygon><interior/></Polygon>"),
+            Err(GmlError::MissingElement {
+                element: "exterior",
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_gml("<MultiPoint><pointMember/></MultiPoint>"),
+            Err(GmlError::MissingElement {
+                element: "geometry",
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_gml("<MultiCurve><curveMember><Point><pos>1 2</pos></Point></curveMember></MultiCurve>"),
+            Err(GmlError::MismatchedType { expected: "line string", found }) if found == "Point"
+        ));
+        assert!(matches!(
+            from_gml("<Curve><segments><Arc><posList>0 0 1 1 2 0</posList></Arc></segments></Curve>"),
+            Err(GmlError::UnsupportedGeometry(name)) if name == "Arc"
+        ));
+        assert!(matches!(
+            from_gml(r#"<LineString srsDimension="1"><posList>0 0</posList></LineString>"#),
+            Err(GmlError::InvalidDimension(_))
+        ));
+        for invalid in &[
+            "<Point><pos>1</pos></Point>",
+            "<Point><pos>1 a</pos></Point>",
+            r#"<Point srsDimension="3"><pos>1 2</pos></Point>"#,
+            "<LineString><posList>0 0 1</posList></LineString>",
+            r#"<LineString srsDimension="3"><posList>0 0 1 1</posList></LineString>"#,
+            "<LineString><coordinates>0 0</coordinates></LineString>",
+        ] {
+            assert!(
+                matches!(from_gml(invalid), Err(GmlError::InvalidCoordinates(_))),
+                "{}",
+                invalid
+            );
+        }
+        assert!(matches!(
+            Geometry::<u8>::from_gml("<Point><pos>-1 2</pos></Point>"),
+            Err(GmlError::InvalidCoordinate(_))
+        ));
+    }
+}
@@ -0,0 +1,912 @@
+//! Reading and writing [GPX](https://www.topografix.com/gpx.asp) files.
+//!
+//! With the `gpx` feature enabled, [`Gpx::read`](struct.Gpx.html#method.read) reads the
+//! waypoints, routes, and tracks of a GPX 1.0 or 1.1 file, and
+//! [`Gpx::write`](struct.Gpx.html#method.write) writes them back as GPX 1.1. Their points keep
+//! their elevation, time, and name, if any: other elements, such as the file's metadata and
+//! extensions, are skipped.
+//!
+//! Waypoints convert to `Point`s, routes to `LineString`s, and tracks to `MultiLineString`s, with
+//! a line string for each of their segments, with longitudes as `x` and latitudes as `y`. The
+//! times of their points are the number of seconds since the Unix epoch, so points with times
+//! also convert to `PointM`s, `LineStringM`s, and `Vec`s of `LineStringM`s, with the times as
+//! their measures. Times are read from any time zone, and written in UTC, to the millisecond.
+//!
+//! Coordinates are always `f64`s in GPX: other coordinate types are converted using `NumCast`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::gpx::{Gpx, GpxError};
+//! use geo_types::{line_string, CoordinateM, LineStringM};
+//!
+//! let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+//! <gpx version="1.1" creator="logger" xmlns="http://www.topografix.com/GPX/1/1">
+//!   <trk>
+//!     <name>Meetup</name>
+//!     <trkseg>
+//!       <trkpt lat="47.37" lon="8.54"><time>2020-01-01T12:00:00Z</time></trkpt>
+//!       <trkpt lat="47.38" lon="8.55"><time>2020-01-01T12:01:00Z</time></trkpt>
+//!     </trkseg>
+//!   </trk>
+//! </gpx>"#;
+//!
+//! let gpx = Gpx::<f64>::read(xml.as_bytes())?;
+//! let track = &gpx.tracks[0];
+//! assert_eq!(track.name.as_deref(), Some("Meetup"));
+//! assert_eq!(
+//!     track.to_multi_line_string().0,
+//!     vec![line_string![(x: 8.54, y: 47.37), (x: 8.55, y: 47.38)]]
+//! );
+//! let measured: Vec<LineStringM<f64>> = track.to_line_strings_m().unwrap();
+//! assert_eq!(measured[0][1], CoordinateM { x: 8.55, y: 47.38, m: 1577880060. });
+//!
+//! let mut written = vec![];
+//! gpx.write(&mut written)?;
+//! assert_eq!(Gpx::read(&written[..])?, gpx);
+//! # Ok::<(), GpxError>(())
+//! ```
+use crate::{CoordinateM, CoordinateType, LineString, LineStringM, MultiLineString, Point, PointM};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// The error returned when reading or writing GPX fails.
+#[derive(Debug)]
+pub enum GpxError {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The input isn't well-formed XML.
+    Xml(String),
+    /// The input's root element isn't `gpx`.
+    NotGpx,
+    /// A point is missing its `lat` or `lon` attribute.
+    MissingCoordinate(&'static str),
+    /// A number, such as a latitude or an elevation, can't be parsed.
+    InvalidNumber(String),
+    /// A time can't be parsed as an [XML Schema
+    /// `dateTime`](https://www.w3.org/TR/xmlschema-2/#dateTime).
+    InvalidTime(String),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+}
+
+impl fmt::Display for GpxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GpxError::Io(e) => write!(f, "{}", e),
+            GpxError::Xml(e) => write!(f, "Invalid XML: {}", e),
+            GpxError::NotGpx => write!(f, "Not a GPX file"),
+            GpxError::MissingCoordinate(attribute) => {
+                write!(f, "Point without a '{}' attribute", attribute)
+            }
+            GpxError::InvalidNumber(number) => write!(f, "Invalid number '{}'", number),
+            GpxError::InvalidTime(time) => write!(f, "Invalid time '{}'", time),
+            GpxError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+        }
+    }
+}
+
+impl Error for GpxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GpxError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GpxError {
+    fn from(e: io::Error) -> Self {
+        GpxError::Io(e)
+    }
+}
+
+impl From<quick_xml::Error> for GpxError {
+    fn from(e: quick_xml::Error) -> Self {
+        match e {
+            quick_xml::Error::Io(e) => GpxError::Io(io::Error::new(e.kind(), e.to_string())),
+            e => GpxError::Xml(e.to_string()),
+        }
+    }
+}
+
+/// A waypoint, or a point of a route or track.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Waypoint<T: CoordinateType> {
+    /// The longitude and latitude of the point.
+    pub point: Point<T>,
+    /// The elevation, in meters.
+    pub elevation: Option<f64>,
+    /// The number of seconds since the Unix epoch, 1970-01-01T00:00:00Z.
+    pub time: Option<f64>,
+    pub name: Option<String>,
+}
+
+impl<T: CoordinateType> Waypoint<T> {
+    /// The point, with its time as its measure, if it has a time which can be represented by
+    /// the coordinate type.
+    pub fn to_point_m(&self) -> Option<PointM<T>> {
+        let m = T::from(self.time?)?;
+        Some(PointM(CoordinateM::from_2d(self.point.0, m)))
+    }
+}
+
+impl<T: CoordinateType> From<Point<T>> for Waypoint<T> {
+    fn from(point: Point<T>) -> Self {
+        Waypoint {
+            point,
+            elevation: None,
+            time: None,
+            name: None,
+        }
+    }
+}
+
+impl<T: CoordinateType> From<PointM<T>> for Waypoint<T> {
+    /// A waypoint with the point's measure as its time.
+    fn from(point: PointM<T>) -> Self {
+        Waypoint {
+            time: point.m().to_f64(),
+            ..Waypoint::from(point.to_2d())
+        }
+    }
+}
+
+/// A route: an ordered list of points leading to a destination.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route<T: CoordinateType> {
+    pub name: Option<String>,
+    pub points: Vec<Waypoint<T>>,
+}
+
+impl<T: CoordinateType> Route<T> {
+    pub fn to_line_string(&self) -> LineString<T> {
+        self.points.iter().map(|point| point.point).collect()
+    }
+
+    /// The route, with the times of its points as their measures, if every point has a time
+    /// which can be represented by the coordinate type.
+    pub fn to_line_string_m(&self) -> Option<LineStringM<T>> {
+        measured(&self.points)
+    }
+}
+
+impl<T: CoordinateType> From<LineString<T>> for Route<T> {
+    fn from(line_string: LineString<T>) -> Self {
+        Route {
+            name: None,
+            points: line_string.points_iter().map(Waypoint::from).collect(),
+        }
+    }
+}
+
+impl<T: CoordinateType> From<LineStringM<T>> for Route<T> {
+    /// A route with the measures of the line string's points as their times.
+    fn from(line_string: LineStringM<T>) -> Self {
+        Route {
+            name: None,
+            points: line_string
+                .into_points()
+                .into_iter()
+                .map(Waypoint::from)
+                .collect(),
+        }
+    }
+}
+
+/// A track: the points recorded along a path, in segments split where recording stopped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<T: CoordinateType> {
+    pub name: Option<String>,
+    pub segments: Vec<Vec<Waypoint<T>>>,
+}
+
+impl<T: CoordinateType> Track<T> {
+    /// The track, with a line string for each segment.
+    pub fn to_multi_line_string(&self) -> MultiLineString<T> {
+        MultiLineString(
+            self.segments
+                .iter()
+                .map(|segment| segment.iter().map(|point| point.point).collect())
+                .collect(),
+        )
+    }
+
+    /// The segments of the track, with the times of their points as their measures, if every
+    /// point has a time which can be represented by the coordinate type.
+    pub fn to_line_strings_m(&self) -> Option<Vec<LineStringM<T>>> {
+        self.segments
+            .iter()
+            .map(|segment| measured(segment))
+            .collect()
+    }
+}
+
+impl<T: CoordinateType> From<MultiLineString<T>> for Track<T> {
+    fn from(multi_line_string: MultiLineString<T>) -> Self {
+        Track {
+            name: None,
+            segments: multi_line_string
+                .0
+                .into_iter()
+                .map(|line_string| line_string.points_iter().map(Waypoint::from).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<T: CoordinateType> From<Vec<LineStringM<T>>> for Track<T> {
+    /// A track with a segment for each line string, with the measures of their points as
+    /// their times.
+    fn from(segments: Vec<LineStringM<T>>) -> Self {
+        Track {
+            name: None,
+            segments: segments
+                .into_iter()
+                .map(|segment| {
+                    segment
+                        .into_points()
+                        .into_iter()
+                        .map(Waypoint::from)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+fn measured<T: CoordinateType>(points: &[Waypoint<T>]) -> Option<LineStringM<T>> {
+    points
+        .iter()
+        .map(|point| point.to_point_m().map(|point| point.0))
+        .collect::<Option<Vec<_>>>()
+        .map(LineStringM::from)
+}
+
+/// The waypoints, routes, and tracks of a GPX file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gpx<T: CoordinateType> {
+    pub waypoints: Vec<Waypoint<T>>,
+    pub routes: Vec<Route<T>>,
+    pub tracks: Vec<Track<T>>,
+}
+
+impl<T: CoordinateType> Default for Gpx<T> {
+    fn default() -> Self {
+        Gpx {
+            waypoints: vec![],
+            routes: vec![],
+            tracks: vec![],
+        }
+    }
+}
+
+impl<T: CoordinateType> Gpx<T> {
+    /// Read the waypoints, routes, and tracks of a GPX file.
+    pub fn read<R: BufRead>(reader: R) -> Result<Gpx<T>, GpxError> {
+        let mut reader = quick_xml::Reader::from_reader(reader);
+        reader.config_mut().trim_text(true);
+        let mut parser = Parser {
+            gpx: Gpx::default(),
+            root: false,
+            elements: vec![],
+            point: None,
+            route: None,
+            track: None,
+            segment: None,
+            text: String::new(),
+        };
+        let mut buf = vec![];
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => parser.start(&e)?,
+                Event::Empty(e) => {
+                    parser.start(&e)?;
+                    parser.end()?;
+                }
+                Event::End(_) => parser.end()?,
+                Event::Text(e) => parser.text.push_str(&e.unescape()?),
+                Event::CData(e) => parser
+                    .text
+                    .push_str(&e.decode().map_err(quick_xml::Error::from)?),
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        if !parser.root {
+            return Err(GpxError::NotGpx);
+        }
+        if !parser.elements.is_empty() {
+            return Err(GpxError::Xml("unclosed elements at the end".to_string()));
+        }
+        Ok(parser.gpx)
+    }
+
+    /// Write the waypoints, routes, and tracks as a GPX 1.1 file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), GpxError> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<gpx version="1.1" creator="geo-types" xmlns="http://www.topografix.com/GPX/1/1">"#
+        )?;
+        for waypoint in &self.waypoints {
+            write_point(&mut writer, "wpt", waypoint, 1)?;
+        }
+        for route in &self.routes {
+            writeln!(writer, "  <rte>")?;
+            write_name(&mut writer, &route.name, 2)?;
+            for point in &route.points {
+                write_point(&mut writer, "rtept", point, 2)?;
+            }
+            writeln!(writer, "  </rte>")?;
+        }
+        for track in &self.tracks {
+            writeln!(writer, "  <trk>")?;
+            write_name(&mut writer, &track.name, 2)?;
+            for segment in &track.segments {
+                writeln!(writer, "    <trkseg>")?;
+                for point in segment {
+                    write_point(&mut writer, "trkpt", point, 3)?;
+                }
+                writeln!(writer, "    </trkseg>")?;
+            }
+            writeln!(writer, "  </trk>")?;
+        }
+        writeln!(writer, "</gpx>")?;
+        Ok(())
+    }
+}
+
+// Reading
+
+// Builds the waypoints, routes, and tracks as the elements of a file are read
+struct Parser<T: CoordinateType> {
+    gpx: Gpx<T>,
+    // Whether the root element has been read
+    root: bool,
+    // The local names of the open elements
+    elements: Vec<Vec<u8>>,
+    point: Option<Waypoint<T>>,
+    route: Option<Route<T>>,
+    track: Option<Track<T>>,
+    segment: Option<Vec<Waypoint<T>>>,
+    // The text of the innermost open element
+    text: String,
+}
+
+impl<T: CoordinateType> Parser<T> {
+    fn parent(&self) -> &[u8] {
+        match self.elements.len() {
+            0 | 1 => b"",
+            len => &self.elements[len - 2],
+        }
+    }
+
+    fn start(&mut self, e: &BytesStart) -> Result<(), GpxError> {
+        let name = e.local_name().as_ref().to_vec();
+        if self.elements.is_empty() {
+            if self.root || name != b"gpx" {
+                return Err(GpxError::NotGpx);
+            }
+            self.root = true;
+        }
+        self.text.clear();
+        let parent = self.elements.last().map(|parent| parent.as_slice());
+        match (parent, name.as_slice()) {
+            (Some(b"gpx"), b"wpt") | (Some(b"rte"), b"rtept") | (Some(b"trkseg"), b"trkpt") => {
+                let coordinate = |attribute: &'static str| -> Result<T, GpxError> {
+                    let value = e
+                        .try_get_attribute(attribute)
+                        .map_err(quick_xml::Error::from)?
+                        .ok_or(GpxError::MissingCoordinate(attribute))?
+                        .unescape_value()?;
+                    let value = parse_number(&value)?;
+                    T::from(value).ok_or(GpxError::InvalidCoordinate(value))
+                };
+                self.point = Some(Waypoint::from(Point::new(
+                    coordinate("lon")?,
+                    coordinate("lat")?,
+                )));
+            }
+            (Some(b"gpx"), b"rte") => {
+                self.route = Some(Route {
+                    name: None,
+                    points: vec![],
+                })
+            }
+            (Some(b"gpx"), b"trk") => {
+                self.track = Some(Track {
+                    name: None,
+                    segments: vec![],
+                })
+            }
+            (Some(b"trk"), b"trkseg") => self.segment = Some(vec![]),
+            _ => {}
+        }
+        self.elements.push(name);
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<(), GpxError> {
+        let text = std::mem::take(&mut self.text);
+        let parent = self.parent().to_vec();
+        let name = match self.elements.pop() {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+        let is_point = |element: &[u8]| matches!(element, b"wpt" | b"rtept" | b"trkpt");
+        match (parent.as_slice(), name.as_slice()) {
+            (parent, b"ele") if is_point(parent) => {
+                if let Some(point) = &mut self.point {
+                    point.elevation = Some(parse_number(&text)?);
+                }
+            }
+            (parent, b"time") if is_point(parent) => {
+                if let Some(point) = &mut self.point {
+                    point.time = Some(parse_time(&text).ok_or(GpxError::InvalidTime(text))?);
+                }
+            }
+            (parent, b"name") if is_point(parent) => {
+                if let Some(point) = &mut self.point {
+                    point.name = Some(text);
+                }
+            }
+            (b"rte", b"name") => {
+                if let Some(route) = &mut self.route {
+                    route.name = Some(text);
+                }
+            }
+            (b"trk", b"name") => {
+                if let Some(track) = &mut self.track {
+                    track.name = Some(text);
+                }
+            }
+            (b"gpx", b"wpt") => self.gpx.waypoints.extend(self.point.take()),
+            (b"rte", b"rtept") => {
+                if let (Some(route), Some(point)) = (&mut self.route, self.point.take()) {
+                    route.points.push(point);
+                }
+            }
+            (b"trkseg", b"trkpt") => {
+                if let (Some(segment), Some(point)) = (&mut self.segment, self.point.take()) {
+                    segment.push(point);
+                }
+            }
+            (b"trk", b"trkseg") => {
+                if let (Some(track), Some(segment)) = (&mut self.track, self.segment.take()) {
+                    track.segments.push(segment);
+                }
+            }
+            (b"gpx", b"rte") => self.gpx.routes.extend(self.route.take()),
+            (b"gpx", b"trk") => self.gpx.tracks.extend(self.track.take()),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn parse_number(number: &str) -> Result<f64, GpxError> {
+    number
+        .trim()
+        .parse()
+        .map_err(|_| GpxError::InvalidNumber(number.to_string()))
+}
+
+// A number of at least `min_len` decimal digits, without a sign
+fn number(digits: Option<&str>, min_len: usize) -> Option<i64> {
+    let digits = digits?;
+    if digits.len() < min_len || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+// The number of days from 1970-01-01 to a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The year, month, and day of a number of days from 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Seconds since the Unix epoch of an XML Schema dateTime, such as `2020-01-01T12:00:00.5+01:00`.
+// Times without a time zone are taken to be in UTC, as GPX requires.
+fn parse_time(time: &str) -> Option<f64> {
+    let time = time.trim();
+    let (negative, time) = match time.strip_prefix('-') {
+        Some(time) => (true, time),
+        None => (false, time),
+    };
+    let (date, rest) = time.split_at(time.find('T')?);
+    let mut date_parts = date.splitn(3, '-');
+    let year = number(date_parts.next(), 4)?;
+    let year = if negative { -year } else { year };
+    let month = number(date_parts.next(), 2).filter(|month| (1..=12).contains(month))?;
+    let day = number(date_parts.next(), 2)
+        .filter(|&day| day >= 1 && day <= days_in_month(year, month))?;
+
+    let rest = &rest[1..];
+    let zone_start = rest.find(['Z', '+', '-']).unwrap_or(rest.len());
+    let (clock, zone) = rest.split_at(zone_start);
+    if clock.len() < 8 || clock.as_bytes()[2] != b':' || clock.as_bytes()[5] != b':' {
+        return None;
+    }
+    let hour = number(clock.get(0..2), 2).filter(|&hour| hour < 24)?;
+    let minute = number(clock.get(3..5), 2).filter(|&minute| minute < 60)?;
+    let whole_seconds = number(clock.get(6..8), 2).filter(|&second| second < 61)?;
+    let fraction = match &clock[8..] {
+        "" => 0.,
+        fraction => {
+            number(fraction.strip_prefix('.'), 1)?;
+            format!("0{}", fraction).parse::<f64>().ok()?
+        }
+    };
+    let offset = match zone {
+        "" | "Z" => 0,
+        _ => {
+            let sign = if zone.starts_with('-') { -1 } else { 1 };
+            if zone.len() != 6 || zone.as_bytes()[3] != b':' {
+                return None;
+            }
+            let hours = number(zone.get(1..3), 2).filter(|&hours| hours <= 14)?;
+            let minutes = number(zone.get(4..6), 2).filter(|&minutes| minutes < 60)?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+    let seconds =
+        days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + whole_seconds
+            - offset;
+    Some(seconds as f64 + fraction)
+}
+
+// An XML Schema dateTime in UTC, to the millisecond
+fn format_time(seconds: f64) -> String {
+    let millis = (seconds * 1000.).round() as i64;
+    let (days, millis_of_day) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    let (year, month, day) = civil_from_days(days);
+    let seconds_of_day = millis_of_day / 1000;
+    let mut time = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    );
+    if millis_of_day % 1000 != 0 {
+        time.push_str(&format!(".{:03}", millis_of_day % 1000));
+    }
+    time.push('Z');
+    time
+}
+
+// Writing
+
+fn indent<W: Write>(writer: &mut W, depth: usize) -> io::Result<()> {
+    write!(writer, "{:1$}", "", 2 * depth)
+}
+
+fn write_name<W: Write>(writer: &mut W, name: &Option<String>, depth: usize) -> io::Result<()> {
+    if let Some(name) = name {
+        indent(writer, depth)?;
+        writeln!(writer, "<name>{}</name>", escape(name.as_str()))?;
+    }
+    Ok(())
+}
+
+fn write_point<W: Write, T: CoordinateType>(
+    writer: &mut W,
+    element: &str,
+    point: &Waypoint<T>,
+    depth: usize,
+) -> io::Result<()> {
+    indent(writer, depth)?;
+    write!(
+        writer,
+        r#"<{} lat="{}" lon="{}""#,
+        element,
+        point
+            .point
+            .y()
+            .to_f64()
+            .expect("coordinate can't be converted to f64"),
+        point
+            .point
+            .x()
+            .to_f64()
+            .expect("coordinate can't be converted to f64"),
+    )?;
+    let elevation = point.elevation.filter(|elevation| elevation.is_finite());
+    let time = point.time.filter(|time| time.is_finite());
+    if elevation.is_none() && time.is_none() && point.name.is_none() {
+        return writeln!(writer, "/>");
+    }
+    writeln!(writer, ">")?;
+    // in the order of the GPX schema
+    if let Some(elevation) = elevation {
+        indent(writer, depth + 1)?;
+        writeln!(writer, "<ele>{}</ele>", elevation)?;
+    }
+    if let Some(time) = time {
+        indent(writer, depth + 1)?;
+        writeln!(writer, "<time>{}</time>", format_time(time))?;
+    }
+    write_name(writer, &point.name, depth + 1)?;
+    indent(writer, depth)?;
+    writeln!(writer, "</{}>", element)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    const GPX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="logger" xmlns="http://www.topografix.com/GPX/1/1"
+    xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">
+  <metadata>
+    <name>Not a waypoint</name>
+    <time>2020-01-01T00:00:00Z</time>
+  </metadata>
+  <wpt lat="47.37" lon="8.54">
+    <ele>408.5</ele>
+    <time>2020-01-01T12:01:00Z</time>
+    <name>Caf&#233; &amp; Bar</name>
+  </wpt>
+  <wpt lat="-33.9" lon="18.4"><name><![CDATA[<Cape Town>]]></name></wpt>
+  <rte>
+    <name>Route</name>
+    <rtept lat="1" lon="2"/>
+    <rtept lat="3" lon="4"/>
+  </rte>
+  <trk>
+    <name>Track</name>
+    <link href="https://example.com"><text>Link</text></link>
+    <trkseg>
+      <trkpt lat="0" lon="0">
+        <time>1970-01-01T00:00:00Z</time>
+        <extensions><gpxtpx:TrackPointExtension><gpxtpx:hr>120</gpxtpx:hr></gpxtpx:TrackPointExtension></extensions>
+      </trkpt>
+      <trkpt lat="1" lon="1"><time>1970-01-01T00:00:10.5Z</time></trkpt>
+    </trkseg>
+    <trkseg>
+      <trkpt lat="2" lon="2"><time>1970-01-01T01:00:00+01:00</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>
+"#;
+
+    fn waypoint(x: f64, y: f64, time: Option<f64>) -> Waypoint<f64> {
+        Waypoint {
+            time,
+            ..Waypoint::from(point!(x: x, y: y))
+        }
+    }
+
+    #[test]
+    fn read() {
+        let gpx = Gpx::<f64>::read(GPX.as_bytes()).unwrap();
+        assert_eq!(
+            gpx.waypoints,
+            vec![
+                Waypoint {
+                    point: point!(x: 8.54, y: 47.37),
+                    elevation: Some(408.5),
+                    time: Some(1577880060.),
+                    name: Some("Café & Bar".to_string()),
+                },
+                Waypoint {
+                    name: Some("<Cape Town>".to_string()),
+                    ..Waypoint::from(point!(x: 18.4, y: -33.9))
+                }
+            ]
+        );
+        assert_eq!(
+            gpx.routes,
+            vec![Route {
+                name: Some("Route".to_string()),
+                points: vec![waypoint(2., 1., None), waypoint(4., 3., None)],
+            }]
+        );
+        assert_eq!(
+            gpx.tracks,
+            vec![Track {
+                name: Some("Track".to_string()),
+                segments: vec![
+                    vec![waypoint(0., 0., Some(0.)), waypoint(1., 1., Some(10.5))],
+                    vec![waypoint(2., 2., Some(0.))],
+                ],
+            }]
+        );
+
+        // GPX 1.0 has no namespace by default
+        let gpx = Gpx::<i32>::read(&br#"<gpx version="1.0"><wpt lat="1" lon="2"></wpt></gpx>"#[..])
+            .unwrap();
+        assert_eq!(gpx.waypoints, vec![Waypoint::from(point!(x: 2, y: 1))]);
+    }
+
+    #[test]
+    fn geometries() {
+        let gpx = Gpx::<f64>::read(GPX.as_bytes()).unwrap();
+        assert_eq!(
+            gpx.waypoints[0].to_point_m(),
+            Some(PointM::new(8.54, 47.37, 1577880060.))
+        );
+        assert_eq!(gpx.waypoints[1].to_point_m(), None);
+
+        let route = &gpx.routes[0];
+        assert_eq!(
+            route.to_line_string(),
+            line_string![(x: 2., y: 1.), (x: 4., y: 3.)]
+        );
+        assert_eq!(route.to_line_string_m(), None);
+
+        let track = &gpx.tracks[0];
+        assert_eq!(
+            track.to_multi_line_string(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 2., y: 2.)],
+            ])
+        );
+        let measured = track.to_line_strings_m().unwrap();
+        assert_eq!(
+            measured,
+            vec![
+                LineStringM::from(vec![
+                    CoordinateM {
+                        x: 0.,
+                        y: 0.,
+                        m: 0.
+                    },
+                    CoordinateM {
+                        x: 1.,
+                        y: 1.,
+                        m: 10.5
+                    },
+                ]),
+                LineStringM::from(vec![CoordinateM {
+                    x: 2.,
+                    y: 2.,
+                    m: 0.
+                }]),
+            ]
+        );
+
+        // and back
+        assert_eq!(Track::from(measured).segments, track.segments);
+        assert_eq!(
+            Track::from(track.to_multi_line_string()).segments[0][1],
+            waypoint(1., 1., None)
+        );
+        assert_eq!(Route::from(route.to_line_string()).points, route.points);
+        assert_eq!(
+            Route::from(LineStringM::from(vec![CoordinateM { x: 1, y: 2, m: 3 }])).points,
+            vec![Waypoint {
+                time: Some(3.),
+                ..Waypoint::from(point!(x: 1, y: 2))
+            }]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut gpx = Gpx::<f64>::read(GPX.as_bytes()).unwrap();
+        gpx.tracks[0].segments[0][0].time = Some(951820215.25);
+        gpx.routes[0].name = Some("'Quoted' \"route\"".to_string());
+        gpx.routes.push(Route {
+            name: None,
+            points: vec![],
+        });
+        let mut written = vec![];
+        gpx.write(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.contains(r#"<wpt lat="47.37" lon="8.54">"#));
+        assert!(written.contains("<time>2000-02-29T10:30:15.250Z</time>"));
+        assert!(written.contains(r#"<rtept lat="1" lon="2"/>"#));
+        assert_eq!(Gpx::read(written.as_bytes()).unwrap(), gpx);
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(parse_time("1970-01-01T00:00:00Z"), Some(0.));
+        assert_eq!(parse_time("1970-01-01T00:00:00"), Some(0.));
+        assert_eq!(
+            parse_time("2000-02-29T12:30:15.25+02:00"),
+            Some(951820215.25)
+        );
+        assert_eq!(parse_time("2024-07-14T08:05:03-05:30"), Some(1720964103.));
+        assert_eq!(parse_time(" 1900-03-01T00:00:00Z\n"), Some(-2203891200.));
+        for invalid in &[
+            "2020-01-01",
+            "2020-1-01T00:00:00Z",
+            "2001-02-29T00:00:00Z",
+            "2020-13-01T00:00:00Z",
+            "2020-01-01T24:00:00Z",
+            "2020-01-01T12:00Z",
+            "2020-01-01T12:00:00.Z",
+            "2020-01-01T12:00:00+1:00",
+            "2020-01-01T12:00:00UTC",
+            "+2020-01-01T12:00:00Z",
+        ] {
+            assert_eq!(parse_time(invalid), None, "{}", invalid);
+        }
+
+        assert_eq!(format_time(0.), "1970-01-01T00:00:00Z");
+        assert_eq!(format_time(951820215.25), "2000-02-29T10:30:15.250Z");
+        assert_eq!(format_time(-1.), "1969-12-31T23:59:59Z");
+        assert_eq!(format_time(-2203891200.), "1900-03-01T00:00:00Z");
+        assert_eq!(format_time(59.9999), "1970-01-01T00:01:00Z");
+    }
+
+    #[test]
+    fn errors() {
+        let read = |xml: &str| Gpx::<f64>::read(xml.as_bytes());
+        assert!(matches!(read(""), Err(GpxError::NotGpx)));
+        assert!(matches!(read("<kml></kml>"), Err(GpxError::NotGpx)));
+        assert!(matches!(read("<gpx/><gpx/>"), Err(GpxError::NotGpx)));
+        assert!(matches!(read("<gpx><rte></gpx>"), Err(GpxError::Xml(_))));
+        assert!(matches!(read("<gpx>"), Err(GpxError::Xml(_))));
+        assert!(matches!(
+            read(r#"<gpx><wpt lat="1"/></gpx>"#),
+            Err(GpxError::MissingCoordinate("lon"))
+        ));
+        assert!(matches!(
+            read(r#"<gpx><wpt lat="north" lon="1"/></gpx>"#),
+            Err(GpxError::InvalidNumber(_))
+        ));
+        assert!(matches!(
+            read(r#"<gpx><wpt lat="1" lon="1"><ele>high</ele></wpt></gpx>"#),
+            Err(GpxError::InvalidNumber(_))
+        ));
+        assert!(matches!(
+            read(r#"<gpx><wpt lat="1" lon="1"><time>noon</time></wpt></gpx>"#),
+            Err(GpxError::InvalidTime(_))
+        ));
+        assert!(matches!(
+            Gpx::<u8>::read(&br#"<gpx><wpt lat="-1" lon="1"/></gpx>"#[..]),
+            Err(GpxError::InvalidCoordinate(_))
+        ));
+    }
+}
@@ -0,0 +1,681 @@
+//! Reading and writing the geometries of [KML](https://www.ogc.org/standards/kml/) files.
+//!
+//! With the `kml` feature enabled, [`Kml::read`](struct.Kml.html#method.read) reads the
+//! placemarks of a KML file, wherever they are in its documents and folders, with their names,
+//! descriptions, and geometries, and [`Kml::write`](struct.Kml.html#method.write) writes them
+//! back in a single document. Styles, extended data, and other elements are skipped.
+//! [`Geometry::from_kml`](../enum.Geometry.html#method.from_kml) and
+//! [`Geometry::to_kml`](../enum.Geometry.html#method.to_kml) read and write a single geometry
+//! element.
+//!
+//! `Point`, `LineString`, `LinearRing`, and `Polygon` elements convert to `Point`s,
+//! `LineString`s, `LineString`s, and `Polygon`s, with longitudes as `x` and latitudes as `y`:
+//! altitudes are ignored. A `MultiGeometry` converts to a `MultiPoint`, `MultiLineString`, or
+//! `MultiPolygon` if all of its geometries are points, line strings, or polygons, and to a
+//! `GeometryCollection`, which can nest, otherwise. When writing, a `Line` is written as a
+//! `LineString`, a `Rect` or `Triangle` as a `Polygon`, and multi-geometries and geometry
+//! collections as `MultiGeometry` elements.
+//!
+//! Coordinates are always `f64`s in KML: other coordinate types are converted using `NumCast`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::kml::{Kml, KmlError};
+//! use geo_types::{point, polygon, Geometry, MultiPoint};
+//!
+//! let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+//! <kml xmlns="http://www.opengis.net/kml/2.2">
+//!   <Document>
+//!     <Folder>
+//!       <Placemark>
+//!         <name>Meetup</name>
+//!         <Point><coordinates>8.54,47.37,408</coordinates></Point>
+//!       </Placemark>
+//!       <Placemark>
+//!         <name>Market</name>
+//!         <MultiGeometry>
+//!           <Point><coordinates>8.5,47.3</coordinates></Point>
+//!           <Point><coordinates>8.6,47.4</coordinates></Point>
+//!         </MultiGeometry>
+//!       </Placemark>
+//!     </Folder>
+//!   </Document>
+//! </kml>"#;
+//!
+//! let kml = Kml::<f64>::read(xml.as_bytes())?;
+//! assert_eq!(kml.placemarks[0].name.as_deref(), Some("Meetup"));
+//! assert_eq!(
+//!     kml.placemarks[0].geometry,
+//!     Some(Geometry::Point(point!(x: 8.54, y: 47.37)))
+//! );
+//! assert_eq!(
+//!     kml.placemarks[1].geometry,
+//!     Some(Geometry::MultiPoint(MultiPoint(vec![
+//!         point!(x: 8.5, y: 47.3),
+//!         point!(x: 8.6, y: 47.4),
+//!     ])))
+//! );
+//!
+//! let mut written = vec![];
+//! kml.write(&mut written)?;
+//! assert_eq!(Kml::read(&written[..])?, kml);
+//!
+//! let square = Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]);
+//! assert_eq!(Geometry::from_kml(&square.to_kml())?, square);
+//! # Ok::<(), KmlError>(())
+//! ```
+use crate::xml::{self, Element, XmlError};
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
+};
+use quick_xml::escape::escape;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// The error returned when reading or writing KML fails.
+#[derive(Debug)]
+pub enum KmlError {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The input isn't well-formed XML.
+    Xml(String),
+    /// The input's root element isn't `kml`.
+    NotKml,
+    /// The element with the given name isn't a KML geometry.
+    NotAGeometry(String),
+    /// An element is missing a child it needs, such as the `coordinates` of a `Point`.
+    MissingElement {
+        element: &'static str,
+        parent: String,
+    },
+    /// The `coordinates` of a geometry can't be parsed, or a point doesn't have exactly one.
+    InvalidCoordinates(String),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+}
+
+impl fmt::Display for KmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KmlError::Io(e) => write!(f, "{}", e),
+            KmlError::Xml(e) => write!(f, "Invalid XML: {}", e),
+            KmlError::NotKml => write!(f, "Not a KML file"),
+            KmlError::NotAGeometry(name) => write!(f, "'{}' isn't a KML geometry", name),
+            KmlError::MissingElement { element, parent } => {
+                write!(f, "'{}' without a '{}' element", parent, element)
+            }
+            KmlError::InvalidCoordinates(coordinates) => {
+                write!(f, "Invalid coordinates '{}'", coordinates)
+            }
+            KmlError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+        }
+    }
+}
+
+impl Error for KmlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KmlError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for KmlError {
+    fn from(e: io::Error) -> Self {
+        KmlError::Io(e)
+    }
+}
+
+impl From<XmlError> for KmlError {
+    fn from(e: XmlError) -> Self {
+        match e {
+            XmlError::Io(e) => KmlError::Io(e),
+            XmlError::Syntax(e) => KmlError::Xml(e),
+        }
+    }
+}
+
+/// A placemark, with its geometry, if it has one which can be read.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placemark<T: CoordinateType> {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The geometry, or `None` if the placemark has no geometry, or only one which isn't
+    /// supported, such as a `Model` or a `gx:Track`.
+    pub geometry: Option<Geometry<T>>,
+}
+
+impl<T: CoordinateType> From<Geometry<T>> for Placemark<T> {
+    fn from(geometry: Geometry<T>) -> Self {
+        Placemark {
+            name: None,
+            description: None,
+            geometry: Some(geometry),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Kml<T: CoordinateType> {
+    pub placemarks: Vec<Placemark<T>>,
+}
+
+impl<T: CoordinateType> Default for Kml<T> {
+    fn default() -> Self {
+        Kml { placemarks: vec![] }
+    }
+}
+
+impl<T: CoordinateType> Kml<T> {
+    /// Read the placemarks of a KML file, in the order they appear in it.
+    pub fn read<R: BufRead>(reader: R) -> Result<Kml<T>, KmlError> {
+        let mut placemarks = vec![];
+        let root = xml::read(
+            reader,
+            |element| element.name == "Placemark",
+            |element| {
+                placemarks.push(read_placemark(&element)?);
+                Ok::<_, KmlError>(())
+            },
+        )?;
+        match root {
+            Some(root) if root.name == "kml" => Ok(Kml { placemarks }),
+            _ => Err(KmlError::NotKml),
+        }
+    }
+
+    /// Write the placemarks as a KML 2.2 file, with a single document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to `f64`.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), KmlError> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, r#"<kml xmlns="http://www.opengis.net/kml/2.2">"#)?;
+        writeln!(writer, "  <Document>")?;
+        for placemark in &self.placemarks {
+            writeln!(writer, "    <Placemark>")?;
+            if let Some(name) = &placemark.name {
+                writeln!(writer, "      <name>{}</name>", escape(name.as_str()))?;
+            }
+            if let Some(description) = &placemark.description {
+                writeln!(
+                    writer,
+                    "      <description>{}</description>",
+                    escape(description.as_str())
+                )?;
+            }
+            if let Some(geometry) = &placemark.geometry {
+                writeln!(writer, "      {}", geometry.to_kml())?;
+            }
+            writeln!(writer, "    </Placemark>")?;
+        }
+        writeln!(writer, "  </Document>")?;
+        writeln!(writer, "</kml>")?;
+        Ok(())
+    }
+}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Read a KML geometry element, such as `<Point><coordinates>1,2</coordinates></Point>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry};
+    ///
+    /// let kml = "<LineString><coordinates>1,2,0 3,4,0</coordinates></LineString>";
+    /// assert_eq!(
+    ///     Geometry::from_kml(kml).unwrap(),
+    ///     Geometry::LineString(line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+    /// );
+    /// ```
+    pub fn from_kml(kml: &str) -> Result<Geometry<T>, KmlError> {
+        let mut geometry = None;
+        xml::read(
+            kml.as_bytes(),
+            |_| true,
+            |element| {
+                geometry = Some(read_geometry(&element)?);
+                Ok::<_, KmlError>(())
+            },
+        )?;
+        geometry.ok_or_else(|| KmlError::Xml("no root element".to_string()))
+    }
+
+    /// Write this geometry as a KML geometry element, without a namespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// assert_eq!(
+    ///     Geometry::Point(point!(x: 1.5, y: -2.)).to_kml(),
+    ///     "<Point><coordinates>1.5,-2</coordinates></Point>"
+    /// );
+    /// ```
+    pub fn to_kml(&self) -> String {
+        let mut kml = String::new();
+        write_geometry(&mut kml, self);
+        kml
+    }
+}
+
+// Reading
+
+fn read_placemark<T: CoordinateType>(placemark: &Element) -> Result<Placemark<T>, KmlError> {
+    let text = |name| {
+        placemark
+            .child(name)
+            .map(|child| child.text.trim().to_string())
+    };
+    let geometry = placemark
+        .children
+        .iter()
+        .find(|child| is_geometry(&child.name))
+        .map(read_geometry)
+        .transpose()?;
+    Ok(Placemark {
+        name: text("name"),
+        description: text("description"),
+        geometry,
+    })
+}
+
+fn is_geometry(name: &str) -> bool {
+    matches!(
+        name,
+        "Point" | "LineString" | "LinearRing" | "Polygon" | "MultiGeometry"
+    )
+}
+
+fn read_geometry<T: CoordinateType>(element: &Element) -> Result<Geometry<T>, KmlError> {
+    match element.name.as_str() {
+        "Point" => match read_coordinates(element)?[..] {
+            [coord] => Ok(Geometry::Point(Point(coord))),
+            _ => Err(KmlError::InvalidCoordinates(coordinates(element)?.clone())),
+        },
+        "LineString" | "LinearRing" => {
+            Ok(Geometry::LineString(LineString(read_coordinates(element)?)))
+        }
+        "Polygon" => read_polygon(element).map(Geometry::Polygon),
+        "MultiGeometry" => read_multi_geometry(element),
+        name => Err(KmlError::NotAGeometry(name.to_string())),
+    }
+}
+
+fn missing(element: &'static str, parent: &Element) -> KmlError {
+    KmlError::MissingElement {
+        element,
+        parent: parent.name.clone(),
+    }
+}
+
+fn coordinates(element: &Element) -> Result<&String, KmlError> {
+    element
+        .child("coordinates")
+        .map(|coordinates| &coordinates.text)
+        .ok_or_else(|| missing("coordinates", element))
+}
+
+fn read_coordinates<T: CoordinateType>(element: &Element) -> Result<Vec<Coordinate<T>>, KmlError> {
+    let text = coordinates(element)?;
+    let value = |v: f64| T::from(v).ok_or(KmlError::InvalidCoordinate(v));
+    xml::parse_tuples(text)
+        .ok_or_else(|| KmlError::InvalidCoordinates(text.clone()))?
+        .into_iter()
+        .map(|(x, y)| {
+            Ok(Coordinate {
+                x: value(x)?,
+                y: value(y)?,
+            })
+        })
+        .collect()
+}
+
+fn read_ring<T: CoordinateType>(boundary: &Element) -> Result<LineString<T>, KmlError> {
+    let ring = boundary
+        .child("LinearRing")
+        .ok_or_else(|| missing("LinearRing", boundary))?;
+    read_coordinates(ring).map(LineString)
+}
+
+fn read_polygon<T: CoordinateType>(polygon: &Element) -> Result<Polygon<T>, KmlError> {
+    let exterior = read_ring(
+        polygon
+            .child("outerBoundaryIs")
+            .ok_or_else(|| missing("outerBoundaryIs", polygon))?,
+    )?;
+    // Each inner boundary should have a single ring, but some files put several in one
+    let mut interiors = vec![];
+    for boundary in polygon.children_named("innerBoundaryIs") {
+        for ring in boundary.children_named("LinearRing") {
+            interiors.push(read_coordinates(ring).map(LineString)?);
+        }
+    }
+    Ok(Polygon::new(exterior, interiors))
+}
+
+fn read_multi_geometry<T: CoordinateType>(element: &Element) -> Result<Geometry<T>, KmlError> {
+    let geometries = element
+        .children
+        .iter()
+        .filter(|child| is_geometry(&child.name))
+        .map(read_geometry)
+        .collect::<Result<Vec<_>, _>>()?;
+    if geometries.is_empty() {
+        return Ok(Geometry::GeometryCollection(GeometryCollection(vec![])));
+    }
+    if geometries.iter().all(|g| matches!(g, Geometry::Point(_))) {
+        let points = geometries.into_iter().filter_map(|g| match g {
+            Geometry::Point(p) => Some(p),
+            _ => None,
+        });
+        return Ok(Geometry::MultiPoint(MultiPoint(points.collect())));
+    }
+    if geometries
+        .iter()
+        .all(|g| matches!(g, Geometry::LineString(_)))
+    {
+        let line_strings = geometries.into_iter().filter_map(|g| match g {
+            Geometry::LineString(ls) => Some(ls),
+            _ => None,
+        });
+        return Ok(Geometry::MultiLineString(MultiLineString(
+            line_strings.collect(),
+        )));
+    }
+    if geometries.iter().all(|g| matches!(g, Geometry::Polygon(_))) {
+        let polygons = geometries.into_iter().filter_map(|g| match g {
+            Geometry::Polygon(p) => Some(p),
+            _ => None,
+        });
+        return Ok(Geometry::MultiPolygon(MultiPolygon(polygons.collect())));
+    }
+    Ok(Geometry::GeometryCollection(GeometryCollection(geometries)))
+}
+
+// Writing
+
+fn write_coordinates<T: CoordinateType>(kml: &mut String, coords: &[Coordinate<T>]) {
+    let value = |v: T| v.to_f64().expect("coordinate can't be converted to f64");
+    kml.push_str("<coordinates>");
+    for (i, coord) in coords.iter().enumerate() {
+        if i > 0 {
+            kml.push(' ');
+        }
+        kml.push_str(&format!("{},{}", value(coord.x), value(coord.y)));
+    }
+    kml.push_str("</coordinates>");
+}
+
+fn write_line_string<T: CoordinateType>(kml: &mut String, coords: &[Coordinate<T>]) {
+    kml.push_str("<LineString>");
+    write_coordinates(kml, coords);
+    kml.push_str("</LineString>");
+}
+
+fn write_polygon<T: CoordinateType>(kml: &mut String, polygon: &Polygon<T>) {
+    kml.push_str("<Polygon><outerBoundaryIs><LinearRing>");
+    write_coordinates(kml, &polygon.exterior().0);
+    kml.push_str("</LinearRing></outerBoundaryIs>");
+    for interior in polygon.interiors() {
+        kml.push_str("<innerBoundaryIs><LinearRing>");
+        write_coordinates(kml, &interior.0);
+        kml.push_str("</LinearRing></innerBoundaryIs>");
+    }
+    kml.push_str("</Polygon>");
+}
+
+fn write_geometry<T: CoordinateType>(kml: &mut String, geometry: &Geometry<T>) {
+    match geometry {
+        Geometry::Point(p) => {
+            kml.push_str("<Point>");
+            write_coordinates(kml, &[p.0]);
+            kml.push_str("</Point>");
+        }
+        Geometry::Line(l) => write_line_string(kml, &[l.start, l.end]),
+        Geometry::LineString(ls) => write_line_string(kml, &ls.0),
+        Geometry::Polygon(p) => write_polygon(kml, p),
+        Geometry::Rect(r) => write_polygon(kml, &Polygon::from(*r)),
+        Geometry::Triangle(t) => write_polygon(kml, &Polygon::from(*t)),
+        Geometry::MultiPoint(mp) => {
+            kml.push_str("<MultiGeometry>");
+            for p in &mp.0 {
+                write_geometry(kml, &Geometry::Point(*p));
+            }
+            kml.push_str("</MultiGeometry>");
+        }
+        Geometry::MultiLineString(mls) => {
+            kml.push_str("<MultiGeometry>");
+            for ls in &mls.0 {
+                write_line_string(kml, &ls.0);
+            }
+            kml.push_str("</MultiGeometry>");
+        }
+        Geometry::MultiPolygon(mp) => {
+            kml.push_str("<MultiGeometry>");
+            for p in &mp.0 {
+                write_polygon(kml, p);
+            }
+            kml.push_str("</MultiGeometry>");
+        }
+        Geometry::GeometryCollection(gc) => {
+            kml.push_str("<MultiGeometry>");
+            for g in &gc.0 {
+                write_geometry(kml, g);
+            }
+            kml.push_str("</MultiGeometry>");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Rect};
+
+    const KML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+  <Document>
+    <name>Not a placemark</name>
+    <Style id="red"><LineStyle><color>ff0000ff</color></LineStyle></Style>
+    <Placemark>
+      <name> Caf&#233; &amp; Bar </name>
+      <description><![CDATA[<b>Open</b> daily]]></description>
+      <styleUrl>#red</styleUrl>
+      <Point><extrude>1</extrude><coordinates>
+        8.54,47.37,0
+      </coordinates></Point>
+    </Placemark>
+    <Folder>
+      <Folder>
+        <Placemark>
+          <Polygon>
+            <outerBoundaryIs><LinearRing><coordinates>0,0 4,0 4,4 0,4 0,0</coordinates></LinearRing></outerBoundaryIs>
+            <innerBoundaryIs><LinearRing><coordinates>1,1 2,1 2,2 1,1</coordinates></LinearRing></innerBoundaryIs>
+            <innerBoundaryIs><LinearRing><coordinates>3,3 3.5,3 3.5,3.5 3,3</coordinates></LinearRing></innerBoundaryIs>
+          </Polygon>
+        </Placemark>
+      </Folder>
+      <Placemark>
+        <MultiGeometry>
+          <Point><coordinates>1,2</coordinates></Point>
+          <MultiGeometry>
+            <LineString><coordinates>1,2 3,4</coordinates></LineString>
+            <LinearRing><coordinates>0,0 1,0 1,1 0,0</coordinates></LinearRing>
+          </MultiGeometry>
+        </MultiGeometry>
+      </Placemark>
+      <Placemark>
+        <name>Recorded</name>
+        <gx:Track><when>2020-01-01T12:00:00Z</when><gx:coord>8.54 47.37 0</gx:coord></gx:Track>
+      </Placemark>
+    </Folder>
+  </Document>
+</kml>
+"#;
+
+    #[test]
+    fn read() {
+        let kml = Kml::<f64>::read(KML.as_bytes()).unwrap();
+        assert_eq!(
+            kml.placemarks,
+            vec![
+                Placemark {
+                    name: Some("Café & Bar".to_string()),
+                    description: Some("<b>Open</b> daily".to_string()),
+                    geometry: Some(Geometry::Point(point!(x: 8.54, y: 47.37))),
+                },
+                Placemark::from(Geometry::Polygon(Polygon::new(
+                    line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                    vec![
+                        line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+                        line_string![(x: 3., y: 3.), (x: 3.5, y: 3.), (x: 3.5, y: 3.5)],
+                    ],
+                ))),
+                Placemark::from(Geometry::GeometryCollection(GeometryCollection(vec![
+                    Geometry::Point(point!(x: 1., y: 2.)),
+                    Geometry::MultiLineString(MultiLineString(vec![
+                        line_string![(x: 1., y: 2.), (x: 3., y: 4.)],
+                        line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)],
+                    ])),
+                ]))),
+                Placemark {
+                    name: Some("Recorded".to_string()),
+                    description: None,
+                    geometry: None,
+                },
+            ]
+        );
+
+        // KML 2.1 and files without a namespace
+        let kml = Kml::<i32>::read(
+            &br#"<kml><Placemark><Point><coordinates>1,2</coordinates></Point></Placemark></kml>"#
+                [..],
+        )
+        .unwrap();
+        assert_eq!(
+            kml.placemarks,
+            vec![Placemark::from(Geometry::Point(point!(x: 1, y: 2)))]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut kml = Kml::<f64>::read(KML.as_bytes()).unwrap();
+        kml.placemarks[0].name = Some("'Quoted' <name>".to_string());
+        kml.placemarks
+            .push(Placemark::from(Geometry::MultiPolygon(MultiPolygon(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+                polygon![(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.)],
+            ]))));
+        kml.placemarks
+            .push(Placemark::from(Geometry::GeometryCollection(
+                GeometryCollection(vec![]),
+            )));
+        let mut written = vec![];
+        kml.write(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.contains("<Point><coordinates>8.54,47.37</coordinates></Point>"));
+        assert!(written.contains("<description>&lt;b&gt;Open&lt;/b&gt; daily</description>"));
+        assert_eq!(Kml::read(written.as_bytes()).unwrap(), kml);
+    }
+
+    #[test]
+    fn geometries() {
+        let write_read = |geometry: Geometry<i32>| Geometry::from_kml(&geometry.to_kml()).unwrap();
+        assert_eq!(
+            write_read(Geometry::Line(Line::new((0, 0), (1, 2)))),
+            Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 2)])
+        );
+        assert_eq!(
+            write_read(Geometry::Rect(Rect::new((0, 0), (1, 2)))),
+            Geometry::Polygon(Polygon::from(Rect::new((0, 0), (1, 2))))
+        );
+        let multi_point = Geometry::MultiPoint(MultiPoint(vec![point!(x: 1, y: 2)]));
+        assert_eq!(write_read(multi_point.clone()), multi_point);
+        // collections of a single kind of geometry read back as multi-geometries
+        assert_eq!(
+            write_read(Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Point(point!(x: 1, y: 2))
+            ]))),
+            multi_point
+        );
+        assert_eq!(
+            Geometry::<f64>::LineString(LineString(vec![])).to_kml(),
+            "<LineString><coordinates></coordinates></LineString>"
+        );
+        assert_eq!(
+            Geometry::<f64>::from_kml("<LineString><coordinates/></LineString>").unwrap(),
+            Geometry::LineString(LineString(vec![]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let read = |xml: &str| Kml::<f64>::read(xml.as_bytes());
+        assert!(matches!(read(""), Err(KmlError::NotKml)));
+        assert!(matches!(read("<gpx></gpx>"), Err(KmlError::NotKml)));
+        assert!(matches!(
+            read("<kml><Placemark></kml>"),
+            Err(KmlError::Xml(_))
+        ));
+
+        let from_kml = |kml: &str| Geometry::<f64>::from_kml(kml);
+        assert!(matches!(from_kml(""), Err(KmlError::Xml(_))));
+        assert!(matches!(
+            from_kml("<Placemark/>"),
+            Err(KmlError::NotAGeometry(name)) if name == "Placemark"
+        ));
+        assert!(matches!(
+            from_kml("<Point/>"),
+            Err(KmlError::MissingElement {
+                element: "coordinates",
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_kml("<Polygon><innerBoundaryIs/></Polygon>"),
+            Err(KmlError::MissingElement {
+                element: "outerBoundaryIs",
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_kml("<Polygon><outerBoundaryIs/></Polygon>"),
+            Err(KmlError::MissingElement {
+                element: "LinearRing",
+                ..
+            })
+        ));
+        for invalid in &["", "1,2 3,4", "1;2", "1,2,3,4"] {
+            assert!(
+                matches!(
+                    from_kml(&format!(
+                        "<Point><coordinates>{}</coordinates></Point>",
+                        invalid
+                    )),
+                    Err(KmlError::InvalidCoordinates(_))
+                ),
+                "{}",
+                invalid
+            );
+        }
+        assert!(matches!(
+            Geometry::<u8>::from_kml("<Point><coordinates>-1,2</coordinates></Point>"),
+            Err(KmlError::InvalidCoordinate(_))
+        ));
+    }
+}
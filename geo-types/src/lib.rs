@@ -6,6 +6,25 @@
 //! with other `GeoRust` crates. Otherwise, the [`geo`](https://crates.io/crates/geo) crate re-exports these types and
 //! provides geospatial algorithms, while the [`geojson`](https://crates.io/crates/geojson) crate allows serialising
 //! and de-serialising `geo-types` primitives to GeoJSON.
+//!
+//! # `no_std`
+//!
+//! Without its default `std` feature, the crate only needs `core` and `alloc`, so the
+//! geometries, their bounded and streaming forms, and
+//! [`PackedRTree`](struct.PackedRTree.html) can be used where the standard library isn't
+//! available, such as on chain. Floating point functions then come from `libm`. The `wkb`
+//! feature reads byte slices and writes `Vec`s without it, but the other features reading and
+//! writing formats, such as `geojson`, turn `std` back on, and
+//! [`IoWriter`](stream/struct.IoWriter.html) and the streaming WKB reader and writer need it.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+// For the macros, which can't name `alloc` in crates without `extern crate alloc;`
+#[doc(hidden)]
+pub extern crate alloc as _alloc;
+
 extern crate num_traits;
 
 #[cfg(feature = "serde")]
@@ -15,7 +34,13 @@ extern crate serde;
 #[cfg(feature = "rstar")]
 extern crate rstar;
 
-use num_traits::{Num, NumCast};
+#[cfg(feature = "geojson")]
+extern crate serde_json;
+
+#[cfg(feature = "scale-codec")]
+extern crate codec;
+
+use num_traits::{Float, Num, NumCast};
 
 /// The type of an x or y value of a point/coordinate.
 ///
@@ -25,6 +50,17 @@ pub trait CoordinateType: Num + Copy + NumCast + PartialOrd {}
 // Little bit of a hack to make to make this work
 impl<T: Num + Copy + NumCast + PartialOrd> CoordinateType for T {}
 
+/// A [`CoordinateType`](trait.CoordinateType.html) which can represent the points between other
+/// coordinates, such as where two segments cross.
+///
+/// Floats (`f32` and `f64`) implement this, as does
+/// [`Fixed`](fixed_point/struct.Fixed.html) with the `fixed-point` feature. Integers don't:
+/// algorithms which compute new vertices, like boolean operations, would have to round them off
+/// the segments they're on, and give wrong results.
+pub trait FractionalCoordinateType: CoordinateType {}
+
+impl<T: Float> FractionalCoordinateType for T {}
+
 mod coordinate;
 pub use crate::coordinate::Coordinate;
 
@@ -40,6 +76,9 @@ pub use crate::line::Line;
 mod line_string;
 pub use crate::line_string::{LineString, PointsIter};
 
+mod coord_buffer;
+pub use crate::coord_buffer::{CoordBuffer, CoordBufferIter};
+
 mod multi_line_string;
 pub use crate::multi_line_string::MultiLineString;
 
@@ -49,18 +88,138 @@ pub use crate::polygon::Polygon;
 mod multi_polygon;
 pub use crate::multi_polygon::MultiPolygon;
 
+mod error;
+pub use crate::error::Error;
+
 mod geometry;
-pub use crate::geometry::Geometry;
+#[cfg(feature = "rstar")]
+pub use crate::geometry::GeometryCell;
+pub use crate::geometry::{Geometry, GeometryType};
+
+mod shared_geometry;
+pub use crate::shared_geometry::SharedGeometry;
 
 mod geometry_collection;
 pub use crate::geometry_collection::GeometryCollection;
 
+mod geometry_collection_index;
+pub use crate::geometry_collection_index::GeometryCollectionIndex;
+
+mod packed_rtree;
+pub use crate::packed_rtree::{PackedRTree, PackedRTreeObject};
+
 mod triangle;
 pub use crate::triangle::Triangle;
 
 mod rect;
 pub use crate::rect::Rect;
 
+mod total_eq;
+pub use crate::total_eq::{Total, TotalEq};
+
+mod visitor;
+pub use crate::visitor::GeometryVisitor;
+
+mod coordinate_z;
+pub use crate::coordinate_z::CoordinateZ;
+
+mod point_z;
+pub use crate::point_z::PointZ;
+
+mod multi_point_z;
+pub use crate::multi_point_z::MultiPointZ;
+
+mod line_string_z;
+pub use crate::line_string_z::LineStringZ;
+
+mod multi_line_string_z;
+pub use crate::multi_line_string_z::MultiLineStringZ;
+
+mod polygon_z;
+pub use crate::polygon_z::PolygonZ;
+
+mod multi_polygon_z;
+pub use crate::multi_polygon_z::MultiPolygonZ;
+
+mod geometry_z;
+pub use crate::geometry_z::GeometryZ;
+
+mod geometry_collection_z;
+pub use crate::geometry_collection_z::GeometryCollectionZ;
+
+mod coordinate_m;
+pub use crate::coordinate_m::CoordinateM;
+
+mod point_m;
+pub use crate::point_m::PointM;
+
+mod line_string_m;
+pub use crate::line_string_m::LineStringM;
+
+pub mod bounded;
+
+pub mod stream;
+
+#[cfg(feature = "fixed-point")]
+pub mod fixed_point;
+
+#[cfg(feature = "smallvec")]
+pub mod small;
+
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+
+#[cfg(feature = "wkt")]
+pub mod wkt;
+
+#[cfg(feature = "wkb")]
+pub mod wkb;
+
+#[cfg(feature = "geohash")]
+pub mod geohash;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+#[cfg(feature = "geos")]
+pub mod geos;
+
+#[cfg(feature = "sqlx-postgres")]
+pub mod sqlx_postgres;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "flatgeobuf")]
+pub mod flatgeobuf;
+
+#[cfg(feature = "shapefile")]
+pub mod shapefile;
+
+#[cfg(feature = "gpx")]
+pub mod gpx;
+
+#[cfg(feature = "polyline")]
+pub mod polyline;
+
+#[cfg(feature = "twkb")]
+pub mod twkb;
+
+#[cfg(feature = "geobuf")]
+pub mod geobuf;
+
+#[cfg(feature = "kml")]
+pub mod kml;
+
+#[cfg(feature = "gml")]
+pub mod gml;
+
+#[cfg(feature = "rand")]
+pub mod random;
+
+#[cfg(any(feature = "kml", feature = "gml"))]
+mod xml;
+
 #[macro_use]
 mod macros;
 
@@ -74,7 +233,7 @@ extern crate approx;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::convert::TryFrom;
+    use core::convert::TryFrom;
 
     #[test]
     fn type_test() {
@@ -95,15 +254,116 @@ mod tests {
         assert_relative_eq!(p.y(), 1.);
     }
 
+    #[test]
+    fn const_constructors() {
+        const CITY_CENTER: Point<f64> = Point::new(7.44, 46.95);
+        static BOUNDS: Rect<f64> =
+            Rect::new_unchecked(Coordinate::new(7.29, 46.91), Coordinate::new(7.5, 46.99));
+        const AXIS: Line<i64> = Line::new_const(Coordinate::new(0, 0), Coordinate::new(0, 4096));
+        const CORNER: Triangle<i64> = Triangle::new(
+            AXIS.start,
+            AXIS.end,
+            Coordinate::new(AXIS.end.y, AXIS.start.x),
+        );
+        const LON_LAT: (f64, f64) = CITY_CENTER.x_y();
+
+        assert_eq!(LON_LAT, (7.44, 46.95));
+        assert_eq!(BOUNDS, Rect::new((7.29, 46.91), (7.5, 46.99)));
+        assert_eq!(BOUNDS.min(), Coordinate { x: 7.29, y: 46.91 });
+        assert_eq!(AXIS.end_point(), Point::new(0, 4096));
+        assert_eq!(CORNER.to_array()[2], Coordinate { x: 4096, y: 0 });
+    }
+
+    #[test]
+    fn macros() {
+        // every arm works through its full path, without the other macros imported
+        assert_eq!(
+            crate::polygon![],
+            Polygon::<f64>::new(LineString(vec![]), vec![])
+        );
+        assert_eq!(
+            crate::line_string![(x: 1, y: 2)],
+            LineString(vec![Coordinate { x: 1, y: 2 }])
+        );
+        assert_eq!(
+            crate::multi_line_string![[(x: 1, y: 2)], [], [crate::coord!(x: 3, y: 4)]],
+            MultiLineString(vec![
+                LineString(vec![Coordinate { x: 1, y: 2 }]),
+                LineString(vec![]),
+                LineString(vec![Coordinate { x: 3, y: 4 }]),
+            ])
+        );
+        let polygon = crate::polygon!(
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+        );
+        assert_eq!(
+            crate::multi_polygon![
+                (
+                    exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4)],
+                    interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+                ),
+                [(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1)],
+            ],
+            MultiPolygon(vec![
+                polygon,
+                Polygon::new(vec![(0, 0), (1, 0), (1, 1)].into(), vec![])
+            ])
+        );
+        assert_eq!(
+            crate::multi_point![(x: 1, y: 2)],
+            MultiPoint(vec![Point::new(1, 2)])
+        );
+        assert_eq!(
+            crate::triangle![(x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1)],
+            Triangle::from([(0, 0), (1, 0), (0, 1)])
+        );
+        assert_eq!(
+            crate::rect!(min: (x: 0, y: 0), max: (x: 1, y: 2)),
+            Rect::new((0, 0), (1, 2))
+        );
+        assert_eq!(
+            crate::geometry_collection![Point::new(0, 0), Line::new((0, 0), (1, 1))],
+            GeometryCollection(vec![
+                Geometry::Point(Point::new(0, 0)),
+                Geometry::Line(Line::new((0, 0), (1, 1))),
+            ])
+        );
+        let empty: GeometryCollection<f64> = crate::geometry_collection![];
+        assert!(empty.0.is_empty());
+    }
+
     #[test]
     fn convert_types() {
         let p: Point<f32> = Point::new(0., 0.);
-        let p1 = p.clone();
+        let p1 = p;
         let g: Geometry<f32> = p.into();
         let p2 = Point::try_from(g).unwrap();
         assert_eq!(p1, p2);
     }
 
+    #[test]
+    fn geometry_accessors() {
+        let mut g: Geometry<f64> = Rect::new((0., 0.), (1., 1.)).into();
+        assert_eq!(g.geometry_type(), GeometryType::Rect);
+        assert_eq!(g.as_rect().map(|rect| rect.max().x), Some(1.));
+        assert!(g.as_polygon().is_none());
+        assert!(g.as_point_mut().is_none());
+        g.as_rect_mut().unwrap().set_max((2., 3.));
+        assert_eq!(g, Rect::new((0., 0.), (2., 3.)).into());
+
+        let mut gc = Geometry::GeometryCollection(GeometryCollection(vec![g]));
+        gc.as_geometry_collection_mut().unwrap().0.clear();
+        assert_eq!(
+            gc.as_geometry_collection(),
+            Some(&GeometryCollection(vec![]))
+        );
+        assert_eq!(
+            format!("{} {:?}", gc.geometry_type(), GeometryType::MultiPoint),
+            "GeometryCollection MultiPoint"
+        );
+    }
+
     #[test]
     fn polygon_new_test() {
         let exterior = LineString(vec![
@@ -162,6 +422,143 @@ mod tests {
         assert_relative_eq!(25.999999999999996, l.distance_2(&Point::new(4.0, 10.0)));
     }
 
+    #[cfg(feature = "rstar")]
+    #[test]
+    fn polygon_rect_triangle_test() {
+        use rstar::{PointDistance, RTreeObject, AABB};
+
+        let polygon = Polygon::new(
+            vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)].into(),
+            vec![vec![(2., 2.), (8., 2.), (8., 8.), (2., 8.)].into()],
+        );
+        assert_eq!(
+            polygon.envelope(),
+            AABB::from_corners(Point::new(0., 0.), Point::new(10., 10.))
+        );
+        assert_relative_eq!(polygon.distance_2(&Point::new(1., 5.)), 0.);
+        // the middle of the hole is outside the polygon
+        assert_relative_eq!(polygon.distance_2(&Point::new(5., 5.)), 9.);
+        assert_relative_eq!(polygon.distance_2(&Point::new(13., 14.)), 25.);
+
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 1. });
+        assert_eq!(
+            rect.envelope(),
+            AABB::from_corners(Point::new(0., 0.), Point::new(2., 1.))
+        );
+        assert_relative_eq!(rect.distance_2(&Point::new(1., 0.5)), 0.);
+        assert_relative_eq!(rect.distance_2(&Point::new(5., 5.)), 25.);
+
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        assert_eq!(
+            triangle.envelope(),
+            AABB::from_corners(Point::new(0., 0.), Point::new(4., 4.))
+        );
+        assert_relative_eq!(triangle.distance_2(&Point::new(1., 1.)), 0.);
+        assert_relative_eq!(triangle.distance_2(&Point::new(3., 3.)), 2.);
+        assert_relative_eq!(triangle.distance_2(&Point::new(-3., 0.)), 9.);
+    }
+
+    #[cfg(feature = "rstar")]
+    #[test]
+    fn rtree_test() {
+        use rstar::RTree;
+
+        let polygons = vec![
+            Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.)].into(), vec![]),
+            Polygon::new(vec![(5., 5.), (6., 5.), (6., 6.)].into(), vec![]),
+        ];
+        let tree = RTree::bulk_load(polygons.clone());
+        assert_eq!(
+            tree.locate_at_point(&Point::new(5.9, 5.1)),
+            Some(&polygons[1])
+        );
+        assert_eq!(tree.locate_at_point(&Point::new(5.1, 5.9)), None);
+
+        let geometries: Vec<GeometryCell<f64>> = vec![
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::MultiPoint(vec![(10., 10.), (20., 0.)].into()),
+            Geometry::Rect(Rect::new((5., -1.), (6., 1.))),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]
+        .into_iter()
+        .map(GeometryCell::from)
+        .collect();
+        let tree = RTree::bulk_load(geometries);
+        let nearest = |x, y| {
+            tree.nearest_neighbor(&Point::new(x, y))
+                .unwrap()
+                .geometry()
+                .type_name()
+        };
+        assert_eq!(nearest(1., 1.), "Point");
+        assert_eq!(nearest(5.5, 0.), "Rect");
+        assert_eq!(nearest(19., 1.), "MultiPoint");
+        assert_eq!(tree.locate_all_at_point(&Point::new(5.5, 0.5)).count(), 1);
+    }
+
+    #[cfg(feature = "scale-codec")]
+    #[test]
+    fn scale_codec_roundtrip() {
+        use codec::{Decode, Encode, MaxEncodedLen};
+
+        let gc: GeometryCollection<i64> = vec![
+            Geometry::Point(Point::new(1, 2)),
+            Geometry::Polygon(Polygon::new(
+                vec![(0, 0), (10, 0), (10, 10), (0, 0)].into(),
+                vec![vec![(1, 1), (2, 1), (2, 2)].into()],
+            )),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::MultiPoint(
+                vec![(0, 0), (1, 1)].into(),
+            )])),
+        ]
+        .into_iter()
+        .collect();
+        let encoded = gc.encode();
+        assert_eq!(GeometryCollection::decode(&mut &encoded[..]).unwrap(), gc);
+
+        assert_eq!(Point::<i64>::max_encoded_len(), 16);
+        assert_eq!(Line::<i32>::max_encoded_len(), 16);
+
+        // rings decoded off the wire are closed, as with Polygon::new
+        let open_ring = (
+            LineString::<i64>::from(vec![(0, 0), (10, 0), (10, 10)]),
+            Vec::<LineString<i64>>::new(),
+        );
+        let polygon = Polygon::<i64>::decode(&mut &open_ring.encode()[..]).unwrap();
+        assert_eq!(polygon.exterior().num_coords(), 4);
+    }
+
+    #[test]
+    fn z_types_to_2d() {
+        let exterior: LineStringZ<_> = vec![(0., 0., 5.), (1., 1., 6.), (1., 0., 7.)].into();
+        let polygon = PolygonZ::new(exterior, vec![]);
+        assert_eq!(polygon.exterior().num_coords(), 4);
+        assert_eq!(
+            polygon.exterior()[3],
+            CoordinateZ {
+                x: 0.,
+                y: 0.,
+                z: 5.
+            }
+        );
+
+        let gc: GeometryCollectionZ<_> = vec![
+            GeometryZ::Point(PointZ::new(1., 2., 3.)),
+            GeometryZ::Polygon(polygon),
+        ]
+        .into_iter()
+        .collect();
+        let flat = gc.to_2d();
+        assert_eq!(flat[0], Geometry::Point(Point::new(1., 2.)));
+        assert_eq!(
+            flat[1],
+            Geometry::Polygon(Polygon::new(
+                vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)].into(),
+                vec![]
+            ))
+        );
+    }
+
     #[test]
     fn test_rects() {
         let r = Rect::new(Coordinate { x: -1., y: -1. }, Coordinate { x: 1., y: 1. });
@@ -174,4 +571,54 @@ mod tests {
             )
         );
     }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_test() {
+        let polygon = Polygon::new(
+            vec![(0., 0.), (1., 0.), (1., 1.)].into(),
+            vec![vec![(0.2, 0.1), (0.8, 0.1), (0.8, 0.7)].into()],
+        );
+        let moved = Polygon::new(
+            vec![(0., 1e-10), (1., 0.), (1., 1.)].into(),
+            vec![vec![(0.2, 0.1), (0.8, 0.1 + 1e-10), (0.8, 0.7)].into()],
+        );
+        assert_relative_eq!(polygon, moved, epsilon = 1e-9);
+        assert_abs_diff_eq!(polygon, moved, epsilon = 1e-9);
+        assert_relative_ne!(polygon, moved, epsilon = 1e-11);
+        assert_ulps_ne!(polygon, moved);
+        assert_ulps_eq!(polygon, polygon.clone());
+
+        // with a different number of interiors
+        let filled = Polygon::new(polygon.exterior().clone(), vec![]);
+        assert_relative_ne!(polygon, filled, epsilon = 1.);
+
+        let line_string: LineString<f64> = vec![(0., 0.), (1., 1.)].into();
+        let shorter: LineString<f64> = vec![(0., 0.)].into();
+        assert_abs_diff_ne!(line_string, shorter, epsilon = 1.);
+
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(1., 2.)),
+            Geometry::LineString(line_string.clone()),
+        ]);
+        let nearly = GeometryCollection(vec![
+            Geometry::Point(Point::new(1., 2. + 1e-12)),
+            Geometry::LineString(line_string),
+        ]);
+        assert_relative_eq!(gc, nearly, epsilon = 1e-9);
+        assert_relative_eq!(
+            Geometry::GeometryCollection(gc),
+            Geometry::GeometryCollection(nearly),
+            epsilon = 1e-9
+        );
+
+        // geometries of different types are different, even if they cover the same points
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_relative_eq!(Geometry::Rect(rect), Geometry::Rect(rect));
+        assert_relative_ne!(
+            Geometry::Rect(rect),
+            Geometry::Polygon(Polygon::from(rect)),
+            epsilon = 1.
+        );
+    }
 }
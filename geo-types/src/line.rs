@@ -1,8 +1,12 @@
-use crate::{Coordinate, CoordinateType, Point};
+use crate::{Coordinate, CoordinateType, Point, Rect};
 
 /// A line segment made up of exactly two [`Point`s](struct.Point.html).
 #[derive(PartialEq, Clone, Copy, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "scale-codec",
+    derive(codec::Encode, codec::Decode, codec::MaxEncodedLen)
+)]
 pub struct Line<T>
 where
     T: CoordinateType,
@@ -37,6 +41,22 @@ where
         }
     }
 
+    /// Creates a new line segment from two coordinates, like [`new`](#method.new), but usable in
+    /// a `const` or `static`, since it doesn't go through `Into`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Line};
+    ///
+    /// static EQUATOR: Line<f64> = Line::new_const(Coordinate::new(-180., 0.), Coordinate::new(180., 0.));
+    ///
+    /// assert_eq!(EQUATOR, Line::new((-180., 0.), (180., 0.)));
+    /// ```
+    pub const fn new_const(start: Coordinate<T>, end: Coordinate<T>) -> Line<T> {
+        Line { start, end }
+    }
+
     /// Calculate the difference in ‘x’ components (Δx).
     ///
     /// Equivalent to:
@@ -135,17 +155,22 @@ where
         self.start.x * self.end.y - self.start.y * self.end.x
     }
 
-    pub fn start_point(&self) -> Point<T> {
+    pub const fn start_point(&self) -> Point<T> {
         Point(self.start)
     }
 
-    pub fn end_point(&self) -> Point<T> {
+    pub const fn end_point(&self) -> Point<T> {
         Point(self.end)
     }
 
-    pub fn points(&self) -> (Point<T>, Point<T>) {
+    pub const fn points(&self) -> (Point<T>, Point<T>) {
         (self.start_point(), self.end_point())
     }
+
+    /// Return the bounding rectangle of this `Line`.
+    pub fn bounding_rect(&self) -> Rect<T> {
+        crate::private_utils::line_bounding_rect(*self)
+    }
 }
 
 impl<T: CoordinateType> From<[(T, T); 2]> for Line<T> {
@@ -177,3 +202,49 @@ where
         d.powi(2)
     }
 }
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Line<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.start.abs_diff_eq(&other.start, epsilon) && self.end.abs_diff_eq(&other.end, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Line<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.start.relative_eq(&other.start, epsilon, max_relative)
+            && self.end.relative_eq(&other.end, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Line<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.start.ulps_eq(&other.start, epsilon, max_ulps)
+            && self.end.ulps_eq(&other.end, epsilon, max_ulps)
+    }
+}
@@ -1,6 +1,8 @@
-use crate::{Coordinate, CoordinateType, Line, Point, Triangle};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use crate::{Coordinate, CoordinateType, Line, Point, Rect, Triangle};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// An ordered collection of two or more [`Coordinate`s](struct.Coordinate.html), representing a
 /// path between locations.
@@ -75,12 +77,13 @@ use std::ops::{Index, IndexMut};
 
 #[derive(PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
 pub struct LineString<T>(pub Vec<Coordinate<T>>)
 where
     T: CoordinateType;
 
 /// A `Point` iterator returned by the `points_iter` method
-pub struct PointsIter<'a, T: CoordinateType + 'a>(::std::slice::Iter<'a, Coordinate<T>>);
+pub struct PointsIter<'a, T: CoordinateType + 'a>(::core::slice::Iter<'a, Coordinate<T>>);
 
 impl<'a, T: CoordinateType> Iterator for PointsIter<'a, T> {
     type Item = Point<T>;
@@ -98,7 +101,7 @@ impl<'a, T: CoordinateType> DoubleEndedIterator for PointsIter<'a, T> {
 
 impl<T: CoordinateType> LineString<T> {
     /// Return an iterator yielding the coordinates of a `LineString` as `Point`s
-    pub fn points_iter(&self) -> PointsIter<T> {
+    pub fn points_iter(&self) -> PointsIter<'_, T> {
         PointsIter(self.0.iter())
     }
 
@@ -135,24 +138,46 @@ impl<T: CoordinateType> LineString<T> {
     /// );
     /// assert!(lines.next().is_none());
     /// ```
-    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator + Iterator<Item = Line<T>> + 'a {
-        self.0.windows(2).map(|w| {
-            // slice::windows(N) is guaranteed to yield a slice with exactly N elements
-            unsafe { Line::new(*w.get_unchecked(0), *w.get_unchecked(1)) }
-        })
+    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.coord_windows()
+            .map(|[start, end]| Line::new(start, end))
     }
 
     /// An iterator which yields the coordinates of a `LineString` as `Triangle`s
-    pub fn triangles<'a>(&'a self) -> impl ExactSizeIterator + Iterator<Item = Triangle<T>> + 'a {
-        self.0.windows(3).map(|w| {
+    pub fn triangles<'a>(&'a self) -> impl ExactSizeIterator<Item = Triangle<T>> + 'a {
+        self.coord_windows().map(|[a, b, c]| Triangle(a, b, c))
+    }
+
+    /// Return an iterator yielding each run of `N` consecutive coordinates of a `LineString`,
+    /// as arrays, for algorithms which look at several coordinates at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Coordinate};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 7., y: 9.), (x: 1., y: 2.)];
+    ///
+    /// let mut windows = line_string.coord_windows::<3>();
+    /// assert_eq!(windows.len(), 2);
+    /// let [a, b, c] = windows.next().unwrap();
+    /// assert_eq!(
+    ///     (a, b, c),
+    ///     (Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 0. }, Coordinate { x: 7., y: 9. })
+    /// );
+    /// assert_eq!(windows.next().unwrap()[2], Coordinate { x: 1., y: 2. });
+    /// assert!(windows.next().is_none());
+    /// ```
+    pub fn coord_windows<'a, const N: usize>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = [Coordinate<T>; N]> + 'a {
+        self.0.windows(N).map(|window| {
             // slice::windows(N) is guaranteed to yield a slice with exactly N elements
-            unsafe {
-                Triangle(
-                    *w.get_unchecked(0),
-                    *w.get_unchecked(1),
-                    *w.get_unchecked(2),
-                )
-            }
+            <[Coordinate<T>; N]>::try_from(window).unwrap()
         })
     }
 
@@ -181,6 +206,24 @@ impl<T: CoordinateType> LineString<T> {
     pub fn num_coords(&self) -> usize {
         self.0.len()
     }
+
+    /// Return the bounding rectangle of this `LineString`, or `None` if it's empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Coordinate, LineString};
+    ///
+    /// let line_string = line_string![(x: 40, y: 116), (x: 42, y: 116), (x: 42, y: 118)];
+    /// let rect = line_string.bounding_rect().unwrap();
+    ///
+    /// assert_eq!(rect.min(), Coordinate { x: 40, y: 116 });
+    /// assert_eq!(rect.max(), Coordinate { x: 42, y: 118 });
+    /// assert!(LineString::<i32>(vec![]).bounding_rect().is_none());
+    /// ```
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::line_string_bounding_rect(self)
+    }
 }
 
 /// Turn a `Vec` of `Point`-like objects into a `LineString`.
@@ -200,7 +243,7 @@ impl<T: CoordinateType, IC: Into<Coordinate<T>>> FromIterator<IC> for LineString
 /// Iterate over all the [Coordinate](struct.Coordinates.html)s in this `LineString`.
 impl<T: CoordinateType> IntoIterator for LineString<T> {
     type Item = Coordinate<T>;
-    type IntoIter = ::std::vec::IntoIter<Coordinate<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Coordinate<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -210,9 +253,9 @@ impl<T: CoordinateType> IntoIterator for LineString<T> {
 /// Mutably iterate over all the [Coordinate](struct.Coordinates.html)s in this `LineString`.
 impl<'a, T: CoordinateType> IntoIterator for &'a mut LineString<T> {
     type Item = &'a mut Coordinate<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Coordinate<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Coordinate<T>>;
 
-    fn into_iter(self) -> ::std::slice::IterMut<'a, Coordinate<T>> {
+    fn into_iter(self) -> ::core::slice::IterMut<'a, Coordinate<T>> {
         self.0.iter_mut()
     }
 }
@@ -239,18 +282,7 @@ where
     type Envelope = ::rstar::AABB<Point<T>>;
 
     fn envelope(&self) -> Self::Envelope {
-        use num_traits::Bounded;
-        let bounding_rect = crate::private_utils::line_string_bounding_rect(self);
-        match bounding_rect {
-            None => ::rstar::AABB::from_corners(
-                Point::new(Bounded::min_value(), Bounded::min_value()),
-                Point::new(Bounded::max_value(), Bounded::max_value()),
-            ),
-            Some(b) => ::rstar::AABB::from_corners(
-                Point::new(b.min().x, b.min().y),
-                Point::new(b.max().x, b.max().y),
-            ),
-        }
+        crate::private_utils::rect_to_envelope(self.bounding_rect())
     }
 }
 
@@ -268,3 +300,64 @@ where
         }
     }
 }
+
+/// Line strings are approximately equal if they have the same number of coordinates, and each
+/// is approximately equal to the other's at the same position.
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for LineString<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for LineString<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for LineString<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
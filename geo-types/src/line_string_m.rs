@@ -0,0 +1,102 @@
+use crate::{CoordinateM, CoordinateType, LineString, PointM};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
+
+/// An ordered collection of two or more [`CoordinateM`s](struct.CoordinateM.html), representing
+/// a measured path between locations, e.g. a GPS track with a timestamp on every fix.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{CoordinateM, LineString, LineStringM};
+///
+/// let track: LineStringM<f64> = vec![
+///     CoordinateM { x: 0., y: 0., m: 0. },
+///     CoordinateM { x: 10., y: 0., m: 60. },
+/// ]
+/// .into();
+///
+/// assert_eq!(track.m_values().collect::<Vec<_>>(), vec![0., 60.]);
+/// assert_eq!(track.to_2d(), LineString::from(vec![(0., 0.), (10., 0.)]));
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineStringM<T>(pub Vec<CoordinateM<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> LineStringM<T> {
+    /// Return an iterator yielding the coordinates of a `LineStringM` as `PointM`s
+    pub fn points_iter(&self) -> impl DoubleEndedIterator<Item = PointM<T>> + '_ {
+        self.0.iter().map(|c| PointM(*c))
+    }
+
+    /// Return the coordinates of a `LineStringM` as a `Vec` of `PointM`s
+    pub fn into_points(self) -> Vec<PointM<T>> {
+        self.0.into_iter().map(PointM).collect()
+    }
+
+    /// Return an iterator yielding the measure of each coordinate, in order
+    pub fn m_values(&self) -> impl DoubleEndedIterator<Item = T> + '_ {
+        self.0.iter().map(|c| c.m)
+    }
+
+    /// Return the number of coordinates in the `LineStringM`.
+    pub fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Drop the measure of every coordinate, returning the 2D `LineString`.
+    pub fn to_2d(&self) -> LineString<T> {
+        LineString(self.0.iter().map(|c| c.to_2d()).collect())
+    }
+}
+
+/// Turn a `Vec` of `PointM`-like objects into a `LineStringM`.
+impl<T: CoordinateType, IC: Into<CoordinateM<T>>> From<Vec<IC>> for LineStringM<T> {
+    fn from(v: Vec<IC>) -> Self {
+        LineStringM(v.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Turn an iterator of `PointM`-like objects into a `LineStringM`.
+impl<T: CoordinateType, IC: Into<CoordinateM<T>>> FromIterator<IC> for LineStringM<T> {
+    fn from_iter<I: IntoIterator<Item = IC>>(iter: I) -> Self {
+        LineStringM(iter.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Iterate over all the [CoordinateM](struct.CoordinateM.html)s in this `LineStringM`.
+impl<T: CoordinateType> IntoIterator for LineStringM<T> {
+    type Item = CoordinateM<T>;
+    type IntoIter = ::alloc::vec::IntoIter<CoordinateM<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Mutably iterate over all the [CoordinateM](struct.CoordinateM.html)s in this `LineStringM`.
+impl<'a, T: CoordinateType> IntoIterator for &'a mut LineStringM<T> {
+    type Item = &'a mut CoordinateM<T>;
+    type IntoIter = ::core::slice::IterMut<'a, CoordinateM<T>>;
+
+    fn into_iter(self) -> ::core::slice::IterMut<'a, CoordinateM<T>> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T: CoordinateType> Index<usize> for LineStringM<T> {
+    type Output = CoordinateM<T>;
+
+    fn index(&self, index: usize) -> &CoordinateM<T> {
+        self.0.index(index)
+    }
+}
+
+impl<T: CoordinateType> IndexMut<usize> for LineStringM<T> {
+    fn index_mut(&mut self, index: usize) -> &mut CoordinateM<T> {
+        self.0.index_mut(index)
+    }
+}
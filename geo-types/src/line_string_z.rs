@@ -0,0 +1,113 @@
+use crate::{CoordinateType, CoordinateZ, LineString, PointZ};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
+
+/// An ordered collection of two or more [`CoordinateZ`s](struct.CoordinateZ.html), representing
+/// a path between locations in 3D space.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{CoordinateZ, LineStringZ};
+///
+/// let line_string: LineStringZ<f64> = vec![(0., 0., 10.), (10., 0., 12.)].into();
+///
+/// assert_eq!(line_string[1], CoordinateZ { x: 10., y: 0., z: 12. });
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineStringZ<T>(pub Vec<CoordinateZ<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> LineStringZ<T> {
+    /// Return an iterator yielding the coordinates of a `LineStringZ` as `PointZ`s
+    pub fn points_iter(&self) -> impl DoubleEndedIterator<Item = PointZ<T>> + '_ {
+        self.0.iter().map(|c| PointZ(*c))
+    }
+
+    /// Return the coordinates of a `LineStringZ` as a `Vec` of `PointZ`s
+    pub fn into_points(self) -> Vec<PointZ<T>> {
+        self.0.into_iter().map(PointZ).collect()
+    }
+
+    /// Close the `LineStringZ`. Specifically, if the `LineStringZ` has is at least one coordinate,
+    /// and the value of the first coordinate does not equal the value of the last coordinate, then
+    /// a new coordinate is added to the end with the value of the first coordinate.
+    pub(crate) fn close(&mut self) {
+        if let (Some(first), Some(last)) = (self.0.first().copied(), self.0.last().copied()) {
+            if first != last {
+                self.0.push(first);
+            }
+        }
+    }
+
+    /// Return the number of coordinates in the `LineStringZ`.
+    pub fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Drop the `z` component of every coordinate, returning the 2D `LineString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, LineStringZ};
+    ///
+    /// let line_string: LineStringZ<f64> = vec![(0., 0., 10.), (10., 0., 12.)].into();
+    ///
+    /// assert_eq!(line_string.to_2d(), LineString::from(vec![(0., 0.), (10., 0.)]));
+    /// ```
+    pub fn to_2d(&self) -> LineString<T> {
+        LineString(self.0.iter().map(|c| c.to_2d()).collect())
+    }
+}
+
+/// Turn a `Vec` of `PointZ`-like objects into a `LineStringZ`.
+impl<T: CoordinateType, IC: Into<CoordinateZ<T>>> From<Vec<IC>> for LineStringZ<T> {
+    fn from(v: Vec<IC>) -> Self {
+        LineStringZ(v.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Turn an iterator of `PointZ`-like objects into a `LineStringZ`.
+impl<T: CoordinateType, IC: Into<CoordinateZ<T>>> FromIterator<IC> for LineStringZ<T> {
+    fn from_iter<I: IntoIterator<Item = IC>>(iter: I) -> Self {
+        LineStringZ(iter.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+/// Iterate over all the [CoordinateZ](struct.CoordinateZ.html)s in this `LineStringZ`.
+impl<T: CoordinateType> IntoIterator for LineStringZ<T> {
+    type Item = CoordinateZ<T>;
+    type IntoIter = ::alloc::vec::IntoIter<CoordinateZ<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Mutably iterate over all the [CoordinateZ](struct.CoordinateZ.html)s in this `LineStringZ`.
+impl<'a, T: CoordinateType> IntoIterator for &'a mut LineStringZ<T> {
+    type Item = &'a mut CoordinateZ<T>;
+    type IntoIter = ::core::slice::IterMut<'a, CoordinateZ<T>>;
+
+    fn into_iter(self) -> ::core::slice::IterMut<'a, CoordinateZ<T>> {
+        self.0.iter_mut()
+    }
+}
+
+impl<T: CoordinateType> Index<usize> for LineStringZ<T> {
+    type Output = CoordinateZ<T>;
+
+    fn index(&self, index: usize) -> &CoordinateZ<T> {
+        self.0.index(index)
+    }
+}
+
+impl<T: CoordinateType> IndexMut<usize> for LineStringZ<T> {
+    fn index_mut(&mut self, index: usize) -> &mut CoordinateZ<T> {
+        self.0.index_mut(index)
+    }
+}
@@ -27,6 +27,30 @@ macro_rules! point {
     };
 }
 
+/// Creates a [`Coordinate`] from the given x/y values.
+///
+/// ```txt
+/// coord!(x: «x», y: «y»)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::coord;
+///
+/// let c = coord!(x: 181.2, y: 51.79);
+///
+/// assert_eq!(c, geo_types::Coordinate { x: 181.2, y: 51.79 });
+/// ```
+///
+/// [`Coordinate`]: ./struct.Coordinate.html
+#[macro_export]
+macro_rules! coord {
+    (x: $x:expr, y: $y:expr $(,)?) => {
+        $crate::Coordinate { x: $x, y: $y }
+    };
+}
+
 /// Creates a [`LineString`] containing the given coordinates.
 ///
 /// ```txt
@@ -90,12 +114,12 @@ macro_rules! point {
 /// [`LineString`]: ./line_string/struct.LineString.html
 #[macro_export]
 macro_rules! line_string {
-    () => { $crate::LineString(vec![]) };
+    () => { $crate::LineString($crate::_alloc::vec![]) };
     (
         $((x: $x:expr, y: $y:expr)),*
         $(,)?
     ) => {
-        line_string![
+        $crate::line_string![
             $(
                 $crate::Coordinate { x: $x, y: $y },
             )*
@@ -107,7 +131,7 @@ macro_rules! line_string {
     ) => {
         $crate::LineString(
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [$($coord), *]
                 )
             )
@@ -181,7 +205,7 @@ macro_rules! line_string {
 /// [`Polygon`]: ./struct.Polygon.html
 #[macro_export]
 macro_rules! polygon {
-    () => { $crate::Polygon::new(line_string![], vec![]) };
+    () => { $crate::Polygon::new($crate::line_string![], $crate::_alloc::vec![]) };
     (
         exterior: [
             $((x: $exterior_x:expr, y: $exterior_y:expr)),*
@@ -196,7 +220,7 @@ macro_rules! polygon {
         ]
         $(,)?
     ) => {
-        polygon!(
+        $crate::polygon!(
             exterior: [
                 $(
                     $crate::Coordinate { x: $exterior_x, y: $exterior_y },
@@ -229,7 +253,7 @@ macro_rules! polygon {
                 $($exterior_coord), *
             ],
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [
                         $(
                             $crate::line_string![$($interior_coord),*]
@@ -243,7 +267,7 @@ macro_rules! polygon {
         $((x: $x:expr, y: $y:expr)),*
         $(,)?
     ) => {
-        polygon![
+        $crate::polygon![
             $($crate::Coordinate { x: $x, y: $y }),*
         ]
     };
@@ -253,7 +277,259 @@ macro_rules! polygon {
     ) => {
         $crate::Polygon::new(
             $crate::line_string![$($coord,)*],
-            vec![],
+            $crate::_alloc::vec![],
+        )
+    };
+}
+
+/// Creates a [`Rect`] from its corners.
+///
+/// ```txt
+/// rect!(min: «Coordinate|(x,y)», max: «Coordinate|(x,y)»)
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min`'s x/y coordinate is larger than that of the `max`'s, like
+/// [`Rect::new`](./struct.Rect.html#method.new).
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::rect;
+///
+/// let rect = rect!(min: (x: -111., y: 41.), max: (x: -104., y: 45.));
+///
+/// assert_eq!(rect.max(), geo_types::Coordinate { x: -104., y: 45. });
+/// ```
+///
+/// [`Rect`]: ./struct.Rect.html
+#[macro_export]
+macro_rules! rect {
+    (
+        min: (x: $min_x:expr, y: $min_y:expr),
+        max: (x: $max_x:expr, y: $max_y:expr)
+        $(,)?
+    ) => {
+        $crate::rect!(
+            min: $crate::Coordinate { x: $min_x, y: $min_y },
+            max: $crate::Coordinate { x: $max_x, y: $max_y },
+        )
+    };
+    (min: $min:expr, max: $max:expr $(,)?) => {
+        $crate::Rect::new($min, $max)
+    };
+}
+
+/// Creates a [`Triangle`] from its three vertices.
+///
+/// ```txt
+/// triangle![«Coordinate|(x,y)», «Coordinate|(x,y)», «Coordinate|(x,y)»]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::triangle;
+///
+/// let triangle = triangle![(x: 0., y: 0.), (x: 5., y: 0.), (x: 0., y: 5.)];
+///
+/// assert_eq!(triangle.1, geo_types::Coordinate { x: 5., y: 0. });
+/// ```
+///
+/// [`Triangle`]: ./struct.Triangle.html
+#[macro_export]
+macro_rules! triangle {
+    (
+        (x: $x0:expr, y: $y0:expr),
+        (x: $x1:expr, y: $y1:expr),
+        (x: $x2:expr, y: $y2:expr)
+        $(,)?
+    ) => {
+        $crate::Triangle(
+            $crate::Coordinate { x: $x0, y: $y0 },
+            $crate::Coordinate { x: $x1, y: $y1 },
+            $crate::Coordinate { x: $x2, y: $y2 },
+        )
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::Triangle($a, $b, $c)
+    };
+}
+
+/// Creates a [`MultiPoint`] containing the given points.
+///
+/// ```txt
+/// multi_point![«Point|(x,y)», …]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{multi_point, point};
+///
+/// let points = multi_point![(x: 1., y: 2.), (x: 3., y: 4.)];
+///
+/// assert_eq!(points.0[1], point!(x: 3., y: 4.));
+/// assert_eq!(multi_point![point!(x: 1., y: 2.), point!(x: 3., y: 4.)], points);
+/// ```
+///
+/// [`MultiPoint`]: ./struct.MultiPoint.html
+#[macro_export]
+macro_rules! multi_point {
+    () => { $crate::MultiPoint($crate::_alloc::vec![]) };
+    (
+        $((x: $x:expr, y: $y:expr)),*
+        $(,)?
+    ) => {
+        $crate::multi_point![
+            $($crate::Point::new($x, $y)),*
+        ]
+    };
+    (
+        $($point:expr),*
+        $(,)?
+    ) => {
+        $crate::MultiPoint(
+            <[_]>::into_vec(
+                $crate::_alloc::boxed::Box::new(
+                    [$($point), *]
+                )
+            )
+        )
+    };
+}
+
+/// Creates a [`MultiLineString`] of line strings, each written like the contents of a
+/// [`line_string!`](macro.line_string.html).
+///
+/// ```txt
+/// multi_line_string![
+///     [«Coordinate|(x,y)», …],
+///     …
+/// ]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, multi_line_string};
+///
+/// let lines = multi_line_string![
+///     [(x: 0., y: 0.), (x: 1., y: 1.)],
+///     [(x: 2., y: 2.), (x: 3., y: 2.), (x: 4., y: 3.)],
+/// ];
+///
+/// assert_eq!(lines.0[0], line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+/// ```
+///
+/// [`MultiLineString`]: ./struct.MultiLineString.html
+#[macro_export]
+macro_rules! multi_line_string {
+    (
+        $($line_string:tt),*
+        $(,)?
+    ) => {
+        $crate::MultiLineString(
+            <[_]>::into_vec(
+                $crate::_alloc::boxed::Box::new(
+                    [$($crate::line_string! $line_string), *]
+                )
+            )
+        )
+    };
+}
+
+/// Creates a [`MultiPolygon`] of polygons, each written like the contents of a
+/// [`polygon!`](macro.polygon.html), in brackets, or in parentheses for polygons with interiors.
+///
+/// ```txt
+/// multi_polygon![
+///     [«Coordinate|(x,y)», …],
+///     (
+///         exterior: [«Coordinate|(x,y)», …],
+///         interiors: [
+///             [«Coordinate|(x,y)», …],
+///             …
+///         ],
+///     ),
+///     …
+/// ]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::multi_polygon;
+///
+/// let polygons = multi_polygon![
+///     [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+///     (
+///         exterior: [(x: 2., y: 0.), (x: 6., y: 0.), (x: 6., y: 4.), (x: 2., y: 4.)],
+///         interiors: [
+///             [(x: 3., y: 1.), (x: 3., y: 2.), (x: 4., y: 2.)],
+///         ],
+///     ),
+/// ];
+///
+/// assert_eq!(polygons.0.len(), 2);
+/// assert_eq!(polygons.0[1].interiors().len(), 1);
+/// ```
+///
+/// [`MultiPolygon`]: ./struct.MultiPolygon.html
+#[macro_export]
+macro_rules! multi_polygon {
+    (
+        $($polygon:tt),*
+        $(,)?
+    ) => {
+        $crate::MultiPolygon(
+            <[_]>::into_vec(
+                $crate::_alloc::boxed::Box::new(
+                    [$($crate::polygon! $polygon), *]
+                )
+            )
+        )
+    };
+}
+
+/// Creates a [`GeometryCollection`] of geometries of any type, each converted into a
+/// [`Geometry`]; nested collections have to be wrapped in a `Geometry` already.
+///
+/// ```txt
+/// geometry_collection![«Geometry|Point|Line|LineString|…», …]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{geometry_collection, line_string, point, rect, Geometry};
+///
+/// let collection = geometry_collection![
+///     point!(x: 1., y: 2.),
+///     line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///     rect!(min: (x: 0., y: 0.), max: (x: 1., y: 1.)),
+///     Geometry::GeometryCollection(geometry_collection![point!(x: 3., y: 4.)]),
+/// ];
+///
+/// assert_eq!(collection.0[0], Geometry::Point(point!(x: 1., y: 2.)));
+/// assert_eq!(collection.0[3].type_name(), "GeometryCollection");
+/// ```
+///
+/// [`Geometry`]: ./enum.Geometry.html
+/// [`GeometryCollection`]: ./struct.GeometryCollection.html
+#[macro_export]
+macro_rules! geometry_collection {
+    (
+        $($geometry:expr),*
+        $(,)?
+    ) => {
+        $crate::GeometryCollection(
+            <[_]>::into_vec(
+                $crate::_alloc::boxed::Box::new(
+                    [$($crate::Geometry::from($geometry)), *]
+                )
+            )
         )
     };
 }
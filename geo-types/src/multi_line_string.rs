@@ -1,5 +1,6 @@
-use crate::{CoordinateType, LineString};
-use std::iter::FromIterator;
+use crate::{CoordinateType, LineString, Rect};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
 
 /// A collection of [`LineString`s](line_string/struct.LineString.html).
 ///
@@ -8,10 +9,20 @@ use std::iter::FromIterator;
 /// Iterating over this objects, yields the component `LineString`s.
 #[derive(PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
 pub struct MultiLineString<T>(pub Vec<LineString<T>>)
 where
     T: CoordinateType;
 
+impl<T: CoordinateType> MultiLineString<T> {
+    /// Return the bounding rectangle of this `MultiLineString`, or `None` if it has no
+    /// coordinates.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(
+            self.0.iter().flat_map(|line| line.0.iter().cloned()),
+        )
+    }
+}
 impl<T: CoordinateType, ILS: Into<LineString<T>>> From<ILS> for MultiLineString<T> {
     fn from(ls: ILS) -> Self {
         MultiLineString(vec![ls.into()])
@@ -26,9 +37,68 @@ impl<T: CoordinateType, ILS: Into<LineString<T>>> FromIterator<ILS> for MultiLin
 
 impl<T: CoordinateType> IntoIterator for MultiLineString<T> {
     type Item = LineString<T>;
-    type IntoIter = ::std::vec::IntoIter<LineString<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<LineString<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for MultiLineString<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for MultiLineString<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for MultiLineString<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
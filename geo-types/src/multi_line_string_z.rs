@@ -0,0 +1,32 @@
+use crate::{CoordinateType, LineStringZ, MultiLineString};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A collection of [`LineStringZ`s](struct.LineStringZ.html).
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiLineStringZ<T>(pub Vec<LineStringZ<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> MultiLineStringZ<T> {
+    /// Drop the `z` component of every coordinate, returning the 2D `MultiLineString`.
+    pub fn to_2d(&self) -> MultiLineString<T> {
+        MultiLineString(self.0.iter().map(|l| l.to_2d()).collect())
+    }
+}
+
+impl<T: CoordinateType, ILS: Into<LineStringZ<T>>> FromIterator<ILS> for MultiLineStringZ<T> {
+    fn from_iter<I: IntoIterator<Item = ILS>>(iter: I) -> Self {
+        MultiLineStringZ(iter.into_iter().map(|ls| ls.into()).collect())
+    }
+}
+
+impl<T: CoordinateType> IntoIterator for MultiLineStringZ<T> {
+    type Item = LineStringZ<T>;
+    type IntoIter = ::alloc::vec::IntoIter<LineStringZ<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{CoordinateType, Point};
-use std::iter::FromIterator;
+use crate::{CoordinateType, Point, Rect};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
 
 /// A collection of [`Point`s](struct.Point.html).
 ///
@@ -16,10 +17,21 @@ use std::iter::FromIterator;
 /// ```
 #[derive(PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
 pub struct MultiPoint<T>(pub Vec<Point<T>>)
 where
     T: CoordinateType;
 
+impl<T: CoordinateType> MultiPoint<T> {
+    /// Return the bounding rectangle of this `MultiPoint`, or `None` if it's empty.
+    ///
+    /// [Empty points](struct.Point.html#method.is_empty) have no extent, and are left out.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(
+            self.0.iter().filter(|p| !p.is_empty()).map(|p| p.0),
+        )
+    }
+}
 impl<T: CoordinateType, IP: Into<Point<T>>> From<IP> for MultiPoint<T> {
     /// Convert a single `Point` (or something which can be converted to a `Point`) into a
     /// one-member `MultiPoint`
@@ -46,9 +58,68 @@ impl<T: CoordinateType, IP: Into<Point<T>>> FromIterator<IP> for MultiPoint<T> {
 /// Iterate over the `Point`s in this `MultiPoint`.
 impl<T: CoordinateType> IntoIterator for MultiPoint<T> {
     type Item = Point<T>;
-    type IntoIter = ::std::vec::IntoIter<Point<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Point<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for MultiPoint<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for MultiPoint<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for MultiPoint<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
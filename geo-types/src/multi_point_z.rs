@@ -0,0 +1,38 @@
+use crate::{CoordinateType, MultiPoint, PointZ};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A collection of [`PointZ`s](struct.PointZ.html).
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiPointZ<T>(pub Vec<PointZ<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> MultiPointZ<T> {
+    /// Drop the `z` component of every point, returning the 2D `MultiPoint`.
+    pub fn to_2d(&self) -> MultiPoint<T> {
+        MultiPoint(self.0.iter().map(|p| p.to_2d()).collect())
+    }
+}
+
+impl<T: CoordinateType, IP: Into<PointZ<T>>> From<Vec<IP>> for MultiPointZ<T> {
+    fn from(v: Vec<IP>) -> MultiPointZ<T> {
+        MultiPointZ(v.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+impl<T: CoordinateType, IP: Into<PointZ<T>>> FromIterator<IP> for MultiPointZ<T> {
+    fn from_iter<I: IntoIterator<Item = IP>>(iter: I) -> Self {
+        MultiPointZ(iter.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+impl<T: CoordinateType> IntoIterator for MultiPointZ<T> {
+    type Item = PointZ<T>;
+    type IntoIter = ::alloc::vec::IntoIter<PointZ<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
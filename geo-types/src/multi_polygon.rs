@@ -1,5 +1,6 @@
-use crate::{CoordinateType, Polygon};
-use std::iter::FromIterator;
+use crate::{CoordinateType, Polygon, Rect};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
 
 /// A collection of [`Polygon`s](struct.Polygon.html).
 ///
@@ -8,10 +9,22 @@ use std::iter::FromIterator;
 /// Iterating over this object yields the component Polygons.
 #[derive(PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::Decode))]
 pub struct MultiPolygon<T>(pub Vec<Polygon<T>>)
 where
     T: CoordinateType;
 
+impl<T: CoordinateType> MultiPolygon<T> {
+    /// Return the bounding rectangle of this `MultiPolygon`, or `None` if it has no
+    /// coordinates.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(
+            self.0
+                .iter()
+                .flat_map(|poly| poly.exterior().0.iter().cloned()),
+        )
+    }
+}
 impl<T: CoordinateType, IP: Into<Polygon<T>>> From<IP> for MultiPolygon<T> {
     fn from(x: IP) -> Self {
         MultiPolygon(vec![x.into()])
@@ -32,9 +45,68 @@ impl<T: CoordinateType, IP: Into<Polygon<T>>> FromIterator<IP> for MultiPolygon<
 
 impl<T: CoordinateType> IntoIterator for MultiPolygon<T> {
     type Item = Polygon<T>;
-    type IntoIter = ::std::vec::IntoIter<Polygon<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Polygon<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for MultiPolygon<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for MultiPolygon<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for MultiPolygon<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{CoordinateType, MultiPolygon, PolygonZ};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// A collection of [`PolygonZ`s](struct.PolygonZ.html).
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiPolygonZ<T>(pub Vec<PolygonZ<T>>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> MultiPolygonZ<T> {
+    /// Drop the `z` component of every coordinate, returning the 2D `MultiPolygon`.
+    pub fn to_2d(&self) -> MultiPolygon<T> {
+        MultiPolygon(self.0.iter().map(|p| p.to_2d()).collect())
+    }
+}
+
+impl<T: CoordinateType, IP: Into<PolygonZ<T>>> From<Vec<IP>> for MultiPolygonZ<T> {
+    fn from(x: Vec<IP>) -> Self {
+        MultiPolygonZ(x.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+impl<T: CoordinateType, IP: Into<PolygonZ<T>>> FromIterator<IP> for MultiPolygonZ<T> {
+    fn from_iter<I: IntoIterator<Item = IP>>(iter: I) -> Self {
+        MultiPolygonZ(iter.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+impl<T: CoordinateType> IntoIterator for MultiPolygonZ<T> {
+    type Item = PolygonZ<T>;
+    type IntoIter = ::alloc::vec::IntoIter<PolygonZ<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
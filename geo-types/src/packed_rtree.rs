@@ -0,0 +1,409 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+const NODE_SIZE: usize = 16;
+
+/// Objects which can be stored in a [`PackedRTree`](struct.PackedRTree.html), by their bounding
+/// rectangle.
+///
+/// This is implemented for all the geometry types.
+pub trait PackedRTreeObject<T: CoordinateType> {
+    /// The rectangle the object is indexed by, or `None` if it has no coordinates, in which case
+    /// it's never returned by queries.
+    fn index_rect(&self) -> Option<Rect<T>>;
+}
+
+macro_rules! packed_rtree_object_impl {
+    ($($type:ident => $rect:expr),* $(,)?) => {
+        $(
+            impl<T: CoordinateType> PackedRTreeObject<T> for $type<T> {
+                fn index_rect(&self) -> Option<Rect<T>> {
+                    $rect(self)
+                }
+            }
+        )*
+    };
+}
+
+packed_rtree_object_impl!(
+    Point => |g: &Point<T>| Some(g.bounding_rect()),
+    Line => |g: &Line<T>| Some(g.bounding_rect()),
+    LineString => LineString::bounding_rect,
+    Polygon => Polygon::bounding_rect,
+    MultiPoint => MultiPoint::bounding_rect,
+    MultiLineString => MultiLineString::bounding_rect,
+    MultiPolygon => MultiPolygon::bounding_rect,
+    GeometryCollection => GeometryCollection::bounding_rect,
+    Rect => |g: &Rect<T>| Some(*g),
+    Triangle => |g: &Triangle<T>| Some(g.bounding_rect()),
+    Geometry => Geometry::bounding_rect,
+);
+
+/// A static, packed R-tree of the bounding rectangles of a slice of geometries, for finding the
+/// geometries near a rectangle or point without scanning the whole slice.
+///
+/// The tree is bulk-loaded once using Sort-Tile-Recursive packing, and can't be changed
+/// afterwards. Unlike [`rstar::RTree`](https://docs.rs/rstar/0.7.1/rstar/struct.RTree.html), it
+/// doesn't need floating point coordinates: building it only compares coordinates, and its
+/// storage is allocated up front, with exactly one node for each geometry and parent. The same
+/// geometries always produce the same tree, and queries return geometries in the order they
+/// appear in the slice, so results don't depend on how the tree happens to be laid out. It
+/// only needs `core` and `alloc`, so it's available without the `std` feature.
+///
+/// Rectangle queries only compare bounding rectangles: they return every geometry whose
+/// bounding rectangle intersects the query, which may include geometries that don't intersect
+/// it themselves.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, Coordinate, PackedRTree, Point, Rect};
+///
+/// let points: Vec<Point<i64>> = (0..1000).map(|i| point!(x: i % 100, y: i / 100)).collect();
+/// let tree = PackedRTree::new(&points);
+///
+/// let rect = Rect::new(Coordinate { x: 10, y: 2 }, Coordinate { x: 12, y: 3 });
+/// assert_eq!(tree.query_rect(&rect).count(), 6);
+///
+/// let coord = Coordinate { x: 40, y: 20 };
+/// let nearest = tree.nearest_neighbor(&coord, |p| {
+///     (p.x() - coord.x).pow(2) + (p.y() - coord.y).pow(2)
+/// });
+/// assert_eq!(nearest, Some(&point!(x: 40, y: 9)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PackedRTree<'a, T, G>
+where
+    T: CoordinateType,
+{
+    objects: &'a [G],
+    /// The rectangles of every node: first the (sorted) leaves, then each level of parents,
+    /// ending with the root
+    rects: Vec<Rect<T>>,
+    /// For leaves, the index of the object in the slice. For parents, the position of the first
+    /// child in `rects`
+    indices: Vec<usize>,
+    /// The start and end positions of each level in `rects`, from the leaves up
+    levels: Vec<(usize, usize)>,
+}
+
+fn compare<T: CoordinateType>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+pub(crate) fn intersects<T: CoordinateType>(a: &Rect<T>, b: &Rect<T>) -> bool {
+    a.min().x <= b.max().x
+        && a.max().x >= b.min().x
+        && a.min().y <= b.max().y
+        && a.max().y >= b.min().y
+}
+
+fn merge<T: CoordinateType>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
+    let pick = |a: T, b: T, min: bool| if (a < b) == min { a } else { b };
+    Rect::new(
+        Coordinate {
+            x: pick(a.min().x, b.min().x, true),
+            y: pick(a.min().y, b.min().y, true),
+        },
+        Coordinate {
+            x: pick(a.max().x, b.max().x, false),
+            y: pick(a.max().y, b.max().y, false),
+        },
+    )
+}
+
+// The squared distance from the coordinate to the nearest point of the rectangle, computed
+// without subtracting a larger value from a smaller one, so it works for unsigned types
+fn rect_distance_2<T: CoordinateType>(rect: &Rect<T>, coord: &Coordinate<T>) -> T {
+    let gap = |value: T, min: T, max: T| {
+        if value < min {
+            min - value
+        } else if value > max {
+            value - max
+        } else {
+            T::zero()
+        }
+    };
+    let dx = gap(coord.x, rect.min().x, rect.max().x);
+    let dy = gap(coord.y, rect.min().y, rect.max().y);
+    dx * dx + dy * dy
+}
+
+// The smallest number whose square is at least `n`
+fn ceil_sqrt(n: usize) -> usize {
+    let mut root = 0;
+    while root * root < n {
+        root += 1;
+    }
+    root
+}
+
+impl<'a, T, G> PackedRTree<'a, T, G>
+where
+    T: CoordinateType,
+    G: PackedRTreeObject<T>,
+{
+    /// Build a tree of the bounding rectangles of `objects`.
+    ///
+    /// Building the tree takes `O(n log n)` time, after which each query only visits the parts
+    /// of the tree near the query.
+    pub fn new(objects: &'a [G]) -> Self {
+        let mut leaves: Vec<(usize, Rect<T>)> = objects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, g)| g.index_rect().map(|r| (i, r)))
+            .collect();
+
+        // Sort-Tile-Recursive packing: sort into vertical slices by x, then each slice by y
+        leaves.sort_by(|(_, a), (_, b)| compare(a.min().x, b.min().x));
+        let node_count = leaves.len().div_ceil(NODE_SIZE);
+        let slice_count = ceil_sqrt(node_count).max(1);
+        let slice_size = NODE_SIZE * node_count.div_ceil(slice_count).max(1);
+        for slice in leaves.chunks_mut(slice_size) {
+            slice.sort_by(|(_, a), (_, b)| compare(a.min().y, b.min().y));
+        }
+
+        let mut capacity = leaves.len();
+        let mut level_size = leaves.len();
+        while level_size > 1 {
+            level_size = level_size.div_ceil(NODE_SIZE);
+            capacity += level_size;
+        }
+        let mut rects = Vec::with_capacity(capacity);
+        let mut indices = Vec::with_capacity(capacity);
+        for (index, rect) in leaves {
+            indices.push(index);
+            rects.push(rect);
+        }
+        let mut levels = vec![(0, rects.len())];
+        while let Some(&(start, end)) = levels.last() {
+            if end - start <= 1 {
+                break;
+            }
+            for child_start in (start..end).step_by(NODE_SIZE) {
+                let child_end = (child_start + NODE_SIZE).min(end);
+                let rect = rects[child_start + 1..child_end]
+                    .iter()
+                    .fold(rects[child_start], |acc, &r| merge(acc, r));
+                rects.push(rect);
+                indices.push(child_start);
+            }
+            levels.push((end, rects.len()));
+        }
+
+        PackedRTree {
+            objects,
+            rects,
+            indices,
+            levels,
+        }
+    }
+
+    /// The objects the tree was built from.
+    pub fn objects(&self) -> &'a [G] {
+        self.objects
+    }
+
+    /// Return the objects whose bounding rectangle intersects `rect`, in the order they appear
+    /// in the slice.
+    pub fn query_rect(&self, rect: &Rect<T>) -> impl Iterator<Item = &'a G> {
+        let mut found = vec![];
+        if let Some(&(root, end)) = self.levels.last() {
+            if root < end {
+                self.search(self.levels.len() - 1, root, rect, &mut found);
+            }
+        }
+        found.sort_unstable();
+        let objects = self.objects;
+        found.into_iter().map(move |i| &objects[i])
+    }
+
+    /// Return the objects whose bounding rectangle contains `coord`, in the order they appear in
+    /// the slice.
+    pub fn query_point(&self, coord: &Coordinate<T>) -> impl Iterator<Item = &'a G> {
+        self.query_rect(&Rect::new(*coord, *coord))
+    }
+
+    /// Return the object nearest to `coord`, or `None` if the tree is empty.
+    ///
+    /// `distance_2` measures the squared distance from `coord` to an object. It's only called
+    /// for objects whose bounding rectangle is at least as near as the nearest object found so
+    /// far, so it should never be less than the squared distance to the object's bounding
+    /// rectangle. Of several objects at the same distance, the first in the slice is returned.
+    pub fn nearest_neighbor<F>(&self, coord: &Coordinate<T>, distance_2: F) -> Option<&'a G>
+    where
+        F: Fn(&G) -> T,
+    {
+        let &(root, end) = self.levels.last()?;
+        if root == end {
+            return None;
+        }
+        let mut nearest = None;
+        self.nearest(
+            self.levels.len() - 1,
+            root,
+            coord,
+            &distance_2,
+            &mut nearest,
+        );
+        nearest.map(|(_, i)| &self.objects[i])
+    }
+
+    fn search(&self, level: usize, position: usize, rect: &Rect<T>, found: &mut Vec<usize>) {
+        if !intersects(&self.rects[position], rect) {
+            return;
+        }
+        if level == 0 {
+            found.push(self.indices[position]);
+            return;
+        }
+        let child_start = self.indices[position];
+        let child_end = (child_start + NODE_SIZE).min(self.levels[level - 1].1);
+        for child in child_start..child_end {
+            self.search(level - 1, child, rect, found);
+        }
+    }
+
+    // Update `nearest` with the (squared distance, index) of the nearest object under the node
+    fn nearest<F>(
+        &self,
+        level: usize,
+        position: usize,
+        coord: &Coordinate<T>,
+        distance_2: &F,
+        nearest: &mut Option<(T, usize)>,
+    ) where
+        F: Fn(&G) -> T,
+    {
+        if level == 0 {
+            let index = self.indices[position];
+            let distance = distance_2(&self.objects[index]);
+            let is_nearer = match *nearest {
+                None => true,
+                Some((best, best_index)) => {
+                    distance < best || (distance == best && index < best_index)
+                }
+            };
+            if is_nearer {
+                *nearest = Some((distance, index));
+            }
+            return;
+        }
+        // visit the children from the nearest, so that further ones can be skipped
+        let child_start = self.indices[position];
+        let child_end = (child_start + NODE_SIZE).min(self.levels[level - 1].1);
+        let mut children = [(T::zero(), 0); NODE_SIZE];
+        for (child, slot) in (child_start..child_end).zip(children.iter_mut()) {
+            *slot = (rect_distance_2(&self.rects[child], coord), child);
+        }
+        let children = &mut children[..child_end - child_start];
+        children.sort_unstable_by(|a, b| compare(a.0, b.0).then(a.1.cmp(&b.1)));
+        for &(distance, child) in children.iter() {
+            if let Some((best, _)) = *nearest {
+                if distance > best {
+                    break;
+                }
+            }
+            self.nearest(level - 1, child, coord, distance_2, nearest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    // deterministic pseudo-random numbers below 1000
+    fn random_numbers() -> impl FnMut() -> i64 {
+        let mut seed = 12345u64;
+        move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as i64 % 1000
+        }
+    }
+
+    #[test]
+    fn nearest_neighbor_matches_linear_scan() {
+        let mut random = random_numbers();
+        let points: Vec<Point<i64>> = (0..3000).map(|_| Point::new(random(), random())).collect();
+        let tree = PackedRTree::new(&points);
+        for _ in 0..200 {
+            let coord = Coordinate {
+                x: random() * 2 - 500,
+                y: random() * 2 - 500,
+            };
+            let distance_2 = |p: &Point<i64>| (p.x() - coord.x).pow(2) + (p.y() - coord.y).pow(2);
+            // the first of the nearest points
+            let expected = points.iter().min_by_key(|p| distance_2(p)).unwrap();
+            assert!(core::ptr::eq(
+                tree.nearest_neighbor(&coord, distance_2).unwrap(),
+                expected
+            ));
+        }
+    }
+
+    #[test]
+    fn nearest_line_strings() {
+        // the distance to a line string is only ever as small as the distance to its rectangle,
+        // so the nearest by rectangle needn't be the nearest
+        let line_strings: Vec<LineString<f64>> = vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 10.)],
+            line_string![(x: 0., y: 6.), (x: 3., y: 6.)],
+            LineString(vec![]),
+        ];
+        let tree = PackedRTree::new(&line_strings);
+        let coord = Coordinate { x: 1., y: 8. };
+        let distance_2 = |line_string: &LineString<f64>| {
+            line_string
+                .lines()
+                .map(|line| {
+                    let d = crate::private_utils::point_line_euclidean_distance(Point(coord), line);
+                    d * d
+                })
+                .fold(f64::MAX, f64::min)
+        };
+        assert_eq!(
+            tree.nearest_neighbor(&coord, distance_2),
+            Some(&line_strings[1])
+        );
+        assert_eq!(tree.query_point(&coord).count(), 1);
+    }
+
+    #[test]
+    fn unsigned_and_empty_trees() {
+        let rects: Vec<Rect<u32>> = vec![Rect::new((5, 5), (6, 6)), Rect::new((0, 0), (1, 1))];
+        let tree = PackedRTree::new(&rects);
+        let coord = Coordinate { x: 4, y: 3 };
+        let nearest = tree.nearest_neighbor(&coord, |rect| rect_distance_2(rect, &coord));
+        assert_eq!(nearest, Some(&rects[0]));
+        assert_eq!(
+            tree.query_rect(&Rect::new((0, 0), (10, 10)))
+                .collect::<Vec<_>>(),
+            vec![&rects[0], &rects[1]]
+        );
+
+        let empty: Vec<Geometry<f64>> = vec![LineString(vec![]).into()];
+        let tree = PackedRTree::new(&empty);
+        assert_eq!(
+            tree.nearest_neighbor(&Coordinate { x: 0., y: 0. }, |_| 0.),
+            None
+        );
+        assert_eq!(tree.query_point(&Coordinate { x: 0., y: 0. }).count(), 0);
+        assert_eq!(tree.objects().len(), 1);
+    }
+
+    #[test]
+    fn storage_is_exact() {
+        for n in [0, 1, 15, 16, 17, 256, 257, 5000] {
+            let points: Vec<Point<i32>> = (0..n).map(|i| Point::new(i, -i)).collect();
+            let tree = PackedRTree::new(&points);
+            assert_eq!(tree.rects.len(), tree.rects.capacity());
+            assert_eq!(tree.indices.len(), tree.indices.capacity());
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use crate::{Coordinate, CoordinateType};
+use crate::{Coordinate, CoordinateType, Rect};
+use core::ops::Add;
+use core::ops::Neg;
+use core::ops::Sub;
 use num_traits::Float;
-use std::ops::Add;
-use std::ops::Neg;
-use std::ops::Sub;
 
 /// A single point in 2D space.
 ///
@@ -19,6 +19,10 @@ use std::ops::Sub;
 /// ```
 #[derive(PartialEq, Clone, Copy, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "scale-codec",
+    derive(codec::Encode, codec::Decode, codec::MaxEncodedLen)
+)]
 pub struct Point<T>(pub Coordinate<T>)
 where
     T: CoordinateType;
@@ -57,7 +61,7 @@ where
     /// assert_eq!(p.x(), 1.234);
     /// assert_eq!(p.y(), 2.345);
     /// ```
-    pub fn new(x: T, y: T) -> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
         Point(Coordinate { x, y })
     }
 
@@ -72,7 +76,7 @@ where
     ///
     /// assert_eq!(p.x(), 1.234);
     /// ```
-    pub fn x(self) -> T {
+    pub const fn x(self) -> T {
         self.0.x
     }
 
@@ -104,7 +108,7 @@ where
     ///
     /// assert_eq!(p.y(), 2.345);
     /// ```
-    pub fn y(self) -> T {
+    pub const fn y(self) -> T {
         self.0.y
     }
 
@@ -138,7 +142,7 @@ where
     /// assert_eq!(y, 2.345);
     /// assert_eq!(x, 1.234);
     /// ```
-    pub fn x_y(self) -> (T, T) {
+    pub const fn x_y(self) -> (T, T) {
         (self.0.x, self.0.y)
     }
     /// Returns the longitude/horizontal component of the point.
@@ -201,6 +205,49 @@ where
     pub fn set_lat(&mut self, lat: T) -> &mut Point<T> {
         self.set_y(lat)
     }
+
+    /// Return the bounding rectangle of this `Point`, which has zero width and height.
+    ///
+    /// That of an [empty point](#method.is_empty) has NaN corners; collections of geometries
+    /// leave empty points out of their bounding rectangles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Point};
+    ///
+    /// let p = Point::new(1, 2);
+    ///
+    /// assert_eq!(p.bounding_rect().min(), Coordinate { x: 1, y: 2 });
+    /// assert_eq!(p.bounding_rect().max(), Coordinate { x: 1, y: 2 });
+    /// ```
+    pub fn bounding_rect(&self) -> Rect<T> {
+        Rect::new(self.0, self.0)
+    }
+
+    /// Whether this is the empty point, such as a WKT `POINT EMPTY`, whose coordinates are NaN.
+    ///
+    /// Conversions from and to other formats follow this convention, as WKB does, so only points
+    /// with coordinates which can be NaN can be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Point;
+    ///
+    /// assert!(Point::<f64>::empty().is_empty());
+    /// assert!(!Point::new(f64::NAN, 0.).is_empty());
+    /// assert!(!Point::new(1, 2).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        // NaN is the only value which can't be compared with itself
+        self.x().partial_cmp(&self.x()).is_none() && self.y().partial_cmp(&self.y()).is_none()
+    }
+
+    /// The empty point, or `None` if `T` has no NaN
+    pub(crate) fn try_empty() -> Option<Point<T>> {
+        T::from(f64::NAN).map(|nan| Point::new(nan, nan))
+    }
 }
 
 impl<T> Point<T>
@@ -251,6 +298,20 @@ impl<T> Point<T>
 where
     T: CoordinateType + Float,
 {
+    /// Creates an empty point, such as a WKT `POINT EMPTY`, whose coordinates are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Point;
+    ///
+    /// let empty = Point::<f64>::empty();
+    /// assert!(empty.x().is_nan() && empty.y().is_nan());
+    /// ```
+    pub fn empty() -> Point<T> {
+        Point::new(T::nan(), T::nan())
+    }
+
     /// Converts the (x,y) components of Point to degrees
     ///
     /// # Example
@@ -386,3 +447,47 @@ where
         }
     }
 }
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Point<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Point<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Point<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{CoordinateM, CoordinateType, Point};
+
+/// A single point in 2D space carrying a _measure_ (`m`) value.
+///
+/// See [`CoordinateM`](struct.CoordinateM.html) for what the measure represents.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{Point, PointM};
+///
+/// let p = PointM::new(1.234, 2.345, 60.);
+///
+/// assert_eq!(p.m(), 60.);
+/// assert_eq!(p.to_2d(), Point::new(1.234, 2.345));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointM<T>(pub CoordinateM<T>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> From<CoordinateM<T>> for PointM<T> {
+    fn from(x: CoordinateM<T>) -> PointM<T> {
+        PointM(x)
+    }
+}
+
+impl<T> PointM<T>
+where
+    T: CoordinateType,
+{
+    /// Creates a new point with the given measure.
+    pub const fn new(x: T, y: T, m: T) -> PointM<T> {
+        PointM(CoordinateM { x, y, m })
+    }
+
+    /// Returns the x/horizontal component of the point.
+    pub const fn x(self) -> T {
+        self.0.x
+    }
+
+    /// Sets the x/horizontal component of the point.
+    pub fn set_x(&mut self, x: T) -> &mut PointM<T> {
+        self.0.x = x;
+        self
+    }
+
+    /// Returns the y/vertical component of the point.
+    pub const fn y(self) -> T {
+        self.0.y
+    }
+
+    /// Sets the y/vertical component of the point.
+    pub fn set_y(&mut self, y: T) -> &mut PointM<T> {
+        self.0.y = y;
+        self
+    }
+
+    /// Returns the measure of the point.
+    pub const fn m(self) -> T {
+        self.0.m
+    }
+
+    /// Sets the measure of the point.
+    pub fn set_m(&mut self, m: T) -> &mut PointM<T> {
+        self.0.m = m;
+        self
+    }
+
+    /// Drop the measure, returning the 2D `Point`.
+    pub fn to_2d(self) -> Point<T> {
+        Point(self.0.to_2d())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{CoordinateType, CoordinateZ, Point};
+
+/// A single point in 3D space.
+///
+/// `PointZ`s can be created using the `new(x, y, z)` constructor, a `CoordinateZ`, or from
+/// three-element tuples or arrays.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{CoordinateZ, PointZ};
+/// let p1: PointZ<f64> = (0., 1., 2.).into();
+/// let c = CoordinateZ { x: 10., y: 20., z: 30. };
+/// let p2: PointZ<f64> = c.into();
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointZ<T>(pub CoordinateZ<T>)
+where
+    T: CoordinateType;
+
+impl<T: CoordinateType> From<CoordinateZ<T>> for PointZ<T> {
+    fn from(x: CoordinateZ<T>) -> PointZ<T> {
+        PointZ(x)
+    }
+}
+
+impl<T: CoordinateType> From<(T, T, T)> for PointZ<T> {
+    fn from(coords: (T, T, T)) -> PointZ<T> {
+        PointZ::new(coords.0, coords.1, coords.2)
+    }
+}
+
+impl<T: CoordinateType> From<[T; 3]> for PointZ<T> {
+    fn from(coords: [T; 3]) -> PointZ<T> {
+        PointZ::new(coords[0], coords[1], coords[2])
+    }
+}
+
+impl<T> PointZ<T>
+where
+    T: CoordinateType,
+{
+    /// Creates a new point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::PointZ;
+    ///
+    /// let p = PointZ::new(1.234, 2.345, 3.456);
+    ///
+    /// assert_eq!(p.x(), 1.234);
+    /// assert_eq!(p.y(), 2.345);
+    /// assert_eq!(p.z(), 3.456);
+    /// ```
+    pub const fn new(x: T, y: T, z: T) -> PointZ<T> {
+        PointZ(CoordinateZ { x, y, z })
+    }
+
+    /// Returns the x/horizontal component of the point.
+    pub const fn x(self) -> T {
+        self.0.x
+    }
+
+    /// Sets the x/horizontal component of the point.
+    pub fn set_x(&mut self, x: T) -> &mut PointZ<T> {
+        self.0.x = x;
+        self
+    }
+
+    /// Returns the y/vertical component of the point.
+    pub const fn y(self) -> T {
+        self.0.y
+    }
+
+    /// Sets the y/vertical component of the point.
+    pub fn set_y(&mut self, y: T) -> &mut PointZ<T> {
+        self.0.y = y;
+        self
+    }
+
+    /// Returns the z component (typically elevation) of the point.
+    pub const fn z(self) -> T {
+        self.0.z
+    }
+
+    /// Sets the z component (typically elevation) of the point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::PointZ;
+    ///
+    /// let mut p = PointZ::new(1.234, 2.345, 3.456);
+    /// p.set_z(9.876);
+    ///
+    /// assert_eq!(p.z(), 9.876);
+    /// ```
+    pub fn set_z(&mut self, z: T) -> &mut PointZ<T> {
+        self.0.z = z;
+        self
+    }
+
+    /// Returns a tuple that contains the x, y, and z components of the point.
+    pub const fn x_y_z(self) -> (T, T, T) {
+        self.0.x_y_z()
+    }
+
+    /// Drop the `z` component, returning the 2D `Point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Point, PointZ};
+    ///
+    /// let p = PointZ::new(1.234, 2.345, 3.456);
+    ///
+    /// assert_eq!(p.to_2d(), Point::new(1.234, 2.345));
+    /// ```
+    pub fn to_2d(self) -> Point<T> {
+        Point(self.0.to_2d())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{CoordinateType, LineString, Point, Rect};
+use crate::{Coordinate, CoordinateType, Line, LineString, Point, Rect, Triangle};
+use alloc::vec::Vec;
 use num_traits::{Float, Signed};
 
 /// A bounded two-dimensional area.
@@ -33,6 +34,7 @@ use num_traits::{Float, Signed};
 /// [`LineString`]: line_string/struct.LineString.html
 #[derive(PartialEq, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode))]
 pub struct Polygon<T>
 where
     T: CoordinateType,
@@ -364,6 +366,119 @@ where
     {
         (current_vertex + (self.exterior.0.len() - 1) - 1) % (self.exterior.0.len() - 1)
     }
+
+    /// Return an iterator yielding one `Line` for each line segment of the exterior and then of
+    /// each interior ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::polygon;
+    ///
+    /// let polygon = polygon![
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+    ///     interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+    /// ];
+    ///
+    /// assert_eq!(polygon.lines().count(), 6);
+    /// assert_eq!(polygon.lines().last(), polygon.interiors()[0].lines().last());
+    /// ```
+    pub fn lines<'a>(&'a self) -> impl Iterator<Item = Line<T>> + 'a {
+        self.exterior
+            .lines()
+            .chain(self.interiors.iter().flat_map(|interior| interior.lines()))
+    }
+
+    /// Return an iterator yielding the triangles of a fan around the first coordinate of the
+    /// exterior ring, each wound the same way as the ring.
+    ///
+    /// The triangles cover the `Polygon` exactly if its exterior is convex, and it has no
+    /// interior rings, which are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, Coordinate, Triangle};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    ///
+    /// let mut fan = polygon.triangle_fan();
+    /// assert_eq!(fan.len(), 2);
+    /// assert_eq!(fan.next(), Some(Triangle::from([(0., 0.), (2., 0.), (2., 2.)])));
+    /// assert_eq!(fan.next(), Some(Triangle::from([(0., 0.), (2., 2.), (0., 2.)])));
+    /// assert!(fan.next().is_none());
+    /// ```
+    pub fn triangle_fan<'a>(&'a self) -> impl ExactSizeIterator<Item = Triangle<T>> + 'a {
+        let ring = self.open_exterior();
+        (1..ring.len().saturating_sub(1)).map(move |i| Triangle(ring[0], ring[i], ring[i + 1]))
+    }
+
+    /// Return an iterator yielding the triangles of a strip zigzagging between the two sides of
+    /// the exterior ring from its first coordinate, each wound the same way as the ring.
+    ///
+    /// Compared with a fan, the triangles of a strip are less thin when the exterior has many
+    /// coordinates. Like a fan, they cover the `Polygon` exactly if its exterior is convex, and it
+    /// has no interior rings, which are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{polygon, Triangle};
+    ///
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 2., y: 0.),
+    ///     (x: 3., y: 1.),
+    ///     (x: 2., y: 2.),
+    ///     (x: 0., y: 2.),
+    /// ];
+    ///
+    /// let strip: Vec<_> = polygon.triangle_strip().collect();
+    /// assert_eq!(
+    ///     strip,
+    ///     vec![
+    ///         Triangle::from([(0., 0.), (2., 0.), (0., 2.)]),
+    ///         Triangle::from([(0., 2.), (2., 0.), (3., 1.)]),
+    ///         Triangle::from([(0., 2.), (3., 1.), (2., 2.)]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn triangle_strip<'a>(&'a self) -> impl ExactSizeIterator<Item = Triangle<T>> + 'a {
+        let ring = self.open_exterior();
+        let n = ring.len();
+        // the k-th coordinate of the strip alternates between the two sides of the ring
+        let zigzag = move |k: usize| {
+            if k == 0 {
+                ring[0]
+            } else if k % 2 == 1 {
+                ring[k / 2 + 1]
+            } else {
+                ring[n - k / 2]
+            }
+        };
+        (0..n.saturating_sub(2)).map(move |k| {
+            let (a, b, c) = (zigzag(k), zigzag(k + 1), zigzag(k + 2));
+            // consecutive triangles of a strip alternate their winding
+            if k % 2 == 0 {
+                Triangle(a, b, c)
+            } else {
+                Triangle(b, a, c)
+            }
+        })
+    }
+
+    // The coordinates of the exterior ring, without the closing one
+    fn open_exterior(&self) -> &[Coordinate<T>] {
+        let ring = &self.exterior.0;
+        &ring[..ring.len().saturating_sub(1)]
+    }
+
+    /// Return the bounding rectangle of this `Polygon`, or `None` if it's empty.
+    ///
+    /// Only the exterior ring is considered, since the interior rings lie within it.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::line_string_bounding_rect(&self.exterior)
+    }
 }
 
 // used to check the sign of a vec of floats
@@ -411,6 +526,21 @@ where
     }
 }
 
+/// Decoding goes through [`Polygon::new`](#method.new), so decoded rings [will be closed].
+///
+/// [will be closed]: #linestring-closing-operation
+#[cfg(feature = "scale-codec")]
+impl<T> codec::Decode for Polygon<T>
+where
+    T: CoordinateType + codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let exterior = LineString::decode(input)?;
+        let interiors = Vec::<LineString<T>>::decode(input)?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
 impl<T: CoordinateType> From<Rect<T>> for Polygon<T> {
     fn from(r: Rect<T>) -> Polygon<T> {
         Polygon::new(
@@ -426,3 +556,96 @@ impl<T: CoordinateType> From<Rect<T>> for Polygon<T> {
         )
     }
 }
+
+impl<T: CoordinateType> From<Triangle<T>> for Polygon<T> {
+    fn from(t: Triangle<T>) -> Polygon<T> {
+        Polygon::new(vec![t.0, t.1, t.2, t.0].into(), Vec::new())
+    }
+}
+
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::RTreeObject for Polygon<T>
+where
+    T: Float + ::rstar::RTreeNum,
+{
+    type Envelope = ::rstar::AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        crate::private_utils::rect_to_envelope(self.bounding_rect())
+    }
+}
+
+/// Points inside the polygon, and not in one of its holes, are at a distance of zero.
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::PointDistance for Polygon<T>
+where
+    T: Float + ::rstar::RTreeNum,
+{
+    fn distance_2(&self, point: &Point<T>) -> T {
+        let d = crate::private_utils::point_polygon_euclidean_distance(*point, self);
+        d.powi(2)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Polygon<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.exterior.abs_diff_eq(&other.exterior, epsilon)
+            && self.interiors.len() == other.interiors.len()
+            && self
+                .interiors
+                .iter()
+                .zip(&other.interiors)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Polygon<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.exterior
+            .relative_eq(&other.exterior, epsilon, max_relative)
+            && self.interiors.len() == other.interiors.len()
+            && self
+                .interiors
+                .iter()
+                .zip(&other.interiors)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Polygon<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.exterior.ulps_eq(&other.exterior, epsilon, max_ulps)
+            && self.interiors.len() == other.interiors.len()
+            && self
+                .interiors
+                .iter()
+                .zip(&other.interiors)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
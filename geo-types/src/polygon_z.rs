@@ -0,0 +1,104 @@
+use crate::{CoordinateType, LineStringZ, Polygon};
+use alloc::vec::Vec;
+
+/// A bounded area in 3D space, i.e. a [`Polygon`](struct.Polygon.html) whose rings are
+/// [`LineStringZ`](struct.LineStringZ.html)s.
+///
+/// As with `Polygon`, all exterior and interior rings are guaranteed to be _closed_.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{LineStringZ, PolygonZ};
+///
+/// let polygon = PolygonZ::new(
+///     LineStringZ::from(vec![(0., 0., 1.), (1., 1., 1.), (1., 0., 1.)]),
+///     vec![],
+/// );
+///
+/// assert_eq!(
+///     polygon.exterior(),
+///     &LineStringZ::from(vec![(0., 0., 1.), (1., 1., 1.), (1., 0., 1.), (0., 0., 1.)])
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolygonZ<T>
+where
+    T: CoordinateType,
+{
+    exterior: LineStringZ<T>,
+    interiors: Vec<LineStringZ<T>>,
+}
+
+impl<T> PolygonZ<T>
+where
+    T: CoordinateType,
+{
+    /// Create a new `PolygonZ` with the provided exterior `LineStringZ` ring and
+    /// interior `LineStringZ` rings.
+    ///
+    /// Upon calling `new`, the exterior and interior rings will be closed.
+    pub fn new(mut exterior: LineStringZ<T>, mut interiors: Vec<LineStringZ<T>>) -> PolygonZ<T> {
+        exterior.close();
+        for interior in &mut interiors {
+            interior.close();
+        }
+        PolygonZ {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// Consume the `PolygonZ`, returning the exterior ring and a vector of the interior rings.
+    pub fn into_inner(self) -> (LineStringZ<T>, Vec<LineStringZ<T>>) {
+        (self.exterior, self.interiors)
+    }
+
+    /// Return a reference to the exterior `LineStringZ` ring.
+    pub fn exterior(&self) -> &LineStringZ<T> {
+        &self.exterior
+    }
+
+    /// Execute the provided closure `f`, which is provided with a mutable
+    /// reference to the exterior ring. After the closure executes, the ring will be closed.
+    pub fn exterior_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut LineStringZ<T>),
+    {
+        f(&mut self.exterior);
+        self.exterior.close();
+    }
+
+    /// Return a slice of the interior `LineStringZ` rings.
+    pub fn interiors(&self) -> &[LineStringZ<T>] {
+        &self.interiors
+    }
+
+    /// Execute the provided closure `f`, which is provided with a mutable
+    /// reference to the interior rings. After the closure executes, each ring will be closed.
+    pub fn interiors_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [LineStringZ<T>]),
+    {
+        f(&mut self.interiors);
+        for interior in &mut self.interiors {
+            interior.close();
+        }
+    }
+
+    /// Add an interior ring to the `PolygonZ`. The new ring will be closed.
+    pub fn interiors_push(&mut self, new_interior: impl Into<LineStringZ<T>>) {
+        let mut new_interior = new_interior.into();
+        new_interior.close();
+        self.interiors.push(new_interior);
+    }
+
+    /// Drop the `z` component of every coordinate, returning the 2D `Polygon`.
+    pub fn to_2d(&self) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.to_2d(),
+            self.interiors.iter().map(|l| l.to_2d()).collect(),
+        )
+    }
+}
@@ -0,0 +1,312 @@
+//! Encoding line strings as [encoded
+//! polylines](https://developers.google.com/maps/documentation/utilities/polylinealgorithm),
+//! and decoding them.
+//!
+//! With the `polyline` feature enabled,
+//! [`LineString::to_polyline`](../struct.LineString.html#method.to_polyline) encodes a line
+//! string, reading its `x`s as longitudes and `y`s as latitudes, and
+//! [`LineString::from_polyline`](../struct.LineString.html#method.from_polyline) decodes one.
+//!
+//! The precision is the number of decimal places coordinates are rounded to: Google's
+//! polylines use 5, and OSRM and Valhalla's "polyline6" use 6. Coordinates are converted to and
+//! from `f64`s using `NumCast`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{line_string, LineString};
+//!
+//! let line_string = line_string![
+//!     (x: -120.2, y: 38.5),
+//!     (x: -120.95, y: 40.7),
+//!     (x: -126.453, y: 43.252),
+//! ];
+//! let polyline = line_string.to_polyline(5).unwrap();
+//! assert_eq!(polyline, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//! assert_eq!(LineString::from_polyline(&polyline, 5), Ok(line_string));
+//! ```
+use crate::{Coordinate, CoordinateType, LineString};
+use std::error::Error;
+use std::fmt;
+
+/// The most decimal places coordinates can be rounded to.
+pub const MAX_PRECISION: u32 = 10;
+
+/// The error returned when encoding or decoding a polyline fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolylineError {
+    /// The precision is more than [`MAX_PRECISION`](constant.MAX_PRECISION.html).
+    InvalidPrecision(u32),
+    /// A character which can't be in a polyline was found at the given position.
+    InvalidCharacter { found: char, position: usize },
+    /// The polyline ended in the middle of a coordinate.
+    UnexpectedEnd,
+    /// A value starting at the given position is too large to decode.
+    Overflow(usize),
+    /// A coordinate can't be encoded, or can't be represented by the coordinate type.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolylineError::InvalidPrecision(precision) => write!(
+                f,
+                "Polyline precision {} is more than {}",
+                precision, MAX_PRECISION
+            ),
+            PolylineError::InvalidCharacter { found, position } => write!(
+                f,
+                "Invalid polyline character {:?} at position {}",
+                found, position
+            ),
+            PolylineError::UnexpectedEnd => write!(f, "Unexpected end of polyline"),
+            PolylineError::Overflow(position) => {
+                write!(f, "Polyline value at position {} is too large", position)
+            }
+            PolylineError::InvalidCoordinate => write!(f, "Invalid polyline coordinate"),
+        }
+    }
+}
+
+impl Error for PolylineError {}
+
+fn factor(precision: u32) -> Result<f64, PolylineError> {
+    if precision > MAX_PRECISION {
+        return Err(PolylineError::InvalidPrecision(precision));
+    }
+    Ok(10f64.powi(precision as i32))
+}
+
+// A coordinate value, rounded to an integer number of `1 / factor`s
+fn scale<T: CoordinateType>(value: T, factor: f64) -> Result<i64, PolylineError> {
+    let scaled = value
+        .to_f64()
+        .map(|value| (value * factor).round())
+        .ok_or(PolylineError::InvalidCoordinate)?;
+    // leaving room for the differences between values; NaNs aren't finite either
+    if !scaled.is_finite() || scaled.abs() >= (1u64 << 61) as f64 {
+        return Err(PolylineError::InvalidCoordinate);
+    }
+    Ok(scaled as i64)
+}
+
+// Append a value, as chunks of 5 bits from the least significant, with the sign in the lowest
+// bit
+fn encode(value: i64, polyline: &mut String) {
+    let mut value = if value < 0 {
+        !((value as u64) << 1)
+    } else {
+        (value as u64) << 1
+    };
+    while value >= 0x20 {
+        polyline.push((((value & 0x1f) | 0x20) as u8 + 63) as char);
+        value >>= 5;
+    }
+    polyline.push((value as u8 + 63) as char);
+}
+
+// Read the value at `position`, returning it and the position after it
+fn decode(polyline: &[u8], mut position: usize) -> Result<(i64, usize), PolylineError> {
+    let start = position;
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *polyline.get(position).ok_or(PolylineError::UnexpectedEnd)?;
+        if !(63..=126).contains(&byte) {
+            return Err(PolylineError::InvalidCharacter {
+                found: byte as char,
+                position,
+            });
+        }
+        let chunk = u64::from(byte - 63);
+        // only 4 more bits fit once 60 have been read
+        if shift > 60 || (shift == 60 && chunk & 0x10 != 0) {
+            return Err(PolylineError::Overflow(start));
+        }
+        value |= (chunk & 0x1f) << shift;
+        shift += 5;
+        position += 1;
+        if chunk & 0x20 == 0 {
+            break;
+        }
+    }
+    let value = if value & 1 == 1 {
+        !(value >> 1) as i64
+    } else {
+        (value >> 1) as i64
+    };
+    Ok((value, position))
+}
+
+impl<T: CoordinateType> LineString<T> {
+    /// Encode this line string as a polyline, reading `x` as longitude and `y` as latitude,
+    /// with coordinates rounded to `precision` decimal places.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the precision is more than
+    /// [`MAX_PRECISION`](polyline/constant.MAX_PRECISION.html), or if a coordinate isn't
+    /// finite, or is too large to encode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 8.541694, y: 47.376887), (x: 8.5417, y: 47.3769)];
+    /// assert_eq!(line_string.to_polyline(6), Ok("m~sjyA{~ihOYK".to_string()));
+    /// ```
+    pub fn to_polyline(&self, precision: u32) -> Result<String, PolylineError> {
+        let factor = factor(precision)?;
+        let mut polyline = String::new();
+        let mut previous = (0, 0);
+        for coord in &self.0 {
+            let lat = scale(coord.y, factor)?;
+            let lon = scale(coord.x, factor)?;
+            encode(lat - previous.0, &mut polyline);
+            encode(lon - previous.1, &mut polyline);
+            previous = (lat, lon);
+        }
+        Ok(polyline)
+    }
+
+    /// Decode a polyline with `precision` decimal places, with longitudes as `x` and latitudes
+    /// as `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, LineString};
+    ///
+    /// assert_eq!(
+    ///     LineString::from_polyline("_p~iF~ps|U_ulLnnqC", 5),
+    ///     Ok(line_string![(x: -120.2, y: 38.5), (x: -120.95, y: 40.7)])
+    /// );
+    /// ```
+    pub fn from_polyline(polyline: &str, precision: u32) -> Result<LineString<T>, PolylineError> {
+        let factor = factor(precision)?;
+        let polyline = polyline.as_bytes();
+        let value =
+            |scaled: i64| T::from(scaled as f64 / factor).ok_or(PolylineError::InvalidCoordinate);
+        let mut coords = vec![];
+        let (mut lat, mut lon) = (0i64, 0i64);
+        let mut position = 0;
+        while position < polyline.len() {
+            let (lat_delta, lon_start) = decode(polyline, position)?;
+            let (lon_delta, next) = decode(polyline, lon_start)?;
+            lat = lat
+                .checked_add(lat_delta)
+                .ok_or(PolylineError::Overflow(position))?;
+            lon = lon
+                .checked_add(lon_delta)
+                .ok_or(PolylineError::Overflow(lon_start))?;
+            coords.push(Coordinate {
+                x: value(lon)?,
+                y: value(lat)?,
+            });
+            position = next;
+        }
+        Ok(LineString(coords))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn encode_values() {
+        // the example in Google's description of the algorithm
+        let mut polyline = String::new();
+        encode(scale(-179.9832104, 1e5).unwrap(), &mut polyline);
+        assert_eq!(polyline, "`~oia@");
+        assert_eq!(decode(b"`~oia@", 0), Ok((-17998321, 6)));
+
+        for &value in &[0, 1, -1, 31, -32, 1 << 40, -(1 << 60), (1 << 61) - 1] {
+            let mut polyline = String::new();
+            encode(value, &mut polyline);
+            assert_eq!(decode(polyline.as_bytes(), 0), Ok((value, polyline.len())));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let line_string = line_string![
+            (x: 8.541694, y: 47.376887),
+            (x: -0.127758, y: 51.507351),
+            (x: 151.209296, y: -33.86882),
+            (x: 8.541694, y: 47.376887),
+        ];
+        for &precision in &[5, 6] {
+            let polyline = line_string.to_polyline(precision).unwrap();
+            let decoded = LineString::<f64>::from_polyline(&polyline, precision).unwrap();
+            let tolerance = 0.5 / 10f64.powi(precision as i32) + 1e-12;
+            for (a, b) in line_string.0.iter().zip(&decoded.0) {
+                assert!((a.x - b.x).abs() <= tolerance && (a.y - b.y).abs() <= tolerance);
+            }
+            assert_eq!(decoded.0.len(), line_string.0.len());
+        }
+        assert_eq!(LineString::<f64>(vec![]).to_polyline(5), Ok(String::new()));
+        assert_eq!(
+            LineString::<f64>::from_polyline("", 5),
+            Ok(LineString(vec![]))
+        );
+
+        // integer coordinates with no decimal places
+        let line_string: LineString<i32> = line_string![(x: 1, y: 2), (x: -3, y: 4)];
+        let polyline = line_string.to_polyline(0).unwrap();
+        assert_eq!(LineString::from_polyline(&polyline, 0), Ok(line_string));
+    }
+
+    #[test]
+    fn errors() {
+        let line_string = line_string![(x: 1., y: 2.)];
+        assert_eq!(
+            line_string.to_polyline(11),
+            Err(PolylineError::InvalidPrecision(11))
+        );
+        assert_eq!(
+            LineString::<f64>::from_polyline("??", 11),
+            Err(PolylineError::InvalidPrecision(11))
+        );
+        for invalid in &[f64::NAN, f64::INFINITY, 1e300] {
+            assert_eq!(
+                line_string![(x: 0., y: *invalid)].to_polyline(5),
+                Err(PolylineError::InvalidCoordinate)
+            );
+        }
+
+        assert_eq!(
+            LineString::<f64>::from_polyline("_p~iF", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            LineString::<f64>::from_polyline("_p~i", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            LineString::<f64>::from_polyline("_p iF~ps|U", 5),
+            Err(PolylineError::InvalidCharacter {
+                found: ' ',
+                position: 2
+            })
+        );
+        assert_eq!(
+            LineString::<f64>::from_polyline("??é", 5),
+            Err(PolylineError::InvalidCharacter {
+                found: 'Ã',
+                position: 2
+            })
+        );
+        assert_eq!(
+            LineString::<f64>::from_polyline("??~~~~~~~~~~~~~~?", 5),
+            Err(PolylineError::Overflow(2))
+        );
+        assert_eq!(
+            LineString::<u8>::from_polyline("_p~iF~ps|U", 5),
+            Err(PolylineError::InvalidCoordinate)
+        );
+    }
+}
@@ -3,7 +3,7 @@
 // hidden module is public so the geo crate can reuse these algorithms to
 // prevent duplication. These functions are _not_ meant for public consumption.
 
-use crate::{Coordinate, CoordinateType, Line, LineString, Point, Rect};
+use crate::{Coordinate, CoordinateType, Line, LineString, Point, Polygon, Rect, Triangle};
 use num_traits::Float;
 
 pub static COORD_PRECISION: f32 = 1e-1; // 0.1m
@@ -158,3 +158,71 @@ where
     }
     false
 }
+
+pub fn point_polygon_euclidean_distance<T>(p: Point<T>, polygon: &Polygon<T>) -> T
+where
+    T: Float,
+{
+    // Points inside the polygon are at no distance from it
+    if polygon_contains_point(polygon, p) {
+        return T::zero();
+    }
+    core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .filter(|ring| !ring.0.is_empty())
+        .map(|ring| point_line_string_euclidean_distance(p, ring))
+        .fold(T::max_value(), |accum, val| accum.min(val))
+}
+
+pub fn point_triangle_euclidean_distance<T>(p: Point<T>, triangle: Triangle<T>) -> T
+where
+    T: Float,
+{
+    let sides = triangle.to_lines();
+    let cross = |side: Line<T>| Point(side.start).cross_prod(Point(side.end), p);
+    let crosses = [cross(sides[0]), cross(sides[1]), cross(sides[2])];
+    // Inside (or on) the triangle if it's on the same side of, or on, every edge
+    if crosses.iter().all(|&cross| cross >= T::zero())
+        || crosses.iter().all(|&cross| cross <= T::zero())
+    {
+        return T::zero();
+    }
+    sides
+        .iter()
+        .map(|&side| point_line_euclidean_distance(p, side))
+        .fold(T::max_value(), |accum, val| accum.min(val))
+}
+
+// Whether the point is inside an odd number of the polygon's rings, using the crossing number
+pub fn polygon_contains_point<T>(polygon: &Polygon<T>, p: Point<T>) -> bool
+where
+    T: Float,
+{
+    let mut inside = false;
+    for ring in core::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        for line in ring.lines() {
+            let (start, end) = (line.start, line.end);
+            if (start.y > p.y()) != (end.y > p.y())
+                && p.x() < start.x + (p.y() - start.y) * (end.x - start.x) / (end.y - start.y)
+            {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+#[cfg(feature = "rstar")]
+pub fn rect_to_envelope<T>(rect: Option<Rect<T>>) -> ::rstar::AABB<Point<T>>
+where
+    T: Float + ::rstar::RTreeNum,
+{
+    use num_traits::Bounded;
+    match rect {
+        None => ::rstar::AABB::from_corners(
+            Point::new(Bounded::min_value(), Bounded::min_value()),
+            Point::new(Bounded::max_value(), Bounded::max_value()),
+        ),
+        Some(b) => ::rstar::AABB::from_corners(b.min().into(), b.max().into()),
+    }
+}
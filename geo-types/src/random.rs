@@ -0,0 +1,488 @@
+//! Generating random, valid geometries, for property-based testing and fuzzing.
+//!
+//! With the `rand` feature enabled, [`RandomGeometries`](struct.RandomGeometries.html) is a
+//! [`rand`](https://docs.rs/rand/0.8) distribution of every geometry type, and of `Coordinate`s,
+//! which lie within a rectangle. The geometries are valid as well as random:
+//!
+//! * line strings have at least two coordinates, and multi-geometries and geometry collections at
+//!   least one member;
+//! * the rings of polygons are closed and simple, with at least three distinct vertices, and four
+//!   coordinates; exteriors are counter-clockwise and interiors clockwise, and interiors lie
+//!   strictly inside their exterior, apart from one another;
+//! * the polygons of a multi-polygon lie apart from one another, and triangles aren't degenerate.
+//!
+//! To drive a fuzzer, seed a random number generator, such as `rand`'s `StdRng`, with the fuzzed
+//! bytes.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::random::RandomGeometries;
+//! use geo_types::{Geometry, Polygon};
+//! use rand::rngs::StdRng;
+//! use rand::{Rng, SeedableRng};
+//!
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let geometries = RandomGeometries {
+//!     max_points: 16,
+//!     ..RandomGeometries::default()
+//! };
+//!
+//! let polygon: Polygon<f64> = rng.sample(&geometries);
+//! assert!(polygon.exterior().0.len() >= 4);
+//! assert_eq!(polygon.exterior().0.first(), polygon.exterior().0.last());
+//!
+//! let anything: Vec<Geometry<f64>> = (&mut rng).sample_iter(&geometries).take(100).collect();
+//! assert_eq!(anything.len(), 100);
+//! ```
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use num_traits::Float;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// A distribution of random, valid geometries within a rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RandomGeometries<T: Float> {
+    /// The rectangle coordinates lie within.
+    pub bounds: Rect<T>,
+    /// The most coordinates of a line string, and vertices of a ring, not counting the
+    /// coordinate closing it. Line strings have at least 2, and rings at least 3.
+    pub max_points: usize,
+    /// The most interiors of a polygon.
+    pub max_interiors: usize,
+    /// The most members of a multi-geometry or geometry collection, which have at least 1.
+    pub max_members: usize,
+    /// The most levels of geometry collections in a geometry collection, or in a geometry.
+    pub max_depth: usize,
+}
+
+impl<T: Float> Default for RandomGeometries<T> {
+    fn default() -> Self {
+        let coord = |x, y| Coordinate {
+            x: T::from(x).unwrap(),
+            y: T::from(y).unwrap(),
+        };
+        RandomGeometries {
+            bounds: Rect::new(coord(-180, -90), coord(180, 90)),
+            max_points: 8,
+            max_interiors: 2,
+            max_members: 4,
+            max_depth: 2,
+        }
+    }
+}
+
+// A rectangle to place a geometry in, in `f64`s
+#[derive(Clone, Copy)]
+struct Frame {
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl Frame {
+    fn coord<T: Float>(&self, rng: &mut (impl Rng + ?Sized)) -> Coordinate<T> {
+        let x = lerp(self.min.0, self.max.0, rng.gen());
+        let y = lerp(self.min.1, self.max.1, rng.gen());
+        to_coordinate(x, y)
+    }
+
+    // The position of a point of the square from (-1, -1) to (1, 1) when the square is
+    // stretched over the frame
+    fn map<T: Float>(&self, (x, y): (f64, f64)) -> Coordinate<T> {
+        to_coordinate(
+            lerp(self.min.0, self.max.0, (x + 1.) / 2.),
+            lerp(self.min.1, self.max.1, (y + 1.) / 2.),
+        )
+    }
+
+    // The `index`th of `count` cells of a grid over the frame, shrunk so that they're apart
+    fn cell(&self, index: usize, count: usize) -> Frame {
+        let columns = (count as f64).sqrt().ceil() as usize;
+        let rows = count.div_ceil(columns);
+        let width = (self.max.0 - self.min.0) / columns as f64;
+        let height = (self.max.1 - self.min.1) / rows as f64;
+        let min_x = self.min.0 + (index % columns) as f64 * width;
+        let min_y = self.min.1 + (index / columns) as f64 * height;
+        Frame {
+            min: (min_x + 0.05 * width, min_y + 0.05 * height),
+            max: (min_x + 0.95 * width, min_y + 0.95 * height),
+        }
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+fn to_coordinate<T: Float>(x: f64, y: f64) -> Coordinate<T> {
+    Coordinate {
+        x: T::from(x).unwrap(),
+        y: T::from(y).unwrap(),
+    }
+}
+
+// The vertices of a simple ring with `count` vertices around `center`, no further from it than
+// `radius`, counter-clockwise. The vertices are at increasing angles, less than half a turn
+// apart, so the ring is star-shaped around its center.
+fn star(
+    rng: &mut (impl Rng + ?Sized),
+    count: usize,
+    center: (f64, f64),
+    radius: f64,
+) -> Vec<(f64, f64)> {
+    let step = 2. * PI / count as f64;
+    let start = rng.gen_range(0. ..2. * PI);
+    (0..count)
+        .map(|i| {
+            let angle = start + (i as f64 + rng.gen_range(0. ..STEP_JITTER)) * step;
+            let distance = radius * rng.gen_range(0.5..=1.);
+            (
+                center.0 + distance * angle.cos(),
+                center.1 + distance * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+// How far into the next step a vertex of a star's angle can be, which keeps consecutive
+// vertices of a triangle less than half a turn apart
+const STEP_JITTER: f64 = 0.4;
+
+// The distance from the center of a star with `count` vertices to its nearest edge is at least
+// this fraction of its radius
+fn inner_radius(count: usize) -> f64 {
+    let widest = (1. + STEP_JITTER) * 2. * PI / count as f64;
+    0.5 * (widest / 2.).cos()
+}
+
+impl<T: Float> RandomGeometries<T> {
+    fn frame(&self) -> Frame {
+        let min = self.bounds.min();
+        let max = self.bounds.max();
+        let value = |v: T| v.to_f64().unwrap();
+        Frame {
+            min: (value(min.x), value(min.y)),
+            max: (value(max.x), value(max.y)),
+        }
+    }
+
+    fn members(&self, rng: &mut (impl Rng + ?Sized)) -> usize {
+        rng.gen_range(1..=self.max_members.max(1))
+    }
+
+    fn line_string(&self, rng: &mut (impl Rng + ?Sized), frame: &Frame) -> LineString<T> {
+        let count = rng.gen_range(2..=self.max_points.max(2));
+        LineString((0..count).map(|_| frame.coord(rng)).collect())
+    }
+
+    fn polygon(&self, rng: &mut (impl Rng + ?Sized), frame: &Frame) -> Polygon<T> {
+        let count = rng.gen_range(3..=self.max_points.max(3));
+        let ring = |vertices: Vec<(f64, f64)>| -> LineString<T> {
+            let mut coords: Vec<_> = vertices.into_iter().map(|v| frame.map(v)).collect();
+            coords.push(coords[0]);
+            LineString(coords)
+        };
+        let exterior = ring(star(rng, count, (0., 0.), 1.));
+
+        // The interiors are inside the disk the exterior surrounds, around a circle, and are
+        // small enough for their disks to be apart
+        let inner = inner_radius(count);
+        let interiors = match rng.gen_range(0..=self.max_interiors) {
+            0 => vec![],
+            1 => vec![(0., inner * 0.8)],
+            holes => {
+                let gap = (PI / holes as f64).sin().min(1.);
+                let start = rng.gen_range(0. ..2. * PI);
+                (0..holes)
+                    .map(|i| {
+                        let angle = start + 2. * PI * i as f64 / holes as f64;
+                        (angle, inner / 2. * gap * 0.9)
+                    })
+                    .collect()
+            }
+        };
+        let distance = if interiors.len() > 1 { inner / 2. } else { 0. };
+        let interiors = interiors
+            .into_iter()
+            .map(|(angle, radius)| {
+                let center = (distance * angle.cos(), distance * angle.sin());
+                let count = rng.gen_range(3..=self.max_points.max(3));
+                let mut vertices = star(rng, count, center, radius);
+                vertices.reverse();
+                ring(vertices)
+            })
+            .collect();
+        Polygon::new(exterior, interiors)
+    }
+
+    fn geometry(&self, rng: &mut (impl Rng + ?Sized), depth: usize) -> Geometry<T> {
+        let kinds = if depth < self.max_depth { 10 } else { 9 };
+        match rng.gen_range(0..kinds) {
+            0 => Geometry::Point(self.sample(rng)),
+            1 => Geometry::Line(self.sample(rng)),
+            2 => Geometry::LineString(self.sample(rng)),
+            3 => Geometry::Polygon(self.sample(rng)),
+            4 => Geometry::MultiPoint(self.sample(rng)),
+            5 => Geometry::MultiLineString(self.sample(rng)),
+            6 => Geometry::MultiPolygon(self.sample(rng)),
+            7 => Geometry::Rect(self.sample(rng)),
+            8 => Geometry::Triangle(self.sample(rng)),
+            _ => Geometry::GeometryCollection(self.geometry_collection(rng, depth + 1)),
+        }
+    }
+
+    fn geometry_collection(
+        &self,
+        rng: &mut (impl Rng + ?Sized),
+        depth: usize,
+    ) -> GeometryCollection<T> {
+        let count = self.members(rng);
+        GeometryCollection((0..count).map(|_| self.geometry(rng, depth)).collect())
+    }
+}
+
+impl<T: Float> Distribution<Coordinate<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Coordinate<T> {
+        self.frame().coord(rng)
+    }
+}
+
+impl<T: Float> Distribution<Point<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<T> {
+        Point(self.sample(rng))
+    }
+}
+
+impl<T: Float> Distribution<Line<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Line<T> {
+        let start: Coordinate<T> = self.sample(rng);
+        Line::new(start, self.sample(rng))
+    }
+}
+
+impl<T: Float> Distribution<LineString<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> LineString<T> {
+        self.line_string(rng, &self.frame())
+    }
+}
+
+impl<T: Float> Distribution<Polygon<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Polygon<T> {
+        self.polygon(rng, &self.frame())
+    }
+}
+
+impl<T: Float> Distribution<MultiPoint<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MultiPoint<T> {
+        let count = self.members(rng);
+        MultiPoint((0..count).map(|_| self.sample(rng)).collect())
+    }
+}
+
+impl<T: Float> Distribution<MultiLineString<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MultiLineString<T> {
+        let count = self.members(rng);
+        MultiLineString((0..count).map(|_| self.sample(rng)).collect())
+    }
+}
+
+impl<T: Float> Distribution<MultiPolygon<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MultiPolygon<T> {
+        let count = self.members(rng);
+        let frame = self.frame();
+        MultiPolygon(
+            (0..count)
+                .map(|i| self.polygon(rng, &frame.cell(i, count)))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> Distribution<GeometryCollection<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GeometryCollection<T> {
+        self.geometry_collection(rng, 1)
+    }
+}
+
+impl<T: Float> Distribution<Rect<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rect<T> {
+        let (a, b): (Coordinate<T>, Coordinate<T>) = (self.sample(rng), self.sample(rng));
+        Rect::new(
+            Coordinate {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+            },
+            Coordinate {
+                x: a.x.max(b.x),
+                y: a.y.max(b.y),
+            },
+        )
+    }
+}
+
+impl<T: Float> Distribution<Triangle<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Triangle<T> {
+        let frame = self.frame();
+        let vertices = star(rng, 3, (0., 0.), 1.);
+        Triangle(
+            frame.map(vertices[0]),
+            frame.map(vertices[1]),
+            frame.map(vertices[2]),
+        )
+    }
+}
+
+impl<T: Float> Distribution<Geometry<T>> for RandomGeometries<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Geometry<T> {
+        self.geometry(rng, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn twice_signed_area(ring: &LineString<f64>) -> f64 {
+        ring.0
+            .windows(2)
+            .map(|w| w[0].x * w[1].y - w[1].x * w[0].y)
+            .sum()
+    }
+
+    fn segments_cross(a: Line<f64>, b: Line<f64>) -> bool {
+        let side = |l: Line<f64>, c: Coordinate<f64>| {
+            (l.end.x - l.start.x) * (c.y - l.start.y) - (l.end.y - l.start.y) * (c.x - l.start.x)
+        };
+        side(a, b.start) * side(a, b.end) < 0. && side(b, a.start) * side(b, a.end) < 0.
+    }
+
+    fn contains(ring: &LineString<f64>, c: Coordinate<f64>) -> bool {
+        let mut inside = false;
+        for l in ring.lines() {
+            if (l.start.y > c.y) != (l.end.y > c.y)
+                && c.x
+                    < l.start.x + (c.y - l.start.y) / (l.end.y - l.start.y) * (l.end.x - l.start.x)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    fn is_simple(ring: &LineString<f64>) -> bool {
+        let lines: Vec<_> = ring.lines().collect();
+        lines
+            .iter()
+            .enumerate()
+            .all(|(i, &a)| lines[i + 1..].iter().all(|&b| !segments_cross(a, b)))
+    }
+
+    fn check_polygon(polygon: &Polygon<f64>, max_points: usize) {
+        let exterior = polygon.exterior();
+        assert!(exterior.0.len() >= 4 && exterior.0.len() <= max_points + 1);
+        assert_eq!(exterior.0.first(), exterior.0.last());
+        assert!(twice_signed_area(exterior) > 0.);
+        assert!(is_simple(exterior));
+        for (i, interior) in polygon.interiors().iter().enumerate() {
+            assert!(interior.0.len() >= 4);
+            assert_eq!(interior.0.first(), interior.0.last());
+            assert!(twice_signed_area(interior) < 0.);
+            assert!(is_simple(interior));
+            assert!(interior.0.iter().all(|&c| contains(exterior, c)));
+            for line in interior.lines() {
+                assert!(exterior.lines().all(|other| !segments_cross(line, other)));
+            }
+            for other in &polygon.interiors()[i + 1..] {
+                assert!(other.0.iter().all(|&c| !contains(interior, c)));
+                assert!(interior.0.iter().all(|&c| !contains(other, c)));
+            }
+        }
+    }
+
+    fn within(geometries: &RandomGeometries<f64>, c: Coordinate<f64>) -> bool {
+        let (min, max) = (geometries.bounds.min(), geometries.bounds.max());
+        c.x >= min.x && c.x <= max.x && c.y >= min.y && c.y <= max.y
+    }
+
+    #[test]
+    fn valid_geometries() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for &max_points in &[3, 12] {
+            let geometries = RandomGeometries {
+                bounds: Rect::new(Coordinate { x: 10., y: -5. }, Coordinate { x: 20., y: 1. }),
+                max_points,
+                max_interiors: 5,
+                max_members: 6,
+                max_depth: 2,
+            };
+            check_geometries(&mut rng, &geometries);
+        }
+    }
+
+    fn check_geometries(rng: &mut StdRng, geometries: &RandomGeometries<f64>) {
+        for _ in 0..200 {
+            let polygon: Polygon<f64> = rng.sample(geometries);
+            check_polygon(&polygon, geometries.max_points);
+            assert!(polygon.exterior().0.iter().all(|&c| within(geometries, c)));
+
+            let multi_polygon: MultiPolygon<f64> = rng.sample(geometries);
+            assert!(!multi_polygon.0.is_empty() && multi_polygon.0.len() <= 6);
+            for (i, polygon) in multi_polygon.0.iter().enumerate() {
+                check_polygon(polygon, geometries.max_points);
+                for other in &multi_polygon.0[i + 1..] {
+                    let (a, b) = (polygon.exterior(), other.exterior());
+                    assert!(a.0.iter().all(|&c| !contains(b, c)));
+                    assert!(b.0.iter().all(|&c| !contains(a, c)));
+                    for line in a.lines() {
+                        assert!(b.lines().all(|other| !segments_cross(line, other)));
+                    }
+                }
+            }
+
+            let line_string: LineString<f64> = rng.sample(geometries);
+            assert!(line_string.0.len() >= 2);
+            assert!(line_string.0.len() <= geometries.max_points.max(2));
+            assert!(line_string.0.iter().all(|&c| within(geometries, c)));
+
+            let triangle: Triangle<f64> = rng.sample(geometries);
+            assert!(twice_signed_area(Polygon::from(triangle).exterior()) > 0.);
+
+            let multi_point: MultiPoint<f64> = rng.sample(geometries);
+            assert!(!multi_point.0.is_empty() && multi_point.0.len() <= 6);
+        }
+    }
+
+    #[test]
+    fn nesting() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let geometries = RandomGeometries::<f32>::default();
+        fn depth(geometry: &Geometry<f32>) -> usize {
+            match geometry {
+                Geometry::GeometryCollection(gc) => 1 + gc.0.iter().map(depth).max().unwrap(),
+                _ => 0,
+            }
+        }
+        let mut kinds = std::collections::HashSet::new();
+        for _ in 0..500 {
+            let geometry: Geometry<f32> = rng.sample(geometries);
+            assert!(depth(&geometry) <= geometries.max_depth);
+            kinds.insert(core::mem::discriminant(&geometry));
+
+            let gc: GeometryCollection<f32> = rng.sample(geometries);
+            assert!(!gc.0.is_empty());
+            assert!(gc.0.iter().all(|g| depth(g) < geometries.max_depth));
+        }
+        assert_eq!(kinds.len(), 10);
+
+        // the same seed gives the same geometries
+        let sample = |seed| -> Geometry<f32> { StdRng::seed_from_u64(seed).sample(geometries) };
+        assert_eq!(sample(2), sample(2));
+    }
+}
@@ -3,6 +3,7 @@ use crate::{Coordinate, CoordinateType};
 /// A bounded 2D quadrilateral whose area is defined by minimum and maximum `Coordinates`.
 #[derive(PartialEq, Clone, Copy, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "scale-codec", derive(codec::Encode, codec::MaxEncodedLen))]
 pub struct Rect<T>
 where
     T: CoordinateType,
@@ -41,7 +42,27 @@ impl<T: CoordinateType> Rect<T> {
         Rect { min, max }
     }
 
-    pub fn min(self) -> Coordinate<T> {
+    /// Creates a new rectangle, like [`new`](#method.new), but usable in a `const` or `static`,
+    /// which means `min` and `max` can't be checked: a `Rect` whose `min` is larger than its `max`
+    /// is a logic error, which other methods may misbehave on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// const WEB_MERCATOR: Rect<f64> = Rect::new_unchecked(
+    ///     Coordinate::new(-20037508.34, -20037508.34),
+    ///     Coordinate::new(20037508.34, 20037508.34),
+    /// );
+    ///
+    /// assert_eq!(WEB_MERCATOR.width(), 2. * 20037508.34);
+    /// ```
+    pub const fn new_unchecked(min: Coordinate<T>, max: Coordinate<T>) -> Rect<T> {
+        Rect { min, max }
+    }
+
+    pub const fn min(self) -> Coordinate<T> {
         self.min
     }
 
@@ -53,7 +74,7 @@ impl<T: CoordinateType> Rect<T> {
         Self::assert_valid_bounds(self.min, self.max);
     }
 
-    pub fn max(self) -> Coordinate<T> {
+    pub const fn max(self) -> Coordinate<T> {
         self.max
     }
 
@@ -73,6 +94,11 @@ impl<T: CoordinateType> Rect<T> {
         self.max().y - self.min().y
     }
 
+    /// Return the bounding rectangle of this `Rect`, i.e. itself.
+    pub fn bounding_rect(&self) -> Rect<T> {
+        *self
+    }
+
     fn assert_valid_bounds(min: Coordinate<T>, max: Coordinate<T>) {
         assert!(
             min.x <= max.x && min.y <= max.y,
@@ -81,6 +107,92 @@ impl<T: CoordinateType> Rect<T> {
     }
 }
 
+/// Decoding fails if the decoded `min` coordinate is larger than the `max` coordinate.
+#[cfg(feature = "scale-codec")]
+impl<T> codec::Decode for Rect<T>
+where
+    T: CoordinateType + codec::Decode,
+{
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let min = Coordinate::decode(input)?;
+        let max = Coordinate::decode(input)?;
+        if min.x <= max.x && min.y <= max.y {
+            Ok(Rect { min, max })
+        } else {
+            Err("Rect 'min' coordinate is larger than its 'max' coordinate".into())
+        }
+    }
+}
+
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::RTreeObject for Rect<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    type Envelope = ::rstar::AABB<crate::Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        ::rstar::AABB::from_corners(self.min.into(), self.max.into())
+    }
+}
+
+/// Points inside the rectangle are at a distance of zero.
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::PointDistance for Rect<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    fn distance_2(&self, point: &crate::Point<T>) -> T {
+        ::rstar::RTreeObject::envelope(self).distance_2(point)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Rect<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.min.abs_diff_eq(&other.min, epsilon) && self.max.abs_diff_eq(&other.max, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Rect<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.min.relative_eq(&other.min, epsilon, max_relative)
+            && self.max.relative_eq(&other.max, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Rect<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.min.ulps_eq(&other.min, epsilon, max_ulps)
+            && self.max.ulps_eq(&other.max, epsilon, max_ulps)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -105,6 +217,15 @@ mod test {
         assert_eq!(rect.width(), 10);
     }
 
+    #[cfg(feature = "scale-codec")]
+    #[test]
+    fn rect_decode_invalid_bounds() {
+        use codec::{Decode, Encode};
+
+        let encoded = (Coordinate { x: 10, y: 20 }, Coordinate { x: 20, y: 10 }).encode();
+        assert!(Rect::<i32>::decode(&mut &encoded[..]).is_err());
+    }
+
     #[test]
     fn rect_height() {
         let rect = Rect::new((10., 10.), (20., 20.));
@@ -0,0 +1,1128 @@
+//! Reading and writing the geometries of [shapefiles](https://en.wikipedia.org/wiki/Shapefile).
+//!
+//! With the `shapefile` feature enabled, a [`Reader`](struct.Reader.html) reads the shapes of
+//! a shapefile's `.shp` file in order, or, given its `.shx` index too, by their position, and
+//! a [`Writer`](struct.Writer.html) writes both files. The attributes in the `.dbf` file
+//! aren't read or written: its rows are in the same order as the shapes.
+//!
+//! Like [WKB](../wkb/index.html), shapefile coordinates are always `f64`s: other coordinate
+//! types are converted using `NumCast`. Shapes are read as:
+//!
+//! - Point: a `Point`
+//! - MultiPoint: a `MultiPoint`
+//! - PolyLine: a `LineString` if it has one part, and a `MultiLineString` otherwise
+//! - Polygon: a `Polygon` if it has one outer ring, and a `MultiPolygon` otherwise
+//! - Null: `None`
+//!
+//! The rings of a Polygon shape aren't grouped into polygons: outer rings are clockwise, and
+//! holes counterclockwise. Each hole is assigned to the smallest outer ring which contains it,
+//! since an outer ring can be an island in the hole of another, and a hole outside every outer
+//! ring is read as an outer ring itself. When writing, rings are closed, and reversed if they
+//! have the wrong winding. A `Line` is written as a PolyLine, and a `Rect` or `Triangle` as a
+//! Polygon.
+//!
+//! The Z and M values of PointZ, PolyLineM, etc. shapes are ignored, and MultiPatch shapes
+//! can't be read.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::shapefile::{Reader, ShapeType, ShapefileError, Writer};
+//! use geo_types::{line_string, Geometry};
+//!
+//! let mut writer = Writer::new(ShapeType::PolyLine)?;
+//! writer.add_shape(Some(&Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)])))?;
+//! writer.add_shape::<f64>(None)?;
+//! let (shp, shx) = writer.finish();
+//!
+//! let reader = Reader::with_index(&shp, &shx)?;
+//! assert_eq!(reader.shape_count(), Some(2));
+//! assert_eq!(
+//!     reader.shape::<f64>(0)?,
+//!     Some(Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]))
+//! );
+//! assert_eq!(reader.shapes::<f64>().collect::<Result<Vec<_>, _>>()?.len(), 2);
+//! # Ok::<(), ShapefileError>(())
+//! ```
+use crate::private_utils::polygon_contains_point;
+use crate::{
+    Coordinate, CoordinateType, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect,
+};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
+
+const FILE_CODE: i32 = 9994;
+const VERSION: i32 = 1000;
+const HEADER_SIZE: usize = 100;
+
+/// The type of the shapes in a shapefile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeType {
+    Null,
+    Point,
+    PolyLine,
+    Polygon,
+    MultiPoint,
+    PointZ,
+    PolyLineZ,
+    PolygonZ,
+    MultiPointZ,
+    PointM,
+    PolyLineM,
+    PolygonM,
+    MultiPointM,
+}
+
+impl ShapeType {
+    fn code(self) -> i32 {
+        match self {
+            ShapeType::Null => 0,
+            ShapeType::Point => 1,
+            ShapeType::PolyLine => 3,
+            ShapeType::Polygon => 5,
+            ShapeType::MultiPoint => 8,
+            ShapeType::PointZ => 11,
+            ShapeType::PolyLineZ => 13,
+            ShapeType::PolygonZ => 15,
+            ShapeType::MultiPointZ => 18,
+            ShapeType::PointM => 21,
+            ShapeType::PolyLineM => 23,
+            ShapeType::PolygonM => 25,
+            ShapeType::MultiPointM => 28,
+        }
+    }
+
+    fn from_code(code: i32) -> Result<ShapeType, ShapefileError> {
+        Ok(match code {
+            0 => ShapeType::Null,
+            1 => ShapeType::Point,
+            3 => ShapeType::PolyLine,
+            5 => ShapeType::Polygon,
+            8 => ShapeType::MultiPoint,
+            11 => ShapeType::PointZ,
+            13 => ShapeType::PolyLineZ,
+            15 => ShapeType::PolygonZ,
+            18 => ShapeType::MultiPointZ,
+            21 => ShapeType::PointM,
+            23 => ShapeType::PolyLineM,
+            25 => ShapeType::PolygonM,
+            28 => ShapeType::MultiPointM,
+            _ => return Err(ShapefileError::UnsupportedShapeType(code)),
+        })
+    }
+}
+
+/// The error returned when reading or writing a shapefile fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShapefileError {
+    /// The input ended before the header or a shape was complete.
+    UnexpectedEnd,
+    /// The input doesn't start with the header of a `.shp` or `.shx` file.
+    NotAShapefile,
+    /// The shape type code is unknown, or isn't supported (e.g. MultiPatch).
+    UnsupportedShapeType(i32),
+    /// A shape, or an index, has a different shape type than the file.
+    MismatchedShapeType {
+        expected: ShapeType,
+        found: ShapeType,
+    },
+    /// A shape is malformed (e.g. its parts refer to points it doesn't have).
+    InvalidShape(&'static str),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+    /// The `.shx` index wasn't given, so shapes can't be read by their position.
+    NoIndex,
+    /// There's no shape at the given position.
+    NoSuchShape(usize),
+    /// A geometry can't be written as a shape of the file's type.
+    MismatchedGeometry {
+        shape_type: ShapeType,
+        found: &'static str,
+    },
+    /// The file would be larger than a shapefile can be.
+    TooLarge,
+}
+
+impl fmt::Display for ShapefileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShapefileError::UnexpectedEnd => write!(f, "Unexpected end of shapefile"),
+            ShapefileError::NotAShapefile => write!(f, "Not a shapefile"),
+            ShapefileError::UnsupportedShapeType(code) => {
+                write!(f, "Unsupported shape type {}", code)
+            }
+            ShapefileError::MismatchedShapeType { expected, found } => write!(
+                f,
+                "Expected a {:?} shape, but found a {:?}",
+                expected, found
+            ),
+            ShapefileError::InvalidShape(reason) => write!(f, "Invalid shape: {}", reason),
+            ShapefileError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+            ShapefileError::NoIndex => write!(f, "No shapefile index was given"),
+            ShapefileError::NoSuchShape(index) => write!(f, "No shape at position {}", index),
+            ShapefileError::MismatchedGeometry { shape_type, found } => {
+                write!(
+                    f,
+                    "A {} can't be written as a {:?} shape",
+                    found, shape_type
+                )
+            }
+            ShapefileError::TooLarge => write!(f, "Shapefile too large"),
+        }
+    }
+}
+
+impl Error for ShapefileError {}
+
+/// The header of a shapefile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    /// The type of every shape which isn't Null.
+    pub shape_type: ShapeType,
+    /// The bounding rectangle of the shapes, unless it isn't a valid `Rect`.
+    pub bbox: Option<Rect<f64>>,
+}
+
+// Reading
+
+fn take<const N: usize>(bytes: &[u8], position: usize) -> Result<[u8; N], ShapefileError> {
+    let mut buf = [0; N];
+    buf.copy_from_slice(
+        position
+            .checked_add(N)
+            .and_then(|end| bytes.get(position..end))
+            .ok_or(ShapefileError::UnexpectedEnd)?,
+    );
+    Ok(buf)
+}
+
+fn be_i32(bytes: &[u8], position: usize) -> Result<i32, ShapefileError> {
+    take(bytes, position).map(i32::from_be_bytes)
+}
+
+fn le_i32(bytes: &[u8], position: usize) -> Result<i32, ShapefileError> {
+    take(bytes, position).map(i32::from_le_bytes)
+}
+
+fn le_f64(bytes: &[u8], position: usize) -> Result<f64, ShapefileError> {
+    take(bytes, position).map(f64::from_le_bytes)
+}
+
+// A length in bytes, given in 16-bit words
+fn words(words: i32) -> Option<usize> {
+    usize::try_from(words).ok()?.checked_mul(2)
+}
+
+// The header, and the length of the file it gives, which is no longer than `bytes`
+fn read_header(bytes: &[u8]) -> Result<(Header, usize), ShapefileError> {
+    if be_i32(bytes, 0)? != FILE_CODE || le_i32(bytes, 28)? != VERSION {
+        return Err(ShapefileError::NotAShapefile);
+    }
+    let len = words(be_i32(bytes, 24)?)
+        .filter(|&len| len >= HEADER_SIZE)
+        .ok_or(ShapefileError::NotAShapefile)?;
+    let shape_type = ShapeType::from_code(le_i32(bytes, 32)?)?;
+    let min = Coordinate {
+        x: le_f64(bytes, 36)?,
+        y: le_f64(bytes, 44)?,
+    };
+    let max = Coordinate {
+        x: le_f64(bytes, 52)?,
+        y: le_f64(bytes, 60)?,
+    };
+    let bbox = if min.x <= max.x && min.y <= max.y {
+        Some(Rect::new(min, max))
+    } else {
+        None
+    };
+    Ok((Header { shape_type, bbox }, len.min(bytes.len())))
+}
+
+fn count(content: &[u8], position: usize) -> Result<usize, ShapefileError> {
+    usize::try_from(le_i32(content, position)?)
+        .map_err(|_| ShapefileError::InvalidShape("negative number of parts or points"))
+}
+
+fn read_coords(
+    content: &[u8],
+    position: usize,
+    count: usize,
+) -> Result<Vec<Coordinate<f64>>, ShapefileError> {
+    // Check that the coordinates are there before allocating them
+    count
+        .checked_mul(16)
+        .and_then(|len| position.checked_add(len))
+        .filter(|&end| end <= content.len())
+        .ok_or(ShapefileError::UnexpectedEnd)?;
+    (0..count)
+        .map(|i| {
+            Ok(Coordinate {
+                x: le_f64(content, position + 16 * i)?,
+                y: le_f64(content, position + 16 * i + 8)?,
+            })
+        })
+        .collect()
+}
+
+// The points of each part of a PolyLine or Polygon, after its bounding box
+fn read_parts(content: &[u8]) -> Result<Vec<Vec<Coordinate<f64>>>, ShapefileError> {
+    let num_parts = count(content, 36)?;
+    let num_points = count(content, 40)?;
+    let points_position = num_parts
+        .checked_mul(4)
+        .and_then(|len| len.checked_add(44))
+        .ok_or(ShapefileError::UnexpectedEnd)?;
+    let mut points = read_coords(content, points_position, num_points)?.into_iter();
+    let starts = (0..num_parts)
+        .map(|i| count(content, 44 + 4 * i))
+        .collect::<Result<Vec<_>, _>>()?;
+    if starts.first().is_some_and(|&start| start != 0) {
+        return Err(ShapefileError::InvalidShape(
+            "first part doesn't start at 0",
+        ));
+    }
+    // Each part ends where the next one starts
+    let ends = starts.iter().skip(1).copied().chain(iter::once(num_points));
+    starts
+        .iter()
+        .zip(ends)
+        .map(|(&start, end)| {
+            if start > end || end > num_points {
+                return Err(ShapefileError::InvalidShape("invalid starts of parts"));
+            }
+            Ok(points.by_ref().take(end - start).collect())
+        })
+        .collect()
+}
+
+// Twice the signed area of a ring, which is negative if it's clockwise
+fn signed_area(ring: &[Coordinate<f64>]) -> f64 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum()
+}
+
+// Whether a hole is inside an outer ring, going by the first of its vertices which isn't
+// also one of the outer ring's, since holes can touch their outer rings
+fn contains_hole(outer: &Polygon<f64>, hole: &[Coordinate<f64>]) -> bool {
+    hole.iter()
+        .find(|coord| !outer.exterior().0.contains(coord))
+        .is_some_and(|&coord| polygon_contains_point(outer, Point(coord)))
+}
+
+// Group the rings of a Polygon shape into polygons, by their winding
+fn assemble_polygons(rings: Vec<Vec<Coordinate<f64>>>) -> Vec<Polygon<f64>> {
+    let mut outers = vec![];
+    let mut holes = vec![];
+    for ring in rings.into_iter().filter(|ring| !ring.is_empty()) {
+        let area = signed_area(&ring);
+        if area < 0. {
+            outers.push((Polygon::new(LineString(ring), vec![]), -area));
+        } else {
+            holes.push(ring);
+        }
+    }
+    let mut interiors = vec![vec![]; outers.len()];
+    let mut orphans = vec![];
+    for hole in holes {
+        let smallest = outers
+            .iter()
+            .enumerate()
+            .filter(|(_, (outer, _))| contains_hole(outer, &hole))
+            .min_by(|(_, (_, a)), (_, (_, b))| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(i, _)| i);
+        match smallest {
+            Some(i) => interiors[i].push(LineString(hole)),
+            None => orphans.push(Polygon::new(LineString(hole), vec![])),
+        }
+    }
+    outers
+        .into_iter()
+        .zip(interiors)
+        .map(|((outer, _), interiors)| Polygon::new(outer.into_inner().0, interiors))
+        .chain(orphans)
+        .collect()
+}
+
+fn convert<T: CoordinateType>(
+    coords: Vec<Coordinate<f64>>,
+) -> Result<LineString<T>, ShapefileError> {
+    let ordinate = |value: f64| T::from(value).ok_or(ShapefileError::InvalidCoordinate(value));
+    coords
+        .into_iter()
+        .map(|coord| {
+            Ok(Coordinate {
+                x: ordinate(coord.x)?,
+                y: ordinate(coord.y)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(LineString)
+}
+
+fn convert_polygon<T: CoordinateType>(polygon: Polygon<f64>) -> Result<Polygon<T>, ShapefileError> {
+    let (exterior, interiors) = polygon.into_inner();
+    Ok(Polygon::new(
+        convert(exterior.0)?,
+        interiors
+            .into_iter()
+            .map(|interior| convert(interior.0))
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+// The shape in the content of a record
+fn read_shape<T: CoordinateType>(
+    content: &[u8],
+    shape_type: ShapeType,
+) -> Result<Option<Geometry<T>>, ShapefileError> {
+    let found = ShapeType::from_code(le_i32(content, 0)?)?;
+    Ok(Some(match found {
+        ShapeType::Null => return Ok(None),
+        _ if found != shape_type => {
+            return Err(ShapefileError::MismatchedShapeType {
+                expected: shape_type,
+                found,
+            });
+        }
+        ShapeType::Point | ShapeType::PointZ | ShapeType::PointM => {
+            let mut points = convert(read_coords(content, 4, 1)?)?.0;
+            Geometry::Point(Point(points.remove(0)))
+        }
+        ShapeType::MultiPoint | ShapeType::MultiPointZ | ShapeType::MultiPointM => {
+            let points = convert(read_coords(content, 40, count(content, 36)?)?)?;
+            Geometry::MultiPoint(MultiPoint(points.0.into_iter().map(Point).collect()))
+        }
+        ShapeType::PolyLine | ShapeType::PolyLineZ | ShapeType::PolyLineM => {
+            let mut parts = read_parts(content)?
+                .into_iter()
+                .map(convert)
+                .collect::<Result<Vec<_>, _>>()?;
+            if parts.len() == 1 {
+                Geometry::LineString(parts.remove(0))
+            } else {
+                Geometry::MultiLineString(MultiLineString(parts))
+            }
+        }
+        ShapeType::Polygon | ShapeType::PolygonZ | ShapeType::PolygonM => {
+            let mut polygons = assemble_polygons(read_parts(content)?)
+                .into_iter()
+                .map(convert_polygon)
+                .collect::<Result<Vec<_>, _>>()?;
+            if polygons.len() == 1 {
+                Geometry::Polygon(polygons.remove(0))
+            } else {
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+        }
+    }))
+}
+
+// The shape of the record at `position`, and the position of the next record
+fn read_record<T: CoordinateType>(
+    shp: &[u8],
+    position: usize,
+    shape_type: ShapeType,
+) -> Result<(Option<Geometry<T>>, usize), ShapefileError> {
+    let len = words(be_i32(shp, position.saturating_add(4))?)
+        .ok_or(ShapefileError::InvalidShape("negative content length"))?;
+    let start = position + 8;
+    let end = start
+        .checked_add(len)
+        .ok_or(ShapefileError::UnexpectedEnd)?;
+    let content = shp.get(start..end).ok_or(ShapefileError::UnexpectedEnd)?;
+    Ok((read_shape(content, shape_type)?, end))
+}
+
+/// Reads the shapes of a shapefile from its `.shp` file, and optionally its `.shx` index.
+pub struct Reader<'a> {
+    shp: &'a [u8],
+    // The entries of the index, after its header
+    shx: Option<&'a [u8]>,
+    header: Header,
+}
+
+impl<'a> Reader<'a> {
+    /// Read the header of a `.shp` file, to read its shapes in order.
+    pub fn new(shp: &'a [u8]) -> Result<Reader<'a>, ShapefileError> {
+        let (header, len) = read_header(shp)?;
+        Ok(Reader {
+            shp: &shp[..len],
+            shx: None,
+            header,
+        })
+    }
+
+    /// Read the headers of a `.shp` file and its `.shx` index, to also read shapes by their
+    /// position.
+    pub fn with_index(shp: &'a [u8], shx: &'a [u8]) -> Result<Reader<'a>, ShapefileError> {
+        let mut reader = Reader::new(shp)?;
+        let (index_header, len) = read_header(shx)?;
+        if index_header.shape_type != reader.header.shape_type {
+            return Err(ShapefileError::MismatchedShapeType {
+                expected: reader.header.shape_type,
+                found: index_header.shape_type,
+            });
+        }
+        // the header may be cut short, or give a length shorter than itself
+        let index = shx
+            .get(HEADER_SIZE..len)
+            .ok_or(ShapefileError::InvalidShape("truncated index"))?;
+        reader.shx = Some(index);
+        Ok(reader)
+    }
+
+    /// The header of the `.shp` file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The number of shapes, if the index was given.
+    pub fn shape_count(&self) -> Option<usize> {
+        self.shx.map(|shx| shx.len() / 8)
+    }
+
+    /// Read the shape at `index`, counting from 0, using the `.shx` index. A Null shape is
+    /// read as `None`.
+    pub fn shape<T: CoordinateType>(
+        &self,
+        index: usize,
+    ) -> Result<Option<Geometry<T>>, ShapefileError> {
+        let shx = self.shx.ok_or(ShapefileError::NoIndex)?;
+        let entry = index
+            .checked_mul(8)
+            .filter(|&entry| entry + 8 <= shx.len())
+            .ok_or(ShapefileError::NoSuchShape(index))?;
+        let position = words(be_i32(shx, entry)?)
+            .filter(|&position| position >= HEADER_SIZE)
+            .ok_or(ShapefileError::InvalidShape("invalid offset in the index"))?;
+        read_record(self.shp, position, self.header.shape_type).map(|(shape, _)| shape)
+    }
+
+    /// An iterator over the shapes, in order. A Null shape is read as `None`.
+    pub fn shapes<T: CoordinateType>(&self) -> Shapes<'a, T> {
+        Shapes {
+            shp: self.shp,
+            shape_type: self.header.shape_type,
+            position: HEADER_SIZE,
+            failed: false,
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the shapes of a [`Reader`](struct.Reader.html), which ends after the first
+/// error.
+pub struct Shapes<'a, T: CoordinateType> {
+    shp: &'a [u8],
+    shape_type: ShapeType,
+    position: usize,
+    failed: bool,
+    coordinate_type: PhantomData<T>,
+}
+
+impl<'a, T: CoordinateType> Iterator for Shapes<'a, T> {
+    type Item = Result<Option<Geometry<T>>, ShapefileError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.position >= self.shp.len() {
+            return None;
+        }
+        match read_record(self.shp, self.position, self.shape_type) {
+            Ok((shape, next)) => {
+                self.position = next;
+                Some(Ok(shape))
+            }
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// Writing
+
+// The bounding box of some coordinates, ignoring NaNs
+#[derive(Clone, Copy)]
+struct Bounds {
+    min: Coordinate<f64>,
+    max: Coordinate<f64>,
+}
+
+impl Bounds {
+    fn empty() -> Bounds {
+        Bounds {
+            min: Coordinate {
+                x: f64::INFINITY,
+                y: f64::INFINITY,
+            },
+            max: Coordinate {
+                x: f64::NEG_INFINITY,
+                y: f64::NEG_INFINITY,
+            },
+        }
+    }
+
+    fn of(coords: &[Coordinate<f64>]) -> Bounds {
+        let mut bounds = Bounds::empty();
+        for &coord in coords {
+            bounds.expand(coord, coord);
+        }
+        bounds
+    }
+
+    fn expand(&mut self, min: Coordinate<f64>, max: Coordinate<f64>) {
+        self.min.x = self.min.x.min(min.x);
+        self.min.y = self.min.y.min(min.y);
+        self.max.x = self.max.x.max(max.x);
+        self.max.y = self.max.y.max(max.y);
+    }
+
+    // Written as zeros if there are no coordinates
+    fn write(&self, buf: &mut Vec<u8>) {
+        let ordinates = if self.min.x <= self.max.x {
+            [self.min.x, self.min.y, self.max.x, self.max.y]
+        } else {
+            [0.; 4]
+        };
+        for ordinate in &ordinates {
+            buf.extend_from_slice(&ordinate.to_le_bytes());
+        }
+    }
+}
+
+fn to_f64<T: CoordinateType>(coords: &[Coordinate<T>]) -> Vec<Coordinate<f64>> {
+    coords
+        .iter()
+        .map(|coord| Coordinate {
+            x: coord
+                .x
+                .to_f64()
+                .expect("coordinate can't be converted to f64"),
+            y: coord
+                .y
+                .to_f64()
+                .expect("coordinate can't be converted to f64"),
+        })
+        .collect()
+}
+
+fn write_coords(coords: &[Coordinate<f64>], buf: &mut Vec<u8>) {
+    for coord in coords {
+        buf.extend_from_slice(&coord.x.to_le_bytes());
+        buf.extend_from_slice(&coord.y.to_le_bytes());
+    }
+}
+
+// A closed ring, reversed if needed so that it's clockwise, or counterclockwise for a hole
+fn ring<T: CoordinateType>(ring: &LineString<T>, clockwise: bool) -> Vec<Coordinate<f64>> {
+    let mut ring = to_f64(&ring.0);
+    if ring.first() != ring.last() {
+        ring.push(ring[0]);
+    }
+    if (signed_area(&ring) < 0.) != clockwise {
+        ring.reverse();
+    }
+    ring
+}
+
+fn polygon_rings<T: CoordinateType>(polygon: &Polygon<T>) -> Vec<Vec<Coordinate<f64>>> {
+    if polygon.exterior().0.is_empty() {
+        return vec![];
+    }
+    iter::once(ring(polygon.exterior(), true))
+        .chain(
+            polygon
+                .interiors()
+                .iter()
+                .filter(|interior| !interior.0.is_empty())
+                .map(|interior| ring(interior, false)),
+        )
+        .collect()
+}
+
+/// Writes shapes to the `.shp` file and `.shx` index of a shapefile.
+///
+/// Since both files start with the bounding box of every shape, shapes are encoded as they're
+/// added, and kept until both files are returned by [`finish`](#method.finish).
+pub struct Writer {
+    shape_type: ShapeType,
+    shp: Vec<u8>,
+    shx: Vec<u8>,
+    bounds: Bounds,
+}
+
+impl Writer {
+    /// Write shapes of `shape_type`, which can be Null, Point, MultiPoint, PolyLine, or
+    /// Polygon.
+    pub fn new(shape_type: ShapeType) -> Result<Writer, ShapefileError> {
+        match shape_type {
+            ShapeType::Null
+            | ShapeType::Point
+            | ShapeType::MultiPoint
+            | ShapeType::PolyLine
+            | ShapeType::Polygon => Ok(Writer {
+                shape_type,
+                shp: vec![0; HEADER_SIZE],
+                shx: vec![0; HEADER_SIZE],
+                bounds: Bounds::empty(),
+            }),
+            _ => Err(ShapefileError::UnsupportedShapeType(shape_type.code())),
+        }
+    }
+
+    /// Write the shape of `geometry`, or a Null shape for `None`.
+    ///
+    /// Fails with `ShapefileError::MismatchedGeometry` if it can't be written as a shape of
+    /// the file's type, such as a `Point` in a PolyLine file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn add_shape<T: CoordinateType>(
+        &mut self,
+        geometry: Option<&Geometry<T>>,
+    ) -> Result<(), ShapefileError> {
+        let content = match geometry {
+            Some(geometry) => self.content(geometry)?,
+            None => ShapeType::Null.code().to_le_bytes().to_vec(),
+        };
+        let position = self.shp.len();
+        let record = (self.shx.len() - HEADER_SIZE) / 8 + 1;
+        let words = |len: usize| i32::try_from(len / 2).map_err(|_| ShapefileError::TooLarge);
+        let (offset, len) = (words(position)?, words(content.len())?);
+        words(position + 8 + content.len())?;
+        self.shx.extend_from_slice(&offset.to_be_bytes());
+        self.shx.extend_from_slice(&len.to_be_bytes());
+        self.shp.extend_from_slice(&(record as i32).to_be_bytes());
+        self.shp.extend_from_slice(&len.to_be_bytes());
+        self.shp.extend_from_slice(&content);
+        Ok(())
+    }
+
+    // The content of a record: its shape type, followed by the shape
+    fn content<T: CoordinateType>(
+        &mut self,
+        geometry: &Geometry<T>,
+    ) -> Result<Vec<u8>, ShapefileError> {
+        let mut buf = self.shape_type.code().to_le_bytes().to_vec();
+        match (self.shape_type, geometry) {
+            (ShapeType::Point, Geometry::Point(p)) => {
+                let coords = to_f64(&[p.0]);
+                self.bounds.expand(coords[0], coords[0]);
+                write_coords(&coords, &mut buf);
+            }
+            (ShapeType::MultiPoint, Geometry::MultiPoint(mp)) => {
+                let coords = to_f64(&mp.0.iter().map(|p| p.0).collect::<Vec<_>>());
+                let bounds = Bounds::of(&coords);
+                self.bounds.expand(bounds.min, bounds.max);
+                bounds.write(&mut buf);
+                buf.extend_from_slice(&(coords.len() as i32).to_le_bytes());
+                write_coords(&coords, &mut buf);
+            }
+            (ShapeType::PolyLine, Geometry::Line(l)) => {
+                self.parts(vec![to_f64(&[l.start, l.end])], &mut buf)
+            }
+            (ShapeType::PolyLine, Geometry::LineString(ls)) => {
+                self.parts(vec![to_f64(&ls.0)], &mut buf)
+            }
+            (ShapeType::PolyLine, Geometry::MultiLineString(mls)) => {
+                self.parts(mls.0.iter().map(|ls| to_f64(&ls.0)).collect(), &mut buf)
+            }
+            (ShapeType::Polygon, Geometry::Polygon(p)) => self.parts(polygon_rings(p), &mut buf),
+            (ShapeType::Polygon, Geometry::MultiPolygon(mp)) => {
+                self.parts(mp.0.iter().flat_map(polygon_rings).collect(), &mut buf)
+            }
+            (ShapeType::Polygon, Geometry::Rect(r)) => {
+                self.parts(polygon_rings(&Polygon::from(*r)), &mut buf)
+            }
+            (ShapeType::Polygon, Geometry::Triangle(t)) => {
+                self.parts(polygon_rings(&Polygon::from(*t)), &mut buf)
+            }
+            (shape_type, geometry) => {
+                return Err(ShapefileError::MismatchedGeometry {
+                    shape_type,
+                    found: geometry.type_name(),
+                });
+            }
+        }
+        Ok(buf)
+    }
+
+    // The bounding box, parts, and points of a PolyLine or Polygon
+    fn parts(&mut self, parts: Vec<Vec<Coordinate<f64>>>, buf: &mut Vec<u8>) {
+        let coords = parts.concat();
+        let bounds = Bounds::of(&coords);
+        self.bounds.expand(bounds.min, bounds.max);
+        bounds.write(buf);
+        buf.extend_from_slice(&(parts.len() as i32).to_le_bytes());
+        buf.extend_from_slice(&(coords.len() as i32).to_le_bytes());
+        let mut start = 0;
+        for part in &parts {
+            buf.extend_from_slice(&(start as i32).to_le_bytes());
+            start += part.len();
+        }
+        write_coords(&coords, buf);
+    }
+
+    /// Finish writing, returning the `.shp` file and its `.shx` index.
+    pub fn finish(mut self) -> (Vec<u8>, Vec<u8>) {
+        let shape_type = self.shape_type;
+        let bounds = self.bounds;
+        for file in &mut [&mut self.shp, &mut self.shx] {
+            let mut header = Vec::with_capacity(HEADER_SIZE);
+            header.extend_from_slice(&FILE_CODE.to_be_bytes());
+            header.extend_from_slice(&[0; 20]);
+            // `add_shape` checked that the length fits
+            header.extend_from_slice(&((file.len() / 2) as i32).to_be_bytes());
+            header.extend_from_slice(&VERSION.to_le_bytes());
+            header.extend_from_slice(&shape_type.code().to_le_bytes());
+            bounds.write(&mut header);
+            // The ranges of the Z and M values
+            header.extend_from_slice(&[0; 32]);
+            file[..HEADER_SIZE].copy_from_slice(&header);
+        }
+        (self.shp, self.shx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Triangle};
+
+    // A `.shp` file with `records` of `shape_type`, written by hand
+    fn shp(shape_type: ShapeType, records: &[Vec<u8>]) -> Vec<u8> {
+        let mut shp = FILE_CODE.to_be_bytes().to_vec();
+        shp.resize(28, 0);
+        shp.extend_from_slice(&VERSION.to_le_bytes());
+        shp.extend_from_slice(&shape_type.code().to_le_bytes());
+        shp.resize(HEADER_SIZE, 0);
+        for (i, content) in records.iter().enumerate() {
+            shp.extend_from_slice(&(i as i32 + 1).to_be_bytes());
+            shp.extend_from_slice(&(content.len() as i32 / 2).to_be_bytes());
+            shp.extend_from_slice(content);
+        }
+        let len = (shp.len() as i32 / 2).to_be_bytes();
+        shp[24..28].copy_from_slice(&len);
+        shp
+    }
+
+    // The content of a Polygon record with `rings`, in that order and winding
+    fn polygon_record(rings: &[&[(f64, f64)]]) -> Vec<u8> {
+        let mut content = ShapeType::Polygon.code().to_le_bytes().to_vec();
+        content.resize(36, 0);
+        let points = rings.iter().map(|ring| ring.len()).sum::<usize>();
+        content.extend_from_slice(&(rings.len() as i32).to_le_bytes());
+        content.extend_from_slice(&(points as i32).to_le_bytes());
+        let mut start = 0;
+        for ring in rings {
+            content.extend_from_slice(&(start as i32).to_le_bytes());
+            start += ring.len();
+        }
+        for &(x, y) in rings.iter().flat_map(|ring| ring.iter()) {
+            content.extend_from_slice(&x.to_le_bytes());
+            content.extend_from_slice(&y.to_le_bytes());
+        }
+        content
+    }
+
+    fn round_trip(
+        shape_type: ShapeType,
+        shapes: &[Option<Geometry<f64>>],
+    ) -> Vec<Option<Geometry<f64>>> {
+        let mut writer = Writer::new(shape_type).unwrap();
+        for shape in shapes {
+            writer.add_shape(shape.as_ref()).unwrap();
+        }
+        let (shp, shx) = writer.finish();
+        let reader = Reader::with_index(&shp, &shx).unwrap();
+        assert_eq!(reader.header().shape_type, shape_type);
+        assert_eq!(reader.shape_count(), Some(shapes.len()));
+        let read = reader.shapes().collect::<Result<Vec<_>, _>>().unwrap();
+        for (i, shape) in read.iter().enumerate() {
+            assert_eq!(&reader.shape::<f64>(i).unwrap(), shape);
+        }
+        read
+    }
+
+    #[test]
+    fn every_shape_type() {
+        let points = vec![
+            Some(Geometry::Point(point!(x: 1., y: 2.))),
+            None,
+            Some(Geometry::Point(point!(x: -3., y: 4.))),
+        ];
+        assert_eq!(round_trip(ShapeType::Point, &points), points);
+
+        let multi_points = vec![Some(Geometry::MultiPoint(MultiPoint(vec![
+            point!(x: 1., y: 2.),
+            point!(x: 3., y: 4.),
+        ])))];
+        assert_eq!(
+            round_trip(ShapeType::MultiPoint, &multi_points),
+            multi_points
+        );
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        let lines = vec![
+            Some(Geometry::LineString(line_string.clone())),
+            Some(Geometry::MultiLineString(MultiLineString(vec![
+                line_string.clone(),
+                line_string![(x: 5., y: 5.), (x: 6., y: 6.)],
+            ]))),
+        ];
+        assert_eq!(round_trip(ShapeType::PolyLine, &lines), lines);
+
+        // clockwise exterior and counterclockwise interior, as they're written
+        let square = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        );
+        let island = polygon![(x: 20., y: 20.), (x: 20., y: 21.), (x: 21., y: 21.)];
+        let polygons = vec![
+            Some(Geometry::Polygon(square.clone())),
+            Some(Geometry::MultiPolygon(MultiPolygon(vec![square, island]))),
+            None,
+        ];
+        assert_eq!(round_trip(ShapeType::Polygon, &polygons), polygons);
+
+        assert_eq!(round_trip(ShapeType::Null, &[None, None]), vec![None, None]);
+    }
+
+    #[test]
+    fn geometries_without_a_shape_type() {
+        let line = Geometry::Line(Line::new((0., 0.), (1., 1.)));
+        assert_eq!(
+            round_trip(ShapeType::PolyLine, &[Some(line)]),
+            vec![Some(Geometry::LineString(
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+            ))]
+        );
+        let rect = Geometry::Rect(Rect::new((0., 0.), (1., 1.)));
+        let triangle =
+            Geometry::Triangle(Triangle((0., 0.).into(), (1., 0.).into(), (0., 1.).into()));
+        let read = round_trip(ShapeType::Polygon, &[Some(rect), Some(triangle)]);
+        assert_eq!(
+            read[0],
+            Some(Geometry::Polygon(polygon![
+                (x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)
+            ]))
+        );
+        assert_eq!(
+            read[1],
+            Some(Geometry::Polygon(
+                polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 0.)]
+            ))
+        );
+    }
+
+    #[test]
+    fn rings_are_reoriented_when_written() {
+        let counterclockwise = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        );
+        let read = round_trip(
+            ShapeType::Polygon,
+            &[Some(Geometry::Polygon(counterclockwise))],
+        );
+        assert_eq!(
+            read[0],
+            Some(Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+            )))
+        );
+    }
+
+    #[test]
+    fn rings_are_assigned_by_winding() {
+        let outer: &[(f64, f64)] = &[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)];
+        let hole: &[(f64, f64)] = &[(2., 2.), (8., 2.), (8., 8.), (2., 8.), (2., 2.)];
+        // an island in the hole, with a hole of its own which touches it
+        let island: &[(f64, f64)] = &[(3., 3.), (3., 7.), (7., 7.), (7., 3.), (3., 3.)];
+        let island_hole: &[(f64, f64)] = &[(3., 3.), (5., 4.), (5., 5.), (4., 5.), (3., 3.)];
+        // a hole outside every outer ring
+        let orphan: &[(f64, f64)] = &[(20., 20.), (21., 20.), (21., 21.), (20., 20.)];
+
+        let shp = shp(
+            ShapeType::Polygon,
+            &[polygon_record(&[island_hole, hole, orphan, outer, island])],
+        );
+        let reader = Reader::new(&shp).unwrap();
+        let shapes = reader
+            .shapes::<f64>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let ring = |ring: &[(f64, f64)]| LineString::from(ring.to_vec());
+        assert_eq!(
+            shapes,
+            vec![Some(Geometry::MultiPolygon(MultiPolygon(vec![
+                Polygon::new(ring(outer), vec![ring(hole)]),
+                Polygon::new(ring(island), vec![ring(island_hole)]),
+                Polygon::new(ring(orphan), vec![]),
+            ])))]
+        );
+    }
+
+    #[test]
+    fn z_and_m_values_are_ignored() {
+        let mut point_z = ShapeType::PointZ.code().to_le_bytes().to_vec();
+        for value in &[1., 2., 3., 4.] {
+            point_z.extend_from_slice(&f64::to_le_bytes(*value));
+        }
+        let shp = shp(ShapeType::PointZ, &[point_z]);
+        let reader = Reader::new(&shp).unwrap();
+        assert_eq!(
+            reader.shapes::<f64>().next(),
+            Some(Ok(Some(Geometry::Point(point!(x: 1., y: 2.)))))
+        );
+
+        let mut polygon_m = polygon_record(&[&[(0., 0.), (0., 1.), (1., 1.), (0., 0.)]]);
+        polygon_m[..4].copy_from_slice(&ShapeType::PolygonM.code().to_le_bytes());
+        // the range of M values, and the M value of each point
+        polygon_m.resize(polygon_m.len() + 16 + 4 * 8, 0);
+        let shp = self::shp(ShapeType::PolygonM, &[polygon_m]);
+        let reader = Reader::new(&shp).unwrap();
+        assert_eq!(
+            reader.shapes::<f64>().next(),
+            Some(Ok(Some(Geometry::Polygon(polygon![
+                (x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)
+            ]))))
+        );
+    }
+
+    #[test]
+    fn header() {
+        let mut writer = Writer::new(ShapeType::Point).unwrap();
+        writer
+            .add_shape(Some(&Geometry::Point(point!(x: 1, y: -2))))
+            .unwrap();
+        writer
+            .add_shape(Some(&Geometry::Point(point!(x: 3, y: 4))))
+            .unwrap();
+        let (shp, shx) = writer.finish();
+        // 100 byte headers, and 28 bytes per point and 8 per index entry
+        assert_eq!((shp.len(), shx.len()), (156, 116));
+        assert_eq!(&shp[24..28], &78i32.to_be_bytes());
+        assert_eq!(&shx[24..28], &58i32.to_be_bytes());
+        assert_eq!(&shx[100..108], &[0, 0, 0, 50, 0, 0, 0, 10]);
+        let reader = Reader::with_index(&shp, &shx).unwrap();
+        assert_eq!(
+            reader.header(),
+            &Header {
+                shape_type: ShapeType::Point,
+                bbox: Some(Rect::new((1., -2.), (3., 4.))),
+            }
+        );
+        assert_eq!(
+            reader.shape::<i32>(1),
+            Ok(Some(Geometry::Point(point!(x: 3, y: 4))))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Writer::new(ShapeType::PointZ).err(),
+            Some(ShapefileError::UnsupportedShapeType(11))
+        );
+        let mut writer = Writer::new(ShapeType::PolyLine).unwrap();
+        assert_eq!(
+            writer.add_shape(Some(&Geometry::Point(point!(x: 0., y: 0.)))),
+            Err(ShapefileError::MismatchedGeometry {
+                shape_type: ShapeType::PolyLine,
+                found: "Point"
+            })
+        );
+        writer
+            .add_shape(Some(&Geometry::LineString(
+                line_string![(x: 1000., y: 0.), (x: 1., y: 1.)],
+            )))
+            .unwrap();
+        let (shp, shx) = writer.finish();
+
+        let reader = Reader::new(&shp).unwrap();
+        assert_eq!(reader.shape::<f64>(0), Err(ShapefileError::NoIndex));
+        assert!(matches!(
+            reader.shapes::<i8>().next(),
+            Some(Err(ShapefileError::InvalidCoordinate(_)))
+        ));
+        let reader = Reader::with_index(&shp, &shx).unwrap();
+        assert_eq!(reader.shape::<f64>(1), Err(ShapefileError::NoSuchShape(1)));
+        assert_eq!(
+            Reader::new(&shx[..50]).err(),
+            Some(ShapefileError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Reader::new(&[0; 100]).err(),
+            Some(ShapefileError::NotAShapefile)
+        );
+
+        let points = self::shp(ShapeType::Point, &[]);
+        assert!(matches!(
+            Reader::with_index(&points, &shx),
+            Err(ShapefileError::MismatchedShapeType { .. })
+        ));
+        let mismatched = self::shp(ShapeType::Point, &[shp[108..].to_vec()]);
+        assert_eq!(
+            Reader::new(&mismatched).unwrap().shapes::<f64>().next(),
+            Some(Err(ShapefileError::MismatchedShapeType {
+                expected: ShapeType::Point,
+                found: ShapeType::PolyLine
+            }))
+        );
+    }
+
+    #[test]
+    fn invalid_files() {
+        let mut writer = Writer::new(ShapeType::Polygon).unwrap();
+        writer
+            .add_shape(Some(&Geometry::Polygon(polygon![
+                (x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)
+            ])))
+            .unwrap();
+        let (shp, shx) = writer.finish();
+        // truncated or corrupted files fail, but don't panic
+        for len in 0..shp.len() {
+            let shapes = Reader::new(&shp[..len])
+                .and_then(|reader| reader.shapes::<f64>().collect::<Result<Vec<_>, _>>());
+            assert!(shapes.map_or(true, |shapes| shapes.is_empty()));
+        }
+        for i in 0..shp.len() {
+            let mut corrupt = shp.clone();
+            corrupt[i] ^= 0xFF;
+            if let Ok(reader) = Reader::with_index(&corrupt, &shx) {
+                let _ = reader.shapes::<f64>().collect::<Vec<_>>();
+                let _ = reader.shape::<f64>(0);
+            }
+        }
+        for len in 0..shx.len() {
+            if let Ok(reader) = Reader::with_index(&shp, &shx[..len]) {
+                let _ = reader.shape::<f64>(0);
+            }
+        }
+        for i in 0..shx.len() {
+            let mut corrupt = shx.clone();
+            corrupt[i] ^= 0xFF;
+            if let Ok(reader) = Reader::with_index(&shp, &corrupt) {
+                let _ = reader.shape::<f64>(0);
+            }
+        }
+        // a header cut short after the fields which are read
+        assert_eq!(
+            Reader::with_index(&shp, &shx[..80]).err(),
+            Some(ShapefileError::InvalidShape("truncated index"))
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::stream::{GeomEventSink, GeomEventSource, StreamError};
+use crate::{CoordinateType, Geometry};
+use alloc::sync::Arc;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// A [`Geometry`](enum.Geometry.html) which can be shared by many owners without being cloned,
+/// and is only copied when one of them changes it.
+///
+/// Cloning a `SharedGeometry` only counts another reference to the same geometry, so a large
+/// boundary can be held by thousands of features while being stored once. It dereferences to
+/// the `Geometry`, so the algorithms in `geo` can be used on it directly.
+/// [`make_mut`](#method.make_mut) gives a mutable reference, cloning the geometry first if it's
+/// shared, so the other owners never see the change.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{polygon, Geometry, SharedGeometry};
+///
+/// let boundary = SharedGeometry::new(Geometry::Polygon(polygon![
+///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)
+/// ]));
+/// let features: Vec<SharedGeometry<f64>> = (0..1000).map(|_| boundary.clone()).collect();
+/// assert!(SharedGeometry::ptr_eq(&features[0], &features[999]));
+/// assert_eq!(boundary.share_count(), 1001);
+///
+/// // changing one copy leaves the others alone
+/// let mut moved = features[0].clone();
+/// if let Geometry::Polygon(polygon) = moved.make_mut() {
+///     polygon.exterior_mut(|exterior| exterior.0[0].x = -1.);
+/// }
+/// assert!(!SharedGeometry::ptr_eq(&moved, &boundary));
+/// assert_ne!(moved, boundary);
+/// assert_eq!(features[0], boundary);
+/// ```
+pub struct SharedGeometry<T: CoordinateType>(Arc<Geometry<T>>);
+
+impl<T: CoordinateType> SharedGeometry<T> {
+    /// Create the first owner of `geometry`
+    pub fn new(geometry: Geometry<T>) -> Self {
+        SharedGeometry(Arc::new(geometry))
+    }
+
+    /// Return a mutable reference to the geometry, cloning it first if it has other owners, so
+    /// that they keep the geometry as it was
+    pub fn make_mut(&mut self) -> &mut Geometry<T> {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Return the geometry, without cloning it if this is its only owner
+    pub fn into_inner(self) -> Geometry<T> {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    /// Whether `a` and `b` share the same geometry, rather than holding equal copies
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        Arc::ptr_eq(&a.0, &b.0)
+    }
+
+    /// The number of `SharedGeometry`s sharing this one's geometry, including this one
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+}
+
+impl<T: CoordinateType> Clone for SharedGeometry<T> {
+    fn clone(&self) -> Self {
+        SharedGeometry(Arc::clone(&self.0))
+    }
+}
+
+impl<T: CoordinateType> Deref for SharedGeometry<T> {
+    type Target = Geometry<T>;
+
+    fn deref(&self) -> &Geometry<T> {
+        &self.0
+    }
+}
+
+impl<T: CoordinateType> AsRef<Geometry<T>> for SharedGeometry<T> {
+    fn as_ref(&self) -> &Geometry<T> {
+        &self.0
+    }
+}
+
+impl<T: CoordinateType, G: Into<Geometry<T>>> From<G> for SharedGeometry<T> {
+    fn from(geometry: G) -> Self {
+        SharedGeometry::new(geometry.into())
+    }
+}
+
+impl<T: CoordinateType> From<Arc<Geometry<T>>> for SharedGeometry<T> {
+    fn from(shared: Arc<Geometry<T>>) -> Self {
+        SharedGeometry(shared)
+    }
+}
+
+/// Geometries are equal if they're shared, or if their contents are equal
+impl<T: CoordinateType> PartialEq for SharedGeometry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        SharedGeometry::ptr_eq(self, other) || self.0 == other.0
+    }
+}
+
+impl<T: CoordinateType + fmt::Debug> fmt::Debug for SharedGeometry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: CoordinateType + Hash> Hash for SharedGeometry<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: CoordinateType> GeomEventSource<T> for SharedGeometry<T> {
+    type Error = <Geometry<T> as GeomEventSource<T>>::Error;
+
+    fn process<S>(&self, sink: &mut S) -> Result<(), StreamError<Self::Error, S::Error>>
+    where
+        S: GeomEventSink<T> + ?Sized,
+    {
+        self.0.process(sink)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stream::GeometryBuilder;
+    use crate::{line_string, point, Point};
+
+    #[test]
+    fn copy_on_write() {
+        let mut shared = SharedGeometry::from(point!(x: 1, y: 2));
+        // the only owner changes the geometry in place
+        let before: *const Geometry<i32> = &*shared;
+        *shared.make_mut() = Geometry::Point(point!(x: 3, y: 4));
+        assert_eq!(&*shared as *const _, before);
+
+        let other = shared.clone();
+        assert_eq!(other.share_count(), 2);
+        *shared.make_mut() = Geometry::Point(point!(x: 5, y: 6));
+        assert_eq!(other.share_count(), 1);
+        assert_eq!(*other, Geometry::Point(point!(x: 3, y: 4)));
+        assert_eq!(shared.into_inner(), Geometry::Point(point!(x: 5, y: 6)));
+
+        let copy = other.clone();
+        assert_eq!(copy.into_inner(), other.into_inner());
+    }
+
+    #[test]
+    fn equality_and_deref() {
+        let a = SharedGeometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        let b = SharedGeometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        assert!(!SharedGeometry::ptr_eq(&a, &b));
+        assert_eq!(a, b);
+        assert!(a.as_line_string().is_some());
+        assert_eq!(format!("{:?}", a), format!("{:?}", *b));
+
+        // an empty point isn't equal to itself, unless it's shared
+        let empty = SharedGeometry::from(Point::<f64>::empty());
+        assert_eq!(empty, empty.clone());
+        assert_ne!(empty, SharedGeometry::from(Point::<f64>::empty()));
+
+        let built = GeometryBuilder::build(&a).unwrap();
+        assert_eq!(built, vec![(*a).clone()]);
+    }
+}
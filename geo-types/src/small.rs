@@ -0,0 +1,320 @@
+//! Line strings and polygons which store their first few coordinates inline, rather than on the
+//! heap, backed by the [`smallvec`](https://docs.rs/smallvec) crate.
+//!
+//! A [`Polygon`](../struct.Polygon.html) made from a [`Rect`](../struct.Rect.html) allocates a
+//! `Vec` for its five coordinates, which adds up when there are millions of them.
+//! [`SmallLineString`](struct.SmallLineString.html) and
+//! [`SmallPolygon`](struct.SmallPolygon.html) keep up to `N` coordinates in each ring inline,
+//! where `N` is a type parameter defaulting to five, and only allocate when a ring grows past
+//! that. A `SmallPolygon` without interior rings doesn't allocate at all until then.
+//!
+//! `LineString` and `Polygon` don't take the storage as a type parameter themselves. A default
+//! for it would only apply where a type is written out: in an expression such as
+//! `LineString::from(coords)` or `Polygon::new(exterior, vec![])`, the storage would have to be
+//! inferred, so existing code converting into them would need annotations, and every algorithm
+//! in `geo` would have to be generic over it. Convert these types into them, or into a
+//! [`Geometry`](../enum.Geometry.html), with `From` to use the algorithms in `geo`; a buffer
+//! which has already spilled onto the heap is moved rather than copied, both ways.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::small::SmallPolygon;
+//! use geo_types::{Polygon, Rect};
+//!
+//! let small: SmallPolygon<f64> = Rect::new((0., 0.), (1., 2.)).into();
+//! assert!(!small.spilled());
+//! assert_eq!(small.exterior().num_coords(), 5);
+//!
+//! let polygon = Polygon::from(small);
+//! assert_eq!(polygon, Polygon::from(Rect::new((0., 0.), (1., 2.))));
+//! ```
+use crate::{Coordinate, CoordinateType, Geometry, Line, LineString, Polygon, Rect};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use smallvec::SmallVec;
+
+/// A [`LineString`](../struct.LineString.html) storing up to `N` coordinates inline.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::small::SmallLineString;
+/// use geo_types::{line_string, LineString};
+///
+/// let small: SmallLineString<f64, 3> = vec![(0., 0.), (1., 0.), (1., 1.)].into();
+/// assert!(!small.spilled());
+///
+/// let longer: SmallLineString<f64, 3> = line_string![
+///     (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)
+/// ].into();
+/// assert!(longer.spilled());
+/// assert_eq!(LineString::from(longer).num_coords(), 4);
+/// ```
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub struct SmallLineString<T: CoordinateType, const N: usize = 5>(pub SmallVec<[Coordinate<T>; N]>);
+
+impl<T: CoordinateType, const N: usize> SmallLineString<T, N> {
+    /// Whether the coordinates have outgrown the inline storage, and moved onto the heap
+    pub fn spilled(&self) -> bool {
+        self.0.spilled()
+    }
+
+    /// Return the number of coordinates
+    pub fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Return an iterator yielding one `Line` for each line segment
+    pub fn lines<'a>(&'a self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.0.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// Return the bounding rectangle, or `None` if there are no coordinates
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        crate::private_utils::get_bounding_rect(self.0.iter().cloned())
+    }
+
+    // Append the first coordinate if it differs from the last, as `LineString::close` does
+    fn close(&mut self) {
+        if let (Some(first), Some(last)) = (self.0.first().copied(), self.0.last().copied()) {
+            if first != last {
+                self.0.push(first);
+            }
+        }
+    }
+}
+
+impl<T: CoordinateType, IC: Into<Coordinate<T>>, const N: usize> FromIterator<IC>
+    for SmallLineString<T, N>
+{
+    fn from_iter<I: IntoIterator<Item = IC>>(iter: I) -> Self {
+        SmallLineString(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: CoordinateType, IC: Into<Coordinate<T>>, const N: usize> From<Vec<IC>>
+    for SmallLineString<T, N>
+{
+    fn from(v: Vec<IC>) -> Self {
+        v.into_iter().collect()
+    }
+}
+
+/// Move the coordinates inline if there are few enough of them, or keep the `LineString`'s `Vec`
+/// otherwise
+impl<T: CoordinateType, const N: usize> From<LineString<T>> for SmallLineString<T, N> {
+    fn from(line_string: LineString<T>) -> Self {
+        SmallLineString(SmallVec::from_vec(line_string.0))
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<SmallLineString<T, N>> for LineString<T> {
+    fn from(small: SmallLineString<T, N>) -> Self {
+        LineString(small.0.into_vec())
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<SmallLineString<T, N>> for Geometry<T> {
+    fn from(small: SmallLineString<T, N>) -> Self {
+        Geometry::LineString(small.into())
+    }
+}
+
+/// A [`Polygon`](../struct.Polygon.html) whose rings each store up to `N` coordinates inline.
+///
+/// Like `Polygon`, its rings are always closed.
+#[derive(PartialEq, Clone, Debug, Hash)]
+pub struct SmallPolygon<T: CoordinateType, const N: usize = 5> {
+    exterior: SmallLineString<T, N>,
+    interiors: Vec<SmallLineString<T, N>>,
+}
+
+impl<T: CoordinateType, const N: usize> SmallPolygon<T, N> {
+    /// Create a new `SmallPolygon` from its exterior and interior rings, closing each of them as
+    /// [`Polygon::new`](../struct.Polygon.html#method.new) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::small::{SmallLineString, SmallPolygon};
+    ///
+    /// let polygon: SmallPolygon<f64, 4> =
+    ///     SmallPolygon::new(vec![(0., 0.), (1., 1.), (1., 0.)].into(), vec![]);
+    /// assert_eq!(polygon.exterior().num_coords(), 4);
+    /// assert!(!polygon.spilled());
+    /// ```
+    pub fn new(
+        mut exterior: SmallLineString<T, N>,
+        mut interiors: Vec<SmallLineString<T, N>>,
+    ) -> Self {
+        exterior.close();
+        for interior in &mut interiors {
+            interior.close();
+        }
+        SmallPolygon {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// Return the exterior ring and the interior rings
+    pub fn into_inner(self) -> (SmallLineString<T, N>, Vec<SmallLineString<T, N>>) {
+        (self.exterior, self.interiors)
+    }
+
+    /// Return a reference to the exterior ring
+    pub fn exterior(&self) -> &SmallLineString<T, N> {
+        &self.exterior
+    }
+
+    /// Return a slice of the interior rings
+    pub fn interiors(&self) -> &[SmallLineString<T, N>] {
+        &self.interiors
+    }
+
+    /// Whether anything is stored on the heap: a ring which has outgrown the inline storage, or
+    /// any interior rings at all
+    pub fn spilled(&self) -> bool {
+        self.exterior.spilled() || !self.interiors.is_empty()
+    }
+
+    /// Return the bounding rectangle, or `None` if it's empty.
+    ///
+    /// Only the exterior ring is considered, since the interior rings lie within it.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.exterior.bounding_rect()
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<Polygon<T>> for SmallPolygon<T, N> {
+    fn from(polygon: Polygon<T>) -> Self {
+        let (exterior, interiors) = polygon.into_inner();
+        SmallPolygon {
+            exterior: exterior.into(),
+            interiors: interiors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<SmallPolygon<T, N>> for Polygon<T> {
+    fn from(small: SmallPolygon<T, N>) -> Self {
+        Polygon::new(
+            small.exterior.into(),
+            small.interiors.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<SmallPolygon<T, N>> for Geometry<T> {
+    fn from(small: SmallPolygon<T, N>) -> Self {
+        Geometry::Polygon(small.into())
+    }
+}
+
+impl<T: CoordinateType, const N: usize> From<Rect<T>> for SmallPolygon<T, N> {
+    fn from(r: Rect<T>) -> Self {
+        SmallPolygon::new(
+            SmallLineString(
+                [
+                    (r.min().x, r.min().y),
+                    (r.max().x, r.min().y),
+                    (r.max().x, r.max().y),
+                    (r.min().x, r.max().y),
+                    (r.min().x, r.min().y),
+                ]
+                .iter()
+                .map(|&coord| coord.into())
+                .collect(),
+            ),
+            Vec::new(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn rects_stay_inline() {
+        let rect = Rect::new((0, 0), (2, 3));
+        let small: SmallPolygon<i32> = rect.into();
+        assert!(!small.spilled());
+        assert_eq!(small.bounding_rect(), Some(rect));
+        assert_eq!(Polygon::from(small), Polygon::from(rect));
+
+        // fewer inline coordinates than a rect has
+        let spilled: SmallPolygon<i32, 4> = rect.into();
+        assert!(spilled.spilled());
+        assert_eq!(Polygon::from(spilled), Polygon::from(rect));
+    }
+
+    #[test]
+    fn round_trips() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let small = SmallPolygon::<f64>::from(polygon.clone());
+        assert!(!small.exterior().spilled());
+        assert!(small.spilled());
+        assert_eq!(small.interiors().len(), 1);
+        assert_eq!(Polygon::from(small), polygon);
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 2.)];
+        let small: SmallLineString<f64, 2> = line_string.clone().into();
+        assert_eq!(
+            small.lines().collect::<Vec<_>>(),
+            line_string.lines().collect::<Vec<_>>()
+        );
+        assert_eq!(LineString::from(small), line_string);
+    }
+
+    #[test]
+    fn new_closes_rings() {
+        let polygon: SmallPolygon<i32> = SmallPolygon::new(
+            vec![(0, 0), (1, 0), (1, 1)].into(),
+            vec![
+                vec![(0, 0), (0, 0)].into(),
+                SmallLineString(SmallVec::new()),
+            ],
+        );
+        assert_eq!(
+            polygon.exterior(),
+            &SmallLineString::from(vec![(0, 0), (1, 0), (1, 1), (0, 0)])
+        );
+        assert_eq!(polygon.interiors()[0].num_coords(), 2);
+        assert_eq!(polygon.interiors()[1].num_coords(), 0);
+        assert_eq!(polygon.into_inner().1.len(), 2);
+    }
+
+    #[test]
+    fn spilled_buffers_are_moved() {
+        let line_string: LineString<f64> = (0..10).map(|i| (i as f64, 0.)).collect();
+        let buffer = line_string.0.as_ptr();
+        let small: SmallLineString<f64> = line_string.into();
+        assert!(small.spilled());
+        assert_eq!(small.0.as_ptr(), buffer);
+        let line_string = LineString::from(small);
+        assert_eq!(line_string.0.as_ptr(), buffer);
+
+        let polygon = Polygon::new(line_string, vec![]);
+        let expected = polygon.clone();
+        let buffer = polygon.exterior().0.as_ptr();
+        let small = SmallPolygon::<f64>::from(polygon);
+        match Geometry::from(small) {
+            Geometry::Polygon(converted) => {
+                assert_eq!(converted, expected);
+                assert_eq!(converted.exterior().0.as_ptr(), buffer);
+            }
+            _ => panic!("expected a polygon"),
+        }
+        let small: SmallLineString<f64, 2> = vec![(0., 0.), (1., 1.)].into();
+        assert_eq!(
+            Geometry::from(small),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)])
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Reading and writing geometries in PostGIS columns with [sqlx](https://docs.rs/sqlx).
+//!
+//! With the `sqlx-postgres` feature enabled, [`Geometry`](../enum.Geometry.html) and the
+//! geometry types which have a PostGIS equivalent (`Point`, `LineString`, `Polygon`,
+//! `MultiPoint`, `MultiLineString`, `MultiPolygon` and `GeometryCollection`) implement sqlx's
+//! `Type`, `Encode` and `Decode` for Postgres, so they can be bound to queries and read from
+//! rows as `geometry` or `geography` values, which are sent as
+//! [EWKB](../wkb/index.html). A `Geometry` holding a `Line`, `Rect` or `Triangle` is written as
+//! a `LineString` or `Polygon`.
+//!
+//! Geometries are written without an SRID, which PostGIS stores as `0`: wrap them in a
+//! [`WithSrid`](struct.WithSrid.html) to write one, such as for a column declared as
+//! `geometry(Point, 4326)`, or to read the SRID of a value. Reading a concrete type such as
+//! `Point` fails if the value holds a different geometry type.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use geo_types::sqlx_postgres::WithSrid;
+//! use geo_types::{point, Point};
+//! # async fn example(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+//!
+//! sqlx::query("INSERT INTO places (name, location) VALUES ($1, $2)")
+//!     .bind("Zürich")
+//!     .bind(WithSrid::new(point!(x: 8.54, y: 47.37), 4326))
+//!     .execute(&pool)
+//!     .await?;
+//!
+//! let (location,): (Point<f64>,) =
+//!     sqlx::query_as("SELECT location FROM places WHERE name = $1")
+//!         .bind("Zürich")
+//!         .fetch_one(&pool)
+//!         .await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::wkb::ByteOrder;
+use crate::{
+    CoordinateType, Error, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use ::sqlx::{Decode, Encode, Type, TypeInfo};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+/// A geometry, and the SRID of the coordinate reference system of its coordinates, if any,
+/// as read from or written to a PostGIS column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithSrid<G> {
+    pub geometry: G,
+    pub srid: Option<u32>,
+}
+
+impl<G> WithSrid<G> {
+    /// Tag `geometry` with `srid`, such as `4326` for WGS 84 longitudes and latitudes.
+    pub fn new(geometry: G, srid: u32) -> WithSrid<G> {
+        WithSrid {
+            geometry,
+            srid: Some(srid),
+        }
+    }
+}
+
+// A geometry which can be written as, and read back from, (E)WKB
+trait Ewkb<T: CoordinateType>: Sized {
+    fn to_geometry(&self) -> Cow<'_, Geometry<T>>;
+
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, Error>;
+}
+
+impl<T: CoordinateType> Ewkb<T> for Geometry<T> {
+    fn to_geometry(&self) -> Cow<'_, Geometry<T>> {
+        Cow::Borrowed(self)
+    }
+
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, Error> {
+        Ok(geometry)
+    }
+}
+
+impl<T: CoordinateType> Ewkb<T> for GeometryCollection<T> {
+    fn to_geometry(&self) -> Cow<'_, Geometry<T>> {
+        Cow::Owned(Geometry::GeometryCollection(self.clone()))
+    }
+
+    // Unlike `From`, reading a single geometry as a collection fails, like any other mismatch
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, Error> {
+        match geometry {
+            Geometry::GeometryCollection(gc) => Ok(gc),
+            other => Err(Error::MismatchedGeometry {
+                expected: "GeometryCollection",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+macro_rules! impl_ewkb {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType> Ewkb<T> for $type<T> {
+                fn to_geometry(&self) -> Cow<'_, Geometry<T>> {
+                    Cow::Owned(Geometry::$type(self.clone()))
+                }
+
+                fn from_geometry(geometry: Geometry<T>) -> Result<Self, Error> {
+                    $type::try_from(geometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_ewkb!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
+
+fn type_info() -> PgTypeInfo {
+    PgTypeInfo::with_name("geometry")
+}
+
+fn compatible(ty: &PgTypeInfo) -> bool {
+    // PostGIS sends `geography` values as EWKB too
+    ty.name().eq_ignore_ascii_case("geometry") || ty.name().eq_ignore_ascii_case("geography")
+}
+
+fn encode<T: CoordinateType, G: Ewkb<T>>(
+    geometry: &G,
+    srid: Option<u32>,
+    buf: &mut PgArgumentBuffer,
+) -> Result<IsNull, BoxDynError> {
+    let ewkb = geometry
+        .to_geometry()
+        .to_wkb_with(ByteOrder::LittleEndian, srid);
+    buf.extend_from_slice(&ewkb);
+    Ok(IsNull::No)
+}
+
+// Values are EWKB in the binary format, and hex-encoded EWKB in the text format
+fn decode<T: CoordinateType, G: Ewkb<T>>(
+    format: PgValueFormat,
+    bytes: &[u8],
+) -> Result<WithSrid<G>, BoxDynError> {
+    let (geometry, srid) = match format {
+        PgValueFormat::Binary => Geometry::from_ewkb(bytes)?,
+        PgValueFormat::Text => Geometry::from_ewkb(&from_hex(bytes)?)?,
+    };
+    Ok(WithSrid {
+        geometry: G::from_geometry(geometry)?,
+        srid,
+    })
+}
+
+fn from_hex(hex: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+    fn digit(byte: u8) -> Result<u8, BoxDynError> {
+        match byte {
+            b'0'..=b'9' => Ok(byte - b'0'),
+            b'a'..=b'f' => Ok(byte - b'a' + 10),
+            b'A'..=b'F' => Ok(byte - b'A' + 10),
+            _ => Err(format!("Invalid hex digit {:?} in EWKB", byte as char).into()),
+        }
+    }
+    if hex.len() & 1 != 0 {
+        return Err("Hex-encoded EWKB has an odd number of digits".into());
+    }
+    hex.chunks(2)
+        .map(|pair| Ok(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+macro_rules! impl_sqlx {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType> Type<Postgres> for $type<T> {
+                fn type_info() -> PgTypeInfo {
+                    type_info()
+                }
+
+                fn compatible(ty: &PgTypeInfo) -> bool {
+                    compatible(ty)
+                }
+            }
+
+            impl<T: CoordinateType> Encode<'_, Postgres> for $type<T> {
+                fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                    encode(self, None, buf)
+                }
+            }
+
+            impl<'r, T: CoordinateType> Decode<'r, Postgres> for $type<T> {
+                fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+                    decode(value.format(), value.as_bytes()?).map(|decoded| decoded.geometry)
+                }
+            }
+
+            impl<T: CoordinateType> Type<Postgres> for WithSrid<$type<T>> {
+                fn type_info() -> PgTypeInfo {
+                    type_info()
+                }
+
+                fn compatible(ty: &PgTypeInfo) -> bool {
+                    compatible(ty)
+                }
+            }
+
+            impl<T: CoordinateType> Encode<'_, Postgres> for WithSrid<$type<T>> {
+                fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                    encode(&self.geometry, self.srid, buf)
+                }
+            }
+
+            impl<'r, T: CoordinateType> Decode<'r, Postgres> for WithSrid<$type<T>> {
+                fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+                    decode(value.format(), value.as_bytes()?)
+                }
+            }
+        )*
+    };
+}
+
+impl_sqlx!(
+    Geometry,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn encoded<G: for<'q> Encode<'q, Postgres>>(value: &G) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf), Ok(IsNull::No)));
+        buf.to_vec()
+    }
+
+    #[test]
+    fn round_trip() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let bytes = encoded(&polygon);
+        assert_eq!(
+            decode::<f64, Polygon<f64>>(PgValueFormat::Binary, &bytes).unwrap(),
+            WithSrid {
+                geometry: polygon.clone(),
+                srid: None
+            }
+        );
+
+        let tagged = WithSrid::new(Geometry::Polygon(polygon), 4326);
+        let bytes = encoded(&tagged);
+        assert_eq!(
+            decode::<f64, Geometry<f64>>(PgValueFormat::Binary, &bytes).unwrap(),
+            tagged
+        );
+
+        let line_string: LineString<i32> = line_string![(x: 1, y: 2), (x: 3, y: 4)];
+        assert_eq!(
+            decode::<i32, LineString<i32>>(PgValueFormat::Binary, &encoded(&line_string))
+                .unwrap()
+                .geometry,
+            line_string
+        );
+    }
+
+    #[test]
+    fn decode_text() {
+        // SRID=4326;POINT(1 2), as output by PostGIS
+        let hex = b"0101000020E6100000000000000000F03F0000000000000040";
+        assert_eq!(
+            decode::<f64, Point<f64>>(PgValueFormat::Text, hex).unwrap(),
+            WithSrid::new(point!(x: 1., y: 2.), 4326)
+        );
+        assert!(decode::<f64, Point<f64>>(PgValueFormat::Text, b"0101000").is_err());
+        assert!(decode::<f64, Point<f64>>(PgValueFormat::Text, b"0x").is_err());
+    }
+
+    #[test]
+    fn mismatched_types() {
+        let bytes = encoded(&point!(x: 1., y: 2.));
+        assert!(decode::<f64, Polygon<f64>>(PgValueFormat::Binary, &bytes).is_err());
+        assert!(decode::<f64, GeometryCollection<f64>>(PgValueFormat::Binary, &bytes).is_err());
+        assert!(decode::<f64, Geometry<f64>>(PgValueFormat::Binary, &bytes).is_ok());
+    }
+
+    #[test]
+    fn type_compatibility() {
+        assert!(<Point<f64> as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("geography")
+        ));
+        assert!(<WithSrid<Geometry<f64>> as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("geometry")
+        ));
+        assert!(!<Point<f64> as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("bytea")
+        ));
+    }
+}
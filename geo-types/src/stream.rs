@@ -0,0 +1,769 @@
+//! Stream geometries as events, from a [`GeomEventSource`](trait.GeomEventSource.html) to a
+//! [`GeomEventSink`](trait.GeomEventSink.html), without building the geometries in between.
+//!
+//! Every geometry type is a source, and [`GeometryBuilder`](struct.GeometryBuilder.html) is a sink
+//! which builds them back up. With their features enabled, the WKT, WKB and GeoJSON modules each
+//! have a reader, which is a source, and a writer, which is a sink, so a large input in one format
+//! can be converted to another without building any geometries from it.
+//!
+//! A geometry is written as a `begin_` event, the events of its parts, and the matching `end_`
+//! event:
+//!
+//! * a point has one `coord`, or none if it's empty
+//! * a line string has a `coord` for each of its coordinates
+//! * a polygon has a `begin_ring`, the `coord`s of the ring, and an `end_ring` for its exterior and
+//!   then each of its interiors, or no rings at all if it's empty
+//! * a multi-geometry or collection has the events of each of its members
+//!
+//! A `Line` is written as a line string, and a `Rect` or `Triangle` as a polygon. A stream may
+//! hold several geometries, one after another.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::stream::{GeomEventSink, GeomEventSource, GeometryBuilder};
+//! use geo_types::{line_string, Coordinate, Geometry, MultiLineString};
+//!
+//! // count coordinates without collecting them
+//! struct Counter(usize);
+//!
+//! impl GeomEventSink<f64> for Counter {
+//!     type Error = std::convert::Infallible;
+//!
+//!     fn coord(&mut self, _: Coordinate<f64>) -> Result<(), Self::Error> {
+//!         self.0 += 1;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mls = MultiLineString(vec![
+//!     line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+//!     line_string![(x: 2., y: 2.)],
+//! ]);
+//! let mut counter = Counter(0);
+//! mls.process(&mut counter).unwrap();
+//! assert_eq!(counter.0, 3);
+//!
+//! assert_eq!(
+//!     GeometryBuilder::build(&mls).unwrap(),
+//!     vec![Geometry::MultiLineString(mls)]
+//! );
+//! ```
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Receive the events of a stream of geometries; see the [module docs](index.html) for their
+/// order.
+///
+/// Every event is ignored by default, so a sink only implements the ones it needs.
+#[allow(unused_variables)]
+pub trait GeomEventSink<T: CoordinateType> {
+    /// The error returned when an event can't be handled, e.g. because the output failed.
+    type Error;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_point(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_point(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_line_string(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_line_string(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_polygon(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_polygon(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Begin one of the rings of a polygon, the exterior first
+    fn begin_ring(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_ring(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_multi_point(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_multi_point(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_multi_line_string(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_multi_line_string(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_multi_polygon(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_multi_polygon(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn begin_collection(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end_collection(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Something which can be written to a [`GeomEventSink`](trait.GeomEventSink.html) as events,
+/// such as a geometry, or a reader of encoded geometries.
+pub trait GeomEventSource<T: CoordinateType> {
+    /// The error returned when the source can't be read, e.g. because its input is invalid.
+    type Error;
+
+    /// Write the events of this source to `sink`, stopping at the first error of either.
+    fn process<S>(&self, sink: &mut S) -> Result<(), StreamError<Self::Error, S::Error>>
+    where
+        S: GeomEventSink<T> + ?Sized;
+}
+
+/// The error returned when streaming from a source to a sink fails, because of one or the other.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StreamError<R, W> {
+    /// Reading the source failed.
+    Source(R),
+    /// Writing to the sink failed.
+    Sink(W),
+}
+
+impl<R: fmt::Display, W: fmt::Display> fmt::Display for StreamError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Source(e) => write!(f, "{}", e),
+            StreamError::Sink(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Error, W: Error> Error for StreamError<R, W> {}
+
+/// Adapts an `io::Write`, such as a file, to the `fmt::Write` which the WKT and GeoJSON writers
+/// write text to, so they can write straight to it.
+///
+/// Nothing is buffered, so wrap files in a `BufWriter`. A failed write is returned by the writer
+/// as a `fmt::Error`; the `io::Error` itself is returned by `into_inner`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "wkt")] {
+/// use geo_types::point;
+/// use geo_types::stream::{GeomEventSource, IoWriter};
+/// use geo_types::wkt::WktWriter;
+///
+/// let mut writer = WktWriter::new(IoWriter::new(Vec::new()));
+/// point!(x: 1, y: 2).process(&mut writer).unwrap();
+///
+/// assert_eq!(writer.into_inner().into_inner().unwrap(), b"POINT(1 2)");
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct IoWriter<W: io::Write> {
+    out: W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> IoWriter<W> {
+    /// Write to `out`.
+    pub fn new(out: W) -> Self {
+        IoWriter { out, error: None }
+    }
+
+    /// Return the output written to, or the error which stopped writing to it.
+    pub fn into_inner(self) -> io::Result<W> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.out),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+        self.out.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+// Geometries as sources
+
+fn coords<T, S>(coords: &[Coordinate<T>], sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    for coord in coords {
+        sink.coord(*coord)?;
+    }
+    Ok(())
+}
+
+fn point<T, S>(point: &Point<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_point()?;
+    if !point.is_empty() {
+        sink.coord(point.0)?;
+    }
+    sink.end_point()
+}
+
+fn line<T, S>(line: &Line<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_line_string()?;
+    coords(&[line.start, line.end], sink)?;
+    sink.end_line_string()
+}
+
+fn line_string<T, S>(line_string: &LineString<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_line_string()?;
+    coords(&line_string.0, sink)?;
+    sink.end_line_string()
+}
+
+fn polygon<T, S>(polygon: &Polygon<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_polygon()?;
+    if !polygon.exterior().0.is_empty() {
+        for ring in core::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            sink.begin_ring()?;
+            coords(&ring.0, sink)?;
+            sink.end_ring()?;
+        }
+    }
+    sink.end_polygon()
+}
+
+fn multi_point<T, S>(multi_point: &MultiPoint<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_multi_point()?;
+    for p in &multi_point.0 {
+        point(p, sink)?;
+    }
+    sink.end_multi_point()
+}
+
+fn multi_line_string<T, S>(
+    multi_line_string: &MultiLineString<T>,
+    sink: &mut S,
+) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_multi_line_string()?;
+    for ls in &multi_line_string.0 {
+        line_string(ls, sink)?;
+    }
+    sink.end_multi_line_string()
+}
+
+fn multi_polygon<T, S>(multi_polygon: &MultiPolygon<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_multi_polygon()?;
+    for p in &multi_polygon.0 {
+        polygon(p, sink)?;
+    }
+    sink.end_multi_polygon()
+}
+
+fn geometry_collection<T, S>(gc: &GeometryCollection<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    sink.begin_collection()?;
+    for g in &gc.0 {
+        geometry(g, sink)?;
+    }
+    sink.end_collection()
+}
+
+fn rect<T, S>(rect: &Rect<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    polygon(&Polygon::from(*rect), sink)
+}
+
+fn triangle<T, S>(triangle: &Triangle<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    polygon(&Polygon::from(*triangle), sink)
+}
+
+fn geometry<T, S>(geometry: &Geometry<T>, sink: &mut S) -> Result<(), S::Error>
+where
+    T: CoordinateType,
+    S: GeomEventSink<T> + ?Sized,
+{
+    match geometry {
+        Geometry::Point(g) => point(g, sink),
+        Geometry::Line(g) => line(g, sink),
+        Geometry::LineString(g) => line_string(g, sink),
+        Geometry::Polygon(g) => polygon(g, sink),
+        Geometry::MultiPoint(g) => multi_point(g, sink),
+        Geometry::MultiLineString(g) => multi_line_string(g, sink),
+        Geometry::MultiPolygon(g) => multi_polygon(g, sink),
+        Geometry::GeometryCollection(g) => geometry_collection(g, sink),
+        Geometry::Rect(g) => rect(g, sink),
+        Geometry::Triangle(g) => triangle(g, sink),
+    }
+}
+
+macro_rules! impl_source {
+    ($($type:ident => $emit:ident),*) => {
+        $(
+            impl<T: CoordinateType> GeomEventSource<T> for $type<T> {
+                type Error = Infallible;
+
+                fn process<S>(&self, sink: &mut S) -> Result<(), StreamError<Infallible, S::Error>>
+                where
+                    S: GeomEventSink<T> + ?Sized,
+                {
+                    $emit(self, sink).map_err(StreamError::Sink)
+                }
+            }
+        )*
+    };
+}
+
+impl_source!(
+    Point => point,
+    Line => line,
+    LineString => line_string,
+    Polygon => polygon,
+    MultiPoint => multi_point,
+    MultiLineString => multi_line_string,
+    MultiPolygon => multi_polygon,
+    GeometryCollection => geometry_collection,
+    Rect => rect,
+    Triangle => triangle,
+    Geometry => geometry
+);
+
+// Building geometries
+
+/// The error returned by [`GeometryBuilder`](struct.GeometryBuilder.html) when the events it's
+/// given can't be built into geometries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// The event can't come where it did, e.g. a `coord` outside of any geometry, or an
+    /// `end_polygon` ending a line string.
+    UnexpectedEvent(&'static str),
+    /// The events ended before the last geometry was complete.
+    UnfinishedGeometry,
+    /// An empty point can't be built, because the coordinate type has no NaN to represent it.
+    EmptyPoint,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::UnexpectedEvent(event) => write!(f, "Unexpected {} event", event),
+            BuildError::UnfinishedGeometry => write!(f, "Unfinished geometry"),
+            BuildError::EmptyPoint => write!(f, "Empty points can't be represented"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BuildError {}
+
+// A geometry being built, and the parts of it built so far
+enum Frame<T: CoordinateType> {
+    Point(Option<Coordinate<T>>),
+    LineString(Vec<Coordinate<T>>),
+    Ring(Vec<Coordinate<T>>),
+    Polygon(Vec<LineString<T>>),
+    MultiPoint(Vec<Point<T>>),
+    MultiLineString(Vec<LineString<T>>),
+    MultiPolygon(Vec<Polygon<T>>),
+    Collection(Vec<Geometry<T>>),
+}
+
+/// A [`GeomEventSink`](trait.GeomEventSink.html) which builds the geometries it's given.
+///
+/// A builder which has returned an error shouldn't be used again.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::stream::{GeomEventSink, GeometryBuilder};
+/// use geo_types::{Coordinate, Geometry, LineString};
+///
+/// let mut builder = GeometryBuilder::new();
+/// builder.begin_line_string().unwrap();
+/// builder.coord(Coordinate { x: 0, y: 0 }).unwrap();
+/// builder.coord(Coordinate { x: 1, y: 2 }).unwrap();
+/// builder.end_line_string().unwrap();
+///
+/// assert_eq!(
+///     builder.finish(),
+///     Ok(vec![Geometry::LineString(LineString::from(vec![(0, 0), (1, 2)]))])
+/// );
+/// ```
+pub struct GeometryBuilder<T: CoordinateType> {
+    stack: Vec<Frame<T>>,
+    geometries: Vec<Geometry<T>>,
+}
+
+impl<T: CoordinateType> Default for GeometryBuilder<T> {
+    fn default() -> Self {
+        GeometryBuilder::new()
+    }
+}
+
+impl<T: CoordinateType> GeometryBuilder<T> {
+    pub fn new() -> Self {
+        GeometryBuilder {
+            stack: vec![],
+            geometries: vec![],
+        }
+    }
+
+    /// Build every geometry of `source`.
+    pub fn build<R>(source: &R) -> Result<Vec<Geometry<T>>, StreamError<R::Error, BuildError>>
+    where
+        R: GeomEventSource<T> + ?Sized,
+    {
+        let mut builder = GeometryBuilder::new();
+        source.process(&mut builder)?;
+        builder.finish().map_err(StreamError::Sink)
+    }
+
+    /// Return the geometries built, or an error if the last one is unfinished.
+    pub fn finish(self) -> Result<Vec<Geometry<T>>, BuildError> {
+        if self.stack.is_empty() {
+            Ok(self.geometries)
+        } else {
+            Err(BuildError::UnfinishedGeometry)
+        }
+    }
+
+    fn begin(&mut self, frame: Frame<T>, event: &'static str) -> Result<(), BuildError> {
+        let allowed = match (self.stack.last(), &frame) {
+            (None, Frame::Ring(_)) => false,
+            (None, _) => true,
+            (Some(Frame::Collection(_)), Frame::Ring(_)) => false,
+            (Some(Frame::Collection(_)), _) => true,
+            (Some(Frame::Polygon(_)), Frame::Ring(_))
+            | (Some(Frame::MultiPoint(_)), Frame::Point(_))
+            | (Some(Frame::MultiLineString(_)), Frame::LineString(_))
+            | (Some(Frame::MultiPolygon(_)), Frame::Polygon(_)) => true,
+            _ => false,
+        };
+        if !allowed {
+            return Err(BuildError::UnexpectedEvent(event));
+        }
+        self.stack.push(frame);
+        Ok(())
+    }
+
+    /// Add a finished geometry to the one being built, or to those built
+    fn add(&mut self, geometry: Geometry<T>, event: &'static str) -> Result<(), BuildError> {
+        match (self.stack.last_mut(), geometry) {
+            (None, geometry) => self.geometries.push(geometry),
+            (Some(Frame::Collection(geometries)), geometry) => geometries.push(geometry),
+            (Some(Frame::MultiPoint(points)), Geometry::Point(point)) => points.push(point),
+            (Some(Frame::MultiLineString(line_strings)), Geometry::LineString(line_string)) => {
+                line_strings.push(line_string)
+            }
+            (Some(Frame::MultiPolygon(polygons)), Geometry::Polygon(polygon)) => {
+                polygons.push(polygon)
+            }
+            _ => return Err(BuildError::UnexpectedEvent(event)),
+        }
+        Ok(())
+    }
+}
+
+impl<T: CoordinateType> GeomEventSink<T> for GeometryBuilder<T> {
+    type Error = BuildError;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> Result<(), BuildError> {
+        match self.stack.last_mut() {
+            Some(Frame::Point(point @ None)) => *point = Some(coord),
+            Some(Frame::LineString(coords)) | Some(Frame::Ring(coords)) => coords.push(coord),
+            _ => return Err(BuildError::UnexpectedEvent("coord")),
+        }
+        Ok(())
+    }
+
+    fn begin_point(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::Point(None), "begin_point")
+    }
+
+    fn end_point(&mut self) -> Result<(), BuildError> {
+        let point = match self.stack.pop() {
+            Some(Frame::Point(Some(coord))) => Point(coord),
+            Some(Frame::Point(None)) => Point::try_empty().ok_or(BuildError::EmptyPoint)?,
+            _ => return Err(BuildError::UnexpectedEvent("end_point")),
+        };
+        self.add(Geometry::Point(point), "end_point")
+    }
+
+    fn begin_line_string(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::LineString(vec![]), "begin_line_string")
+    }
+
+    fn end_line_string(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::LineString(coords)) => {
+                self.add(Geometry::LineString(LineString(coords)), "end_line_string")
+            }
+            _ => Err(BuildError::UnexpectedEvent("end_line_string")),
+        }
+    }
+
+    fn begin_polygon(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::Polygon(vec![]), "begin_polygon")
+    }
+
+    fn end_polygon(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::Polygon(rings)) => {
+                let mut rings = rings.into_iter();
+                let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+                let polygon = Polygon::new(exterior, rings.collect());
+                self.add(Geometry::Polygon(polygon), "end_polygon")
+            }
+            _ => Err(BuildError::UnexpectedEvent("end_polygon")),
+        }
+    }
+
+    fn begin_ring(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::Ring(vec![]), "begin_ring")
+    }
+
+    fn end_ring(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::Ring(coords)) => match self.stack.last_mut() {
+                Some(Frame::Polygon(rings)) => {
+                    rings.push(LineString(coords));
+                    Ok(())
+                }
+                _ => Err(BuildError::UnexpectedEvent("end_ring")),
+            },
+            _ => Err(BuildError::UnexpectedEvent("end_ring")),
+        }
+    }
+
+    fn begin_multi_point(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::MultiPoint(vec![]), "begin_multi_point")
+    }
+
+    fn end_multi_point(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::MultiPoint(points)) => {
+                self.add(Geometry::MultiPoint(MultiPoint(points)), "end_multi_point")
+            }
+            _ => Err(BuildError::UnexpectedEvent("end_multi_point")),
+        }
+    }
+
+    fn begin_multi_line_string(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::MultiLineString(vec![]), "begin_multi_line_string")
+    }
+
+    fn end_multi_line_string(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::MultiLineString(line_strings)) => self.add(
+                Geometry::MultiLineString(MultiLineString(line_strings)),
+                "end_multi_line_string",
+            ),
+            _ => Err(BuildError::UnexpectedEvent("end_multi_line_string")),
+        }
+    }
+
+    fn begin_multi_polygon(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::MultiPolygon(vec![]), "begin_multi_polygon")
+    }
+
+    fn end_multi_polygon(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::MultiPolygon(polygons)) => self.add(
+                Geometry::MultiPolygon(MultiPolygon(polygons)),
+                "end_multi_polygon",
+            ),
+            _ => Err(BuildError::UnexpectedEvent("end_multi_polygon")),
+        }
+    }
+
+    fn begin_collection(&mut self) -> Result<(), BuildError> {
+        self.begin(Frame::Collection(vec![]), "begin_collection")
+    }
+
+    fn end_collection(&mut self) -> Result<(), BuildError> {
+        match self.stack.pop() {
+            Some(Frame::Collection(geometries)) => self.add(
+                Geometry::GeometryCollection(GeometryCollection(geometries)),
+                "end_collection",
+            ),
+            _ => Err(BuildError::UnexpectedEvent("end_collection")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn builds_what_it_is_given() {
+        let gc: Geometry<f64> = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 1., y: 2.)),
+            Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            )),
+            Geometry::Polygon(Polygon::new(LineString(vec![]), vec![])),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 0., y: 1.)])),
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                LineString(vec![]),
+            ])),
+            Geometry::MultiPolygon(MultiPolygon(vec![])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]));
+        assert_eq!(GeometryBuilder::build(&gc).unwrap(), vec![gc]);
+
+        let built = GeometryBuilder::build(&Point::<f64>::empty()).unwrap();
+        assert!(matches!(built.as_slice(), [Geometry::Point(point)] if point.is_empty()));
+    }
+
+    #[test]
+    fn special_geometries() {
+        let line = Line::new((0, 0), (1, 2));
+        assert_eq!(
+            GeometryBuilder::build(&line).unwrap(),
+            vec![Geometry::LineString(
+                line_string![(x: 0, y: 0), (x: 1, y: 2)]
+            )]
+        );
+        let rect = Rect::new((0, 0), (1, 2));
+        assert_eq!(
+            GeometryBuilder::build(&rect).unwrap(),
+            vec![Geometry::Polygon(Polygon::from(rect))]
+        );
+        let triangle = Triangle::from([(0, 0), (1, 0), (0, 1)]);
+        assert_eq!(
+            GeometryBuilder::build(&triangle).unwrap(),
+            vec![Geometry::Polygon(
+                polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1)]
+            )]
+        );
+    }
+
+    #[test]
+    fn several_geometries() {
+        let mut builder = GeometryBuilder::new();
+        point!(x: 1, y: 2).process(&mut builder).unwrap();
+        line_string![(x: 3, y: 4)].process(&mut builder).unwrap();
+        assert_eq!(
+            builder.finish(),
+            Ok(vec![
+                Geometry::Point(point!(x: 1, y: 2)),
+                Geometry::LineString(line_string![(x: 3, y: 4)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut builder = GeometryBuilder::<i32>::new();
+        assert_eq!(
+            builder.coord(Coordinate { x: 0, y: 0 }),
+            Err(BuildError::UnexpectedEvent("coord"))
+        );
+        assert_eq!(
+            builder.begin_ring(),
+            Err(BuildError::UnexpectedEvent("begin_ring"))
+        );
+
+        let mut builder = GeometryBuilder::<i32>::new();
+        builder.begin_multi_point().unwrap();
+        assert_eq!(
+            builder.begin_line_string(),
+            Err(BuildError::UnexpectedEvent("begin_line_string"))
+        );
+        builder.begin_point().unwrap();
+        assert_eq!(builder.end_point(), Err(BuildError::EmptyPoint));
+
+        let mut builder = GeometryBuilder::<i32>::new();
+        builder.begin_polygon().unwrap();
+        assert_eq!(
+            builder.end_line_string(),
+            Err(BuildError::UnexpectedEvent("end_line_string"))
+        );
+
+        let mut builder = GeometryBuilder::<i32>::new();
+        builder.begin_collection().unwrap();
+        assert_eq!(builder.finish(), Err(BuildError::UnfinishedGeometry));
+    }
+}
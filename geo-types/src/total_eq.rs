@@ -0,0 +1,388 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use core::hash::{Hash, Hasher};
+
+/// Equality and hashing which are total, even for floats.
+///
+/// Floats are the same if they have the same bit pattern, except that `0.0` and `-0.0` are the
+/// same, as are all NaNs, so, unlike with `==`, a NaN is the same as itself. Integer coordinates
+/// are compared and hashed as they are, and geometries are the same if they are the same type,
+/// with the same coordinates, in the same order.
+///
+/// Use [`Total`](struct.Total.html) to make a geometry `Eq` and `Hash` with this.
+pub trait TotalEq {
+    /// Whether this is the same as `other`.
+    fn total_eq(&self, other: &Self) -> bool;
+
+    /// Feed this into `state`, so that values which are `total_eq` hash the same.
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// A geometry, or coordinate, which is `Eq` and `Hash` by its [`TotalEq`](trait.TotalEq.html)
+/// implementation, so that geometries with float coordinates can be the keys of `HashMap`s and
+/// `HashSet`s, and be deduplicated.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, Total};
+/// use std::collections::HashSet;
+///
+/// let line_strings = vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///     line_string![(x: 2., y: f64::NAN)],
+///     line_string![(x: -0., y: 0.), (x: 1., y: 1.)],
+///     line_string![(x: 2., y: f64::NAN)],
+/// ];
+/// let unique: HashSet<_> = line_strings.into_iter().map(Total).collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total<G>(pub G);
+
+impl<G: TotalEq> PartialEq for Total<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_eq(&other.0)
+    }
+}
+
+impl<G: TotalEq> Eq for Total<G> {}
+
+impl<G: TotalEq> Hash for Total<G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+impl<G> From<G> for Total<G> {
+    fn from(geometry: G) -> Self {
+        Total(geometry)
+    }
+}
+
+impl TotalEq for f64 {
+    fn total_eq(&self, other: &Self) -> bool {
+        canonical_bits_64(*self) == canonical_bits_64(*other)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        canonical_bits_64(*self).hash(state)
+    }
+}
+
+impl TotalEq for f32 {
+    fn total_eq(&self, other: &Self) -> bool {
+        canonical_bits_32(*self) == canonical_bits_32(*other)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        canonical_bits_32(*self).hash(state)
+    }
+}
+
+// The bits of a float, with every NaN as the same NaN, and -0 as 0
+fn canonical_bits_64(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else if value == 0. {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+fn canonical_bits_32(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else if value == 0. {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+macro_rules! impl_total_eq_by_eq {
+    ($($t:ty),*) => {
+        $(
+            impl TotalEq for $t {
+                fn total_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+
+                fn total_hash<H: Hasher>(&self, state: &mut H) {
+                    self.hash(state)
+                }
+            }
+        )*
+    };
+}
+
+impl_total_eq_by_eq!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "fixed-point")]
+impl<F: fixed::traits::Fixed> TotalEq for crate::fixed_point::Fixed<F> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state)
+    }
+}
+
+fn slice_eq<G: TotalEq>(a: &[G], b: &[G]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.total_eq(b))
+}
+
+// Like slices' `Hash`, the length is hashed too, so that `[[a], [b]]` and `[[a, b]]` differ
+fn slice_hash<G: TotalEq, H: Hasher>(geometries: &[G], state: &mut H) {
+    geometries.len().hash(state);
+    for geometry in geometries {
+        geometry.total_hash(state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Coordinate<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.x.total_eq(&other.x) && self.y.total_eq(&other.y)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.x.total_hash(state);
+        self.y.total_hash(state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Point<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.0.total_eq(&other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Line<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.start.total_eq(&other.start) && self.end.total_eq(&other.end)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.start.total_hash(state);
+        self.end.total_hash(state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for LineString<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        slice_eq(&self.0, &other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        slice_hash(&self.0, state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Polygon<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.exterior().total_eq(other.exterior()) && slice_eq(self.interiors(), other.interiors())
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.exterior().total_hash(state);
+        slice_hash(self.interiors(), state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for MultiPoint<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        slice_eq(&self.0, &other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        slice_hash(&self.0, state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for MultiLineString<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        slice_eq(&self.0, &other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        slice_hash(&self.0, state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for MultiPolygon<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        slice_eq(&self.0, &other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        slice_hash(&self.0, state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for GeometryCollection<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        slice_eq(&self.0, &other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        slice_hash(&self.0, state)
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Rect<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.min().total_eq(&other.min()) && self.max().total_eq(&other.max())
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.min().total_hash(state);
+        self.max().total_hash(state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Triangle<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.0.total_eq(&other.0) && self.1.total_eq(&other.1) && self.2.total_eq(&other.2)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state);
+        self.1.total_hash(state);
+        self.2.total_hash(state);
+    }
+}
+
+impl<T: CoordinateType + TotalEq> TotalEq for Geometry<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Geometry::Point(a), Geometry::Point(b)) => a.total_eq(b),
+            (Geometry::Line(a), Geometry::Line(b)) => a.total_eq(b),
+            (Geometry::LineString(a), Geometry::LineString(b)) => a.total_eq(b),
+            (Geometry::Polygon(a), Geometry::Polygon(b)) => a.total_eq(b),
+            (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => a.total_eq(b),
+            (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => a.total_eq(b),
+            (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => a.total_eq(b),
+            (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => a.total_eq(b),
+            (Geometry::Rect(a), Geometry::Rect(b)) => a.total_eq(b),
+            (Geometry::Triangle(a), Geometry::Triangle(b)) => a.total_eq(b),
+            _ => false,
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Geometry::Point(g) => g.total_hash(state),
+            Geometry::Line(g) => g.total_hash(state),
+            Geometry::LineString(g) => g.total_hash(state),
+            Geometry::Polygon(g) => g.total_hash(state),
+            Geometry::MultiPoint(g) => g.total_hash(state),
+            Geometry::MultiLineString(g) => g.total_hash(state),
+            Geometry::MultiPolygon(g) => g.total_hash(state),
+            Geometry::GeometryCollection(g) => g.total_hash(state),
+            Geometry::Rect(g) => g.total_hash(state),
+            Geometry::Triangle(g) => g.total_hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{HashMap, HashSet};
+
+    fn hash<G: TotalEq>(geometry: G) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        Total(geometry).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn floats() {
+        assert!(0f64.total_eq(&-0.));
+        assert_eq!(hash(0f64), hash(-0f64));
+        assert!(f64::NAN.total_eq(&-f64::NAN));
+        assert!(f64::NAN.total_eq(&f64::from_bits(f64::NAN.to_bits() + 1)));
+        assert_eq!(hash(f64::NAN), hash(-f64::NAN));
+        assert!(!f64::NAN.total_eq(&f64::INFINITY));
+        assert!(!(0.1f64 + 0.2).total_eq(&0.3));
+        assert!(f32::NAN.total_eq(&-f32::NAN));
+        assert!((-0f32).total_eq(&0.));
+        assert!(!1f32.total_eq(&-1.));
+    }
+
+    #[test]
+    fn geometries() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: f64::NAN)]],
+        );
+        assert_ne!(polygon, polygon.clone());
+        assert_eq!(Total(polygon.clone()), Total(polygon.clone()));
+        assert_eq!(hash(polygon.clone()), hash(polygon.clone()));
+        let filled = Polygon::new(polygon.exterior().clone(), vec![]);
+        assert_ne!(Total(polygon.clone()), Total(filled.clone()));
+
+        let mut areas = HashMap::new();
+        areas.insert(Total(Geometry::Polygon(polygon.clone())), 14.);
+        areas.insert(Total(Geometry::Polygon(filled)), 16.);
+        assert_eq!(areas[&Total(Geometry::Polygon(polygon))], 14.);
+
+        // geometries of different types aren't the same, even with the same coordinates
+        let line = Line::new((0., 0.), (1., 1.));
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_ne!(
+            Total(Geometry::Line(line)),
+            Total(Geometry::LineString(line_string.clone()))
+        );
+        assert_ne!(
+            Total(Geometry::MultiPoint(MultiPoint(vec![point!(x: 0., y: 0.)]))),
+            Total(Geometry::Point(point!(x: 0., y: 0.)))
+        );
+
+        // nor are collections split differently
+        let split = MultiLineString(vec![
+            line_string![(x: 0., y: 0.)],
+            line_string![(x: 1., y: 1.)],
+        ]);
+        let whole = MultiLineString(vec![line_string]);
+        assert_ne!(Total(split.clone()), Total(whole.clone()));
+        assert_ne!(hash(split), hash(whole));
+
+        let unique: HashSet<_> = vec![
+            Total(GeometryCollection(vec![Geometry::Point(
+                point!(x: -0., y: 1.),
+            )])),
+            Total(GeometryCollection(vec![Geometry::Point(
+                point!(x: 0., y: 1.),
+            )])),
+            Total(GeometryCollection(vec![])),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(unique.len(), 2);
+
+        let rects: HashSet<_> = vec![
+            Total(Rect::new((0, 0), (1, 1))),
+            Total(Rect::new((0, 0), (1, 1))),
+            Total(Rect::new((0, 0), (1, 2))),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(
+            Total(Triangle::from([(0., 0.), (1., 0.), (0., 1.)])),
+            Total(Triangle::from([(0., -0.), (1., 0.), (0., 1.)]))
+        );
+    }
+}
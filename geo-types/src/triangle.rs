@@ -1,15 +1,43 @@
-use crate::{Coordinate, CoordinateType, Line};
+use crate::{Coordinate, CoordinateType, Line, Rect};
 
 /// A bounded 2D area whose three vertices are defined by `Coordinate`s.
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(PartialEq, Copy, Clone, Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "scale-codec",
+    derive(codec::Encode, codec::Decode, codec::MaxEncodedLen)
+)]
 pub struct Triangle<T: CoordinateType>(pub Coordinate<T>, pub Coordinate<T>, pub Coordinate<T>);
 
 impl<T: CoordinateType> Triangle<T> {
-    pub fn to_array(&self) -> [Coordinate<T>; 3] {
+    /// Creates a new triangle from its vertices, in a `const` too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Triangle};
+    ///
+    /// const UNIT: Triangle<i32> = Triangle::new(
+    ///     Coordinate::new(0, 0),
+    ///     Coordinate::new(1, 0),
+    ///     Coordinate::new(0, 1),
+    /// );
+    ///
+    /// assert_eq!(UNIT, Triangle::from([(0, 0), (1, 0), (0, 1)]));
+    /// ```
+    pub const fn new(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Triangle<T> {
+        Triangle(a, b, c)
+    }
+
+    pub const fn to_array(&self) -> [Coordinate<T>; 3] {
         [self.0, self.1, self.2]
     }
 
+    /// Return the bounding rectangle of this `Triangle`.
+    pub fn bounding_rect(&self) -> Rect<T> {
+        crate::private_utils::get_bounding_rect(self.to_array().iter().cloned()).unwrap()
+    }
+
     pub fn to_lines(&self) -> [Line<T>; 3] {
         [
             Line::new(self.0, self.1),
@@ -24,3 +52,78 @@ impl<IC: Into<Coordinate<T>> + Copy, T: CoordinateType> From<[IC; 3]> for Triang
         Triangle(array[0].into(), array[1].into(), array[2].into())
     }
 }
+
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::RTreeObject for Triangle<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    type Envelope = ::rstar::AABB<crate::Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let bounding_rect = self.bounding_rect();
+        ::rstar::AABB::from_corners(bounding_rect.min().into(), bounding_rect.max().into())
+    }
+}
+
+/// Points inside the triangle are at a distance of zero.
+#[cfg(feature = "rstar")]
+impl<T> ::rstar::PointDistance for Triangle<T>
+where
+    T: ::num_traits::Float + ::rstar::RTreeNum,
+{
+    fn distance_2(&self, point: &crate::Point<T>) -> T {
+        let d = crate::private_utils::point_triangle_euclidean_distance(*point, *self);
+        d.powi(2)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::AbsDiffEq for Triangle<T>
+where
+    T: CoordinateType + ::approx::AbsDiffEq<Epsilon = T>,
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+            && self.1.abs_diff_eq(&other.1, epsilon)
+            && self.2.abs_diff_eq(&other.2, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::RelativeEq for Triangle<T>
+where
+    T: CoordinateType + ::approx::RelativeEq<Epsilon = T>,
+{
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && self.1.relative_eq(&other.1, epsilon, max_relative)
+            && self.2.relative_eq(&other.2, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> ::approx::UlpsEq for Triangle<T>
+where
+    T: CoordinateType + ::approx::UlpsEq<Epsilon = T>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+            && self.2.ulps_eq(&other.2, epsilon, max_ulps)
+    }
+}
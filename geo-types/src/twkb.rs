@@ -0,0 +1,753 @@
+//! Reading and writing geometries as [Tiny Well-Known Binary
+//! (TWKB)](https://github.com/TWKB/Specification/blob/master/twkb.md).
+//!
+//! With the `twkb` feature enabled, [`Geometry`](../enum.Geometry.html) can be read from, and
+//! written to, 2D TWKB. TWKB rounds coordinates to a number of decimal places (the precision,
+//! which may be negative to round to tens, hundreds, and so on), and stores each one as a
+//! variable length difference from the previous one, so it is usually several times smaller
+//! than WKB.
+//!
+//! Coordinates are converted to and from `f64`s using `NumCast`. As in WKB, a `Geometry::Line`
+//! is written as a `LineString`, and a `Geometry::Rect` or `Geometry::Triangle` as a `Polygon`.
+//! The ID lists of multi-geometries and collections are skipped when reading, and never
+//! written.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::twkb::TwkbOptions;
+//! use geo_types::{line_string, Geometry};
+//!
+//! let geometry = Geometry::LineString(line_string![(x: 1.25, y: 2.5), (x: 1.5, y: 2.75)]);
+//!
+//! let twkb = geometry.to_twkb(2).unwrap();
+//! assert_eq!(twkb.len(), 9);
+//! assert_eq!(Geometry::from_twkb(&twkb), Ok(geometry.clone()));
+//!
+//! let options = TwkbOptions { bbox: true, size: true };
+//! let twkb = geometry.to_twkb_with(2, options).unwrap();
+//! assert_eq!(Geometry::from_twkb(&twkb), Ok(geometry));
+//! ```
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
+};
+use std::error::Error;
+use std::fmt;
+
+const POINT: u8 = 1;
+const LINE_STRING: u8 = 2;
+const POLYGON: u8 = 3;
+const MULTI_POINT: u8 = 4;
+const MULTI_LINE_STRING: u8 = 5;
+const MULTI_POLYGON: u8 = 6;
+const GEOMETRY_COLLECTION: u8 = 7;
+
+const BBOX: u8 = 0x01;
+const SIZE: u8 = 0x02;
+const ID_LIST: u8 = 0x04;
+const EXTENDED_DIMENSIONS: u8 = 0x08;
+const EMPTY: u8 = 0x10;
+
+/// The fewest decimal places coordinates can be rounded to, i.e. to the nearest 10⁸.
+pub const MIN_PRECISION: i8 = -8;
+/// The most decimal places coordinates can be rounded to.
+pub const MAX_PRECISION: i8 = 7;
+
+/// The optional parts of a TWKB geometry to write.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TwkbOptions {
+    /// Write the bounding box of each geometry which has coordinates.
+    pub bbox: bool,
+    /// Write the size of each geometry, so that readers can skip it.
+    pub size: bool,
+}
+
+/// The error returned when reading or writing TWKB fails.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TwkbError {
+    /// The input ended before the geometry was complete.
+    UnexpectedEnd,
+    /// The input continued after the geometry was complete, at the given byte offset.
+    TrailingBytes(usize),
+    /// The geometry type is unknown.
+    UnsupportedGeometryType(u8),
+    /// The geometry has a Z or M dimension.
+    UnsupportedDimensions,
+    /// The geometry is an empty point, which a `Point` can't represent unless its coordinates
+    /// can be NaN.
+    EmptyPoint,
+    /// A number starting at the given byte offset is too large to read.
+    Overflow(usize),
+    /// The precision isn't between [`MIN_PRECISION`](constant.MIN_PRECISION.html) and
+    /// [`MAX_PRECISION`](constant.MAX_PRECISION.html).
+    InvalidPrecision(i8),
+    /// A coordinate value can't be written, or can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+}
+
+impl fmt::Display for TwkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TwkbError::UnexpectedEnd => write!(f, "Unexpected end of TWKB"),
+            TwkbError::TrailingBytes(position) => {
+                write!(f, "Unexpected trailing bytes at position {}", position)
+            }
+            TwkbError::UnsupportedGeometryType(geometry_type) => {
+                write!(f, "Unsupported geometry type {}", geometry_type)
+            }
+            TwkbError::UnsupportedDimensions => write!(f, "Unsupported Z or M dimension"),
+            TwkbError::EmptyPoint => write!(f, "Empty points can't be represented"),
+            TwkbError::Overflow(position) => {
+                write!(f, "Number at position {} is too large", position)
+            }
+            TwkbError::InvalidPrecision(precision) => write!(
+                f,
+                "Precision {} isn't between {} and {}",
+                precision, MIN_PRECISION, MAX_PRECISION
+            ),
+            TwkbError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+        }
+    }
+}
+
+impl Error for TwkbError {}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Read a geometry from TWKB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry};
+    ///
+    /// // LINESTRING(1 1,5 5), as written by PostGIS
+    /// let twkb = [0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08];
+    ///
+    /// assert_eq!(
+    ///     Geometry::from_twkb(&twkb),
+    ///     Ok(Geometry::LineString(line_string![(x: 1., y: 1.), (x: 5., y: 5.)]))
+    /// );
+    /// ```
+    pub fn from_twkb(bytes: &[u8]) -> Result<Geometry<T>, TwkbError> {
+        let mut reader = Reader {
+            bytes,
+            position: 0,
+            precision: 0,
+            last: [0, 0],
+        };
+        let geometry = reader.geometry()?;
+        if reader.position < bytes.len() {
+            return Err(TwkbError::TrailingBytes(reader.position));
+        }
+        Ok(geometry)
+    }
+
+    /// Write this geometry as TWKB, with its coordinates rounded to `precision` decimal places,
+    /// and without a bounding box or size.
+    ///
+    /// # Errors
+    ///
+    /// See [`to_twkb_with`](#method.to_twkb_with).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn to_twkb(&self, precision: i8) -> Result<Vec<u8>, TwkbError> {
+        self.to_twkb_with(precision, TwkbOptions::default())
+    }
+
+    /// Write this geometry as TWKB, with its coordinates rounded to `precision` decimal places,
+    /// and the optional parts chosen by `options`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the precision isn't between [`MIN_PRECISION`](twkb/constant.MIN_PRECISION.html)
+    /// and [`MAX_PRECISION`](twkb/constant.MAX_PRECISION.html), or if a coordinate isn't
+    /// finite, or is too large to write at that precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::twkb::TwkbOptions;
+    /// use geo_types::{point, Geometry};
+    ///
+    /// let geometry = Geometry::Point(point!(x: 1234., y: 5678.));
+    /// let twkb = geometry.to_twkb_with(-2, TwkbOptions::default()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Geometry::from_twkb(&twkb),
+    ///     Ok(Geometry::Point(point!(x: 1200., y: 5700.)))
+    /// );
+    /// ```
+    pub fn to_twkb_with(&self, precision: i8, options: TwkbOptions) -> Result<Vec<u8>, TwkbError> {
+        if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(TwkbError::InvalidPrecision(precision));
+        }
+        let mut bytes = vec![];
+        write(self, precision, options, &mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn uvarint(mut value: u64, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn svarint(value: i64, bytes: &mut Vec<u8>) {
+    uvarint(zigzag(value), bytes)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    precision: i8,
+    /// The previous coordinate of the current geometry, which the next is relative to
+    last: [i64; 2],
+}
+
+impl<'a> Reader<'a> {
+    fn byte(&mut self) -> Result<u8, TwkbError> {
+        let byte = *self
+            .bytes
+            .get(self.position)
+            .ok_or(TwkbError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn uvarint(&mut self) -> Result<u64, TwkbError> {
+        let start = self.position;
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            // only 1 more bit fits once 63 have been read
+            if shift > 63 || (shift == 63 && byte & 0x7e != 0) {
+                return Err(TwkbError::Overflow(start));
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn svarint(&mut self) -> Result<i64, TwkbError> {
+        self.uvarint().map(unzigzag)
+    }
+
+    /// Read the next value of the `i`th ordinate
+    fn ordinate<T: CoordinateType>(&mut self, i: usize) -> Result<T, TwkbError> {
+        let start = self.position;
+        self.last[i] = self.last[i]
+            .checked_add(self.svarint()?)
+            .ok_or(TwkbError::Overflow(start))?;
+        let value = if self.precision >= 0 {
+            self.last[i] as f64 / 10f64.powi(self.precision.into())
+        } else {
+            self.last[i] as f64 * 10f64.powi((-self.precision).into())
+        };
+        T::from(value).ok_or(TwkbError::InvalidCoordinate(value))
+    }
+
+    fn coordinate<T: CoordinateType>(&mut self) -> Result<Coordinate<T>, TwkbError> {
+        Ok(Coordinate {
+            x: self.ordinate(0)?,
+            y: self.ordinate(1)?,
+        })
+    }
+
+    /// Read an unsigned varint count, followed by that many items
+    fn repeated<I, R>(&mut self, read: R) -> Result<Vec<I>, TwkbError>
+    where
+        R: FnMut(&mut Self) -> Result<I, TwkbError>,
+    {
+        let count = self.uvarint()?;
+        self.members(count, read)
+    }
+
+    /// Read the count of a multi-geometry or collection, and skip its IDs, if any
+    fn member_count(&mut self, id_list: bool) -> Result<u64, TwkbError> {
+        let count = self.uvarint()?;
+        if id_list {
+            for _ in 0..count {
+                self.svarint()?;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Read `count` items
+    fn members<I, R>(&mut self, count: u64, mut read: R) -> Result<Vec<I>, TwkbError>
+    where
+        R: FnMut(&mut Self) -> Result<I, TwkbError>,
+    {
+        // Don't trust the count for the allocation: every item is at least 1 byte long
+        let remaining = self.bytes.len() - self.position;
+        let mut items = Vec::with_capacity((count as usize).min(remaining));
+        for _ in 0..count {
+            items.push(read(self)?);
+        }
+        Ok(items)
+    }
+
+    fn line_string<T: CoordinateType>(&mut self) -> Result<LineString<T>, TwkbError> {
+        self.repeated(Self::coordinate).map(LineString)
+    }
+
+    fn polygon<T: CoordinateType>(&mut self) -> Result<Polygon<T>, TwkbError> {
+        let mut rings = self.repeated(Self::line_string)?.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn geometry<T: CoordinateType>(&mut self) -> Result<Geometry<T>, TwkbError> {
+        let type_and_precision = self.byte()?;
+        let geometry_type = type_and_precision & 0x0f;
+        let metadata = self.byte()?;
+        if metadata & EXTENDED_DIMENSIONS != 0 && self.byte()? & 0x03 != 0 {
+            return Err(TwkbError::UnsupportedDimensions);
+        }
+        if metadata & SIZE != 0 {
+            let size = self.uvarint()?;
+            if size > (self.bytes.len() - self.position) as u64 {
+                return Err(TwkbError::UnexpectedEnd);
+            }
+        }
+        if metadata & EMPTY != 0 {
+            return match geometry_type {
+                POINT => Point::try_empty()
+                    .map(Geometry::Point)
+                    .ok_or(TwkbError::EmptyPoint),
+                LINE_STRING => Ok(Geometry::LineString(LineString(vec![]))),
+                POLYGON => Ok(Geometry::Polygon(Polygon::new(LineString(vec![]), vec![]))),
+                MULTI_POINT => Ok(Geometry::MultiPoint(MultiPoint(vec![]))),
+                MULTI_LINE_STRING => Ok(Geometry::MultiLineString(MultiLineString(vec![]))),
+                MULTI_POLYGON => Ok(Geometry::MultiPolygon(MultiPolygon(vec![]))),
+                GEOMETRY_COLLECTION => Ok(Geometry::GeometryCollection(GeometryCollection(vec![]))),
+                _ => Err(TwkbError::UnsupportedGeometryType(geometry_type)),
+            };
+        }
+        if metadata & BBOX != 0 {
+            for _ in 0..4 {
+                self.svarint()?;
+            }
+        }
+        let id_list = metadata & ID_LIST != 0;
+        self.precision = unzigzag(u64::from(type_and_precision >> 4)) as i8;
+        self.last = [0, 0];
+        Ok(match geometry_type {
+            POINT => Geometry::Point(Point(self.coordinate()?)),
+            LINE_STRING => Geometry::LineString(self.line_string()?),
+            POLYGON => Geometry::Polygon(self.polygon()?),
+            MULTI_POINT => {
+                let count = self.member_count(id_list)?;
+                Geometry::MultiPoint(MultiPoint(
+                    self.members(count, |r| r.coordinate().map(Point))?,
+                ))
+            }
+            MULTI_LINE_STRING => {
+                let count = self.member_count(id_list)?;
+                Geometry::MultiLineString(MultiLineString(self.members(count, Self::line_string)?))
+            }
+            MULTI_POLYGON => {
+                let count = self.member_count(id_list)?;
+                Geometry::MultiPolygon(MultiPolygon(self.members(count, Self::polygon)?))
+            }
+            GEOMETRY_COLLECTION => {
+                let count = self.member_count(id_list)?;
+                Geometry::GeometryCollection(GeometryCollection(
+                    self.members(count, Self::geometry)?,
+                ))
+            }
+            _ => return Err(TwkbError::UnsupportedGeometryType(geometry_type)),
+        })
+    }
+}
+
+/// The body of a geometry being written, and the bounding box of its coordinates
+struct Body {
+    bytes: Vec<u8>,
+    precision: i8,
+    /// The previous coordinate, which the next is written relative to
+    last: [i64; 2],
+    /// `[min x, min y, max x, max y]`
+    bbox: Option<[i64; 4]>,
+}
+
+impl Body {
+    fn count(&mut self, count: usize) {
+        uvarint(count as u64, &mut self.bytes)
+    }
+
+    fn include(&mut self, bbox: [i64; 4]) {
+        self.bbox = Some(match self.bbox {
+            Some(b) => [
+                b[0].min(bbox[0]),
+                b[1].min(bbox[1]),
+                b[2].max(bbox[2]),
+                b[3].max(bbox[3]),
+            ],
+            None => bbox,
+        });
+    }
+
+    fn coordinate<T: CoordinateType>(&mut self, coord: &Coordinate<T>) -> Result<(), TwkbError> {
+        let precision = self.precision;
+        let scale = |ordinate: T| -> Result<i64, TwkbError> {
+            let value = ordinate
+                .to_f64()
+                .expect("coordinate can't be converted to f64");
+            let rounded = if precision >= 0 {
+                (value * 10f64.powi(precision.into())).round()
+            } else {
+                (value / 10f64.powi((-precision).into())).round()
+            };
+            // leaving room for the differences between values; NaNs aren't finite either
+            if !rounded.is_finite() || rounded.abs() >= (1u64 << 62) as f64 {
+                return Err(TwkbError::InvalidCoordinate(value));
+            }
+            Ok(rounded as i64)
+        };
+        let scaled = [scale(coord.x)?, scale(coord.y)?];
+        for (value, last) in scaled.iter().zip(&self.last) {
+            svarint(value - last, &mut self.bytes);
+        }
+        self.last = scaled;
+        self.include([scaled[0], scaled[1], scaled[0], scaled[1]]);
+        Ok(())
+    }
+
+    fn coordinates<T: CoordinateType>(
+        &mut self,
+        coords: &[Coordinate<T>],
+    ) -> Result<(), TwkbError> {
+        self.count(coords.len());
+        coords.iter().try_for_each(|coord| self.coordinate(coord))
+    }
+
+    fn polygon<T: CoordinateType>(&mut self, polygon: &Polygon<T>) -> Result<(), TwkbError> {
+        if polygon.exterior().0.is_empty() {
+            self.count(0);
+            return Ok(());
+        }
+        self.count(1 + polygon.interiors().len());
+        self.coordinates(&polygon.exterior().0)?;
+        for interior in polygon.interiors() {
+            self.coordinates(&interior.0)?;
+        }
+        Ok(())
+    }
+
+    /// Write the body of `geometry`, returning its type and whether it's empty
+    fn geometry<T: CoordinateType>(
+        &mut self,
+        geometry: &Geometry<T>,
+        options: TwkbOptions,
+    ) -> Result<(u8, bool), TwkbError> {
+        Ok(match geometry {
+            Geometry::Point(p) if p.is_empty() => (POINT, true),
+            Geometry::Point(p) => {
+                self.coordinate(&p.0)?;
+                (POINT, false)
+            }
+            Geometry::Line(l) => {
+                self.coordinates(&[l.start, l.end])?;
+                (LINE_STRING, false)
+            }
+            Geometry::LineString(ls) => {
+                self.coordinates(&ls.0)?;
+                (LINE_STRING, ls.0.is_empty())
+            }
+            Geometry::Polygon(p) => {
+                self.polygon(p)?;
+                (POLYGON, p.exterior().0.is_empty())
+            }
+            Geometry::MultiPoint(mp) => {
+                self.count(mp.0.len());
+                for p in &mp.0 {
+                    self.coordinate(&p.0)?;
+                }
+                (MULTI_POINT, mp.0.is_empty())
+            }
+            Geometry::MultiLineString(mls) => {
+                self.count(mls.0.len());
+                for ls in &mls.0 {
+                    self.coordinates(&ls.0)?;
+                }
+                (MULTI_LINE_STRING, mls.0.is_empty())
+            }
+            Geometry::MultiPolygon(mp) => {
+                self.count(mp.0.len());
+                for p in &mp.0 {
+                    self.polygon(p)?;
+                }
+                (MULTI_POLYGON, mp.0.is_empty())
+            }
+            Geometry::Rect(r) => {
+                self.polygon(&Polygon::from(*r))?;
+                (POLYGON, false)
+            }
+            Geometry::Triangle(t) => {
+                self.polygon(&Polygon::from(*t))?;
+                (POLYGON, false)
+            }
+            Geometry::GeometryCollection(gc) => {
+                self.count(gc.0.len());
+                for g in &gc.0 {
+                    if let Some(bbox) = write(g, self.precision, options, &mut self.bytes)? {
+                        self.include(bbox);
+                    }
+                }
+                (GEOMETRY_COLLECTION, gc.0.is_empty())
+            }
+        })
+    }
+}
+
+/// Write `geometry` with its header, returning its bounding box, if it has any coordinates
+fn write<T: CoordinateType>(
+    geometry: &Geometry<T>,
+    precision: i8,
+    options: TwkbOptions,
+    bytes: &mut Vec<u8>,
+) -> Result<Option<[i64; 4]>, TwkbError> {
+    let mut body = Body {
+        bytes: vec![],
+        precision,
+        last: [0, 0],
+        bbox: None,
+    };
+    let (geometry_type, empty) = body.geometry(geometry, options)?;
+    bytes.push(((zigzag(precision.into()) as u8) << 4) | geometry_type);
+    if empty {
+        bytes.push(EMPTY);
+        return Ok(None);
+    }
+
+    let mut metadata = 0;
+    let mut bbox_bytes = vec![];
+    if let (true, Some(bbox)) = (options.bbox, body.bbox) {
+        metadata |= BBOX;
+        for i in 0..2 {
+            svarint(bbox[i], &mut bbox_bytes);
+            svarint(bbox[i + 2] - bbox[i], &mut bbox_bytes);
+        }
+    }
+    if options.size {
+        metadata |= SIZE;
+    }
+    bytes.push(metadata);
+    if options.size {
+        uvarint((bbox_bytes.len() + body.bytes.len()) as u64, bytes);
+    }
+    bytes.extend_from_slice(&bbox_bytes);
+    bytes.extend_from_slice(&body.bytes);
+    Ok(body.bbox)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Rect};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn known_twkb() {
+        // LINESTRING(1 1,5 5), with and without a bounding box
+        let line_string = Geometry::LineString(line_string![(x: 1., y: 1.), (x: 5., y: 5.)]);
+        assert_eq!(line_string.to_twkb(0), Ok(hex("02000202020808")));
+        let bbox = TwkbOptions {
+            bbox: true,
+            size: false,
+        };
+        assert_eq!(
+            line_string.to_twkb_with(0, bbox),
+            Ok(hex("0201020802080202020808"))
+        );
+        assert_eq!(
+            Geometry::from_twkb(&hex("0201020802080202020808")),
+            Ok(line_string)
+        );
+
+        // POINT(1.5 -2.25) with 2 decimal places, i.e. (150, -225)
+        let point = Geometry::Point(point!(x: 1.5, y: -2.25));
+        assert_eq!(point.to_twkb(2), Ok(hex("4100ac02c103")));
+        assert_eq!(Geometry::from_twkb(&hex("4100ac02c103")), Ok(point));
+
+        // LINESTRING EMPTY
+        assert_eq!(
+            Geometry::<f64>::LineString(LineString(vec![])).to_twkb(0),
+            Ok(hex("0210"))
+        );
+
+        // POINT EMPTY, whose coordinates are NaN
+        let empty = Geometry::Point(Point::<f64>::empty());
+        assert_eq!(empty.to_twkb(0), Ok(hex("0110")));
+        assert!(matches!(
+            Geometry::<f32>::from_twkb(&hex("0110")),
+            Ok(Geometry::Point(point)) if point.is_empty()
+        ));
+
+        // MULTIPOINT(0 0,1 1), with the IDs 1 and 2
+        assert_eq!(
+            Geometry::from_twkb(&hex("040402020400000202")),
+            Ok(Geometry::MultiPoint(MultiPoint(vec![
+                point!(x: 0., y: 0.),
+                point!(x: 1., y: 1.)
+            ])))
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let gc: Geometry<f64> = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 1.5, y: -2.)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            )),
+            Geometry::Polygon(Polygon::new(LineString(vec![]), vec![])),
+            Geometry::MultiPoint(MultiPoint(vec![
+                point!(x: 0., y: 1.),
+                point!(x: -2.5, y: 3.),
+            ])),
+            Geometry::MultiLineString(MultiLineString(vec![LineString(vec![])])),
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 1., y: 1.)
+            ]])),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(
+                point!(x: 3., y: 4.),
+            )])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]));
+        for &precision in &[1, 3, MAX_PRECISION] {
+            for &bbox in &[false, true] {
+                for &size in &[false, true] {
+                    let twkb = gc
+                        .to_twkb_with(precision, TwkbOptions { bbox, size })
+                        .unwrap();
+                    assert_eq!(Geometry::from_twkb(&twkb), Ok(gc.clone()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        let point = Geometry::Point(point!(x: 1.23456, y: -0.0004));
+        assert_eq!(
+            Geometry::from_twkb(&point.to_twkb(3).unwrap()),
+            Ok(Geometry::Point(point!(x: 1.235, y: 0.)))
+        );
+        let rect = Geometry::Rect(Rect::new((12345, -67890), (23456, 0)));
+        assert_eq!(
+            Geometry::from_twkb(&rect.to_twkb(-3).unwrap()),
+            Ok(Geometry::Polygon(polygon![
+                (x: 12000, y: -68000),
+                (x: 23000, y: -68000),
+                (x: 23000, y: 0),
+                (x: 12000, y: 0),
+                (x: 12000, y: -68000),
+            ]))
+        );
+        let line = Geometry::Line(Line::new((0, 0), (1, 2)));
+        assert_eq!(
+            Geometry::from_twkb(&line.to_twkb(0).unwrap()),
+            Ok(Geometry::LineString(
+                line_string![(x: 0, y: 0), (x: 1, y: 2)]
+            ))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let point = Geometry::Point(point!(x: 1.5, y: 2.));
+        assert_eq!(point.to_twkb(8), Err(TwkbError::InvalidPrecision(8)));
+        assert_eq!(point.to_twkb(-9), Err(TwkbError::InvalidPrecision(-9)));
+        assert_eq!(
+            Geometry::Point(point!(x: 0., y: f64::INFINITY)).to_twkb(0),
+            Err(TwkbError::InvalidCoordinate(f64::INFINITY))
+        );
+        assert_eq!(
+            Geometry::Point(point!(x: 1e13, y: 0.)).to_twkb(7),
+            Err(TwkbError::InvalidCoordinate(1e13))
+        );
+
+        let twkb = point.to_twkb(1).unwrap();
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&twkb[..twkb.len() - 1]),
+            Err(TwkbError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Geometry::<u8>::from_twkb(&twkb),
+            Ok(Geometry::Point(point!(x: 1, y: 2)))
+        );
+        assert_eq!(
+            Geometry::<u8>::from_twkb(&Geometry::Point(point!(x: -1., y: 0.)).to_twkb(0).unwrap()),
+            Err(TwkbError::InvalidCoordinate(-1.))
+        );
+        let mut trailing = twkb.clone();
+        trailing.push(0);
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&trailing),
+            Err(TwkbError::TrailingBytes(twkb.len()))
+        );
+        // a size larger than the rest of the geometry
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&hex("01020a0000")),
+            Err(TwkbError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&hex("080000")),
+            Err(TwkbError::UnsupportedGeometryType(8))
+        );
+        // POINT Z(1 2 3)
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&hex("010801020406")),
+            Err(TwkbError::UnsupportedDimensions)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_twkb(&hex("0110")),
+            Err(TwkbError::EmptyPoint)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&hex("0100ffffffffffffffffff7f00")),
+            Err(TwkbError::Overflow(2))
+        );
+        // the second coordinate overflows when added to the first
+        assert_eq!(
+            Geometry::<f64>::from_twkb(&hex("020002feffffffffffffffff0100feffffffffffffffff0100")),
+            Err(TwkbError::Overflow(14))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Visit the geometries in a [`Geometry`](enum.Geometry.html) tree, using
+/// [`Geometry::accept`](enum.Geometry.html#method.accept), so that serializers, validators, and
+/// statistics can be written once rather than matching on every variant.
+///
+/// Every method does nothing by default, except for those of multi-geometries, which visit each
+/// member, and `visit_geometry_collection`, which calls `enter_collection`, accepts each member,
+/// and then calls `exit_collection`. Overriding one of those replaces the walk over its members.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, point, Geometry, GeometryCollection, GeometryVisitor, LineString, Point};
+///
+/// // count the coordinates of points and line strings, and how deeply collections nest
+/// #[derive(Default)]
+/// struct Stats {
+///     coords: usize,
+///     depth: usize,
+///     max_depth: usize,
+/// }
+///
+/// impl GeometryVisitor<f64> for Stats {
+///     fn visit_point(&mut self, _: &Point<f64>) {
+///         self.coords += 1;
+///     }
+///
+///     fn visit_line_string(&mut self, line_string: &LineString<f64>) {
+///         self.coords += line_string.0.len();
+///     }
+///
+///     fn enter_collection(&mut self, _: &GeometryCollection<f64>) {
+///         self.depth += 1;
+///         self.max_depth = self.max_depth.max(self.depth);
+///     }
+///
+///     fn exit_collection(&mut self, _: &GeometryCollection<f64>) {
+///         self.depth -= 1;
+///     }
+/// }
+///
+/// let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+///     Geometry::Point(point!(x: 0., y: 0.)),
+///     Geometry::GeometryCollection(GeometryCollection(vec![Geometry::LineString(
+///         line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///     )])),
+/// ]));
+/// let mut stats = Stats::default();
+/// geometry.accept(&mut stats);
+///
+/// assert_eq!(stats.coords, 3);
+/// assert_eq!(stats.max_depth, 2);
+/// ```
+pub trait GeometryVisitor<T: CoordinateType> {
+    fn visit_point(&mut self, _point: &Point<T>) {}
+
+    fn visit_line(&mut self, _line: &Line<T>) {}
+
+    fn visit_line_string(&mut self, _line_string: &LineString<T>) {}
+
+    fn visit_polygon(&mut self, _polygon: &Polygon<T>) {}
+
+    fn visit_rect(&mut self, _rect: &Rect<T>) {}
+
+    fn visit_triangle(&mut self, _triangle: &Triangle<T>) {}
+
+    /// Visit each point of a `MultiPoint`
+    fn visit_multi_point(&mut self, multi_point: &MultiPoint<T>) {
+        for point in &multi_point.0 {
+            self.visit_point(point);
+        }
+    }
+
+    /// Visit each line string of a `MultiLineString`
+    fn visit_multi_line_string(&mut self, multi_line_string: &MultiLineString<T>) {
+        for line_string in &multi_line_string.0 {
+            self.visit_line_string(line_string);
+        }
+    }
+
+    /// Visit each polygon of a `MultiPolygon`
+    fn visit_multi_polygon(&mut self, multi_polygon: &MultiPolygon<T>) {
+        for polygon in &multi_polygon.0 {
+            self.visit_polygon(polygon);
+        }
+    }
+
+    /// Called before the members of a `GeometryCollection` are visited
+    fn enter_collection(&mut self, _collection: &GeometryCollection<T>) {}
+
+    /// Called after the members of a `GeometryCollection` are visited
+    fn exit_collection(&mut self, _collection: &GeometryCollection<T>) {}
+
+    /// Enter a `GeometryCollection`, accept each of its members, then exit it
+    fn visit_geometry_collection(&mut self, collection: &GeometryCollection<T>) {
+        self.enter_collection(collection);
+        for geometry in &collection.0 {
+            geometry.accept(self);
+        }
+        self.exit_collection(collection);
+    }
+}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Call the method of `visitor` for the type of geometry this holds; see
+    /// [`GeometryVisitor`](trait.GeometryVisitor.html).
+    pub fn accept<V: GeometryVisitor<T> + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Geometry::Point(g) => visitor.visit_point(g),
+            Geometry::Line(g) => visitor.visit_line(g),
+            Geometry::LineString(g) => visitor.visit_line_string(g),
+            Geometry::Polygon(g) => visitor.visit_polygon(g),
+            Geometry::MultiPoint(g) => visitor.visit_multi_point(g),
+            Geometry::MultiLineString(g) => visitor.visit_multi_line_string(g),
+            Geometry::MultiPolygon(g) => visitor.visit_multi_polygon(g),
+            Geometry::GeometryCollection(g) => visitor.visit_geometry_collection(g),
+            Geometry::Rect(g) => visitor.visit_rect(g),
+            Geometry::Triangle(g) => visitor.visit_triangle(g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    // record the order of every call
+    #[derive(Default)]
+    struct Log(Vec<String>);
+
+    impl GeometryVisitor<i32> for Log {
+        fn visit_point(&mut self, point: &Point<i32>) {
+            self.0.push(format!("point {}", point.x()));
+        }
+
+        fn visit_line(&mut self, _: &Line<i32>) {
+            self.0.push("line".to_string());
+        }
+
+        fn visit_line_string(&mut self, _: &LineString<i32>) {
+            self.0.push("line string".to_string());
+        }
+
+        fn visit_polygon(&mut self, _: &Polygon<i32>) {
+            self.0.push("polygon".to_string());
+        }
+
+        fn visit_rect(&mut self, _: &Rect<i32>) {
+            self.0.push("rect".to_string());
+        }
+
+        fn visit_triangle(&mut self, _: &Triangle<i32>) {
+            self.0.push("triangle".to_string());
+        }
+
+        fn enter_collection(&mut self, collection: &GeometryCollection<i32>) {
+            self.0.push(format!("enter {}", collection.0.len()));
+        }
+
+        fn exit_collection(&mut self, _: &GeometryCollection<i32>) {
+            self.0.push("exit".to_string());
+        }
+    }
+
+    #[test]
+    fn visits_in_order() {
+        let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 1, y: 0), point!(x: 2, y: 0)])),
+            Geometry::Line(Line::new((0, 0), (1, 1))),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::MultiLineString(MultiLineString(vec![line_string![(x: 0, y: 0)]])),
+                Geometry::MultiPolygon(MultiPolygon(vec![polygon![(x: 0, y: 0)]])),
+            ])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+            Geometry::Rect(Rect::new((0, 0), (1, 1))),
+            Geometry::Triangle(Triangle::from([(0, 0), (1, 0), (0, 1)])),
+        ]));
+        let mut log = Log::default();
+        geometry.accept(&mut log);
+        assert_eq!(
+            log.0,
+            vec![
+                "enter 6",
+                "point 1",
+                "point 2",
+                "line",
+                "enter 2",
+                "line string",
+                "polygon",
+                "exit",
+                "enter 0",
+                "exit",
+                "rect",
+                "triangle",
+                "exit",
+            ]
+        );
+    }
+
+    #[test]
+    fn trait_objects() {
+        let mut log = Log::default();
+        let visitor: &mut dyn GeometryVisitor<i32> = &mut log;
+        Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(
+            point!(x: 3, y: 0),
+        )]))
+        .accept(visitor);
+        assert_eq!(log.0, vec!["enter 1", "point 3", "exit"]);
+    }
+}
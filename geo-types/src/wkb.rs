@@ -0,0 +1,1040 @@
+//! Reading and writing geometries as [Well-Known Binary (WKB)](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary).
+//!
+//! With the `wkb` feature enabled, [`Geometry`](../enum.Geometry.html) can be read from, and
+//! written to, 2D WKB in either byte order, as well as PostGIS' [Extended WKB
+//! (EWKB)](https://postgis.net/docs/using_postgis_dbmanagement.html#EWKB_EWKT), which can carry
+//! an SRID.
+//!
+//! WKB coordinates are always `f64`s: other coordinate types are converted using `NumCast`.
+//! Since WKB has no `Line`, `Rect` or `Triangle` type, a `Geometry::Line` is written as a
+//! `LineString`, and a `Geometry::Rect` or `Geometry::Triangle` as a `Polygon`. An empty point
+//! has NaN coordinates, in WKB as in [`Point::is_empty`](../struct.Point.html#method.is_empty).
+//!
+//! Reading from a byte slice and writing to a `Vec` only need `core` and `alloc`, so they work
+//! without the `std` feature; streaming from an `io::Read` with
+//! [`WkbReader`](struct.WkbReader.html), or to an `io::Write` with
+//! [`WkbWriter`](struct.WkbWriter.html), needs it.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::wkb::ByteOrder;
+//! use geo_types::{point, Geometry};
+//!
+//! let geometry = Geometry::Point(point!(x: 1., y: 2.));
+//!
+//! let wkb = geometry.to_wkb();
+//! assert_eq!(Geometry::from_wkb(&wkb).unwrap(), geometry);
+//!
+//! let ewkb = geometry.to_wkb_with(ByteOrder::BigEndian, Some(4326));
+//! assert_eq!(Geometry::from_ewkb(&ewkb).unwrap(), (geometry, Some(4326)));
+//! ```
+use crate::stream::{GeomEventSink, GeomEventSource, GeometryBuilder, StreamError};
+use crate::{Coordinate, CoordinateType, Geometry};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::convert::Infallible;
+use core::fmt;
+#[cfg(feature = "std")]
+use {
+    core::cell::{Cell, RefCell},
+    core::marker::PhantomData,
+    std::error::Error,
+    std::io::{self, Read, Write},
+};
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// The byte order of the numbers in a WKB geometry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Also known as XDR
+    BigEndian,
+    /// Also known as NDR
+    LittleEndian,
+}
+
+/// The error returned when reading WKB fails.
+#[derive(Debug)]
+pub enum WkbError {
+    /// Reading the input failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// The input ended before the geometry was complete.
+    UnexpectedEnd,
+    /// The input continued after the geometry was complete, at the given byte offset.
+    TrailingBytes(usize),
+    /// An invalid byte order marker was found at the given byte offset.
+    InvalidByteOrder { found: u8, position: usize },
+    /// The geometry type is unknown, or isn't supported (e.g. geometries with a Z or M
+    /// dimension).
+    UnsupportedGeometryType(u32),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+    /// A geometry collection at the given byte offset is nested more deeply than
+    /// [`MAX_NESTING`](constant.MAX_NESTING.html) collections.
+    TooDeeplyNested(usize),
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            WkbError::Io(e) => write!(f, "{}", e),
+            WkbError::UnexpectedEnd => write!(f, "Unexpected end of WKB"),
+            WkbError::TrailingBytes(position) => {
+                write!(f, "Unexpected trailing bytes at position {}", position)
+            }
+            WkbError::InvalidByteOrder { found, position } => write!(
+                f,
+                "Invalid byte order {:#04x} at position {}",
+                found, position
+            ),
+            WkbError::UnsupportedGeometryType(geometry_type) => {
+                write!(f, "Unsupported geometry type {:#x}", geometry_type)
+            }
+            WkbError::InvalidCoordinate(value) => {
+                write!(f, "Coordinate {} can't be represented", value)
+            }
+            WkbError::TooDeeplyNested(position) => {
+                write!(
+                    f,
+                    "Geometry collection nested too deeply at position {}",
+                    position
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for WkbError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WkbError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<T: CoordinateType> Geometry<T> {
+    /// Read a geometry from WKB, or from EWKB, discarding its SRID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// // POINT(1 2), little-endian
+    /// let wkb = [
+    ///     0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+    /// ];
+    ///
+    /// assert_eq!(Geometry::from_wkb(&wkb).unwrap(), Geometry::Point(point!(x: 1., y: 2.)));
+    /// ```
+    pub fn from_wkb(bytes: &[u8]) -> Result<Geometry<T>, WkbError> {
+        Self::from_ewkb(bytes).map(|(geometry, _)| geometry)
+    }
+
+    /// Read a geometry, and its SRID (if any), from EWKB. Plain WKB is read with no SRID.
+    pub fn from_ewkb(bytes: &[u8]) -> Result<(Geometry<T>, Option<u32>), WkbError> {
+        let mut reader = Reader::new(bytes);
+        let mut builder = GeometryBuilder::new();
+        let srid = match reader.next_geometry(&mut builder) {
+            Ok(Some(srid)) => srid,
+            Ok(None) => return Err(WkbError::UnexpectedEnd),
+            Err(StreamError::Source(e)) => return Err(e),
+            // the reader's events are always well-formed, but may hold an empty point
+            Err(StreamError::Sink(_)) => return Err(WkbError::InvalidCoordinate(f64::NAN)),
+        };
+        if reader.position < bytes.len() {
+            return Err(WkbError::TrailingBytes(reader.position));
+        }
+        let mut geometries = builder.finish().map_err(|_| WkbError::UnexpectedEnd)?;
+        let geometry = geometries.pop().ok_or(WkbError::UnexpectedEnd)?;
+        Ok((geometry, srid))
+    }
+
+    /// Write this geometry as little-endian WKB.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        self.to_wkb_with(ByteOrder::LittleEndian, None)
+    }
+
+    /// Write this geometry as WKB using `byte_order`. If an `srid` is given, EWKB is written
+    /// instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate can't be converted to an `f64`.
+    pub fn to_wkb_with(&self, byte_order: ByteOrder, srid: Option<u32>) -> Vec<u8> {
+        let mut encoder = Encoder::new(vec![], byte_order, srid);
+        self.process(&mut encoder)
+            .expect("writing WKB to a Vec can't fail");
+        encoder.out
+    }
+}
+
+impl<W> From<WkbError> for StreamError<WkbError, W> {
+    fn from(e: WkbError) -> Self {
+        StreamError::Source(e)
+    }
+}
+
+type ReadResult<W> = Result<(), StreamError<WkbError, W>>;
+
+/// A [`GeomEventSource`](../stream/trait.GeomEventSource.html) which reads geometries from WKB or
+/// EWKB, reading them as their events are written, rather than building the geometries.
+///
+/// The WKB is read from a `Read`, such as a file, a few bytes at a time, so even a huge geometry
+/// is never held in memory; nothing is buffered, so wrap files in a `BufReader`. The input may
+/// hold several geometries, one after another, as [`WkbWriter`](struct.WkbWriter.html) writes
+/// them; each is written to the sink in turn, up to the end of the input.
+///
+/// The coordinate type is chosen when the reader is created, since sinks such as `WkbWriter`
+/// accept any of them. A point whose coordinates are both NaN is read as an empty point, with
+/// no `coord` event. The reader needs the `std` feature; without it, WKB can still be read from
+/// a byte slice with [`Geometry::from_ewkb`](../enum.Geometry.html#method.from_ewkb).
+#[cfg(feature = "std")]
+pub struct WkbReader<R: Read, T> {
+    reader: RefCell<Reader<R>>,
+    srid: Cell<Option<u32>>,
+    coordinate_type: PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, T: CoordinateType> WkbReader<R, T> {
+    /// Read WKB from `input`, with coordinates of type `T`.
+    pub fn new(input: R) -> Self {
+        WkbReader {
+            reader: RefCell::new(Reader::new(input)),
+            srid: Cell::new(None),
+            coordinate_type: PhantomData,
+        }
+    }
+
+    /// Return the SRID of the last geometry read, if it has one.
+    pub fn srid(&self) -> Option<u32> {
+        self.srid.get()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, T: CoordinateType> GeomEventSource<T> for WkbReader<R, T> {
+    type Error = WkbError;
+
+    /// Write the events of the geometries which haven't been read yet.
+    fn process<S>(&self, sink: &mut S) -> ReadResult<S::Error>
+    where
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let mut reader = self.reader.borrow_mut();
+        while let Some(srid) = reader.next_geometry(sink)? {
+            self.srid.set(srid);
+        }
+        Ok(())
+    }
+}
+
+fn ordinate<T: CoordinateType>(value: f64) -> Result<T, WkbError> {
+    T::from(value).ok_or(WkbError::InvalidCoordinate(value))
+}
+
+/// The most geometry collections which may be nested inside each other when reading WKB, so that
+/// untrusted input can't overflow the stack.
+pub const MAX_NESTING: usize = 128;
+
+// Where WKB is read from: a byte slice, and with the `std` feature, any `Read`
+trait Input {
+    // Fill as much of `buf` as there is input for, returning how much that was
+    fn fill(&mut self, buf: &mut [u8]) -> Result<usize, WkbError>;
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Input for R {
+    fn fill(&mut self, buf: &mut [u8]) -> Result<usize, WkbError> {
+        let mut read = 0;
+        while read < buf.len() {
+            match self.read(&mut buf[read..]) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(WkbError::Io(e)),
+            }
+        }
+        Ok(read)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Input for &[u8] {
+    fn fill(&mut self, buf: &mut [u8]) -> Result<usize, WkbError> {
+        let read = buf.len().min(self.len());
+        let (bytes, rest) = self.split_at(read);
+        buf[..read].copy_from_slice(bytes);
+        *self = rest;
+        Ok(read)
+    }
+}
+
+struct Reader<R: Input> {
+    input: R,
+    position: usize,
+    // the number of collections the reader is inside
+    depth: usize,
+}
+
+impl<R: Input> Reader<R> {
+    fn new(input: R) -> Self {
+        Reader {
+            input,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    /// Fill `buf`, returning how much of it was read before the end of the input
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, WkbError> {
+        let read = self.input.fill(buf)?;
+        self.position += read;
+        Ok(read)
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let mut buf = [0; N];
+        if self.read(&mut buf)? < N {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        Ok(buf)
+    }
+
+    fn u32(&mut self, byte_order: ByteOrder) -> Result<u32, WkbError> {
+        let buf = self.take()?;
+        Ok(match byte_order {
+            ByteOrder::BigEndian => u32::from_be_bytes(buf),
+            ByteOrder::LittleEndian => u32::from_le_bytes(buf),
+        })
+    }
+
+    fn f64(&mut self, byte_order: ByteOrder) -> Result<f64, WkbError> {
+        let buf = self.take()?;
+        Ok(match byte_order {
+            ByteOrder::BigEndian => f64::from_be_bytes(buf),
+            ByteOrder::LittleEndian => f64::from_le_bytes(buf),
+        })
+    }
+
+    /// Read a coordinate, or `None` if both of its values are NaN
+    fn coordinate<T: CoordinateType>(
+        &mut self,
+        byte_order: ByteOrder,
+    ) -> Result<Option<Coordinate<T>>, WkbError> {
+        let (x, y) = (self.f64(byte_order)?, self.f64(byte_order)?);
+        if x.is_nan() && y.is_nan() {
+            return Ok(None);
+        }
+        Ok(Some(Coordinate {
+            x: ordinate(x)?,
+            y: ordinate(y)?,
+        }))
+    }
+
+    /// Read a `u32` count, followed by that many coordinates
+    fn coordinates<T, S>(&mut self, byte_order: ByteOrder, sink: &mut S) -> ReadResult<S::Error>
+    where
+        T: CoordinateType,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        for _ in 0..self.u32(byte_order)? {
+            let (x, y) = (self.f64(byte_order)?, self.f64(byte_order)?);
+            let coord = Coordinate {
+                x: ordinate(x)?,
+                y: ordinate(y)?,
+            };
+            sink.coord(coord).map_err(StreamError::Sink)?;
+        }
+        Ok(())
+    }
+
+    /// Read the rest of the header of a geometry which starts with the byte order marker `found`
+    /// at `position`: the byte order, geometry type, and the SRID, if any
+    fn header(
+        &mut self,
+        found: u8,
+        position: usize,
+    ) -> Result<(ByteOrder, u32, Option<u32>), WkbError> {
+        let byte_order = match found {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            _ => return Err(WkbError::InvalidByteOrder { found, position }),
+        };
+        let geometry_type = self.u32(byte_order)?;
+        if geometry_type & (EWKB_Z | EWKB_M) != 0 {
+            return Err(WkbError::UnsupportedGeometryType(geometry_type));
+        }
+        let srid = if geometry_type & EWKB_SRID != 0 {
+            Some(self.u32(byte_order)?)
+        } else {
+            None
+        };
+        Ok((byte_order, geometry_type & !EWKB_SRID, srid))
+    }
+
+    /// Read a geometry, returning its SRID, or `None` if the input ended before it began
+    fn next_geometry<T, S>(
+        &mut self,
+        sink: &mut S,
+    ) -> Result<Option<Option<u32>>, StreamError<WkbError, S::Error>>
+    where
+        T: CoordinateType,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let position = self.position;
+        let mut found = [0];
+        if self.read(&mut found)? == 0 {
+            return Ok(None);
+        }
+        self.rest_of_geometry(found[0], position, None, sink)
+            .map(Some)
+    }
+
+    /// Read a geometry, which must be of the `expected` type, if one is given
+    fn geometry<T, S>(&mut self, expected: Option<u32>, sink: &mut S) -> ReadResult<S::Error>
+    where
+        T: CoordinateType,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let position = self.position;
+        let [found] = self.take::<1>()?;
+        self.rest_of_geometry(found, position, expected, sink)
+            .map(|_| ())
+    }
+
+    /// Read the rest of a geometry starting at `position` with the byte order marker `found`,
+    /// returning its SRID
+    fn rest_of_geometry<T, S>(
+        &mut self,
+        found: u8,
+        position: usize,
+        expected: Option<u32>,
+        sink: &mut S,
+    ) -> Result<Option<u32>, StreamError<WkbError, S::Error>>
+    where
+        T: CoordinateType,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let (byte_order, geometry_type, srid) = self.header(found, position)?;
+        match expected {
+            Some(expected) if expected != geometry_type => {
+                return Err(WkbError::UnsupportedGeometryType(geometry_type).into())
+            }
+            _ => {}
+        }
+        let result = match geometry_type {
+            POINT => {
+                sink.begin_point().map_err(StreamError::Sink)?;
+                if let Some(coord) = self.coordinate(byte_order)? {
+                    sink.coord(coord).map_err(StreamError::Sink)?;
+                }
+                sink.end_point().map_err(StreamError::Sink)
+            }
+            LINE_STRING => {
+                sink.begin_line_string().map_err(StreamError::Sink)?;
+                self.coordinates(byte_order, sink)?;
+                sink.end_line_string().map_err(StreamError::Sink)
+            }
+            POLYGON => {
+                sink.begin_polygon().map_err(StreamError::Sink)?;
+                for _ in 0..self.u32(byte_order)? {
+                    sink.begin_ring().map_err(StreamError::Sink)?;
+                    self.coordinates(byte_order, sink)?;
+                    sink.end_ring().map_err(StreamError::Sink)?;
+                }
+                sink.end_polygon().map_err(StreamError::Sink)
+            }
+            MULTI_POINT => {
+                sink.begin_multi_point().map_err(StreamError::Sink)?;
+                for _ in 0..self.u32(byte_order)? {
+                    self.geometry(Some(POINT), sink)?;
+                }
+                sink.end_multi_point().map_err(StreamError::Sink)
+            }
+            MULTI_LINE_STRING => {
+                sink.begin_multi_line_string().map_err(StreamError::Sink)?;
+                for _ in 0..self.u32(byte_order)? {
+                    self.geometry(Some(LINE_STRING), sink)?;
+                }
+                sink.end_multi_line_string().map_err(StreamError::Sink)
+            }
+            MULTI_POLYGON => {
+                sink.begin_multi_polygon().map_err(StreamError::Sink)?;
+                for _ in 0..self.u32(byte_order)? {
+                    self.geometry(Some(POLYGON), sink)?;
+                }
+                sink.end_multi_polygon().map_err(StreamError::Sink)
+            }
+            GEOMETRY_COLLECTION => {
+                if self.depth == MAX_NESTING {
+                    return Err(WkbError::TooDeeplyNested(position).into());
+                }
+                self.depth += 1;
+                sink.begin_collection().map_err(StreamError::Sink)?;
+                for _ in 0..self.u32(byte_order)? {
+                    self.geometry(None, sink)?;
+                }
+                self.depth -= 1;
+                sink.end_collection().map_err(StreamError::Sink)
+            }
+            _ => Err(WkbError::UnsupportedGeometryType(geometry_type).into()),
+        };
+        result.map(|()| srid)
+    }
+}
+
+struct WriterFrame {
+    // the position of the count of members, if the geometry has one
+    count_position: Option<usize>,
+    count: u32,
+}
+
+/// A [`GeomEventSink`](../stream/trait.GeomEventSink.html) which writes the geometries it's given
+/// as WKB, one after another, to a `Write`, such as a file.
+///
+/// Each geometry is written to the output as it ends, so only the one being written is held in
+/// memory, while the number of members of each of its parts is filled in. Nothing else is
+/// buffered, so wrap files in a `BufWriter`. An empty point is written with
+/// NaN coordinates, and the events are expected to be well-formed, e.g. as written by a
+/// [`GeomEventSource`](../stream/trait.GeomEventSource.html). The writer needs the `std`
+/// feature; without it, WKB can still be written to a `Vec` with
+/// [`Geometry::to_wkb_with`](../enum.Geometry.html#method.to_wkb_with).
+///
+/// # Panics
+///
+/// Panics if a coordinate can't be converted to an `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::stream::GeomEventSource;
+/// use geo_types::wkb::{ByteOrder, WkbWriter};
+/// use geo_types::{line_string, Geometry};
+///
+/// let ls = line_string![(x: 0., y: 0.), (x: 1., y: 2.)];
+/// let mut writer = WkbWriter::new(vec![], ByteOrder::BigEndian, Some(4326));
+/// ls.process(&mut writer).unwrap();
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     Geometry::LineString(ls).to_wkb_with(ByteOrder::BigEndian, Some(4326))
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub struct WkbWriter<W: Write>(Encoder<W>);
+
+#[cfg(feature = "std")]
+impl<W: Write> WkbWriter<W> {
+    /// Write WKB to `out` using `byte_order`. If an `srid` is given, EWKB is written instead, with the SRID
+    /// in the header of each geometry (but not of their members).
+    pub fn new(out: W, byte_order: ByteOrder, srid: Option<u32>) -> Self {
+        WkbWriter(Encoder::new(out, byte_order, srid))
+    }
+
+    /// Return the output written to.
+    pub fn into_inner(self) -> W {
+        self.0.out
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! forward_events {
+    ($($event:ident),*) => {
+        $(
+            fn $event(&mut self) -> io::Result<()> {
+                GeomEventSink::<T>::$event(&mut self.0)
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl<T: CoordinateType, W: Write> GeomEventSink<T> for WkbWriter<W> {
+    type Error = io::Error;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> io::Result<()> {
+        self.0.coord(coord)
+    }
+
+    forward_events!(
+        begin_point,
+        end_point,
+        begin_line_string,
+        end_line_string,
+        begin_polygon,
+        end_polygon,
+        begin_ring,
+        end_ring,
+        begin_multi_point,
+        end_multi_point,
+        begin_multi_line_string,
+        end_multi_line_string,
+        begin_multi_polygon,
+        end_multi_polygon,
+        begin_collection,
+        end_collection
+    );
+}
+
+// Where an encoder writes each geometry once it's complete: a `Vec`, and with the `std` feature,
+// any `Write`
+trait Output {
+    type Error;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Output for W {
+    type Error = io::Error;
+
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        Write::write_all(self, bytes)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Output for Vec<u8> {
+    type Error = Infallible;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Infallible> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+// The events of geometries, encoded as WKB
+struct Encoder<W: Output> {
+    out: W,
+    // the geometry being written
+    bytes: Vec<u8>,
+    byte_order: ByteOrder,
+    srid: Option<u32>,
+    stack: Vec<WriterFrame>,
+}
+
+impl<W: Output> Encoder<W> {
+    fn new(out: W, byte_order: ByteOrder, srid: Option<u32>) -> Self {
+        Encoder {
+            out,
+            bytes: vec![],
+            byte_order,
+            srid,
+            stack: vec![],
+        }
+    }
+
+    fn u32(&mut self, value: u32) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+
+    fn f64(&mut self, value: f64) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+
+    /// Write the header of a geometry, and a placeholder for its count of members, if it has one
+    fn begin(&mut self, geometry_type: Option<u32>, counted: bool) {
+        if let Some(parent) = self.stack.last_mut() {
+            parent.count += 1;
+        }
+        if let Some(geometry_type) = geometry_type {
+            self.bytes.push(match self.byte_order {
+                ByteOrder::BigEndian => 0,
+                ByteOrder::LittleEndian => 1,
+            });
+            match self.srid {
+                Some(srid) if self.stack.is_empty() => {
+                    self.u32(geometry_type | EWKB_SRID);
+                    self.u32(srid);
+                }
+                _ => self.u32(geometry_type),
+            }
+        }
+        let count_position = if counted {
+            self.u32(0);
+            Some(self.bytes.len() - 4)
+        } else {
+            None
+        };
+        self.stack.push(WriterFrame {
+            count_position,
+            count: 0,
+        });
+    }
+
+    /// Fill in the count of members of the geometry ending, and write it out if it's the whole
+    /// geometry
+    fn end(&mut self) -> Result<(), W::Error> {
+        if let Some(WriterFrame {
+            count_position: Some(position),
+            count,
+        }) = self.stack.pop()
+        {
+            let count = match self.byte_order {
+                ByteOrder::BigEndian => count.to_be_bytes(),
+                ByteOrder::LittleEndian => count.to_le_bytes(),
+            };
+            self.bytes[position..position + 4].copy_from_slice(&count);
+        }
+        if self.stack.is_empty() {
+            self.out.write_all(&self.bytes)?;
+            self.bytes.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<T: CoordinateType, W: Output> GeomEventSink<T> for Encoder<W> {
+    type Error = W::Error;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> Result<(), W::Error> {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.count += 1;
+        }
+        for ordinate in &[coord.x, coord.y] {
+            let value = ordinate
+                .to_f64()
+                .expect("coordinate can't be converted to f64");
+            self.f64(value);
+        }
+        Ok(())
+    }
+
+    fn begin_point(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(POINT), false);
+        Ok(())
+    }
+
+    fn end_point(&mut self) -> Result<(), W::Error> {
+        if let Some(WriterFrame { count: 0, .. }) = self.stack.last() {
+            self.f64(f64::NAN);
+            self.f64(f64::NAN);
+        }
+        self.end()
+    }
+
+    fn begin_line_string(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(LINE_STRING), true);
+        Ok(())
+    }
+
+    fn end_line_string(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_polygon(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(POLYGON), true);
+        Ok(())
+    }
+
+    fn end_polygon(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_ring(&mut self) -> Result<(), W::Error> {
+        self.begin(None, true);
+        Ok(())
+    }
+
+    fn end_ring(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_multi_point(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(MULTI_POINT), true);
+        Ok(())
+    }
+
+    fn end_multi_point(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_multi_line_string(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(MULTI_LINE_STRING), true);
+        Ok(())
+    }
+
+    fn end_multi_line_string(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_multi_polygon(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(MULTI_POLYGON), true);
+        Ok(())
+    }
+
+    fn end_multi_polygon(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+
+    fn begin_collection(&mut self) -> Result<(), W::Error> {
+        self.begin(Some(GEOMETRY_COLLECTION), true);
+        Ok(())
+    }
+
+    fn end_collection(&mut self) -> Result<(), W::Error> {
+        self.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, GeometryCollection, Line, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Point, Polygon,
+    };
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn read_known_wkb() {
+        let point = Geometry::Point(point!(x: 1., y: 2.));
+        assert_eq!(
+            Geometry::from_wkb(&hex("0101000000000000000000f03f0000000000000040")).unwrap(),
+            point
+        );
+        assert_eq!(
+            Geometry::from_wkb(&hex("00000000013ff00000000000004000000000000000")).unwrap(),
+            point
+        );
+        // SRID=4326;POINT(1 2), as written by PostGIS
+        assert_eq!(
+            Geometry::from_ewkb(&hex("0101000020e6100000000000000000f03f0000000000000040"))
+                .unwrap(),
+            (point, Some(4326))
+        );
+        // POINT EMPTY, as written by PostGIS
+        let empty = hex("0101000000000000000000f87f000000000000f87f");
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&empty),
+            Ok(Geometry::Point(point)) if point.is_empty()
+        ));
+        assert_eq!(Geometry::Point(Point::<f64>::empty()).to_wkb(), empty);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let gc: Geometry<f64> = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: 1.5, y: -2.)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+            )),
+            Geometry::Polygon(Polygon::new(LineString(vec![]), vec![])),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 0., y: 1.), point!(x: 2., y: 3.)])),
+            Geometry::MultiLineString(MultiLineString(vec![LineString(vec![])])),
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 1., y: 1.)
+            ]])),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(
+                point!(x: 3., y: 4.),
+            )])),
+        ]));
+        for &byte_order in &[ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            for &srid in &[None, Some(3857)] {
+                let wkb = gc.to_wkb_with(byte_order, srid);
+                assert_eq!(Geometry::from_ewkb(&wkb).unwrap(), (gc.clone(), srid));
+            }
+        }
+    }
+
+    #[test]
+    fn line_is_written_as_line_string() {
+        let line = Geometry::Line(Line::new((0, 0), (1, 2)));
+        assert_eq!(
+            Geometry::from_wkb(&line.to_wkb()).unwrap(),
+            Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 2)])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn streaming() {
+        let mp = Geometry::MultiPolygon(MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            Polygon::new(LineString(vec![]), vec![]),
+        ]));
+        let ewkb = mp.to_wkb_with(ByteOrder::BigEndian, Some(4326));
+        let reader = WkbReader::<_, f64>::new(ewkb.as_slice());
+        assert_eq!(reader.srid(), None);
+        let mut writer = WkbWriter::new(vec![], ByteOrder::BigEndian, Some(4326));
+        reader.process(&mut writer).unwrap();
+        assert_eq!(reader.srid(), Some(4326));
+        assert_eq!(writer.into_inner(), ewkb);
+
+        // only the SRID of the whole geometry is written
+        let mut writer = WkbWriter::new(vec![], ByteOrder::LittleEndian, Some(4326));
+        point!(x: 1., y: 2.).process(&mut writer).unwrap();
+        MultiPoint(vec![Point::<f64>::empty()])
+            .process(&mut writer)
+            .unwrap();
+        let wkb = writer.into_inner();
+        assert_eq!(
+            wkb,
+            hex(concat!(
+                "0101000020e6100000000000000000f03f0000000000000040",
+                "0104000020e6100000010000000101000000000000000000f87f000000000000f87f"
+            ))
+        );
+
+        // several geometries, read a byte at a time
+        struct Trickle<'a>(&'a [u8]);
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match self.0.split_first() {
+                    Some((&byte, rest)) if !buf.is_empty() => {
+                        buf[0] = byte;
+                        self.0 = rest;
+                        Ok(1)
+                    }
+                    _ => Ok(0),
+                }
+            }
+        }
+        let geometries = GeometryBuilder::build(&WkbReader::new(Trickle(&wkb))).unwrap();
+        assert_eq!(geometries.len(), 2);
+        assert_eq!(geometries[0], Geometry::Point(point!(x: 1., y: 2.)));
+        assert!(matches!(
+            &geometries[1],
+            Geometry::MultiPoint(MultiPoint(points)) if points.len() == 1 && points[0].is_empty()
+        ));
+        assert!(GeometryBuilder::<f64>::build(&WkbReader::new(io::empty()))
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            GeometryBuilder::<f64>::build(&WkbReader::new(&wkb[..30])),
+            Err(StreamError::Source(WkbError::UnexpectedEnd))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_errors() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+        }
+        impl Write for Failing {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("failed"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        assert!(matches!(
+            GeometryBuilder::<f64>::build(&WkbReader::new(Failing)),
+            Err(StreamError::Source(WkbError::Io(_)))
+        ));
+        let mut writer = WkbWriter::new(Failing, ByteOrder::LittleEndian, None);
+        assert!(point!(x: 1., y: 2.).process(&mut writer).is_err());
+    }
+
+    #[cfg(feature = "wkt")]
+    #[test]
+    fn wkt_to_wkb() {
+        use crate::wkt::WktReader;
+
+        let wkt = "GEOMETRYCOLLECTION(POINT EMPTY,POLYGON((0 0,1 0,1 1,0 0),(0.2 0.1,0.5 0.1,0.5 0.4,0.2 0.1)))";
+        let mut writer = WkbWriter::new(vec![], ByteOrder::LittleEndian, None);
+        WktReader::<_, f64>::new(wkt.as_bytes())
+            .process(&mut writer)
+            .unwrap();
+        let wkb = writer.into_inner();
+        assert_eq!(wkb, wkt.parse::<Geometry<f64>>().unwrap().to_wkb());
+        assert_eq!(Geometry::<f64>::from_wkb(&wkb).unwrap().to_string(), wkt);
+    }
+
+    #[test]
+    fn errors() {
+        let wkb = Geometry::Point(point!(x: 1.5, y: 2.)).to_wkb();
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&wkb[..20]),
+            Err(WkbError::UnexpectedEnd)
+        ));
+        assert_eq!(
+            Geometry::<i32>::from_wkb(&wkb).unwrap(),
+            // NumCast truncates 1.5, but NaN can't be represented
+            Geometry::Point(point!(x: 1, y: 2))
+        );
+        let nan = Geometry::Point(point!(x: f64::NAN, y: 0.)).to_wkb();
+        assert!(matches!(
+            Geometry::<i32>::from_wkb(&nan),
+            Err(WkbError::InvalidCoordinate(_))
+        ));
+        let mut trailing = wkb.clone();
+        trailing.push(0);
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&trailing),
+            Err(WkbError::TrailingBytes(21))
+        ));
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&hex("02")),
+            Err(WkbError::InvalidByteOrder {
+                found: 2,
+                position: 0
+            })
+        ));
+        // POINT Z(1 2 3)
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&hex(
+                "01e9030000000000000000f03f00000000000000400000000000000840"
+            )),
+            Err(WkbError::UnsupportedGeometryType(1001))
+        ));
+        // collections of one collection, nested deeply enough to overflow the stack if they
+        // weren't limited, then a point
+        let nested = |depth: usize| {
+            let mut wkb = hex("010700000001000000").repeat(depth);
+            wkb.extend(Geometry::Point(point!(x: 1., y: 2.)).to_wkb());
+            wkb
+        };
+        assert!(Geometry::<f64>::from_wkb(&nested(MAX_NESTING)).is_ok());
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&nested(MAX_NESTING + 1)),
+            Err(WkbError::TooDeeplyNested(position)) if position == MAX_NESTING * 9
+        ));
+        assert!(Geometry::<f64>::from_wkb(&nested(200_000)).is_err());
+        // a MULTIPOINT containing a LINESTRING
+        assert!(matches!(
+            Geometry::<f64>::from_wkb(&hex("010400000001000000010200000000000000")),
+            Err(WkbError::UnsupportedGeometryType(2))
+        ));
+    }
+}
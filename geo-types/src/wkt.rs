@@ -0,0 +1,1113 @@
+//! Reading and writing geometries as [Well-Known Text (WKT)](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry).
+//!
+//! With the `wkt` feature enabled, every geometry type implements `Display`, which writes WKT,
+//! and `FromStr`, which reads it. The precision of the written coordinates can be set using the
+//! standard formatting syntax, or using [`ToWkt`](trait.ToWkt.html).
+//!
+//! `Line`s are written as a `LINESTRING`, and `Rect`s and `Triangle`s as a `POLYGON`. Only 2D
+//! geometries are supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::wkt::ToWkt;
+//! use geo_types::{line_string, point, Geometry, GeometryCollection, LineString, Point};
+//!
+//! let point: Point<f64> = "POINT(1.5 -2)".parse().unwrap();
+//! assert_eq!(point, point!(x: 1.5, y: -2.));
+//!
+//! let gc = GeometryCollection(vec![
+//!     Geometry::Point(point),
+//!     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1. / 3., y: 1.)]),
+//! ]);
+//! assert_eq!(
+//!     gc.to_wkt_with_precision(2),
+//!     "GEOMETRYCOLLECTION(POINT(1.50 -2.00),LINESTRING(0.00 0.00,0.33 1.00))"
+//! );
+//!
+//! let geometry: Geometry<f64> = gc.to_wkt().parse().unwrap();
+//! assert_eq!(Geometry::GeometryCollection(gc), geometry);
+//! ```
+use crate::stream::{GeomEventSink, GeomEventSource, GeometryBuilder, StreamError};
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Write a geometry as WKT.
+///
+/// This is implemented for every geometry type, and is a shorthand for formatting them with
+/// `Display`.
+pub trait ToWkt: fmt::Display {
+    /// Write the WKT for this geometry, using the shortest representation of each coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt::ToWkt;
+    /// use geo_types::line_string;
+    ///
+    /// let ls = line_string![(x: 0., y: 0.), (x: 10.5, y: 2.)];
+    ///
+    /// assert_eq!(ls.to_wkt(), "LINESTRING(0 0,10.5 2)");
+    /// ```
+    fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+
+    /// Write the WKT for this geometry, with `precision` digits after the decimal point for each
+    /// (non-integer) coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt::ToWkt;
+    /// use geo_types::point;
+    ///
+    /// let p = point!(x: 1. / 3., y: 2.);
+    ///
+    /// assert_eq!(p.to_wkt_with_precision(3), "POINT(0.333 2.000)");
+    /// ```
+    fn to_wkt_with_precision(&self, precision: usize) -> String {
+        format!("{:.*}", precision, self)
+    }
+}
+
+/// The error returned when reading WKT fails.
+#[derive(Debug)]
+pub enum WktError {
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The input ended before the geometry was complete.
+    UnexpectedEnd,
+    /// An unexpected token was found at the given byte offset.
+    UnexpectedToken { found: String, position: usize },
+    /// A coordinate at the given byte offset couldn't be parsed.
+    InvalidNumber { found: String, position: usize },
+    /// The geometry type is unknown, or isn't supported (e.g. geometries with a Z or M
+    /// dimension, or an empty `POINT` of coordinates which can't be NaN).
+    UnsupportedGeometry(String),
+    /// The WKT was valid, but describes a different type of geometry than the one requested.
+    MismatchedGeometry {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The WKT was valid, but the geometry can't be represented by the requested type, e.g. a
+    /// `LINESTRING` with three coordinates can't be read as a `Line`.
+    InvalidGeometry(&'static str),
+    /// A `GEOMETRYCOLLECTION` at the given byte offset is nested more deeply than
+    /// [`MAX_NESTING`](constant.MAX_NESTING.html) collections.
+    TooDeeplyNested { position: usize },
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WktError::Io(e) => write!(f, "{}", e),
+            WktError::UnexpectedEnd => write!(f, "Unexpected end of WKT"),
+            WktError::UnexpectedToken { found, position } => {
+                write!(f, "Unexpected '{}' at position {}", found, position)
+            }
+            WktError::InvalidNumber { found, position } => {
+                write!(f, "Invalid number '{}' at position {}", found, position)
+            }
+            WktError::UnsupportedGeometry(geometry) => {
+                write!(f, "Unsupported geometry type '{}'", geometry)
+            }
+            WktError::MismatchedGeometry { expected, found } => {
+                write!(f, "Expected a {}, but found a {}", expected, found)
+            }
+            WktError::InvalidGeometry(reason) => write!(f, "Invalid geometry: {}", reason),
+            WktError::TooDeeplyNested { position } => {
+                write!(
+                    f,
+                    "Geometry collection nested too deeply at position {}",
+                    position
+                )
+            }
+        }
+    }
+}
+
+impl Error for WktError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WktError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// Writing
+
+struct WriterFrame {
+    // whether the members are geometries with their own tags
+    collection: bool,
+    tagged: bool,
+    children: usize,
+}
+
+/// A [`GeomEventSink`](../stream/trait.GeomEventSink.html) which writes the geometries it's given
+/// as WKT to a `fmt::Write`, such as a `String`, one per line.
+///
+/// This is how geometries are formatted with `Display`, so the WKT is the same.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::stream::GeomEventSource;
+/// use geo_types::wkt::WktWriter;
+/// use geo_types::{point, Rect};
+///
+/// let mut writer = WktWriter::with_precision(String::new(), 1);
+/// point!(x: 1., y: 2.).process(&mut writer).unwrap();
+/// Rect::new((0., 0.), (1., 1.)).process(&mut writer).unwrap();
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     "POINT(1.0 2.0)\nPOLYGON((0.0 0.0,1.0 0.0,1.0 1.0,0.0 1.0,0.0 0.0))"
+/// );
+/// ```
+pub struct WktWriter<W: fmt::Write> {
+    out: W,
+    precision: Option<usize>,
+    stack: Vec<WriterFrame>,
+    written: bool,
+}
+
+impl<W: fmt::Write> WktWriter<W> {
+    /// Write WKT to `out`, using the shortest representation of each coordinate.
+    pub fn new(out: W) -> Self {
+        WktWriter {
+            out,
+            precision: None,
+            stack: vec![],
+            written: false,
+        }
+    }
+
+    /// Write WKT to `out`, with `precision` digits after the decimal point for each (non-integer)
+    /// coordinate.
+    pub fn with_precision(out: W, precision: usize) -> Self {
+        WktWriter {
+            precision: Some(precision),
+            ..WktWriter::new(out)
+        }
+    }
+
+    /// Return the output written to.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Start a member of the geometry being written with `(`, or separate it from the last one
+    /// with `,`
+    fn member(&mut self) -> fmt::Result {
+        match self.stack.last_mut() {
+            Some(parent) => {
+                parent.children += 1;
+                self.out
+                    .write_str(if parent.children == 1 { "(" } else { "," })
+            }
+            None => {
+                if self.written {
+                    self.out.write_str("\n")?;
+                }
+                self.written = true;
+                Ok(())
+            }
+        }
+    }
+
+    fn begin(&mut self, tag: &str, collection: bool) -> fmt::Result {
+        let tagged = match self.stack.last() {
+            Some(parent) => parent.collection,
+            None => true,
+        };
+        self.member()?;
+        if tagged {
+            self.out.write_str(tag)?;
+        }
+        self.stack.push(WriterFrame {
+            collection,
+            tagged,
+            children: 0,
+        });
+        Ok(())
+    }
+
+    /// Close the geometry being written with `)`, or write `EMPTY` if it had no members, with a
+    /// space only between it and a tag
+    fn end(&mut self) -> fmt::Result {
+        match self.stack.pop() {
+            Some(WriterFrame {
+                children: 0,
+                tagged,
+                ..
+            }) => self.out.write_str(if tagged { " EMPTY" } else { "EMPTY" }),
+            Some(_) => self.out.write_str(")"),
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+impl<T, W> GeomEventSink<T> for WktWriter<W>
+where
+    T: CoordinateType + fmt::Display,
+    W: fmt::Write,
+{
+    type Error = fmt::Error;
+
+    fn coord(&mut self, coord: Coordinate<T>) -> fmt::Result {
+        self.member()?;
+        match self.precision {
+            Some(precision) => write!(
+                self.out,
+                "{:.*} {:.*}",
+                precision, coord.x, precision, coord.y
+            ),
+            None => write!(self.out, "{} {}", coord.x, coord.y),
+        }
+    }
+
+    fn begin_point(&mut self) -> fmt::Result {
+        self.begin("POINT", false)
+    }
+
+    fn end_point(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_line_string(&mut self) -> fmt::Result {
+        self.begin("LINESTRING", false)
+    }
+
+    fn end_line_string(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_polygon(&mut self) -> fmt::Result {
+        self.begin("POLYGON", false)
+    }
+
+    fn end_polygon(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_ring(&mut self) -> fmt::Result {
+        self.begin("", false)
+    }
+
+    fn end_ring(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_point(&mut self) -> fmt::Result {
+        self.begin("MULTIPOINT", false)
+    }
+
+    fn end_multi_point(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_line_string(&mut self) -> fmt::Result {
+        self.begin("MULTILINESTRING", false)
+    }
+
+    fn end_multi_line_string(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_multi_polygon(&mut self) -> fmt::Result {
+        self.begin("MULTIPOLYGON", false)
+    }
+
+    fn end_multi_polygon(&mut self) -> fmt::Result {
+        self.end()
+    }
+
+    fn begin_collection(&mut self) -> fmt::Result {
+        self.begin("GEOMETRYCOLLECTION", true)
+    }
+
+    fn end_collection(&mut self) -> fmt::Result {
+        self.end()
+    }
+}
+
+macro_rules! impl_display {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType + fmt::Display> fmt::Display for $type<T> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let mut writer = WktWriter {
+                        precision: f.precision(),
+                        ..WktWriter::new(f)
+                    };
+                    self.process(&mut writer).map_err(|_| fmt::Error)
+                }
+            }
+        )*
+    };
+}
+
+impl_display!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry,
+    Rect,
+    Triangle
+);
+
+macro_rules! impl_to_wkt {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType + fmt::Display> ToWkt for $type<T> {}
+        )*
+    };
+}
+
+impl_to_wkt!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Geometry,
+    Rect,
+    Triangle
+);
+
+// Reading
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+/// The tokens of some WKT, and their byte offsets, read as they're needed
+struct Tokens<R: BufRead> {
+    input: R,
+    position: usize,
+}
+
+impl<R: BufRead> Tokens<R> {
+    fn peek_byte(&mut self) -> Result<Option<u8>, WktError> {
+        loop {
+            match self.input.fill_buf() {
+                Ok(buf) => return Ok(buf.first().copied()),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(WktError::Io(e)),
+            }
+        }
+    }
+
+    fn bump(&mut self) {
+        self.input.consume(1);
+        self.position += 1;
+    }
+
+    /// Read a character which isn't ASCII, as far as it's valid UTF-8
+    fn non_ascii(&mut self, first: u8) -> Result<String, WktError> {
+        let len = match first {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => 1,
+        };
+        let mut bytes = vec![first];
+        self.bump();
+        while bytes.len() < len {
+            match self.peek_byte()? {
+                Some(b) if b & 0xc0 == 0x80 => {
+                    bytes.push(b);
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    fn next(&mut self) -> Result<Option<(Token, usize)>, WktError> {
+        while let Some(b) = self.peek_byte()? {
+            let start = self.position;
+            let token = match b {
+                _ if b.is_ascii_whitespace() => {
+                    self.bump();
+                    continue;
+                }
+                b'(' => Token::LeftParen,
+                b')' => Token::RightParen,
+                b',' => Token::Comma,
+                _ if b.is_ascii_alphanumeric() || b"+-.".contains(&b) => {
+                    let is_word = b.is_ascii_alphabetic();
+                    let mut text = String::new();
+                    while let Some(b) = self.peek_byte()? {
+                        let continues = if is_word {
+                            b.is_ascii_alphabetic()
+                        } else {
+                            b.is_ascii_digit() || b"+-.eE".contains(&b)
+                        };
+                        if !continues {
+                            break;
+                        }
+                        text.push(char::from(b));
+                        self.bump();
+                    }
+                    return Ok(Some((
+                        if is_word {
+                            Token::Word(text)
+                        } else {
+                            Token::Number(text)
+                        },
+                        start,
+                    )));
+                }
+                _ if b.is_ascii() => {
+                    return Err(WktError::UnexpectedToken {
+                        found: char::from(b).to_string(),
+                        position: start,
+                    })
+                }
+                _ => {
+                    let found = self.non_ascii(b)?;
+                    if found.chars().all(char::is_whitespace) {
+                        continue;
+                    }
+                    return Err(WktError::UnexpectedToken {
+                        found,
+                        position: start,
+                    });
+                }
+            };
+            self.bump();
+            return Ok(Some((token, start)));
+        }
+        Ok(None)
+    }
+}
+
+impl<W> From<WktError> for StreamError<WktError, W> {
+    fn from(e: WktError) -> Self {
+        StreamError::Source(e)
+    }
+}
+
+type ParseResult<W> = Result<(), StreamError<WktError, W>>;
+
+/// The most geometry collections which may be nested inside each other when reading WKT, so that
+/// untrusted input can't overflow the stack.
+pub const MAX_NESTING: usize = 128;
+
+struct Parser<R: BufRead> {
+    tokens: Tokens<R>,
+    peeked: Option<(Token, usize)>,
+    // the number of collections the parser is inside
+    depth: usize,
+}
+
+impl<R: BufRead> Parser<R> {
+    fn new(input: R) -> Self {
+        Parser {
+            tokens: Tokens { input, position: 0 },
+            peeked: None,
+            depth: 0,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, WktError> {
+        if self.peeked.is_none() {
+            self.peeked = self.tokens.next()?;
+        }
+        Ok(self.peeked.as_ref().map(|(token, _)| token))
+    }
+
+    /// The next token, if there is one
+    fn next_token(&mut self) -> Result<Option<(Token, usize)>, WktError> {
+        match self.peeked.take() {
+            Some(token) => Ok(Some(token)),
+            None => self.tokens.next(),
+        }
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), WktError> {
+        self.next_token()?.ok_or(WktError::UnexpectedEnd)
+    }
+
+    fn unexpected(token: Token, position: usize) -> WktError {
+        let found = match token {
+            Token::Word(s) | Token::Number(s) => s,
+            Token::LeftParen => "(".to_string(),
+            Token::RightParen => ")".to_string(),
+            Token::Comma => ",".to_string(),
+        };
+        WktError::UnexpectedToken { found, position }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), WktError> {
+        match self.next()? {
+            (token, _) if token == expected => Ok(()),
+            (token, position) => Err(Self::unexpected(token, position)),
+        }
+    }
+
+    /// Consume an `EMPTY`, if there is one
+    fn empty(&mut self) -> Result<bool, WktError> {
+        match self.peek()? {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("EMPTY") => {
+                self.peeked = None;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Parse `(item,item,…)`, or `EMPTY`
+    fn collection<E, P>(&mut self, mut parse_item: P) -> ParseResult<E>
+    where
+        P: FnMut(&mut Self) -> ParseResult<E>,
+    {
+        if self.empty()? {
+            return Ok(());
+        }
+        self.expect(Token::LeftParen)?;
+        loop {
+            parse_item(self)?;
+            match self.next()? {
+                (Token::Comma, _) => continue,
+                (Token::RightParen, _) => return Ok(()),
+                (token, position) => return Err(Self::unexpected(token, position).into()),
+            }
+        }
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, WktError> {
+        match self.next()? {
+            (Token::Number(s), position) => match s.parse() {
+                Ok(n) => Ok(n),
+                Err(_) => Err(WktError::InvalidNumber { found: s, position }),
+            },
+            (token, position) => Err(Self::unexpected(token, position)),
+        }
+    }
+
+    fn coordinate<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let coord = Coordinate {
+            x: self.number()?,
+            y: self.number()?,
+        };
+        sink.coord(coord).map_err(StreamError::Sink)
+    }
+
+    /// Parse the coordinates of a line string, or a ring
+    fn coordinates<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        self.collection(|p| p.coordinate(sink))
+    }
+
+    fn line_string<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        sink.begin_line_string().map_err(StreamError::Sink)?;
+        self.coordinates(sink)?;
+        sink.end_line_string().map_err(StreamError::Sink)
+    }
+
+    fn polygon<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        sink.begin_polygon().map_err(StreamError::Sink)?;
+        self.collection(|p| {
+            sink.begin_ring().map_err(StreamError::Sink)?;
+            p.coordinates(sink)?;
+            sink.end_ring().map_err(StreamError::Sink)
+        })?;
+        sink.end_polygon().map_err(StreamError::Sink)
+    }
+
+    /// Parse `(x y)`, or `EMPTY`
+    fn point<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        sink.begin_point().map_err(StreamError::Sink)?;
+        if !self.empty()? {
+            self.expect(Token::LeftParen)?;
+            self.coordinate(sink)?;
+            self.expect(Token::RightParen)?;
+        }
+        sink.end_point().map_err(StreamError::Sink)
+    }
+
+    /// A `MULTIPOINT` member, which may or may not be wrapped in parentheses, or `EMPTY`
+    fn multi_point_member<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        match self.peek()? {
+            Some(Token::Number(_)) => {
+                sink.begin_point().map_err(StreamError::Sink)?;
+                self.coordinate(sink)?;
+                sink.end_point().map_err(StreamError::Sink)
+            }
+            _ => self.point(sink),
+        }
+    }
+
+    fn geometry<T, S>(&mut self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        T: CoordinateType + FromStr,
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let (tag, position) = match self.next()? {
+            (Token::Word(word), position) => (word.to_ascii_uppercase(), position),
+            (token, position) => return Err(Self::unexpected(token, position).into()),
+        };
+        if let Some(Token::Word(dimension)) = self.peek()? {
+            if !dimension.eq_ignore_ascii_case("EMPTY") {
+                return Err(WktError::UnsupportedGeometry(format!(
+                    "{} {}",
+                    tag,
+                    dimension.to_ascii_uppercase()
+                ))
+                .into());
+            }
+        }
+        match tag.as_str() {
+            "POINT" => self.point(sink),
+            "LINESTRING" => self.line_string(sink),
+            "POLYGON" => self.polygon(sink),
+            "MULTIPOINT" => {
+                sink.begin_multi_point().map_err(StreamError::Sink)?;
+                self.collection(|p| p.multi_point_member(sink))?;
+                sink.end_multi_point().map_err(StreamError::Sink)
+            }
+            "MULTILINESTRING" => {
+                sink.begin_multi_line_string().map_err(StreamError::Sink)?;
+                self.collection(|p| p.line_string(sink))?;
+                sink.end_multi_line_string().map_err(StreamError::Sink)
+            }
+            "MULTIPOLYGON" => {
+                sink.begin_multi_polygon().map_err(StreamError::Sink)?;
+                self.collection(|p| p.polygon(sink))?;
+                sink.end_multi_polygon().map_err(StreamError::Sink)
+            }
+            "GEOMETRYCOLLECTION" => {
+                if self.depth == MAX_NESTING {
+                    return Err(WktError::TooDeeplyNested { position }.into());
+                }
+                self.depth += 1;
+                sink.begin_collection().map_err(StreamError::Sink)?;
+                self.collection(|p| p.geometry(sink))?;
+                self.depth -= 1;
+                sink.end_collection().map_err(StreamError::Sink)
+            }
+            _ => Err(WktError::UnsupportedGeometry(tag).into()),
+        }
+    }
+}
+
+/// A [`GeomEventSource`](../stream/trait.GeomEventSource.html) which reads geometries from WKT,
+/// parsing them as their events are written, rather than building the geometries.
+///
+/// The WKT is read from a `BufRead`, such as a `BufReader` of a file, or the bytes of a `&str`,
+/// a token at a time, so even a huge geometry is never held in memory. The input may hold
+/// several geometries, separated by whitespace, as [`WktWriter`](struct.WktWriter.html) writes
+/// them; each is written to the sink in turn, up to the end of the input.
+///
+/// The coordinate type is chosen when the reader is created, since sinks such as `WktWriter`
+/// accept any of them.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::stream::GeomEventSource;
+/// use geo_types::wkt::{WktReader, WktWriter};
+///
+/// // reformat some WKT
+/// let mut writer = WktWriter::with_precision(String::new(), 1);
+/// WktReader::<_, f64>::new("multipoint (1 2, 3 4)\npoint (5 6)".as_bytes())
+///     .process(&mut writer)
+///     .unwrap();
+///
+/// assert_eq!(writer.into_inner(), "MULTIPOINT((1.0 2.0),(3.0 4.0))\nPOINT(5.0 6.0)");
+/// ```
+pub struct WktReader<R: BufRead, T> {
+    parser: RefCell<Parser<R>>,
+    coordinate_type: PhantomData<T>,
+}
+
+impl<R: BufRead, T: CoordinateType + FromStr> WktReader<R, T> {
+    /// Read WKT from `input`, with coordinates of type `T`.
+    pub fn new(input: R) -> Self {
+        WktReader {
+            parser: RefCell::new(Parser::new(input)),
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead, T: CoordinateType + FromStr> GeomEventSource<T> for WktReader<R, T> {
+    type Error = WktError;
+
+    /// Write the events of the geometries which haven't been read yet.
+    fn process<S>(&self, sink: &mut S) -> ParseResult<S::Error>
+    where
+        S: GeomEventSink<T> + ?Sized,
+    {
+        let mut parser = self.parser.borrow_mut();
+        while parser.peek()?.is_some() {
+            parser.geometry(sink)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: CoordinateType + FromStr> FromStr for Geometry<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(s.as_bytes());
+        let mut builder = GeometryBuilder::new();
+        parser.geometry(&mut builder).map_err(|e| match e {
+            StreamError::Source(e) => e,
+            // the parser's events are always well-formed, but may hold an empty point
+            StreamError::Sink(_) => WktError::UnsupportedGeometry("POINT EMPTY".to_string()),
+        })?;
+        if let Some((token, position)) = parser.next_token()? {
+            return Err(Parser::<&[u8]>::unexpected(token, position));
+        }
+        let mut geometries = builder.finish().map_err(|_| WktError::UnexpectedEnd)?;
+        geometries.pop().ok_or(WktError::UnexpectedEnd)
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType + FromStr> FromStr for $type<T> {
+                type Err = WktError;
+
+                fn from_str(s: &str) -> Result<Self, WktError> {
+                    match s.parse()? {
+                        Geometry::$type(g) => Ok(g),
+                        other => Err(WktError::MismatchedGeometry {
+                            expected: stringify!($type),
+                            found: other.type_name(),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+/// Read a `LINESTRING` with exactly two coordinates.
+impl<T: CoordinateType + FromStr> FromStr for Line<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, WktError> {
+        match LineString::from_str(s)?.0.as_slice() {
+            [start, end] => Ok(Line::new(*start, *end)),
+            _ => Err(WktError::InvalidGeometry(
+                "a Line must have exactly two coordinates",
+            )),
+        }
+    }
+}
+
+/// Read a `POLYGON` with no interior rings, and an exterior ring with three (distinct)
+/// coordinates.
+impl<T: CoordinateType + FromStr> FromStr for Triangle<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, WktError> {
+        let polygon = Polygon::from_str(s)?;
+        match (polygon.exterior().0.as_slice(), polygon.interiors()) {
+            ([a, b, c, _], []) => Ok(Triangle(*a, *b, *c)),
+            _ => Err(WktError::InvalidGeometry(
+                "a Triangle must have three coordinates and no interior rings",
+            )),
+        }
+    }
+}
+
+/// Read a `POLYGON` with no interior rings, whose exterior ring is an axis-aligned rectangle.
+impl<T: CoordinateType + FromStr> FromStr for Rect<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, WktError> {
+        let err = WktError::InvalidGeometry(
+            "a Rect must have four axis-aligned corners and no interior rings",
+        );
+        let polygon = Polygon::from_str(s)?;
+        let corners = match (polygon.exterior().0.as_slice(), polygon.interiors()) {
+            ([a, b, c, d, _], []) => [*a, *b, *c, *d],
+            _ => return Err(err),
+        };
+        let bounds = crate::private_utils::get_bounding_rect(corners.iter().cloned()).unwrap();
+        let (min, max) = (bounds.min(), bounds.max());
+        let all_corners_distinct = (0..4).all(|i| (i + 1..4).all(|j| corners[i] != corners[j]));
+        let all_on_bounds = corners
+            .iter()
+            .all(|c| (c.x == min.x || c.x == max.x) && (c.y == min.y || c.y == max.y));
+        let all_edges_axis_aligned = (0..4).all(|i| {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            a.x == b.x || a.y == b.y
+        });
+        if all_corners_distinct && all_on_bounds && all_edges_axis_aligned {
+            Ok(bounds)
+        } else {
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn write() {
+        let poly = polygon!(
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+        );
+        assert_eq!(
+            poly.to_wkt(),
+            "POLYGON((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1))"
+        );
+        assert_eq!(
+            MultiPoint(vec![point!(x: 1, y: 2), point!(x: 3, y: 4)]).to_wkt(),
+            "MULTIPOINT((1 2),(3 4))"
+        );
+        assert_eq!(
+            MultiPolygon(vec![poly.clone(), Polygon::new(LineString(vec![]), vec![])]).to_wkt(),
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1)),EMPTY)"
+        );
+        assert_eq!(
+            Rect::new((0., 1.), (2., 3.)).to_wkt_with_precision(1),
+            "POLYGON((0.0 1.0,2.0 1.0,2.0 3.0,0.0 3.0,0.0 1.0))"
+        );
+        // precision is ignored for integer coordinates
+        assert_eq!(
+            format!("{:.1}", Geometry::Polygon(poly)),
+            "POLYGON((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1))"
+        );
+        assert_eq!(LineString::<f64>(vec![]).to_wkt(), "LINESTRING EMPTY");
+        assert_eq!(
+            GeometryCollection::<f64>::new().to_wkt(),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+
+    #[test]
+    fn read() {
+        let ls: LineString<f64> = " linestring ( 1 2 , -3.5 4e2 ) ".parse().unwrap();
+        assert_eq!(ls, line_string![(x: 1., y: 2.), (x: -3.5, y: 400.)]);
+        let mp: MultiPoint<i32> = "MULTIPOINT(1 2,(3 4))".parse().unwrap();
+        assert_eq!(mp, MultiPoint(vec![point!(x: 1, y: 2), point!(x: 3, y: 4)]));
+        let empty: Polygon<f64> = "POLYGON EMPTY".parse().unwrap();
+        assert!(empty.exterior().0.is_empty());
+        let line: Line<f64> = "LINESTRING(0 0,1 1)".parse().unwrap();
+        assert_eq!(line, Line::new((0., 0.), (1., 1.)));
+        let tri: Triangle<f64> = "POLYGON((0 0,1 0,0 1,0 0))".parse().unwrap();
+        assert_eq!(
+            tri.to_array(),
+            [(0., 0.).into(), (1., 0.).into(), (0., 1.).into()]
+        );
+        let rect: Rect<f64> = "POLYGON((0 1,0 3,2 3,2 1,0 1))".parse().unwrap();
+        assert_eq!(rect, Rect::new((0., 1.), (2., 3.)));
+        let gc: GeometryCollection<f64> = "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION EMPTY)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            gc,
+            GeometryCollection(vec![
+                Geometry::Point(point!(x: 1., y: 2.)),
+                Geometry::GeometryCollection(GeometryCollection::new()),
+            ])
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let gc = GeometryCollection(vec![
+            Geometry::Point(point!(x: 1.25, y: -0.5)),
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 0.1, y: 0.), (x: 0.1, y: 0.2)]),
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                LineString(vec![]),
+            ])),
+            Geometry::MultiPolygon(MultiPolygon(vec![])),
+        ]);
+        assert_eq!(gc.to_wkt().parse::<GeometryCollection<f64>>().unwrap(), gc);
+    }
+
+    #[test]
+    fn empty_points() {
+        assert_eq!(Point::<f64>::empty().to_wkt(), "POINT EMPTY");
+        let point: Point<f64> = "POINT EMPTY".parse().unwrap();
+        assert!(point.is_empty());
+        let mp = MultiPoint(vec![point!(x: 1., y: 2.), Point::empty()]);
+        assert_eq!(mp.to_wkt(), "MULTIPOINT((1 2),EMPTY)");
+        let mp: MultiPoint<f32> = mp.to_wkt().parse().unwrap();
+        assert_eq!(mp.0[0], point!(x: 1., y: 2.));
+        assert!(mp.0[1].is_empty());
+        assert!(matches!(
+            "POINT EMPTY".parse::<Point<i32>>(),
+            Err(WktError::UnsupportedGeometry(found)) if found == "POINT EMPTY"
+        ));
+    }
+
+    #[test]
+    fn streaming() {
+        let mut writer = WktWriter::new(String::new());
+        point!(x: 1, y: 2).process(&mut writer).unwrap();
+        GeometryCollection::<i32>::new()
+            .process(&mut writer)
+            .unwrap();
+        assert_eq!(writer.into_inner(), "POINT(1 2)\nGEOMETRYCOLLECTION EMPTY");
+
+        let wkt =
+            "GEOMETRYCOLLECTION(MULTIPOLYGON(((0 0,1 0,1 1,0 0)),EMPTY),MULTIPOINT(EMPTY,(1 2)))";
+        let mut writer = WktWriter::new(String::new());
+        WktReader::<_, f64>::new(wkt.as_bytes())
+            .process(&mut writer)
+            .unwrap();
+        assert_eq!(writer.into_inner(), wkt);
+
+        // several geometries, as they're written, and read a few bytes at a time
+        let wkt = "POINT(1 2)\nGEOMETRYCOLLECTION EMPTY\n LINESTRING(0 0,1 1)\u{a0}";
+        let reader = WktReader::new(io::BufReader::with_capacity(3, wkt.as_bytes()));
+        let geometries = GeometryBuilder::<i32>::build(&reader).unwrap();
+        assert_eq!(
+            geometries,
+            vec![
+                Geometry::Point(point!(x: 1, y: 2)),
+                Geometry::GeometryCollection(GeometryCollection::new()),
+                Geometry::LineString(line_string![(x: 0, y: 0), (x: 1, y: 1)]),
+            ]
+        );
+        let mut writer = WktWriter::new(String::new());
+        WktReader::<_, i32>::new("".as_bytes())
+            .process(&mut writer)
+            .unwrap();
+        assert_eq!(writer.into_inner(), "");
+
+        // a sink's error stops the parser
+        struct FirstCoord(Option<Coordinate<i32>>);
+        impl GeomEventSink<i32> for FirstCoord {
+            type Error = ();
+
+            fn coord(&mut self, coord: Coordinate<i32>) -> Result<(), ()> {
+                self.0 = Some(coord);
+                Err(())
+            }
+        }
+        let mut sink = FirstCoord(None);
+        assert!(matches!(
+            WktReader::new("LINESTRING(1 2,3 4,5".as_bytes()).process(&mut sink),
+            Err(StreamError::Sink(()))
+        ));
+        assert_eq!(sink.0, Some(Coordinate { x: 1, y: 2 }));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            "POINT(1 2".parse::<Point<f64>>(),
+            Err(WktError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            "POINT(1 2 3)".parse::<Point<f64>>(),
+            Err(WktError::UnexpectedToken { found, position: 10 }) if found == "3"
+        ));
+        assert!(matches!(
+            "POINT(1 x)".parse::<Point<f64>>(),
+            Err(WktError::UnexpectedToken { found, position: 8 }) if found == "x"
+        ));
+        assert!(matches!(
+            "POINT(1 é)".parse::<Point<f64>>(),
+            Err(WktError::UnexpectedToken { found, position: 8 }) if found == "é"
+        ));
+        assert!(matches!(
+            "POINT(1.5 2)".parse::<Point<i32>>(),
+            Err(WktError::InvalidNumber { found, position: 6 }) if found == "1.5"
+        ));
+        assert!(matches!(
+            "POINT Z (1 2 3)".parse::<Point<f64>>(),
+            Err(WktError::UnsupportedGeometry(found)) if found == "POINT Z"
+        ));
+        assert!(matches!(
+            "POINT(1 2)".parse::<LineString<f64>>(),
+            Err(WktError::MismatchedGeometry {
+                expected: "LineString",
+                found: "Point"
+            })
+        ));
+        assert!(matches!(
+            "POINT(1 2) POINT(3 4)".parse::<Point<f64>>(),
+            Err(WktError::UnexpectedToken { position: 11, .. })
+        ));
+
+        let nested =
+            |depth: usize| "GEOMETRYCOLLECTION(".repeat(depth) + "POINT(1 2)" + &")".repeat(depth);
+        assert!(nested(MAX_NESTING).parse::<Geometry<f64>>().is_ok());
+        assert!(matches!(
+            nested(MAX_NESTING + 1).parse::<Geometry<f64>>(),
+            Err(WktError::TooDeeplyNested { position }) if position == MAX_NESTING * 19
+        ));
+        // deep enough to overflow the stack, if it weren't limited
+        assert!(nested(200_000).parse::<Geometry<f64>>().is_err());
+        assert!("LINESTRING(0 0,1 1,2 2)".parse::<Line<f64>>().is_err());
+        assert!("POLYGON((0 0,1 0,1 2,0 0))".parse::<Rect<f64>>().is_err());
+        assert!("POLYGON((0 0,1 1,1 0,0 1,0 0))"
+            .parse::<Rect<f64>>()
+            .is_err());
+    }
+}
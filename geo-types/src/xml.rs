@@ -0,0 +1,254 @@
+//! A minimal tree of XML elements, shared by the KML and GML readers.
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use quick_xml::NsReader;
+use std::io::{self, BufRead};
+
+/// The error reading an XML document fails with.
+#[derive(Debug)]
+pub(crate) enum XmlError {
+    Io(io::Error),
+    /// The input isn't well-formed XML.
+    Syntax(String),
+}
+
+impl From<quick_xml::Error> for XmlError {
+    fn from(e: quick_xml::Error) -> Self {
+        match e {
+            quick_xml::Error::Io(e) => XmlError::Io(io::Error::new(e.kind(), e.to_string())),
+            e => XmlError::Syntax(e.to_string()),
+        }
+    }
+}
+
+/// The namespace of an element.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Namespace {
+    None,
+    Uri(String),
+    /// The element's prefix isn't bound to a namespace.
+    Unknown(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Element {
+    pub(crate) namespace: Namespace,
+    /// The local name.
+    pub(crate) name: String,
+    /// The local names and values of the attributes.
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    /// The text directly inside the element, untrimmed.
+    pub(crate) text: String,
+}
+
+impl Element {
+    fn new(namespace: ResolveResult, e: &BytesStart) -> Result<Element, XmlError> {
+        let namespace = match namespace {
+            ResolveResult::Unbound => Namespace::None,
+            ResolveResult::Bound(uri) => {
+                Namespace::Uri(String::from_utf8_lossy(uri.as_ref()).into_owned())
+            }
+            ResolveResult::Unknown(prefix) => {
+                Namespace::Unknown(String::from_utf8_lossy(&prefix).into_owned())
+            }
+        };
+        let mut attributes = vec![];
+        for attribute in e.attributes() {
+            let attribute = attribute.map_err(quick_xml::Error::from)?;
+            let name = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+            attributes.push((name, attribute.unescape_value()?.into_owned()));
+        }
+        Ok(Element {
+            namespace,
+            name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+            attributes,
+            children: vec![],
+            text: String::new(),
+        })
+    }
+
+    #[cfg(any(feature = "gml", test))]
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// Read a document, passing each element `select` returns `true` for, with its descendants, to
+/// `handle`, and returning the root element, without its descendants, if there is one.
+///
+/// The descendants of a selected element aren't passed to `select`, so only the outermost of
+/// nested matching elements are handled.
+pub(crate) fn read<R, E>(
+    reader: R,
+    mut select: impl FnMut(&Element) -> bool,
+    mut handle: impl FnMut(Element) -> Result<(), E>,
+) -> Result<Option<Element>, E>
+where
+    R: BufRead,
+    E: From<XmlError>,
+{
+    let mut reader = NsReader::from_reader(reader);
+    let mut root = None;
+    // The number of open elements outside of a selected element
+    let mut depth = 0;
+    // The open elements of the selected element being read, from the outermost
+    let mut open: Vec<Element> = vec![];
+    let mut buf = vec![];
+    loop {
+        let (namespace, event) = reader
+            .read_resolved_event_into(&mut buf)
+            .map_err(XmlError::from)?;
+        let (e, empty) = match event {
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(_) => {
+                match open.pop() {
+                    Some(element) => match open.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => handle(element)?,
+                    },
+                    None => depth -= 1,
+                }
+                buf.clear();
+                continue;
+            }
+            Event::Text(e) => {
+                if let Some(element) = open.last_mut() {
+                    element
+                        .text
+                        .push_str(&e.unescape().map_err(XmlError::from)?);
+                }
+                buf.clear();
+                continue;
+            }
+            Event::CData(e) => {
+                if let Some(element) = open.last_mut() {
+                    let text = e
+                        .decode()
+                        .map_err(|e| XmlError::from(quick_xml::Error::from(e)))?;
+                    element.text.push_str(&text);
+                }
+                buf.clear();
+                continue;
+            }
+            Event::Eof => break,
+            _ => {
+                buf.clear();
+                continue;
+            }
+        };
+        let element = Element::new(namespace, &e)?;
+        if open.is_empty() && depth == 0 {
+            if root.is_some() {
+                return Err(XmlError::Syntax("several root elements".to_string()).into());
+            }
+            root = Some(element.clone());
+        }
+        if !open.is_empty() || select(&element) {
+            open.push(element);
+            if empty {
+                let element = open.pop().unwrap();
+                match open.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => handle(element)?,
+                }
+            }
+        } else if !empty {
+            depth += 1;
+        }
+        buf.clear();
+    }
+    if depth > 0 || !open.is_empty() {
+        return Err(XmlError::Syntax("unclosed elements at the end".to_string()).into());
+    }
+    Ok(root)
+}
+
+/// The first two values of each of the whitespace-separated tuples of comma-separated numbers,
+/// such as `1,2,3 4,5,6`, of KML and GML 2 coordinates. Tuples can have two or three values.
+pub(crate) fn parse_tuples(text: &str) -> Option<Vec<(f64, f64)>> {
+    text.split_whitespace()
+        .map(|tuple| {
+            let mut values = tuple.split(',').map(|value| value.parse::<f64>().ok());
+            let x = values.next()??;
+            let y = values.next()??;
+            match (values.next(), values.next()) {
+                (None, _) | (Some(Some(_)), None) => Some((x, y)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn read_all(xml: &str) -> Result<Vec<Element>, XmlError> {
+        let mut elements = vec![];
+        read(
+            xml.as_bytes(),
+            |element| element.name == "b",
+            |element| {
+                elements.push(element);
+                Ok::<_, XmlError>(())
+            },
+        )?;
+        Ok(elements)
+    }
+
+    #[test]
+    fn select_elements() {
+        let xml = r#"<a xmlns:x="urn:x"><b n="1">one <c/>&amp; <![CDATA[<two>]]></b>
+            <d><x:b><b/></x:b></d><y:b/></a>"#;
+        let elements = read_all(xml).unwrap();
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements[0].attribute("n"), Some("1"));
+        assert_eq!(elements[0].text, "one & <two>");
+        assert_eq!(elements[0].children[0].name, "c");
+        assert_eq!(elements[1].namespace, Namespace::Uri("urn:x".to_string()));
+        assert_eq!(
+            elements[1].children,
+            vec![Element {
+                namespace: Namespace::None,
+                name: "b".to_string(),
+                attributes: vec![],
+                children: vec![],
+                text: String::new(),
+            }]
+        );
+        assert_eq!(elements[2].namespace, Namespace::Unknown("y".to_string()));
+
+        assert!(matches!(read_all("<a/><a/>"), Err(XmlError::Syntax(_))));
+        assert!(matches!(read_all("<a><b>"), Err(XmlError::Syntax(_))));
+        assert!(matches!(read_all("<a><b></a>"), Err(XmlError::Syntax(_))));
+        assert!(read_all("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn tuples() {
+        assert_eq!(
+            parse_tuples(" 1,2\n\t3.5,-4,100 "),
+            Some(vec![(1., 2.), (3.5, -4.)])
+        );
+        assert_eq!(parse_tuples(""), Some(vec![]));
+        for invalid in &["1", "1,", "1,2,3,4", "1,2,", "1,a", "1 , 2"] {
+            assert_eq!(parse_tuples(invalid), None, "{}", invalid);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#![allow(clippy::excessive_precision)]
 #[macro_use]
 extern crate criterion;
 extern crate geo;
@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::algorithm::affine_ops::{AffineOps, AffineTransform};
+use geo::algorithm::simd;
+use geo::prelude::*;
+use geo::{CoordBuffer, LineString};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+    let line_string = LineString::<f64>::from(points);
+    let transform = AffineTransform::identity().rotate(30.).translate(1., 2.);
+
+    c.bench_function("affine transform f64", |bencher| {
+        let mut line_string = line_string.clone();
+        bencher.iter(|| line_string.affine_transform_mut(&transform));
+    });
+
+    c.bench_function("simd affine transform f64", |bencher| {
+        let mut line_string = line_string.clone();
+        bencher.iter(|| simd::affine_transform(&mut line_string.0, &transform));
+    });
+
+    c.bench_function("bounding rect f64", |bencher| {
+        bencher.iter(|| line_string.bounding_rect());
+    });
+
+    c.bench_function("simd bounding rect f64", |bencher| {
+        bencher.iter(|| simd::bounding_rect(&line_string.0));
+    });
+
+    c.bench_function("euclidean length f64", |bencher| {
+        bencher.iter(|| line_string.euclidean_length());
+    });
+
+    c.bench_function("simd euclidean length f64", |bencher| {
+        bencher.iter(|| simd::euclidean_length(&line_string.0));
+    });
+
+    let buffer = CoordBuffer::from(&line_string);
+
+    c.bench_function("simd affine transform buffer f64", |bencher| {
+        let mut buffer = buffer.clone();
+        bencher.iter(|| simd::affine_transform_buffer(&mut buffer, &transform));
+    });
+
+    c.bench_function("simd bounding rect buffer f64", |bencher| {
+        bencher.iter(|| simd::bounding_rect_buffer(&buffer));
+    });
+
+    c.bench_function("simd euclidean length buffer f64", |bencher| {
+        bencher.iter(|| simd::euclidean_length_buffer(&buffer));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
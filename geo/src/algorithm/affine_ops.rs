@@ -0,0 +1,280 @@
+use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use crate::{Coordinate, CoordinateType, Point};
+use num_traits::Float;
+
+/// A 2D affine transformation: any combination of translation, rotation, scaling, and skewing,
+/// stored as the matrix
+///
+/// ```text
+/// | a b xoff |
+/// | d e yoff |
+/// | 0 0 1    |
+/// ```
+///
+/// so that a coordinate `(x, y)` is transformed to `(a * x + b * y + xoff, d * x + e * y + yoff)`.
+///
+/// Transforms are built up from the identity, each method applying a further transformation
+/// after the ones so far, so a whole chain of them is applied to a geometry in a single pass over
+/// its coordinates. Rotations, scales, and skews are about the origin; to transform about a
+/// point, translate the point to the origin first, and back afterwards, or use the
+/// [`RotateCentroid`](../rotate/trait.RotateCentroid.html), [`Scale`](../scale/trait.Scale.html), and
+/// [`Skew`](../skew/trait.Skew.html) traits.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::algorithm::affine_ops::{AffineOps, AffineTransform};
+/// use geo::{line_string, Coordinate};
+///
+/// // rotate by a quarter turn, move right, then stretch horizontally
+/// let transform = AffineTransform::identity()
+///     .rotate(90.)
+///     .translate(1., 0.)
+///     .scale(2., 1.);
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+/// let transformed = line_string.affine_transform(&transform);
+///
+/// assert_eq!(transformed.0[0], Coordinate { x: 2., y: 0. });
+/// assert_relative_eq!(transformed.0[1].x, 2., epsilon = 1e-12);
+/// assert_relative_eq!(transformed.0[1].y, 1., epsilon = 1e-12);
+/// assert_relative_eq!(transformed.0[2].x, 0., epsilon = 1e-12);
+/// assert_relative_eq!(transformed.0[2].y, 1., epsilon = 1e-12);
+///
+/// // undo it again
+/// let restored = transformed.affine_transform(&transform.inverse().unwrap());
+/// assert_relative_eq!(restored.0[2].x, 1., epsilon = 1e-12);
+/// assert_relative_eq!(restored.0[2].y, 1., epsilon = 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AffineTransform<T: CoordinateType>([[T; 3]; 2]);
+
+impl<T: CoordinateType> AffineTransform<T> {
+    /// The transform which leaves every coordinate where it is.
+    pub fn identity() -> Self {
+        Self::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+        )
+    }
+
+    /// A transform from the entries of its matrix.
+    pub fn new(a: T, b: T, xoff: T, d: T, e: T, yoff: T) -> Self {
+        AffineTransform([[a, b, xoff], [d, e, yoff]])
+    }
+
+    /// The entries of the matrix, as `[a, b, xoff, d, e, yoff]`.
+    pub fn coefficients(&self) -> [T; 6] {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        [a, b, xoff, d, e, yoff]
+    }
+
+    /// Whether this is the identity, which leaves every coordinate where it is.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// This transform, followed by `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        let [[oa, ob, oxoff], [od, oe, oyoff]] = other.0;
+        Self::new(
+            oa * a + ob * d,
+            oa * b + ob * e,
+            oa * xoff + ob * yoff + oxoff,
+            od * a + oe * d,
+            od * b + oe * e,
+            od * xoff + oe * yoff + oyoff,
+        )
+    }
+
+    /// This transform, followed by a translation by `xoff` and `yoff`.
+    pub fn translate(&self, xoff: T, yoff: T) -> Self {
+        self.compose(&Self::new(
+            T::one(),
+            T::zero(),
+            xoff,
+            T::zero(),
+            T::one(),
+            yoff,
+        ))
+    }
+
+    /// This transform, followed by scaling by `x` horizontally and `y` vertically, about the
+    /// origin.
+    pub fn scale(&self, x: T, y: T) -> Self {
+        self.compose(&Self::new(x, T::zero(), T::zero(), T::zero(), y, T::zero()))
+    }
+
+    /// Transform a single coordinate.
+    pub fn apply(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        Coordinate {
+            x: a * coord.x + b * coord.y + xoff,
+            y: d * coord.x + e * coord.y + yoff,
+        }
+    }
+}
+
+impl<T: Float> AffineTransform<T> {
+    /// This transform, followed by a rotation by `degrees` about the origin. Positive angles
+    /// are counter-clockwise.
+    pub fn rotate(&self, degrees: T) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        self.compose(&Self::new(cos, -sin, T::zero(), sin, cos, T::zero()))
+    }
+
+    /// This transform, followed by a rotation by `degrees` about `origin`.
+    pub fn rotate_around_point(&self, degrees: T, origin: Point<T>) -> Self {
+        self.translate(-origin.x(), -origin.y())
+            .rotate(degrees)
+            .translate(origin.x(), origin.y())
+    }
+
+    /// This transform, followed by skewing horizontal lines by `x_degrees` and vertical lines by
+    /// `y_degrees`, about the origin. Skewing by positive angles tilts vertical lines to the
+    /// right, and horizontal lines upwards.
+    pub fn skew(&self, x_degrees: T, y_degrees: T) -> Self {
+        let (x, y) = (x_degrees.to_radians().tan(), y_degrees.to_radians().tan());
+        self.compose(&Self::new(T::one(), x, T::zero(), y, T::one(), T::zero()))
+    }
+
+    /// The transform which undoes this transform, or `None` if it collapses the plane onto a
+    /// line or a point.
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, xoff], [d, e, yoff]] = self.0;
+        let determinant = a * e - b * d;
+        if determinant == T::zero() || !determinant.is_finite() {
+            return None;
+        }
+        let (a, b, d, e) = (
+            e / determinant,
+            -b / determinant,
+            -d / determinant,
+            a / determinant,
+        );
+        Some(Self::new(
+            a,
+            b,
+            -(a * xoff + b * yoff),
+            d,
+            e,
+            -(d * xoff + e * yoff),
+        ))
+    }
+}
+
+impl<T: CoordinateType> Default for AffineTransform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Apply an [`AffineTransform`](struct.AffineTransform.html) to every coordinate of a geometry.
+pub trait AffineOps<T: CoordinateType> {
+    /// Return the geometry, transformed by `transform`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::affine_ops::{AffineOps, AffineTransform};
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 0, y: 2)];
+    /// let transform = AffineTransform::identity().scale(3, 1).translate(1, 1);
+    ///
+    /// assert_eq!(
+    ///     polygon.affine_transform(&transform),
+    ///     polygon![(x: 1, y: 1), (x: 7, y: 1), (x: 1, y: 3)]
+    /// );
+    /// ```
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self;
+
+    /// Transform the geometry by `transform`, in place.
+    fn affine_transform_mut(&mut self, transform: &AffineTransform<T>);
+}
+
+impl<T, G> AffineOps<T> for G
+where
+    T: CoordinateType,
+    G: MapCoords<T, T, Output = G> + MapCoordsInplace<T>,
+{
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self {
+        self.map_coords(|&(x, y)| {
+            let coord = transform.apply(Coordinate { x, y });
+            (coord.x, coord.y)
+        })
+    }
+
+    fn affine_transform_mut(&mut self, transform: &AffineTransform<T>) {
+        self.map_coords_inplace(|&(x, y)| {
+            let coord = transform.apply(Coordinate { x, y });
+            (coord.x, coord.y)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::rotate::RotatePoint;
+    use crate::algorithm::translate::Translate;
+    use crate::{line_string, point};
+
+    #[test]
+    fn matches_sequential_transforms() {
+        let line_string = line_string![(x: 1., y: 2.), (x: 5.5, y: -1.), (x: -3., y: 4.)];
+        let origin = point!(x: 2., y: 1.);
+        let transform = AffineTransform::identity()
+            .translate(1., 2.)
+            .rotate_around_point(30., origin)
+            .scale(2., 0.5);
+        let sequential = line_string
+            .translate(1., 2.)
+            .rotate_around_point(30., origin)
+            .map_coords(|&(x, y)| (x * 2., y * 0.5));
+        let transformed = line_string.affine_transform(&transform);
+        for (a, b) in transformed.0.iter().zip(&sequential.0) {
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-12);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-12);
+        }
+        let mut in_place = line_string;
+        in_place.affine_transform_mut(&transform);
+        assert_eq!(in_place, transformed);
+    }
+
+    #[test]
+    fn skew() {
+        let skew_x = AffineTransform::identity().skew(45., 0.);
+        let coord = skew_x.apply(Coordinate { x: 2., y: 1. });
+        assert_relative_eq!(coord.x, 3.);
+        assert_relative_eq!(coord.y, 1.);
+        let skew_y = AffineTransform::identity().skew(0., 45.);
+        let coord = skew_y.apply(Coordinate { x: 2., y: 1. });
+        assert_relative_eq!(coord.x, 2.);
+        assert_relative_eq!(coord.y, 3.);
+    }
+
+    #[test]
+    fn compose_and_inverse() {
+        let transform = AffineTransform::new(2., 1., 3., -1., 4., 5.);
+        assert!(AffineTransform::identity().compose(&transform) == transform);
+        assert!(transform.compose(&AffineTransform::identity()) == transform);
+        let round_trip = transform.compose(&transform.inverse().unwrap());
+        for (value, identity) in round_trip
+            .coefficients()
+            .iter()
+            .zip(&AffineTransform::identity().coefficients())
+        {
+            assert_relative_eq!(value, identity, epsilon = 1e-12);
+        }
+        assert_eq!(AffineTransform::identity().scale(1., 0.).inverse(), None);
+        assert!(AffineTransform::<f64>::default().is_identity());
+    }
+}
@@ -1,9 +1,12 @@
-use crate::{CoordinateType, Line, LineString, MultiPolygon, Polygon, Rect, Triangle};
+use crate::{
+    CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 use num_traits::Float;
 
 use crate::algorithm::winding_order::twice_signed_ring_area;
 
-/// Signed planar area of a geometry.
+/// Signed and unsigned planar area of a geometry.
 ///
 /// # Examples
 ///
@@ -19,19 +22,32 @@ use crate::algorithm::winding_order::twice_signed_ring_area;
 ///     (x: 0., y: 0.),
 /// ];
 ///
-/// assert_eq!(polygon.area(), 30.);
+/// assert_eq!(polygon.signed_area(), 30.);
+/// assert_eq!(polygon.unsigned_area(), 30.);
 ///
 /// polygon.exterior_mut(|line_string| {
 ///     line_string.0.reverse();
 /// });
 ///
-/// assert_eq!(polygon.area(), -30.);
+/// assert_eq!(polygon.signed_area(), -30.);
+/// assert_eq!(polygon.unsigned_area(), 30.);
 /// ```
 pub trait Area<T>
 where
     T: CoordinateType,
 {
-    fn area(&self) -> T;
+    /// The area, which is negative for a `Polygon` (or `Triangle`) whose exterior is wound
+    /// clockwise. Holes are always subtracted, whatever their winding order.
+    fn signed_area(&self) -> T;
+
+    /// The area, which is never negative
+    fn unsigned_area(&self) -> T;
+
+    /// The signed area, as returned by `signed_area`
+    #[deprecated(note = "use signed_area")]
+    fn area(&self) -> T {
+        self.signed_area()
+    }
 }
 
 // Calculation of simple (no interior holes) Polygon area
@@ -42,25 +58,45 @@ where
     twice_signed_ring_area(linestring) / (T::one() + T::one())
 }
 
-impl<T> Area<T> for Line<T>
-where
-    T: CoordinateType,
-{
-    fn area(&self) -> T {
-        T::zero()
-    }
+macro_rules! zero_area_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T> Area<T> for $type<T>
+            where
+                T: CoordinateType,
+            {
+                fn signed_area(&self) -> T {
+                    T::zero()
+                }
+
+                fn unsigned_area(&self) -> T {
+                    T::zero()
+                }
+            }
+        )*
+    };
 }
 
+zero_area_impl!(Point, Line, LineString, MultiPoint, MultiLineString);
+
 impl<T> Area<T> for Polygon<T>
 where
     T: Float,
 {
-    fn area(&self) -> T {
-        self.interiors()
-            .iter()
-            .fold(get_linestring_area(self.exterior()), |total, next| {
-                total - get_linestring_area(next)
-            })
+    fn signed_area(&self) -> T {
+        let exterior = get_linestring_area(self.exterior());
+        let area = self.interiors().iter().fold(exterior.abs(), |total, next| {
+            total - get_linestring_area(next).abs()
+        });
+        if exterior < T::zero() {
+            -area
+        } else {
+            area
+        }
+    }
+
+    fn unsigned_area(&self) -> T {
+        self.signed_area().abs()
     }
 }
 
@@ -68,10 +104,16 @@ impl<T> Area<T> for MultiPolygon<T>
 where
     T: Float,
 {
-    fn area(&self) -> T {
+    fn signed_area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.signed_area())
+    }
+
+    fn unsigned_area(&self) -> T {
         self.0
             .iter()
-            .fold(T::zero(), |total, next| total + next.area())
+            .fold(T::zero(), |total, next| total + next.unsigned_area())
     }
 }
 
@@ -79,39 +121,102 @@ impl<T> Area<T> for Rect<T>
 where
     T: CoordinateType,
 {
-    fn area(&self) -> T {
+    fn signed_area(&self) -> T {
         self.width() * self.height()
     }
+
+    fn unsigned_area(&self) -> T {
+        self.signed_area()
+    }
 }
 
 impl<T> Area<T> for Triangle<T>
 where
     T: Float,
 {
-    fn area(&self) -> T {
+    fn signed_area(&self) -> T {
         self.to_lines()
             .iter()
             .fold(T::zero(), |total, line| total + line.determinant())
             / (T::one() + T::one())
     }
+
+    fn unsigned_area(&self) -> T {
+        self.signed_area().abs()
+    }
+}
+
+impl<T> Area<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn signed_area(&self) -> T {
+        match self {
+            Geometry::Point(g) => g.signed_area(),
+            Geometry::Line(g) => g.signed_area(),
+            Geometry::LineString(g) => g.signed_area(),
+            Geometry::Polygon(g) => g.signed_area(),
+            Geometry::MultiPoint(g) => g.signed_area(),
+            Geometry::MultiLineString(g) => g.signed_area(),
+            Geometry::MultiPolygon(g) => g.signed_area(),
+            Geometry::GeometryCollection(g) => g.signed_area(),
+            Geometry::Rect(g) => g.signed_area(),
+            Geometry::Triangle(g) => g.signed_area(),
+        }
+    }
+
+    fn unsigned_area(&self) -> T {
+        match self {
+            Geometry::Point(g) => g.unsigned_area(),
+            Geometry::Line(g) => g.unsigned_area(),
+            Geometry::LineString(g) => g.unsigned_area(),
+            Geometry::Polygon(g) => g.unsigned_area(),
+            Geometry::MultiPoint(g) => g.unsigned_area(),
+            Geometry::MultiLineString(g) => g.unsigned_area(),
+            Geometry::MultiPolygon(g) => g.unsigned_area(),
+            Geometry::GeometryCollection(g) => g.unsigned_area(),
+            Geometry::Rect(g) => g.unsigned_area(),
+            Geometry::Triangle(g) => g.unsigned_area(),
+        }
+    }
+}
+
+impl<T> Area<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn signed_area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.signed_area())
+    }
+
+    fn unsigned_area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.unsigned_area())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::algorithm::area::Area;
-    use crate::{line_string, polygon, Coordinate, Line, MultiPolygon, Polygon, Rect, Triangle};
+    use crate::{
+        line_string, polygon, Coordinate, Geometry, GeometryCollection, Line, MultiPolygon, Point,
+        Polygon, Rect, Triangle,
+    };
 
     // Area of the polygon
     #[test]
     fn area_empty_polygon_test() {
         let poly: Polygon<f32> = polygon![];
-        assert_relative_eq!(poly.area(), 0.);
+        assert_relative_eq!(poly.signed_area(), 0.);
     }
 
     #[test]
     fn area_one_point_polygon_test() {
         let poly = polygon![(x: 1., y: 0.)];
-        assert_relative_eq!(poly.area(), 0.);
+        assert_relative_eq!(poly.signed_area(), 0.);
     }
     #[test]
     fn area_polygon_test() {
@@ -122,16 +227,20 @@ mod test {
             (x: 0., y: 6.),
             (x: 0., y: 0.)
         ];
-        assert_relative_eq!(polygon.area(), 30.);
+        assert_relative_eq!(polygon.signed_area(), 30.);
+        let clockwise = Polygon::new(polygon.exterior().0.iter().rev().cloned().collect(), vec![]);
+        #[allow(deprecated)]
+        let area = clockwise.area();
+        assert_relative_eq!(area, -30.);
     }
     #[test]
     fn rectangle_test() {
         let rect1: Rect<f32> =
             Rect::new(Coordinate { x: 10., y: 30. }, Coordinate { x: 20., y: 40. });
-        assert_relative_eq!(rect1.area(), 100.);
+        assert_relative_eq!(rect1.signed_area(), 100.);
 
         let rect2: Rect<i32> = Rect::new(Coordinate { x: 10, y: 30 }, Coordinate { x: 20, y: 40 });
-        assert_eq!(rect2.area(), 100);
+        assert_eq!(rect2.signed_area(), 100);
     }
     #[test]
     fn area_polygon_inner_test() {
@@ -160,7 +269,7 @@ mod test {
                 ],
             ],
         ];
-        assert_relative_eq!(poly.area(), 98.);
+        assert_relative_eq!(poly.signed_area(), 98.);
     }
     #[test]
     fn area_multipolygon_test() {
@@ -186,13 +295,13 @@ mod test {
             (x: 5., y: 5.)
         ];
         let mpoly = MultiPolygon(vec![poly0, poly1, poly2]);
-        assert_relative_eq!(mpoly.area(), 102.);
-        assert_relative_eq!(mpoly.area(), 102.);
+        assert_relative_eq!(mpoly.signed_area(), 102.);
+        assert_relative_eq!(mpoly.unsigned_area(), 102.);
     }
     #[test]
     fn area_line_test() {
         let line1 = Line::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 1.0, y: 1.0 });
-        assert_relative_eq!(line1.area(), 0.);
+        assert_relative_eq!(line1.signed_area(), 0.);
     }
 
     #[test]
@@ -202,13 +311,36 @@ mod test {
             Coordinate { x: 1.0, y: 0.0 },
             Coordinate { x: 0.0, y: 1.0 },
         );
-        assert_relative_eq!(triangle.area(), 0.5);
+        assert_relative_eq!(triangle.signed_area(), 0.5);
 
         let triangle = Triangle(
             Coordinate { x: 0.0, y: 0.0 },
             Coordinate { x: 0.0, y: 1.0 },
             Coordinate { x: 1.0, y: 0.0 },
         );
-        assert_relative_eq!(triangle.area(), -0.5);
+        assert_relative_eq!(triangle.signed_area(), -0.5);
+        assert_relative_eq!(triangle.unsigned_area(), 0.5);
+    }
+
+    #[test]
+    fn area_polygon_holes_any_winding_test() {
+        let hole_ccw = line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)];
+        let mut hole_cw = hole_ccw.clone();
+        hole_cw.0.reverse();
+        let exterior = line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)];
+        let poly = Polygon::new(exterior, vec![hole_ccw, hole_cw]);
+        assert_relative_eq!(poly.signed_area(), -15.);
+        assert_relative_eq!(poly.unsigned_area(), 15.);
+    }
+
+    #[test]
+    fn area_geometry_collection_test() {
+        let gc = GeometryCollection(vec![
+            Geometry::Rect(Rect::new((0., 0.), (2., 2.))),
+            Geometry::Point(Point::new(5., 5.)),
+            Geometry::Triangle(Triangle((0., 0.).into(), (0., 1.).into(), (1., 0.).into())),
+        ]);
+        assert_relative_eq!(gc.signed_area(), 3.5);
+        assert_relative_eq!(gc.unsigned_area(), 4.5);
     }
 }
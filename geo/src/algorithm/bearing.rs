@@ -5,7 +5,6 @@ use num_traits::Float;
 ///
 /// Bullock, R.: Great Circle Distances and Bearings Between Two Locations, 2007.
 /// (https://dtcenter.org/met/users/docs/write_ups/gc_simple.pdf)
-
 pub trait Bearing<T: Float> {
     /// Returns the bearing to another Point in degrees, where North is 0° and East is 90°.
     ///
@@ -0,0 +1,535 @@
+use crate::algorithm::parts::ToParts;
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::algorithm::relate::topology_graph::{cmp_coords, ring_position, TopologyGraph};
+use crate::algorithm::relate::CoordPos;
+use crate::algorithm::winding_order::twice_signed_ring_area;
+use crate::utils::cmp_values;
+use crate::{
+    Coordinate, CoordinateType, FractionalCoordinateType, LineString, MultiPolygon, Polygon,
+};
+use std::cmp::Ordering;
+
+/// Boolean operations on the areas of `Polygon`s and `MultiPolygon`s.
+///
+/// The rings of both geometries are noded against each other, so that edges only meet at their
+/// endpoints, and coincident edges are merged. Each edge is then kept if the result is inside on
+/// exactly one of its sides, and the kept edges are traced into rings. Shared edges and
+/// vertices are therefore handled exactly, and as the computation only uses the coordinate
+/// type's own arithmetic and robust orientation predicates, it's deterministic for fixed-point
+/// coordinates too. Vertices closer to each other, or to an edge, than about 10<sup>-12</sup>
+/// of the magnitude of the coordinates are snapped together, so that the coordinates computed
+/// by earlier operations don't leave slivers. Polygons with a NaN or infinite coordinate are
+/// treated as empty.
+///
+/// The operations are only implemented for a
+/// [`FractionalCoordinateType`](../../trait.FractionalCoordinateType.html), as the points at
+/// which edges cross are generally between the integers, so can't be represented with integer
+/// coordinates. Convert those to floats, or fixed-point numbers, first:
+///
+/// ```compile_fail
+/// use geo::algorithm::bool_ops::BooleanOps;
+/// use geo::polygon;
+///
+/// let a = polygon![(x: 0, y: 0), (x: 3, y: 1), (x: 0, y: 3), (x: 0, y: 0)];
+/// let b = polygon![(x: 1, y: 0), (x: 3, y: 3), (x: 0, y: 2), (x: 1, y: 0)];
+/// a.intersection(&b);
+/// ```
+///
+/// The result is a `MultiPolygon` whose exteriors are wound counter-clockwise and whose
+/// interiors are wound clockwise, without collinear vertices. Polygons whose exteriors have no
+/// area are treated as empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::bool_ops::BooleanOps;
+/// use geo::polygon;
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)];
+/// let b = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.), (x: 2., y: 2.)];
+///
+/// assert_eq!(a.union(&b).unsigned_area(), 28.);
+/// assert_eq!(a.intersection(&b).unsigned_area(), 4.);
+/// assert_eq!(a.difference(&b).unsigned_area(), 12.);
+/// assert_eq!(a.xor(&b).unsigned_area(), 24.);
+///
+/// // the intersection is the square from (2, 2) to (4, 4)
+/// let intersection = a.intersection(&b);
+/// assert_eq!(
+///     intersection.0[0],
+///     polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.)]
+/// );
+/// ```
+pub trait BooleanOps<T, Rhs = Self>
+where
+    T: FractionalCoordinateType,
+{
+    /// The area inside both `self` and `other`
+    fn intersection(&self, other: &Rhs) -> MultiPolygon<T>;
+
+    /// The area inside `self` or `other`
+    fn union(&self, other: &Rhs) -> MultiPolygon<T>;
+
+    /// The area inside `self` but not `other`
+    fn difference(&self, other: &Rhs) -> MultiPolygon<T>;
+
+    /// The area inside exactly one of `self` and `other`
+    fn xor(&self, other: &Rhs) -> MultiPolygon<T>;
+}
+
+macro_rules! impl_boolean_ops {
+    ($(($type:ident, $rhs:ident)),*) => {
+        $(
+            impl<T> BooleanOps<T, $rhs<T>> for $type<T>
+            where
+                T: FractionalCoordinateType,
+            {
+                fn intersection(&self, other: &$rhs<T>) -> MultiPolygon<T> {
+                    overlay(self, other, |a, b| a && b)
+                }
+
+                fn union(&self, other: &$rhs<T>) -> MultiPolygon<T> {
+                    overlay(self, other, |a, b| a || b)
+                }
+
+                fn difference(&self, other: &$rhs<T>) -> MultiPolygon<T> {
+                    overlay(self, other, |a, b| a && !b)
+                }
+
+                fn xor(&self, other: &$rhs<T>) -> MultiPolygon<T> {
+                    overlay(self, other, |a, b| a != b)
+                }
+            }
+        )*
+    };
+}
+
+impl_boolean_ops!(
+    (Polygon, Polygon),
+    (Polygon, MultiPolygon),
+    (MultiPolygon, Polygon),
+    (MultiPolygon, MultiPolygon)
+);
+
+/// The area in which `inside` holds for the positions relative to `a` and `b`
+fn overlay<T, A, B>(a: &A, b: &B, inside: fn(bool, bool) -> bool) -> MultiPolygon<T>
+where
+    T: CoordinateType,
+    A: ToParts<T>,
+    B: ToParts<T>,
+{
+    let graph = TopologyGraph::snapped(a.parts(), b.parts());
+
+    // the edges of the result, directed so that the result is on their left
+    let mut edges = vec![];
+    for edge in graph.labelled_edges() {
+        let left = inside(edge.left_inside[0], edge.left_inside[1]);
+        let right = inside(edge.right_inside[0], edge.right_inside[1]);
+        if left && !right {
+            edges.push((edge.start, edge.end));
+        } else if right && !left {
+            edges.push((edge.end, edge.start));
+        }
+    }
+    edges.sort_by(|a, b| cmp_coords(&a.0, &b.0).then(cmp_coords(&a.1, &b.1)));
+
+    assemble_polygons(trace_rings(&edges))
+}
+
+/// Assemble rings into polygons: those wound counter-clockwise are exteriors, and those wound
+/// clockwise are interiors of the smallest exterior around them. Interiors outside every
+/// exterior, and rings without area, are dropped
+pub(crate) fn assemble_polygons<T>(rings: Vec<LineString<T>>) -> MultiPolygon<T>
+where
+    T: CoordinateType,
+{
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for ring in rings {
+        let area = twice_signed_ring_area(&ring);
+        if area > T::zero() {
+            shells.push((ring, area));
+        } else if area < T::zero() {
+            holes.push(ring);
+        }
+    }
+
+    let mut polygons: Vec<(LineString<T>, T, Vec<LineString<T>>)> = shells
+        .into_iter()
+        .map(|(ring, area)| (ring, area, vec![]))
+        .collect();
+    for hole in holes {
+        // the result is just outside the hole, so the hole belongs to the smallest shell
+        // around its edges
+        let (a, b) = (hole.0[0], hole.0[1]);
+        let two = T::one() + T::one();
+        let mid = Coordinate {
+            x: (a.x + b.x) / two,
+            y: (a.y + b.y) / two,
+        };
+        let shell = polygons
+            .iter_mut()
+            .filter(|(shell, _, _)| ring_position(shell, mid) == CoordPos::Inside)
+            .min_by(|x, y| cmp_values(x.1, y.1));
+        if let Some((_, _, interiors)) = shell {
+            interiors.push(hole);
+        }
+    }
+    MultiPolygon(
+        polygons
+            .into_iter()
+            .map(|(exterior, _, interiors)| Polygon::new(exterior, interiors))
+            .collect(),
+    )
+}
+
+/// Join directed edges, sorted by their starting coordinate, into closed rings, turning as far
+/// left as possible at each node so that rings touching at a vertex are kept apart
+fn trace_rings<T>(edges: &[(Coordinate<T>, Coordinate<T>)]) -> Vec<LineString<T>>
+where
+    T: CoordinateType,
+{
+    let mut visited = vec![false; edges.len()];
+    let mut rings = vec![];
+    for first in 0..edges.len() {
+        if visited[first] {
+            continue;
+        }
+        let start = edges[first].0;
+        let mut coords = vec![start];
+        let mut current = first;
+        let closed = loop {
+            visited[current] = true;
+            let (from, to) = edges[current];
+            coords.push(to);
+            if to == start {
+                break true;
+            }
+            // the unvisited edges leaving `to`
+            let begin = edges.partition_point(|e| cmp_coords(&e.0, &to) == Ordering::Less);
+            let end = edges.partition_point(|e| cmp_coords(&e.0, &to) != Ordering::Greater);
+            let next = (begin..end)
+                .filter(|i| !visited[*i])
+                .min_by(|i, j| clockwise_cmp(to, from, edges[*i].1, edges[*j].1));
+            match next {
+                Some(next) => current = next,
+                None => break false,
+            }
+        };
+        if closed {
+            rings.extend(
+                split_at_repeated_vertices(coords)
+                    .into_iter()
+                    .map(|ring| LineString(without_collinear_vertices(ring))),
+            );
+        }
+    }
+    rings
+}
+
+/// Split a closed ring which passes through a vertex more than once, as one does where a hole
+/// touches its exterior, into simple rings
+pub(crate) fn split_at_repeated_vertices<T>(coords: Vec<Coordinate<T>>) -> Vec<Vec<Coordinate<T>>>
+where
+    T: CoordinateType,
+{
+    let mut rings = vec![];
+    let mut path: Vec<Coordinate<T>> = Vec::with_capacity(coords.len());
+    for coord in coords {
+        if let Some(index) = path.iter().position(|c| *c == coord) {
+            let mut ring = path.split_off(index);
+            ring.push(coord);
+            if ring.len() > 3 {
+                rings.push(ring);
+            }
+        }
+        path.push(coord);
+    }
+    rings
+}
+
+/// Compare the clockwise angles from the ray from `v` through `u` to the rays through `a` and
+/// `b`, a direction along the ray itself coming last
+pub(crate) fn clockwise_cmp<T>(
+    v: Coordinate<T>,
+    u: Coordinate<T>,
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+) -> Ordering
+where
+    T: CoordinateType,
+{
+    let half = |w: Coordinate<T>| match orient2d(v, u, w) {
+        Orientation::Clockwise => 0,
+        Orientation::CounterClockwise => 2,
+        // opposite to the ray if the dot product is negative
+        Orientation::Collinear => {
+            if (u.x - v.x) * (w.x - v.x) + (u.y - v.y) * (w.y - v.y) < T::zero() {
+                1
+            } else {
+                3
+            }
+        }
+    };
+    half(a).cmp(&half(b)).then_with(|| match orient2d(v, a, b) {
+        Orientation::Clockwise => Ordering::Less,
+        Orientation::CounterClockwise => Ordering::Greater,
+        Orientation::Collinear => Ordering::Equal,
+    })
+}
+
+/// Remove the vertices of a closed ring at which it continues in a straight line
+fn without_collinear_vertices<T>(mut coords: Vec<Coordinate<T>>) -> Vec<Coordinate<T>>
+where
+    T: CoordinateType,
+{
+    coords.pop();
+    let mut kept: Vec<Coordinate<T>> = Vec::with_capacity(coords.len());
+    let len = coords.len();
+    for (index, coord) in coords.iter().enumerate() {
+        let previous = coords[(index + len - 1) % len];
+        let next = coords[(index + 1) % len];
+        if orient2d(previous, *coord, next) != Orientation::Collinear {
+            kept.push(*coord);
+        }
+    }
+    if let Some(first) = kept.first().copied() {
+        kept.push(first);
+    }
+    kept
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::polygon;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+            (x: x, y: y),
+        ]
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let a = square(0., 0., 4.);
+        let b = square(2., 2., 4.);
+        let union = a.union(&b);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior().0.len(), 9);
+        assert_eq!(union.unsigned_area(), 28.);
+        assert_eq!(b.difference(&a).unsigned_area(), 12.);
+        let xor = a.xor(&b);
+        assert_eq!(xor.0.len(), 2);
+        assert_eq!(xor.unsigned_area(), 24.);
+    }
+
+    #[test]
+    fn shared_edges() {
+        let a = square(0., 0., 2.);
+        let b = square(2., 0., 2.);
+        // merged into one rectangle, without the vertices of the shared edge
+        let union = a.union(&b);
+        assert_eq!(
+            union,
+            MultiPolygon(vec![polygon![
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 2.),
+                (x: 0., y: 2.),
+                (x: 0., y: 0.),
+            ]])
+        );
+        assert!(a.intersection(&b).0.is_empty());
+        assert_eq!(a.difference(&b), MultiPolygon(vec![a.clone()]));
+
+        // identical polygons
+        assert_eq!(a.union(&a), MultiPolygon(vec![a.clone()]));
+        assert_eq!(a.intersection(&a), MultiPolygon(vec![a.clone()]));
+        assert!(a.difference(&a).0.is_empty());
+        assert!(a.xor(&a).0.is_empty());
+    }
+
+    #[test]
+    fn touching_at_a_vertex() {
+        let a = square(0., 0., 1.);
+        let b = square(1., 1., 1.);
+        let union = a.union(&b);
+        assert_eq!(union.0.len(), 2);
+        assert_eq!(union.unsigned_area(), 2.);
+        assert!(a.intersection(&b).0.is_empty());
+    }
+
+    #[test]
+    fn holes() {
+        let outer = square(0., 0., 6.);
+        let inner = square(2., 2., 2.);
+        let frame = outer.difference(&inner);
+        assert_eq!(frame.0.len(), 1);
+        assert_eq!(frame.0[0].interiors().len(), 1);
+        assert_eq!(frame.unsigned_area(), 32.);
+
+        // filling the hole again
+        assert_eq!(frame.union(&inner), MultiPolygon(vec![outer.clone()]));
+        // a square across the hole's edge
+        let across = square(3., 3., 2.);
+        assert_eq!(frame.intersection(&across).unsigned_area(), 3.);
+        assert_eq!(frame.union(&across).unsigned_area(), 33.);
+        // a square within the hole
+        assert!(frame.intersection(&square(2.5, 2.5, 1.)).0.is_empty());
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let a = MultiPolygon(vec![square(0., 0., 2.), square(4., 0., 2.)]);
+        let bridge = square(1., 0.5, 4.);
+        let union = a.union(&bridge);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.unsigned_area(), 8. + 16. - 3.);
+        assert_eq!(bridge.intersection(&a).unsigned_area(), 3.);
+        assert_eq!(a.xor(&a), MultiPolygon(vec![]));
+        assert_eq!(a.union(&MultiPolygon(vec![])).unsigned_area(), 8.);
+    }
+
+    #[test]
+    fn crossing_diagonals() {
+        let diamond = polygon![
+            (x: 2., y: -1.),
+            (x: 5., y: 2.),
+            (x: 2., y: 5.),
+            (x: -1., y: 2.),
+            (x: 2., y: -1.),
+        ];
+        let a = square(0., 0., 4.);
+        let intersection = a.intersection(&diamond);
+        assert_eq!(intersection.0[0].exterior().0.len(), 9);
+        assert_eq!(intersection.unsigned_area(), 16. - 2.);
+        assert_eq!(
+            a.union(&diamond).unsigned_area(),
+            a.unsigned_area() + diamond.unsigned_area() - 14.
+        );
+    }
+
+    #[test]
+    fn nearly_coincident_vertices() {
+        use crate::algorithm::validation::Validation;
+        let a = square(0., 0., 2.);
+        // triangles whose tip is a rounding error either side of the right edge of the square
+        for tip in [2. - f64::EPSILON, 2. + 2. * f64::EPSILON].iter() {
+            let triangle =
+                polygon![(x: *tip, y: 1.), (x: 4., y: 0.), (x: 4., y: 2.), (x: *tip, y: 1.)];
+            let union = a.union(&triangle);
+            assert!(union.is_valid());
+            assert_eq!(union.0.len(), 2);
+            assert_relative_eq!(union.unsigned_area(), 6., epsilon = 1e-12);
+        }
+
+        // squares whose shared corners are a rounding error apart
+        let b = polygon![
+            (x: 2. + 2. * f64::EPSILON, y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2. + 2. * f64::EPSILON, y: 0.),
+        ];
+        let union = a.union(&b);
+        assert!(union.is_valid());
+        assert_eq!(union.0.len(), 1);
+        assert_relative_eq!(union.unsigned_area(), 8., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn non_finite_vertices() {
+        let a = square(0., 0., 2.);
+        let nan = polygon![(x: 1., y: 1.), (x: f64::NAN, y: 5.), (x: 3., y: 3.), (x: 1., y: 1.)];
+        let infinite = polygon![(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: f64::INFINITY)];
+        for broken in [nan, infinite].iter() {
+            assert_eq!(a.union(broken), MultiPolygon(vec![a.clone()]));
+            assert_eq!(broken.union(&a), MultiPolygon(vec![a.clone()]));
+            assert!(a.intersection(broken).0.is_empty());
+            assert!(broken.difference(&a).0.is_empty());
+        }
+    }
+
+    #[test]
+    fn integer_vertices() {
+        use crate::algorithm::map_coords::MapCoords;
+        // triangles with integer vertices, whose edges cross between the integers
+        let a = polygon![(x: 0, y: 0), (x: 3, y: 1), (x: 0, y: 3), (x: 0, y: 0)];
+        let b = polygon![(x: 1, y: 0), (x: 3, y: 3), (x: 0, y: 2), (x: 1, y: 0)];
+        let (a, b) = (
+            a.map_coords(|&(x, y): &(i64, i64)| (x as f64, y as f64)),
+            b.map_coords(|&(x, y): &(i64, i64)| (x as f64, y as f64)),
+        );
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.0.len(), 1);
+        assert_relative_eq!(intersection.unsigned_area(), 214. / 91., epsilon = 1e-12);
+        let union = a.union(&b);
+        assert!(union.0[0]
+            .exterior()
+            .0
+            .contains(&Coordinate { x: 3., y: 3. }));
+        assert_relative_eq!(
+            union.unsigned_area(),
+            a.unsigned_area() + b.unsigned_area() - 214. / 91.,
+            epsilon = 1e-12
+        );
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI64F64;
+        use num_traits::NumCast;
+
+        let fixed = |x: f64| <FixedI64F64 as NumCast>::from(x).unwrap();
+        let square = |x: f64, y: f64, size: f64| {
+            Polygon::new(
+                LineString::from(vec![
+                    (fixed(x), fixed(y)),
+                    (fixed(x + size), fixed(y)),
+                    (fixed(x + size), fixed(y + size)),
+                    (fixed(x), fixed(y + size)),
+                    (fixed(x), fixed(y)),
+                ]),
+                vec![],
+            )
+        };
+        let triangle = Polygon::new(
+            LineString::from(vec![
+                (fixed(0.), fixed(0.)),
+                (fixed(3.), fixed(1.)),
+                (fixed(1.), fixed(3.)),
+                (fixed(0.), fixed(0.)),
+            ]),
+            vec![],
+        );
+        let a = square(0.5, 0.5, 2.);
+        let intersection = a.intersection(&triangle);
+        // the same result every time
+        assert_eq!(intersection, a.intersection(&triangle));
+        assert_eq!(intersection.0.len(), 1);
+        let union = a.union(&square(2.5, 0.5, 1.));
+        assert_eq!(
+            union,
+            MultiPolygon(vec![Polygon::new(
+                LineString::from(vec![
+                    (fixed(0.5), fixed(0.5)),
+                    (fixed(3.5), fixed(0.5)),
+                    (fixed(3.5), fixed(1.5)),
+                    (fixed(2.5), fixed(1.5)),
+                    (fixed(2.5), fixed(2.5)),
+                    (fixed(0.5), fixed(2.5)),
+                    (fixed(0.5), fixed(0.5)),
+                ]),
+                vec![],
+            )])
+        );
+    }
+}
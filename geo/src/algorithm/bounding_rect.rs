@@ -1,10 +1,12 @@
 use crate::{
-    Coordinate, CoordinateType, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Polygon, Rect, Triangle,
+    CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 
 /// Calculation of the bounding rectangle of a geometry.
+///
+/// Every geometry in `geo-types` also has an inherent `bounding_rect` method, which this
+/// delegates to.
 pub trait BoundingRect<T: CoordinateType> {
     type Output;
 
@@ -31,16 +33,25 @@ pub trait BoundingRect<T: CoordinateType> {
     fn bounding_rect(&self) -> Self::Output;
 }
 
+impl<T> BoundingRect<T> for Point<T>
+where
+    T: CoordinateType,
+{
+    type Output = Rect<T>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        Point::bounding_rect(self)
+    }
+}
+
 impl<T> BoundingRect<T> for MultiPoint<T>
 where
     T: CoordinateType,
 {
     type Output = Option<Rect<T>>;
 
-    ///
-    /// Return the BoundingRect for a MultiPoint
     fn bounding_rect(&self) -> Self::Output {
-        get_bounding_rect(self.0.iter().map(|p| p.0))
+        MultiPoint::bounding_rect(self)
     }
 }
 
@@ -51,14 +62,7 @@ where
     type Output = Rect<T>;
 
     fn bounding_rect(&self) -> Self::Output {
-        let a = self.start;
-        let b = self.end;
-        let (xmin, xmax) = if a.x <= b.x { (a.x, b.x) } else { (b.x, a.x) };
-        let (ymin, ymax) = if a.y <= b.y { (a.y, b.y) } else { (b.y, a.y) };
-        Rect::new(
-            Coordinate { x: xmin, y: ymin },
-            Coordinate { x: xmax, y: ymax },
-        )
+        Line::bounding_rect(self)
     }
 }
 
@@ -68,10 +72,8 @@ where
 {
     type Output = Option<Rect<T>>;
 
-    ///
-    /// Return the BoundingRect for a LineString
     fn bounding_rect(&self) -> Self::Output {
-        line_string_bounding_rect(self)
+        LineString::bounding_rect(self)
     }
 }
 
@@ -81,10 +83,8 @@ where
 {
     type Output = Option<Rect<T>>;
 
-    ///
-    /// Return the BoundingRect for a MultiLineString
     fn bounding_rect(&self) -> Self::Output {
-        get_bounding_rect(self.0.iter().flat_map(|line| line.0.iter().cloned()))
+        MultiLineString::bounding_rect(self)
     }
 }
 
@@ -94,11 +94,8 @@ where
 {
     type Output = Option<Rect<T>>;
 
-    ///
-    /// Return the BoundingRect for a Polygon
     fn bounding_rect(&self) -> Self::Output {
-        let line = self.exterior();
-        get_bounding_rect(line.0.iter().cloned())
+        Polygon::bounding_rect(self)
     }
 }
 
@@ -108,14 +105,8 @@ where
 {
     type Output = Option<Rect<T>>;
 
-    ///
-    /// Return the BoundingRect for a MultiPolygon
     fn bounding_rect(&self) -> Self::Output {
-        get_bounding_rect(
-            self.0
-                .iter()
-                .flat_map(|poly| poly.exterior().0.iter().cloned()),
-        )
+        MultiPolygon::bounding_rect(self)
     }
 }
 
@@ -126,7 +117,7 @@ where
     type Output = Rect<T>;
 
     fn bounding_rect(&self) -> Self::Output {
-        get_bounding_rect(self.to_array().iter().cloned()).unwrap()
+        Triangle::bounding_rect(self)
     }
 }
 
@@ -137,7 +128,29 @@ where
     type Output = Rect<T>;
 
     fn bounding_rect(&self) -> Self::Output {
-        *self
+        Rect::bounding_rect(self)
+    }
+}
+
+impl<T> BoundingRect<T> for Geometry<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        Geometry::bounding_rect(self)
+    }
+}
+
+impl<T> BoundingRect<T> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        GeometryCollection::bounding_rect(self)
     }
 }
 
@@ -146,8 +159,8 @@ mod test {
     use crate::algorithm::bounding_rect::BoundingRect;
     use crate::line_string;
     use crate::{
-        polygon, Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon,
-        Rect,
+        polygon, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Point, Polygon, Rect,
     };
 
     #[test]
@@ -203,6 +216,20 @@ mod test {
         assert_eq!(bounding_rect, multipoint.bounding_rect().unwrap());
     }
     #[test]
+    fn empty_points_test() {
+        let empty = Point::<f64>::empty();
+        let multipoint = MultiPoint(vec![empty, Point::new(1., 2.), Point::new(3., -1.)]);
+        let bounding_rect = Rect::new(Coordinate { x: 1., y: -1. }, Coordinate { x: 3., y: 2. });
+        assert_eq!(multipoint.bounding_rect(), Some(bounding_rect));
+        assert!(MultiPoint(vec![empty]).bounding_rect().is_none());
+        assert!(Geometry::Point(empty).bounding_rect().is_none());
+        let gc = GeometryCollection(vec![
+            Geometry::Point(empty),
+            Geometry::MultiPoint(multipoint),
+        ]);
+        assert_eq!(gc.bounding_rect(), Some(bounding_rect));
+    }
+    #[test]
     fn polygon_test() {
         let linestring = line_string![
             (x: 0., y: 0.),
@@ -241,4 +268,23 @@ mod test {
             Rect::new(Coordinate { x: 0., y: 1. }, Coordinate { x: 2., y: 3. },)
         );
     }
+    #[test]
+    fn geometry_collection_test() {
+        fn via_trait<G: BoundingRect<i32>>(g: &G) -> G::Output {
+            g.bounding_rect()
+        }
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(-4, 2)),
+            Geometry::Polygon(polygon![(x: 0, y: 0), (x: 5, y: 0), (x: 0, y: 9)]),
+            Geometry::LineString(line_string![]),
+        ]);
+        assert_eq!(
+            via_trait(&gc),
+            Some(Rect::new(
+                Coordinate { x: -4, y: 0 },
+                Coordinate { x: 5, y: 9 }
+            ))
+        );
+        assert_eq!(via_trait(&GeometryCollection::<i32>::new()), None);
+    }
 }
@@ -0,0 +1,675 @@
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::utils::is_finite_coord;
+use crate::{
+    Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+use num_traits::Float;
+
+/// How the ends of a buffered line are shaped
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CapStyle {
+    /// A half circle around the end
+    Round,
+    /// Cut off squarely at the end
+    Flat,
+    /// A half square around the end, extending beyond it by the buffer distance
+    Square,
+}
+
+/// How the offset lines of a buffer are joined at the outside of a corner
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JoinStyle {
+    /// An arc around the corner
+    Round,
+    /// Extend the offset lines until they meet, unless that's further from the corner than the
+    /// mitre limit allows, in which case the corner is bevelled
+    Mitre,
+    /// A straight line between the ends of the offset lines
+    Bevel,
+}
+
+/// The parameters of a buffer
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::buffer::{BufferStyle, CapStyle};
+///
+/// let style: BufferStyle<f64> = BufferStyle {
+///     cap_style: CapStyle::Flat,
+///     ..BufferStyle::default()
+/// };
+/// assert_eq!(style.quadrant_segments, 8);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BufferStyle<T: Float> {
+    pub cap_style: CapStyle,
+    pub join_style: JoinStyle,
+    /// The number of segments that approximate a quarter circle
+    pub quadrant_segments: usize,
+    /// The greatest distance of a mitred corner from the original corner, as a multiple of the
+    /// buffer distance
+    pub mitre_limit: T,
+}
+
+impl<T: Float> Default for BufferStyle<T> {
+    fn default() -> Self {
+        BufferStyle {
+            cap_style: CapStyle::Round,
+            join_style: JoinStyle::Round,
+            quadrant_segments: 8,
+            mitre_limit: T::from(5).unwrap(),
+        }
+    }
+}
+
+/// Compute the area within a distance of a geometry.
+///
+/// Points and lines are grown into areas, with their ends shaped by the
+/// [`CapStyle`](enum.CapStyle.html) and their corners by the
+/// [`JoinStyle`](enum.JoinStyle.html). Polygons grow for a positive distance, and shrink for a
+/// negative one, which removes the parts narrower than twice the distance.
+///
+/// The buffer is computed as the union of simple pieces (a rectangle along each segment, and a
+/// piece for each join and cap) using [`BooleanOps`](../bool_ops/trait.BooleanOps.html), so
+/// arcs are approximated with `quadrant_segments` segments per quarter circle.
+///
+/// Points, line strings, and polygons with a NaN or infinite coordinate have empty buffers, as
+/// does every geometry for a NaN or infinite distance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::buffer::{Buffer, BufferStyle, CapStyle, JoinStyle};
+/// use geo::{line_string, polygon};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+/// let style = BufferStyle {
+///     cap_style: CapStyle::Flat,
+///     join_style: JoinStyle::Mitre,
+///     ..BufferStyle::default()
+/// };
+/// let buffer = line_string.buffer_with_style(1., &style);
+/// assert_eq!(buffer.unsigned_area(), 16.);
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)];
+/// assert_eq!(square.buffer(-1.).unsigned_area(), 4.);
+/// assert!(square.buffer(-2.).0.is_empty());
+/// ```
+pub trait Buffer<T: Float> {
+    /// The buffer with round caps and joins
+    fn buffer(&self, distance: T) -> MultiPolygon<T> {
+        self.buffer_with_style(distance, &BufferStyle::default())
+    }
+
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T>;
+}
+
+impl<T: Float> Buffer<T> for Point<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() || !distance.is_finite() || !is_finite_coord(&self.0) {
+            return MultiPolygon(vec![]);
+        }
+        let c = self.0;
+        match style.cap_style {
+            CapStyle::Flat => MultiPolygon(vec![]),
+            CapStyle::Round => {
+                let steps = 4 * style.quadrant_segments.max(1);
+                let full = T::from(std::f64::consts::PI * 2.).unwrap();
+                let ring = (0..=steps)
+                    .map(|k| {
+                        let angle = full * T::from(k % steps).unwrap() / T::from(steps).unwrap();
+                        Coordinate {
+                            x: c.x + distance * angle.cos(),
+                            y: c.y + distance * angle.sin(),
+                        }
+                    })
+                    .collect();
+                MultiPolygon(vec![Polygon::new(LineString(ring), vec![])])
+            }
+            CapStyle::Square => {
+                let ring = vec![
+                    (c.x - distance, c.y - distance),
+                    (c.x + distance, c.y - distance),
+                    (c.x + distance, c.y + distance),
+                    (c.x - distance, c.y + distance),
+                    (c.x - distance, c.y - distance),
+                ];
+                MultiPolygon(vec![Polygon::new(ring.into(), vec![])])
+            }
+        }
+    }
+}
+
+impl<T: Float> Buffer<T> for MultiPoint<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        union_all(
+            self.0
+                .iter()
+                .flat_map(|point| point.buffer_with_style(distance, style).0)
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> Buffer<T> for Line<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        LineString(vec![self.start, self.end]).buffer_with_style(distance, style)
+    }
+}
+
+impl<T: Float> Buffer<T> for LineString<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() || !distance.is_finite() || !self.0.iter().all(is_finite_coord) {
+            return MultiPolygon(vec![]);
+        }
+        let mut coords = self.0.clone();
+        coords.dedup();
+        match coords.len() {
+            0 => MultiPolygon(vec![]),
+            1 => Point(coords[0]).buffer_with_style(distance, style),
+            _ => union_all(line_pieces(&coords, distance, style, true)),
+        }
+    }
+}
+
+impl<T: Float> Buffer<T> for MultiLineString<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        union_all(
+            self.0
+                .iter()
+                .flat_map(|line_string| line_string.buffer_with_style(distance, style).0)
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> Buffer<T> for Polygon<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        let rings = std::iter::once(self.exterior()).chain(self.interiors());
+        if !distance.is_finite() || !rings.flat_map(|ring| &ring.0).all(is_finite_coord) {
+            return MultiPolygon(vec![]);
+        }
+        let polygon = MultiPolygon(vec![self.clone()]);
+        if distance == T::zero() {
+            return polygon;
+        }
+        // the area within the distance of the rings, which is added to or removed from the
+        // polygon
+        let mut pieces = vec![];
+        for ring in std::iter::once(self.exterior()).chain(self.interiors()) {
+            let mut coords = ring.0.clone();
+            coords.dedup();
+            if coords.len() > 1 {
+                pieces.extend(line_pieces(&coords, distance.abs(), style, false));
+            }
+        }
+        let rings = union_all(pieces);
+        if distance > T::zero() {
+            polygon.union(&rings)
+        } else {
+            polygon.difference(&rings)
+        }
+    }
+}
+
+impl<T: Float> Buffer<T> for MultiPolygon<T> {
+    fn buffer_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiPolygon<T> {
+        union_all(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon.buffer_with_style(distance, style).0)
+                .collect(),
+        )
+    }
+}
+
+/// Union polygons pairwise, which is much faster than adding them to the result one by one. Even
+/// a single polygon goes through an overlay, so that results are always wound the same way
+pub(crate) fn union_all<T: Float>(polygons: Vec<Polygon<T>>) -> MultiPolygon<T> {
+    let mut layer: Vec<MultiPolygon<T>> = polygons
+        .into_iter()
+        .map(|polygon| MultiPolygon(vec![polygon]))
+        .collect();
+    if layer.len() == 1 {
+        layer.push(MultiPolygon(vec![]));
+    }
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a.union(b),
+                _ => pair[0].clone(),
+            })
+            .collect();
+    }
+    layer.pop().unwrap_or_else(|| MultiPolygon(vec![]))
+}
+
+pub(crate) fn offset<T: Float>(c: Coordinate<T>, normal: (T, T), distance: T) -> Coordinate<T> {
+    Coordinate {
+        x: c.x + normal.0 * distance,
+        y: c.y + normal.1 * distance,
+    }
+}
+
+/// A closed ring from `center` to `from`, along an arc around `center` sweeping through `sweep`
+/// (counter-clockwise if positive) in `steps` segments to `to`, and back to `center`. The ends of
+/// the arc are passed in, rather than computed, so they exactly match those of adjacent pieces
+fn pie<T: Float>(
+    center: Coordinate<T>,
+    from: Coordinate<T>,
+    to: Coordinate<T>,
+    sweep: T,
+    steps: usize,
+) -> Vec<Coordinate<T>> {
+    let mut ring = vec![center, from];
+    ring.extend(arc_interior(center, from, sweep, steps));
+    ring.extend(vec![to, center]);
+    ring
+}
+
+/// The points strictly between the ends of an arc around `center`, from `from` sweeping through
+/// `sweep` (counter-clockwise if positive) in `steps` segments
+fn arc_interior<T: Float>(
+    center: Coordinate<T>,
+    from: Coordinate<T>,
+    sweep: T,
+    steps: usize,
+) -> Vec<Coordinate<T>> {
+    let radius = (from.x - center.x).hypot(from.y - center.y);
+    let start = (from.y - center.y).atan2(from.x - center.x);
+    (1..steps)
+        .map(|k| {
+            let angle = start + sweep * T::from(k).unwrap() / T::from(steps).unwrap();
+            Coordinate {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+/// The number of segments of an arc spanning `sweep`, in proportion to `quadrant_segments`
+fn arc_steps<T: Float>(sweep: T, style: &BufferStyle<T>) -> usize {
+    let quadrant = T::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let quadrants = (sweep.abs() / quadrant).to_f64().unwrap_or(0.);
+    ((quadrants * style.quadrant_segments.max(1) as f64).ceil() as usize).max(1)
+}
+
+/// The rectangles, joins, and (if `capped` and the line isn't closed) caps whose union is the
+/// buffer of a line string of distinct consecutive coordinates
+fn line_pieces<T: Float>(
+    coords: &[Coordinate<T>],
+    distance: T,
+    style: &BufferStyle<T>,
+    capped: bool,
+) -> Vec<Polygon<T>> {
+    let polygon = |ring: Vec<Coordinate<T>>| Polygon::new(LineString(ring), vec![]);
+    // the unit direction of each segment
+    let directions: Vec<(T, T)> = coords
+        .windows(2)
+        .map(|pair| {
+            let (dx, dy) = (pair[1].x - pair[0].x, pair[1].y - pair[0].y);
+            let length = dx.hypot(dy);
+            (dx / length, dy / length)
+        })
+        .collect();
+    let normal = |(dx, dy): (T, T)| (-dy, dx);
+
+    let mut pieces = vec![];
+    for (pair, direction) in coords.windows(2).zip(&directions) {
+        let n = normal(*direction);
+        // the rectangle passes through the ends of the segment, so it exactly shares edges with
+        // the joins and caps, which meet at them
+        pieces.push(polygon(vec![
+            offset(pair[0], n, distance),
+            offset(pair[1], n, distance),
+            pair[1],
+            offset(pair[1], n, -distance),
+            offset(pair[0], n, -distance),
+            pair[0],
+            offset(pair[0], n, distance),
+        ]));
+    }
+
+    let closed = coords.len() > 2 && coords.first() == coords.last();
+    // each corner, with the directions of the segments into and out of it
+    let mut joins: Vec<_> = coords
+        .windows(3)
+        .zip(directions.windows(2))
+        .map(|(vertices, pair)| (vertices[1], pair[0], pair[1]))
+        .collect();
+    if closed {
+        joins.push((coords[0], directions[directions.len() - 1], directions[0]));
+    }
+    for (vertex, incoming, outgoing) in joins {
+        pieces.extend(join(vertex, incoming, outgoing, distance, style));
+    }
+
+    if capped && !closed {
+        let first = directions[0];
+        let last = directions[directions.len() - 1];
+        let end = coords[coords.len() - 1];
+        let backwards = (-first.0, -first.1);
+        pieces.extend(cap(coords[0], backwards, distance, style));
+        pieces.extend(cap(end, last, distance, style));
+    }
+    pieces
+}
+
+/// The piece filling the outside of the corner at `vertex`, between segments with unit
+/// directions `incoming` and `outgoing`
+fn join<T: Float>(
+    vertex: Coordinate<T>,
+    incoming: (T, T),
+    outgoing: (T, T),
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Option<Polygon<T>> {
+    let cross = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+    let dot = incoming.0 * outgoing.0 + incoming.1 * outgoing.1;
+    if cross == T::zero() {
+        // straight on, or back the way it came, which needs a cap
+        return if dot < T::zero() {
+            cap(vertex, incoming, distance, style)
+        } else {
+            None
+        };
+    }
+    // the outside of a left turn is to the right
+    let side = if cross > T::zero() {
+        -distance
+    } else {
+        distance
+    };
+    let from = offset(vertex, (-incoming.1, incoming.0), side);
+    let to = offset(vertex, (-outgoing.1, outgoing.0), side);
+    let mut ring = vec![vertex, from];
+    ring.extend(join_interior(vertex, from, to, cross, dot, style));
+    ring.extend(vec![to, vertex]);
+    Some(Polygon::new(LineString(ring), vec![]))
+}
+
+/// The points strictly between `from` and `to`, the ends of the offset lines on the outside of
+/// the corner at `vertex`, along the join between them. `cross` and `dot` are the cross and dot
+/// products of the unit directions of the segments into and out of the corner.
+pub(crate) fn join_interior<T: Float>(
+    vertex: Coordinate<T>,
+    from: Coordinate<T>,
+    to: Coordinate<T>,
+    cross: T,
+    dot: T,
+    style: &BufferStyle<T>,
+) -> Vec<Coordinate<T>> {
+    match style.join_style {
+        JoinStyle::Round => {
+            let sweep = cross.atan2(dot);
+            arc_interior(vertex, from, sweep, arc_steps(sweep, style))
+        }
+        JoinStyle::Bevel => vec![],
+        JoinStyle::Mitre => {
+            // the offset lines meet along the bisector, at distance / cos(half the turn), which
+            // is (from + to) / (1 + cos(the turn)) from the vertex
+            let two = T::one() + T::one();
+            let limit = style.mitre_limit;
+            if two > limit * limit * (T::one() + dot) {
+                vec![]
+            } else {
+                vec![Coordinate {
+                    x: vertex.x + (from.x + to.x - two * vertex.x) / (T::one() + dot),
+                    y: vertex.y + (from.y + to.y - two * vertex.y) / (T::one() + dot),
+                }]
+            }
+        }
+    }
+}
+
+/// The piece at the end `vertex` of a line leaving it in the unit `direction`
+fn cap<T: Float>(
+    vertex: Coordinate<T>,
+    direction: (T, T),
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Option<Polygon<T>> {
+    let n = (-direction.1, direction.0);
+    let left = offset(vertex, n, distance);
+    let right = offset(vertex, n, -distance);
+    let ring = match style.cap_style {
+        CapStyle::Flat => return None,
+        CapStyle::Round => {
+            // from the right side, around the end, to the left side
+            let half = T::from(std::f64::consts::PI).unwrap();
+            pie(vertex, right, left, half, arc_steps(half, style))
+        }
+        CapStyle::Square => {
+            let ahead = (direction.0 * distance, direction.1 * distance);
+            vec![
+                right,
+                Coordinate {
+                    x: right.x + ahead.0,
+                    y: right.y + ahead.1,
+                },
+                Coordinate {
+                    x: left.x + ahead.0,
+                    y: left.y + ahead.1,
+                },
+                left,
+                vertex,
+                right,
+            ]
+        }
+    };
+    Some(Polygon::new(LineString(ring), vec![]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    fn style(cap_style: CapStyle, join_style: JoinStyle) -> BufferStyle<f64> {
+        BufferStyle {
+            cap_style,
+            join_style,
+            ..BufferStyle::default()
+        }
+    }
+
+    #[test]
+    fn point() {
+        let point = Point::new(1., 1.);
+        let circle = point.buffer(2.);
+        // a regular polygon of 32 sides, inscribed in the circle
+        let expected = 0.5 * 32. * 4. * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(circle.unsigned_area(), expected, epsilon = 1e-9);
+        let square = point.buffer_with_style(2., &style(CapStyle::Square, JoinStyle::Round));
+        assert_eq!(square.unsigned_area(), 16.);
+        assert!(point
+            .buffer_with_style(2., &style(CapStyle::Flat, JoinStyle::Round))
+            .0
+            .is_empty());
+        assert!(point.buffer(-1.).0.is_empty());
+    }
+
+    #[test]
+    fn line_caps() {
+        let line = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        let flat = line.buffer_with_style(1., &style(CapStyle::Flat, JoinStyle::Round));
+        assert_eq!(
+            flat,
+            MultiPolygon(vec![polygon![
+                (x: 0., y: -1.),
+                (x: 4., y: -1.),
+                (x: 4., y: 1.),
+                (x: 0., y: 1.),
+                (x: 0., y: -1.),
+            ]])
+        );
+        let square = line.buffer_with_style(1., &style(CapStyle::Square, JoinStyle::Round));
+        assert_relative_eq!(square.unsigned_area(), 12., epsilon = 1e-12);
+        let round = line.buffer(1.);
+        let half_circles = 0.5 * 16. * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(
+            round.unsigned_area(),
+            8. + 2. * half_circles,
+            epsilon = 1e-9
+        );
+        assert_eq!(round.0.len(), 1);
+    }
+
+    #[test]
+    fn line_joins() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let area = |join_style| {
+            line_string
+                .buffer_with_style(1., &style(CapStyle::Flat, join_style))
+                .unsigned_area()
+        };
+        // the rectangles overlap in a unit square, and the join adds the outside corner
+        assert_relative_eq!(area(JoinStyle::Mitre), 16., epsilon = 1e-12);
+        assert_relative_eq!(area(JoinStyle::Bevel), 15.5, epsilon = 1e-12);
+        let quarter_circle = 0.25 * 32. * 0.5 * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(area(JoinStyle::Round), 15. + quarter_circle, epsilon = 1e-9);
+
+        // a sharp turn exceeds the mitre limit, so is bevelled
+        let sharp = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 0.5)];
+        let mitred = sharp.buffer_with_style(1., &style(CapStyle::Flat, JoinStyle::Mitre));
+        let bevelled = sharp.buffer_with_style(1., &style(CapStyle::Flat, JoinStyle::Bevel));
+        assert_relative_eq!(
+            mitred.unsigned_area(),
+            bevelled.unsigned_area(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn overlapping_pieces() {
+        use crate::algorithm::validation::Validation;
+        // the pieces of the buffers of zigzags overlap a lot, and meet at computed coordinates
+        for n in 3..12 {
+            let zigzag: LineString<f64> = (0..n)
+                .map(|i| (i as f64, if i % 2 == 0 { 0. } else { 1.3 }))
+                .collect::<Vec<_>>()
+                .into();
+            for distance in [0.3, 1., 3.].iter() {
+                for join_style in [JoinStyle::Round, JoinStyle::Mitre, JoinStyle::Bevel].iter() {
+                    let buffer =
+                        zigzag.buffer_with_style(*distance, &style(CapStyle::Square, *join_style));
+                    assert_eq!(buffer.0.len(), 1);
+                    assert!(buffer.is_valid());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_grows_and_shrinks() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let mitred = square.buffer_with_style(1., &style(CapStyle::Round, JoinStyle::Mitre));
+        assert_eq!(
+            mitred,
+            MultiPolygon(vec![polygon![
+                (x: -1., y: -1.),
+                (x: 5., y: -1.),
+                (x: 5., y: 5.),
+                (x: -1., y: 5.),
+                (x: -1., y: -1.),
+            ]])
+        );
+        let quarter_circle = 0.25 * 32. * 0.5 * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(
+            square.buffer(1.).unsigned_area(),
+            16. + 16. + 4. * quarter_circle,
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            square.buffer(-1.),
+            MultiPolygon(vec![polygon![
+                (x: 1., y: 1.),
+                (x: 3., y: 1.),
+                (x: 3., y: 3.),
+                (x: 1., y: 3.),
+                (x: 1., y: 1.),
+            ]])
+        );
+        assert_eq!(square.buffer(0.), MultiPolygon(vec![square.clone()]));
+        assert!(square.buffer(-3.).0.is_empty());
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.)]],
+        ];
+        let mitre = style(CapStyle::Round, JoinStyle::Mitre);
+        // the hole closes up
+        assert_eq!(frame.buffer_with_style(1., &mitre).unsigned_area(), 64.);
+        let grown = frame.buffer_with_style(0.5, &mitre);
+        assert_eq!(grown.0[0].interiors().len(), 1);
+        assert_eq!(grown.unsigned_area(), 49. - 1.);
+        // the frame is two wide, so it vanishes when shrunk by 1, except, with round joins, at
+        // its corners, which are more than 1 from the corners of the hole
+        assert_eq!(
+            frame.buffer_with_style(-0.5, &mitre).unsigned_area(),
+            25. - 9.
+        );
+        assert!(frame.buffer_with_style(-1., &mitre).0.is_empty());
+        let corners = frame.buffer(-1.);
+        assert_eq!(corners.0.len(), 4);
+        let quarter_circle = 0.25 * 32. * 0.5 * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(
+            corners.unsigned_area(),
+            4. * (1. - quarter_circle),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn multi_geometries() {
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+        let square = style(CapStyle::Square, JoinStyle::Round);
+        assert_eq!(points.buffer_with_style(1., &square).0.len(), 2);
+        assert_eq!(points.buffer_with_style(6., &square).0.len(), 1);
+        let lines = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: -1.), (x: 1., y: 1.)],
+        ]);
+        let flat = style(CapStyle::Flat, JoinStyle::Round);
+        assert_eq!(
+            lines.buffer_with_style(0.5, &flat).unsigned_area(),
+            2. + 2. - 1.
+        );
+    }
+
+    #[test]
+    fn non_finite_input() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let broken = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: f64::NAN, y: 4.), (x: 0., y: 4.)];
+        let line = line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 0.), (x: 4., y: 4.)];
+        for distance in [1., -1., 0.].iter() {
+            assert!(broken.buffer(*distance).0.is_empty());
+        }
+        assert!(line.buffer(1.).0.is_empty());
+        assert!(Point::new(f64::NAN, 0.).buffer(1.).0.is_empty());
+        assert!(square.buffer(f64::NAN).0.is_empty());
+        assert!(square.buffer(f64::INFINITY).0.is_empty());
+        assert!(Point::new(0., 0.).buffer(f64::INFINITY).0.is_empty());
+        // the finite members of collections are still buffered
+        let points = MultiPoint(vec![Point::new(f64::NAN, f64::NAN), Point::new(0., 0.)]);
+        let square_caps = style(CapStyle::Square, JoinStyle::Round);
+        assert_eq!(
+            points.buffer_with_style(1., &square_caps).unsigned_area(),
+            4.
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use crate::algorithm::validation::{RingRole, Validation, ValidationError};
+use crate::{Coordinate, CoordinateM, LineString, LineStringM, Polygon};
+use num_traits::Float;
+
+/// Build a `Polygon` a ring, or a coordinate, at a time, checking that it's valid when it's
+/// built, rather than when it's first used.
+///
+/// Rings are closed, like `Polygon::new` closes them, and the polygon is then checked with
+/// [`Validation`](../validation/trait.Validation.html): each ring needs at least three distinct
+/// points, mustn't repeat a point, turn back on itself or intersect itself, rings may only touch
+/// at points, and every interior has to be inside the exterior. Unlike `Validation`, an empty
+/// exterior isn't valid either.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::builder::PolygonBuilder;
+/// use geo::algorithm::validation::{RingRole, ValidationError};
+/// use geo::Coordinate;
+///
+/// let polygon = PolygonBuilder::new()
+///     .exterior(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)])
+///     .interior(vec![(1., 1.), (2., 1.), (2., 2.)])
+///     .build()
+///     .unwrap();
+/// assert_eq!(polygon.exterior().0.len(), 5);
+///
+/// // a hole outside of the exterior
+/// let error = PolygonBuilder::new()
+///     .exterior(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)])
+///     .interior(vec![(1., 1.), (2., 1.), (2., 2.)])
+///     .interior(vec![(11., 1.), (12., 1.), (12., 2.)])
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(
+///     error,
+///     ValidationError::HoleOutsideShell {
+///         ring: RingRole::Interior(1),
+///         coordinate: Coordinate { x: 11., y: 1. },
+///     }
+/// );
+/// ```
+///
+/// Coordinates can be added one at a time too, to the last ring started:
+///
+/// ```
+/// use geo::algorithm::builder::PolygonBuilder;
+/// use geo::polygon;
+///
+/// let mut builder = PolygonBuilder::new();
+/// for &(x, y) in &[(0., 0.), (4., 0.), (4., 4.)] {
+///     builder.push((x, y).into());
+/// }
+/// builder.start_interior();
+/// for &(x, y) in &[(3., 1.), (3., 2.), (2., 1.)] {
+///     builder.push((x, y).into());
+/// }
+///
+/// assert_eq!(
+///     builder.build().unwrap(),
+///     polygon!(
+///         exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+///         interiors: [[(x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonBuilder<T: Float> {
+    exterior: LineString<T>,
+    interiors: Vec<LineString<T>>,
+}
+
+impl<T: Float> PolygonBuilder<T> {
+    /// A builder with an empty exterior, and no interiors.
+    pub fn new() -> Self {
+        PolygonBuilder {
+            exterior: LineString(vec![]),
+            interiors: vec![],
+        }
+    }
+
+    /// Set the exterior ring, replacing any coordinates pushed to it.
+    pub fn exterior(mut self, ring: impl Into<LineString<T>>) -> Self {
+        self.exterior = ring.into();
+        self
+    }
+
+    /// Add an interior ring.
+    pub fn interior(mut self, ring: impl Into<LineString<T>>) -> Self {
+        self.interiors.push(ring.into());
+        self
+    }
+
+    /// Add a coordinate to the end of the last interior ring started, or to the exterior if no
+    /// interior has been.
+    pub fn push(&mut self, coord: Coordinate<T>) {
+        self.interiors
+            .last_mut()
+            .unwrap_or(&mut self.exterior)
+            .0
+            .push(coord);
+    }
+
+    /// Start a new, empty, interior ring, which [`push`](#method.push) adds to from now on.
+    pub fn start_interior(&mut self) {
+        self.interiors.push(LineString(vec![]));
+    }
+
+    /// Close the rings, and return the polygon, or the first reason it isn't valid.
+    pub fn build(self) -> Result<Polygon<T>, ValidationError<T>> {
+        if self.exterior.0.is_empty() {
+            return Err(ValidationError::RingTooFewPoints {
+                ring: RingRole::Exterior,
+            });
+        }
+        let polygon = Polygon::new(self.exterior, self.interiors);
+        match polygon.validation_errors().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(polygon),
+        }
+    }
+}
+
+impl<T: Float> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A coordinate [`LineStringBuilder`](struct.LineStringBuilder.html) can build a line string of:
+/// a `Coordinate`, for a `LineString`, or a `CoordinateM`, for a `LineStringM`.
+pub trait BuilderCoordinate<T: Float>: Copy {
+    type LineString;
+
+    fn x_y(&self) -> Coordinate<T>;
+
+    /// The measure, if the coordinate has one.
+    fn m(&self) -> Option<T>;
+
+    fn line_string(coords: Vec<Self>) -> Self::LineString;
+}
+
+impl<T: Float> BuilderCoordinate<T> for Coordinate<T> {
+    type LineString = LineString<T>;
+
+    fn x_y(&self) -> Coordinate<T> {
+        *self
+    }
+
+    fn m(&self) -> Option<T> {
+        None
+    }
+
+    fn line_string(coords: Vec<Self>) -> LineString<T> {
+        LineString(coords)
+    }
+}
+
+impl<T: Float> BuilderCoordinate<T> for CoordinateM<T> {
+    type LineString = LineStringM<T>;
+
+    fn x_y(&self) -> Coordinate<T> {
+        self.to_2d()
+    }
+
+    fn m(&self) -> Option<T> {
+        Some(self.m)
+    }
+
+    fn line_string(coords: Vec<Self>) -> LineStringM<T> {
+        LineStringM(coords)
+    }
+}
+
+/// Build a `LineString`, or a `LineStringM`, a coordinate at a time, dropping the coordinates
+/// which repeat the last one kept, to within a tolerance, and optionally the ones whose measure
+/// goes back, so noisy streams, such as GPS tracks, can be cleaned up as they're read.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::builder::LineStringBuilder;
+/// use geo::{line_string, Coordinate};
+///
+/// let mut builder = LineStringBuilder::new().tolerance(0.5);
+/// for &(x, y) in &[(0., 0.), (0., 0.), (0.3, 0.4), (2., 0.), (2., 0.)] {
+///     builder.push(Coordinate { x, y });
+/// }
+///
+/// assert_eq!(builder.build(), line_string![(x: 0., y: 0.), (x: 2., y: 0.)]);
+/// ```
+///
+/// With measures, e.g. the timestamps of GPS fixes, which mustn't go back:
+///
+/// ```
+/// use geo::algorithm::builder::LineStringBuilder;
+/// use geo::CoordinateM;
+///
+/// let mut builder = LineStringBuilder::new().monotone_m(true);
+/// assert!(builder.push(CoordinateM { x: 0., y: 0., m: 10. }));
+/// assert!(builder.push(CoordinateM { x: 5., y: 0., m: 20. }));
+/// // a fix from before the last one
+/// assert!(!builder.push(CoordinateM { x: 3., y: 0., m: 15. }));
+/// assert!(builder.push(CoordinateM { x: 9., y: 0., m: 30. }));
+///
+/// assert_eq!(builder.build().m_values().collect::<Vec<_>>(), vec![10., 20., 30.]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineStringBuilder<T: Float, C: BuilderCoordinate<T> = Coordinate<T>> {
+    coords: Vec<C>,
+    tolerance: T,
+    monotone_m: bool,
+}
+
+impl<T: Float, C: BuilderCoordinate<T>> LineStringBuilder<T, C> {
+    /// A builder with no coordinates, which only drops exact repeats.
+    pub fn new() -> Self {
+        LineStringBuilder {
+            coords: vec![],
+            tolerance: T::zero(),
+            monotone_m: false,
+        }
+    }
+
+    /// Drop the coordinates within this distance of the last one kept.
+    pub fn tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Whether to drop the coordinates whose measure is less than that of the last one kept.
+    /// Coordinates without measures are never dropped for it.
+    pub fn monotone_m(mut self, monotone_m: bool) -> Self {
+        self.monotone_m = monotone_m;
+        self
+    }
+
+    /// Add a coordinate to the end, unless it's within the tolerance of the last one kept, or
+    /// its measure goes back while they're required to be monotone, returning whether it was
+    /// kept. Coordinates which aren't finite are kept, as there's no telling what they repeat.
+    pub fn push(&mut self, coord: C) -> bool {
+        if let Some(last) = self.coords.last() {
+            let (a, b) = (last.x_y(), coord.x_y());
+            if (a.x - b.x).hypot(a.y - b.y) <= self.tolerance {
+                return false;
+            }
+            if let (true, Some(last), Some(m)) = (self.monotone_m, last.m(), coord.m()) {
+                if m < last {
+                    return false;
+                }
+            }
+        }
+        self.coords.push(coord);
+        true
+    }
+
+    /// The coordinates kept so far.
+    pub fn coords(&self) -> &[C] {
+        &self.coords
+    }
+
+    /// The line string of the coordinates kept.
+    pub fn build(self) -> C::LineString {
+        C::line_string(self.coords)
+    }
+}
+
+impl<T: Float, C: BuilderCoordinate<T>> Default for LineStringBuilder<T, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float, C: BuilderCoordinate<T>> Extend<C> for LineStringBuilder<T, C> {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, coords: I) {
+        for coord in coords {
+            self.push(coord);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn polygon_builder() {
+        let mut builder = PolygonBuilder::new().exterior(vec![(0., 0.), (4., 0.)]);
+        builder.push(Coordinate { x: 4., y: 4. });
+        // closed rings stay as they are
+        let builder = builder.interior(vec![(2., 1.), (3., 1.), (3., 2.), (2., 1.)]);
+        assert_eq!(
+            builder.build(),
+            Ok(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+                interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.)]],
+            ))
+        );
+
+        assert_eq!(
+            PolygonBuilder::<f64>::new().build(),
+            Err(ValidationError::RingTooFewPoints {
+                ring: RingRole::Exterior
+            })
+        );
+        assert_eq!(
+            PolygonBuilder::new()
+                .exterior(vec![(0., 0.), (4., 0.), (4., 4.)])
+                .interior(vec![(1., 1.), (2., 1.), (1., 1.)])
+                .build(),
+            Err(ValidationError::RingTooFewPoints {
+                ring: RingRole::Interior(0)
+            })
+        );
+
+        // an interior started, but with nothing pushed to it
+        let mut builder = PolygonBuilder::new().exterior(vec![(0., 0.), (4., 0.), (4., 4.)]);
+        builder.start_interior();
+        assert_eq!(
+            builder.build(),
+            Err(ValidationError::RingTooFewPoints {
+                ring: RingRole::Interior(0)
+            })
+        );
+
+        // the other checks of Validation apply too
+        let bowtie = PolygonBuilder::new()
+            .exterior(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.)])
+            .build();
+        assert_eq!(
+            bowtie,
+            Err(ValidationError::SelfIntersection {
+                ring: RingRole::Exterior,
+                coordinate: Coordinate { x: 1., y: 1. },
+            })
+        );
+        assert!(matches!(
+            PolygonBuilder::new()
+                .exterior(vec![(0., 0.), (f64::NAN, 0.), (4., 4.)])
+                .build(),
+            Err(ValidationError::NonFiniteCoordinate { .. })
+        ));
+    }
+
+    #[test]
+    fn line_string_builder() {
+        let mut builder = LineStringBuilder::new();
+        builder.extend(vec![
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: -0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: f64::NAN, y: 0. },
+            Coordinate { x: f64::NAN, y: 0. },
+        ]);
+        assert_eq!(builder.coords().len(), 5);
+        assert_eq!(builder.coords()[2], Coordinate { x: 0., y: 0. });
+
+        // only the last coordinate kept counts, so slow drift is kept
+        let mut builder = LineStringBuilder::new().tolerance(1.);
+        let kept: Vec<_> = (0..10)
+            .map(|i| {
+                builder.push(Coordinate {
+                    x: i as f64 * 0.6,
+                    y: 0.,
+                })
+            })
+            .collect();
+        assert_eq!(kept.iter().filter(|&&kept| kept).count(), 5);
+        assert_eq!(
+            builder.build().0.last(),
+            Some(&Coordinate { x: 4.8, y: 0. })
+        );
+
+        let mut builder = LineStringBuilder::new();
+        builder.extend(vec![
+            CoordinateM {
+                x: 0.,
+                y: 0.,
+                m: 1.,
+            },
+            CoordinateM {
+                x: 1.,
+                y: 0.,
+                m: 0.,
+            },
+            CoordinateM {
+                x: 1.,
+                y: 0.,
+                m: 2.,
+            },
+        ]);
+        // measures can go back unless they're required not to
+        assert_eq!(builder.coords().len(), 2);
+        let mut builder = LineStringBuilder::new().monotone_m(true);
+        builder.extend(vec![
+            CoordinateM {
+                x: 0.,
+                y: 0.,
+                m: 1.,
+            },
+            CoordinateM {
+                x: 1.,
+                y: 0.,
+                m: 0.,
+            },
+            CoordinateM {
+                x: 2.,
+                y: 0.,
+                m: 1.,
+            },
+            // a duplicate position is dropped whatever its measure
+            CoordinateM {
+                x: 2.,
+                y: 0.,
+                m: 5.,
+            },
+        ]);
+        assert_eq!(
+            builder.build(),
+            LineStringM(vec![
+                CoordinateM {
+                    x: 0.,
+                    y: 0.,
+                    m: 1.
+                },
+                CoordinateM {
+                    x: 2.,
+                    y: 0.,
+                    m: 1.
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use crate::algorithm::relate::topology_graph::cmp_coords;
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Total, TotalEq, Triangle,
+};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Put a geometry into a canonical form, so that geometries which only differ in how "the same"
+/// shape is written down end up identical.
+pub trait Canonicalize<T> {
+    /// Returns the canonical form of this geometry:
+    ///
+    /// - rings are oriented like [`Orient`](../orient/trait.Orient.html)'s default, with
+    ///   counter-clockwise exteriors and clockwise interiors, and start at their least
+    ///   coordinate, ordering coordinates by `x` and then `y`
+    /// - the interiors of polygons, and the members of multi-geometries and of geometry
+    ///   collections, are sorted
+    /// - `Line`s and `LineString`s run in whichever direction makes their coordinates least
+    /// - `-0` is replaced by `0`
+    ///
+    /// Geometries of different types are never made the same, a `LineString` isn't treated as a
+    /// ring even if it's closed, and repeated coordinates and members are kept; see
+    /// [`Dedup`](trait.Dedup.html) to remove repeated members. Rings which aren't closed are
+    /// left as they are, bar `-0`. NaNs are ordered after every other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::canonicalize::Canonicalize;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// // the same square, clockwise, starting in another corner
+    /// let rotated = polygon![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.), (x: 0., y: 1.)];
+    ///
+    /// assert_ne!(square, rotated);
+    /// assert_eq!(square.canonicalize(), rotated.canonicalize());
+    /// assert_eq!(square.canonicalize(), square);
+    /// ```
+    fn canonicalize(&self) -> Self;
+}
+
+/// Remove the members of a collection which are the same as an earlier member, once both are
+/// [canonicalized](trait.Canonicalize.html).
+pub trait Dedup<T> {
+    /// Returns this collection without the members which are the same as an earlier member, once
+    /// both are canonicalized, as [`TotalEq`](../../trait.TotalEq.html) decides.
+    ///
+    /// The members which are kept aren't canonicalized, and stay in their order; canonicalize the
+    /// result as well for a unique representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::canonicalize::{Canonicalize, Dedup};
+    /// use geo::{line_string, MultiLineString};
+    ///
+    /// let lines = MultiLineString(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+    ///     line_string![(x: 2., y: 2.), (x: 3., y: 3.)],
+    ///     line_string![(x: 1., y: 1.), (x: 0., y: -0.)],
+    /// ]);
+    ///
+    /// let deduped = lines.dedup();
+    /// assert_eq!(deduped.0, lines.0[..2]);
+    /// assert_eq!(deduped.canonicalize(), lines.canonicalize().dedup());
+    /// ```
+    fn dedup(&self) -> Self;
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Coordinate<T> {
+    fn canonicalize(&self) -> Self {
+        // Adding zero maps -0 to 0, and leaves every other value (and integers) as it is
+        Coordinate {
+            x: self.x + T::zero(),
+            y: self.y + T::zero(),
+        }
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Point<T> {
+    fn canonicalize(&self) -> Self {
+        Point(self.0.canonicalize())
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Line<T> {
+    fn canonicalize(&self) -> Self {
+        let (start, end) = (self.start.canonicalize(), self.end.canonicalize());
+        if cmp_coords(&end, &start) == Ordering::Less {
+            Line::new(end, start)
+        } else {
+            Line::new(start, end)
+        }
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for LineString<T> {
+    fn canonicalize(&self) -> Self {
+        let forwards: Vec<_> = self.0.iter().map(Canonicalize::canonicalize).collect();
+        let backwards: Vec<_> = forwards.iter().rev().cloned().collect();
+        if cmp_slices(&backwards, &forwards, cmp_coords) == Ordering::Less {
+            LineString(backwards)
+        } else {
+            LineString(forwards)
+        }
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Polygon<T> {
+    fn canonicalize(&self) -> Self {
+        let exterior = canonical_ring(self.exterior(), WindingOrder::CounterClockwise);
+        let mut interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .map(|ring| canonical_ring(ring, WindingOrder::Clockwise))
+            .collect();
+        interiors.sort_by(cmp_line_strings);
+        Polygon::new(exterior, interiors)
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for MultiPoint<T> {
+    fn canonicalize(&self) -> Self {
+        let mut points: Vec<_> = self.0.iter().map(Canonicalize::canonicalize).collect();
+        points.sort_by(|a, b| cmp_coords(&a.0, &b.0));
+        MultiPoint(points)
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for MultiLineString<T> {
+    fn canonicalize(&self) -> Self {
+        let mut line_strings: Vec<_> = self.0.iter().map(Canonicalize::canonicalize).collect();
+        line_strings.sort_by(cmp_line_strings);
+        MultiLineString(line_strings)
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for MultiPolygon<T> {
+    fn canonicalize(&self) -> Self {
+        let mut polygons: Vec<_> = self.0.iter().map(Canonicalize::canonicalize).collect();
+        polygons.sort_by(cmp_polygons);
+        MultiPolygon(polygons)
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Rect<T> {
+    fn canonicalize(&self) -> Self {
+        Rect::new(self.min().canonicalize(), self.max().canonicalize())
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Triangle<T> {
+    fn canonicalize(&self) -> Self {
+        let ring = canonical_ring(
+            Polygon::from(*self).exterior(),
+            WindingOrder::CounterClockwise,
+        );
+        Triangle(ring.0[0], ring.0[1], ring.0[2])
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for GeometryCollection<T> {
+    fn canonicalize(&self) -> Self {
+        let mut geometries: Vec<_> = self.0.iter().map(Canonicalize::canonicalize).collect();
+        geometries.sort_by(cmp_geometries);
+        GeometryCollection(geometries)
+    }
+}
+
+impl<T: CoordinateType> Canonicalize<T> for Geometry<T> {
+    fn canonicalize(&self) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.canonicalize()),
+            Geometry::Line(g) => Geometry::Line(g.canonicalize()),
+            Geometry::LineString(g) => Geometry::LineString(g.canonicalize()),
+            Geometry::Polygon(g) => Geometry::Polygon(g.canonicalize()),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.canonicalize()),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.canonicalize()),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.canonicalize()),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.canonicalize()),
+            Geometry::Rect(g) => Geometry::Rect(g.canonicalize()),
+            Geometry::Triangle(g) => Geometry::Triangle(g.canonicalize()),
+        }
+    }
+}
+
+// Keep the first of the members with the same canonical form
+fn dedup<G, T>(members: &[G]) -> Vec<G>
+where
+    G: Canonicalize<T> + TotalEq + Clone,
+{
+    let mut seen = HashSet::new();
+    members
+        .iter()
+        .filter(|member| seen.insert(Total(member.canonicalize())))
+        .cloned()
+        .collect()
+}
+
+impl<T: CoordinateType + TotalEq> Dedup<T> for MultiPoint<T> {
+    fn dedup(&self) -> Self {
+        MultiPoint(dedup(&self.0))
+    }
+}
+
+impl<T: CoordinateType + TotalEq> Dedup<T> for MultiLineString<T> {
+    fn dedup(&self) -> Self {
+        MultiLineString(dedup(&self.0))
+    }
+}
+
+impl<T: CoordinateType + TotalEq> Dedup<T> for MultiPolygon<T> {
+    fn dedup(&self) -> Self {
+        MultiPolygon(dedup(&self.0))
+    }
+}
+
+impl<T: CoordinateType + TotalEq> Dedup<T> for GeometryCollection<T> {
+    fn dedup(&self) -> Self {
+        GeometryCollection(dedup(&self.0))
+    }
+}
+
+// Orient a closed ring, and start it at its least coordinate. If that coordinate is repeated, or
+// the ring has no winding order, the least of the candidates is picked.
+fn canonical_ring<T: CoordinateType>(ring: &LineString<T>, winding: WindingOrder) -> LineString<T> {
+    let coords: Vec<_> = ring.0.iter().map(Canonicalize::canonicalize).collect();
+    let closed = coords.len() > 1 && cmp_coords(&coords[0], &coords[coords.len() - 1]).is_eq();
+    if !closed {
+        return LineString(coords);
+    }
+    let forwards = &coords[..coords.len() - 1];
+    let backwards: Vec<_> = forwards.iter().rev().cloned().collect();
+    let directions = match ring.winding_order() {
+        Some(order) if order == winding => vec![forwards],
+        Some(_) => vec![&backwards[..]],
+        None => vec![forwards, &backwards[..]],
+    };
+    let least = forwards
+        .iter()
+        .min_by(|a, b| cmp_coords(a, b))
+        .expect("a closed ring has coordinates");
+    let mut best: Option<Vec<Coordinate<T>>> = None;
+    for direction in directions {
+        for start in 0..direction.len() {
+            if !cmp_coords(&direction[start], least).is_eq() {
+                continue;
+            }
+            let rotated: Vec<_> = direction[start..]
+                .iter()
+                .chain(&direction[..start])
+                .cloned()
+                .collect();
+            let better = match &best {
+                Some(best) => cmp_slices(&rotated, best, cmp_coords) == Ordering::Less,
+                None => true,
+            };
+            if better {
+                best = Some(rotated);
+            }
+        }
+    }
+    let mut best = best.expect("a closed ring starts somewhere");
+    best.push(best[0]);
+    LineString(best)
+}
+
+// Lexicographically, so a slice is less than any longer slice it starts
+fn cmp_slices<G>(a: &[G], b: &[G], cmp: impl Fn(&G, &G) -> Ordering) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn cmp_line_strings<T: CoordinateType>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+    cmp_slices(&a.0, &b.0, cmp_coords)
+}
+
+fn cmp_polygons<T: CoordinateType>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering {
+    cmp_line_strings(a.exterior(), b.exterior())
+        .then_with(|| cmp_slices(a.interiors(), b.interiors(), cmp_line_strings))
+}
+
+fn cmp_geometries<T: CoordinateType>(a: &Geometry<T>, b: &Geometry<T>) -> Ordering {
+    match (a, b) {
+        (Geometry::Point(a), Geometry::Point(b)) => cmp_coords(&a.0, &b.0),
+        (Geometry::Line(a), Geometry::Line(b)) => {
+            cmp_coords(&a.start, &b.start).then_with(|| cmp_coords(&a.end, &b.end))
+        }
+        (Geometry::LineString(a), Geometry::LineString(b)) => cmp_line_strings(a, b),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => cmp_polygons(a, b),
+        (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => {
+            cmp_slices(&a.0, &b.0, |a, b| cmp_coords(&a.0, &b.0))
+        }
+        (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_line_strings)
+        }
+        (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_polygons)
+        }
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_geometries)
+        }
+        (Geometry::Rect(a), Geometry::Rect(b)) => {
+            cmp_coords(&a.min(), &b.min()).then_with(|| cmp_coords(&a.max(), &b.max()))
+        }
+        (Geometry::Triangle(a), Geometry::Triangle(b)) => cmp_coords(&a.0, &b.0)
+            .then_with(|| cmp_coords(&a.1, &b.1))
+            .then_with(|| cmp_coords(&a.2, &b.2)),
+        _ => type_index(a).cmp(&type_index(b)),
+    }
+}
+
+fn type_index<T: CoordinateType>(geometry: &Geometry<T>) -> u8 {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn rings() {
+        let polygon = polygon!(
+            exterior: [(x: 2f64, y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)],
+            interiors: [
+                [(x: 1.5, y: 1.5), (x: 1.75, y: 1.5), (x: 1.75, y: 1.75)],
+                [(x: 0.5, y: 0.5), (x: 0.75, y: 0.5), (x: -0., y: 0.75)],
+            ],
+        );
+        let canonical = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            interiors: [
+                [(x: 0., y: 0.75), (x: 0.75, y: 0.5), (x: 0.5, y: 0.5)],
+                [(x: 1.5, y: 1.5), (x: 1.75, y: 1.75), (x: 1.75, y: 1.5)],
+            ],
+        );
+        assert_eq!(polygon.canonicalize(), canonical);
+        assert!(polygon.canonicalize().interiors()[0].0[0]
+            .x
+            .is_sign_positive());
+        assert_eq!(canonical.canonicalize(), canonical);
+
+        let triangle = Triangle::from([(1, 1), (0, 2), (0, 0)]);
+        assert_eq!(
+            triangle.canonicalize(),
+            Triangle::from([(0, 0), (1, 1), (0, 2)])
+        );
+
+        // a ring without area, and one touching itself, at its least coordinate
+        let flat = polygon![(x: 1, y: 1), (x: 0, y: 0), (x: 2, y: 2)];
+        assert_eq!(
+            flat.canonicalize(),
+            polygon![(x: 0, y: 0), (x: 1, y: 1), (x: 2, y: 2)]
+        );
+        let bowtie = polygon![
+            (x: 0, y: 0), (x: 1, y: 1), (x: 0, y: 1), (x: 0, y: 0), (x: 1, y: -1), (x: 1, y: 0)
+        ];
+        let canonical = bowtie.canonicalize();
+        assert_eq!(
+            canonical.exterior().0,
+            line_string![
+                (x: 0, y: 0), (x: 1, y: -1), (x: 1, y: 0), (x: 0, y: 0), (x: 1, y: 1),
+                (x: 0, y: 1), (x: 0, y: 0)
+            ]
+            .0
+        );
+        assert_eq!(canonical.canonicalize(), canonical);
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            Line::new((1, 0), (0, 5)).canonicalize(),
+            Line::new((0, 5), (1, 0))
+        );
+        let line_string = line_string![(x: 1, y: 0), (x: 0, y: 5), (x: 1, y: 0)];
+        assert_eq!(line_string.canonicalize(), line_string);
+        assert_eq!(
+            line_string![(x: 3, y: 0), (x: 0, y: 5), (x: 1, y: 0)].canonicalize(),
+            line_string![(x: 1, y: 0), (x: 0, y: 5), (x: 3, y: 0)]
+        );
+    }
+
+    #[test]
+    fn collections() {
+        let points = MultiPoint(vec![
+            point!(x: 1., y: f64::NAN),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 3.),
+        ]);
+        let canonical = points.canonicalize();
+        assert_eq!(canonical.0[0], point!(x: 0., y: 3.));
+        assert_eq!(canonical.0[1], point!(x: 1., y: 0.));
+        assert!(canonical.0[2].y().is_nan());
+
+        let polygons = MultiPolygon(vec![
+            polygon![(x: 2, y: 0), (x: 3, y: 0), (x: 3, y: 1)],
+            polygon![(x: 0, y: 0), (x: 1, y: 1), (x: 1, y: 0)],
+        ]);
+        assert_eq!(
+            polygons.canonicalize(),
+            MultiPolygon(vec![
+                polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1)],
+                polygon![(x: 2, y: 0), (x: 3, y: 0), (x: 3, y: 1)],
+            ])
+        );
+
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygons.0[1].clone()),
+            Geometry::MultiPolygon(polygons.clone()),
+            Geometry::Point(point!(x: 5, y: 5)),
+            Geometry::Polygon(polygons.0[0].clone()),
+        ]);
+        let canonical = collection.canonicalize();
+        assert_eq!(
+            canonical,
+            GeometryCollection(vec![
+                Geometry::Point(point!(x: 5, y: 5)),
+                Geometry::Polygon(polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1)]),
+                Geometry::Polygon(polygon![(x: 2, y: 0), (x: 3, y: 0), (x: 3, y: 1)]),
+                Geometry::MultiPolygon(polygons.canonicalize()),
+            ])
+        );
+        assert_eq!(canonical.canonicalize(), canonical);
+    }
+
+    #[test]
+    fn dedup() {
+        let polygons = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.)],
+            polygon![(x: 1., y: 0.), (x: 1., y: 1.), (x: -0., y: 0.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)],
+            polygon![(x: 1., y: 1.), (x: 0., y: 0.), (x: 1., y: 0.)],
+        ]);
+        assert_eq!(
+            polygons.dedup().0,
+            vec![polygons.0[0].clone(), polygons.0[2].clone()]
+        );
+
+        let points = MultiPoint(vec![
+            point!(x: f64::NAN, y: 0.),
+            point!(x: f64::NAN, y: -0.),
+        ]);
+        assert_eq!(points.dedup().0.len(), 1);
+
+        // a line and a line string with the same coordinates aren't the same
+        let collection = GeometryCollection(vec![
+            Geometry::Line(Line::new((0, 0), (1, 1))),
+            Geometry::LineString(line_string![(x: 1, y: 1), (x: 0, y: 0)]),
+            Geometry::Line(Line::new((1, 1), (0, 0))),
+        ]);
+        assert_eq!(collection.dedup().0, collection.0[..2]);
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+use num_traits::Float;
+
+/// Resample a geometry along a [centripetal Catmull–Rom spline] through its vertices, for
+/// displaying jagged tracks or simplified boundaries as smooth curves.
+///
+/// Unlike [`ChaikinSmoothing`](../chaikin_smoothing/trait.ChaikinSmoothing.html), the curve
+/// passes through every vertex of the geometry. The centripetal parameterization spaces the
+/// curve by the square root of the distances between vertices, so it never overshoots into loops
+/// or cusps where vertices are unevenly spaced. A `LineString` is extended past its ends by
+/// reflecting its first and last segments, unless it is closed, in which case the curve runs
+/// all the way round, as it does for the rings of `Polygon`s.
+///
+/// [centripetal Catmull–Rom spline]: https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline
+pub trait CatmullRom<T: Float> {
+    /// Return the geometry with each segment between distinct vertices replaced by
+    /// `subdivisions` pieces of the spline through them.
+    ///
+    /// Repeated vertices are dropped, and a `subdivisions` of `0` or `1` leaves the remaining
+    /// vertices as they are.
+    ///
+    /// # Panics
+    ///
+    /// If the number of coordinates of the curve would overflow `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::catmull_rom::CatmullRom;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 4.), (x: 8., y: 0.)];
+    /// let curve = line_string.catmull_rom(4);
+    ///
+    /// assert_eq!(curve.0.len(), 9);
+    /// // the curve passes through the vertices, and rounds off the peak
+    /// assert_eq!(curve.0[4], line_string.0[1]);
+    /// assert!(curve.0[3].y > 3. && curve.0[5].y > 3.);
+    /// ```
+    fn catmull_rom(&self, subdivisions: usize) -> Self;
+}
+
+impl<T: Float> CatmullRom<T> for LineString<T> {
+    fn catmull_rom(&self, subdivisions: usize) -> Self {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 || subdivisions < 2 {
+            return LineString(coords);
+        }
+        let closed = coords.len() > 3 && coords.first() == coords.last();
+        if closed {
+            coords.pop();
+        }
+        let count = coords.len();
+        let two = T::one() + T::one();
+        // the vertex `offset` after the one at `index`, wrapping round a ring, or reflected
+        // past the ends of an open line
+        let neighbour = |index: usize, offset: isize| -> Coordinate<T> {
+            let target = index as isize + offset;
+            if closed {
+                coords[target.rem_euclid(count as isize) as usize]
+            } else if target < 0 {
+                let (first, second) = (coords[0], coords[1]);
+                Coordinate {
+                    x: first.x * two - second.x,
+                    y: first.y * two - second.y,
+                }
+            } else if target as usize >= count {
+                let (last, before) = (coords[count - 1], coords[count - 2]);
+                Coordinate {
+                    x: last.x * two - before.x,
+                    y: last.y * two - before.y,
+                }
+            } else {
+                coords[target as usize]
+            }
+        };
+
+        let segments = if closed { count } else { count - 1 };
+        let length = segments
+            .checked_mul(subdivisions)
+            .and_then(|length| length.checked_add(1))
+            .expect("too many subdivisions for the coordinates of the curve to be counted");
+        let mut curve = Vec::with_capacity(length);
+        for (index, &coord) in coords.iter().enumerate().take(segments) {
+            let points = [
+                neighbour(index, -1),
+                coord,
+                neighbour(index, 1),
+                neighbour(index, 2),
+            ];
+            curve.push(points[1]);
+            for step in 1..subdivisions {
+                let fraction = T::from(step).unwrap() / T::from(subdivisions).unwrap();
+                curve.push(spline_point(&points, fraction));
+            }
+        }
+        curve.push(if closed { coords[0] } else { coords[count - 1] });
+        LineString(curve)
+    }
+}
+
+impl<T: Float> CatmullRom<T> for MultiLineString<T> {
+    fn catmull_rom(&self, subdivisions: usize) -> Self {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|line_string| line_string.catmull_rom(subdivisions))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> CatmullRom<T> for Polygon<T> {
+    fn catmull_rom(&self, subdivisions: usize) -> Self {
+        Polygon::new(
+            self.exterior().catmull_rom(subdivisions),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.catmull_rom(subdivisions))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> CatmullRom<T> for MultiPolygon<T> {
+    fn catmull_rom(&self, subdivisions: usize) -> Self {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.catmull_rom(subdivisions))
+                .collect(),
+        )
+    }
+}
+
+// The point a `fraction` of the way between the middle two of `points` along the centripetal
+// spline, using the Barry and Goldman pyramid
+fn spline_point<T: Float>(points: &[Coordinate<T>; 4], fraction: T) -> Coordinate<T> {
+    let mut knots = [T::zero(); 4];
+    for i in 1..4 {
+        let (start, end) = (points[i - 1], points[i]);
+        knots[i] = knots[i - 1] + (end.x - start.x).hypot(end.y - start.y).sqrt();
+    }
+    let t = knots[1] + (knots[2] - knots[1]) * fraction;
+    let between = |start: Coordinate<T>, end: Coordinate<T>, t_start: T, t_end: T| {
+        let fraction = (t - t_start) / (t_end - t_start);
+        Coordinate {
+            x: start.x + (end.x - start.x) * fraction,
+            y: start.y + (end.y - start.y) * fraction,
+        }
+    };
+    let a1 = between(points[0], points[1], knots[0], knots[1]);
+    let a2 = between(points[1], points[2], knots[1], knots[2]);
+    let a3 = between(points[2], points[3], knots[2], knots[3]);
+    let b1 = between(a1, a2, knots[0], knots[2]);
+    let b2 = between(a2, a3, knots[1], knots[3]);
+    between(b1, b2, knots[1], knots[2])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::{line_string, point, polygon, Point};
+
+    #[test]
+    fn straight_lines_stay_straight() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.), (x: 3., y: 3.)];
+        let curve = line_string.catmull_rom(5);
+        assert_eq!(curve.0.len(), 16);
+        for (i, coord) in curve.0.iter().enumerate() {
+            let expected = i as f64 / 5.;
+            assert_relative_eq!(coord.x, expected, epsilon = 1e-12);
+            assert_relative_eq!(coord.y, expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn rings_approximate_circles() {
+        let ring: LineString<f64> = (0..=8)
+            .map(|i| {
+                let angle = (i % 8) as f64 * std::f64::consts::PI / 4.;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let polygon = Polygon::new(ring, vec![]).catmull_rom(8);
+        let exterior = polygon.exterior();
+        assert_eq!(exterior.0.first(), exterior.0.last());
+        assert_eq!(exterior.0.len(), 65);
+        let center = point!(x: 0., y: 0.);
+        for coord in &exterior.0 {
+            assert_relative_eq!(
+                Point(*coord).euclidean_distance(&center),
+                1.,
+                epsilon = 1e-2
+            );
+        }
+    }
+
+    #[test]
+    fn uneven_vertices_and_degenerate_lines() {
+        // the vertices are passed through, in spite of the repeated one
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10.5, y: 0.5),
+            (x: 0., y: 1.)
+        ];
+        let curve = line_string.catmull_rom(10);
+        assert_eq!(curve.0.len(), 31);
+        assert_eq!(curve.0[10], line_string.0[1]);
+        assert_eq!(curve.0[20], line_string.0[3]);
+        // without overshooting far past the sharp turn
+        for coord in &curve.0 {
+            assert!(coord.x < 11. && coord.y > -0.5 && coord.y < 1.5);
+        }
+
+        let short = line_string![(x: 0., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(short.catmull_rom(4), line_string![(x: 0., y: 0.)]);
+        let pair = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let middle = pair.catmull_rom(2).0[1];
+        assert_relative_eq!(middle.x, 1., epsilon = 1e-12);
+        assert_relative_eq!(middle.y, 0., epsilon = 1e-12);
+        assert_eq!(line_string.catmull_rom(1).0.len(), 4);
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(square.catmull_rom(3).exterior().0.len(), 13);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_subdivisions() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        line_string.catmull_rom(usize::MAX / 2 + 1);
+    }
+}
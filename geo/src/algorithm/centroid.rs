@@ -1,9 +1,12 @@
-use num_traits::{Float, FromPrimitive};
-use std::iter::Sum;
+use num_traits::Float;
+use std::cmp::Ordering;
 
 use crate::algorithm::area::{get_linestring_area, Area};
 use crate::algorithm::euclidean_length::EuclideanLength;
-use crate::{Line, LineString, MultiPoint, MultiPolygon, Point, Polygon, Rect};
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 
 /// Calculation of the centroid.
 /// The centroid is the arithmetic mean position of all points in the shape.
@@ -12,6 +15,11 @@ use crate::{Line, LineString, MultiPoint, MultiPolygon, Point, Polygon, Rect};
 /// The geometric centroid of a convex object always lies in the object.
 /// A non-convex object might have a centroid that _is outside the object itself_.
 ///
+/// The centroid of a geometry made of parts of different dimensions, like a
+/// `GeometryCollection`, is that of its highest-dimensional parts: polygons outweigh lines,
+/// which outweigh points. Parts that are degenerate, like a polygon without area, count as
+/// the lower-dimensional shape they collapse to. Empty geometries have no centroid.
+///
 /// # Examples
 ///
 /// ```
@@ -47,16 +55,195 @@ pub trait Centroid<T: Float> {
     fn centroid(&self) -> Self::Output;
 }
 
-// Calculation of a Polygon centroid without interior rings
-fn simple_polygon_centroid<T>(poly_ext: &LineString<T>) -> Option<Point<T>>
+/// The dimensions of the parts contributing to a centroid, in increasing order of weight
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Dimensions {
+    Zero,
+    One,
+    Two,
+}
+
+/// A weighted sum of the centroids of the highest-dimensional parts added so far
+struct WeightedCentroid<T: Float> {
+    dimensions: Dimensions,
+    weight: T,
+    accumulated: Coordinate<T>,
+}
+
+struct CentroidOperation<T: Float>(Option<WeightedCentroid<T>>);
+
+impl<T: Float> CentroidOperation<T> {
+    fn new() -> Self {
+        CentroidOperation(None)
+    }
+
+    fn centroid(&self) -> Option<Point<T>> {
+        self.0.as_ref().map(|weighted| {
+            Point::new(
+                weighted.accumulated.x / weighted.weight,
+                weighted.accumulated.y / weighted.weight,
+            )
+        })
+    }
+
+    fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coordinate<T>, weight: T) {
+        let accumulated = Coordinate {
+            x: centroid.x * weight,
+            y: centroid.y * weight,
+        };
+        self.add_weighted_sum(dimensions, accumulated, weight);
+    }
+
+    fn add_weighted_sum(&mut self, dimensions: Dimensions, accumulated: Coordinate<T>, weight: T) {
+        match &mut self.0 {
+            Some(current) => match dimensions.cmp(&current.dimensions) {
+                Ordering::Less => {}
+                Ordering::Equal => {
+                    current.weight = current.weight + weight;
+                    current.accumulated = Coordinate {
+                        x: current.accumulated.x + accumulated.x,
+                        y: current.accumulated.y + accumulated.y,
+                    };
+                }
+                Ordering::Greater => {
+                    *current = WeightedCentroid {
+                        dimensions,
+                        weight,
+                        accumulated,
+                    }
+                }
+            },
+            None => {
+                self.0 = Some(WeightedCentroid {
+                    dimensions,
+                    weight,
+                    accumulated,
+                })
+            }
+        }
+    }
+
+    fn add_coord(&mut self, coord: Coordinate<T>) {
+        self.add_centroid(Dimensions::Zero, coord, T::one());
+    }
+
+    fn add_point(&mut self, point: &Point<T>) {
+        // an empty point has no position to weigh in
+        if !point.is_empty() {
+            self.add_coord(point.0);
+        }
+    }
+
+    fn add_line(&mut self, line: &Line<T>) {
+        let length = line.euclidean_length();
+        if length == T::zero() {
+            self.add_coord(line.start);
+        } else {
+            self.add_centroid(Dimensions::One, line.centroid().0, length);
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        if line_string.0.len() == 1 {
+            self.add_coord(line_string.0[0]);
+        }
+        for line in line_string.lines() {
+            self.add_line(&line);
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        let exterior_area = get_linestring_area(polygon.exterior()).abs();
+        if exterior_area == T::zero() {
+            // a polygon without area is considered as its exterior line string
+            self.add_line_string(polygon.exterior());
+            return;
+        }
+        let exterior_centroid = simple_polygon_centroid(polygon.exterior());
+        let (interior_x, interior_y, interior_area) = polygon
+            .interiors()
+            .iter()
+            .filter_map(|ring| {
+                let area = get_linestring_area(ring).abs();
+                if area == T::zero() {
+                    return None;
+                }
+                let centroid = simple_polygon_centroid(ring);
+                Some((centroid.x * area, centroid.y * area, area))
+            })
+            .fold((T::zero(), T::zero(), T::zero()), |accum, val| {
+                (accum.0 + val.0, accum.1 + val.1, accum.2 + val.2)
+            });
+        let area = exterior_area - interior_area;
+        if area == T::zero() {
+            // the holes cover the whole polygon
+            self.add_line_string(polygon.exterior());
+        } else {
+            let accumulated = Coordinate {
+                x: exterior_centroid.x * exterior_area - interior_x,
+                y: exterior_centroid.y * exterior_area - interior_y,
+            };
+            self.add_weighted_sum(Dimensions::Two, accumulated, area);
+        }
+    }
+
+    fn add_rect(&mut self, rect: &Rect<T>) {
+        let (min, max) = (rect.min(), rect.max());
+        if rect.width() != T::zero() && rect.height() != T::zero() {
+            let area = rect.width() * rect.height();
+            self.add_centroid(Dimensions::Two, rect.centroid().0, area);
+        } else {
+            self.add_line(&Line::new(min, max));
+        }
+    }
+
+    fn add_triangle(&mut self, triangle: &Triangle<T>) {
+        let area = triangle.unsigned_area();
+        if area != T::zero() {
+            let three = T::one() + T::one() + T::one();
+            let centroid = Coordinate {
+                x: (triangle.0.x + triangle.1.x + triangle.2.x) / three,
+                y: (triangle.0.y + triangle.1.y + triangle.2.y) / three,
+            };
+            self.add_centroid(Dimensions::Two, centroid, area);
+        } else {
+            // a collinear triangle is the line between its two most distant vertices
+            let longest = triangle.to_lines().iter().fold(
+                Line::new(triangle.0, triangle.0),
+                |longest, line| {
+                    if line.euclidean_length() > longest.euclidean_length() {
+                        *line
+                    } else {
+                        longest
+                    }
+                },
+            );
+            self.add_line(&longest);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.add_point(g),
+            Geometry::Line(g) => self.add_line(g),
+            Geometry::LineString(g) => self.add_line_string(g),
+            Geometry::Polygon(g) => self.add_polygon(g),
+            Geometry::MultiPoint(g) => g.0.iter().for_each(|p| self.add_point(p)),
+            Geometry::MultiLineString(g) => g.0.iter().for_each(|l| self.add_line_string(l)),
+            Geometry::MultiPolygon(g) => g.0.iter().for_each(|p| self.add_polygon(p)),
+            Geometry::GeometryCollection(g) => g.0.iter().for_each(|g| self.add_geometry(g)),
+            Geometry::Rect(g) => self.add_rect(g),
+            Geometry::Triangle(g) => self.add_triangle(g),
+        }
+    }
+}
+
+// Calculation of the centroid of a ring with a non-zero area
+fn simple_polygon_centroid<T>(poly_ext: &LineString<T>) -> Coordinate<T>
 where
-    T: Float + FromPrimitive + Sum,
+    T: Float,
 {
     let area = get_linestring_area(poly_ext);
-    if area == T::zero() {
-        // if the polygon is flat (area = 0), it is considered as a linestring
-        return poly_ext.centroid();
-    }
     let (sum_x, sum_y) = poly_ext
         .lines()
         .fold((T::zero(), T::zero()), |accum, line| {
@@ -66,8 +253,11 @@ where
                 accum.1 + ((line.end.y + line.start.y) * tmp),
             )
         });
-    let six = T::from_i32(6).unwrap();
-    Some(Point::new(sum_x / (six * area), sum_y / (six * area)))
+    let six = T::from(6).unwrap();
+    Coordinate {
+        x: sum_x / (six * area),
+        y: sum_y / (six * area),
+    }
 }
 
 impl<T> Centroid<T> for Line<T>
@@ -93,36 +283,30 @@ where
     // The Centroid of a LineString is the mean of the middle of the segment
     // weighted by the length of the segments.
     fn centroid(&self) -> Self::Output {
-        if self.0.is_empty() {
-            return None;
-        }
-        if self.0.len() == 1 {
-            Some(Point(self.0[0]))
-        } else {
-            let (sum_x, sum_y, total_length) =
-                self.lines()
-                    .fold((T::zero(), T::zero(), T::zero()), |accum, line| {
-                        let segment_len = line.euclidean_length();
-                        let line_center = line.centroid();
-                        (
-                            accum.0 + segment_len * line_center.x(),
-                            accum.1 + segment_len * line_center.y(),
-                            accum.2 + segment_len,
-                        )
-                    });
-            if total_length == T::zero() {
-                // length == 0 means that all points were equal, we can just the first one
-                Some(Point(self.0[0]))
-            } else {
-                Some(Point::new(sum_x / total_length, sum_y / total_length))
-            }
+        let mut operation = CentroidOperation::new();
+        operation.add_line_string(self);
+        operation.centroid()
+    }
+}
+
+impl<T> Centroid<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        for line_string in &self.0 {
+            operation.add_line_string(line_string);
         }
+        operation.centroid()
     }
 }
 
 impl<T> Centroid<T> for Polygon<T>
 where
-    T: Float + FromPrimitive + Sum,
+    T: Float,
 {
     type Output = Option<Point<T>>;
 
@@ -136,99 +320,24 @@ where
     // See here for a formula: http://math.stackexchange.com/a/623849
     // See here for detail on alternative methods: https://fotino.me/calculating-centroids/
     fn centroid(&self) -> Self::Output {
-        let linestring = &self.exterior();
-        let vect = &linestring.0;
-        if vect.is_empty() {
-            return None;
-        }
-        if vect.len() == 1 {
-            Some(Point::new(vect[0].x, vect[0].y))
-        } else {
-            let external_centroid = simple_polygon_centroid(self.exterior())?;
-            if self.interiors().is_empty() {
-                Some(external_centroid)
-            } else {
-                let external_area = get_linestring_area(self.exterior()).abs();
-                // accumulate interior Polygons
-                let (totals_x, totals_y, internal_area) = self
-                    .interiors()
-                    .iter()
-                    .filter_map(|ring| {
-                        let area = get_linestring_area(ring).abs();
-                        let centroid = simple_polygon_centroid(ring)?;
-                        Some((centroid.x() * area, centroid.y() * area, area))
-                    })
-                    .fold((T::zero(), T::zero(), T::zero()), |accum, val| {
-                        (accum.0 + val.0, accum.1 + val.1, accum.2 + val.2)
-                    });
-
-                let diff_area = external_area - internal_area;
-                if diff_area == T::zero() {
-                    Some(external_centroid)
-                } else {
-                    Some(Point::new(
-                        ((external_centroid.x() * external_area) - totals_x) / diff_area,
-                        ((external_centroid.y() * external_area) - totals_y) / diff_area,
-                    ))
-                }
-            }
-        }
+        let mut operation = CentroidOperation::new();
+        operation.add_polygon(self);
+        operation.centroid()
     }
 }
 
 impl<T> Centroid<T> for MultiPolygon<T>
 where
-    T: Float + FromPrimitive + Sum,
+    T: Float,
 {
     type Output = Option<Point<T>>;
 
     fn centroid(&self) -> Self::Output {
-        let mut sum_area_x = T::zero();
-        let mut sum_area_y = T::zero();
-        let mut sum_seg_x = T::zero();
-        let mut sum_seg_y = T::zero();
-        let mut sum_x = T::zero();
-        let mut sum_y = T::zero();
-        let mut total_area = T::zero();
-        let mut total_length = T::zero();
-        let vect = &self.0;
-        if vect.is_empty() {
-            return None;
-        }
-        for poly in &self.0 {
-            // the area is signed
-            let area = poly.area().abs();
-            total_area = total_area + area;
-            if let Some(p) = poly.centroid() {
-                if area != T::zero() {
-                    sum_area_x = sum_area_x + area * p.x();
-                    sum_area_y = sum_area_y + area * p.y();
-                } else {
-                    // the polygon is 'flat', we consider it as a linestring
-                    let ls_len = poly.exterior().euclidean_length();
-                    if ls_len == T::zero() {
-                        sum_x = sum_x + p.x();
-                        sum_y = sum_y + p.x();
-                    } else {
-                        sum_seg_x = sum_seg_x + ls_len * p.x();
-                        sum_seg_y = sum_seg_y + ls_len * p.y();
-                        total_length = total_length + ls_len;
-                    }
-                }
-            }
-        }
-        if total_area != T::zero() {
-            Some(Point::new(sum_area_x / total_area, sum_area_y / total_area))
-        } else if total_length != T::zero() {
-            Some(Point::new(
-                sum_seg_x / total_length,
-                sum_seg_y / total_length,
-            ))
-        } else {
-            let nb_points = T::from_usize(self.0.len()).unwrap();
-            // there was only "point" polygons, we do a simple centroid of all points
-            Some(Point::new(sum_x / nb_points, sum_y / nb_points))
+        let mut operation = CentroidOperation::new();
+        for polygon in &self.0 {
+            operation.add_polygon(polygon);
         }
+        operation.centroid()
     }
 }
 
@@ -247,6 +356,23 @@ where
     }
 }
 
+impl<T> Centroid<T> for Triangle<T>
+where
+    T: Float,
+{
+    type Output = Point<T>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        operation.add_triangle(self);
+        operation
+            .centroid()
+            .expect("a triangle always has a centroid")
+    }
+}
+
+/// The centroid of an [empty point](../../struct.Point.html#method.is_empty) is itself, though
+/// collections leave empty points out of their centroids.
 impl<T> Centroid<T> for Point<T>
 where
     T: Float,
@@ -267,6 +393,10 @@ where
 /// let empty_multi_points: MultiPoint<_> = empty.into();
 /// assert_eq!(empty_multi_points.centroid(), None);
 ///
+/// // empty points are left out
+/// let points = MultiPoint(vec![Point::empty(), Point::new(1., 2.)]);
+/// assert_eq!(points.centroid(), Some(Point::new(1., 2.)));
+///
 /// let points: MultiPoint<_> = vec![(5., 1.), (1., 3.), (3., 2.)].into();
 /// assert_eq!(points.centroid(), Some(Point::new(3., 2.)));
 /// ```
@@ -277,17 +407,55 @@ where
     type Output = Option<Point<T>>;
 
     fn centroid(&self) -> Self::Output {
-        if self.0.is_empty() {
-            return None;
+        let mut operation = CentroidOperation::new();
+        for point in &self.0 {
+            operation.add_point(point);
         }
-        let sum = self.0.iter().fold(
-            Point::new(T::zero(), T::zero()),
-            |a: Point<T>, b: &Point<T>| Point::new(a.x() + b.x(), a.y() + b.y()),
-        );
-        Some(Point::new(
-            sum.x() / T::from(self.0.len()).unwrap(),
-            sum.y() / T::from(self.0.len()).unwrap(),
-        ))
+        operation.centroid()
+    }
+}
+
+/// The centroid of a `Geometry` is `None` if it's empty.
+impl<T> Centroid<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        operation.add_geometry(self);
+        operation.centroid()
+    }
+}
+
+///
+/// ```
+/// use geo::algorithm::centroid::Centroid;
+/// use geo::{line_string, point, polygon, Geometry, GeometryCollection, Point};
+///
+/// let collection = GeometryCollection(vec![
+///     Geometry::Point(point!(x: 10., y: 10.)),
+///     Geometry::LineString(line_string![(x: 10., y: 0.), (x: 10., y: 10.)]),
+///     Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]),
+/// ]);
+///
+/// // the polygon outweighs the line string and the point
+/// assert_eq!(collection.centroid(), Some(Point::new(1., 1.)));
+/// assert_eq!(GeometryCollection::<f64>(vec![]).centroid(), None);
+/// ```
+impl<T> Centroid<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        for geometry in &self.0 {
+            operation.add_geometry(geometry);
+        }
+        operation.centroid()
     }
 }
 
@@ -297,7 +465,8 @@ mod test {
     use crate::algorithm::euclidean_distance::EuclideanDistance;
     use crate::line_string;
     use crate::{
-        polygon, Coordinate, Line, LineString, MultiPolygon, Point, Polygon, Rect, COORD_PRECISION,
+        polygon, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle, COORD_PRECISION,
     };
     use num_traits::Float;
 
@@ -496,6 +665,7 @@ mod test {
         assert_eq!(MultiPolygon(vec![poly]).centroid(), Some(p(1., 1.)));
     }
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn multipolygon_two_polygons_test() {
         let linestring =
             LineString::from(vec![p(2., 1.), p(5., 1.), p(5., 3.), p(2., 3.), p(2., 1.)]);
@@ -531,4 +701,76 @@ mod test {
         let line1 = Line::new(c(0., 1.), c(1., 3.));
         assert_eq!(line1.centroid(), Point::new(0.5, 2.));
     }
+    #[test]
+    fn multilinestring_test() {
+        let mls = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 0., y: 3.), (x: 0., y: 5.), (x: 2., y: 5.)],
+            line_string![(x: 9., y: 9.)],
+        ]);
+        // the single point doesn't count next to the lines
+        assert_eq!(mls.centroid(), Some(p(2. / 3., 3.)));
+        assert_eq!(MultiLineString::<f64>(vec![]).centroid(), None);
+    }
+    #[test]
+    fn triangle_test() {
+        let triangle = Triangle(c(0., 0.), c(3., 0.), c(0., 3.));
+        assert_eq!(triangle.centroid(), p(1., 1.));
+        let collinear = Triangle(c(0., 0.), c(4., 4.), c(1., 1.));
+        assert_eq!(collinear.centroid(), p(2., 2.));
+    }
+    #[test]
+    fn degenerate_rect_test() {
+        let rect = Rect::new(c(0., 0.), c(0., 4.));
+        let collection =
+            GeometryCollection(vec![Geometry::Rect(rect), Geometry::Point(p(10., 10.))]);
+        assert_eq!(collection.centroid(), Some(p(0., 2.)));
+    }
+    #[test]
+    fn empty_points_test() {
+        let empty = Point::<f64>::empty();
+        assert!(empty.centroid().is_empty());
+        assert_eq!(MultiPoint(vec![empty]).centroid(), None);
+        assert_eq!(Geometry::Point(empty).centroid(), None);
+        let collection = GeometryCollection(vec![
+            Geometry::Point(empty),
+            Geometry::MultiPoint(MultiPoint(vec![empty, p(2., 4.)])),
+            Geometry::Point(p(4., 2.)),
+        ]);
+        assert_eq!(collection.centroid(), Some(p(3., 3.)));
+    }
+    #[test]
+    fn geometry_collection_dimensions_test() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let line = Line::new(c(10., 0.), c(10., 10.));
+        let points = MultiPoint(vec![p(20., 20.), p(30., 30.)]);
+
+        let puntal = GeometryCollection(vec![Geometry::MultiPoint(points.clone())]);
+        assert_eq!(puntal.centroid(), Some(p(25., 25.)));
+
+        let linear = GeometryCollection(vec![
+            Geometry::MultiPoint(points.clone()),
+            Geometry::Line(line),
+        ]);
+        assert_eq!(linear.centroid(), Some(p(10., 5.)));
+
+        let areal = GeometryCollection(vec![
+            Geometry::MultiPoint(points),
+            Geometry::Line(line),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(square)])),
+        ]);
+        assert_eq!(areal.centroid(), Some(p(1., 1.)));
+        assert_eq!(
+            Geometry::GeometryCollection(areal).centroid(),
+            Some(p(1., 1.))
+        );
+    }
+    #[test]
+    fn geometry_collection_empty_test() {
+        let collection = GeometryCollection::<f64>(vec![
+            Geometry::LineString(line_string![]),
+            Geometry::MultiPolygon(MultiPolygon(vec![])),
+        ]);
+        assert_eq!(collection.centroid(), None);
+    }
 }
@@ -0,0 +1,163 @@
+use crate::{Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+use num_traits::Float;
+
+/// Smooth a geometry using [Chaikin's algorithm], for displaying jagged tracks or simplified
+/// boundaries.
+///
+/// In each iteration, every corner is cut off: each segment is replaced by the two points a
+/// quarter and three quarters of the way along it. A `LineString` keeps its first and last
+/// coordinates, unless it is closed, in which case it is smoothed all the way round, as are the
+/// rings of `Polygon`s. The number of coordinates roughly doubles with each iteration, and the
+/// result tends to a quadratic B-spline.
+///
+/// [Chaikin's algorithm]: https://www.cs.unc.edu/~dm/UNC/COMP258/LECTURES/Chaikins-Algorithm.pdf
+pub trait ChaikinSmoothing<T: Float> {
+    /// Return the geometry, smoothed by cutting off its corners `n_iterations` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::chaikin_smoothing::ChaikinSmoothing;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.)];
+    /// let smoothed = line_string.chaikin_smoothing(1);
+    ///
+    /// assert_eq!(
+    ///     smoothed,
+    ///     line_string![
+    ///         (x: 0., y: 0.),
+    ///         (x: 2., y: 0.),
+    ///         (x: 6., y: 0.),
+    ///         (x: 8., y: 2.),
+    ///         (x: 8., y: 6.),
+    ///         (x: 8., y: 8.),
+    ///     ]
+    /// );
+    /// ```
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self;
+}
+
+impl<T: Float> ChaikinSmoothing<T> for LineString<T> {
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+        let mut line_string = self.clone();
+        for _ in 0..n_iterations {
+            line_string = smooth_once(&line_string);
+        }
+        line_string
+    }
+}
+
+impl<T: Float> ChaikinSmoothing<T> for MultiLineString<T> {
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+        MultiLineString(
+            self.0
+                .iter()
+                .map(|line_string| line_string.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> ChaikinSmoothing<T> for Polygon<T> {
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+        Polygon::new(
+            self.exterior().chaikin_smoothing(n_iterations),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> ChaikinSmoothing<T> for MultiPolygon<T> {
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
+fn smooth_once<T: Float>(line_string: &LineString<T>) -> LineString<T> {
+    let coords = &line_string.0;
+    if coords.len() < 3 {
+        // there are no corners to cut
+        return line_string.clone();
+    }
+    let closed = coords.first() == coords.last();
+    let (first, last) = (coords[0], coords[coords.len() - 1]);
+    let quarter = T::from(0.25).unwrap();
+    let three_quarters = T::from(0.75).unwrap();
+    let between = |start: Coordinate<T>, end: Coordinate<T>, fraction: T| Coordinate {
+        x: start.x + (end.x - start.x) * fraction,
+        y: start.y + (end.y - start.y) * fraction,
+    };
+
+    let mut smoothed = Vec::with_capacity(coords.len() * 2);
+    if !closed {
+        smoothed.push(first);
+    }
+    for window in coords.windows(2) {
+        smoothed.push(between(window[0], window[1], quarter));
+        smoothed.push(between(window[0], window[1], three_quarters));
+    }
+    if closed {
+        smoothed.push(smoothed[0]);
+    } else {
+        smoothed.push(last);
+    }
+    LineString(smoothed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn open_line_strings_keep_their_ends() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 4., y: 4.), (x: 8., y: 0.), (x: 12., y: 4.)];
+        let smoothed = line_string.chaikin_smoothing(3);
+        assert_eq!(smoothed.0.len(), 2 * 2 * 2 * 4);
+        assert_eq!(smoothed.0.first(), line_string.0.first());
+        assert_eq!(smoothed.0.last(), line_string.0.last());
+        assert_eq!(line_string.chaikin_smoothing(0), line_string);
+        let short = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(short.chaikin_smoothing(5), short);
+    }
+
+    #[test]
+    fn closed_rings_are_smoothed_all_round() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let smoothed = square.chaikin_smoothing(1);
+        assert_eq!(smoothed.exterior().0.first(), smoothed.exterior().0.last());
+        assert_eq!(smoothed.exterior().0.len(), 9);
+        // each corner loses a triangle with sides of 1
+        assert_eq!(smoothed.signed_area(), 14.);
+        let smoother = square.chaikin_smoothing(4);
+        assert!(smoother.signed_area() < 14. && smoother.signed_area() > 12.);
+    }
+
+    #[test]
+    fn holes_and_multi_geometries() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.), (x: 0., y: 8.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 6.), (x: 6., y: 6.), (x: 6., y: 2.)]],
+        ];
+        let smoothed = MultiPolygon(vec![polygon]).chaikin_smoothing(1);
+        // the exterior loses 4 triangles of area 2, and the hole 4 of area 0.5
+        assert_eq!(smoothed.signed_area(), 64. - 8. - (16. - 2.));
+        let multi_line_string = MultiLineString(vec![line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 4.),
+            (x: 8., y: 0.)
+        ]]);
+        assert_eq!(multi_line_string.chaikin_smoothing(2).0[0].0.len(), 12);
+    }
+}
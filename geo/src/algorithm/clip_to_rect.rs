@@ -0,0 +1,435 @@
+use crate::algorithm::winding_order::twice_signed_ring_area;
+use crate::{Coordinate, CoordinateType, LineString, MultiLineString, MultiPolygon, Polygon, Rect};
+
+/// Clip a geometry to an axis-aligned `Rect`, keeping the parts of it inside the `Rect`, or on
+/// its boundary.
+///
+/// This is much faster than the general [`BooleanOps`](../bool_ops/trait.BooleanOps.html), as
+/// each segment or ring is clipped against the four sides of the `Rect` in turn, and geometries
+/// entirely inside or outside the `Rect` are returned without clipping any of their segments.
+/// The coordinates on the sides of the `Rect` are computed with the coordinate type's own
+/// arithmetic, so that they're exactly on the sides, which makes it suitable for cutting
+/// geometries into tiles.
+pub trait ClipToRect<T: CoordinateType> {
+    type Output;
+
+    /// Clip a geometry to `rect`.
+    ///
+    /// `LineString`s are cut into a `MultiLineString` of their parts inside `rect`, dropping
+    /// parts which only touch it at a point.
+    ///
+    /// Each ring of a `Polygon` is clipped with the Sutherland–Hodgman algorithm, and rings
+    /// without area are dropped, so a `Polygon` becomes `None` if its exterior is outside
+    /// `rect`. A concave `Polygon` whose parts inside `rect` are only connected outside it stays
+    /// a single `Polygon`, with the parts joined by edges along the sides of `rect`: this covers
+    /// the right area, but isn't [valid](../validation/trait.Validation.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::clip_to_rect::ClipToRect;
+    /// use geo::{line_string, polygon, Coordinate, MultiLineString, Rect};
+    ///
+    /// let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. });
+    ///
+    /// let line_string = line_string![(x: -2., y: 1.), (x: 2., y: 1.), (x: 6., y: 5.)];
+    /// assert_eq!(
+    ///     line_string.clip_to_rect(&rect),
+    ///     MultiLineString(vec![line_string![(x: 0., y: 1.), (x: 2., y: 1.), (x: 4., y: 3.)]])
+    /// );
+    ///
+    /// let polygon = polygon![(x: 2., y: -2.), (x: 6., y: 2.), (x: 2., y: 6.), (x: 2., y: -2.)];
+    /// assert_eq!(
+    ///     polygon.clip_to_rect(&rect).unwrap(),
+    ///     polygon![(x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 0.), (x: 4., y: 0.)]
+    /// );
+    /// ```
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output;
+}
+
+impl<T: CoordinateType> ClipToRect<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> MultiLineString<T> {
+        let mut parts = vec![];
+        clip_line_string(self, rect, &mut parts);
+        MultiLineString(parts)
+    }
+}
+
+impl<T: CoordinateType> ClipToRect<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> MultiLineString<T> {
+        let mut parts = vec![];
+        for line_string in &self.0 {
+            clip_line_string(line_string, rect, &mut parts);
+        }
+        MultiLineString(parts)
+    }
+}
+
+impl<T: CoordinateType> ClipToRect<T> for Polygon<T> {
+    type Output = Option<Polygon<T>>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Option<Polygon<T>> {
+        match self.exterior().bounding_rect() {
+            Some(bounds) if within(&bounds, rect) => return Some(self.clone()),
+            Some(bounds) if !overlaps(&bounds, rect) => return None,
+            None => return None,
+            Some(_) => {}
+        }
+        let exterior = clip_ring(self.exterior(), rect)?;
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter_map(|interior| clip_ring(interior, rect))
+            .collect();
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+impl<T: CoordinateType> ClipToRect<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .filter_map(|polygon| polygon.clip_to_rect(rect))
+                .collect(),
+        )
+    }
+}
+
+fn within<T: CoordinateType>(bounds: &Rect<T>, rect: &Rect<T>) -> bool {
+    bounds.min().x >= rect.min().x
+        && bounds.min().y >= rect.min().y
+        && bounds.max().x <= rect.max().x
+        && bounds.max().y <= rect.max().y
+}
+
+fn overlaps<T: CoordinateType>(bounds: &Rect<T>, rect: &Rect<T>) -> bool {
+    bounds.min().x <= rect.max().x
+        && bounds.min().y <= rect.max().y
+        && bounds.max().x >= rect.min().x
+        && bounds.max().y >= rect.min().y
+}
+
+// One side of a `Rect`, keeping the half of the plane on the side of the `Rect`
+#[derive(Clone, Copy)]
+struct Side<T> {
+    vertical: bool,
+    bound: T,
+    keep_above: bool,
+}
+
+impl<T: CoordinateType> Side<T> {
+    fn of(rect: &Rect<T>) -> [Side<T>; 4] {
+        let side = |vertical, bound, keep_above| Side {
+            vertical,
+            bound,
+            keep_above,
+        };
+        [
+            side(true, rect.min().x, true),
+            side(true, rect.max().x, false),
+            side(false, rect.min().y, true),
+            side(false, rect.max().y, false),
+        ]
+    }
+
+    fn value(&self, coord: Coordinate<T>) -> T {
+        if self.vertical {
+            coord.x
+        } else {
+            coord.y
+        }
+    }
+
+    fn inside(&self, coord: Coordinate<T>) -> bool {
+        if self.keep_above {
+            self.value(coord) >= self.bound
+        } else {
+            self.value(coord) <= self.bound
+        }
+    }
+
+    // Whether a segment crosses the side, rather than only touching it
+    fn crosses(&self, start: Coordinate<T>, end: Coordinate<T>) -> bool {
+        self.value(start) != self.bound
+            && self.value(end) != self.bound
+            && self.inside(start) != self.inside(end)
+    }
+
+    // The point where a segment which crosses the side meets it, exactly on the side
+    fn intersection(&self, start: Coordinate<T>, end: Coordinate<T>) -> Coordinate<T> {
+        if self.vertical {
+            Coordinate {
+                x: self.bound,
+                y: start.y + (end.y - start.y) * (self.bound - start.x) / (end.x - start.x),
+            }
+        } else {
+            Coordinate {
+                x: start.x + (end.x - start.x) * (self.bound - start.y) / (end.y - start.y),
+                y: self.bound,
+            }
+        }
+    }
+}
+
+// Clip a segment to `rect`, returning the clipped segment, and whether its start and end were
+// moved onto the sides of `rect`
+fn clip_segment<T: CoordinateType>(
+    mut start: Coordinate<T>,
+    mut end: Coordinate<T>,
+    rect: &Rect<T>,
+) -> Option<(Coordinate<T>, Coordinate<T>, bool, bool)> {
+    let (mut start_moved, mut end_moved) = (false, false);
+    for side in &Side::of(rect) {
+        match (side.inside(start), side.inside(end)) {
+            (true, true) => {}
+            (false, false) => return None,
+            (false, true) => {
+                start = side.intersection(start, end);
+                start_moved = true;
+            }
+            (true, false) => {
+                end = side.intersection(start, end);
+                end_moved = true;
+            }
+        }
+    }
+    Some((start, end, start_moved, end_moved))
+}
+
+fn clip_line_string<T: CoordinateType>(
+    line_string: &LineString<T>,
+    rect: &Rect<T>,
+    parts: &mut Vec<LineString<T>>,
+) {
+    match line_string.bounding_rect() {
+        Some(bounds) if within(&bounds, rect) => {
+            if line_string.0.len() > 1 {
+                parts.push(line_string.clone());
+            }
+            return;
+        }
+        Some(bounds) if overlaps(&bounds, rect) => {}
+        _ => return,
+    }
+    let mut part: Vec<Coordinate<T>> = vec![];
+    let mut finish = |part: &mut Vec<Coordinate<T>>| {
+        if part.len() > 1 {
+            parts.push(LineString(std::mem::take(part)));
+        } else {
+            part.clear();
+        }
+    };
+    for [start, end] in line_string.coord_windows() {
+        match clip_segment(start, end, rect) {
+            Some((start, end, start_moved, end_moved)) => {
+                if start_moved {
+                    finish(&mut part);
+                }
+                if part.is_empty() {
+                    part.push(start);
+                }
+                if part.last() != Some(&end) {
+                    part.push(end);
+                }
+                if end_moved {
+                    finish(&mut part);
+                }
+            }
+            None => finish(&mut part),
+        }
+    }
+    finish(&mut part);
+}
+
+// Clip a ring to `rect` with the Sutherland–Hodgman algorithm, returning `None` if nothing with
+// any area is left
+fn clip_ring<T: CoordinateType>(ring: &LineString<T>, rect: &Rect<T>) -> Option<LineString<T>> {
+    let mut coords = ring.0.clone();
+    if coords.first() == coords.last() {
+        coords.pop();
+    }
+    for side in &Side::of(rect) {
+        if coords.is_empty() {
+            return None;
+        }
+        let mut clipped = Vec::with_capacity(coords.len() + 2);
+        for (i, start) in coords.iter().enumerate() {
+            let end = coords[(i + 1) % coords.len()];
+            if side.inside(*start) {
+                clipped.push(*start);
+            }
+            if side.crosses(*start, end) {
+                clipped.push(side.intersection(*start, end));
+            }
+        }
+        coords = clipped;
+    }
+    if coords.len() < 3 {
+        return None;
+    }
+    coords.push(coords[0]);
+    let ring = LineString(coords);
+    if twice_signed_ring_area(&ring) == T::zero() {
+        None
+    } else {
+        Some(ring)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bool_ops::BooleanOps;
+    use crate::{line_string, polygon};
+
+    fn square() -> Rect<f64> {
+        Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. })
+    }
+
+    #[test]
+    fn line_string_in_and_out() {
+        let line_string = line_string![
+            (x: -1., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 5.),
+            (x: 2., y: 5.),
+            (x: 2., y: 3.),
+            (x: 3., y: 3.),
+            (x: 3., y: 6.),
+        ];
+        assert_eq!(
+            line_string.clip_to_rect(&square()),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 4.)],
+                line_string![(x: 2., y: 4.), (x: 2., y: 3.), (x: 3., y: 3.), (x: 3., y: 4.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn line_string_touching() {
+        // only touching a corner, or running along a side
+        let corner = line_string![(x: -1., y: 1.), (x: 0., y: 0.), (x: 1., y: -1.)];
+        assert!(corner.clip_to_rect(&square()).0.is_empty());
+        let along = line_string![(x: -1., y: 0.), (x: 5., y: 0.)];
+        assert_eq!(
+            along.clip_to_rect(&square()),
+            MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 4., y: 0.)]])
+        );
+        // leaving through a vertex on the side
+        let leaving = line_string![(x: 2., y: 2.), (x: 4., y: 2.), (x: 6., y: 2.)];
+        assert_eq!(
+            leaving.clip_to_rect(&square()),
+            MultiLineString(vec![line_string![(x: 2., y: 2.), (x: 4., y: 2.)]])
+        );
+        let outside = line_string![(x: 5., y: 5.), (x: 6., y: 6.)];
+        assert!(outside.clip_to_rect(&square()).0.is_empty());
+        let multi = MultiLineString(vec![leaving.clone(), outside, leaving]);
+        assert_eq!(multi.clip_to_rect(&square()).0.len(), 2);
+    }
+
+    #[test]
+    fn polygon_with_holes() {
+        let polygon = polygon![
+            exterior: [(x: -2., y: -2.), (x: 6., y: -2.), (x: 6., y: 2.), (x: -2., y: 2.)],
+            interiors: [
+                [(x: 3., y: -1.), (x: 3., y: 1.), (x: 5., y: 1.), (x: 5., y: -1.)],
+                [(x: 5., y: 0.), (x: 5., y: 1.), (x: 5.5, y: 1.), (x: 5.5, y: 0.)],
+            ],
+        ];
+        let clipped = polygon.clip_to_rect(&square()).unwrap();
+        assert_eq!(
+            clipped.exterior(),
+            &line_string![(x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 4., y: 0.)]
+        );
+        assert_eq!(
+            clipped.interiors(),
+            &[
+                line_string![(x: 3., y: 0.), (x: 3., y: 1.), (x: 4., y: 1.), (x: 4., y: 0.), (x: 3., y: 0.)]
+            ]
+        );
+        assert_eq!(clipped.unsigned_area(), 7.);
+    }
+
+    #[test]
+    fn polygon_inside_and_outside() {
+        let inside = polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)];
+        assert_eq!(inside.clip_to_rect(&square()), Some(inside));
+        let outside = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 5., y: 6.)];
+        assert_eq!(outside.clip_to_rect(&square()), None);
+        // overlapping bounding rects, but outside
+        let corner = polygon![(x: 3., y: 6.), (x: 6., y: 3.), (x: 6., y: 6.)];
+        assert_eq!(corner.clip_to_rect(&square()), None);
+        // touching a side
+        let touching = polygon![(x: 4., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 4., y: 3.)];
+        assert_eq!(touching.clip_to_rect(&square()), None);
+        let containing = polygon![(x: -2., y: -1.), (x: 6., y: -1.), (x: 2., y: 12.)];
+        assert_eq!(
+            containing.clip_to_rect(&square()).unwrap().unsigned_area(),
+            16.
+        );
+        let multi = MultiPolygon(vec![outside, containing, touching]);
+        assert_eq!(multi.clip_to_rect(&square()).0.len(), 1);
+    }
+
+    #[test]
+    fn concave_matches_intersection() {
+        // a comb, whose teeth are only connected outside the rect
+        let comb = polygon![
+            (x: -1., y: -1.),
+            (x: 5., y: -1.),
+            (x: 5., y: 3.),
+            (x: 4.5, y: 3.),
+            (x: 3., y: -0.5),
+            (x: 2., y: 4.5),
+            (x: 1., y: -0.5),
+            (x: -1., y: 3.),
+        ];
+        let rect = Rect::new(Coordinate { x: 0., y: -2. }, Coordinate { x: 4., y: 4. });
+        let clipped = comb.clip_to_rect(&rect).unwrap();
+        let intersection = comb.intersection(&Polygon::from(rect));
+        assert_eq!(intersection.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), intersection.unsigned_area());
+        let clipped = comb.clip_to_rect(&square());
+        let intersection = comb.intersection(&Polygon::from(square()));
+        assert_eq!(intersection.0.len(), 3);
+        assert_relative_eq!(
+            clipped.unwrap().unsigned_area(),
+            intersection.unsigned_area()
+        );
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI64F64;
+        use num_traits::NumCast;
+
+        let fixed = |x: f64| <FixedI64F64 as NumCast>::from(x).unwrap();
+        let rect = Rect::new(
+            Coordinate {
+                x: fixed(0.),
+                y: fixed(0.),
+            },
+            Coordinate {
+                x: fixed(1.),
+                y: fixed(1.),
+            },
+        );
+        let line_string =
+            LineString::from(vec![(fixed(-1.), fixed(0.25)), (fixed(3.), fixed(1.25))]);
+        let clipped = line_string.clip_to_rect(&rect);
+        assert_eq!(clipped.0.len(), 1);
+        assert_eq!(clipped.0[0].0[0].x, fixed(0.));
+        assert_eq!(clipped.0[0].0[1].x, fixed(1.));
+        assert_eq!(clipped.0[0].0[1].y, fixed(0.75));
+    }
+}
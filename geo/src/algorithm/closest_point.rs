@@ -1,11 +1,19 @@
 use crate::prelude::*;
-use crate::{Closest, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use crate::{
+    Closest, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 use num_traits::Float;
 use std::iter;
 
 /// Find the closest `Point` between a given geometry and an input `Point`.
 /// The closest point may intersect the geometry, be a single
 /// point, or be indeterminate, as indicated by the value of the returned enum.
+/// It's indeterminate if several points of the geometry are equally close, or if the
+/// geometry is empty.
+///
+/// For a polygon, the closest point is on its exterior or interior rings, even if the input
+/// point lies inside the polygon.
 ///
 /// # Examples
 ///
@@ -27,7 +35,7 @@ pub trait ClosestPoint<F: Float, Rhs = Point<F>> {
     fn closest_point(&self, p: &Rhs) -> Closest<F>;
 }
 
-impl<'a, F, C> ClosestPoint<F> for &'a C
+impl<F, C> ClosestPoint<F> for &C
 where
     C: ClosestPoint<F>,
     F: Float,
@@ -86,55 +94,159 @@ impl<F: Float> ClosestPoint<F> for Line<F> {
     }
 }
 
-/// A generic function which takes some iterator of points and gives you the
-/// "best" `Closest` it can find. Where "best" is the first intersection or
-/// the `Closest::SinglePoint` which is closest to `p`.
+/// A generic function which takes the `Closest`s of some points and segments and gives you
+/// the "best" one it can find: the first intersection, or else the `Closest::SinglePoint`
+/// which is closest to `p`. If several different points are equally close, we get
+/// `Closest::Indeterminate`.
 ///
 /// If the iterator is empty, we get `Closest::Indeterminate`.
-fn closest_of<C, F, I>(iter: I, p: Point<F>) -> Closest<F>
+fn closest_of<F, I>(candidates: I, p: Point<F>) -> Closest<F>
 where
     F: Float,
-    I: IntoIterator<Item = C>,
-    C: ClosestPoint<F>,
+    I: IntoIterator<Item = Closest<F>>,
 {
-    let mut best = Closest::Indeterminate;
+    let mut best: Option<(Point<F>, F)> = None;
+    let mut tied = false;
+
+    for candidate in candidates {
+        let point = match candidate {
+            Closest::Intersection(_) => return candidate,
+            // e.g. a zero length segment, which is also an endpoint of its neighbours
+            Closest::Indeterminate => continue,
+            Closest::SinglePoint(point) => point,
+        };
+        let distance = point.euclidean_distance(&p);
+        match best {
+            Some((best_point, best_distance))
+                if distance > best_distance
+                    || (distance == best_distance && point == best_point) => {}
+            Some((_, best_distance)) if distance == best_distance => tied = true,
+            _ => {
+                best = Some((point, distance));
+                tied = false;
+            }
+        }
+    }
 
-    for line_segment in iter {
-        let got = line_segment.closest_point(&p);
-        best = got.best_of_two(&best, p);
+    match best {
+        Some((point, _)) if !tied => Closest::SinglePoint(point),
+        _ => Closest::Indeterminate,
     }
+}
+
+fn line_string_candidates<'a, F: Float>(
+    line_string: &'a LineString<F>,
+    p: Point<F>,
+) -> impl Iterator<Item = Closest<F>> + 'a {
+    line_string.lines().map(move |line| line.closest_point(&p))
+}
 
-    best
+fn polygon_candidates<'a, F: Float>(
+    polygon: &'a Polygon<F>,
+    p: Point<F>,
+) -> impl Iterator<Item = Closest<F>> + 'a {
+    polygon
+        .interiors()
+        .iter()
+        .chain(iter::once(polygon.exterior()))
+        .flat_map(move |ring| line_string_candidates(ring, p))
+}
+
+// The `Closest`s of the points and segments of a `Geometry`, so that ties between its members
+// are detected
+fn geometry_candidates<'a, F: Float>(
+    geometry: &'a Geometry<F>,
+    p: Point<F>,
+) -> Box<dyn Iterator<Item = Closest<F>> + 'a> {
+    match geometry {
+        Geometry::Point(g) => Box::new(iter::once(g.closest_point(&p))),
+        Geometry::Line(g) => Box::new(iter::once(g.closest_point(&p))),
+        Geometry::LineString(g) => Box::new(line_string_candidates(g, p)),
+        Geometry::Polygon(g) => Box::new(polygon_candidates(g, p)),
+        Geometry::MultiPoint(g) => Box::new(g.0.iter().map(move |point| point.closest_point(&p))),
+        Geometry::MultiLineString(g) => Box::new(
+            g.0.iter()
+                .flat_map(move |line_string| line_string_candidates(line_string, p)),
+        ),
+        Geometry::MultiPolygon(g) => Box::new(
+            g.0.iter()
+                .flat_map(move |polygon| polygon_candidates(polygon, p)),
+        ),
+        Geometry::GeometryCollection(g) => Box::new(
+            g.0.iter()
+                .flat_map(move |geometry| geometry_candidates(geometry, p)),
+        ),
+        Geometry::Rect(g) => Box::new(iter::once(g.closest_point(&p))),
+        Geometry::Triangle(g) => Box::new(iter::once(g.closest_point(&p))),
+    }
 }
 
 impl<F: Float> ClosestPoint<F> for LineString<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        closest_of(self.lines(), *p)
+        closest_of(line_string_candidates(self, *p), *p)
     }
 }
 
 impl<F: Float> ClosestPoint<F> for Polygon<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        let prospectives = self.interiors().iter().chain(iter::once(self.exterior()));
-        closest_of(prospectives, *p)
+        closest_of(polygon_candidates(self, *p), *p)
     }
 }
 
 impl<F: Float> ClosestPoint<F> for MultiPolygon<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        closest_of(self.0.iter(), *p)
+        closest_of(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon_candidates(polygon, *p)),
+            *p,
+        )
     }
 }
 
 impl<F: Float> ClosestPoint<F> for MultiPoint<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        closest_of(self.0.iter(), *p)
+        closest_of(self.0.iter().map(|point| point.closest_point(p)), *p)
     }
 }
 
 impl<F: Float> ClosestPoint<F> for MultiLineString<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        closest_of(self.0.iter(), *p)
+        closest_of(
+            self.0
+                .iter()
+                .flat_map(|line_string| line_string_candidates(line_string, *p)),
+            *p,
+        )
+    }
+}
+
+impl<F: Float> ClosestPoint<F> for Rect<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        Polygon::from(*self).closest_point(p)
+    }
+}
+
+impl<F: Float> ClosestPoint<F> for Triangle<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(self.to_lines().iter().map(|line| line.closest_point(p)), *p)
+    }
+}
+
+impl<F: Float> ClosestPoint<F> for Geometry<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(geometry_candidates(self, *p), *p)
+    }
+}
+
+impl<F: Float> ClosestPoint<F> for GeometryCollection<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(
+            self.0
+                .iter()
+                .flat_map(|geometry| geometry_candidates(geometry, *p)),
+            *p,
+        )
     }
 }
 
@@ -202,7 +314,7 @@ mod tests {
         let some_random_points = random_looking_points();
 
         for (i, random_point) in some_random_points.into_iter().enumerate() {
-            let p: Point<_> = random_point.into();
+            let p: Point<_> = random_point;
 
             let got_from_left = left.closest_point(&p);
             let got_from_right = right.closest_point(&p);
@@ -260,6 +372,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn polygon_without_rings_and_point_outside_is_same_as_linestring() {
         let poly = holy_polygon();
         let p = Point::new(1000.0, 12345.6789);
@@ -298,4 +411,70 @@ mod tests {
 
         assert_eq!(got, should_be);
     }
+
+    #[test]
+    fn equally_close_points_are_indeterminate() {
+        let points = MultiPoint(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0)]);
+        assert_eq!(
+            points.closest_point(&Point::new(1.0, 5.0)),
+            Closest::Indeterminate
+        );
+        assert_eq!(
+            points.closest_point(&Point::new(1.5, 5.0)),
+            Closest::SinglePoint(Point::new(2.0, 0.0))
+        );
+
+        // the center of a square is equally close to all of its sides
+        let square = Polygon::new(a_square(10.0), vec![]);
+        assert_eq!(
+            square.closest_point(&Point::new(5.0, 5.0)),
+            Closest::Indeterminate
+        );
+        // but a shared vertex of two sides is a single point
+        assert_eq!(
+            square.closest_point(&Point::new(-1.0, -1.0)),
+            Closest::SinglePoint(Point::new(0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn a_tie_is_not_hidden_by_a_farther_member() {
+        let collection = GeometryCollection(vec![
+            Geometry::MultiPoint(MultiPoint(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0)])),
+            Geometry::Point(Point::new(1.0, 100.0)),
+        ]);
+        assert_eq!(
+            collection.closest_point(&Point::new(1.0, 1.0)),
+            Closest::Indeterminate
+        );
+    }
+
+    #[test]
+    fn geometry_collection_closest_point() {
+        let collection = GeometryCollection(vec![
+            Geometry::Line(Line::from([(0.0, 0.0), (10.0, 0.0)])),
+            Geometry::Rect(Rect::new((20.0, 20.0), (30.0, 30.0))),
+            Geometry::Triangle(Triangle::from([(40.0, 0.0), (50.0, 0.0), (45.0, 5.0)])),
+        ]);
+        assert_eq!(
+            collection.closest_point(&Point::new(5.0, 3.0)),
+            Closest::SinglePoint(Point::new(5.0, 0.0))
+        );
+        assert_eq!(
+            collection.closest_point(&Point::new(25.0, 31.0)),
+            Closest::SinglePoint(Point::new(25.0, 30.0))
+        );
+        assert_eq!(
+            collection.closest_point(&Point::new(45.0, 0.0)),
+            Closest::Intersection(Point::new(45.0, 0.0))
+        );
+        assert_eq!(
+            Geometry::GeometryCollection(collection).closest_point(&Point::new(9.0, -2.0)),
+            Closest::SinglePoint(Point::new(9.0, 0.0))
+        );
+        assert_eq!(
+            GeometryCollection::<f32>(vec![]).closest_point(&Point::new(0.0, 0.0)),
+            Closest::Indeterminate
+        );
+    }
 }
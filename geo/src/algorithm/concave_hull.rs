@@ -0,0 +1,328 @@
+use crate::algorithm::convexhull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::intersects::Intersects;
+use crate::{
+    GeometryCollection, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+use num_traits::Float;
+use rstar::{PointDistance, RTree, RTreeNum, RTreeObject, AABB};
+use std::collections::VecDeque;
+
+pub trait ConcaveHull<T> {
+    /// Returns a concave hull of a geometry's points: a polygon enclosing all of them, which
+    /// follows their outline more tightly than the convex hull. The hull is oriented
+    /// counter-clockwise.
+    ///
+    /// Starting from the convex hull, each edge is dug in to the nearest point inside, when
+    /// that point is nearer to it than to any other edge, its distance from the nearer end of
+    /// the edge is less than the edge's length divided by `concavity`, and digging in doesn't
+    /// make the hull intersect itself. The resulting edges are dug in to in turn. Smaller values
+    /// of `concavity` give tighter hulls; as it grows, the hull approaches the convex hull.
+    /// Values below 1 let edges be dug in to points further away than their own length, giving
+    /// spiky outlines.
+    ///
+    /// This is the algorithm of [Park, Jin-Seo; Oh, Se-Jong (2012)](https://arxiv.org/abs/1309.7829).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::concave_hull::ConcaveHull;
+    /// use geo::algorithm::convexhull::ConvexHull;
+    /// use geo::MultiPoint;
+    ///
+    /// // points filling an L shape, which covers three quarters of its bounding square
+    /// let points: MultiPoint<f64> = (0..=10)
+    ///     .flat_map(|x| (0..=10).map(move |y| (x as f64, y as f64)))
+    ///     .filter(|(x, y)| *x <= 5. || *y <= 5.)
+    ///     .collect::<Vec<_>>()
+    ///     .into();
+    ///
+    /// assert_eq!(points.convex_hull().unsigned_area(), 87.5);
+    /// assert_eq!(points.concave_hull(1.).unsigned_area(), 75.);
+    /// // less concave, the hull cuts across the inner corner
+    /// assert_eq!(points.concave_hull(2.).unsigned_area(), 75.5);
+    /// ```
+    fn concave_hull(&self, concavity: T) -> Polygon<T>
+    where
+        T: Float + RTreeNum;
+}
+
+impl<T> ConcaveHull<T> for Polygon<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        Polygon::new(
+            concave_hull(self.exterior().clone().into_points(), concavity),
+            vec![],
+        )
+    }
+}
+
+impl<T> ConcaveHull<T> for MultiPolygon<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        let aggregated: Vec<Point<T>> = self
+            .0
+            .iter()
+            .flat_map(|elem| elem.exterior().0.iter().map(|c| Point(*c)))
+            .collect();
+        Polygon::new(concave_hull(aggregated, concavity), vec![])
+    }
+}
+
+impl<T> ConcaveHull<T> for LineString<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        Polygon::new(concave_hull(self.clone().into_points(), concavity), vec![])
+    }
+}
+
+impl<T> ConcaveHull<T> for MultiLineString<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        let aggregated: Vec<Point<T>> = self
+            .0
+            .iter()
+            .flat_map(|elem| elem.clone().into_points())
+            .collect();
+        Polygon::new(concave_hull(aggregated, concavity), vec![])
+    }
+}
+
+impl<T> ConcaveHull<T> for MultiPoint<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        Polygon::new(concave_hull(self.0.clone(), concavity), vec![])
+    }
+}
+
+impl<T> ConcaveHull<T> for GeometryCollection<T>
+where
+    T: Float + RTreeNum,
+{
+    fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        let aggregated: Vec<Point<T>> = self.coords_iter().map(Point).collect();
+        Polygon::new(concave_hull(aggregated, concavity), vec![])
+    }
+}
+
+fn concave_hull<T>(mut points: Vec<Point<T>>, concavity: T) -> LineString<T>
+where
+    T: Float + RTreeNum,
+{
+    let hull = quick_hull(&mut points);
+    if hull.len() < 4 {
+        return LineString::from(hull);
+    }
+    let mut inside = RTree::bulk_load(points);
+    for vertex in &hull {
+        while inside.remove(vertex).is_some() {}
+    }
+    let hull_edges: Vec<Line<T>> = hull.windows(2).map(|w| Line::new(w[0], w[1])).collect();
+    let mut edges = RTree::bulk_load(hull_edges.clone());
+
+    // dig in to the edges in order around the hull, so that those which stay are too
+    let mut queue: VecDeque<Line<T>> = hull_edges.into();
+    let mut ring = vec![];
+    while let Some(edge) = queue.pop_front() {
+        match dig_point(edge, concavity, &inside, &edges) {
+            Some(point) => {
+                while inside.remove(&point).is_some() {}
+                edges.remove(&edge);
+                let first = Line::new(edge.start, point.0);
+                let second = Line::new(point.0, edge.end);
+                edges.insert(first);
+                edges.insert(second);
+                queue.push_front(second);
+                queue.push_front(first);
+            }
+            None => ring.push(edge.start),
+        }
+    }
+    ring.push(ring[0]);
+    LineString(ring)
+}
+
+/// The point inside the hull to dig `edge` in to, if any
+fn dig_point<T>(
+    edge: Line<T>,
+    concavity: T,
+    inside: &RTree<Point<T>>,
+    edges: &RTree<Line<T>>,
+) -> Option<Point<T>>
+where
+    T: Float + RTreeNum,
+{
+    let reach = edge.euclidean_length() / concavity;
+    let (start, end) = (edge.start_point(), edge.end_point());
+    let envelope = AABB::from_corners(
+        Point::new(
+            start.x().min(end.x()) - reach,
+            start.y().min(end.y()) - reach,
+        ),
+        Point::new(
+            start.x().max(end.x()) + reach,
+            start.y().max(end.y()) + reach,
+        ),
+    );
+    let nearest = inside
+        .locate_in_envelope(&envelope)
+        .filter(|point| {
+            point
+                .euclidean_distance(&start)
+                .min(point.euclidean_distance(&end))
+                < reach
+        })
+        .filter(|point| {
+            // only points nearer to this edge than to any other
+            let distance = edge.distance_2(point);
+            match edges.nearest_neighbor(point) {
+                Some(other) => *other == edge || other.distance_2(point) >= distance,
+                None => true,
+            }
+        })
+        .min_by(|a, b| {
+            edge.distance_2(a)
+                .partial_cmp(&edge.distance_2(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+    let first = Line::new(edge.start, nearest.0);
+    let second = Line::new(nearest.0, edge.end);
+    if crosses(first, edge, edges) || crosses(second, edge, edges) {
+        None
+    } else {
+        Some(*nearest)
+    }
+}
+
+/// Whether a new edge, replacing `edge`, would cross one of the others
+fn crosses<T>(new: Line<T>, edge: Line<T>, edges: &RTree<Line<T>>) -> bool
+where
+    T: Float + RTreeNum,
+{
+    edges
+        .locate_in_envelope_intersecting(&new.envelope())
+        .filter(|other| **other != edge)
+        .any(|other| {
+            // the edges on either side of `edge` share an end with the new edge, and only cross
+            // it if they overlap
+            let shared = [new.start, new.end]
+                .iter()
+                .find(|c| **c == other.start || **c == other.end)
+                .copied();
+            match shared {
+                Some(shared) => {
+                    let far = if other.start == shared {
+                        other.end_point()
+                    } else {
+                        other.start_point()
+                    };
+                    let new_far = if new.start == shared {
+                        new.end_point()
+                    } else {
+                        new.start_point()
+                    };
+                    new.intersects(&far) || other.intersects(&new_far)
+                }
+                None => new.intersects(other),
+            }
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::convexhull::ConvexHull;
+    use crate::algorithm::validation::Validation;
+    use crate::algorithm::winding_order::Winding;
+    use crate::{line_string, polygon, Coordinate};
+
+    fn grid(keep: impl Fn(f64, f64) -> bool) -> MultiPoint<f64> {
+        (0..=10)
+            .flat_map(|x| (0..=10).map(move |y| (x as f64, y as f64)))
+            .filter(|(x, y)| keep(*x, *y))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn assert_encloses(hull: &Polygon<f64>, points: &MultiPoint<f64>) {
+        assert!(hull.is_valid(), "{:?}", hull.validation_errors());
+        assert!(hull.exterior().is_ccw());
+        for point in &points.0 {
+            assert!(hull.intersects(point), "{:?} is outside", point);
+        }
+    }
+
+    #[test]
+    fn l_shape() {
+        let points = grid(|x, y| x <= 2. || y <= 2.);
+        let hull = points.concave_hull(1.);
+        assert_encloses(&hull, &points);
+        assert_eq!(hull.unsigned_area(), 10. * 2. + 8. * 2.);
+        assert_eq!(points.convex_hull().unsigned_area(), 100. - 8. * 8. / 2.);
+    }
+
+    #[test]
+    fn concavity() {
+        let points = grid(|x, y| x <= 2. || y <= 2.);
+        let mut previous = 0.;
+        for concavity in [1., 2., 4., 1000.].iter() {
+            let hull = points.concave_hull(*concavity);
+            assert_encloses(&hull, &points);
+            assert!(hull.unsigned_area() >= previous);
+            previous = hull.unsigned_area();
+        }
+        assert_eq!(previous, points.convex_hull().unsigned_area());
+    }
+
+    #[test]
+    fn scattered() {
+        // points in a ring, whose hull is dug in to wherever the ring is sparse, but without
+        // crossing itself
+        let points: MultiPoint<f64> = (0..60)
+            .map(|i| {
+                let angle = i as f64 * 0.61;
+                let radius = 5. + (i as f64 * 1.7).sin();
+                (radius * angle.cos(), radius * angle.sin())
+            })
+            .collect::<Vec<_>>()
+            .into();
+        for concavity in [0.5, 1., 2., 3.].iter() {
+            let hull = points.concave_hull(*concavity);
+            assert_encloses(&hull, &points);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(
+            ls.concave_hull(2.).exterior().0,
+            vec![
+                Coordinate::from((1., 1.)),
+                Coordinate::from((0., 0.)),
+                Coordinate::from((1., 1.)),
+            ]
+        );
+        let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.), (x: 0., y: 0.)];
+        assert_eq!(triangle.concave_hull(2.), triangle.convex_hull());
+        assert!(MultiPoint::<f64>(vec![])
+            .concave_hull(2.)
+            .exterior()
+            .0
+            .is_empty());
+    }
+}
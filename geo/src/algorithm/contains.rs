@@ -1,11 +1,30 @@
 use num_traits::Float;
 
 use crate::algorithm::intersects::Intersects;
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::algorithm::relate::Relate;
 use crate::{
-    Coordinate, CoordinateType, Line, LineString, MultiPolygon, Point, Polygon, Rect, Triangle,
+    CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
 ///  Checks if the geometry A is completely inside the B geometry
+///
+/// # Boundaries
+///
+/// Points are located with robust orientation predicates, so a point exactly on an edge is
+/// always treated the same way, however the edge is oriented:
+///
+/// - a `Polygon`, `MultiPolygon` or `Triangle` doesn't contain the points of its exterior or
+///   interior rings, and doesn't contain the points in its holes
+/// - a `Rect` contains the points on its edges
+/// - a `Line`, `LineString`, or `MultiLineString` contains all of its points, including its
+///   endpoints
+/// - a `GeometryCollection` contains a point if one of its members does
+///
+/// A `Polygon` contains another `Polygon` as [`Relate`](../relate/trait.Relate.html)'s
+/// `contains` decides, which is authoritative: when no point of the other is outside it, and
+/// their interiors meet, so the polygons may share parts of their boundaries.
 pub trait Contains<Rhs = Self> {
     /// Checks if `rhs` is completely contained within `self`.
     ///
@@ -44,7 +63,11 @@ where
     T: Float,
 {
     fn contains(&self, p: &Point<T>) -> bool {
-        ::geo_types::private_utils::line_string_contains_point(self, *p)
+        match self.0.len() {
+            0 => false,
+            1 => Point(self.0[0]).contains(p),
+            _ => self.lines().any(|line| line.contains(p)),
+        }
     }
 }
 
@@ -135,21 +158,24 @@ where
         return PositionPoint::OnBoundary;
     }
 
-    let mut xints = T::zero();
+    // count the edges crossing the ray from p in the positive x direction, deciding which side
+    // of each edge p is on with a robust orientation predicate
     let mut crossings = 0;
     for line in linestring.lines() {
-        if p.y() > line.start.y.min(line.end.y)
-            && p.y() <= line.start.y.max(line.end.y)
+        if orient2d(line.start, line.end, p.0) == Orientation::Collinear
+            && p.x() >= line.start.x.min(line.end.x)
             && p.x() <= line.start.x.max(line.end.x)
+            && p.y() >= line.start.y.min(line.end.y)
+            && p.y() <= line.start.y.max(line.end.y)
         {
-            if line.start.y != line.end.y {
-                xints = (p.y() - line.start.y) * (line.end.x - line.start.x)
-                    / (line.end.y - line.start.y)
-                    + line.start.x;
-            }
-            if (line.start.x == line.end.x) || (p.x() <= xints) {
-                crossings += 1;
-            }
+            return PositionPoint::OnBoundary;
+        }
+        let upward = line.start.y <= p.y() && line.end.y > p.y();
+        let downward = line.end.y <= p.y() && line.start.y > p.y();
+        if (upward && orient2d(line.start, line.end, p.0) == Orientation::CounterClockwise)
+            || (downward && orient2d(line.start, line.end, p.0) == Orientation::Clockwise)
+        {
+            crossings += 1;
         }
     }
     if crossings % 2 == 1 {
@@ -164,7 +190,7 @@ where
     T: Float,
 {
     fn contains(&self, p: &Point<T>) -> bool {
-        match get_position(*p, &self.exterior()) {
+        match get_position(*p, self.exterior()) {
             PositionPoint::OnBoundary | PositionPoint::Outside => false,
             _ => self
                 .interiors()
@@ -202,8 +228,7 @@ where
     T: Float,
 {
     fn contains(&self, poly: &Polygon<T>) -> bool {
-        // decompose poly's exterior ring into Lines, and check each for containment
-        poly.exterior().lines().all(|line| self.contains(&line))
+        self.relate(poly).contains()
     }
 }
 
@@ -256,28 +281,108 @@ where
     T: CoordinateType,
 {
     fn contains(&self, point: &Point<T>) -> bool {
-        let sign_1 = sign(&point.0, &self.0, &self.1);
-        let sign_2 = sign(&point.0, &self.1, &self.2);
-        let sign_3 = sign(&point.0, &self.2, &self.0);
+        // like a polygon, the triangle doesn't contain the points on its boundary, so a
+        // degenerate triangle contains none
+        let orientation = orient2d(self.0, self.1, point.0);
+        orientation != Orientation::Collinear
+            && orient2d(self.1, self.2, point.0) == orientation
+            && orient2d(self.2, self.0, point.0) == orientation
+    }
+}
 
-        ((sign_1 == sign_2) && (sign_2 == sign_3))
+impl<T> Contains<Point<T>> for MultiPoint<T>
+where
+    T: Float,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|point| point.contains(p))
     }
 }
 
-fn sign<T>(point_1: &Coordinate<T>, point_2: &Coordinate<T>, point_3: &Coordinate<T>) -> bool
+impl<T> Contains<Point<T>> for MultiLineString<T>
 where
-    T: CoordinateType,
+    T: Float,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|line_string| line_string.contains(p))
+    }
+}
+
+impl<T> Contains<MultiPoint<T>> for Polygon<T>
+where
+    T: Float,
 {
-    (point_1.x - point_3.x) * (point_2.y - point_3.y)
-        - (point_2.x - point_3.x) * (point_1.y - point_3.y)
-        < T::zero()
+    fn contains(&self, multi_point: &MultiPoint<T>) -> bool {
+        !multi_point.0.is_empty() && multi_point.0.iter().all(|point| self.contains(point))
+    }
+}
+
+// As the interiors of the polygons of a valid `MultiPolygon` are disjoint, a line, line
+// string, or polygon inside it is (except when passing through a point where two polygons
+// touch) inside one of them
+macro_rules! impl_multi_polygon_contains {
+    ($($type:ident),*) => {
+        $(
+            impl<T> Contains<$type<T>> for MultiPolygon<T>
+            where
+                T: Float,
+            {
+                fn contains(&self, rhs: &$type<T>) -> bool {
+                    self.0.iter().any(|polygon| polygon.contains(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_multi_polygon_contains!(Line, LineString, Polygon);
+
+impl<T> Contains<MultiPoint<T>> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn contains(&self, multi_point: &MultiPoint<T>) -> bool {
+        !multi_point.0.is_empty() && multi_point.0.iter().all(|point| self.contains(point))
+    }
+}
+
+impl<T> Contains<Point<T>> for Geometry<T>
+where
+    T: Float,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        match self {
+            Geometry::Point(g) => g.contains(p),
+            Geometry::Line(g) => g.contains(p),
+            Geometry::LineString(g) => g.contains(p),
+            Geometry::Polygon(g) => g.contains(p),
+            Geometry::MultiPoint(g) => g.contains(p),
+            Geometry::MultiLineString(g) => g.contains(p),
+            Geometry::MultiPolygon(g) => g.contains(p),
+            Geometry::GeometryCollection(g) => g.contains(p),
+            Geometry::Rect(g) => g.contains(p),
+            Geometry::Triangle(g) => g.contains(p),
+        }
+    }
+}
+
+impl<T> Contains<Point<T>> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|geometry| geometry.contains(p))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::algorithm::contains::Contains;
     use crate::line_string;
-    use crate::{Coordinate, Line, LineString, MultiPolygon, Point, Polygon, Rect, Triangle};
+    use crate::{
+        Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+        MultiPolygon, Point, Polygon, Rect, Triangle,
+    };
     #[test]
     // V doesn't contain rect because two of its edges intersect with V's exterior boundary
     fn polygon_does_not_contain_polygon() {
@@ -305,7 +410,7 @@ mod test {
             .into(),
             vec![],
         );
-        assert_eq!(!v.contains(&rect), true);
+        assert!(!v.contains(&rect));
     }
     #[test]
     // V contains rect because all its vertices are contained, and none of its edges intersect with V's boundaries
@@ -334,7 +439,7 @@ mod test {
             .into(),
             vec![],
         );
-        assert_eq!(v.contains(&rect), true);
+        assert!(v.contains(&rect));
     }
     #[test]
     // LineString is fully contained
@@ -344,7 +449,7 @@ mod test {
             vec![],
         );
         let ls = LineString::from(vec![(3.0, 0.5), (3.0, 3.5)]);
-        assert_eq!(poly.contains(&ls), true);
+        assert!(poly.contains(&ls));
     }
     /// Tests: Point in LineString
     #[test]
@@ -405,6 +510,16 @@ mod test {
         assert!(!poly.contains(&Point::new(2.1, 2.1)));
     }
     #[test]
+    fn point_on_diagonal_boundary() {
+        let poly = Polygon::new(LineString::from(vec![(0., 0.), (2., 0.), (0., 2.)]), vec![]);
+        assert!(!poly.contains(&Point::new(1., 1.)));
+        assert!(poly.contains(&Point::new(0.999, 1.)));
+        assert_eq!(
+            super::get_position(Point::new(0.5, 1.5), poly.exterior()),
+            super::PositionPoint::OnBoundary
+        );
+    }
+    #[test]
     fn point_polygon_with_inner_test() {
         let linestring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
         let inner_linestring = LineString::from(vec![
@@ -507,8 +622,8 @@ mod test {
             Coordinate { x: -10., y: -20. },
             Coordinate { x: 10., y: 20. },
         );
-        assert_eq!(true, bounding_rect_xl.contains(&bounding_rect_sm));
-        assert_eq!(false, bounding_rect_sm.contains(&bounding_rect_xl));
+        assert!(bounding_rect_xl.contains(&bounding_rect_sm));
+        assert!(!bounding_rect_sm.contains(&bounding_rect_xl));
     }
     #[test]
     fn point_in_line_test() {
@@ -606,17 +721,17 @@ mod test {
     }
 
     #[test]
-    fn triangle_contains_point_on_edge() {
+    fn triangle_not_contains_point_on_edge() {
         let t = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let p = Point::new(1.0, 0.0);
-        assert!(t.contains(&p));
+        assert!(!t.contains(&p));
     }
 
     #[test]
-    fn triangle_contains_point_on_vertex() {
+    fn triangle_not_contains_point_on_vertex() {
         let t = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let p = Point::new(2.0, 0.0);
-        assert!(t.contains(&p));
+        assert!(!t.contains(&p));
     }
 
     #[test]
@@ -646,4 +761,162 @@ mod test {
         let p = Point::new(-1.0, -0.5);
         assert!(t.contains(&p));
     }
+
+    #[test]
+    fn triangle_contains_point_on_edge_regardless_of_winding() {
+        let p = Point::new(0.1, 0.2);
+        let t = Triangle::from([(0.0, 0.0), (0.3, 0.6), (2.0, 0.0)]);
+        let reversed = Triangle::from([(2.0, 0.0), (0.3, 0.6), (0.0, 0.0)]);
+        assert_eq!(t.contains(&p), reversed.contains(&p));
+    }
+
+    #[test]
+    fn degenerate_triangle_contains_no_point() {
+        let t = Triangle::from([(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+        assert!(!t.contains(&Point::new(0.5, 0.5)));
+        assert!(!t.contains(&Point::new(3.0, 3.0)));
+        assert!(!t.contains(&Point::new(0.5, 0.6)));
+    }
+
+    #[test]
+    fn triangle_contains_point_like_polygon() {
+        let triangles = [
+            Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]),
+            Triangle::from([(2.0, 2.0), (2.0, 0.0), (0.0, 0.0)]),
+            Triangle::from([(0.0, 0.0), (0.3, 0.6), (2.0, 0.0)]),
+            Triangle::from([(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]),
+        ];
+        for t in &triangles {
+            let polygon = Polygon::from(*t);
+            for x in -1..=9 {
+                for y in -1..=9 {
+                    let p = Point::new(x as f64 * 0.25, y as f64 * 0.25);
+                    assert_eq!(t.contains(&p), polygon.contains(&p), "{:?} {:?}", t, p);
+                }
+            }
+            for &vertex in &t.to_array() {
+                assert!(!t.contains(&Point(vertex)));
+            }
+        }
+    }
+
+    #[test]
+    fn multi_point_contains_point() {
+        let multi_point = MultiPoint(vec![Point::new(0., 0.), Point::new(1., 2.)]);
+        assert!(multi_point.contains(&Point::new(1., 2.)));
+        assert!(!multi_point.contains(&Point::new(2., 1.)));
+    }
+
+    #[test]
+    fn multi_line_string_contains_point() {
+        let multi_line_string = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (2., 0.)]),
+            LineString::from(vec![(0., 1.), (2., 3.)]),
+        ]);
+        assert!(multi_line_string.contains(&Point::new(1., 2.)));
+        assert!(multi_line_string.contains(&Point::new(2., 0.)));
+        assert!(!multi_line_string.contains(&Point::new(1., 1.)));
+    }
+
+    #[test]
+    fn multi_polygon_contains_line_string_and_polygon() {
+        let square = |x: f64| {
+            Polygon::new(
+                LineString::from(vec![(x, 0.), (x + 2., 0.), (x + 2., 2.), (x, 2.), (x, 0.)]),
+                vec![],
+            )
+        };
+        let multi_polygon = MultiPolygon(vec![square(0.), square(10.)]);
+        let inside = LineString::from(vec![(10.5, 0.5), (11.5, 1.5)]);
+        let across = LineString::from(vec![(0.5, 0.5), (10.5, 0.5)]);
+        assert!(multi_polygon.contains(&inside));
+        assert!(!multi_polygon.contains(&across));
+        assert!(multi_polygon.contains(&Line::new((0.5, 0.5), (1.5, 1.5))));
+        // sharing an edge with the boundary, as `Relate` allows
+        let small = Polygon::new(
+            LineString::from(vec![(11., 1.), (12., 1.), (12., 1.5), (11., 1.)]),
+            vec![],
+        );
+        assert!(multi_polygon.contains(&small));
+        let poking_out = Polygon::new(
+            LineString::from(vec![(11., 1.), (13., 1.), (12., 1.5), (11., 1.)]),
+            vec![],
+        );
+        assert!(!multi_polygon.contains(&poking_out));
+        let small = Polygon::new(
+            LineString::from(vec![(10.5, 0.5), (11.5, 0.5), (11.5, 1.5), (10.5, 0.5)]),
+            vec![],
+        );
+        assert!(multi_polygon.contains(&small));
+        assert!(!multi_polygon.contains(&square(5.)));
+        assert!(multi_polygon.contains(&MultiPoint(vec![Point::new(1., 1.), Point::new(11., 1.)])));
+        assert!(!multi_polygon.contains(&MultiPoint(vec![Point::new(1., 1.), Point::new(5., 1.)])));
+    }
+
+    #[test]
+    fn polygon_with_hole_contains_multi_point() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (3., 1.),
+                (3., 3.),
+                (1., 3.),
+                (1., 1.),
+            ])],
+        );
+        assert!(polygon.contains(&MultiPoint(vec![
+            Point::new(0.5, 0.5),
+            Point::new(3.5, 3.5)
+        ])));
+        assert!(!polygon.contains(&MultiPoint(vec![Point::new(0.5, 0.5), Point::new(2., 2.)])));
+        assert!(!polygon.contains(&MultiPoint(vec![Point::new(0.5, 0.5), Point::new(1., 2.)])));
+        assert!(!polygon.contains(&MultiPoint(vec![])));
+    }
+
+    #[test]
+    fn geometry_collection_contains_point() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::Rect(Rect::new((0., 0.), (2., 2.))),
+        ]);
+        assert!(collection.contains(&Point::new(10., 10.)));
+        assert!(collection.contains(&Point::new(2., 1.)));
+        assert!(!collection.contains(&Point::new(5., 5.)));
+        assert!(Geometry::GeometryCollection(collection).contains(&Point::new(1., 1.)));
+        assert!(!GeometryCollection::<f64>(vec![]).contains(&Point::new(0., 0.)));
+    }
+
+    #[test]
+    fn polygon_contains_polygon_as_relate_does() {
+        use crate::algorithm::relate::Relate;
+        use crate::polygon;
+
+        let outer = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let framed = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        ];
+        let candidates = vec![
+            outer.clone(),
+            framed.clone(),
+            // sharing an edge, and a corner, with the outer square
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.), (x: 3., y: 4.)],
+            // around the hole, and across the outer square's edge
+            polygon![(x: 0.5, y: 0.5), (x: 3.5, y: 0.5), (x: 3.5, y: 3.5), (x: 0.5, y: 3.5)],
+            polygon![(x: 3., y: 1.), (x: 5., y: 1.), (x: 5., y: 2.), (x: 3., y: 2.)],
+        ];
+        for a in [outer.clone(), framed.clone()].iter() {
+            for b in &candidates {
+                assert_eq!(a.contains(b), a.relate(b).contains(), "{:?} {:?}", a, b);
+            }
+        }
+        assert!(outer.contains(&outer));
+        assert!(outer.contains(&candidates[2]));
+        assert!(outer.contains(&framed));
+        assert!(!framed.contains(&outer));
+        assert!(!framed.contains(&candidates[4]));
+        assert!(!outer.contains(&candidates[5]));
+    }
 }
@@ -1,67 +1,64 @@
-use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::predicates::{orient2d, Orientation};
 use crate::utils::partition_slice;
-use crate::{Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
-use num_traits::Float;
+use crate::{
+    CoordinateType, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use num_traits::NumCast;
 use std::mem;
 
 fn swap_remove_to_first<'a, T>(slice: &mut &'a mut [T], idx: usize) -> &'a mut T {
-    let tmp = mem::replace(slice, &mut []);
+    let tmp = std::mem::take(slice);
     tmp.swap(0, idx);
     let (h, t) = tmp.split_first_mut().unwrap();
     *slice = t;
     h
 }
 
-// Determine whether a point lies on one side of a line segment, or the other.
-// The cross product v x w of two vectors v and w is a vector whose length is
-// |v||w|sin φ, (where |v| is the length of v and φ is the angle between the vectors),
-// and which is orthogonal (perpendicular) to both v and w.  Since there are two
-// such possible vectors, the definition arbitrarily selects the one that matches
-// the direction in which a screw would move if rotated from v to w
-
-// Mathematically, if the coordinates of vectors v and w are (vx, vy) and (wx, wy)
-// respectively, the cross product will be (vxwy - vywx). If a segment is
-// defined by points A B and, we wish to check on which side of AB a third point C falls,
-// we can compute the cross product AB x AC and check its sign:
-// If it's negative, it will be on the "right" side of AB
-// (when standing on A and looking towards B). If positive, it will be on the left side
+// Determine whether a point lies on the left side of the line segment AB (when standing on A
+// and looking towards B), using a robust orientation predicate so that points very close to
+// the line are classified correctly
 fn point_location<T>(p_a: Point<T>, p_b: Point<T>, p_c: Point<T>) -> bool
 where
-    T: Float,
+    T: CoordinateType,
 {
-    p_a.cross_prod(p_b, p_c) > T::zero()
+    orient2d(p_a.0, p_b.0, p_c.0) == Orientation::CounterClockwise
 }
 
 // Adapted from https://web.archive.org/web/20180409175413/http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
-fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
+pub(crate) fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
 where
-    T: Float,
+    T: CoordinateType,
 {
-    // can't build a hull from fewer than four points
-    if points.len() < 4 {
+    // can't build a hull from fewer than two points
+    if points.len() < 2 {
         return points.to_vec();
     }
     let mut hull = vec![];
     let min = swap_remove_to_first(&mut points, 0);
     let max = swap_remove_to_first(&mut points, 0);
-    if min.x() > max.x() {
+    // the least and greatest points in lexicographic order are vertices of the hull, even when
+    // several share the least or greatest x
+    let less = |a: &Point<T>, b: &Point<T>| a.x() < b.x() || (a.x() == b.x() && a.y() < b.y());
+    if less(max, min) {
         mem::swap(min, max);
     }
     for point in points.iter_mut() {
-        if point.x() < min.x() {
+        if less(point, min) {
             mem::swap(point, min);
         }
-        if point.x() > max.x() {
+        if less(max, point) {
             mem::swap(point, max);
         }
     }
     {
-        let (mut points, _) = partition_slice(&mut points, |p| point_location(*max, *min, *p));
-        hull_set(*max, *min, &mut points, &mut hull);
+        let (points, _) = partition_slice(points, |p| point_location(*max, *min, *p));
+        hull_set(*max, *min, points, &mut hull);
     }
     hull.push(*max);
-    let (mut points, _) = partition_slice(&mut points, |p| point_location(*min, *max, *p));
-    hull_set(*min, *max, &mut points, &mut hull);
+    let (points, _) = partition_slice(points, |p| point_location(*min, *max, *p));
+    hull_set(*min, *max, points, &mut hull);
     hull.push(*min);
     // close the polygon
     let final_element = *hull.first().unwrap();
@@ -72,7 +69,7 @@ where
 // recursively calculate the convex hull of a subset of points
 fn hull_set<T>(p_a: Point<T>, p_b: Point<T>, mut set: &mut [Point<T>], hull: &mut Vec<Point<T>>)
 where
-    T: Float,
+    T: CoordinateType,
 {
     if set.is_empty() {
         return;
@@ -81,11 +78,10 @@ where
         hull.push(set[0]);
         return;
     }
-    let mut furthest_distance = Float::min_value();
+    let mut furthest_distance = -1.;
     let mut furthest_idx = 0;
     for (idx, point) in set.iter().enumerate() {
-        // let current_distance = pseudo_distance(p_a, p_b, point);
-        let current_distance = point.euclidean_distance(&Line::new(p_a.0, p_b.0));
+        let current_distance = pseudo_distance(p_a, p_b, *point);
         if current_distance > furthest_distance {
             furthest_distance = current_distance;
             furthest_idx = idx
@@ -95,17 +91,38 @@ where
     let furthest_point = swap_remove_to_first(&mut set, furthest_idx);
     // points over PB
     {
-        let (mut points, _) = partition_slice(set, |p| point_location(*furthest_point, p_b, *p));
-        hull_set(*furthest_point, p_b, &mut points, hull);
+        let (points, _) = partition_slice(set, |p| point_location(*furthest_point, p_b, *p));
+        hull_set(*furthest_point, p_b, points, hull);
     }
     hull.push(*furthest_point);
     // points over AP
-    let (mut points, _) = partition_slice(set, |p| point_location(p_a, *furthest_point, *p));
-    hull_set(p_a, *furthest_point, &mut points, hull);
+    let (points, _) = partition_slice(set, |p| point_location(p_a, *furthest_point, *p));
+    hull_set(p_a, *furthest_point, points, hull);
+}
+
+// The distance of `p` from the line through `a` and `b`, scaled by the length of `ab`, computed
+// in `f64` so that integer coordinates can't overflow. The furthest point is always on the hull,
+// and the robust orientations of the other points decide which are.
+fn pseudo_distance<T>(a: Point<T>, b: Point<T>, p: Point<T>) -> f64
+where
+    T: CoordinateType,
+{
+    let f = |value: T| <f64 as NumCast>::from(value).unwrap_or(0.);
+    let (a, b, p) = (
+        (f(a.x()), f(a.y())),
+        (f(b.x()), f(b.y())),
+        (f(p.x()), f(p.y())),
+    );
+    ((b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)).abs()
 }
 
 pub trait ConvexHull<T> {
-    /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
+    /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise,
+    /// without collinear vertices.
+    ///
+    /// Points are classified with the robust [`orient2d`](../predicates/fn.orient2d.html)
+    /// predicate, so points very near an edge of the hull are neither missed nor spuriously
+    /// included, and integer and fixed-point coordinates are supported.
     ///
     /// This implementation uses the QuickHull algorithm,
     /// based on [Barber, C. Bradford; Dobkin, David P.; Huhdanpaa, Hannu (1 December 1996)](https://dx.doi.org/10.1145%2F235815.235821)
@@ -143,12 +160,12 @@ pub trait ConvexHull<T> {
     /// ```
     fn convex_hull(&self) -> Polygon<T>
     where
-        T: Float;
+        T: CoordinateType;
 }
 
 impl<T> ConvexHull<T> for Polygon<T>
 where
-    T: Float,
+    T: CoordinateType,
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(
@@ -160,7 +177,7 @@ where
 
 impl<T> ConvexHull<T> for MultiPolygon<T>
 where
-    T: Float,
+    T: CoordinateType,
 {
     fn convex_hull(&self) -> Polygon<T> {
         let mut aggregated: Vec<Point<T>> = self
@@ -174,7 +191,7 @@ where
 
 impl<T> ConvexHull<T> for LineString<T>
 where
-    T: Float,
+    T: CoordinateType,
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(
@@ -186,7 +203,7 @@ where
 
 impl<T> ConvexHull<T> for MultiLineString<T>
 where
-    T: Float,
+    T: CoordinateType,
 {
     fn convex_hull(&self) -> Polygon<T> {
         let mut aggregated: Vec<Point<T>> = self
@@ -200,14 +217,25 @@ where
 
 impl<T> ConvexHull<T> for MultiPoint<T>
 where
-    T: Float,
+    T: CoordinateType,
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(LineString::from(quick_hull(&mut self.0.clone())), vec![])
     }
 }
 
+impl<T> ConvexHull<T> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    fn convex_hull(&self) -> Polygon<T> {
+        let mut aggregated: Vec<Point<T>> = self.coords_iter().map(Point).collect();
+        Polygon::new(LineString::from(quick_hull(&mut aggregated)), vec![])
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod test {
     use super::*;
     use crate::{line_string, polygon, Coordinate, Point};
@@ -260,7 +288,7 @@ mod test {
     #[test]
     // test whether output is ccw
     fn quick_hull_test_ccw() {
-        let initial = vec![
+        let initial = [
             (1.0, 0.0),
             (2.0, 1.0),
             (1.75, 1.1),
@@ -269,7 +297,7 @@ mod test {
             (1.0, 0.0),
         ];
         let mut v: Vec<_> = initial.iter().map(|e| Point::new(e.0, e.1)).collect();
-        let correct = vec![(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0), (1.0, 0.0)];
+        let correct = [(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0), (1.0, 0.0)];
         let v_correct: Vec<_> = correct.iter().map(|e| Point::new(e.0, e.1)).collect();
         let res = quick_hull(&mut v);
         assert_eq!(res, v_correct);
@@ -278,7 +306,7 @@ mod test {
     // test that output isn't rotated
     fn quick_hull_test_ccw_maintain() {
         // initial input begins at min y, is oriented ccw
-        let initial = vec![
+        let initial = [
             (0., 0.),
             (2., 0.),
             (2.5, 1.75),
@@ -289,7 +317,7 @@ mod test {
             (0., 0.),
         ];
         let mut v: Vec<_> = initial.iter().map(|e| Point::new(e.0, e.1)).collect();
-        let correct = vec![
+        let correct = [
             (2.0, 0.0),
             (2.5, 1.75),
             (1.75, 2.5),
@@ -396,4 +424,87 @@ mod test {
         let res = mp.convex_hull();
         assert_eq!(res.exterior().0, correct);
     }
+    #[test]
+    fn integer_coordinates() {
+        // a square with points along its edges, including several with the least x
+        let mp = MultiPoint::from(vec![
+            (0, 2),
+            (0, 0),
+            (2, 0),
+            (4, 0),
+            (4, 4),
+            (2, 4),
+            (0, 4),
+            (2, 2),
+            (4, 1),
+        ]);
+        let correct = vec![
+            Coordinate::from((4, 0)),
+            Coordinate::from((4, 4)),
+            Coordinate::from((0, 4)),
+            Coordinate::from((0, 0)),
+            Coordinate::from((4, 0)),
+        ];
+        let res = mp.convex_hull();
+        assert_eq!(res.exterior().0, correct);
+
+        // a clockwise triangle's hull is counter-clockwise
+        let ls = line_string![(x: 0, y: 0), (x: 0, y: 3), (x: 3, y: 0)];
+        let correct = vec![
+            Coordinate::from((3, 0)),
+            Coordinate::from((0, 3)),
+            Coordinate::from((0, 0)),
+            Coordinate::from((3, 0)),
+        ];
+        assert_eq!(ls.convex_hull().exterior().0, correct);
+    }
+
+    #[test]
+    fn nearly_collinear() {
+        // the naive determinant puts the middle point on the line between the others, but it's
+        // slightly above it, so a vertex of the hull
+        let points = vec![
+            Point::new(0.5, 0.5),
+            Point::new(28.46686479390819, 28.466864793908186),
+            Point::new(12., 12.),
+            Point::new(30., 0.),
+        ];
+        let res = MultiPoint(points).convex_hull();
+        assert_eq!(res.exterior().0.len(), 5);
+        assert!(res.exterior().0.contains(&Coordinate::from((12., 12.))));
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let gc = GeometryCollection(vec![
+            Point::new(0.0, 0.0).into(),
+            line_string![(x: 3.0, y: 0.0), (x: 1.0, y: 1.0), (x: 3.0, y: 3.0)].into(),
+            polygon![(x: 0.0, y: 2.0), (x: 1.0, y: 2.0), (x: 0.0, y: 3.0), (x: 0.0, y: 2.0)].into(),
+        ]);
+        let correct = vec![
+            Coordinate::from((3.0, 0.0)),
+            Coordinate::from((3.0, 3.0)),
+            Coordinate::from((0.0, 3.0)),
+            Coordinate::from((0.0, 0.0)),
+            Coordinate::from((3.0, 0.0)),
+        ];
+        assert_eq!(gc.convex_hull().exterior().0, correct);
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI32F32;
+
+        let fixed = |x: f64| <FixedI32F32 as NumCast>::from(x).unwrap();
+        let mp: MultiPoint<FixedI32F32> = vec![(0.0, 0.0), (2.0, 0.0), (1.0, 0.5), (1.0, 2.0)]
+            .into_iter()
+            .map(|(x, y)| Point::new(fixed(x), fixed(y)))
+            .collect();
+        let correct: Vec<_> = vec![(2.0, 0.0), (1.0, 2.0), (0.0, 0.0), (2.0, 0.0)]
+            .into_iter()
+            .map(|(x, y)| Coordinate::from((fixed(x), fixed(y))))
+            .collect();
+        assert_eq!(mp.convex_hull().exterior().0, correct);
+    }
 }
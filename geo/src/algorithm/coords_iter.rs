@@ -0,0 +1,529 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+#[cfg(feature = "bumpalo")]
+use geo_types::arena::{ArenaLineString, ArenaPolygon};
+use std::{array, iter, slice};
+
+type LineStringIter<'a, T> = iter::Copied<slice::Iter<'a, Coordinate<T>>>;
+type PolygonIter<'a, T> = iter::Chain<
+    LineStringIter<'a, T>,
+    iter::FlatMap<
+        slice::Iter<'a, LineString<T>>,
+        LineStringIter<'a, T>,
+        fn(&'a LineString<T>) -> LineStringIter<'a, T>,
+    >,
+>;
+type MultiPointIter<'a, T> =
+    iter::Map<slice::Iter<'a, Point<T>>, fn(&'a Point<T>) -> Coordinate<T>>;
+type FlatMapIter<'a, G, I> = iter::FlatMap<slice::Iter<'a, G>, I, fn(&'a G) -> I>;
+
+/// Iterate over the coordinates of a geometry.
+///
+/// The iterators of `Point`, `Line`, `LineString`, `MultiPoint`, `Rect`, and `Triangle` implement
+/// `ExactSizeIterator`; for every geometry, `coords_count` returns the number of coordinates
+/// without iterating over them. With the `bumpalo` feature, it's also implemented for the
+/// geometries allocated in an arena, `ArenaLineString` and `ArenaPolygon`.
+pub trait CoordsIter<'a, T: CoordinateType> {
+    type Iter: Iterator<Item = Coordinate<T>>;
+    type ExteriorIter: Iterator<Item = Coordinate<T>>;
+
+    /// Iterate over all the coordinates of a geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coords_iter::CoordsIter;
+    /// use geo::{polygon, Coordinate};
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]],
+    /// );
+    ///
+    /// let mut coords = polygon.coords_iter();
+    /// assert_eq!(Some(Coordinate { x: 0., y: 0. }), coords.next());
+    /// assert_eq!(8, polygon.coords_count());
+    /// assert_eq!(8, polygon.coords_iter().count());
+    /// ```
+    fn coords_iter(&'a self) -> Self::Iter;
+
+    /// Return the number of coordinates in a geometry.
+    fn coords_count(&'a self) -> usize;
+
+    /// Iterate over the coordinates of the exteriors of a geometry, skipping the interior rings
+    /// of any `Polygon`s. For every other geometry, this is the same as `coords_iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coords_iter::CoordsIter;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]],
+    /// );
+    ///
+    /// assert_eq!(4, polygon.exterior_coords_iter().count());
+    /// ```
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter;
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Point<T> {
+    type Iter = iter::Once<Coordinate<T>>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        iter::once(self.0)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        1
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Line<T> {
+    type Iter = array::IntoIter<Coordinate<T>, 2>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        IntoIterator::into_iter([self.start, self.end])
+    }
+
+    fn coords_count(&'a self) -> usize {
+        2
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for LineString<T> {
+    type Iter = LineStringIter<'a, T>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().copied()
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.len()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Polygon<T> {
+    type Iter = PolygonIter<'a, T>;
+    type ExteriorIter = LineStringIter<'a, T>;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.exterior().coords_iter().chain(
+            self.interiors()
+                .iter()
+                .flat_map(LineString::coords_iter as _),
+        )
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.exterior().coords_count()
+            + self
+                .interiors()
+                .iter()
+                .map(LineString::coords_count)
+                .sum::<usize>()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.exterior().coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for MultiPoint<T> {
+    type Iter = MultiPointIter<'a, T>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().map(|p| p.0)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.len()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for MultiLineString<T> {
+    type Iter = FlatMapIter<'a, LineString<T>, LineStringIter<'a, T>>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().flat_map(LineString::coords_iter as _)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.iter().map(LineString::coords_count).sum()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for MultiPolygon<T> {
+    type Iter = FlatMapIter<'a, Polygon<T>, PolygonIter<'a, T>>;
+    type ExteriorIter = FlatMapIter<'a, Polygon<T>, LineStringIter<'a, T>>;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().flat_map(Polygon::coords_iter as _)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.iter().map(Polygon::coords_count).sum()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.0.iter().flat_map(Polygon::exterior_coords_iter as _)
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for GeometryCollection<T> {
+    type Iter = FlatMapIter<'a, Geometry<T>, GeometryCoordsIter<'a, T>>;
+    type ExteriorIter = FlatMapIter<'a, Geometry<T>, GeometryExteriorCoordsIter<'a, T>>;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().flat_map(Geometry::coords_iter as _)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.iter().map(Geometry::coords_count).sum()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.0.iter().flat_map(Geometry::exterior_coords_iter as _)
+    }
+}
+
+/// The corners of a `Rect`, in the same order as its `Polygon` exterior
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Rect<T> {
+    type Iter = array::IntoIter<Coordinate<T>, 4>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        let (min, max) = (self.min(), self.max());
+        IntoIterator::into_iter([
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+        ])
+    }
+
+    fn coords_count(&'a self) -> usize {
+        4
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Triangle<T> {
+    type Iter = array::IntoIter<Coordinate<T>, 3>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        IntoIterator::into_iter(self.to_array())
+    }
+
+    fn coords_count(&'a self) -> usize {
+        3
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+macro_rules! geometry_coords_iter {
+    ($(#[$attr:meta])* $name:ident, $iter:ident, $method:ident) => {
+        $(#[$attr])*
+        pub enum $name<'a, T: CoordinateType + 'a> {
+            Point(<Point<T> as CoordsIter<'a, T>>::$iter),
+            Line(<Line<T> as CoordsIter<'a, T>>::$iter),
+            LineString(<LineString<T> as CoordsIter<'a, T>>::$iter),
+            Polygon(<Polygon<T> as CoordsIter<'a, T>>::$iter),
+            MultiPoint(<MultiPoint<T> as CoordsIter<'a, T>>::$iter),
+            MultiLineString(<MultiLineString<T> as CoordsIter<'a, T>>::$iter),
+            MultiPolygon(<MultiPolygon<T> as CoordsIter<'a, T>>::$iter),
+            // boxed, as the collection's iterator contains this type
+            GeometryCollection(Box<<GeometryCollection<T> as CoordsIter<'a, T>>::$iter>),
+            Rect(<Rect<T> as CoordsIter<'a, T>>::$iter),
+            Triangle(<Triangle<T> as CoordsIter<'a, T>>::$iter),
+        }
+
+        impl<'a, T: CoordinateType + 'a> $name<'a, T> {
+            fn new(geometry: &'a Geometry<T>) -> Self {
+                match geometry {
+                    Geometry::Point(g) => $name::Point(g.$method()),
+                    Geometry::Line(g) => $name::Line(g.$method()),
+                    Geometry::LineString(g) => $name::LineString(g.$method()),
+                    Geometry::Polygon(g) => $name::Polygon(g.$method()),
+                    Geometry::MultiPoint(g) => $name::MultiPoint(g.$method()),
+                    Geometry::MultiLineString(g) => $name::MultiLineString(g.$method()),
+                    Geometry::MultiPolygon(g) => $name::MultiPolygon(g.$method()),
+                    Geometry::GeometryCollection(g) => {
+                        $name::GeometryCollection(Box::new(g.$method()))
+                    }
+                    Geometry::Rect(g) => $name::Rect(g.$method()),
+                    Geometry::Triangle(g) => $name::Triangle(g.$method()),
+                }
+            }
+        }
+
+        impl<'a, T: CoordinateType + 'a> Iterator for $name<'a, T> {
+            type Item = Coordinate<T>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match self {
+                    $name::Point(iter) => iter.next(),
+                    $name::Line(iter) => iter.next(),
+                    $name::LineString(iter) => iter.next(),
+                    $name::Polygon(iter) => iter.next(),
+                    $name::MultiPoint(iter) => iter.next(),
+                    $name::MultiLineString(iter) => iter.next(),
+                    $name::MultiPolygon(iter) => iter.next(),
+                    $name::GeometryCollection(iter) => iter.next(),
+                    $name::Rect(iter) => iter.next(),
+                    $name::Triangle(iter) => iter.next(),
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    $name::Point(iter) => iter.size_hint(),
+                    $name::Line(iter) => iter.size_hint(),
+                    $name::LineString(iter) => iter.size_hint(),
+                    $name::Polygon(iter) => iter.size_hint(),
+                    $name::MultiPoint(iter) => iter.size_hint(),
+                    $name::MultiLineString(iter) => iter.size_hint(),
+                    $name::MultiPolygon(iter) => iter.size_hint(),
+                    $name::GeometryCollection(iter) => iter.size_hint(),
+                    $name::Rect(iter) => iter.size_hint(),
+                    $name::Triangle(iter) => iter.size_hint(),
+                }
+            }
+        }
+    };
+}
+
+geometry_coords_iter!(
+    /// An iterator over all the coordinates of a `Geometry`
+    GeometryCoordsIter,
+    Iter,
+    coords_iter
+);
+
+geometry_coords_iter!(
+    /// An iterator over the exterior coordinates of a `Geometry`
+    GeometryExteriorCoordsIter,
+    ExteriorIter,
+    exterior_coords_iter
+);
+
+impl<'a, T: CoordinateType + 'a> CoordsIter<'a, T> for Geometry<T> {
+    type Iter = GeometryCoordsIter<'a, T>;
+    type ExteriorIter = GeometryExteriorCoordsIter<'a, T>;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        GeometryCoordsIter::new(self)
+    }
+
+    fn coords_count(&'a self) -> usize {
+        match self {
+            Geometry::Point(g) => g.coords_count(),
+            Geometry::Line(g) => g.coords_count(),
+            Geometry::LineString(g) => g.coords_count(),
+            Geometry::Polygon(g) => g.coords_count(),
+            Geometry::MultiPoint(g) => g.coords_count(),
+            Geometry::MultiLineString(g) => g.coords_count(),
+            Geometry::MultiPolygon(g) => g.coords_count(),
+            Geometry::GeometryCollection(g) => g.coords_count(),
+            Geometry::Rect(g) => g.coords_count(),
+            Geometry::Triangle(g) => g.coords_count(),
+        }
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        GeometryExteriorCoordsIter::new(self)
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+impl<'a, 'bump: 'a, T: CoordinateType + 'a> CoordsIter<'a, T> for ArenaLineString<'bump, T> {
+    type Iter = LineStringIter<'a, T>;
+    type ExteriorIter = Self::Iter;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().copied()
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.0.len()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+impl<'a, 'bump: 'a, T: CoordinateType + 'a> CoordsIter<'a, T> for ArenaPolygon<'bump, T> {
+    type Iter = iter::Chain<
+        LineStringIter<'a, T>,
+        FlatMapIter<'a, ArenaLineString<'bump, T>, LineStringIter<'a, T>>,
+    >;
+    type ExteriorIter = LineStringIter<'a, T>;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.exterior().coords_iter().chain(
+            self.interiors()
+                .iter()
+                .flat_map(ArenaLineString::coords_iter as _),
+        )
+    }
+
+    fn coords_count(&'a self) -> usize {
+        self.exterior().coords_count()
+            + self
+                .interiors()
+                .iter()
+                .map(ArenaLineString::coords_count)
+                .sum::<usize>()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.exterior().coords_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn coords(coords: &[(f64, f64)]) -> Vec<Coordinate<f64>> {
+        coords.iter().map(|&c| c.into()).collect()
+    }
+
+    #[test]
+    fn simple_geometries() {
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(point.coords_iter().collect::<Vec<_>>(), coords(&[(1., 2.)]));
+        assert_eq!(point.coords_iter().len(), 1);
+
+        let line = Line::new((0., 0.), (1., 1.));
+        assert_eq!(line.coords_iter().len(), 2);
+
+        let rect = Rect::new((0., 0.), (2., 1.));
+        assert_eq!(
+            rect.coords_iter().collect::<Vec<_>>(),
+            coords(&[(0., 0.), (2., 0.), (2., 1.), (0., 1.)])
+        );
+
+        let triangle = Triangle((0., 0.).into(), (1., 0.).into(), (0., 1.).into());
+        assert_eq!(triangle.coords_iter().len(), 3);
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]],
+        );
+        let multi_polygon = MultiPolygon(vec![polygon.clone(), polygon.clone()]);
+
+        assert_eq!(polygon.coords_count(), 8);
+        assert_eq!(
+            polygon.exterior_coords_iter().collect::<Vec<_>>(),
+            coords(&[(0., 0.), (4., 0.), (0., 4.), (0., 0.)])
+        );
+        assert_eq!(multi_polygon.coords_iter().count(), 16);
+        assert_eq!(multi_polygon.coords_count(), 16);
+        assert_eq!(multi_polygon.exterior_coords_iter().count(), 8);
+    }
+
+    #[test]
+    fn nested_geometry_collection() {
+        let inner = GeometryCollection(vec![
+            line_string![(x: 1., y: 1.), (x: 2., y: 2.)].into(),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)].into(),
+        ]);
+        let gc = GeometryCollection(vec![
+            point!(x: 0., y: 0.).into(),
+            Geometry::GeometryCollection(inner),
+            MultiPoint(vec![point!(x: 3., y: 3.)]).into(),
+        ]);
+
+        let all: Vec<_> = gc.coords_iter().collect();
+        assert_eq!(
+            all,
+            coords(&[
+                (0., 0.),
+                (1., 1.),
+                (2., 2.),
+                (0., 0.),
+                (1., 0.),
+                (0., 1.),
+                (0., 0.),
+                (3., 3.),
+            ])
+        );
+        assert_eq!(gc.coords_count(), all.len());
+        assert_eq!(gc.exterior_coords_iter().count(), all.len());
+
+        let geometry = Geometry::GeometryCollection(gc);
+        assert_eq!(geometry.coords_count(), all.len());
+        assert_eq!(geometry.coords_iter().collect::<Vec<_>>(), all);
+    }
+
+    #[cfg(feature = "bumpalo")]
+    #[test]
+    fn arena_geometries() {
+        use crate::algorithm::extremes::ExtremeVertices;
+        use geo_types::arena::{ArenaLineString, ArenaPolygon, Bump};
+
+        let bump = Bump::new();
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]],
+        );
+        let in_arena = ArenaPolygon::from_polygon_in(&polygon, &bump);
+        assert_eq!(
+            in_arena.coords_iter().collect::<Vec<_>>(),
+            polygon.coords_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(in_arena.coords_count(), 8);
+        assert_eq!(in_arena.exterior_coords_iter().count(), 4);
+        assert_eq!(in_arena.extremes(), polygon.extremes());
+
+        let line_string = ArenaLineString::from_coords_in(vec![(0., 0.), (1., 2.)], &bump);
+        assert_eq!(line_string.coords_count(), 2);
+        assert_eq!(
+            line_string.coords_iter().collect::<Vec<_>>(),
+            coords(&[(0., 0.), (1., 2.)])
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::buffer::union_all;
+use crate::{MultiPolygon, Polygon};
+use num_traits::Float;
+use rstar::{RTree, RTreeNum, RTreeObject};
+
+/// Where two polygons of a coverage overlap
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageOverlap<T: Float> {
+    /// The indices of the two polygons, the lower first
+    pub polygons: (usize, usize),
+    /// The area inside both of them
+    pub overlap: MultiPolygon<T>,
+}
+
+/// The problems found in a coverage by
+/// [`CoverageValidation`](trait.CoverageValidation.html)
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageReport<T: Float> {
+    /// Each pair of polygons which overlap, with the area they share, ordered by their indices
+    pub overlaps: Vec<CoverageOverlap<T>>,
+    /// The area which none of the polygons cover
+    pub gaps: MultiPolygon<T>,
+}
+
+impl<T: Float> CoverageReport<T> {
+    /// Whether the polygons neither overlap nor leave gaps
+    pub fn is_valid(&self) -> bool {
+        self.overlaps.is_empty() && self.gaps.0.is_empty()
+    }
+}
+
+/// Check that polygons meant to tile a region, such as the cells of a grid or the
+/// administrative areas of a country, form a coverage: that no two of them overlap, and that
+/// they leave no gaps between them.
+///
+/// Polygons which only share borders or vertices don't overlap. The boolean operations of
+/// [`BooleanOps`](../bool_ops/trait.BooleanOps.html) find the overlaps and gaps, so borders need
+/// to be shared exactly, or within the tolerance those operations snap to; those which don't
+/// quite line up can be healed with [`Snap`](../snap/trait.Snap.html) first.
+pub trait CoverageValidation<T: Float> {
+    /// Find the overlaps between the polygons, and the gaps they enclose: the holes in their
+    /// union, apart from anything inside those holes.
+    ///
+    /// Without knowing the region the polygons should cover, a gap at its edge can't be told
+    /// apart from the edge itself; see `validate_coverage_within` to find those too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coverage::CoverageValidation;
+    /// use geo::{MultiPolygon, Rect};
+    ///
+    /// let cells: MultiPolygon<f64> = MultiPolygon(vec![
+    ///     Rect::new((0., 0.), (1., 1.)).into(),
+    ///     Rect::new((1., 0.), (2.1, 1.)).into(),
+    ///     Rect::new((2., 0.), (3., 1.)).into(),
+    ///     Rect::new((0., 1.), (1., 2.)).into(),
+    ///     Rect::new((2., 1.), (3., 2.)).into(),
+    ///     Rect::new((0., 2.), (3., 3.)).into(),
+    /// ]);
+    /// let report = cells.validate_coverage();
+    /// assert!(!report.is_valid());
+    ///
+    /// // the second and third cells overlap
+    /// assert_eq!(report.overlaps.len(), 1);
+    /// assert_eq!(report.overlaps[0].polygons, (1, 2));
+    /// assert_eq!(
+    ///     report.overlaps[0].overlap.0,
+    ///     vec![Rect::new((2., 0.), (2.1, 1.)).into()]
+    /// );
+    ///
+    /// // and the middle cell is missing
+    /// assert_eq!(report.gaps.0, vec![Rect::new((1., 1.), (2., 2.)).into()]);
+    /// ```
+    fn validate_coverage(&self) -> CoverageReport<T>;
+
+    /// Find the overlaps between the polygons, and the gaps they leave in `region`: the area of
+    /// `region` which none of them cover.
+    ///
+    /// Polygons reaching outside `region` aren't reported; intersect them with it to find out
+    /// how far they do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coverage::CoverageValidation;
+    /// use geo::{Polygon, Rect};
+    ///
+    /// let cells: Vec<Polygon<f64>> = vec![
+    ///     Rect::new((0., 0.), (1., 1.)).into(),
+    ///     Rect::new((1., 0.), (2., 1.)).into(),
+    /// ];
+    /// assert!(cells.validate_coverage().is_valid());
+    ///
+    /// let region: Polygon<f64> = Rect::new((0., 0.), (3., 1.)).into();
+    /// let report = cells.validate_coverage_within(&region);
+    /// assert_eq!(report.gaps.0, vec![Rect::new((2., 0.), (3., 1.)).into()]);
+    /// ```
+    fn validate_coverage_within<R>(&self, region: &R) -> CoverageReport<T>
+    where
+        R: BooleanOps<T, MultiPolygon<T>>;
+}
+
+impl<T> CoverageValidation<T> for [Polygon<T>]
+where
+    T: Float + RTreeNum,
+{
+    fn validate_coverage(&self) -> CoverageReport<T> {
+        let union = union_all(self.to_vec());
+        let holes = union
+            .0
+            .iter()
+            .flat_map(|polygon| polygon.interiors())
+            .map(|interior| Polygon::new(interior.clone(), vec![]))
+            .collect();
+        CoverageReport {
+            overlaps: overlaps(self),
+            // polygons inside the holes, which the union keeps apart, aren't gaps
+            gaps: MultiPolygon(holes).difference(&union),
+        }
+    }
+
+    fn validate_coverage_within<R>(&self, region: &R) -> CoverageReport<T>
+    where
+        R: BooleanOps<T, MultiPolygon<T>>,
+    {
+        CoverageReport {
+            overlaps: overlaps(self),
+            gaps: region.difference(&union_all(self.to_vec())),
+        }
+    }
+}
+
+impl<T> CoverageValidation<T> for MultiPolygon<T>
+where
+    T: Float + RTreeNum,
+{
+    fn validate_coverage(&self) -> CoverageReport<T> {
+        self.0.validate_coverage()
+    }
+
+    fn validate_coverage_within<R>(&self, region: &R) -> CoverageReport<T>
+    where
+        R: BooleanOps<T, MultiPolygon<T>>,
+    {
+        self.0.validate_coverage_within(region)
+    }
+}
+
+// Intersect each pair of polygons whose bounding rectangles intersect
+fn overlaps<T: Float + RTreeNum>(polygons: &[Polygon<T>]) -> Vec<CoverageOverlap<T>> {
+    let tree = RTree::bulk_load(polygons.iter().enumerate().map(Indexed).collect());
+    let mut overlaps = vec![];
+    for (i, polygon) in polygons.iter().enumerate() {
+        let mut neighbours: Vec<usize> = tree
+            .locate_in_envelope_intersecting(&polygon.envelope())
+            .map(|neighbour| neighbour.0 .0)
+            .filter(|&j| j > i)
+            .collect();
+        neighbours.sort_unstable();
+        for j in neighbours {
+            let overlap = polygon.intersection(&polygons[j]);
+            if !overlap.0.is_empty() {
+                overlaps.push(CoverageOverlap {
+                    polygons: (i, j),
+                    overlap,
+                });
+            }
+        }
+    }
+    overlaps
+}
+
+// A polygon in the tree, along with its index
+struct Indexed<'a, T: Float + RTreeNum>((usize, &'a Polygon<T>));
+
+impl<'a, T: Float + RTreeNum> RTreeObject for Indexed<'a, T> {
+    type Envelope = <Polygon<T> as RTreeObject>::Envelope;
+
+    fn envelope(&self) -> Self::Envelope {
+        (self.0).1.envelope()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{polygon, Rect};
+
+    #[test]
+    fn tiling() {
+        let cells: Vec<Polygon<f64>> = (0..4)
+            .flat_map(|x| (0..4).map(move |y| (x as f64, y as f64)))
+            .map(|(x, y)| Rect::new((x, y), (x + 1., y + 1.)).into())
+            .collect();
+        assert!(cells.validate_coverage().is_valid());
+        let region: Polygon<f64> = Rect::new((0., 0.), (4., 4.)).into();
+        assert!(cells.validate_coverage_within(&region).is_valid());
+        assert!(Vec::<Polygon<f64>>::new().validate_coverage().is_valid());
+    }
+
+    #[test]
+    fn gap_around_an_island() {
+        // a frame with a hole, and a smaller square in the middle of the hole
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 5.), (x: 1., y: 5.)]],
+        ];
+        let island: Polygon<f64> = Rect::new((2., 2.), (4., 4.)).into();
+        let report = MultiPolygon(vec![frame, island]).validate_coverage();
+        assert!(report.overlaps.is_empty());
+        assert_eq!(report.gaps.0.len(), 1);
+        assert_eq!(report.gaps.0[0].interiors().len(), 1);
+        assert_eq!(report.gaps.unsigned_area(), 12.);
+    }
+
+    #[test]
+    fn overlaps_in_order() {
+        let big: Polygon<f64> = Rect::new((0., 0.), (10., 10.)).into();
+        let a: Polygon<f64> = Rect::new((1., 1.), (2., 2.)).into();
+        let b: Polygon<f64> = Rect::new((5., 5.), (6., 6.)).into();
+        // touching `a` at a corner
+        let c: Polygon<f64> = Rect::new((2., 2.), (3., 3.)).into();
+        let report = MultiPolygon(vec![a, big, b, c]).validate_coverage();
+        assert_eq!(
+            report
+                .overlaps
+                .iter()
+                .map(|overlap| overlap.polygons)
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 3)]
+        );
+        assert_eq!(report.overlaps[1].overlap.unsigned_area(), 1.);
+        assert!(report.gaps.0.is_empty());
+    }
+}
@@ -0,0 +1,375 @@
+//! Tag geometries with their coordinate reference system, so that geographic and projected
+//! geometries can't be mixed up by accident.
+//!
+//! A [`WithCrs`](struct.WithCrs.html) wraps a geometry with a value naming its CRS. Geometries
+//! can only be compared with, or measured against, geometries in the same CRS, and only
+//! measurements which make sense in it are available: distances and lengths on the sphere or
+//! the ellipsoid for [`Geographic`](trait.Geographic.html) systems like
+//! [`Wgs84`](struct.Wgs84.html), and Euclidean distances, lengths, and areas for
+//! [`Projected`](trait.Projected.html) ones like [`WebMercator`] and [`Utm`]. Converting
+//! between them uses [`reproject`](struct.WithCrs.html#method.reproject).
+//!
+//! The CRS values of `Wgs84` and `WebMercator` have no size, so wrapping a geometry in them
+//! costs nothing; a `Utm` stores its zone. The geometry types themselves are left untagged,
+//! so every other algorithm works on the wrapped geometry as before, through
+//! [`geometry`](struct.WithCrs.html#method.geometry).
+//!
+//! [`WebMercator`]: ../projection/struct.WebMercator.html
+//! [`Utm`]: ../projection/struct.Utm.html
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::crs::{WithCrs, Wgs84};
+//! use geo::algorithm::projection::Utm;
+//! use geo::prelude::*;
+//! use geo::point;
+//!
+//! let zurich = WithCrs::new(point!(x: 8.5417f64, y: 47.3769), Wgs84);
+//! let bern = WithCrs::new(point!(x: 7.4474, y: 46.9480), Wgs84);
+//! let on_the_sphere = zurich.haversine_distance(&bern);
+//!
+//! let utm = Utm::new(32, true).unwrap();
+//! let (zurich, bern) = (zurich.reproject(utm), bern.reproject(utm));
+//! let on_the_map = zurich.euclidean_distance(&bern);
+//!
+//! assert!((on_the_sphere - on_the_map).abs() / on_the_map < 0.005);
+//! ```
+//!
+//! Geographic geometries have no Euclidean distances:
+//!
+//! ```compile_fail
+//! use geo::algorithm::crs::{WithCrs, Wgs84};
+//! use geo::prelude::*;
+//! use geo::point;
+//!
+//! let zurich = WithCrs::new(point!(x: 8.5417f64, y: 47.3769), Wgs84);
+//! let bern = WithCrs::new(point!(x: 7.4474, y: 46.9480), Wgs84);
+//! zurich.euclidean_distance(&bern);
+//! ```
+//!
+//! and projected geometries have no distances on the sphere:
+//!
+//! ```compile_fail
+//! use geo::algorithm::crs::WithCrs;
+//! use geo::algorithm::projection::WebMercator;
+//! use geo::prelude::*;
+//! use geo::point;
+//!
+//! let a = WithCrs::new(point!(x: 0f64, y: 0.), WebMercator);
+//! let b = WithCrs::new(point!(x: 1000., y: 0.), WebMercator);
+//! a.haversine_distance(&b);
+//! ```
+use crate::algorithm::area::Area;
+use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::euclidean_length::EuclideanLength;
+#[cfg(feature = "geographiclib")]
+use crate::algorithm::geodesic_area::GeodesicArea;
+use crate::algorithm::geodesic_distance::GeodesicDistance;
+use crate::algorithm::geodesic_length::GeodesicLength;
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::algorithm::haversine_length::HaversineLength;
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::projection::{Project, Transform, Utm, WebMercator};
+use crate::algorithm::vincenty_distance::{FailedToConvergeError, VincentyDistance};
+use crate::algorithm::vincenty_length::VincentyLength;
+use crate::{Coordinate, CoordinateType};
+use num_traits::Float;
+use std::fmt::Debug;
+
+/// A coordinate reference system, naming what a geometry's coordinates mean.
+pub trait Crs: Copy + Debug + PartialEq {}
+
+/// A coordinate reference system of longitudes (`x`) and latitudes (`y`), in degrees.
+pub trait Geographic: Crs {}
+
+/// A coordinate reference system of positions on a map, in metres.
+pub trait Projected: Crs {}
+
+/// The geographic coordinate reference system of GPS and web maps,
+/// [EPSG:4326](https://epsg.io/4326), in longitudes (`x`) and latitudes (`y`).
+///
+/// Its [`Transform`](../projection/trait.Transform.html) leaves coordinates as they are, so
+/// that geometries can be reprojected from and to it like any other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wgs84;
+
+impl Crs for Wgs84 {}
+impl Geographic for Wgs84 {}
+
+impl<T: Float> Transform<T> for Wgs84 {
+    fn project(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        coord
+    }
+
+    fn unproject(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        coord
+    }
+}
+
+impl Crs for WebMercator {}
+impl Projected for WebMercator {}
+impl Crs for Utm {}
+impl Projected for Utm {}
+
+/// A geometry tagged with its [coordinate reference system](index.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WithCrs<G, C: Crs> {
+    geometry: G,
+    crs: C,
+}
+
+impl<G, C: Crs> WithCrs<G, C> {
+    /// Tag a geometry whose coordinates are in `crs`.
+    pub fn new(geometry: G, crs: C) -> Self {
+        WithCrs { geometry, crs }
+    }
+
+    /// The untagged geometry, for algorithms which don't depend on the CRS.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// The CRS of the geometry.
+    pub fn crs(&self) -> C {
+        self.crs
+    }
+
+    /// Return the untagged geometry.
+    pub fn into_geometry(self) -> G {
+        self.geometry
+    }
+
+    /// Convert the geometry from its CRS to `crs`, through longitudes and latitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::crs::{WithCrs, Wgs84};
+    /// use geo::algorithm::projection::WebMercator;
+    /// use geo::line_string;
+    ///
+    /// let route = WithCrs::new(line_string![(x: 0., y: 0.), (x: 1., y: 1.)], Wgs84);
+    /// let on_the_map = route.reproject(WebMercator);
+    /// let back = on_the_map.reproject(Wgs84);
+    ///
+    /// assert!((back.geometry().0[1].y - 1.0f64).abs() < 1e-12);
+    /// ```
+    pub fn reproject<T, D>(&self, crs: D) -> WithCrs<G, D>
+    where
+        T: Float,
+        G: MapCoords<T, T, Output = G>,
+        C: Transform<T>,
+        D: Crs + Transform<T>,
+    {
+        let geometry = self.geometry.unproject(&self.crs).project(&crs);
+        WithCrs { geometry, crs }
+    }
+}
+
+// Measurements on the sphere or the ellipsoid, for geographic geometries
+
+impl<T, G, Rhs, C> HaversineDistance<T, WithCrs<Rhs, C>> for WithCrs<G, C>
+where
+    G: HaversineDistance<T, Rhs>,
+    C: Geographic,
+{
+    fn haversine_distance(&self, rhs: &WithCrs<Rhs, C>) -> T {
+        self.geometry.haversine_distance(&rhs.geometry)
+    }
+}
+
+impl<T, G, Rhs, C> VincentyDistance<T, WithCrs<Rhs, C>> for WithCrs<G, C>
+where
+    G: VincentyDistance<T, Rhs>,
+    C: Geographic,
+{
+    fn vincenty_distance(&self, rhs: &WithCrs<Rhs, C>) -> Result<T, FailedToConvergeError> {
+        self.geometry.vincenty_distance(&rhs.geometry)
+    }
+}
+
+impl<T, G, Rhs, C> GeodesicDistance<T, WithCrs<Rhs, C>> for WithCrs<G, C>
+where
+    G: GeodesicDistance<T, Rhs>,
+    C: Geographic,
+{
+    fn geodesic_distance(&self, rhs: &WithCrs<Rhs, C>) -> T {
+        self.geometry.geodesic_distance(&rhs.geometry)
+    }
+}
+
+impl<T, G, C> HaversineLength<T> for WithCrs<G, C>
+where
+    G: HaversineLength<T>,
+    C: Geographic,
+{
+    fn haversine_length(&self) -> T {
+        self.geometry.haversine_length()
+    }
+}
+
+impl<T, G, C> VincentyLength<T> for WithCrs<G, C>
+where
+    G: VincentyLength<T>,
+    C: Geographic,
+{
+    fn vincenty_length(&self) -> Result<T, FailedToConvergeError> {
+        self.geometry.vincenty_length()
+    }
+}
+
+impl<T, G, C> GeodesicLength<T> for WithCrs<G, C>
+where
+    G: GeodesicLength<T>,
+    C: Geographic,
+{
+    fn geodesic_length(&self) -> T {
+        self.geometry.geodesic_length()
+    }
+}
+
+#[cfg(feature = "geographiclib")]
+impl<T, G, C> GeodesicArea<T> for WithCrs<G, C>
+where
+    T: Float + CoordinateType,
+    G: GeodesicArea<T>,
+    C: Geographic,
+{
+    fn geodesic_area(&self) -> T {
+        self.geometry.geodesic_area()
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        self.geometry.geodesic_area_signed()
+    }
+}
+
+impl<T, G, C> ChamberlainDuquetteArea<T> for WithCrs<G, C>
+where
+    T: Float + CoordinateType,
+    G: ChamberlainDuquetteArea<T>,
+    C: Geographic,
+{
+    fn chamberlain_duquette_area(&self) -> T {
+        self.geometry.chamberlain_duquette_area()
+    }
+}
+
+// Measurements on the plane, for projected geometries
+
+impl<T, G, Rhs, C> EuclideanDistance<T, WithCrs<Rhs, C>> for WithCrs<G, C>
+where
+    G: EuclideanDistance<T, Rhs>,
+    C: Projected,
+{
+    fn euclidean_distance(&self, rhs: &WithCrs<Rhs, C>) -> T {
+        self.geometry.euclidean_distance(&rhs.geometry)
+    }
+}
+
+impl<T, G, C> EuclideanLength<T> for WithCrs<G, C>
+where
+    G: EuclideanLength<T>,
+    C: Projected,
+{
+    fn euclidean_length(&self) -> T {
+        self.geometry.euclidean_length()
+    }
+}
+
+impl<T, G, C> Area<T> for WithCrs<G, C>
+where
+    T: CoordinateType,
+    G: Area<T>,
+    C: Projected,
+{
+    fn signed_area(&self) -> T {
+        self.geometry.signed_area()
+    }
+
+    fn unsigned_area(&self) -> T {
+        self.geometry.unsigned_area()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn measures_match_the_untagged_geometries() {
+        let a = point!(x: 8.5417, y: 47.3769);
+        let b = point!(x: 7.4474, y: 46.9480);
+        let (tagged_a, tagged_b) = (WithCrs::new(a, Wgs84), WithCrs::new(b, Wgs84));
+        assert_eq!(
+            tagged_a.haversine_distance(&tagged_b),
+            a.haversine_distance(&b)
+        );
+        assert_eq!(
+            tagged_a.geodesic_distance(&tagged_b),
+            a.geodesic_distance(&b)
+        );
+        assert_eq!(
+            tagged_a.vincenty_distance(&tagged_b).unwrap(),
+            a.vincenty_distance(&b).unwrap()
+        );
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        let tagged = WithCrs::new(line_string.clone(), Wgs84);
+        assert_eq!(tagged.haversine_length(), line_string.haversine_length());
+        assert_eq!(tagged.geodesic_length(), line_string.geodesic_length());
+
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let geographic = WithCrs::new(polygon.clone(), Wgs84);
+        #[cfg(feature = "geographiclib")]
+        assert_eq!(geographic.geodesic_area(), polygon.geodesic_area());
+        assert_eq!(
+            geographic.chamberlain_duquette_area(),
+            polygon.chamberlain_duquette_area()
+        );
+        let projected = WithCrs::new(polygon.clone(), WebMercator);
+        assert_eq!(projected.unsigned_area(), 1.);
+        assert_eq!(projected.geometry(), &polygon);
+        assert_eq!(projected.into_geometry(), polygon);
+    }
+
+    #[test]
+    fn reprojection() {
+        let area =
+            polygon![(x: 9., y: 45.), (x: 9.01, y: 45.), (x: 9.01, y: 45.01), (x: 9., y: 45.01)];
+        let geographic = WithCrs::new(area.clone(), Wgs84);
+        let utm = Utm::new(32, true).unwrap();
+        let projected = geographic.reproject(utm);
+        assert_eq!(projected.crs(), utm);
+        assert_eq!(projected.geometry(), &area.project(&utm));
+        // the areas on the ellipsoid and the map agree to within the scale factor's distortion
+        #[cfg(feature = "geographiclib")]
+        {
+            let ratio = projected.unsigned_area() / geographic.geodesic_area();
+            assert!((ratio - 1.).abs() < 1e-3);
+        }
+
+        // between projections, through longitudes and latitudes
+        let mercator = projected.reproject(WebMercator);
+        let expected = area.project(&WebMercator);
+        for (a, b) in mercator
+            .geometry()
+            .exterior()
+            .0
+            .iter()
+            .zip(&expected.exterior().0)
+        {
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-6);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-6);
+        }
+        let back = mercator.reproject(Wgs84);
+        for (a, b) in back.geometry().exterior().0.iter().zip(&area.exterior().0) {
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-9);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-9);
+        }
+        assert_eq!(std::mem::size_of::<WithCrs<Coordinate<f64>, Wgs84>>(), 16);
+    }
+}
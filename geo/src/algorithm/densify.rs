@@ -0,0 +1,467 @@
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+use crate::{
+    Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+#[cfg(feature = "geographiclib")]
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use num_traits::{Float, FromPrimitive};
+
+/// Insert vertices into a geometry, so that no segment is longer than a given length in the
+/// plane.
+///
+/// Each segment is split into the fewest pieces of equal length which are no longer than
+/// `max_segment_length`, so the shape of the geometry is unchanged. If `max_segment_length` isn't
+/// positive, the geometry is returned unchanged.
+///
+/// For geometries in longitude and latitude, see
+/// [`DensifyHaversine`](trait.DensifyHaversine.html) and
+/// [`DensifyGeodesic`](trait.DensifyGeodesic.html), which insert vertices along the great circle
+/// or geodesic between them instead, so that the result stays close to the intended route when
+/// it is projected.
+pub trait Densify<T: Float> {
+    type Output;
+
+    /// Insert vertices into a geometry, so that no segment is longer than `max_segment_length`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::densify::Densify;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 0., y: 6.), (x: 1., y: 7.)];
+    /// let densified = line_string.densify(2.);
+    /// assert_eq!(
+    ///     densified,
+    ///     line_string![
+    ///         (x: 0., y: 0.),
+    ///         (x: 0., y: 2.),
+    ///         (x: 0., y: 4.),
+    ///         (x: 0., y: 6.),
+    ///         (x: 1., y: 7.),
+    ///     ]
+    /// );
+    /// ```
+    fn densify(&self, max_segment_length: T) -> Self::Output;
+}
+
+/// Insert vertices into a geometry in longitude and latitude, along the great circles between
+/// its vertices, so that no segment is longer than a given distance on a sphere.
+///
+/// Segments are split into pieces of equal length using the [haversine formula], as in
+/// [`HaversineIntermediate`](../haversine_intermediate/trait.HaversineIntermediate.html). If
+/// `max_distance` isn't positive, the geometry is returned unchanged.
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait DensifyHaversine<T: Float + FromPrimitive> {
+    type Output;
+
+    /// Insert vertices into a geometry, so that no segment is longer than `max_distance` on a
+    /// sphere.
+    ///
+    /// # Units
+    ///
+    /// - `max_distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::densify::DensifyHaversine;
+    /// use geo::prelude::*;
+    /// use geo::line_string;
+    ///
+    /// // New York City to London
+    /// let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+    /// let densified = line_string.densify_haversine(1_000_000.);
+    /// assert_eq!(densified.0.len(), 7);
+    /// for line in densified.lines() {
+    ///     assert!(line.haversine_length() <= 1_000_000.);
+    /// }
+    /// // the route follows the great circle, far north of the straight line on the map
+    /// assert!(densified.0[4].y > 53.);
+    /// ```
+    fn densify_haversine(&self, max_distance: T) -> Self::Output;
+}
+
+/// Insert vertices into a geometry in longitude and latitude, along the geodesics between its
+/// vertices, so that no segment is longer than a given distance on the WGS84 ellipsoid.
+///
+/// Segments are split into pieces of equal length using the method of [Karney (2013)], as in
+/// [`GeodesicDistance`](../geodesic_distance/trait.GeodesicDistance.html). If `max_distance`
+/// isn't positive, the geometry is returned unchanged.
+///
+/// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+#[cfg(feature = "geographiclib")]
+pub trait DensifyGeodesic<T: Float> {
+    type Output;
+
+    /// Insert vertices into a geometry, so that no segment is longer than `max_distance` on the
+    /// WGS84 ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `max_distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::densify::DensifyGeodesic;
+    /// use geo::prelude::*;
+    /// use geo::line_string;
+    ///
+    /// // New York City to London
+    /// let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+    /// let densified = line_string.densify_geodesic(1_000_000.);
+    /// assert_eq!(densified.0.len(), 7);
+    /// assert_relative_eq!(
+    ///     densified.geodesic_length(),
+    ///     line_string.geodesic_length(),
+    ///     epsilon = 1e-6
+    /// );
+    /// ```
+    fn densify_geodesic(&self, max_distance: T) -> Self::Output;
+}
+
+// How to measure a segment and find the points along it
+trait Metric<T: Float> {
+    fn length(&self, start: Point<T>, end: Point<T>) -> T;
+
+    fn between(&self, start: Point<T>, end: Point<T>, fraction: T) -> Point<T>;
+}
+
+struct Euclidean;
+
+impl<T: Float> Metric<T> for Euclidean {
+    fn length(&self, start: Point<T>, end: Point<T>) -> T {
+        start.euclidean_distance(&end)
+    }
+
+    fn between(&self, start: Point<T>, end: Point<T>, fraction: T) -> Point<T> {
+        let (start, end) = (start.0, end.0);
+        Point::new(
+            start.x + (end.x - start.x) * fraction,
+            start.y + (end.y - start.y) * fraction,
+        )
+    }
+}
+
+struct Haversine;
+
+impl<T: Float + FromPrimitive> Metric<T> for Haversine {
+    fn length(&self, start: Point<T>, end: Point<T>) -> T {
+        start.haversine_distance(&end)
+    }
+
+    fn between(&self, start: Point<T>, end: Point<T>, fraction: T) -> Point<T> {
+        start.haversine_intermediate(&end, fraction)
+    }
+}
+
+#[cfg(feature = "geographiclib")]
+struct Wgs84(Geodesic);
+
+#[cfg(feature = "geographiclib")]
+impl<T: Float> Metric<T> for Wgs84 {
+    fn length(&self, start: Point<T>, end: Point<T>) -> T {
+        let distance: f64 = self.0.inverse(
+            to_f64(start.y()),
+            to_f64(start.x()),
+            to_f64(end.y()),
+            to_f64(end.x()),
+        );
+        T::from(distance).unwrap_or_else(T::nan)
+    }
+
+    fn between(&self, start: Point<T>, end: Point<T>, fraction: T) -> Point<T> {
+        let (lat, lng) = (to_f64(start.y()), to_f64(start.x()));
+        let (distance, azimuth, _, _): (f64, f64, f64, f64) =
+            self.0.inverse(lat, lng, to_f64(end.y()), to_f64(end.x()));
+        let (lat, lng): (f64, f64) = self
+            .0
+            .direct(lat, lng, azimuth, distance * to_f64(fraction));
+        let from_f64 = |value: f64| T::from(value).unwrap_or_else(T::nan);
+        Point::new(from_f64(lng), from_f64(lat))
+    }
+}
+
+#[cfg(feature = "geographiclib")]
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap_or_else(f64::nan)
+}
+
+fn densify_coords<T, M>(coords: &[Coordinate<T>], max_length: T, metric: &M) -> LineString<T>
+where
+    T: Float,
+    M: Metric<T>,
+{
+    if max_length.is_nan() || max_length <= T::zero() {
+        return LineString(coords.to_vec());
+    }
+    let mut densified = Vec::with_capacity(coords.len());
+    for pair in coords.windows(2) {
+        let (start, end) = (Point(pair[0]), Point(pair[1]));
+        densified.push(pair[0]);
+        let pieces = (metric.length(start, end) / max_length).ceil();
+        // a non-finite length can't be split
+        if let Some(pieces) = pieces.to_usize() {
+            let pieces_t = T::from(pieces).unwrap();
+            for piece in 1..pieces {
+                let fraction = T::from(piece).unwrap() / pieces_t;
+                densified.push(metric.between(start, end, fraction).0);
+            }
+        }
+    }
+    densified.extend(coords.last());
+    LineString(densified)
+}
+
+fn densify_polygon<T, M>(polygon: &Polygon<T>, max_length: T, metric: &M) -> Polygon<T>
+where
+    T: Float,
+    M: Metric<T>,
+{
+    Polygon::new(
+        densify_coords(&polygon.exterior().0, max_length, metric),
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| densify_coords(&interior.0, max_length, metric))
+            .collect(),
+    )
+}
+
+macro_rules! densify_impls {
+    ($trait:ident, $method:ident, [$($bound:tt)+], $metric:expr) => {
+        impl<T> $trait<T> for Line<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = LineString<T>;
+
+            fn $method(&self, max_length: T) -> LineString<T> {
+                densify_coords(&[self.start, self.end], max_length, &$metric)
+            }
+        }
+
+        impl<T> $trait<T> for LineString<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = LineString<T>;
+
+            fn $method(&self, max_length: T) -> LineString<T> {
+                densify_coords(&self.0, max_length, &$metric)
+            }
+        }
+
+        impl<T> $trait<T> for MultiLineString<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = MultiLineString<T>;
+
+            fn $method(&self, max_length: T) -> MultiLineString<T> {
+                let metric = $metric;
+                MultiLineString(
+                    self.0
+                        .iter()
+                        .map(|line_string| densify_coords(&line_string.0, max_length, &metric))
+                        .collect(),
+                )
+            }
+        }
+
+        impl<T> $trait<T> for Polygon<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = Polygon<T>;
+
+            fn $method(&self, max_length: T) -> Polygon<T> {
+                densify_polygon(self, max_length, &$metric)
+            }
+        }
+
+        impl<T> $trait<T> for MultiPolygon<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = MultiPolygon<T>;
+
+            fn $method(&self, max_length: T) -> MultiPolygon<T> {
+                let metric = $metric;
+                MultiPolygon(
+                    self.0
+                        .iter()
+                        .map(|polygon| densify_polygon(polygon, max_length, &metric))
+                        .collect(),
+                )
+            }
+        }
+
+        impl<T> $trait<T> for Rect<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = Polygon<T>;
+
+            fn $method(&self, max_length: T) -> Polygon<T> {
+                densify_polygon(&Polygon::from(*self), max_length, &$metric)
+            }
+        }
+
+        impl<T> $trait<T> for Triangle<T>
+        where
+            T: $($bound)+,
+        {
+            type Output = Polygon<T>;
+
+            fn $method(&self, max_length: T) -> Polygon<T> {
+                densify_polygon(&Polygon::from(*self), max_length, &$metric)
+            }
+        }
+    };
+}
+
+densify_impls!(Densify, densify, [Float], Euclidean);
+densify_impls!(
+    DensifyHaversine,
+    densify_haversine,
+    [Float + FromPrimitive],
+    Haversine
+);
+#[cfg(feature = "geographiclib")]
+densify_impls!(
+    DensifyGeodesic,
+    densify_geodesic,
+    [Float],
+    Wgs84(Geodesic::wgs84())
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "geographiclib")]
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+    use crate::algorithm::haversine_length::HaversineLength;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn linear() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 3., y: 4. });
+        assert_eq!(
+            line.densify(2.),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 1., y: 4. / 3.),
+                (x: 2., y: 8. / 3.),
+                (x: 3., y: 4.),
+            ]
+        );
+        // segments which are short enough are unchanged
+        assert_eq!(
+            line.densify(5.),
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.)]
+        );
+        assert_eq!(
+            line.densify(0.),
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.)]
+        );
+        assert_eq!(
+            line.densify(-1.),
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = LineString(vec![]);
+        assert_eq!(empty.densify(1.), empty);
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.densify(1.), single);
+        let repeated = line_string![(x: 1., y: 1.), (x: 1., y: 1.), (x: 3., y: 1.)];
+        assert_eq!(
+            repeated.densify(1.),
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.), (x: 2., y: 1.), (x: 3., y: 1.)]
+        );
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let densified = polygon.densify(2.);
+        assert_eq!(
+            densified.exterior(),
+            &line_string![
+                (x: 0., y: 0.),
+                (x: 2., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 2.),
+                (x: 4., y: 4.),
+                (x: 2., y: 4.),
+                (x: 0., y: 4.),
+                (x: 0., y: 2.),
+                (x: 0., y: 0.),
+            ]
+        );
+        assert_eq!(densified.interiors(), polygon.interiors());
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 1. });
+        assert_eq!(rect.densify(1.).exterior().0.len(), 7);
+        let triangle = Triangle(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 2., y: 0. },
+            Coordinate { x: 0., y: 2. },
+        );
+        assert_eq!(triangle.densify(1.).exterior().0.len(), 8);
+    }
+
+    #[test]
+    fn haversine() {
+        let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let densified = line_string.densify_haversine(500_000.);
+        assert_eq!(densified.0.len(), 13);
+        assert_relative_eq!(
+            densified.haversine_length(),
+            line_string.haversine_length(),
+            epsilon = 1e-6
+        );
+        let lengths: Vec<f64> = densified
+            .lines()
+            .map(|line| line.haversine_length())
+            .collect();
+        for length in &lengths {
+            assert_relative_eq!(*length, lengths[0], epsilon = 1e-6);
+        }
+    }
+
+    #[cfg(feature = "geographiclib")]
+    #[test]
+    fn geodesic() {
+        let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let densified = line_string.densify_geodesic(500_000.);
+        assert_eq!(densified.0.len(), 13);
+        let lengths: Vec<f64> = densified
+            .lines()
+            .map(|line| line.start_point().geodesic_distance(&line.end_point()))
+            .collect();
+        for length in &lengths {
+            assert!(*length <= 500_000.);
+            assert_relative_eq!(*length, lengths[0], epsilon = 1e-6);
+        }
+        // across the antimeridian, the route stays near it
+        let line = Line::new(
+            Coordinate { x: 179., y: 0. },
+            Coordinate { x: -179., y: 0. },
+        );
+        let densified = line.densify_geodesic(100_000.);
+        assert_eq!(densified.0.len(), 4);
+        assert!(densified.0[1].x > 179. || densified.0[1].x < -179.);
+    }
+}
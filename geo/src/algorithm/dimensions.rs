@@ -0,0 +1,286 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// The dimensions of a geometry, or of a set of points, such as the intersection of two parts of
+/// geometries
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dimensions {
+    /// There are no points
+    Empty,
+    /// There are only points
+    ZeroDimensional,
+    /// There are curves, and perhaps points
+    OneDimensional,
+    /// There are areas, and perhaps curves and points
+    TwoDimensional,
+}
+
+/// Find the dimensions of a geometry, as they really are rather than as its type suggests, to
+/// detect degenerate geometries such as `Polygon`s without area and `Line`s without length.
+pub trait HasDimensions {
+    /// Return the dimensions of the geometry.
+    ///
+    /// An empty `Point`, with NaN coordinates, has no dimensions. A `LineString` whose coordinates
+    /// are all the same is only a point, and a `Polygon` whose exterior is collinear only a curve.
+    /// The interior rings of polygons are inside their exteriors, so don't count. A collection has
+    /// the greatest dimensions of any of its members, or none at all, if it has no members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::dimensions::{Dimensions, HasDimensions};
+    /// use geo::{line_string, polygon, Geometry, GeometryCollection, Point};
+    ///
+    /// let flat = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+    /// assert_eq!(flat.dimensions(), Dimensions::OneDimensional);
+    ///
+    /// let collection = GeometryCollection(vec![
+    ///     Geometry::Point(Point::new(5., 5.)),
+    ///     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 0., y: 0.)]),
+    /// ]);
+    /// assert_eq!(collection.dimensions(), Dimensions::ZeroDimensional);
+    /// ```
+    fn dimensions(&self) -> Dimensions;
+
+    /// Whether the geometry has no points at all.
+    ///
+    /// Unlike `GeometryCollection::is_empty`, a collection of empty members is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::dimensions::HasDimensions;
+    /// use geo::{Geometry, GeometryCollection, LineString, Point};
+    ///
+    /// let collection = GeometryCollection(vec![Geometry::LineString(LineString::<f64>(vec![]))]);
+    /// assert!(HasDimensions::is_empty(&collection));
+    /// assert!(!HasDimensions::is_empty(&Point::new(0., 0.)));
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.dimensions() == Dimensions::Empty
+    }
+}
+
+// The difference `b - a`, as its magnitude and whether it's negative, so unsigned coordinates
+// work too
+fn difference<T: CoordinateType>(a: T, b: T) -> (T, bool) {
+    if b < a {
+        (a - b, true)
+    } else {
+        (b - a, false)
+    }
+}
+
+fn collinear<T: CoordinateType>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> bool {
+    let (dx_b, negative_x_b) = difference(a.x, b.x);
+    let (dy_b, negative_y_b) = difference(a.y, b.y);
+    let (dx_c, negative_x_c) = difference(a.x, c.x);
+    let (dy_c, negative_y_c) = difference(a.y, c.y);
+    let (left, right) = (dx_b * dy_c, dy_b * dx_c);
+    left == right
+        && (left == T::zero() || (negative_x_b != negative_y_c) == (negative_y_b != negative_x_c))
+}
+
+// The dimensions of the shape spanned by some coordinates
+fn coords_dimensions<T: CoordinateType>(coords: &[Coordinate<T>], areal: bool) -> Dimensions {
+    let first = match coords.first() {
+        Some(first) => *first,
+        None => return Dimensions::Empty,
+    };
+    let second = match coords.iter().find(|coord| **coord != first) {
+        Some(second) => *second,
+        None => return Dimensions::ZeroDimensional,
+    };
+    if areal && coords.iter().any(|coord| !collinear(first, second, *coord)) {
+        Dimensions::TwoDimensional
+    } else {
+        Dimensions::OneDimensional
+    }
+}
+
+fn max_dimensions<'a, G: 'a + HasDimensions>(members: impl Iterator<Item = &'a G>) -> Dimensions {
+    members
+        .map(HasDimensions::dimensions)
+        .max()
+        .unwrap_or(Dimensions::Empty)
+}
+
+impl<T: CoordinateType> HasDimensions for Point<T> {
+    fn dimensions(&self) -> Dimensions {
+        if self.is_empty() {
+            Dimensions::Empty
+        } else {
+            Dimensions::ZeroDimensional
+        }
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for Line<T> {
+    fn dimensions(&self) -> Dimensions {
+        coords_dimensions(&[self.start, self.end], false)
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for LineString<T> {
+    fn dimensions(&self) -> Dimensions {
+        coords_dimensions(&self.0, false)
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for Polygon<T> {
+    fn dimensions(&self) -> Dimensions {
+        coords_dimensions(&self.exterior().0, true)
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for MultiPoint<T> {
+    fn dimensions(&self) -> Dimensions {
+        max_dimensions(self.0.iter())
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for MultiLineString<T> {
+    fn dimensions(&self) -> Dimensions {
+        max_dimensions(self.0.iter())
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for MultiPolygon<T> {
+    fn dimensions(&self) -> Dimensions {
+        max_dimensions(self.0.iter())
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for GeometryCollection<T> {
+    fn dimensions(&self) -> Dimensions {
+        max_dimensions(self.0.iter())
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for Rect<T> {
+    fn dimensions(&self) -> Dimensions {
+        coords_dimensions(
+            &[
+                self.min(),
+                self.max(),
+                Coordinate {
+                    x: self.min().x,
+                    y: self.max().y,
+                },
+            ],
+            true,
+        )
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for Triangle<T> {
+    fn dimensions(&self) -> Dimensions {
+        coords_dimensions(&self.to_array(), true)
+    }
+}
+
+impl<T: CoordinateType> HasDimensions for Geometry<T> {
+    fn dimensions(&self) -> Dimensions {
+        match self {
+            Geometry::Point(g) => g.dimensions(),
+            Geometry::Line(g) => g.dimensions(),
+            Geometry::LineString(g) => g.dimensions(),
+            Geometry::Polygon(g) => g.dimensions(),
+            Geometry::MultiPoint(g) => g.dimensions(),
+            Geometry::MultiLineString(g) => g.dimensions(),
+            Geometry::MultiPolygon(g) => g.dimensions(),
+            Geometry::GeometryCollection(g) => g.dimensions(),
+            Geometry::Rect(g) => g.dimensions(),
+            Geometry::Triangle(g) => g.dimensions(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn degenerate_geometries() {
+        let point = Coordinate { x: 1., y: 1. };
+        assert_eq!(
+            Line::new(point, point).dimensions(),
+            Dimensions::ZeroDimensional
+        );
+        assert_eq!(
+            Line::new(point, Coordinate { x: 2., y: 1. }).dimensions(),
+            Dimensions::OneDimensional
+        );
+        assert_eq!(LineString::<f64>(vec![]).dimensions(), Dimensions::Empty);
+        assert_eq!(
+            line_string![(x: 1, y: 1)].dimensions(),
+            Dimensions::ZeroDimensional
+        );
+
+        assert_eq!(
+            Polygon::<f64>::new(LineString(vec![]), vec![]).dimensions(),
+            Dimensions::Empty
+        );
+        assert_eq!(
+            polygon![(x: 2, y: 2), (x: 2, y: 2), (x: 2, y: 2)].dimensions(),
+            Dimensions::ZeroDimensional
+        );
+        // collinear, with the coordinates going back on themselves
+        assert_eq!(
+            polygon![(x: 0u32, y: 0), (x: 4, y: 2), (x: 2, y: 1), (x: 6, y: 3)].dimensions(),
+            Dimensions::OneDimensional
+        );
+        assert_eq!(
+            polygon![(x: 0u32, y: 4), (x: 4, y: 2), (x: 2, y: 1)].dimensions(),
+            Dimensions::TwoDimensional
+        );
+        // differences with opposite signs, but products of the same magnitude
+        assert_eq!(
+            Triangle::from([(0, 0), (1, 1), (-1, 1)]).dimensions(),
+            Dimensions::TwoDimensional
+        );
+        assert_eq!(
+            Triangle::from([(0, 0), (1, -1), (-1, 1)]).dimensions(),
+            Dimensions::OneDimensional
+        );
+
+        let rect = |(x1, y1), (x2, y2)| Rect::new((x1, y1), (x2, y2)).dimensions();
+        assert_eq!(rect((0., 0.), (0., 0.)), Dimensions::ZeroDimensional);
+        assert_eq!(rect((0., 0.), (0., 3.)), Dimensions::OneDimensional);
+        assert_eq!(rect((0., 0.), (3., 0.)), Dimensions::OneDimensional);
+        assert_eq!(rect((0., 0.), (3., 3.)), Dimensions::TwoDimensional);
+    }
+
+    #[test]
+    fn collections() {
+        assert_eq!(MultiPoint::<f64>(vec![]).dimensions(), Dimensions::Empty);
+        assert_eq!(
+            MultiPoint(vec![Point::<f64>::empty()]).dimensions(),
+            Dimensions::Empty
+        );
+        assert_eq!(
+            MultiPoint(vec![point!(x: 0., y: 0.)]).dimensions(),
+            Dimensions::ZeroDimensional
+        );
+        let collection = GeometryCollection(vec![
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]),
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon![
+                (x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)
+            ]])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]);
+        assert_eq!(collection.dimensions(), Dimensions::OneDimensional);
+        assert!(!HasDimensions::is_empty(&collection));
+        let empty = GeometryCollection(vec![
+            Geometry::MultiLineString(MultiLineString::<f64>(vec![LineString(vec![])])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]);
+        assert!(HasDimensions::is_empty(&empty));
+        assert!(HasDimensions::is_empty(&Geometry::GeometryCollection(
+            empty
+        )));
+    }
+}
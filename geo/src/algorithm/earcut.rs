@@ -0,0 +1,736 @@
+use crate::{Coordinate, LineString, MultiPolygon, Polygon, Triangle};
+use num_traits::Float;
+
+/// The triangles of a tessellated geometry, as indices into a flat buffer of vertices, as used by
+/// GPU APIs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EarcutTriangles<T> {
+    /// The coordinates of the vertices, as `x` then `y` for each one: those of each ring, without
+    /// the closing coordinate, in order, starting with the exterior
+    pub vertices: Vec<T>,
+    /// The indices of the vertices of the triangles, three for each one, counter-clockwise
+    pub triangle_indices: Vec<usize>,
+}
+
+impl<T: Float> EarcutTriangles<T> {
+    fn vertex(&self, index: usize) -> Coordinate<T> {
+        Coordinate {
+            x: self.vertices[2 * index],
+            y: self.vertices[2 * index + 1],
+        }
+    }
+
+    /// Return an iterator over the triangles.
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle<T>> + '_ {
+        self.triangle_indices.chunks(3).map(move |indices| {
+            Triangle(
+                self.vertex(indices[0]),
+                self.vertex(indices[1]),
+                self.vertex(indices[2]),
+            )
+        })
+    }
+}
+
+/// Tessellate a polygon into triangles with the vertices of its rings, by clipping "ears":
+/// triangles formed by a vertex and its neighbours which contain no other vertex.
+///
+/// Holes are joined to the exterior by bridges first. This is a port of Mapbox's
+/// [earcut](https://github.com/mapbox/earcut), which is fast, and robust to the common problems
+/// of real-world data, such as repeated coordinates and rings which touch or intersect
+/// themselves, though the triangles don't cover such invalid polygons exactly. Unlike
+/// [`ConstrainedTriangulation`](../triangulation/trait.ConstrainedTriangulation.html), the
+/// triangles may be slivers, and the arithmetic isn't exact. The time to tessellate grows with
+/// the square of the number of vertices for complex shapes.
+pub trait Earcut<T: Float> {
+    /// Tessellate a geometry into triangles, returned as a flat buffer of vertices and the
+    /// indices of each triangle's vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::earcut::Earcut;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+    /// ];
+    ///
+    /// let earcut = polygon.earcut();
+    /// assert_eq!(
+    ///     earcut.vertices,
+    ///     vec![0., 0., 4., 0., 4., 4., 0., 4., 1., 1., 3., 1., 3., 3., 1., 3.]
+    /// );
+    /// // a square ring takes 8 triangles
+    /// assert_eq!(earcut.triangle_indices.len(), 8 * 3);
+    /// let area: f64 = earcut
+    ///     .triangles()
+    ///     .map(|triangle| {
+    ///         let (a, b, c) = (triangle.0, triangle.1, triangle.2);
+    ///         ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) / 2.
+    ///     })
+    ///     .sum();
+    /// assert_eq!(area, 12.);
+    /// ```
+    fn earcut(&self) -> EarcutTriangles<T>;
+}
+
+impl<T: Float> Earcut<T> for Polygon<T> {
+    fn earcut(&self) -> EarcutTriangles<T> {
+        let mut earcut = EarcutTriangles {
+            vertices: vec![],
+            triangle_indices: vec![],
+        };
+        add_polygon(&mut earcut, self);
+        earcut
+    }
+}
+
+impl<T: Float> Earcut<T> for MultiPolygon<T> {
+    fn earcut(&self) -> EarcutTriangles<T> {
+        let mut earcut = EarcutTriangles {
+            vertices: vec![],
+            triangle_indices: vec![],
+        };
+        for polygon in &self.0 {
+            add_polygon(&mut earcut, polygon);
+        }
+        earcut
+    }
+}
+
+fn add_polygon<T: Float>(earcut: &mut EarcutTriangles<T>, polygon: &Polygon<T>) {
+    let offset = earcut.vertices.len() / 2;
+    let mut rings = vec![];
+    let mut coords = vec![];
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        let start = coords.len();
+        coords.extend(open(ring).iter().copied());
+        rings.push(start..coords.len());
+    }
+    earcut
+        .vertices
+        .extend(coords.iter().flat_map(|coord| vec![coord.x, coord.y]));
+    let mut linked = Linked {
+        nodes: vec![],
+        triangles: vec![],
+    };
+    linked.tessellate(&coords, &rings);
+    earcut
+        .triangle_indices
+        .extend(linked.triangles.into_iter().map(|index| index + offset));
+}
+
+// The coordinates of a ring, without the closing one
+fn open<T: Float>(ring: &LineString<T>) -> &[Coordinate<T>] {
+    match ring.0.split_last() {
+        Some((last, rest)) if rest.first() == Some(last) => rest,
+        _ => &ring.0,
+    }
+}
+
+struct Node<T> {
+    // the index of the vertex
+    i: usize,
+    x: T,
+    y: T,
+    prev: usize,
+    next: usize,
+    steiner: bool,
+}
+
+// Doubly linked rings of vertices, as indices into the nodes
+struct Linked<T> {
+    nodes: Vec<Node<T>>,
+    triangles: Vec<usize>,
+}
+
+impl<T: Float> Linked<T> {
+    fn tessellate(&mut self, coords: &[Coordinate<T>], rings: &[std::ops::Range<usize>]) {
+        let outer = match self.linked_list(coords, rings[0].clone(), true) {
+            Some(outer) => outer,
+            None => return,
+        };
+        if self.next(outer) == self.prev(outer) {
+            return;
+        }
+        let outer = self.eliminate_holes(coords, &rings[1..], outer);
+        self.earcut_linked(Some(outer), 0);
+    }
+
+    fn prev(&self, p: usize) -> usize {
+        self.nodes[p].prev
+    }
+
+    fn next(&self, p: usize) -> usize {
+        self.nodes[p].next
+    }
+
+    // Link the vertices of a ring, in the given orientation
+    fn linked_list(
+        &mut self,
+        coords: &[Coordinate<T>],
+        range: std::ops::Range<usize>,
+        clockwise: bool,
+    ) -> Option<usize> {
+        let mut last = None;
+        let ring = &coords[range.clone()];
+        if clockwise == (signed_area(ring) > T::zero()) {
+            for i in range {
+                last = Some(self.insert_node(i, coords[i], last));
+            }
+        } else {
+            for i in range.rev() {
+                last = Some(self.insert_node(i, coords[i], last));
+            }
+        }
+        if let Some(node) = last {
+            if self.equals(node, self.next(node)) {
+                self.remove_node(node);
+                last = Some(self.next(node));
+            }
+        }
+        last
+    }
+
+    fn insert_node(&mut self, i: usize, coord: Coordinate<T>, last: Option<usize>) -> usize {
+        let p = self.nodes.len();
+        let (prev, next) = match last {
+            Some(last) => (last, self.next(last)),
+            None => (p, p),
+        };
+        self.nodes.push(Node {
+            i,
+            x: coord.x,
+            y: coord.y,
+            prev,
+            next,
+            steiner: false,
+        });
+        if let Some(last) = last {
+            self.nodes[next].prev = p;
+            self.nodes[last].next = p;
+        }
+        p
+    }
+
+    fn remove_node(&mut self, p: usize) {
+        let (prev, next) = (self.prev(p), self.next(p));
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+    }
+
+    fn equals(&self, a: usize, b: usize) -> bool {
+        self.nodes[a].x == self.nodes[b].x && self.nodes[a].y == self.nodes[b].y
+    }
+
+    // Twice the area of a triangle, negative if it's counter-clockwise
+    fn area(&self, p: usize, q: usize, r: usize) -> T {
+        let (p, q, r) = (&self.nodes[p], &self.nodes[q], &self.nodes[r]);
+        (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y)
+    }
+
+    // Remove repeated vertices, and those between collinear edges
+    fn filter_points(&mut self, start: usize, end: Option<usize>) -> usize {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+        loop {
+            let mut again = false;
+            let next = self.next(p);
+            if !self.nodes[p].steiner
+                && (self.equals(p, next) || self.area(self.prev(p), p, next) == T::zero())
+            {
+                self.remove_node(p);
+                p = self.prev(p);
+                end = p;
+                if p == self.next(p) {
+                    break;
+                }
+                again = true;
+            } else {
+                p = next;
+            }
+            if !again && p == end {
+                break;
+            }
+        }
+        end
+    }
+
+    fn earcut_linked(&mut self, ear: Option<usize>, pass: u8) {
+        let mut ear = match ear {
+            Some(ear) => ear,
+            None => return,
+        };
+        let mut stop = ear;
+        while self.prev(ear) != self.next(ear) {
+            let (prev, next) = (self.prev(ear), self.next(ear));
+            if self.is_ear(ear) {
+                self.triangles
+                    .extend(&[self.nodes[prev].i, self.nodes[ear].i, self.nodes[next].i]);
+                self.remove_node(ear);
+                // skipping the next vertex leads to fewer sliver triangles
+                ear = self.next(next);
+                stop = ear;
+                continue;
+            }
+            ear = next;
+            // if the whole ring was looped through without finding an ear
+            if ear == stop {
+                match pass {
+                    // try filtering points and slicing again
+                    0 => {
+                        let ear = self.filter_points(ear, None);
+                        self.earcut_linked(Some(ear), 1);
+                    }
+                    // cure self-intersections
+                    1 => {
+                        let ear = self.filter_points(ear, None);
+                        let ear = self.cure_local_intersections(ear);
+                        self.earcut_linked(Some(ear), 2);
+                    }
+                    // as a last resort, split the polygon in two
+                    _ => self.split_earcut(ear),
+                }
+                break;
+            }
+        }
+    }
+
+    fn is_ear(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.prev(ear), ear, self.next(ear));
+        // reflex, so can't be an ear
+        if self.area(a, b, c) >= T::zero() {
+            return false;
+        }
+        let (na, nb, nc) = (&self.nodes[a], &self.nodes[b], &self.nodes[c]);
+        let (x0, x1) = (na.x.min(nb.x).min(nc.x), na.x.max(nb.x).max(nc.x));
+        let (y0, y1) = (na.y.min(nb.y).min(nc.y), na.y.max(nb.y).max(nc.y));
+        // no vertex may be inside the triangle
+        let mut p = self.next(c);
+        while p != a {
+            let np = &self.nodes[p];
+            if np.x >= x0
+                && np.x <= x1
+                && np.y >= y0
+                && np.y <= y1
+                && point_in_triangle((na.x, na.y), (nb.x, nb.y), (nc.x, nc.y), (np.x, np.y))
+                && self.area(self.prev(p), p, self.next(p)) >= T::zero()
+            {
+                return false;
+            }
+            p = self.next(p);
+        }
+        true
+    }
+
+    // Clip the triangles of local self-intersections, where the edges before and after a vertex
+    // cross
+    fn cure_local_intersections(&mut self, start: usize) -> usize {
+        let mut start = start;
+        let mut p = start;
+        loop {
+            let a = self.prev(p);
+            let b = self.next(self.next(p));
+            if !self.equals(a, b)
+                && self.intersects(a, p, self.next(p), b)
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                self.triangles
+                    .extend(&[self.nodes[a].i, self.nodes[p].i, self.nodes[b].i]);
+                let next = self.next(p);
+                self.remove_node(p);
+                self.remove_node(next);
+                p = b;
+                start = b;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        self.filter_points(p, None)
+    }
+
+    // Split the polygon along a valid diagonal, and tessellate both parts
+    fn split_earcut(&mut self, start: usize) {
+        let mut a = start;
+        loop {
+            let mut b = self.next(self.next(a));
+            while b != self.prev(a) {
+                if self.nodes[a].i != self.nodes[b].i && self.is_valid_diagonal(a, b) {
+                    let c = self.split_polygon(a, b);
+                    let a = self.filter_points(a, Some(self.next(a)));
+                    let c = self.filter_points(c, Some(self.next(c)));
+                    self.earcut_linked(Some(a), 0);
+                    self.earcut_linked(Some(c), 0);
+                    return;
+                }
+                b = self.next(b);
+            }
+            a = self.next(a);
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    // Join the holes to the outer ring by bridges, from left to right
+    fn eliminate_holes(
+        &mut self,
+        coords: &[Coordinate<T>],
+        holes: &[std::ops::Range<usize>],
+        mut outer: usize,
+    ) -> usize {
+        let mut queue = vec![];
+        for hole in holes {
+            if let Some(list) = self.linked_list(coords, hole.clone(), false) {
+                if list == self.next(list) {
+                    self.nodes[list].steiner = true;
+                }
+                queue.push(self.leftmost(list));
+            }
+        }
+        queue.sort_by(|a, b| {
+            self.nodes[*a]
+                .x
+                .partial_cmp(&self.nodes[*b].x)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for hole in queue {
+            outer = self.eliminate_hole(hole, outer);
+        }
+        outer
+    }
+
+    fn eliminate_hole(&mut self, hole: usize, outer: usize) -> usize {
+        let bridge = match self.find_hole_bridge(hole, outer) {
+            Some(bridge) => bridge,
+            None => return outer,
+        };
+        let bridge_reverse = self.split_polygon(bridge, hole);
+        self.filter_points(bridge_reverse, Some(self.next(bridge_reverse)));
+        self.filter_points(bridge, Some(self.next(bridge)))
+    }
+
+    // Find a vertex of the outer ring which can be joined to the leftmost vertex of a hole,
+    // using David Eberly's algorithm
+    fn find_hole_bridge(&self, hole: usize, outer: usize) -> Option<usize> {
+        let (hx, hy) = (self.nodes[hole].x, self.nodes[hole].y);
+        let mut qx = T::neg_infinity();
+        let mut m = None;
+        // find the segment left of the hole point, along a ray towards the left, which is
+        // closest to it
+        let mut p = outer;
+        loop {
+            let (np, nn) = (&self.nodes[p], &self.nodes[self.next(p)]);
+            if hy <= np.y && hy >= nn.y && nn.y != np.y {
+                let x = np.x + (hy - np.y) * (nn.x - np.x) / (nn.y - np.y);
+                if x <= hx && x > qx {
+                    qx = x;
+                    m = Some(if np.x < nn.x { p } else { self.next(p) });
+                    if x == hx {
+                        // the hole touches the outer segment, so join its leftmost end
+                        return m;
+                    }
+                }
+            }
+            p = self.next(p);
+            if p == outer {
+                break;
+            }
+        }
+        let mut m = m?;
+        // look for vertices inside the triangle of the hole point, the intersection, and the
+        // segment's end, choosing the one with the least angle to the ray
+        let stop = m;
+        let (mx, my) = (self.nodes[m].x, self.nodes[m].y);
+        let mut tan_min = T::infinity();
+        let mut p = m;
+        loop {
+            let np = &self.nodes[p];
+            let (ax, cx) = if hy < my { (hx, qx) } else { (qx, hx) };
+            if hx >= np.x
+                && np.x >= mx
+                && hx != np.x
+                && point_in_triangle((ax, hy), (mx, my), (cx, hy), (np.x, np.y))
+            {
+                let tan = (hy - np.y).abs() / (hx - np.x);
+                if self.locally_inside(p, hole)
+                    && (tan < tan_min
+                        || (tan == tan_min
+                            && (np.x > self.nodes[m].x
+                                || (np.x == self.nodes[m].x && self.sector_contains_sector(m, p)))))
+                {
+                    m = p;
+                    tan_min = tan;
+                }
+            }
+            p = self.next(p);
+            if p == stop {
+                break;
+            }
+        }
+        Some(m)
+    }
+
+    // Whether the sector of `m` contains that of `p`
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        self.area(self.prev(m), m, self.prev(p)) < T::zero()
+            && self.area(self.next(p), m, self.next(m)) < T::zero()
+    }
+
+    fn leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+        loop {
+            let (np, nl) = (&self.nodes[p], &self.nodes[leftmost]);
+            if np.x < nl.x || (np.x == nl.x && np.y < nl.y) {
+                leftmost = p;
+            }
+            p = self.next(p);
+            if p == start {
+                break;
+            }
+        }
+        leftmost
+    }
+
+    // Whether a diagonal between two vertices lies inside the polygon, without crossing it
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let zero = T::zero();
+        self.nodes[self.next(a)].i != self.nodes[b].i
+            && self.nodes[self.prev(a)].i != self.nodes[b].i
+            && !self.intersects_polygon(a, b)
+            && (self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+                && self.middle_inside(a, b)
+                // and doesn't create sectors facing opposite ways
+                && (self.area(self.prev(a), a, self.prev(b)) != zero
+                    || self.area(a, self.prev(b), b) != zero)
+                // or is a diagonal without length between two convex vertices
+                || self.equals(a, b)
+                    && self.area(self.prev(a), a, self.next(a)) > zero
+                    && self.area(self.prev(b), b, self.next(b)) > zero)
+    }
+
+    // Whether the segments from `p1` to `q1` and from `p2` to `q2` intersect
+    fn intersects(&self, p1: usize, q1: usize, p2: usize, q2: usize) -> bool {
+        let o1 = sign(self.area(p1, q1, p2));
+        let o2 = sign(self.area(p1, q1, q2));
+        let o3 = sign(self.area(p2, q2, p1));
+        let o4 = sign(self.area(p2, q2, q1));
+        (o1 != o2 && o3 != o4)
+            || (o1 == 0 && self.on_segment(p1, p2, q1))
+            || (o2 == 0 && self.on_segment(p1, q2, q1))
+            || (o3 == 0 && self.on_segment(p2, p1, q2))
+            || (o4 == 0 && self.on_segment(p2, q1, q2))
+    }
+
+    // Whether `q` is within the bounding box of `p` and `r`, given that the three are collinear
+    fn on_segment(&self, p: usize, q: usize, r: usize) -> bool {
+        let (p, q, r) = (&self.nodes[p], &self.nodes[q], &self.nodes[r]);
+        q.x <= p.x.max(r.x) && q.x >= p.x.min(r.x) && q.y <= p.y.max(r.y) && q.y >= p.y.min(r.y)
+    }
+
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.nodes[a].i, self.nodes[b].i);
+        let mut p = a;
+        loop {
+            let next = self.next(p);
+            let (pi, ni) = (self.nodes[p].i, self.nodes[next].i);
+            if pi != ai && ni != ai && pi != bi && ni != bi && self.intersects(p, next, a, b) {
+                return true;
+            }
+            p = next;
+            if p == a {
+                return false;
+            }
+        }
+    }
+
+    // Whether a diagonal from `a` to `b` starts inside the polygon
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let zero = T::zero();
+        let (prev, next) = (self.prev(a), self.next(a));
+        if self.area(prev, a, next) < zero {
+            self.area(a, b, next) >= zero && self.area(a, prev, b) >= zero
+        } else {
+            self.area(a, b, prev) < zero || self.area(a, next, b) < zero
+        }
+    }
+
+    // Whether the middle of a diagonal is inside the polygon
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let two = T::one() + T::one();
+        let px = (self.nodes[a].x + self.nodes[b].x) / two;
+        let py = (self.nodes[a].y + self.nodes[b].y) / two;
+        let mut inside = false;
+        let mut p = a;
+        loop {
+            let (np, nn) = (&self.nodes[p], &self.nodes[self.next(p)]);
+            if (np.y > py) != (nn.y > py)
+                && nn.y != np.y
+                && px < (nn.x - np.x) * (py - np.y) / (nn.y - np.y) + np.x
+            {
+                inside = !inside;
+            }
+            p = self.next(p);
+            if p == a {
+                return inside;
+            }
+        }
+    }
+
+    // Join `a` and `b` by two diagonals in opposite directions, splitting the ring in two if
+    // they're on the same one, or merging their rings otherwise, and return the copy of `b`
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let a2 = self.nodes.len();
+        let b2 = a2 + 1;
+        let (an, bp) = (self.next(a), self.prev(b));
+        let copy = |node: &Node<T>| Node {
+            i: node.i,
+            x: node.x,
+            y: node.y,
+            prev: 0,
+            next: 0,
+            steiner: false,
+        };
+        let (na2, nb2) = (copy(&self.nodes[a]), copy(&self.nodes[b]));
+        self.nodes.push(na2);
+        self.nodes.push(nb2);
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[a2].next = an;
+        self.nodes[an].prev = a2;
+        self.nodes[b2].next = a2;
+        self.nodes[a2].prev = b2;
+        self.nodes[bp].next = b2;
+        self.nodes[b2].prev = bp;
+        b2
+    }
+}
+
+// Twice the signed area of a ring, positive if it's clockwise
+fn signed_area<T: Float>(ring: &[Coordinate<T>]) -> T {
+    let mut sum = T::zero();
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        sum = sum + (ring[j].x - ring[i].x) * (ring[i].y + ring[j].y);
+        j = i;
+    }
+    sum
+}
+
+fn point_in_triangle<T: Float>(a: (T, T), b: (T, T), c: (T, T), p: (T, T)) -> bool {
+    (c.0 - p.0) * (a.1 - p.1) >= (a.0 - p.0) * (c.1 - p.1)
+        && (a.0 - p.0) * (b.1 - p.1) >= (b.0 - p.0) * (a.1 - p.1)
+        && (b.0 - p.0) * (c.1 - p.1) >= (c.0 - p.0) * (b.1 - p.1)
+}
+
+fn sign<T: Float>(value: T) -> i8 {
+    if value > T::zero() {
+        1
+    } else if value < T::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::polygon;
+
+    fn area(earcut: &EarcutTriangles<f64>) -> f64 {
+        earcut
+            .triangles()
+            .map(|triangle| triangle.signed_area())
+            .sum()
+    }
+
+    #[test]
+    fn square() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let earcut = polygon.earcut();
+        assert_eq!(earcut.vertices, vec![0., 0., 1., 0., 1., 1., 0., 1.]);
+        assert_eq!(earcut.triangle_indices.len(), 6);
+        assert_eq!(area(&earcut), 1.);
+        // clockwise rings give the same triangles
+        let mut exterior = polygon.exterior().clone();
+        exterior.0.reverse();
+        let earcut = Polygon::new(exterior, vec![]).earcut();
+        assert_eq!(area(&earcut), 1.);
+        for triangle in earcut.triangles() {
+            assert!(triangle.signed_area() > 0.);
+        }
+    }
+
+    #[test]
+    fn comb() {
+        // teeth along the top, so most vertices aren't ears
+        let mut coords = vec![(0., 0.), (20., 0.)];
+        for i in (0..10).rev() {
+            let x = 2. * i as f64;
+            coords.extend(vec![(x + 2., 5.), (x + 1., 1.)]);
+        }
+        let polygon = Polygon::new(coords.into(), vec![]);
+        let earcut = polygon.earcut();
+        assert_eq!(earcut.triangle_indices.len(), 3 * (22 - 2));
+        assert_relative_eq!(area(&earcut), polygon.unsigned_area());
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.)],
+                [(x: 6., y: 6.), (x: 9., y: 6.), (x: 9., y: 9.), (x: 6., y: 9.)],
+                // touching the exterior
+                [(x: 5., y: 0.), (x: 6., y: 2.), (x: 7., y: 0.5)],
+            ],
+        ];
+        let earcut = polygon.earcut();
+        assert_eq!(earcut.vertices.len(), 2 * 15);
+        assert_relative_eq!(area(&earcut), polygon.unsigned_area());
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert!(empty.earcut().triangle_indices.is_empty());
+        let line = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let earcut = line.earcut();
+        assert_eq!(earcut.vertices.len(), 6);
+        assert!(earcut.triangle_indices.is_empty());
+        // no triangle is degenerate at repeated or collinear coordinates
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+        ];
+        let earcut = polygon.earcut();
+        for triangle in earcut.triangles() {
+            assert!(triangle.signed_area() > 0.);
+        }
+        assert_eq!(area(&earcut), 2.);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let triangle = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 3., y: 1.)];
+        let earcut = MultiPolygon(vec![square, triangle]).earcut();
+        assert_eq!(earcut.vertices.len(), 2 * 7);
+        assert_eq!(earcut.triangle_indices.len(), 9);
+        assert!(earcut.triangle_indices[6..].iter().all(|index| *index >= 4));
+        assert_eq!(area(&earcut), 2.);
+    }
+}
@@ -1,18 +1,25 @@
 use crate::algorithm::contains::{get_position, Contains, PositionPoint};
 use crate::algorithm::euclidean_length::EuclideanLength;
 use crate::algorithm::intersects::Intersects;
+use crate::algorithm::parts::{PartsSink, ToParts};
 use crate::algorithm::polygon_distance_fast_path::*;
 use crate::{
-    Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Triangle,
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use geo_types::private_utils::line_bounding_rect;
 use num_traits::float::FloatConst;
 use num_traits::{Bounded, Float, Signed};
+use std::borrow::Cow;
 
 use rstar::RTree;
 use rstar::RTreeNum;
 
 /// Returns the distance between two geometries.
-
+///
+/// This is implemented for every pair of geometry types, including `Geometry` and
+/// `GeometryCollection`. The distance is `0.0` if the geometries intersect, or if one lies
+/// inside a polygon of the other.
 pub trait EuclideanDistance<T, Rhs = Self> {
     /// Returns the distance between two geometries
     ///
@@ -24,6 +31,9 @@ pub trait EuclideanDistance<T, Rhs = Self> {
     ///
     /// The distance between a `Point` and an empty `LineString` is `0.0`
     ///
+    /// The distance to an [empty point](../../struct.Point.html#method.is_empty), or to a
+    /// collection with nothing in it, is `T::max_value()`, so it's never the nearest
+    ///
     /// # Examples
     ///
     /// ```
@@ -80,6 +90,9 @@ where
 {
     /// Minimum distance between two Points
     fn euclidean_distance(&self, p: &Point<T>) -> T {
+        if self.is_empty() || p.is_empty() {
+            return T::max_value();
+        }
         Line::new(self.0, p.0).euclidean_length()
     }
 }
@@ -224,6 +237,9 @@ where
 {
     /// Minimum distance from a Line to a Point
     fn euclidean_distance(&self, point: &Point<T>) -> T {
+        if point.is_empty() {
+            return T::max_value();
+        }
         ::geo_types::private_utils::point_line_euclidean_distance(*point, *self)
     }
 }
@@ -260,7 +276,7 @@ fn ring_contains_point<T>(poly: &Polygon<T>, p: Point<T>) -> bool
 where
     T: Float,
 {
-    match get_position(p, &poly.exterior()) {
+    match get_position(p, poly.exterior()) {
         PositionPoint::Inside => true,
         PositionPoint::OnBoundary | PositionPoint::Outside => false,
     }
@@ -304,7 +320,7 @@ where
             }
             mindist
         } else {
-            nearest_neighbour_distance(self, &other.exterior())
+            nearest_neighbour_distance(self, other.exterior())
         }
     }
 }
@@ -422,7 +438,7 @@ where
             // check each ring distance, returning the minimum
             let mut mindist: T = Float::max_value();
             for ring in self.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(&poly2.exterior(), ring))
+                mindist = mindist.min(nearest_neighbour_distance(poly2.exterior(), ring))
             }
             return mindist;
         } else if !poly2.interiors().is_empty()
@@ -430,13 +446,13 @@ where
         {
             let mut mindist: T = Float::max_value();
             for ring in poly2.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(&self.exterior(), ring))
+                mindist = mindist.min(nearest_neighbour_distance(self.exterior(), ring))
             }
             return mindist;
         }
         if poly2.is_convex() || !self.is_convex() {
             // fall back to R* nearest neighbour method
-            nearest_neighbour_distance(&self.exterior(), &poly2.exterior())
+            nearest_neighbour_distance(self.exterior(), poly2.exterior())
         } else {
             min_poly_dist(self, poly2)
         }
@@ -464,6 +480,305 @@ where
             .fold(T::max_value(), |accum, val| accum.min(val))
     }
 }
+/// The points, segments, and polygons making up a geometry, for calculating the distance
+/// between pairs of geometries without a more specific implementation
+struct DistanceParts<'a, T: Float> {
+    points: Vec<Point<T>>,
+    lines: Vec<Line<T>>,
+    polygons: Vec<Cow<'a, Polygon<T>>>,
+}
+
+impl<'a, T: Float> Default for DistanceParts<'a, T> {
+    fn default() -> Self {
+        DistanceParts {
+            points: vec![],
+            lines: vec![],
+            polygons: vec![],
+        }
+    }
+}
+
+impl<'a, T: Float> PartsSink<'a, T> for DistanceParts<'a, T> {
+    fn add_point(&mut self, coord: Coordinate<T>) {
+        if !Point(coord).is_empty() {
+            self.points.push(Point(coord));
+        }
+    }
+
+    fn add_line_string(&mut self, coords: &[Coordinate<T>]) {
+        if coords.len() == 1 {
+            self.points.push(Point(coords[0]));
+        }
+        self.lines
+            .extend(coords.windows(2).map(|pair| Line::new(pair[0], pair[1])));
+    }
+
+    fn add_polygon(&mut self, polygon: Cow<'a, Polygon<T>>) {
+        self.add_line_string(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.add_line_string(&interior.0);
+        }
+        self.polygons.push(polygon);
+    }
+}
+
+impl<'a, T: Float> DistanceParts<'a, T> {
+    // Whether any point or segment endpoint of `self` lies in (or on the boundary of) one of
+    // the polygons of `other`
+    fn is_covered_by(&self, other: &DistanceParts<T>) -> bool {
+        let vertices = self
+            .points
+            .iter()
+            .copied()
+            .chain(self.lines.iter().map(|line| line.start_point()));
+        vertices
+            .flat_map(|vertex| other.polygons.iter().map(move |polygon| (vertex, polygon)))
+            .any(|(vertex, polygon)| polygon_covers_point(polygon, vertex))
+    }
+
+    fn distance(&self, other: &DistanceParts<T>) -> T {
+        if self.is_covered_by(other) || other.is_covered_by(self) {
+            return T::zero();
+        }
+        let mut min_distance = T::max_value();
+        for point in &self.points {
+            for other_point in &other.points {
+                min_distance = min_distance.min(point.euclidean_distance(other_point));
+            }
+            for line in &other.lines {
+                min_distance = min_distance.min(line.euclidean_distance(point));
+            }
+        }
+        for line in &self.lines {
+            for point in &other.points {
+                min_distance = min_distance.min(line.euclidean_distance(point));
+            }
+        }
+        let other_rects: Vec<_> = other.lines.iter().map(|l| line_bounding_rect(*l)).collect();
+        for line in &self.lines {
+            let rect = line_bounding_rect(*line);
+            for (other_line, other_rect) in other.lines.iter().zip(other_rects.iter()) {
+                // the segments can't be closer than their bounding rectangles
+                if rect_distance(rect, *other_rect) >= min_distance {
+                    continue;
+                }
+                if line.intersects(other_line) {
+                    return T::zero();
+                }
+                min_distance = min_distance
+                    .min(line.euclidean_distance(&other_line.start_point()))
+                    .min(line.euclidean_distance(&other_line.end_point()))
+                    .min(other_line.euclidean_distance(&line.start_point()))
+                    .min(other_line.euclidean_distance(&line.end_point()));
+            }
+        }
+        min_distance
+    }
+}
+
+fn polygon_covers_point<T: Float>(polygon: &Polygon<T>, point: Point<T>) -> bool {
+    get_position(point, polygon.exterior()) != PositionPoint::Outside
+        && polygon
+            .interiors()
+            .iter()
+            .all(|ring| get_position(point, ring) != PositionPoint::Inside)
+}
+
+fn rect_distance<T: Float>(a: Rect<T>, b: Rect<T>) -> T {
+    let dx = (a.min().x - b.max().x)
+        .max(b.min().x - a.max().x)
+        .max(T::zero());
+    let dy = (a.min().y - b.max().y)
+        .max(b.min().y - a.max().y)
+        .max(T::zero());
+    dx.hypot(dy)
+}
+
+// Implement the distance between pairs of geometries by comparing all of their points and
+// segments, after checking whether one of them lies in a polygon of the other
+macro_rules! impl_euclidean_distance_by_parts {
+    ($from:ident => [$($to:ident),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $to<T>> for $from<T>
+            where
+                T: Float,
+            {
+                fn euclidean_distance(&self, other: &$to<T>) -> T {
+                    self.parts::<DistanceParts<_>>().distance(&other.parts())
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_by_parts!(Point => [Rect, Triangle]);
+impl_euclidean_distance_by_parts!(Line => [MultiPoint, MultiLineString, Rect, Triangle]);
+impl_euclidean_distance_by_parts!(
+    LineString => [MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    Polygon => [MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    MultiPoint => [Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    MultiLineString => [Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    MultiPolygon => [LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    Rect => [Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+impl_euclidean_distance_by_parts!(
+    Triangle => [Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+);
+
+// The distance from a geometry to a `Geometry` is the distance to its inner geometry
+macro_rules! impl_euclidean_distance_to_geometry {
+    ($($from:ident),*) => {
+        $(
+            impl<T> EuclideanDistance<T, Geometry<T>> for $from<T>
+            where
+                T: Float + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, geometry: &Geometry<T>) -> T {
+                    match geometry {
+                        Geometry::Point(g) => self.euclidean_distance(g),
+                        Geometry::Line(g) => self.euclidean_distance(g),
+                        Geometry::LineString(g) => self.euclidean_distance(g),
+                        Geometry::Polygon(g) => self.euclidean_distance(g),
+                        Geometry::MultiPoint(g) => self.euclidean_distance(g),
+                        Geometry::MultiLineString(g) => self.euclidean_distance(g),
+                        Geometry::MultiPolygon(g) => self.euclidean_distance(g),
+                        Geometry::GeometryCollection(g) => self.euclidean_distance(g),
+                        Geometry::Rect(g) => self.euclidean_distance(g),
+                        Geometry::Triangle(g) => self.euclidean_distance(g),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_to_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection
+);
+
+// The distance from a `Geometry` is the distance from its inner geometry
+macro_rules! impl_euclidean_distance_from_geometry {
+    ($($to:ident),*) => {
+        $(
+            impl<T> EuclideanDistance<T, $to<T>> for Geometry<T>
+            where
+                T: Float + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$to<T>) -> T {
+                    match self {
+                        Geometry::Point(g) => g.euclidean_distance(other),
+                        Geometry::Line(g) => g.euclidean_distance(other),
+                        Geometry::LineString(g) => g.euclidean_distance(other),
+                        Geometry::Polygon(g) => g.euclidean_distance(other),
+                        Geometry::MultiPoint(g) => g.euclidean_distance(other),
+                        Geometry::MultiLineString(g) => g.euclidean_distance(other),
+                        Geometry::MultiPolygon(g) => g.euclidean_distance(other),
+                        Geometry::GeometryCollection(g) => g.euclidean_distance(other),
+                        Geometry::Rect(g) => g.euclidean_distance(other),
+                        Geometry::Triangle(g) => g.euclidean_distance(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_from_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+// The distance between a `GeometryCollection` and another geometry is the minimum distance of
+// its members, stopping early if one of them is at distance zero
+fn min_member_distance<'a, T, I, F>(members: I, distance: F) -> T
+where
+    T: Float + 'a,
+    I: IntoIterator<Item = &'a Geometry<T>>,
+    F: Fn(&Geometry<T>) -> T,
+{
+    let mut min_distance = T::max_value();
+    for member in members {
+        min_distance = min_distance.min(distance(member));
+        if min_distance == T::zero() {
+            break;
+        }
+    }
+    min_distance
+}
+
+macro_rules! impl_euclidean_distance_geometry_collection {
+    ($($other:ident),*) => {
+        $(
+            impl<T> EuclideanDistance<T, $other<T>> for GeometryCollection<T>
+            where
+                T: Float + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$other<T>) -> T {
+                    min_member_distance(&self.0, |member| member.euclidean_distance(other))
+                }
+            }
+
+            impl<T> EuclideanDistance<T, GeometryCollection<T>> for $other<T>
+            where
+                T: Float + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &GeometryCollection<T>) -> T {
+                    min_member_distance(&other.0, |member| self.euclidean_distance(member))
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_geometry_collection!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle
+);
+
+impl<T> EuclideanDistance<T, GeometryCollection<T>> for GeometryCollection<T>
+where
+    T: Float + FloatConst + Signed + RTreeNum,
+{
+    fn euclidean_distance(&self, other: &GeometryCollection<T>) -> T {
+        min_member_distance(&self.0, |member| member.euclidean_distance(other))
+    }
+}
+
 /// Uses an R* tree and nearest-neighbour lookups to calculate minimum distances
 // This is somewhat slow and memory-inefficient, but certainly better than quadratic time
 pub fn nearest_neighbour_distance<T>(geom1: &LineString<T>, geom2: &LineString<T>) -> T
@@ -490,11 +805,15 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod test {
     use super::*;
     use crate::algorithm::convexhull::ConvexHull;
     use crate::algorithm::euclidean_distance::EuclideanDistance;
-    use crate::{Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+    use crate::{
+        Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+        Point, Polygon, Rect, Triangle,
+    };
     use geo_types::private_utils::line_segment_distance;
 
     #[test]
@@ -809,7 +1128,7 @@ mod test {
     #[test]
     // test edge-vertex minimum distance
     fn test_minimum_polygon_distance() {
-        let points_raw = vec![
+        let points_raw = [
             (126., 232.),
             (126., 212.),
             (112., 202.),
@@ -825,7 +1144,7 @@ mod test {
             .collect::<Vec<_>>();
         let poly1 = Polygon::new(LineString::from(points), vec![]);
 
-        let points_raw_2 = vec![
+        let points_raw_2 = [
             (188., 231.),
             (189., 207.),
             (174., 196.),
@@ -840,14 +1159,14 @@ mod test {
             .collect::<Vec<_>>();
         let poly2 = Polygon::new(LineString::from(points2), vec![]);
         let dist = min_poly_dist(&poly1.convex_hull(), &poly2.convex_hull());
-        let dist2 = nearest_neighbour_distance(&poly1.exterior(), &poly2.exterior());
+        let dist2 = nearest_neighbour_distance(poly1.exterior(), poly2.exterior());
         assert_relative_eq!(dist, 21.0);
         assert_relative_eq!(dist2, 21.0);
     }
     #[test]
     // test vertex-vertex minimum distance
     fn test_minimum_polygon_distance_2() {
-        let points_raw = vec![
+        let points_raw = [
             (118., 200.),
             (153., 179.),
             (106., 155.),
@@ -860,7 +1179,7 @@ mod test {
             .collect::<Vec<_>>();
         let poly1 = Polygon::new(LineString::from(points), vec![]);
 
-        let points_raw_2 = vec![
+        let points_raw_2 = [
             (242., 186.),
             (260., 146.),
             (182., 175.),
@@ -873,14 +1192,14 @@ mod test {
             .collect::<Vec<_>>();
         let poly2 = Polygon::new(LineString::from(points2), vec![]);
         let dist = min_poly_dist(&poly1.convex_hull(), &poly2.convex_hull());
-        let dist2 = nearest_neighbour_distance(&poly1.exterior(), &poly2.exterior());
+        let dist2 = nearest_neighbour_distance(poly1.exterior(), poly2.exterior());
         assert_relative_eq!(dist, 29.274562336608895);
         assert_relative_eq!(dist2, 29.274562336608895);
     }
     #[test]
     // test edge-edge minimum distance
     fn test_minimum_polygon_distance_3() {
-        let points_raw = vec![
+        let points_raw = [
             (182., 182.),
             (182., 168.),
             (138., 160.),
@@ -893,7 +1212,7 @@ mod test {
             .collect::<Vec<_>>();
         let poly1 = Polygon::new(LineString::from(points), vec![]);
 
-        let points_raw_2 = vec![
+        let points_raw_2 = [
             (232., 196.),
             (234., 150.),
             (194., 165.),
@@ -906,7 +1225,7 @@ mod test {
             .collect::<Vec<_>>();
         let poly2 = Polygon::new(LineString::from(points2), vec![]);
         let dist = min_poly_dist(&poly1.convex_hull(), &poly2.convex_hull());
-        let dist2 = nearest_neighbour_distance(&poly1.exterior(), &poly2.exterior());
+        let dist2 = nearest_neighbour_distance(poly1.exterior(), poly2.exterior());
         assert_relative_eq!(dist, 12.0);
         assert_relative_eq!(dist2, 12.0);
     }
@@ -1015,4 +1334,113 @@ mod test {
         let point = Point::new(1.0, 0.5);
         assert_relative_eq!(triangle.euclidean_distance(&point), 0.0);
     }
+
+    #[test]
+    fn point_rect_distance() {
+        let rect = Rect::new((0., 0.), (2., 2.));
+        assert_relative_eq!(Point::new(1., 1.).euclidean_distance(&rect), 0.);
+        assert_relative_eq!(Point::new(5., 6.).euclidean_distance(&rect), 5.);
+        assert_relative_eq!(rect.euclidean_distance(&Point::new(1., 3.)), 1.);
+    }
+
+    #[test]
+    fn multipoint_multipoint_distance() {
+        let a = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 10.)]);
+        let b = MultiPoint(vec![Point::new(3., 4.), Point::new(20., 20.)]);
+        assert_relative_eq!(a.euclidean_distance(&b), 5.);
+    }
+
+    #[test]
+    fn crossing_segments_distance() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        // crosses an edge, without a vertex inside the triangle
+        let mls = MultiLineString(vec![LineString::from(vec![(3., -1.), (3., 5.)])]);
+        assert_relative_eq!(triangle.euclidean_distance(&mls), 0.);
+        let mls = MultiLineString(vec![LineString::from(vec![(5., 0.), (5., 5.)])]);
+        assert_relative_eq!(triangle.euclidean_distance(&mls), 1.);
+        assert_relative_eq!(mls.euclidean_distance(&triangle), 1.);
+    }
+
+    #[test]
+    fn containment_distance() {
+        let outer = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (8., 2.),
+                (8., 8.),
+                (2., 8.),
+                (2., 2.),
+            ])],
+        );
+        // inside the hole
+        let rect = Rect::new((4., 4.), (5., 5.));
+        assert_relative_eq!(outer.euclidean_distance(&rect), 2.);
+        // inside the polygon itself
+        let rect = Rect::new((0.5, 0.5), (1., 1.));
+        assert_relative_eq!(rect.euclidean_distance(&outer), 0.);
+        let points = MultiPoint(vec![Point::new(5., 5.), Point::new(9., 5.)]);
+        assert_relative_eq!(outer.euclidean_distance(&points), 0.);
+    }
+
+    #[test]
+    fn matches_specialized_implementation() {
+        let poly1 = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]),
+            vec![],
+        );
+        let poly2 = Polygon::new(
+            LineString::from(vec![(5., 1.), (7., 3.), (6., 6.), (5., 1.)]),
+            vec![],
+        );
+        let multi = MultiPolygon(vec![poly2.clone()]);
+        assert_relative_eq!(
+            poly1.euclidean_distance(&multi),
+            poly1.euclidean_distance(&poly2)
+        );
+    }
+
+    #[test]
+    fn geometry_collection_distance() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::Line(Line::from([(0., 3.), (4., 3.)])),
+        ]);
+        let point = Point::new(2., 0.);
+        assert_relative_eq!(collection.euclidean_distance(&point), 3.);
+        assert_relative_eq!(point.euclidean_distance(&collection), 3.);
+        let geometry = Geometry::Rect(Rect::new((9., 9.), (12., 12.)));
+        assert_relative_eq!(collection.euclidean_distance(&geometry), 0.);
+        assert_relative_eq!(geometry.euclidean_distance(&collection), 0.);
+        assert_relative_eq!(
+            Geometry::Point(point).euclidean_distance(&Geometry::GeometryCollection(collection)),
+            3.
+        );
+        let empty = GeometryCollection::<f64>(vec![]);
+        assert_eq!(empty.euclidean_distance(&point), f64::MAX);
+    }
+
+    #[test]
+    fn empty_point_distance() {
+        let empty = Point::<f64>::empty();
+        let point = Point::new(1., 1.);
+        let line = Line::from([(0., 0.), (4., 0.)]);
+        let polygon: Polygon<f64> = Rect::new((0., 0.), (4., 4.)).into();
+        assert_eq!(empty.euclidean_distance(&point), f64::MAX);
+        assert_eq!(point.euclidean_distance(&empty), f64::MAX);
+        assert_eq!(empty.euclidean_distance(&line), f64::MAX);
+        assert_eq!(line.euclidean_distance(&empty), f64::MAX);
+        assert_eq!(empty.euclidean_distance(&polygon), f64::MAX);
+        assert_eq!(empty.euclidean_distance(&empty), f64::MAX);
+
+        // an empty point in a collection doesn't hide the others
+        let points = MultiPoint(vec![empty, Point::new(4., 5.)]);
+        assert_relative_eq!(point.euclidean_distance(&points), 5.);
+        let collection = GeometryCollection(vec![Geometry::Point(empty), Geometry::Line(line)]);
+        assert_relative_eq!(collection.euclidean_distance(&point), 1.);
+        assert_relative_eq!(
+            Geometry::GeometryCollection(collection).euclidean_distance(&Geometry::Point(empty)),
+            f64::MAX
+        );
+    }
 }
@@ -4,7 +4,6 @@ use std::iter::Sum;
 use crate::{Line, LineString, MultiLineString};
 
 /// Calculation of the length
-
 pub trait EuclideanLength<T, RHS = Self> {
     /// Calculation of the length of a Line
     ///
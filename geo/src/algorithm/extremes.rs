@@ -1,5 +1,6 @@
 use crate::algorithm::convexhull::ConvexHull;
-use crate::{ExtremePoint, Extremes};
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{CoordinateType, Extreme, ExtremePoint, Extremes, VertexExtremes};
 use crate::{MultiPoint, MultiPolygon, Point, Polygon};
 use num_traits::{Float, Signed};
 
@@ -54,7 +55,7 @@ where
     if !polygon.is_convex() {
         return Err(());
     }
-    let directions = vec![
+    let directions = [
         Point::new(T::zero(), -T::one()),
         Point::new(T::one(), T::zero()),
         Point::new(T::zero(), T::one()),
@@ -108,6 +109,7 @@ pub trait ExtremeIndices<T: Float + Signed> {
     /// assert_eq!(extremes.ymax, 2);
     /// assert_eq!(extremes.xmin, 3);
     /// ```
+    #[allow(clippy::result_unit_err)]
     fn extreme_indices(&self) -> Result<Extremes, ()>;
 }
 
@@ -180,10 +182,70 @@ where
     }
 }
 
+pub trait ExtremeVertices<'a, T: CoordinateType> {
+    /// Find the vertices of any geometry with the minimum and maximum `x` and `y` coordinates,
+    /// along with their indices among the geometry's coordinates, as `coords_iter` yields them
+    ///
+    /// Unlike `ExtremeIndices`, the geometry needn't be convex. Where several vertices share an
+    /// extreme coordinate, the first is returned; an empty geometry has no extremes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::extremes::ExtremeVertices;
+    /// use geo::{polygon, Coordinate};
+    ///
+    /// // a concave "L" shape
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 1., y: 1.), (x: 1., y: 3.), (x: 0., y: 3.)
+    /// ];
+    /// let extremes = polygon.extremes().unwrap();
+    ///
+    /// assert_eq!(extremes.x_max.index, 1);
+    /// assert_eq!(extremes.y_max.index, 4);
+    /// assert_eq!(extremes.y_max.coord, Coordinate { x: 1., y: 3. });
+    /// ```
+    fn extremes(&'a self) -> Option<VertexExtremes<T>>;
+}
+
+impl<'a, T, G> ExtremeVertices<'a, T> for G
+where
+    T: CoordinateType,
+    G: CoordsIter<'a, T>,
+{
+    fn extremes(&'a self) -> Option<VertexExtremes<T>> {
+        let mut coords = self.coords_iter().enumerate();
+        let (index, coord) = coords.next()?;
+        let first = Extreme { index, coord };
+        let mut extremes = VertexExtremes {
+            x_min: first,
+            y_min: first,
+            x_max: first,
+            y_max: first,
+        };
+        for (index, coord) in coords {
+            let extreme = Extreme { index, coord };
+            if coord.x < extremes.x_min.coord.x {
+                extremes.x_min = extreme;
+            }
+            if coord.y < extremes.y_min.coord.y {
+                extremes.y_min = extreme;
+            }
+            if coord.x > extremes.x_max.coord.x {
+                extremes.x_max = extreme;
+            }
+            if coord.y > extremes.y_max.coord.y {
+                extremes.y_max = extreme;
+            }
+        }
+        Some(extremes)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{point, polygon};
+    use crate::{line_string, point, polygon, Coordinate, Geometry, GeometryCollection};
 
     #[test]
     fn test_polygon_extreme_x() {
@@ -277,4 +339,52 @@ mod test {
         let correct = point!(x: 0.0, y: 1.0);
         assert_eq!(extremes.xmin, correct);
     }
+
+    #[test]
+    fn test_extreme_vertices() {
+        let polygon = polygon!(
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)],
+            interiors: [[(x: 2, y: 2), (x: 3, y: 2), (x: 3, y: 3)]],
+        );
+        let extremes = polygon.extremes().unwrap();
+        // ties go to the first vertex, the closing one is never chosen over the opening one
+        assert_eq!(
+            extremes,
+            VertexExtremes {
+                x_min: Extreme {
+                    index: 0,
+                    coord: Coordinate { x: 0, y: 0 }
+                },
+                y_min: Extreme {
+                    index: 0,
+                    coord: Coordinate { x: 0, y: 0 }
+                },
+                x_max: Extreme {
+                    index: 1,
+                    coord: Coordinate { x: 10, y: 0 }
+                },
+                y_max: Extreme {
+                    index: 2,
+                    coord: Coordinate { x: 10, y: 10 }
+                },
+            }
+        );
+
+        // indices count through the coordinates of every member
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 1., y: 1.)),
+            Geometry::LineString(line_string![(x: 0., y: 2.), (x: 3., y: -1.)]),
+        ]);
+        let extremes = collection.extremes().unwrap();
+        assert_eq!(extremes.x_min.index, 1);
+        assert_eq!(extremes.y_min.index, 2);
+        assert_eq!(extremes.x_max.index, 2);
+        assert_eq!(extremes.y_max.index, 1);
+
+        assert_eq!(
+            line_string![(x: 4., y: 5.)].extremes().unwrap().x_max.index,
+            0
+        );
+        assert!(GeometryCollection::<f64>(vec![]).extremes().is_none());
+    }
 }
@@ -34,45 +34,28 @@ where
     T: Float + FromPrimitive,
 {
     fn frechet_distance(&self, ls: &LineString<T>) -> T {
-        if self.num_coords() != 0 && ls.num_coords() != 0 {
-            let mut data = Data {
-                cache: vec![vec![T::nan(); ls.num_coords()]; self.num_coords()],
-                ls_a: self,
-                ls_b: ls,
-            };
-            data.compute(self.num_coords() - 1, ls.num_coords() - 1)
-        } else {
-            T::zero()
+        if self.num_coords() == 0 || ls.num_coords() == 0 {
+            return T::zero();
         }
-    }
-}
-
-struct Data<'a, T>
-where
-    T: Float + FromPrimitive,
-{
-    cache: Vec<Vec<T>>,
-    ls_a: &'a LineString<T>,
-    ls_b: &'a LineString<T>,
-}
-
-impl<'a, T> Data<'a, T>
-where
-    T: Float + FromPrimitive,
-{
-    fn compute(&mut self, i: usize, j: usize) -> T {
-        if self.cache[i][j].is_nan() {
-            let eucl = Point::from(self.ls_a[i]).euclidean_distance(&Point::from(self.ls_b[j]));
-            self.cache[i][j] = match (i, j) {
-                (0, 0) => eucl,
-                (_, 0) => self.compute(i - 1, 0).max(eucl),
-                (0, _) => self.compute(0, j - 1).max(eucl),
-                (_, _) => ((self.compute(i - 1, j).min(self.compute(i - 1, j - 1)))
-                    .min(self.compute(i, j - 1)))
-                .max(eucl),
-            };
+        // The coupling distances of the previous coordinate of `self` to each coordinate of `ls`,
+        // overwritten in place with those of the current one, so that long lines need neither
+        // deep recursion nor a full table.
+        let mut row = vec![T::zero(); ls.num_coords()];
+        for (i, a) in self.0.iter().enumerate() {
+            let mut diagonal = T::zero();
+            for (j, b) in ls.0.iter().enumerate() {
+                let eucl = Point(*a).euclidean_distance(&Point(*b));
+                let above = row[j];
+                row[j] = match (i, j) {
+                    (0, 0) => eucl,
+                    (0, _) => row[j - 1].max(eucl),
+                    (_, 0) => above.max(eucl),
+                    (_, _) => above.min(diagonal).min(row[j - 1]).max(eucl),
+                };
+                diagonal = above;
+            }
         }
-        self.cache[i][j]
+        row[ls.num_coords() - 1]
     }
 }
 
@@ -119,4 +102,13 @@ mod test {
         let ls_b = LineString::from(vec![(2., 2.), (0., 1.), (2., 4.)]);
         assert_relative_eq!(2., ls_a.frechet_distance(&ls_b));
     }
+
+    #[test]
+    fn long_linestrings() {
+        // too long for the recursion the distance used to be computed with
+        let ls_a: LineString<f64> = (0..19_901).map(|i| (i as f64, 0.)).collect();
+        let ls_b: LineString<f64> = (0..200).map(|i| (i as f64 * 100., 1.)).collect();
+        assert_relative_eq!(50f64.hypot(1.), ls_a.frechet_distance(&ls_b));
+        assert_relative_eq!(50f64.hypot(1.), ls_b.frechet_distance(&ls_a));
+    }
 }
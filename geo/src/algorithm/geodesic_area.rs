@@ -0,0 +1,286 @@
+use crate::{
+    CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+use num_traits::Float;
+
+/// Calculate the geodesic area of a geometry on the WGS84 ellipsoid, treating `x` as longitude
+/// and `y` as latitude, in degrees.
+///
+/// Unlike [`ChamberlainDuquetteArea`](../chamberlain_duquette_area/trait.ChamberlainDuquetteArea.html),
+/// which uses a sphere, this is accurate to round-off, using the method of Karney (2013).
+///
+/// # Units
+///
+/// - return value: meters²
+///
+/// # References
+///
+/// * Charles F. F. Karney, "Algorithms for geodesics", J. Geodesy 87, 43–55 (2013),
+///   <https://doi.org/10.1007/s00190-012-0578-z>
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, prelude::*};
+///
+/// // The O2 in London
+/// let mut p = polygon![
+///     (x: 0.00388383, y: 51.501574),
+///     (x: 0.00538587, y: 51.502278),
+///     (x: 0.00553607, y: 51.503299),
+///     (x: 0.00467777, y: 51.504181),
+///     (x: 0.00327229, y: 51.504435),
+///     (x: 0.00187754, y: 51.504168),
+///     (x: 0.00087976, y: 51.503380),
+///     (x: 0.00107288, y: 51.502324),
+///     (x: 0.00185608, y: 51.501770),
+///     (x: 0.00388383, y: 51.501574),
+/// ];
+///
+/// let area: f64 = p.geodesic_area();
+/// assert_eq!(area.round(), 78_596.); // 78,596 meters²
+///
+/// p.exterior_mut(|line_string| line_string.0.reverse());
+/// let signed_area: f64 = p.geodesic_area_signed();
+/// assert_eq!(signed_area.round(), -78_596.);
+/// ```
+pub trait GeodesicArea<T>
+where
+    T: Float + CoordinateType,
+{
+    /// The area, which is never negative
+    fn geodesic_area(&self) -> T;
+
+    /// The area, which is negative for a `Polygon` whose exterior is wound clockwise. Holes are
+    /// always subtracted, whatever their winding order.
+    fn geodesic_area_signed(&self) -> T;
+}
+
+// Signed area of a ring, positive if it's wound counter-clockwise
+fn ring_area<T>(geoid: &Geodesic, ring: &LineString<T>) -> T
+where
+    T: Float + CoordinateType,
+{
+    let mut coords = &ring.0[..];
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords = &coords[..coords.len() - 1];
+    }
+    if coords.len() < 3 {
+        return T::zero();
+    }
+    let mut polygon_area = PolygonArea::new(geoid, Winding::CounterClockwise);
+    for coord in coords {
+        polygon_area.add_point(
+            coord.y.to_f64().unwrap_or_else(f64::nan),
+            coord.x.to_f64().unwrap_or_else(f64::nan),
+        );
+    }
+    let (_perimeter, area, _count) = polygon_area.compute(true);
+    T::from(area).unwrap_or_else(T::nan)
+}
+
+impl<T> GeodesicArea<T> for Polygon<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        let geoid = Geodesic::wgs84();
+        let exterior = ring_area(&geoid, self.exterior());
+        let area = self.interiors().iter().fold(exterior.abs(), |total, next| {
+            total - ring_area(&geoid, next).abs()
+        });
+        if exterior < T::zero() {
+            -area
+        } else {
+            area
+        }
+    }
+}
+
+impl<T> GeodesicArea<T> for MultiPolygon<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.geodesic_area())
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.geodesic_area_signed())
+    }
+}
+
+impl<T> GeodesicArea<T> for Rect<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        self.geodesic_area_signed()
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        Polygon::from(*self).geodesic_area_signed()
+    }
+}
+
+impl<T> GeodesicArea<T> for Triangle<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        ring_area(&Geodesic::wgs84(), Polygon::from(*self).exterior())
+    }
+}
+
+macro_rules! zero_geodesic_area_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T> GeodesicArea<T> for $type<T>
+            where
+                T: Float + CoordinateType,
+            {
+                fn geodesic_area(&self) -> T {
+                    T::zero()
+                }
+
+                fn geodesic_area_signed(&self) -> T {
+                    T::zero()
+                }
+            }
+        )*
+    };
+}
+
+zero_geodesic_area_impl!(Point, Line, LineString, MultiPoint, MultiLineString);
+
+impl<T> GeodesicArea<T> for Geometry<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        match self {
+            Geometry::Point(g) => g.geodesic_area(),
+            Geometry::Line(g) => g.geodesic_area(),
+            Geometry::LineString(g) => g.geodesic_area(),
+            Geometry::Polygon(g) => g.geodesic_area(),
+            Geometry::MultiPoint(g) => g.geodesic_area(),
+            Geometry::MultiLineString(g) => g.geodesic_area(),
+            Geometry::MultiPolygon(g) => g.geodesic_area(),
+            Geometry::GeometryCollection(g) => g.geodesic_area(),
+            Geometry::Rect(g) => g.geodesic_area(),
+            Geometry::Triangle(g) => g.geodesic_area(),
+        }
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        match self {
+            Geometry::Point(g) => g.geodesic_area_signed(),
+            Geometry::Line(g) => g.geodesic_area_signed(),
+            Geometry::LineString(g) => g.geodesic_area_signed(),
+            Geometry::Polygon(g) => g.geodesic_area_signed(),
+            Geometry::MultiPoint(g) => g.geodesic_area_signed(),
+            Geometry::MultiLineString(g) => g.geodesic_area_signed(),
+            Geometry::MultiPolygon(g) => g.geodesic_area_signed(),
+            Geometry::GeometryCollection(g) => g.geodesic_area_signed(),
+            Geometry::Rect(g) => g.geodesic_area_signed(),
+            Geometry::Triangle(g) => g.geodesic_area_signed(),
+        }
+    }
+}
+
+impl<T> GeodesicArea<T> for GeometryCollection<T>
+where
+    T: Float + CoordinateType,
+{
+    fn geodesic_area(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.geodesic_area())
+    }
+
+    fn geodesic_area_signed(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, next| total + next.geodesic_area_signed())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn one_degree_square() {
+        // from GeographicLib's Planimeter for (0,0), (0,1), (1,1), (1,0)
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(poly.geodesic_area(), 12308778361.469, max_relative = 1e-9);
+        assert_relative_eq!(
+            Rect::new((0., 0.), (1., 1.)).geodesic_area(),
+            poly.geodesic_area()
+        );
+    }
+
+    #[test]
+    fn winding_and_holes() {
+        let mut poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.)
+            ],
+            interiors: [
+                [
+                    (x: 1., y: 1.),
+                    (x: 1., y: 2.),
+                    (x: 2., y: 2.),
+                    (x: 2., y: 1.),
+                    (x: 1., y: 1.),
+                ],
+            ],
+        ];
+        let hole = polygon![
+            (x: 1., y: 1.),
+            (x: 2., y: 1.),
+            (x: 2., y: 2.),
+            (x: 1., y: 2.),
+            (x: 1., y: 1.),
+        ];
+        let exterior = Polygon::new(poly.exterior().clone(), vec![]);
+        let area = exterior.geodesic_area() - hole.geodesic_area();
+        assert_relative_eq!(poly.geodesic_area_signed(), area, max_relative = 1e-12);
+
+        poly.exterior_mut(|line_string| line_string.0.reverse());
+        assert_relative_eq!(poly.geodesic_area_signed(), -area, max_relative = 1e-12);
+        assert_relative_eq!(poly.geodesic_area(), area, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn degenerate() {
+        let poly = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(poly.geodesic_area(), 0.);
+        assert_eq!(Point::new(1., 1.).geodesic_area(), 0.);
+    }
+}
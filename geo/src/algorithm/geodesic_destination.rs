@@ -0,0 +1,73 @@
+use crate::Point;
+use geographiclib_rs::{DirectGeodesic, Geodesic};
+use num_traits::Float;
+
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction,
+/// on the WGS84 ellipsoid
+pub trait GeodesicDestination<T: Float> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction,
+    /// using the method of [Karney (2013)] on the WGS84 ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, where North is 0° and East is 90°
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::prelude::*;
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.geodesic_destination(45., 10000.);
+    /// assert_relative_eq!(p_1.geodesic_distance(&p_2), 10000., epsilon = 1.0e-6);
+    /// ```
+    ///
+    /// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+    fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl<T> GeodesicDestination<T> for Point<T>
+where
+    T: Float,
+{
+    fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T> {
+        let to_f64 = |value: T| value.to_f64().unwrap_or_else(f64::nan);
+        let from_f64 = |value: f64| T::from(value).unwrap_or_else(T::nan);
+        let (lat, lng): (f64, f64) = Geodesic::wgs84().direct(
+            to_f64(self.y()),
+            to_f64(self.x()),
+            to_f64(bearing),
+            to_f64(distance),
+        );
+        Point::new(from_f64(lng), from_f64(lat))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+    use geographiclib_rs::InverseGeodesic;
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(45., 10000.);
+        assert_relative_eq!(p_1.geodesic_distance(&p_2), 10000., epsilon = 1.0e-6);
+        let (_, azimuth, _, _): (f64, f64, f64, f64) =
+            Geodesic::wgs84().inverse(p_1.y(), p_1.x(), p_2.y(), p_2.x());
+        assert_relative_eq!(azimuth, 45., epsilon = 1.0e-10);
+    }
+
+    #[test]
+    fn direct_and_north() {
+        let p_1 = Point::<f64>::new(0., 0.);
+        let p_2 = p_1.geodesic_destination(0., 1_000_000.);
+        assert_relative_eq!(p_2.x(), 0., epsilon = 1.0e-10);
+        assert!(p_2.y() > 9. && p_2.y() < 9.1);
+    }
+}
@@ -0,0 +1,763 @@
+// The inverse problem is solved as in GeographicLib's `Geodesic::GenInverse`, with the series
+// in the third flattening to sixth order:
+//
+// - C. F. F. Karney, Algorithms for geodesics, J. Geodesy 87, 43–55 (2013)
+// - https://geographiclib.sourceforge.io/C++/doc/geodesic.html
+//
+// Only `core` and `num_traits::Float` are used, so that with `num-traits`' `libm` feature this
+// needs no `std`.
+
+use crate::{Point, EQUATORIAL_EARTH_RADIUS};
+use num_traits::Float;
+
+/// Determine the distance between two geometries on the WGS84 ellipsoid, using the method of
+/// [Karney (2013)].
+///
+/// Unlike [`VincentyDistance`](../vincenty_distance/trait.VincentyDistance.html), this always
+/// converges, even for nearly antipodal points, and is accurate to round-off (a few nanometers
+/// for `f64`). It only needs `num_traits::Float`, so unlike the geodesic area and destination, it
+/// doesn't depend on the `geographiclib` feature.
+///
+/// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+pub trait GeodesicDistance<T, Rhs = Self> {
+    /// Determine the distance between two geometries on the WGS84 ellipsoid, using the method
+    /// of [Karney (2013)].
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::Point;
+    ///
+    /// // New York City
+    /// let p1 = Point::<f64>::from((-74.006, 40.7128));
+    /// // London
+    /// let p2 = Point::<f64>::from((-0.1278, 51.5074));
+    ///
+    /// let distance = p1.geodesic_distance(&p2);
+    ///
+    /// assert_eq!(
+    ///     5_585_234., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    ///
+    /// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+    fn geodesic_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> GeodesicDistance<T, Point<T>> for Point<T>
+where
+    T: Float,
+{
+    fn geodesic_distance(&self, rhs: &Point<T>) -> T {
+        Ellipsoid::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x())
+    }
+}
+
+// The WGS84 flattening, exactly as defined, rather than derived from the rounded polar radius
+const WGS84_FLATTENING: f64 = 1. / 298.257_223_563;
+
+// The order of the series in the third flattening
+const ORDER: usize = 6;
+
+// The iterations using Newton's method, before falling back to bisection
+const NEWTON_ITERATIONS: usize = 20;
+const MAX_ITERATIONS: usize = NEWTON_ITERATIONS + f64::MANTISSA_DIGITS as usize + 10;
+
+// The coefficients of the series, each a polynomial in its variable, highest order first,
+// followed by the denominator
+const A1_COEFFS: [f64; 5] = [1., 4., 64., 0., 256.];
+const C1_COEFFS: [f64; 18] = [
+    -1., 6., -16., 32., -9., 64., -128., 2048., 9., -16., 768., 3., -5., 512., -7., 1280., -7.,
+    2048.,
+];
+const A2_COEFFS: [f64; 5] = [-11., -28., -192., 0., 256.];
+const C2_COEFFS: [f64; 18] = [
+    1., 2., 16., 32., 35., 64., 384., 2048., 15., 80., 768., 7., 35., 512., 63., 1280., 77., 2048.,
+];
+const A3_COEFFS: [f64; 18] = [
+    -3., 128., -2., -3., 64., -1., -3., -1., 16., 3., -1., -2., 8., 1., -1., 2., 1., 1.,
+];
+const C3_COEFFS: [f64; 45] = [
+    3., 128., 2., 5., 128., -1., 3., 3., 64., -1., 0., 1., 8., -1., 1., 4., 5., 256., 1., 3., 128.,
+    -3., -2., 3., 64., 1., -3., 2., 32., 7., 512., -10., 9., 384., 5., -9., 5., 192., 7., 512.,
+    -14., 7., 512., 21., 2560.,
+];
+
+struct Ellipsoid<T> {
+    a: T,
+    f: T,
+    f1: T,
+    ep2: T,
+    n: T,
+    b: T,
+    // the coefficients of A3 and C3, as polynomials in eps
+    a3x: [T; ORDER],
+    c3x: [T; ORDER * (ORDER - 1) / 2],
+    tiny: T,
+    tol0: T,
+    tol1: T,
+    tolb: T,
+    xthresh: T,
+    etol2: T,
+}
+
+// The sines and cosines of the reduced latitudes of the two points, and the factors
+// sqrt(1 + ep2 * sin²β)
+struct Latitudes<T> {
+    sbet1: T,
+    cbet1: T,
+    dn1: T,
+    sbet2: T,
+    cbet2: T,
+    dn2: T,
+}
+
+// The result of solving the hybrid problem for an azimuth at the first point
+struct Lambda<T> {
+    lam12: T,
+    sig12: T,
+    ssig1: T,
+    csig1: T,
+    ssig2: T,
+    csig2: T,
+    eps: T,
+    dlam12: T,
+}
+
+impl<T: Float> Ellipsoid<T> {
+    fn wgs84() -> Self {
+        let a = float(EQUATORIAL_EARTH_RADIUS);
+        let f: T = float(WGS84_FLATTENING);
+        let two = float::<T>(2.);
+        let f1 = T::one() - f;
+        let e2 = f * (two - f);
+        let n = f / (two - f);
+
+        let mut a3x = [T::zero(); ORDER];
+        let mut offset = 0;
+        for (j, coeff) in (0..ORDER).rev().zip(a3x.iter_mut()) {
+            let order = (ORDER - j - 1).min(j);
+            *coeff = ratio(&A3_COEFFS, offset, order, n);
+            offset += order + 2;
+        }
+        let mut c3x = [T::zero(); ORDER * (ORDER - 1) / 2];
+        let mut coeffs = c3x.iter_mut();
+        let mut offset = 0;
+        for l in 1..ORDER {
+            for j in (l..ORDER).rev() {
+                let order = (ORDER - j - 1).min(j);
+                *coeffs.next().unwrap() = ratio(&C3_COEFFS, offset, order, n);
+                offset += order + 2;
+            }
+        }
+
+        let tol0 = T::epsilon();
+        let tol2 = tol0.sqrt();
+        let etol2 = float::<T>(0.1) * tol2
+            / (f.abs().max(float(0.001)) * (T::one() - f / two).min(T::one()) / two).sqrt();
+        Ellipsoid {
+            a,
+            f,
+            f1,
+            ep2: e2 / (f1 * f1),
+            n,
+            b: a * f1,
+            a3x,
+            c3x,
+            tiny: T::min_positive_value().sqrt(),
+            tol0,
+            tol1: float::<T>(200.) * tol0,
+            tolb: tol0 * tol2,
+            xthresh: float::<T>(1000.) * tol2,
+            etol2,
+        }
+    }
+
+    // The length of the geodesic between two points, given in degrees
+    fn inverse(&self, lat1: T, lon1: T, lat2: T, lon2: T) -> T {
+        let (zero, one) = (T::zero(), T::one());
+        let (d90, d180) = (float::<T>(90.), float::<T>(180.));
+
+        // make the longitude difference positive, and rounded onto the same half-meridian when
+        // it's very close to it
+        let (lon12, lon12s) = ang_diff(lon1, lon2);
+        let lonsign = if lon12 >= zero { one } else { -one };
+        let lon12 = lonsign * ang_round(lon12);
+        let lon12s = ang_round((d180 - lon12) - lonsign * lon12s);
+        let lam12 = lon12.to_radians();
+        let (slam12, clam12) = if lon12 > d90 {
+            let (s, c) = sin_cos_degrees(lon12s);
+            (s, -c)
+        } else {
+            sin_cos_degrees(lon12)
+        };
+
+        // swap the points so that |lat1| >= |lat2|, and make lat1 <= 0, which leaves the
+        // distance unchanged
+        let (mut lat1, mut lat2) = (ang_round(lat_fix(lat1)), ang_round(lat_fix(lat2)));
+        if lat1.abs() < lat2.abs() || lat2.is_nan() {
+            core::mem::swap(&mut lat1, &mut lat2);
+        }
+        if !lat1.is_sign_negative() {
+            lat1 = -lat1;
+            lat2 = -lat2;
+        }
+
+        let (sbet1, cbet1) = self.reduced_latitude(lat1);
+        let (mut sbet2, mut cbet2) = self.reduced_latitude(lat2);
+        // make the latitudes exactly opposite or equal, when they are in all but round-off
+        if cbet1 < -sbet1 {
+            if cbet2 == cbet1 {
+                sbet2 = if sbet2.is_sign_negative() {
+                    sbet1
+                } else {
+                    -sbet1
+                };
+            }
+        } else if sbet2.abs() == -sbet1 {
+            cbet2 = cbet1;
+        }
+        let lats = Latitudes {
+            sbet1,
+            cbet1,
+            dn1: (one + self.ep2 * sbet1 * sbet1).sqrt(),
+            sbet2,
+            cbet2,
+            dn2: (one + self.ep2 * sbet2 * sbet2).sqrt(),
+        };
+
+        if lat1 == -d90 || slam12 == zero {
+            // along a meridian, which is the shortest path unless it passes near a pole
+            let (ssig1, csig1) = (sbet1, clam12 * cbet1);
+            let (ssig2, csig2) = (sbet2, cbet2);
+            let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+                .max(zero)
+                .atan2(csig1 * csig2 + ssig1 * ssig2);
+            let (s12x, m12x) = lengths(&lats, self.n, sig12, ssig1, csig1, ssig2, csig2);
+            if sig12 < one || m12x >= zero {
+                let tiny = sig12 < float::<T>(3.) * self.tiny
+                    || (sig12 < self.tol0 && (s12x < zero || m12x < zero));
+                return if tiny { zero } else { self.b * s12x };
+            }
+        }
+
+        if sbet1 == zero && (self.f <= zero || lon12s >= self.f * d180) {
+            // along the equator
+            return self.a * lam12;
+        }
+
+        let (sig12, mut salp1, mut calp1, dnm) = self.inverse_start(&lats, lam12, slam12, clam12);
+        if sig12 >= zero {
+            // the points are close enough to use a sphere's geodesic
+            return sig12 * self.b * dnm;
+        }
+
+        // find the azimuth at the first point with Newton's method, keeping it bracketed, and
+        // bisect if that fails to converge
+        let (mut salp1a, mut calp1a, mut salp1b, mut calp1b) = (self.tiny, one, self.tiny, -one);
+        let (mut tripn, mut tripb) = (false, false);
+        let mut iterations = 0;
+        let lambda = loop {
+            let lambda = self.lambda12(
+                &lats,
+                salp1,
+                calp1,
+                slam12,
+                clam12,
+                iterations < NEWTON_ITERATIONS,
+            );
+            let v = lambda.lam12;
+            let tolerance = if tripn { float(8.) } else { one } * self.tol0;
+            if tripb || v.is_nan() || v.abs() < tolerance || iterations + 1 == MAX_ITERATIONS {
+                break lambda;
+            }
+            if v > zero && (iterations > NEWTON_ITERATIONS || calp1 / salp1 > calp1b / salp1b) {
+                salp1b = salp1;
+                calp1b = calp1;
+            } else if v < zero
+                && (iterations > NEWTON_ITERATIONS || calp1 / salp1 < calp1a / salp1a)
+            {
+                salp1a = salp1;
+                calp1a = calp1;
+            }
+            iterations += 1;
+            if iterations <= NEWTON_ITERATIONS && lambda.dlam12 > zero {
+                let dalp1 = -v / lambda.dlam12;
+                if dalp1.abs() < float(core::f64::consts::PI) {
+                    let (sdalp1, cdalp1) = dalp1.sin_cos();
+                    let nsalp1 = salp1 * cdalp1 + calp1 * sdalp1;
+                    if nsalp1 > zero {
+                        let (s, c) = norm(nsalp1, calp1 * cdalp1 - salp1 * sdalp1);
+                        salp1 = s;
+                        calp1 = c;
+                        tripn = v.abs() <= float::<T>(16.) * self.tol0;
+                        continue;
+                    }
+                }
+            }
+            let two = float::<T>(2.);
+            let (s, c) = norm((salp1a + salp1b) / two, (calp1a + calp1b) / two);
+            salp1 = s;
+            calp1 = c;
+            tripn = false;
+            tripb = (salp1a - salp1).abs() + (calp1a - calp1) < self.tolb
+                || (salp1 - salp1b).abs() + (calp1 - calp1b) < self.tolb;
+        };
+        let (s12x, _) = lengths(
+            &lats,
+            lambda.eps,
+            lambda.sig12,
+            lambda.ssig1,
+            lambda.csig1,
+            lambda.ssig2,
+            lambda.csig2,
+        );
+        self.b * s12x
+    }
+
+    // The sine and cosine of the reduced latitude β, where tan β = (1 - f) tan φ
+    fn reduced_latitude(&self, lat: T) -> (T, T) {
+        let (s, c) = sin_cos_degrees(lat);
+        let (s, c) = norm(self.f1 * s, c);
+        (s, c.max(self.tiny))
+    }
+
+    // An initial guess of the azimuth at the first point, returning the arc length as well if
+    // the points are close enough that the guess is exact
+    fn inverse_start(&self, lats: &Latitudes<T>, lam12: T, slam12: T, clam12: T) -> (T, T, T, T) {
+        let (zero, one, half) = (T::zero(), T::one(), float::<T>(0.5));
+        let Latitudes {
+            sbet1,
+            cbet1,
+            sbet2,
+            cbet2,
+            ..
+        } = *lats;
+        let mut sig12 = -one;
+        let mut dnm = one;
+        let sbet12 = sbet2 * cbet1 - cbet2 * sbet1;
+        let cbet12 = cbet2 * cbet1 + sbet2 * sbet1;
+        let sbet12a = sbet2 * cbet1 + cbet2 * sbet1;
+        let shortline = cbet12 >= zero && sbet12 < half && cbet2 * lam12 < half;
+        let (mut somg12, mut comg12) = if shortline {
+            let sbetm2 = (sbet1 + sbet2) * (sbet1 + sbet2);
+            let sbetm2 = sbetm2 / (sbetm2 + (cbet1 + cbet2) * (cbet1 + cbet2));
+            dnm = (one + self.ep2 * sbetm2).sqrt();
+            (lam12 / (self.f1 * dnm)).sin_cos()
+        } else {
+            (slam12, clam12)
+        };
+
+        let mut salp1 = cbet2 * somg12;
+        let mut calp1 = if comg12 >= zero {
+            sbet12 + cbet2 * sbet1 * somg12 * somg12 / (one + comg12)
+        } else {
+            sbet12a - cbet2 * sbet1 * somg12 * somg12 / (one - comg12)
+        };
+        let ssig12 = salp1.hypot(calp1);
+        let csig12 = sbet1 * sbet2 + cbet1 * cbet2 * comg12;
+
+        let pi = float::<T>(core::f64::consts::PI);
+        if shortline && ssig12 < self.etol2 {
+            sig12 = ssig12.atan2(csig12);
+        } else if self.n.abs() > float(0.1)
+            || csig12 >= zero
+            || ssig12 >= float::<T>(6.) * self.n.abs() * pi * cbet1 * cbet1
+        {
+            // the spherical guess is good enough
+        } else {
+            // nearly antipodal points, where the guess comes from the astroid problem
+            let lam12x = (-slam12).atan2(-clam12);
+            let k2 = sbet1 * sbet1 * self.ep2;
+            let eps = k2 / (float::<T>(2.) * (one + (one + k2).sqrt()) + k2);
+            let lamscale = self.f * cbet1 * self.a3f(eps) * pi;
+            let betscale = lamscale * cbet1;
+            let x = lam12x / lamscale;
+            let y = sbet12a / betscale;
+            if y > -self.tol1 && x > -one - self.xthresh {
+                salp1 = one.min(-x);
+                calp1 = -(one - salp1 * salp1).sqrt();
+            } else {
+                let k = astroid(x, y);
+                let omg12a = lamscale * (-x * k / (one + k));
+                let (s, c) = omg12a.sin_cos();
+                somg12 = s;
+                comg12 = -c;
+                salp1 = cbet2 * somg12;
+                calp1 = sbet12a - cbet2 * sbet1 * somg12 * somg12 / (one - comg12);
+            }
+        }
+
+        let (salp1, calp1) = if salp1 > zero {
+            norm(salp1, calp1)
+        } else {
+            (one, zero)
+        };
+        (sig12, salp1, calp1, dnm)
+    }
+
+    // The longitude difference, less the one wanted, of the geodesic leaving the first point
+    // at the given azimuth, and its derivative if `diffp`
+    fn lambda12(
+        &self,
+        lats: &Latitudes<T>,
+        salp1: T,
+        calp1: T,
+        slam120: T,
+        clam120: T,
+        diffp: bool,
+    ) -> Lambda<T> {
+        let (zero, one, two) = (T::zero(), T::one(), float::<T>(2.));
+        let Latitudes {
+            sbet1,
+            cbet1,
+            dn1,
+            sbet2,
+            cbet2,
+            ..
+        } = *lats;
+        let calp1 = if sbet1 == zero && calp1 == zero {
+            -self.tiny
+        } else {
+            calp1
+        };
+        let salp0 = salp1 * cbet1;
+        let calp0 = calp1.hypot(salp1 * sbet1);
+
+        let somg1 = salp0 * sbet1;
+        let comg1 = calp1 * cbet1;
+        let (ssig1, csig1) = norm(sbet1, comg1);
+        let calp2 = if cbet2 != cbet1 || sbet2.abs() != -sbet1 {
+            let d = if cbet1 < -sbet1 {
+                (cbet2 - cbet1) * (cbet1 + cbet2)
+            } else {
+                (sbet1 - sbet2) * (sbet1 + sbet2)
+            };
+            ((calp1 * cbet1) * (calp1 * cbet1) + d).sqrt() / cbet2
+        } else {
+            calp1.abs()
+        };
+        let somg2 = salp0 * sbet2;
+        let comg2 = calp2 * cbet2;
+        let (ssig2, csig2) = norm(sbet2, comg2);
+
+        let sig12 = (csig1 * ssig2 - ssig1 * csig2)
+            .max(zero)
+            .atan2(csig1 * csig2 + ssig1 * ssig2);
+        let somg12 = (comg1 * somg2 - somg1 * comg2).max(zero);
+        let comg12 = comg1 * comg2 + somg1 * somg2;
+        let eta = (somg12 * clam120 - comg12 * slam120).atan2(comg12 * clam120 + somg12 * slam120);
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (two * (one + (one + k2).sqrt()) + k2);
+        let c3 = self.c3f(eps);
+        let b312 = sin_series(ssig2, csig2, &c3) - sin_series(ssig1, csig1, &c3);
+        let domg12 = -self.f * self.a3f(eps) * salp0 * (sig12 + b312);
+        let lam12 = eta + domg12;
+
+        let dlam12 = if !diffp {
+            zero
+        } else if calp2 == zero {
+            -two * self.f1 * dn1 / sbet1
+        } else {
+            let (_, m12b) = lengths(lats, eps, sig12, ssig1, csig1, ssig2, csig2);
+            m12b * self.f1 / (calp2 * cbet2)
+        };
+        Lambda {
+            lam12,
+            sig12,
+            ssig1,
+            csig1,
+            ssig2,
+            csig2,
+            eps,
+            dlam12,
+        }
+    }
+
+    fn a3f(&self, eps: T) -> T {
+        polyval(self.a3x.iter().copied(), eps)
+    }
+
+    fn c3f(&self, eps: T) -> [T; ORDER] {
+        let mut c = [T::zero(); ORDER];
+        let mut mult = T::one();
+        let mut offset = 0;
+        for (l, coeff) in c.iter_mut().enumerate().skip(1) {
+            let order = ORDER - l - 1;
+            mult = mult * eps;
+            *coeff = mult * polyval(self.c3x[offset..=offset + order].iter().copied(), eps);
+            offset += order + 1;
+        }
+        c
+    }
+}
+
+// The arc length of the geodesic, and its reduced length, both divided by b
+fn lengths<T: Float>(
+    lats: &Latitudes<T>,
+    eps: T,
+    sig12: T,
+    ssig1: T,
+    csig1: T,
+    ssig2: T,
+    csig2: T,
+) -> (T, T) {
+    let one = T::one();
+    let a1m1 = series_factor(&A1_COEFFS, eps);
+    let a1 = one + (a1m1 + eps) / (one - eps);
+    let a2m1 = series_factor(&A2_COEFFS, eps);
+    let a2 = one + (a2m1 - eps) / (one + eps);
+    let c1 = series(&C1_COEFFS, eps);
+    let c2 = series(&C2_COEFFS, eps);
+    let b1 = sin_series(ssig2, csig2, &c1) - sin_series(ssig1, csig1, &c1);
+    let b2 = sin_series(ssig2, csig2, &c2) - sin_series(ssig1, csig1, &c2);
+    let j12 = (a1 - a2) * sig12 + (a1 * b1 - a2 * b2);
+    let s12b = a1 * (sig12 + b1);
+    let m12b = lats.dn2 * (csig1 * ssig2) - lats.dn1 * (ssig1 * csig2) - csig1 * csig2 * j12;
+    (s12b, m12b)
+}
+
+// The even polynomial in eps of A1 or A2, before scaling by (1 ± eps)
+fn series_factor<T: Float>(coeffs: &[f64], eps: T) -> T {
+    ratio(coeffs, 0, ORDER / 2, eps * eps)
+}
+
+// The coefficients C1 or C2 of the sine series, indexed from 1
+fn series<T: Float>(coeffs: &[f64], eps: T) -> [T; ORDER + 1] {
+    let eps2 = eps * eps;
+    let mut c = [T::zero(); ORDER + 1];
+    let mut d = eps;
+    let mut offset = 0;
+    for (l, coeff) in c.iter_mut().enumerate().skip(1) {
+        let order = (ORDER - l) / 2;
+        *coeff = d * ratio(coeffs, offset, order, eps2);
+        offset += order + 2;
+        d = d * eps;
+    }
+    c
+}
+
+// Evaluate the sum of c[l] sin(2 l σ), for l from 1, with Clenshaw summation
+fn sin_series<T: Float>(sinx: T, cosx: T, c: &[T]) -> T {
+    let two = float::<T>(2.);
+    let ar = two * (cosx - sinx) * (cosx + sinx);
+    let mut k = c.len();
+    let n = k - 1;
+    let (mut y0, mut y1) = (T::zero(), T::zero());
+    if n % 2 == 1 {
+        k -= 1;
+        y0 = c[k];
+    }
+    for _ in 0..n / 2 {
+        y1 = ar * y0 - y1 + c[k - 1];
+        y0 = ar * y1 - y0 + c[k - 2];
+        k -= 2;
+    }
+    two * sinx * cosx * y0
+}
+
+// The polynomial at `offset` in `coeffs`, of the given order, divided by the denominator after it
+fn ratio<T: Float>(coeffs: &[f64], offset: usize, order: usize, x: T) -> T {
+    let poly = polyval(coeffs[offset..=offset + order].iter().map(|&c| float(c)), x);
+    poly / float(coeffs[offset + order + 1])
+}
+
+fn polyval<T: Float>(coeffs: impl Iterator<Item = T>, x: T) -> T {
+    coeffs.fold(T::zero(), |acc, coeff| acc * x + coeff)
+}
+
+// The positive root of the astroid equation k⁴ + 2k³ - (x² + y² - 1)k² - 2y²k - y² = 0
+fn astroid<T: Float>(x: T, y: T) -> T {
+    let (zero, one, two) = (T::zero(), T::one(), float::<T>(2.));
+    let p = x * x;
+    let q = y * y;
+    let r = (p + q - one) / float(6.);
+    if q == zero && r <= zero {
+        return zero;
+    }
+    let s = p * q / float(4.);
+    let r2 = r * r;
+    let r3 = r * r2;
+    let disc = s * (s + two * r3);
+    let mut u = r;
+    if disc >= zero {
+        let t3 = s + r3;
+        let t3 = if t3 < zero {
+            t3 - disc.sqrt()
+        } else {
+            t3 + disc.sqrt()
+        };
+        let t = t3.cbrt();
+        u = u + t + if t != zero { r2 / t } else { zero };
+    } else {
+        let ang = (-disc).sqrt().atan2(-(s + r3));
+        u = u + two * r * (ang / float(3.)).cos();
+    }
+    let v = (u * u + q).sqrt();
+    let uv = if u < zero { q / (v - u) } else { u + v };
+    let w = (uv - q) / (two * v);
+    uv / ((uv + w * w).sqrt() + w)
+}
+
+fn norm<T: Float>(s: T, c: T) -> (T, T) {
+    let r = s.hypot(c);
+    (s / r, c / r)
+}
+
+// The sine and cosine of an angle in degrees, exact at multiples of 90°
+fn sin_cos_degrees<T: Float>(x: T) -> (T, T) {
+    let d90 = float::<T>(90.);
+    let r = x % float(360.);
+    let q = (r / d90 + float(0.5)).floor();
+    let (s, c) = ((r - d90 * q).to_radians()).sin_cos();
+    let (sinx, cosx) = match q.to_i64().unwrap_or(0) & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    // avoid negative zeros
+    if x != T::zero() {
+        (sinx + T::zero(), cosx + T::zero())
+    } else {
+        (sinx, cosx)
+    }
+}
+
+// The remainder of an angle in degrees, in [-180°, 180°]
+fn ang_remainder<T: Float>(x: T) -> T {
+    let (d180, d360) = (float::<T>(180.), float::<T>(360.));
+    let y = x % d360;
+    if y < -d180 {
+        y + d360
+    } else if y > d180 {
+        y - d360
+    } else {
+        y
+    }
+}
+
+// An angle in degrees, in (-180°, 180°]
+fn ang_normalize<T: Float>(x: T) -> T {
+    let y = ang_remainder(x);
+    if y == -float::<T>(180.) {
+        -y
+    } else {
+        y
+    }
+}
+
+// The sum of two numbers, and its round-off error
+fn sum<T: Float>(u: T, v: T) -> (T, T) {
+    let s = u + v;
+    let up = s - v;
+    let vpp = s - up;
+    (s, -((up - u) + (vpp - v)))
+}
+
+// The difference y - x of two angles in degrees, in (-180°, 180°], and its round-off error
+fn ang_diff<T: Float>(x: T, y: T) -> (T, T) {
+    let d180 = float::<T>(180.);
+    let (d, t) = sum(ang_remainder(-x), ang_remainder(y));
+    let d = ang_normalize(d);
+    sum(if d == d180 && t > T::zero() { -d180 } else { d }, t)
+}
+
+// Round an angle in degrees to a multiple of 1/2⁴, when it's smaller than that, so that tiny
+// angles don't lose precision to a loss of their leading digits elsewhere
+fn ang_round<T: Float>(x: T) -> T {
+    let z = float::<T>(1. / 16.);
+    if x == T::zero() {
+        return T::zero();
+    }
+    let y = x.abs();
+    let y = if y < z { z - (z - y) } else { y };
+    if x < T::zero() {
+        -y
+    } else {
+        y
+    }
+}
+
+fn lat_fix<T: Float>(x: T) -> T {
+    if x.abs() > float(90.) {
+        T::nan()
+    } else {
+        x
+    }
+}
+
+fn float<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+    use crate::algorithm::vincenty_distance::VincentyDistance;
+    use crate::Point;
+
+    #[test]
+    fn matches_vincenty() {
+        let a = Point::<f64>::new(17.072561, 48.154563);
+        let b = Point::<f64>::new(17.072562, 48.154564);
+        assert_relative_eq!(
+            a.geodesic_distance(&b),
+            a.vincenty_distance(&b).unwrap(),
+            epsilon = 1.0e-6
+        );
+
+        let a = Point::<f64>::new(-72.1235, 42.3521);
+        let b = Point::<f64>::new(72.1260, 70.612);
+        assert_relative_eq!(
+            a.geodesic_distance(&b),
+            a.vincenty_distance(&b).unwrap(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn nearly_antipodal() {
+        // Vincenty's formulae fail to converge for these points
+        let a = Point::<f64>::new(0., 0.);
+        let b = Point::<f64>::new(179.5, 0.5);
+        assert!(a.vincenty_distance(&b).is_err());
+        // the example from Karney (2013)
+        assert_relative_eq!(a.geodesic_distance(&b), 19936288.579, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn same_point() {
+        let a = Point::<f32>::new(12., 34.);
+        assert_eq!(a.geodesic_distance(&a), 0.);
+    }
+
+    #[cfg(feature = "geographiclib")]
+    #[test]
+    fn matches_geographiclib() {
+        use geographiclib_rs::{Geodesic, InverseGeodesic};
+        let geodesic = Geodesic::wgs84();
+        // including the poles, the equator, meridians and nearly antipodal points
+        let lats = [-90., -89.9, -60., -1e-9, 0., 1e-9, 0.5, 30., 89.99, 90.];
+        let lons = [
+            -180., -179.5, -120., -0.1, 0., 1e-9, 45., 90., 179.5, 179.99, 180.,
+        ];
+        for &lat1 in &lats {
+            for &lat2 in &lats {
+                for &lon in &lons {
+                    let a = Point::<f64>::new(0., lat1);
+                    let b = Point::<f64>::new(lon, lat2);
+                    let expected: f64 = geodesic.inverse(lat1, 0., lat2, lon);
+                    assert_relative_eq!(a.geodesic_distance(&b), expected, epsilon = 1.0e-8);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use num_traits::Float;
+
+use crate::algorithm::geodesic_distance::GeodesicDistance;
+use crate::{Line, LineString, MultiLineString};
+
+/// Determine the length of a geometry on the WGS84 ellipsoid, using the method of
+/// [Karney (2013)].
+///
+/// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+pub trait GeodesicLength<T, RHS = Self> {
+    /// Determine the length of a geometry on the WGS84 ellipsoid, using the method of
+    /// [Karney (2013)].
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::LineString;
+    ///
+    /// let linestring = LineString::<f64>::from(vec![
+    ///     // New York City
+    ///     (-74.006, 40.7128),
+    ///     // London
+    ///     (-0.1278, 51.5074),
+    /// ]);
+    ///
+    /// let length = linestring.geodesic_length();
+    ///
+    /// assert_eq!(
+    ///     5_585_234., // meters
+    ///     length.round()
+    /// );
+    /// ```
+    ///
+    /// [Karney (2013)]: https://doi.org/10.1007/s00190-012-0578-z
+    fn geodesic_length(&self) -> T;
+}
+
+impl<T> GeodesicLength<T> for Line<T>
+where
+    T: Float,
+{
+    fn geodesic_length(&self) -> T {
+        let (start, end) = self.points();
+        start.geodesic_distance(&end)
+    }
+}
+
+impl<T> GeodesicLength<T> for LineString<T>
+where
+    T: Float,
+{
+    fn geodesic_length(&self) -> T {
+        self.lines().fold(T::zero(), |total_length, line| {
+            total_length + line.geodesic_length()
+        })
+    }
+}
+
+impl<T> GeodesicLength<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn geodesic_length(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, line| total + line.geodesic_length())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::geodesic_length::GeodesicLength;
+    use crate::algorithm::vincenty_length::VincentyLength;
+    use crate::{line_string, Line, MultiLineString};
+
+    #[test]
+    fn geometries() {
+        let line_string = line_string![
+            (x: 17.072561, y: 48.154563),
+            (x: 17.072562, y: 48.154564),
+            (x: 20., y: 50.),
+        ];
+        assert_relative_eq!(
+            line_string.geodesic_length(),
+            line_string.vincenty_length().unwrap(),
+            epsilon = 1.0e-6
+        );
+        let line = Line::from([(0., 0.), (1., 0.)]);
+        // a degree of longitude along the equator
+        assert_relative_eq!(line.geodesic_length(), 111319.49079327357, epsilon = 1.0e-6);
+        let multi = MultiLineString(vec![line_string.clone(), line_string]);
+        assert_relative_eq!(
+            multi.geodesic_length(),
+            2. * multi.0[0].geodesic_length(),
+            epsilon = 1.0e-6
+        );
+    }
+}
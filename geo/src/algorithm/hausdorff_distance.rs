@@ -0,0 +1,287 @@
+use crate::{Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+use geo_types::private_utils::line_segment_distance;
+use num_traits::Float;
+
+/// Determine the similarity between two geometries using the discrete [Hausdorff distance]: the
+/// greatest distance from a vertex of either geometry to the other geometry.
+///
+/// The distances are measured to the lines of the other geometry, and for `Polygon`s, to their
+/// rings, so a vertex of one `Polygon` inside the other counts the distance to its boundary.
+/// Between vertices, the lines of a geometry can be further from the other geometry than its
+/// vertices are; [`Densify`](../densify/trait.Densify.html) a geometry first to take those
+/// places into account.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// Determine the similarity between two geometries using the discrete [Hausdorff distance],
+    /// or zero if either is empty.
+    ///
+    /// Each vertex is only compared with the lines of the other geometry which could be closer
+    /// to it than the best found so far, judging by their bounding boxes, and a vertex is
+    /// skipped as soon as it's found closer than the greatest distance so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    /// use geo::line_string;
+    ///
+    /// let ls_a = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+    /// let ls_b = line_string![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.)];
+    ///
+    /// // the vertex of `ls_b` at (3, 3) is furthest from `ls_a`
+    /// assert_eq!(ls_a.hausdorff_distance(&ls_b), 3.);
+    /// ```
+    ///
+    /// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T;
+}
+
+// The vertices and lines of a geometry, with `Point`s as lines of no length
+trait Parts<T: Float> {
+    fn vertices(&self) -> Vec<Coordinate<T>>;
+    fn lines(&self) -> Vec<Line<T>>;
+}
+
+impl<T: Float> Parts<T> for MultiPoint<T> {
+    fn vertices(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().map(|point| point.0).collect()
+    }
+
+    fn lines(&self) -> Vec<Line<T>> {
+        self.0
+            .iter()
+            .map(|point| Line::new(point.0, point.0))
+            .collect()
+    }
+}
+
+impl<T: Float> Parts<T> for LineString<T> {
+    fn vertices(&self) -> Vec<Coordinate<T>> {
+        self.0.clone()
+    }
+
+    fn lines(&self) -> Vec<Line<T>> {
+        match self.0.as_slice() {
+            [coord] => vec![Line::new(*coord, *coord)],
+            _ => LineString::lines(self).collect(),
+        }
+    }
+}
+
+impl<T: Float> Parts<T> for MultiLineString<T> {
+    fn vertices(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().flat_map(Parts::vertices).collect()
+    }
+
+    fn lines(&self) -> Vec<Line<T>> {
+        self.0.iter().flat_map(Parts::lines).collect()
+    }
+}
+
+impl<T: Float> Parts<T> for Polygon<T> {
+    fn vertices(&self) -> Vec<Coordinate<T>> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(Parts::vertices)
+            .collect()
+    }
+
+    fn lines(&self) -> Vec<Line<T>> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(Parts::lines)
+            .collect()
+    }
+}
+
+impl<T: Float> Parts<T> for MultiPolygon<T> {
+    fn vertices(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().flat_map(Parts::vertices).collect()
+    }
+
+    fn lines(&self) -> Vec<Line<T>> {
+        self.0.iter().flat_map(Parts::lines).collect()
+    }
+}
+
+macro_rules! impl_hausdorff_distance {
+    ($type:ident, [$($rhs:ident),*]) => {
+        $(
+            impl<T> HausdorffDistance<T, $rhs<T>> for $type<T>
+            where
+                T: Float,
+            {
+                fn hausdorff_distance(&self, rhs: &$rhs<T>) -> T {
+                    hausdorff_distance(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_hausdorff_distance!(
+    MultiPoint,
+    [
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon
+    ]
+);
+impl_hausdorff_distance!(
+    LineString,
+    [
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon
+    ]
+);
+impl_hausdorff_distance!(
+    MultiLineString,
+    [
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon
+    ]
+);
+impl_hausdorff_distance!(
+    Polygon,
+    [
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon
+    ]
+);
+impl_hausdorff_distance!(
+    MultiPolygon,
+    [
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon
+    ]
+);
+
+fn hausdorff_distance<T, A, B>(a: &A, b: &B) -> T
+where
+    T: Float,
+    A: Parts<T>,
+    B: Parts<T>,
+{
+    let (a_lines, b_lines) = (a.lines(), b.lines());
+    if a_lines.is_empty() || b_lines.is_empty() {
+        return T::zero();
+    }
+    let distance = directed_distance(&a.vertices(), &b_lines, T::zero());
+    directed_distance(&b.vertices(), &a_lines, distance)
+}
+
+// The greatest of `max` and the distances from each of `vertices` to the nearest of `lines`
+fn directed_distance<T: Float>(vertices: &[Coordinate<T>], lines: &[Line<T>], mut max: T) -> T {
+    let bounds: Vec<_> = lines.iter().map(|line| line.bounding_rect()).collect();
+    for vertex in vertices {
+        let mut nearest = T::infinity();
+        for (line, bounds) in lines.iter().zip(&bounds) {
+            let dx = (bounds.min().x - vertex.x).max(vertex.x - bounds.max().x);
+            let dy = (bounds.min().y - vertex.y).max(vertex.y - bounds.max().y);
+            if dx.max(T::zero()).hypot(dy.max(T::zero())) >= nearest {
+                continue;
+            }
+            nearest = nearest.min(line_segment_distance(
+                (*vertex).into(),
+                line.start_point(),
+                line.end_point(),
+            ));
+            if nearest <= max {
+                // this vertex can't be the furthest
+                break;
+            }
+        }
+        max = max.max(nearest);
+    }
+    max
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn symmetric() {
+        let ls_a = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let ls_b = line_string![(x: 0., y: 1.), (x: 5., y: 2.), (x: 10., y: 1.)];
+        assert_eq!(ls_a.hausdorff_distance(&ls_b), 2.);
+        assert_eq!(ls_b.hausdorff_distance(&ls_a), 2.);
+        // the end of a longer line is far from a shorter one
+        let ls_c = line_string![(x: 0., y: 0.), (x: 16., y: 0.)];
+        assert_eq!(ls_a.hausdorff_distance(&ls_c), 6.);
+        assert_eq!(ls_a.hausdorff_distance(&ls_a), 0.);
+    }
+
+    #[test]
+    fn polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let with_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        ];
+        // the furthest vertex of the hole is 2 from the boundary of the square
+        assert_eq!(square.hausdorff_distance(&with_hole), 2.);
+        let multi_polygon = MultiPolygon(vec![square.clone()]);
+        assert_eq!(multi_polygon.hausdorff_distance(&square), 0.);
+        assert_eq!(square.hausdorff_distance(square.exterior()), 0.);
+    }
+
+    #[test]
+    fn points_and_empty() {
+        let points: MultiPoint<f64> = vec![(0., 3.), (2., -1.)].into();
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(points.hausdorff_distance(&line_string), 3.);
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.hausdorff_distance(&points), 5f64.sqrt());
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.hausdorff_distance(&line_string), 0.);
+    }
+
+    #[test]
+    fn matches_exhaustive_search() {
+        let track = |offset: f64, count: usize| -> LineString<f64> {
+            (0..count)
+                .map(|i| {
+                    let t = i as f64 / count as f64 * 20.;
+                    (t + offset * t.sin(), (t * 0.7).cos() * 3. + offset)
+                })
+                .collect()
+        };
+        let (ls_a, ls_b) = (track(0.3, 300), track(-0.4, 170));
+        let exhaustive = |a: &LineString<f64>, b: &LineString<f64>| {
+            a.0.iter()
+                .map(|vertex| {
+                    b.lines()
+                        .map(|line| {
+                            line_segment_distance(
+                                (*vertex).into(),
+                                line.start_point(),
+                                line.end_point(),
+                            )
+                        })
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0., f64::max)
+        };
+        assert_eq!(
+            ls_a.hausdorff_distance(&ls_b),
+            exhaustive(&ls_a, &ls_b).max(exhaustive(&ls_b, &ls_a))
+        );
+    }
+}
@@ -2,7 +2,6 @@ use crate::{Point, MEAN_EARTH_RADIUS};
 use num_traits::{Float, FromPrimitive};
 
 /// Returns a new Point along a great circle route between two existing points
-
 pub trait HaversineIntermediate<T: Float> {
     /// Returns a new Point along a great circle route between two existing points.
     ///
@@ -29,7 +28,6 @@ pub trait HaversineIntermediate<T: Float> {
     /// assert_relative_eq!(i80.x(), i80_should.x(), epsilon = 0.2);
     /// assert_relative_eq!(i80.y(), i80_should.y(), epsilon = 0.2);
     /// ```
-
     fn haversine_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
     fn haversine_intermediate_fill(
         &self,
@@ -44,7 +42,7 @@ where
     T: Float + FromPrimitive,
 {
     fn haversine_intermediate(&self, other: &Point<T>, f: T) -> Point<T> {
-        let params = get_params(&self, &other);
+        let params = get_params(self, other);
         get_point(&params, f)
     }
 
@@ -54,7 +52,7 @@ where
         max_dist: T,
         include_ends: bool,
     ) -> Vec<Point<T>> {
-        let params = get_params(&self, &other);
+        let params = get_params(self, other);
         let HaversineParams { d, .. } = params;
 
         let total_distance = d * T::from(MEAN_EARTH_RADIUS).unwrap();
@@ -80,7 +78,7 @@ where
         }
 
         if include_ends {
-            points.push(other.clone());
+            points.push(*other);
         }
 
         points
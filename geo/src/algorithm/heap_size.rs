@@ -0,0 +1,350 @@
+use crate::{
+    CoordBuffer, Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, SharedGeometry, Triangle,
+};
+use std::mem::size_of;
+
+/// Report how much memory a geometry uses, and how many coordinates it stores, counting the
+/// members of collections recursively.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::heap_size::HeapSize;
+/// use geo::{polygon, Coordinate, LineString, MultiPolygon, Polygon};
+/// use std::mem::size_of;
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// assert_eq!(polygon.coord_count(), 5);
+/// assert_eq!(
+///     polygon.heap_size(),
+///     polygon.exterior().0.capacity() * size_of::<Coordinate<f64>>()
+/// );
+///
+/// let polygon_size = polygon.heap_size();
+/// let mut multi_polygon = MultiPolygon(Vec::with_capacity(2));
+/// multi_polygon.0.push(polygon);
+/// assert_eq!(multi_polygon.coord_count(), 5);
+/// assert_eq!(
+///     multi_polygon.heap_size(),
+///     2 * size_of::<Polygon<f64>>() + polygon_size
+/// );
+/// ```
+pub trait HeapSize {
+    /// Return the number of bytes the geometry has allocated on the heap, including the unused
+    /// capacity of its `Vec`s, but not the size of the geometry itself.
+    fn heap_size(&self) -> usize;
+
+    /// Return the number of coordinates the geometry stores.
+    ///
+    /// This is how many coordinates are in memory, rather than how many the geometry has:
+    /// a `Rect` stores two, where
+    /// [`CoordsIter::coords_count`](../coords_iter/trait.CoordsIter.html#tymethod.coords_count)
+    /// counts its four corners.
+    fn coord_count(&self) -> usize;
+
+    /// Return the number of bytes the geometry uses altogether: its own size, and what it has
+    /// allocated on the heap.
+    fn total_size(&self) -> usize
+    where
+        Self: Sized,
+    {
+        size_of::<Self>() + self.heap_size()
+    }
+}
+
+// The bytes allocated by a `Vec`, and by each of its members
+fn vec_heap_size<G: HeapSize>(members: &Vec<G>) -> usize {
+    members.capacity() * size_of::<G>() + members.iter().map(HeapSize::heap_size).sum::<usize>()
+}
+
+fn vec_coord_count<G: HeapSize>(members: &[G]) -> usize {
+    members.iter().map(HeapSize::coord_count).sum()
+}
+
+impl<T: CoordinateType> HeapSize for Coordinate<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn coord_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Point<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn coord_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Line<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn coord_count(&self) -> usize {
+        2
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Rect<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn coord_count(&self) -> usize {
+        2
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Triangle<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn coord_count(&self) -> usize {
+        3
+    }
+}
+
+impl<T: CoordinateType> HeapSize for LineString<T> {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+
+    fn coord_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Polygon<T> {
+    fn heap_size(&self) -> usize {
+        // `interiors` only returns a slice, so the capacity of its `Vec` is taken to be its length
+        self.exterior().heap_size()
+            + std::mem::size_of_val(self.interiors())
+            + self
+                .interiors()
+                .iter()
+                .map(HeapSize::heap_size)
+                .sum::<usize>()
+    }
+
+    fn coord_count(&self) -> usize {
+        self.exterior().coord_count() + vec_coord_count(self.interiors())
+    }
+}
+
+impl<T: CoordinateType> HeapSize for MultiPoint<T> {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+
+    fn coord_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordinateType> HeapSize for MultiLineString<T> {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+
+    fn coord_count(&self) -> usize {
+        vec_coord_count(&self.0)
+    }
+}
+
+impl<T: CoordinateType> HeapSize for MultiPolygon<T> {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+
+    fn coord_count(&self) -> usize {
+        vec_coord_count(&self.0)
+    }
+}
+
+impl<T: CoordinateType> HeapSize for GeometryCollection<T> {
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.0)
+    }
+
+    fn coord_count(&self) -> usize {
+        vec_coord_count(&self.0)
+    }
+}
+
+impl<T: CoordinateType> HeapSize for Geometry<T> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Geometry::Point(g) => g.heap_size(),
+            Geometry::Line(g) => g.heap_size(),
+            Geometry::LineString(g) => g.heap_size(),
+            Geometry::Polygon(g) => g.heap_size(),
+            Geometry::MultiPoint(g) => g.heap_size(),
+            Geometry::MultiLineString(g) => g.heap_size(),
+            Geometry::MultiPolygon(g) => g.heap_size(),
+            Geometry::GeometryCollection(g) => g.heap_size(),
+            Geometry::Rect(g) => g.heap_size(),
+            Geometry::Triangle(g) => g.heap_size(),
+        }
+    }
+
+    fn coord_count(&self) -> usize {
+        match self {
+            Geometry::Point(g) => g.coord_count(),
+            Geometry::Line(g) => g.coord_count(),
+            Geometry::LineString(g) => g.coord_count(),
+            Geometry::Polygon(g) => g.coord_count(),
+            Geometry::MultiPoint(g) => g.coord_count(),
+            Geometry::MultiLineString(g) => g.coord_count(),
+            Geometry::MultiPolygon(g) => g.coord_count(),
+            Geometry::GeometryCollection(g) => g.coord_count(),
+            Geometry::Rect(g) => g.coord_count(),
+            Geometry::Triangle(g) => g.coord_count(),
+        }
+    }
+}
+
+impl<T: CoordinateType> HeapSize for CoordBuffer<T> {
+    fn heap_size(&self) -> usize {
+        // one `Vec` of x values, and another of y values
+        2 * self.capacity() * size_of::<T>()
+    }
+
+    fn coord_count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// All of the shared geometry is counted, along with the counts of references to it, however
+/// many owners share it: divide by [`share_count`](../../struct.SharedGeometry.html#method.share_count)
+/// to apportion it between them.
+impl<T: CoordinateType> HeapSize for SharedGeometry<T> {
+    fn heap_size(&self) -> usize {
+        2 * size_of::<usize>() + size_of::<Geometry<T>>() + (**self).heap_size()
+    }
+
+    fn coord_count(&self) -> usize {
+        (**self).coord_count()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T: CoordinateType, const N: usize> HeapSize for geo_types::small::SmallLineString<T, N> {
+    fn heap_size(&self) -> usize {
+        if self.spilled() {
+            self.0.capacity() * size_of::<Coordinate<T>>()
+        } else {
+            0
+        }
+    }
+
+    fn coord_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<T: CoordinateType, const N: usize> HeapSize for geo_types::small::SmallPolygon<T, N> {
+    fn heap_size(&self) -> usize {
+        self.exterior().heap_size()
+            + std::mem::size_of_val(self.interiors())
+            + self
+                .interiors()
+                .iter()
+                .map(HeapSize::heap_size)
+                .sum::<usize>()
+    }
+
+    fn coord_count(&self) -> usize {
+        self.exterior().coord_count() + vec_coord_count(self.interiors())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn primitives_dont_allocate() {
+        assert_eq!(point!(x: 1., y: 2.).heap_size(), 0);
+        assert_eq!(Line::new((0, 0), (1, 1)).coord_count(), 2);
+        assert_eq!(Rect::new((0, 0), (1, 1)).coord_count(), 2);
+        assert_eq!(Triangle::from([(0, 0), (1, 0), (0, 1)]).coord_count(), 3);
+        assert_eq!(point!(x: 1., y: 2.).total_size(), size_of::<Point<f64>>());
+    }
+
+    #[test]
+    fn counts_capacity_recursively() {
+        let mut line_string: LineString<f64> = LineString(Vec::with_capacity(10));
+        line_string.0.push((0., 0.).into());
+        assert_eq!(line_string.coord_count(), 1);
+        assert_eq!(line_string.heap_size(), 10 * size_of::<Coordinate<f64>>());
+
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+        ];
+        assert_eq!(polygon.coord_count(), 8);
+        assert_eq!(
+            polygon.heap_size(),
+            8 * size_of::<Coordinate<f64>>() + size_of::<LineString<f64>>()
+        );
+
+        // cloning a `Vec` doesn't clone its spare capacity
+        let line_string_size = line_string.heap_size();
+        let collection = GeometryCollection(vec![
+            Geometry::LineString(line_string),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(
+                polygon.clone(),
+            )])),
+        ]);
+        assert_eq!(collection.coord_count(), 9);
+        let collection_size = collection.heap_size();
+        assert_eq!(
+            collection_size,
+            3 * size_of::<Geometry<f64>>() + line_string_size + polygon.heap_size()
+        );
+        assert_eq!(
+            Geometry::GeometryCollection(collection).heap_size(),
+            collection_size
+        );
+
+        let multi_point = MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]);
+        assert_eq!(multi_point.coord_count(), 2);
+        assert_eq!(multi_point.heap_size(), 2 * size_of::<Point<f64>>());
+
+        let multi_line_string = MultiLineString(vec![line_string![(x: 0, y: 0), (x: 1, y: 1)]]);
+        assert_eq!(multi_line_string.coord_count(), 2);
+    }
+
+    #[test]
+    fn buffers_and_shared_geometries() {
+        let buffer = CoordBuffer::from_parts(vec![0., 1.], vec![0., 1.]).unwrap();
+        assert_eq!(buffer.coord_count(), 2);
+        assert_eq!(buffer.heap_size(), 4 * size_of::<f64>());
+
+        let shared = SharedGeometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        assert_eq!(shared.coord_count(), 2);
+        assert!(shared.heap_size() > shared.as_line_string().unwrap().heap_size());
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn small_geometries() {
+        use geo_types::small::{SmallLineString, SmallPolygon};
+
+        let rect: SmallPolygon<f64> = Rect::new((0., 0.), (1., 1.)).into();
+        assert_eq!(rect.heap_size(), 0);
+        assert_eq!(rect.coord_count(), 5);
+        let spilled: SmallLineString<f64, 1> = vec![(0., 0.), (1., 1.)].into();
+        assert_eq!(spilled.heap_size(), 2 * size_of::<Coordinate<f64>>());
+    }
+}
@@ -0,0 +1,436 @@
+//! Bin geometries into a hierarchical grid of hexagons, with each cell named by a 64-bit index,
+//! in the style of [H3](https://h3geo.org/).
+//!
+//! Unlike H3, the grid is laid out in the plane of the coordinates, rather than on a sphere: its
+//! hexagons are regular in the units of `x` and `y`, and pointy-topped, with a vertex pointing
+//! along `y`. The cell at the origin is centered on it. At resolution `0` each hexagon's
+//! vertices are [`size(0)`](fn.size.html) = 64 units from its center, and the size halves with
+//! each finer resolution, down to [`MAX_RESOLUTION`](constant.MAX_RESOLUTION.html). For
+//! longitudes and latitudes, cells are about a degree across at resolution 7, and about 100
+//! metres across (near the equator) at resolution 17.
+//!
+//! Cells are found using only the basic arithmetic operations and square roots, which are
+//! exactly rounded, so the same point is always in the same cell on every platform. The grid
+//! only uses `core`, `alloc`, and `num_traits::Float`, not `std`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::hex_grid::{HexCell, Polyfill};
+//! use geo::{point, polygon};
+//!
+//! let cell = HexCell::from_point(&point!(x: 8.54, y: 47.37), 10).unwrap();
+//! assert_eq!(HexCell::from_index(cell.index()), Some(cell));
+//! assert_eq!(cell.k_ring(1).len(), 7);
+//!
+//! let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+//! let cells = square.polyfill(8);
+//! assert!(cells.iter().all(|cell| cell.resolution() == 8));
+//! ```
+use crate::algorithm::contains::Contains;
+use crate::{Coordinate, LineString, MultiPolygon, Point, Polygon};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_traits::Float;
+
+/// The finest resolution of the grid.
+pub const MAX_RESOLUTION: u8 = 24;
+
+// The bits of an index holding each axial coordinate
+const COORD_BITS: u32 = 29;
+const COORD_MASK: u64 = (1 << COORD_BITS) - 1;
+
+/// The distance from the center of a cell to each of its vertices, at a resolution.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::hex_grid::size;
+///
+/// assert_eq!(size::<f64>(0), 64.);
+/// assert_eq!(size::<f64>(7), 0.5);
+/// ```
+pub fn size<T: Float>(resolution: u8) -> T {
+    let two = T::one() + T::one();
+    two.powi(6 - resolution as i32)
+}
+
+/// A hexagonal cell of the grid, at a resolution.
+///
+/// The cell's position is given by its axial coordinates `q` and `r`: moving one cell along
+/// `x` increases `q`, and moving one row along `y` increases `r`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexCell {
+    resolution: u8,
+    q: i32,
+    r: i32,
+}
+
+impl HexCell {
+    /// Return the cell at the given axial coordinates, or `None` if the resolution is finer than
+    /// [`MAX_RESOLUTION`](constant.MAX_RESOLUTION.html), or a coordinate doesn't fit in an
+    /// index.
+    pub fn new(resolution: u8, q: i32, r: i32) -> Option<HexCell> {
+        let limit = 1 << (COORD_BITS - 1);
+        let fits = |value: i32| -limit <= value && value < limit;
+        if resolution > MAX_RESOLUTION || !fits(q) || !fits(r) {
+            return None;
+        }
+        Some(HexCell { resolution, q, r })
+    }
+
+    /// Return the cell holding `point` at `resolution`, or `None` if the resolution is finer
+    /// than [`MAX_RESOLUTION`](constant.MAX_RESOLUTION.html), or the point is too far from the
+    /// origin to be indexed.
+    ///
+    /// A point on the boundary between cells is in the cell whose center is nearest, with ties
+    /// broken by the rounding of its fractional axial coordinates.
+    pub fn from_point<T: Float>(point: &Point<T>, resolution: u8) -> Option<HexCell> {
+        if resolution > MAX_RESOLUTION {
+            return None;
+        }
+        let three = T::from(3).unwrap();
+        let size = size::<T>(resolution);
+        let (x, y) = (point.x() / size, point.y() / size);
+        // fractional cube coordinates, rounded to the nearest cell
+        let q = (three.sqrt() * x - y) / three;
+        let r = (y + y) / three;
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        HexCell::new(resolution, rq.to_i32()?, rr.to_i32()?)
+    }
+
+    /// Return the cell named by an index, or `None` if it isn't a valid index.
+    pub fn from_index(index: u64) -> Option<HexCell> {
+        let resolution = index >> (2 * COORD_BITS);
+        if resolution > MAX_RESOLUTION as u64 {
+            return None;
+        }
+        // sign-extend each coordinate from its bits
+        let unpack = |bits: u64| ((bits << (64 - COORD_BITS)) as i64 >> (64 - COORD_BITS)) as i32;
+        let q = unpack((index >> COORD_BITS) & COORD_MASK);
+        let r = unpack(index & COORD_MASK);
+        HexCell::new(resolution as u8, q, r)
+    }
+
+    /// The index naming this cell, holding its resolution in the top bits, followed by its
+    /// axial coordinates.
+    pub fn index(&self) -> u64 {
+        ((self.resolution as u64) << (2 * COORD_BITS))
+            | ((self.q as u64 & COORD_MASK) << COORD_BITS)
+            | (self.r as u64 & COORD_MASK)
+    }
+
+    pub fn resolution(&self) -> u8 {
+        self.resolution
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    /// The center of the cell.
+    pub fn center<T: Float>(&self) -> Point<T> {
+        let size = size::<T>(self.resolution);
+        let three = T::from(3).unwrap();
+        let two = T::one() + T::one();
+        let (q, r) = (T::from(self.q).unwrap(), T::from(self.r).unwrap());
+        Point::new(size * three.sqrt() * (q + r / two), size * three / two * r)
+    }
+
+    /// The hexagon covered by the cell, as a `Polygon` with a counter-clockwise exterior,
+    /// starting from its vertex pointing along `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::hex_grid::HexCell;
+    /// use geo::algorithm::area::Area;
+    /// use geo::point;
+    ///
+    /// let cell = HexCell::from_point(&point!(x: 0., y: 0.), 6).unwrap();
+    /// let hexagon = cell.boundary::<f64>();
+    ///
+    /// assert_eq!(hexagon.exterior().0.len(), 7);
+    /// assert!((hexagon.signed_area() - 1.5 * 3f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn boundary<T: Float>(&self) -> Polygon<T> {
+        let center = self.center::<T>();
+        let size = size::<T>(self.resolution);
+        let half = size / (T::one() + T::one());
+        let across = half * T::from(3).unwrap().sqrt();
+        let offsets = [
+            (T::zero(), size),
+            (-across, half),
+            (-across, -half),
+            (T::zero(), -size),
+            (across, -half),
+            (across, half),
+            (T::zero(), size),
+        ];
+        let ring: Vec<Coordinate<T>> = offsets
+            .iter()
+            .map(|&(dx, dy)| Coordinate {
+                x: center.x() + dx,
+                y: center.y() + dy,
+            })
+            .collect();
+        Polygon::new(LineString(ring), Vec::new())
+    }
+
+    /// The number of steps between this cell and `other`, moving between neighboring cells,
+    /// or `None` if they're at different resolutions.
+    pub fn grid_distance(&self, other: &HexCell) -> Option<u32> {
+        if self.resolution != other.resolution {
+            return None;
+        }
+        let dq = other.q as i64 - self.q as i64;
+        let dr = other.r as i64 - self.r as i64;
+        Some(((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as u32)
+    }
+
+    /// Return the cells within `k` steps of this one, including itself, ordered by `q`, then
+    /// `r`. Cells which can't be indexed, at the edges of the grid, are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::hex_grid::HexCell;
+    ///
+    /// let cell = HexCell::new(10, 0, 0).unwrap();
+    /// let ring = cell.k_ring(2);
+    ///
+    /// assert_eq!(ring.len(), 19);
+    /// assert!(ring.iter().all(|other| cell.grid_distance(other).unwrap() <= 2));
+    /// ```
+    pub fn k_ring(&self, k: u32) -> Vec<HexCell> {
+        let k = k as i64;
+        let mut cells = Vec::new();
+        for dq in -k..=k {
+            for dr in (-k).max(-dq - k)..=k.min(-dq + k) {
+                let (q, r) = (self.q as i64 + dq, self.r as i64 + dr);
+                if let (Ok(q), Ok(r)) = (i32::try_from(q), i32::try_from(r)) {
+                    if let Some(cell) = HexCell::new(self.resolution, q, r) {
+                        cells.push(cell);
+                    }
+                }
+            }
+        }
+        cells
+    }
+
+    /// The cell at the next coarser resolution holding this cell's center, or `None` at
+    /// resolution `0`.
+    ///
+    /// Hexagons can't be divided exactly into smaller ones, so a cell's parent is chosen by its
+    /// center, and the edges of a parent don't follow the edges of its children.
+    pub fn parent(&self) -> Option<HexCell> {
+        let resolution = self.resolution.checked_sub(1)?;
+        HexCell::from_point(&self.center::<f64>(), resolution)
+    }
+}
+
+/// Cover an area with the cells of the [hexagonal grid](index.html) whose centers are inside
+/// it, as H3's `polyfill` does.
+pub trait Polyfill<T: Float> {
+    /// Return the cells at `resolution` whose centers are inside the area, ordered by `r`, then
+    /// `q`, without duplicates. Centers on the boundary of the area are left out, and an area
+    /// smaller than a cell may have no cells at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::hex_grid::Polyfill;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: -1., y: -1.), (x: 1., y: -1.), (x: 1., y: 1.), (x: -1., y: 1.)];
+    /// let cells = square.polyfill(6);
+    ///
+    /// // the only center inside is the origin's
+    /// assert_eq!(cells.len(), 1);
+    /// assert_eq!((cells[0].q(), cells[0].r()), (0, 0));
+    /// ```
+    fn polyfill(&self, resolution: u8) -> Vec<HexCell>;
+}
+
+impl<T: Float> Polyfill<T> for Polygon<T> {
+    fn polyfill(&self, resolution: u8) -> Vec<HexCell> {
+        let mut cells = Vec::new();
+        let rect = match self.bounding_rect() {
+            Some(rect) if resolution <= MAX_RESOLUTION => rect,
+            _ => return cells,
+        };
+        let size = size::<T>(resolution);
+        let three = T::from(3).unwrap();
+        let two = T::one() + T::one();
+        let (row_height, column_width) = (size * three / two, size * three.sqrt());
+        let rows = match (
+            (rect.min().y / row_height).floor().to_i64(),
+            (rect.max().y / row_height).ceil().to_i64(),
+        ) {
+            (Some(min), Some(max)) => min..=max,
+            _ => return cells,
+        };
+        for r in rows {
+            // the columns of the row whose centers are within the bounding rectangle
+            let offset = T::from(r).unwrap() / two;
+            let columns = match (
+                (rect.min().x / column_width - offset).floor().to_i64(),
+                (rect.max().x / column_width - offset).ceil().to_i64(),
+            ) {
+                (Some(min), Some(max)) => min..=max,
+                _ => continue,
+            };
+            for q in columns {
+                let cell = match (i32::try_from(q), i32::try_from(r)) {
+                    (Ok(q), Ok(r)) => HexCell::new(resolution, q, r),
+                    _ => None,
+                };
+                if let Some(cell) = cell {
+                    if self.contains(&cell.center()) {
+                        cells.push(cell);
+                    }
+                }
+            }
+        }
+        cells
+    }
+}
+
+impl<T: Float> Polyfill<T> for MultiPolygon<T> {
+    fn polyfill(&self, resolution: u8) -> Vec<HexCell> {
+        let mut cells: Vec<HexCell> = self
+            .0
+            .iter()
+            .flat_map(|polygon| polygon.polyfill(resolution))
+            .collect();
+        cells.sort_by_key(|cell| (cell.r, cell.q));
+        cells.dedup();
+        cells
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::{point, polygon};
+
+    #[test]
+    fn points_are_in_the_nearest_cell() {
+        // deterministic pseudo-random points
+        let mut seed = 12345u64;
+        let mut random = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((seed >> 33) % 100_000) as f64 / 100. - 500.
+        };
+        for resolution in &[0, 3, 9] {
+            for _ in 0..500 {
+                let point = point!(x: random(), y: random());
+                let cell = HexCell::from_point(&point, *resolution).unwrap();
+                let distance = point.euclidean_distance(&cell.center());
+                // no neighbor's center is nearer, and the point is inside the hexagon
+                for other in cell.k_ring(1) {
+                    assert!(distance <= point.euclidean_distance(&other.center()) + 1e-9);
+                }
+                assert!(distance <= size::<f64>(*resolution) + 1e-9);
+                let hexagon = cell.boundary::<f64>();
+                assert!(hexagon.contains(&point) || hexagon.exterior().contains(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn indices_round_trip() {
+        let cells = [
+            HexCell::new(0, 0, 0).unwrap(),
+            HexCell::new(24, -1, 1).unwrap(),
+            HexCell::new(12, (1 << 28) - 1, -(1 << 28)).unwrap(),
+        ];
+        for cell in &cells {
+            assert_eq!(HexCell::from_index(cell.index()), Some(*cell));
+        }
+        assert_eq!(HexCell::new(25, 0, 0), None);
+        assert_eq!(HexCell::new(0, 1 << 28, 0), None);
+        assert_eq!(HexCell::from_index(u64::MAX), None);
+        assert_eq!(HexCell::from_point(&point!(x: 1e30, y: 0.), 24), None);
+        assert_eq!(HexCell::from_point(&point!(x: f64::NAN, y: 0.), 0), None);
+    }
+
+    #[test]
+    fn rings_and_parents() {
+        let cell = HexCell::new(5, 3, -2).unwrap();
+        for k in 0..4 {
+            let ring = cell.k_ring(k);
+            assert_eq!(ring.len() as u32, 3 * k * (k + 1) + 1);
+            let mut sorted = ring.clone();
+            sorted.sort();
+            assert_eq!(ring, sorted);
+        }
+        // neighbors share an edge
+        let hexagon = cell.boundary::<f64>();
+        for other in cell.k_ring(1) {
+            if other != cell {
+                let shared = other
+                    .boundary::<f64>()
+                    .exterior()
+                    .0
+                    .iter()
+                    .filter(|coord| {
+                        hexagon
+                            .exterior()
+                            .0
+                            .iter()
+                            .any(|c| (c.x - coord.x).hypot(c.y - coord.y) < 1e-9)
+                    })
+                    .count();
+                assert!(shared >= 2);
+            }
+        }
+        let parent = cell.parent().unwrap();
+        assert_eq!(parent.resolution(), 4);
+        assert!(parent.center::<f64>().euclidean_distance(&cell.center()) <= size::<f64>(4));
+        assert_eq!(HexCell::new(0, 0, 0).unwrap().parent(), None);
+        assert_eq!(cell.grid_distance(&HexCell::new(5, 0, 0).unwrap()), Some(3));
+        assert_eq!(cell.grid_distance(&parent), None);
+    }
+
+    #[test]
+    fn polyfill_covers_the_area() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 40., y: 0.), (x: 40., y: 30.), (x: 0., y: 30.)],
+            interiors: [[(x: 10., y: 10.), (x: 30., y: 10.), (x: 30., y: 20.), (x: 10., y: 20.)]],
+        ];
+        let resolution = 8;
+        let cells = polygon.polyfill(resolution);
+        // each cell covers 1.5 √3 size², so together they cover about the polygon's area
+        let cell_area = cells[0].boundary::<f64>().signed_area();
+        let covered = cells.len() as f64 * cell_area;
+        assert!((covered - polygon.signed_area()).abs() < 0.05 * polygon.signed_area());
+        for cell in &cells {
+            assert!(polygon.contains(&cell.center()));
+        }
+        // including every cell whose center is inside
+        let brute_force = (-200..200)
+            .flat_map(|q| (-200..200).map(move |r| HexCell::new(resolution, q, r).unwrap()))
+            .filter(|cell| polygon.contains(&cell.center()))
+            .count();
+        assert_eq!(cells.len(), brute_force);
+
+        let multi_polygon = MultiPolygon(vec![polygon.clone(), polygon]);
+        assert_eq!(multi_polygon.polyfill(resolution), cells);
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert!(empty.polyfill(0).is_empty());
+    }
+}
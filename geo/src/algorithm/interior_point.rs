@@ -0,0 +1,161 @@
+use crate::{LineString, MultiPolygon, Point, Polygon};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// Find a point strictly inside an area, for placing labels or picking a representative point
+/// of a shape.
+///
+/// Unlike the [centroid](../centroid/trait.Centroid.html), which can be outside a concave
+/// shape, or in one of its holes, the point is always in the interior of the shape.
+pub trait InteriorPoint<T: Float> {
+    /// Return a point in the interior of the area, or `None` if there's no area.
+    ///
+    /// A horizontal line is drawn through the middle of the shape, at a height between its
+    /// vertices, and the point is the middle of the widest part of that line inside the shape.
+    /// For a `MultiPolygon`, that's the widest part of any of its `Polygon`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::centroid::Centroid;
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::interior_point::InteriorPoint;
+    /// use geo::{point, polygon};
+    ///
+    /// // a "C" shape, whose centroid is outside it
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 4., y: 0.),
+    ///     (x: 4., y: 1.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 1., y: 3.),
+    ///     (x: 4., y: 3.),
+    ///     (x: 4., y: 4.),
+    ///     (x: 0., y: 4.),
+    /// ];
+    /// assert!(!polygon.contains(&polygon.centroid().unwrap()));
+    ///
+    /// let interior_point = polygon.interior_point().unwrap();
+    /// assert_eq!(interior_point, point!(x: 0.5, y: 2.));
+    /// assert!(polygon.contains(&interior_point));
+    /// ```
+    fn interior_point(&self) -> Option<Point<T>>;
+}
+
+impl<T: Float> InteriorPoint<T> for Polygon<T> {
+    fn interior_point(&self) -> Option<Point<T>> {
+        widest_interior_interval(self).map(|(_, point)| point)
+    }
+}
+
+impl<T: Float> InteriorPoint<T> for MultiPolygon<T> {
+    fn interior_point(&self) -> Option<Point<T>> {
+        self.0
+            .iter()
+            .filter_map(widest_interior_interval)
+            .fold(
+                None,
+                |widest: Option<(T, Point<T>)>, interval| match widest {
+                    Some(widest) if widest.0 >= interval.0 => Some(widest),
+                    _ => Some(interval),
+                },
+            )
+            .map(|(_, point)| point)
+    }
+}
+
+// The width and middle of the widest interval inside a polygon, along a horizontal line which
+// doesn't pass through any of its vertices
+fn widest_interior_interval<T: Float>(polygon: &Polygon<T>) -> Option<(T, Point<T>)> {
+    let bounds = polygon.exterior().bounding_rect()?;
+    let two = T::one() + T::one();
+    let middle = (bounds.min().y + bounds.max().y) / two;
+    // the closest vertex heights at and below, and above, the middle
+    let (mut below, mut above) = (bounds.min().y, bounds.max().y);
+    let rings = || std::iter::once(polygon.exterior()).chain(polygon.interiors());
+    for coord in rings().flat_map(|ring| &ring.0) {
+        if coord.y <= middle && coord.y > below {
+            below = coord.y;
+        } else if coord.y > middle && coord.y < above {
+            above = coord.y;
+        }
+    }
+    let y = (below + above) / two;
+    if y <= bounds.min().y || y >= bounds.max().y {
+        return None;
+    }
+
+    let mut crossings: Vec<T> = rings().flat_map(|ring| crossings(ring, y)).collect();
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mut widest: Option<(T, Point<T>)> = None;
+    for pair in crossings.chunks_exact(2) {
+        let width = pair[1] - pair[0];
+        if width > T::zero() && !matches!(widest, Some((widest, _)) if widest >= width) {
+            widest = Some((width, Point::new((pair[0] + pair[1]) / two, y)));
+        }
+    }
+    widest
+}
+
+// The x coordinates at which the edges of a ring cross the horizontal line at `y`
+fn crossings<T: Float>(ring: &LineString<T>, y: T) -> impl Iterator<Item = T> + '_ {
+    ring.lines()
+        .filter(move |line| (line.start.y > y) != (line.end.y > y))
+        .map(move |line| line.start.x + (y - line.start.y) * line.dx() / line.dy())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::centroid::Centroid;
+    use crate::algorithm::contains::Contains;
+    use crate::{point, polygon};
+
+    #[test]
+    fn hole_at_the_centroid() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 5.), (x: 4., y: 5.), (x: 4., y: 1.)]],
+        ];
+        assert!(!polygon.contains(&polygon.centroid().unwrap()));
+        // the line at y = 3 is inside from 0 to 1, and from 4 to 6
+        assert_eq!(polygon.interior_point(), Some(point!(x: 5., y: 3.)));
+    }
+
+    #[test]
+    fn scan_line_between_vertices() {
+        // vertices at the middle height, which the line mustn't pass through
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 2.5),
+            (x: 0., y: 4.),
+        ];
+        let point = polygon.interior_point().unwrap();
+        assert_eq!(point.y(), 2.25);
+        assert!(polygon.contains(&point));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let small = polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 1.)];
+        let large = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let multi_polygon = MultiPolygon(vec![small.clone(), large]);
+        assert_eq!(multi_polygon.interior_point(), Some(point!(x: 2., y: 2.)));
+        let point = small.interior_point().unwrap();
+        assert!(small.contains(&point));
+    }
+
+    #[test]
+    fn without_area() {
+        let flat = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(flat.interior_point(), None);
+        let line = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 2., y: 2.)];
+        assert_eq!(line.interior_point(), None);
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.interior_point(), None);
+        assert_eq!(MultiPolygon::<f64>(vec![]).interior_point(), None);
+    }
+}
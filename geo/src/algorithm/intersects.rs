@@ -1,9 +1,21 @@
+use crate::algorithm::bounding_rect::BoundingRect;
 use crate::algorithm::contains::Contains;
-use crate::{Line, LineString, Point, Polygon, Rect};
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 use num_traits::Float;
 
 /// Checks if the geometry A intersects the geometry B.
-
+///
+/// Two geometries intersect if they have at least one point in common, including the points on
+/// their boundaries, so a polygon intersects the points of its rings, but not the points in its
+/// holes. Empty geometries intersect nothing.
+///
+/// This is implemented for every pair of geometry types. Segments are compared using robust
+/// orientation predicates, and the bounding rectangles of both geometries (and of their members
+/// or segments) are compared first, so that disjoint geometries are rejected cheaply.
 pub trait Intersects<Rhs = Self> {
     /// Checks if the geometry A intersects the geometry B.
     ///
@@ -71,30 +83,47 @@ where
     T: Float,
 {
     fn intersects(&self, line: &Line<T>) -> bool {
-        // Using Cramer's Rule:
-        // https://en.wikipedia.org/wiki/Intersection_%28Euclidean_geometry%29#Two_line_segments
-        let a1 = self.dx();
-        let a2 = self.dy();
-        let b1 = -line.dx();
-        let b2 = -line.dy();
-        let c1 = line.start.x - self.start.x;
-        let c2 = line.start.y - self.start.y;
-
-        let d = a1 * b2 - a2 * b1;
-        if d == T::zero() {
-            let (self_start, self_end) = self.points();
-            let (other_start, other_end) = line.points();
-            // lines are parallel
-            // return true iff at least one endpoint intersects the other line
-            self_start.intersects(line)
-                || self_end.intersects(line)
-                || other_start.intersects(self)
-                || other_end.intersects(self)
-        } else {
-            let s = (c1 * b2 - c2 * b1) / d;
-            let t = (a1 * c2 - a2 * c1) / d;
-            (T::zero() <= s) && (s <= T::one()) && (T::zero() <= t) && (t <= T::one())
-        }
+        // The segments intersect iff each one's endpoints are on different sides of the other
+        // (using a robust orientation predicate), or an endpoint of one lies on the other
+        let o1 = orient2d(self.start, self.end, line.start);
+        let o2 = orient2d(self.start, self.end, line.end);
+        let o3 = orient2d(line.start, line.end, self.start);
+        let o4 = orient2d(line.start, line.end, self.end);
+        let collinear = Orientation::Collinear;
+        (o1 != o2 && o3 != o4 && ![o1, o2, o3, o4].contains(&collinear))
+            || (o1 == collinear && in_line_box(self, line.start))
+            || (o2 == collinear && in_line_box(self, line.end))
+            || (o3 == collinear && in_line_box(line, self.start))
+            || (o4 == collinear && in_line_box(line, self.end))
+    }
+}
+
+// Whether `c` is inside the bounding box of `line`, which for a coordinate collinear with the
+// line means that it's on the line
+fn in_line_box<T>(line: &Line<T>, c: Coordinate<T>) -> bool
+where
+    T: Float,
+{
+    c.x >= line.start.x.min(line.end.x)
+        && c.x <= line.start.x.max(line.end.x)
+        && c.y >= line.start.y.min(line.end.y)
+        && c.y <= line.start.y.max(line.end.y)
+}
+
+// Whether the bounding rectangles of `a` and `b` intersect, which is false if either is empty
+fn bounding_rects_intersect<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Float,
+    A: BoundingRect<T>,
+    B: BoundingRect<T>,
+    A::Output: Into<Option<Rect<T>>>,
+    B::Output: Into<Option<Rect<T>>>,
+{
+    let a: Option<Rect<T>> = a.bounding_rect().into();
+    let b: Option<Rect<T>> = b.bounding_rect().into();
+    match (a, b) {
+        (Some(a), Some(b)) => a.intersects(&b),
+        _ => false,
     }
 }
 
@@ -121,6 +150,9 @@ where
     T: Float,
 {
     fn intersects(&self, p: &Polygon<T>) -> bool {
+        if !bounding_rects_intersect(self, p) {
+            return false;
+        }
         p.exterior().intersects(self)
             || p.interiors().iter().any(|inner| inner.intersects(self))
             || p.contains(&self.start_point())
@@ -141,31 +173,16 @@ impl<T> Intersects<LineString<T>> for LineString<T>
 where
     T: Float,
 {
-    // See: https://github.com/brandonxiang/geojson-python-utils/blob/33b4c00c6cf27921fb296052d0c0341bd6ca1af2/geojson_utils.py
     fn intersects(&self, linestring: &LineString<T>) -> bool {
-        if self.0.is_empty() || linestring.0.is_empty() {
+        if !bounding_rects_intersect(self, linestring) {
             return false;
         }
-        for a in self.lines() {
-            for b in linestring.lines() {
-                let u_b = b.dy() * a.dx() - b.dx() * a.dy();
-                if u_b == T::zero() {
-                    continue;
-                }
-                let ua_t = b.dx() * (a.start.y - b.start.y) - b.dy() * (a.start.x - b.start.x);
-                let ub_t = a.dx() * (a.start.y - b.start.y) - a.dy() * (a.start.x - b.start.x);
-                let u_a = ua_t / u_b;
-                let u_b = ub_t / u_b;
-                if (T::zero() <= u_a)
-                    && (u_a <= T::one())
-                    && (T::zero() <= u_b)
-                    && (u_b <= T::one())
-                {
-                    return true;
-                }
-            }
-        }
-        false
+        self.lines().any(|a| {
+            let a_rect = a.bounding_rect();
+            linestring
+                .lines()
+                .any(|b| a_rect.intersects(&b.bounding_rect()) && a.intersects(&b))
+        })
     }
 }
 
@@ -174,6 +191,9 @@ where
     T: Float,
 {
     fn intersects(&self, linestring: &LineString<T>) -> bool {
+        if !bounding_rects_intersect(self, linestring) {
+            return false;
+        }
         // line intersects inner or outer polygon edge
         if self.exterior().intersects(linestring)
             || self
@@ -198,45 +218,15 @@ where
     }
 }
 
-// helper function for intersection check
-fn value_in_range<T>(value: T, min: T, max: T) -> bool
-where
-    T: Float,
-{
-    (value >= min) && (value <= max)
-}
-
 impl<T> Intersects<Rect<T>> for Rect<T>
 where
     T: Float,
 {
     fn intersects(&self, bounding_rect: &Rect<T>) -> bool {
-        // line intersects inner or outer polygon edge
-        if bounding_rect.contains(self) {
-            false
-        } else {
-            let x_overlap = value_in_range(
-                self.min().x,
-                bounding_rect.min().x,
-                bounding_rect.min().x + bounding_rect.width(),
-            ) || value_in_range(
-                bounding_rect.min().x,
-                self.min().x,
-                self.min().x + self.width(),
-            );
-
-            let y_overlap = value_in_range(
-                self.min().y,
-                bounding_rect.min().y,
-                bounding_rect.min().y + bounding_rect.height(),
-            ) || value_in_range(
-                bounding_rect.min().y,
-                self.min().y,
-                self.min().y + self.height(),
-            );
-
-            x_overlap && y_overlap
-        }
+        self.min().x <= bounding_rect.max().x
+            && bounding_rect.min().x <= self.max().x
+            && self.min().y <= bounding_rect.max().y
+            && bounding_rect.min().y <= self.max().y
     }
 }
 
@@ -273,6 +263,9 @@ where
     T: Float,
 {
     fn intersects(&self, polygon: &Polygon<T>) -> bool {
+        if !bounding_rects_intersect(self, polygon) {
+            return false;
+        }
         // self intersects (or contains) any line in polygon
         self.intersects(polygon.exterior()) ||
             polygon.interiors().iter().any(|inner_line_string| self.intersects(inner_line_string)) ||
@@ -281,10 +274,259 @@ where
     }
 }
 
+impl<T> Intersects<Point<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self == p
+    }
+}
+
+impl<T> Intersects<Point<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        match self.0.len() {
+            0 => false,
+            1 => self.0[0] == p.0,
+            _ => self.lines().any(|line| line.intersects(p)),
+        }
+    }
+}
+
+impl<T> Intersects<Point<T>> for Polygon<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self.exterior().intersects(p)
+            || self.interiors().iter().any(|inner| inner.intersects(p))
+            || self.contains(p)
+    }
+}
+
+impl<T> Intersects<Point<T>> for Rect<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self.contains(p)
+    }
+}
+
+impl<T> Intersects<Point<T>> for Triangle<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        let orientations = [
+            orient2d(self.0, self.1, p.0),
+            orient2d(self.1, self.2, p.0),
+            orient2d(self.2, self.0, p.0),
+        ];
+        if orientations.iter().all(|o| *o == Orientation::Collinear) {
+            // a degenerate triangle, whose points are those between its extreme vertices
+            return self.bounding_rect().contains(p);
+        }
+        !(orientations.contains(&Orientation::Clockwise)
+            && orientations.contains(&Orientation::CounterClockwise))
+    }
+}
+
+fn rect_to_polygon<T: CoordinateType>(rect: &Rect<T>) -> Polygon<T> {
+    Polygon::from(*rect)
+}
+
+fn triangle_to_polygon<T: CoordinateType>(triangle: &Triangle<T>) -> Polygon<T> {
+    Polygon::from(*triangle)
+}
+
+macro_rules! impl_intersects_as_polygon {
+    ($type:ident, $to_polygon:ident => [$($rhs:ident),*]) => {
+        $(
+            impl<T> Intersects<$rhs<T>> for $type<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    bounding_rects_intersect(self, rhs) && $to_polygon(self).intersects(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_as_polygon!(Rect, rect_to_polygon => [Line, LineString, Triangle]);
+impl_intersects_as_polygon!(Triangle, triangle_to_polygon => [Line, LineString, Polygon, Triangle]);
+
+macro_rules! symmetric_intersects_impl {
+    ($(($type:ident, $rhs:ident)),*) => {
+        $(
+            impl<T> Intersects<$rhs<T>> for $type<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    rhs.intersects(self)
+                }
+            }
+        )*
+    };
+}
+
+symmetric_intersects_impl!(
+    (Point, LineString),
+    (Point, Polygon),
+    (Point, Rect),
+    (Point, Triangle),
+    (Line, Rect),
+    (Line, Triangle),
+    (LineString, Rect),
+    (LineString, Triangle),
+    (Polygon, Triangle),
+    (Triangle, Rect)
+);
+
+// A multi-geometry intersects another geometry if one of its members does
+macro_rules! impl_intersects_multi {
+    ($multi:ident => [$($rhs:ident),*]) => {
+        $(
+            impl<T> Intersects<$rhs<T>> for $multi<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    bounding_rects_intersect(self, rhs)
+                        && self.0.iter().any(|member| member.intersects(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_multi!(MultiPoint => [
+    Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle
+]);
+impl_intersects_multi!(MultiLineString => [
+    Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle
+]);
+impl_intersects_multi!(MultiPolygon => [
+    Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle
+]);
+
+symmetric_intersects_impl!(
+    (Point, MultiPoint),
+    (Point, MultiLineString),
+    (Point, MultiPolygon),
+    (Line, MultiPoint),
+    (Line, MultiLineString),
+    (Line, MultiPolygon),
+    (LineString, MultiPoint),
+    (LineString, MultiLineString),
+    (LineString, MultiPolygon),
+    (Polygon, MultiPoint),
+    (Polygon, MultiLineString),
+    (Polygon, MultiPolygon),
+    (Rect, MultiPoint),
+    (Rect, MultiLineString),
+    (Rect, MultiPolygon),
+    (Triangle, MultiPoint),
+    (Triangle, MultiLineString),
+    (Triangle, MultiPolygon)
+);
+
+macro_rules! impl_intersects_geometry {
+    ($($rhs:ident),*) => {
+        $(
+            impl<T> Intersects<$rhs<T>> for Geometry<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    match self {
+                        Geometry::Point(g) => g.intersects(rhs),
+                        Geometry::Line(g) => g.intersects(rhs),
+                        Geometry::LineString(g) => g.intersects(rhs),
+                        Geometry::Polygon(g) => g.intersects(rhs),
+                        Geometry::MultiPoint(g) => g.intersects(rhs),
+                        Geometry::MultiLineString(g) => g.intersects(rhs),
+                        Geometry::MultiPolygon(g) => g.intersects(rhs),
+                        Geometry::GeometryCollection(g) => g.intersects(rhs),
+                        Geometry::Rect(g) => g.intersects(rhs),
+                        Geometry::Triangle(g) => g.intersects(rhs),
+                    }
+                }
+            }
+
+            impl<T> Intersects<$rhs<T>> for GeometryCollection<T>
+            where
+                T: Float,
+            {
+                fn intersects(&self, rhs: &$rhs<T>) -> bool {
+                    bounding_rects_intersect(self, rhs)
+                        && self.0.iter().any(|geometry| geometry.intersects(rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+symmetric_intersects_impl!(
+    (Point, Geometry),
+    (Point, GeometryCollection),
+    (Line, Geometry),
+    (Line, GeometryCollection),
+    (LineString, Geometry),
+    (LineString, GeometryCollection),
+    (Polygon, Geometry),
+    (Polygon, GeometryCollection),
+    (MultiPoint, Geometry),
+    (MultiPoint, GeometryCollection),
+    (MultiLineString, Geometry),
+    (MultiLineString, GeometryCollection),
+    (MultiPolygon, Geometry),
+    (MultiPolygon, GeometryCollection),
+    (Rect, Geometry),
+    (Rect, GeometryCollection),
+    (Triangle, Geometry),
+    (Triangle, GeometryCollection)
+);
+
 #[cfg(test)]
 mod test {
     use crate::algorithm::intersects::Intersects;
-    use crate::{line_string, polygon, Coordinate, Line, LineString, Point, Polygon, Rect};
+    use crate::{
+        line_string, polygon, Coordinate, Geometry, GeometryCollection, Line, LineString,
+        MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    };
+
+    #[test]
+    fn nearly_collinear_lines() {
+        // passes just below (12, 12)
+        let line = Line::new((0.5, 0.5), (28.46686479390819, 28.466864793908186));
+        assert!(!line.intersects(&Line::new((12., 12.), (12., 13.))));
+        assert!(line.intersects(&Line::new((12., 11.), (12., 12.))));
+        assert!(line.intersects(&Line::new(
+            (28.46686479390819, 28.466864793908186),
+            (30., 0.)
+        )));
+    }
 
     /// Tests: intersection LineString and LineString
     #[test]
@@ -541,10 +783,10 @@ mod test {
         let bounding_rect_s2 =
             Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 20., y: 30. });
         // confirmed using GEOS
-        assert_eq!(true, bounding_rect_xl.intersects(&bounding_rect_sm));
-        assert_eq!(false, bounding_rect_sm.intersects(&bounding_rect_xl));
-        assert_eq!(true, bounding_rect_sm.intersects(&bounding_rect_s2));
-        assert_eq!(true, bounding_rect_s2.intersects(&bounding_rect_sm));
+        assert!(bounding_rect_xl.intersects(&bounding_rect_sm));
+        assert!(bounding_rect_sm.intersects(&bounding_rect_xl));
+        assert!(bounding_rect_sm.intersects(&bounding_rect_s2));
+        assert!(bounding_rect_s2.intersects(&bounding_rect_sm));
     }
     #[test]
     fn point_intersects_line_test() {
@@ -665,4 +907,95 @@ mod test {
         assert!(a.intersects(&b));
         assert!(b.intersects(&a));
     }
+    #[test]
+    fn collinear_overlapping_linestrings() {
+        let ls1 = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let ls2 = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+        let ls3 = line_string![(x: 2.5, y: 2.5), (x: 3., y: 3.)];
+        assert!(ls1.intersects(&ls2));
+        assert!(ls2.intersects(&ls1));
+        assert!(!ls1.intersects(&ls3));
+    }
+    #[test]
+    fn point_intersects_polygon_and_linestring() {
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.), (x: 1., y: 1.)]],
+        ];
+        // interior, exterior ring, interior ring, hole, outside
+        assert!(poly.intersects(&Point::new(0.5, 0.5)));
+        assert!(Point::new(4., 2.).intersects(&poly));
+        assert!(poly.intersects(&Point::new(1., 2.)));
+        assert!(!poly.intersects(&Point::new(2., 2.)));
+        assert!(!poly.intersects(&Point::new(5., 5.)));
+
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert!(ls.intersects(&Point::new(2., 1.)));
+        assert!(!ls.intersects(&Point::new(1., 1.)));
+        assert!(line_string![(x: 1., y: 1.)].intersects(&Point::new(1., 1.)));
+        assert!(Point::new(1., 1.).intersects(&Point::new(1., 1.)));
+        assert!(!Point::new(1., 1.).intersects(&Point::new(1., 2.)));
+    }
+    #[test]
+    fn rect_and_triangle_intersections() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 2. });
+        let triangle = Triangle::from([(3., 0.), (5., 0.), (3., 2.)]);
+        assert!(rect.intersects(&Point::new(2., 1.)));
+        assert!(triangle.intersects(&Point::new(4., 1.)));
+        assert!(!triangle.intersects(&Point::new(4.5, 1.5)));
+        assert!(!rect.intersects(&triangle));
+        assert!(!triangle.intersects(&rect));
+        assert!(rect.intersects(&Line::from([(1., 1.), (5., 1.)])));
+        assert!(Line::from([(2.5, 1.), (5., 1.)]).intersects(&triangle));
+        assert!(!Line::from([(2.5, 3.), (5., 3.)]).intersects(&triangle));
+        // wholly inside
+        assert!(rect.intersects(&line_string![(x: 0.5, y: 0.5), (x: 1., y: 1.)]));
+        assert!(triangle.intersects(&Triangle::from([(3.5, 0.5), (4., 0.5), (3.5, 1.)])));
+        // touching at a vertex
+        let other = Rect::new(Coordinate { x: 4., y: 1. }, Coordinate { x: 6., y: 3. });
+        assert!(triangle.intersects(&other));
+        assert!(other.intersects(&triangle));
+    }
+    #[test]
+    fn multi_geometry_intersections() {
+        let polygon = |x: f64| polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.), (x: x, y: 0.)];
+        let multi_polygon = MultiPolygon(vec![polygon(0.), polygon(10.)]);
+        let multi_point = MultiPoint(vec![Point::new(5., 0.5), Point::new(10.5, 0.5)]);
+        let multi_line_string = MultiLineString(vec![
+            line_string![(x: 3., y: 0.), (x: 3., y: 1.)],
+            line_string![(x: 6., y: 0.), (x: 6., y: 1.)],
+        ]);
+        assert!(multi_polygon.intersects(&multi_point));
+        assert!(multi_point.intersects(&multi_polygon));
+        assert!(!multi_polygon.intersects(&multi_line_string));
+        assert!(!multi_line_string.intersects(&multi_polygon));
+        assert!(multi_line_string.intersects(&Line::from([(0., 0.5), (4., 0.5)])));
+        assert!(!multi_point.intersects(&MultiPoint(vec![Point::new(10., 0.5)])));
+        assert!(polygon(5.5).intersects(&multi_line_string));
+        assert!(!MultiPolygon::<f64>(vec![]).intersects(&polygon(0.)));
+    }
+    #[test]
+    fn geometry_collection_intersections() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::LineString(line_string![(x: 10., y: 0.), (x: 10., y: 10.)]),
+        ]);
+        let line = Line::from([(5., 5.), (15., 5.)]);
+        assert!(collection.intersects(&line));
+        assert!(line.intersects(&collection));
+        assert!(collection.intersects(&Point::new(0., 0.)));
+        assert!(!collection.intersects(&Point::new(5., 5.)));
+
+        let rect = Geometry::Rect(Rect::new(
+            Coordinate { x: -1., y: -1. },
+            Coordinate { x: 1., y: 1. },
+        ));
+        assert!(rect.intersects(&collection));
+        assert!(collection.intersects(&rect));
+        assert!(Geometry::GeometryCollection(collection.clone()).intersects(&rect));
+
+        let far = GeometryCollection(vec![Geometry::Point(Point::new(20., 20.))]);
+        assert!(!collection.intersects(&far));
+        assert!(!collection.intersects(&GeometryCollection(vec![])));
+    }
 }
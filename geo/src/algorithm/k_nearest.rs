@@ -0,0 +1,327 @@
+use crate::{Coordinate, Geometry, GeometryCollection, MultiPoint, Point};
+use num_traits::Float;
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+
+/// Find the `k` points of a geometry nearest to a point, using a [k-d tree].
+///
+/// Query a geometry through its [`k_nearest_index`](#method.k_nearest_index), which builds the
+/// tree of its points on the first query and reuses it for the rest, or build the tree once
+/// with `kd_tree` and query it with [`KdTree::k_nearest`](struct.KdTree.html#method.k_nearest).
+///
+/// The points of a `MultiPoint` are its members, and those of a `GeometryCollection` are its
+/// `Point` geometries; its other geometries are skipped. Points with a NaN coordinate are
+/// never returned.
+///
+/// [k-d tree]: https://en.wikipedia.org/wiki/K-d_tree
+pub trait KNearest<T: Float> {
+    /// Build a k-d tree of the points of the geometry, for answering many queries without
+    /// scanning every point each time.
+    ///
+    /// Building the tree takes `O(n log n)` time, after which each query usually visits
+    /// `O(k + log n)` points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::k_nearest::KNearest;
+    /// use geo::{point, MultiPoint};
+    ///
+    /// let participants: MultiPoint<f64> = (0..100).map(|i| (i as f64, 0.)).collect();
+    /// let tree = participants.kd_tree();
+    ///
+    /// for meetup in &[point!(x: 10.2, y: 1.), point!(x: 80.6, y: -1.)] {
+    ///     assert_eq!(tree.k_nearest(meetup, 5).len(), 5);
+    /// }
+    /// assert_eq!(tree.k_nearest(&point!(x: 10.2, y: 1.), 1)[0].0, 10);
+    /// ```
+    fn kd_tree(&self) -> KdTree<T>;
+
+    /// Borrow the geometry as an index for finding the points nearest to others, which builds
+    /// its [`KdTree`](struct.KdTree.html) when it's first queried, and keeps it for the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::k_nearest::KNearest;
+    /// use geo::{point, MultiPoint};
+    ///
+    /// let points = MultiPoint(vec![
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 5., y: 5.),
+    ///     point!(x: 3., y: 4.),
+    /// ]);
+    /// let index = points.k_nearest_index();
+    ///
+    /// assert_eq!(
+    ///     index.k_nearest(&point!(x: 0., y: 0.), 2),
+    ///     vec![(0, 0.), (2, 5.)]
+    /// );
+    /// assert_eq!(index.k_nearest(&point!(x: 6., y: 6.), 1), vec![(1, 2_f64.sqrt())]);
+    /// ```
+    fn k_nearest_index(&self) -> KNearestIndex<'_, T, Self> {
+        KNearestIndex {
+            geometry: self,
+            tree: OnceCell::new(),
+        }
+    }
+}
+
+impl<T: Float> KNearest<T> for MultiPoint<T> {
+    fn kd_tree(&self) -> KdTree<T> {
+        KdTree::from_indexed(self.0.iter().map(|point| point.0).enumerate())
+    }
+}
+
+impl<T: Float> KNearest<T> for GeometryCollection<T> {
+    fn kd_tree(&self) -> KdTree<T> {
+        KdTree::from_indexed(
+            self.0
+                .iter()
+                .enumerate()
+                .filter_map(|(i, geometry)| match geometry {
+                    Geometry::Point(point) => Some((i, point.0)),
+                    _ => None,
+                }),
+        )
+    }
+}
+
+/// A geometry whose [`KdTree`](struct.KdTree.html) is built by the first query, and reused by
+/// the rest, created by [`KNearest::k_nearest_index`](trait.KNearest.html#method.k_nearest_index).
+pub struct KNearestIndex<'a, T: Float, G: KNearest<T> + ?Sized> {
+    geometry: &'a G,
+    tree: OnceCell<KdTree<T>>,
+}
+
+impl<'a, T: Float, G: KNearest<T> + ?Sized> KNearestIndex<'a, T, G> {
+    /// Return the positions in the geometry of the `k` points nearest to `point`, or of all of
+    /// them if there are fewer, with their Euclidean distances from it, ordered by distance,
+    /// then position.
+    pub fn k_nearest(&self, point: &Point<T>, k: usize) -> Vec<(usize, T)> {
+        self.tree().k_nearest(point, k)
+    }
+
+    /// The tree of the geometry's points, building it if it hasn't been yet.
+    pub fn tree(&self) -> &KdTree<T> {
+        self.tree.get_or_init(|| self.geometry.kd_tree())
+    }
+}
+
+/// A balanced [k-d tree] of points, for finding the points nearest to others, built by
+/// [`KNearest::kd_tree`](trait.KNearest.html#tymethod.kd_tree) or from a slice of `Point`s.
+///
+/// [k-d tree]: https://en.wikipedia.org/wiki/K-d_tree
+#[derive(Clone, Debug)]
+pub struct KdTree<T: Float> {
+    /// The points and their positions, arranged so that the median of each range (by `x` at
+    /// even depths, and `y` at odd ones) is at its middle, with the points before it in its
+    /// first half
+    nodes: Vec<(Coordinate<T>, usize)>,
+}
+
+// Order by the coordinate along an axis, then by position, so that building the tree is
+// deterministic
+fn compare_on<T: Float>(
+    axis: usize,
+    a: &(Coordinate<T>, usize),
+    b: &(Coordinate<T>, usize),
+) -> Ordering {
+    let value = |node: &(Coordinate<T>, usize)| if axis == 0 { node.0.x } else { node.0.y };
+    value(a)
+        .partial_cmp(&value(b))
+        .unwrap_or(Ordering::Equal)
+        .then(a.1.cmp(&b.1))
+}
+
+fn build<T: Float>(nodes: &mut [(Coordinate<T>, usize)], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let middle = nodes.len() / 2;
+    nodes.select_nth_unstable_by(middle, |a, b| compare_on(depth % 2, a, b));
+    let (before, after) = nodes.split_at_mut(middle);
+    build(before, depth + 1);
+    build(&mut after[1..], depth + 1);
+}
+
+impl<T: Float> KdTree<T> {
+    /// Build a tree of `points`, whose positions are their indices in the slice.
+    pub fn new(points: &[Point<T>]) -> Self {
+        KdTree::from_indexed(points.iter().map(|point| point.0).enumerate())
+    }
+
+    fn from_indexed<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, Coordinate<T>)>,
+    {
+        let mut nodes: Vec<(Coordinate<T>, usize)> = points
+            .into_iter()
+            .filter(|(_, coord)| !coord.x.is_nan() && !coord.y.is_nan())
+            .map(|(i, coord)| (coord, i))
+            .collect();
+        build(&mut nodes, 0);
+        KdTree { nodes }
+    }
+
+    /// The number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the tree has no points.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Return the positions of the `k` points nearest to `point`, or of all of them if there
+    /// are fewer, with their Euclidean distances from it, ordered by distance, then position.
+    pub fn k_nearest(&self, point: &Point<T>, k: usize) -> Vec<(usize, T)> {
+        // the (squared distance, position) of the nearest points found so far, in order
+        let mut nearest = Vec::with_capacity(k.min(self.nodes.len()) + 1);
+        if k > 0 {
+            search(&self.nodes, 0, point.0, k, &mut nearest);
+        }
+        nearest
+            .into_iter()
+            .map(|(distance_2, i)| (i, distance_2.sqrt()))
+            .collect()
+    }
+}
+
+fn search<T: Float>(
+    nodes: &[(Coordinate<T>, usize)],
+    depth: usize,
+    target: Coordinate<T>,
+    k: usize,
+    nearest: &mut Vec<(T, usize)>,
+) {
+    if nodes.is_empty() {
+        return;
+    }
+    let middle = nodes.len() / 2;
+    let (coord, i) = nodes[middle];
+    let (dx, dy) = (target.x - coord.x, target.y - coord.y);
+    let candidate = (dx * dx + dy * dy, i);
+    let position = nearest
+        .iter()
+        .position(|&(distance_2, j)| {
+            candidate.0 < distance_2 || (candidate.0 == distance_2 && candidate.1 < j)
+        })
+        .unwrap_or(nearest.len());
+    if position < k {
+        nearest.insert(position, candidate);
+        nearest.truncate(k);
+    }
+
+    // search the side of the split holding the target first, then the other side if it could
+    // hold points at least as near as the furthest found so far
+    let offset = if depth % 2 == 1 { dy } else { dx };
+    let (before, after) = (&nodes[..middle], &nodes[middle + 1..]);
+    let (near, far) = if offset < T::zero() {
+        (before, after)
+    } else {
+        (after, before)
+    };
+    search(near, depth + 1, target, k, nearest);
+    if nearest.len() < k || offset * offset <= nearest[nearest.len() - 1].0 {
+        search(far, depth + 1, target, k, nearest);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    // deterministic pseudo-random coordinates, between 0 and 100 in steps of 0.1, so that
+    // there are many ties
+    fn random_points(count: usize) -> Vec<Point<f64>> {
+        let mut seed = 12345u64;
+        let mut random = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((seed >> 33) % 1000) as f64 / 10.
+        };
+        (0..count)
+            .map(|_| point!(x: random(), y: random()))
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = random_points(3000);
+        let multi_point = MultiPoint(points.clone());
+        let tree = multi_point.kd_tree();
+        assert_eq!(tree.len(), 3000);
+        for (query, k) in random_points(100).iter().zip([1, 2, 7, 50].iter().cycle()) {
+            let mut expected: Vec<(usize, f64)> = points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (i, (p.x() - query.x()).hypot(p.y() - query.y())))
+                .collect();
+            expected.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+            expected.truncate(*k);
+            let found = tree.k_nearest(query, *k);
+            assert_eq!(
+                found.iter().map(|&(i, _)| i).collect::<Vec<_>>(),
+                expected.iter().map(|&(i, _)| i).collect::<Vec<_>>()
+            );
+            for (&(_, a), &(_, b)) in found.iter().zip(&expected) {
+                assert_relative_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates_and_small_trees() {
+        let points = MultiPoint(vec![point!(x: 1., y: 1.); 5]);
+        let tree = points.kd_tree();
+        let found = tree.k_nearest(&point!(x: 1., y: 1.), 3);
+        assert_eq!(found, vec![(0, 0.), (1, 0.), (2, 0.)]);
+        assert_eq!(tree.k_nearest(&point!(x: 0., y: 0.), 10).len(), 5);
+        assert!(tree.k_nearest(&point!(x: 0., y: 0.), 0).is_empty());
+
+        let empty = MultiPoint::<f64>(vec![]);
+        assert!(empty.kd_tree().is_empty());
+        assert!(empty
+            .kd_tree()
+            .k_nearest(&point!(x: 0., y: 0.), 3)
+            .is_empty());
+
+        let with_nan = MultiPoint(vec![point!(x: f64::NAN, y: 0.), point!(x: 4., y: 3.)]);
+        assert_eq!(
+            with_nan.kd_tree().k_nearest(&point!(x: 0., y: 0.), 2),
+            vec![(1, 5.)]
+        );
+    }
+
+    #[test]
+    fn geometry_collections_use_their_points() {
+        let collection = GeometryCollection(vec![
+            point!(x: 10., y: 0.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            point!(x: 3., y: 4.).into(),
+        ]);
+        assert_eq!(
+            collection.kd_tree().k_nearest(&point!(x: 0., y: 0.), 5),
+            vec![(2, 5.), (0, 10.)]
+        );
+        let tree = KdTree::new(&[point!(x: 1., y: 0.), point!(x: 0., y: 2.)]);
+        assert_eq!(tree.k_nearest(&point!(x: 0., y: 0.), 1), vec![(0, 1.)]);
+    }
+
+    #[test]
+    fn index_builds_its_tree_once() {
+        let points = MultiPoint(random_points(100));
+        let index = points.k_nearest_index();
+        assert!(index.tree.get().is_none());
+
+        let tree = points.kd_tree();
+        for point in random_points(10) {
+            assert_eq!(index.k_nearest(&point, 3), tree.k_nearest(&point, 3));
+        }
+        let built: *const KdTree<f64> = index.tree();
+        assert!(std::ptr::eq(built, index.tree()));
+        assert_eq!(index.tree().len(), 100);
+    }
+}
@@ -0,0 +1,294 @@
+use crate::algorithm::interior_point::InteriorPoint;
+use crate::algorithm::minimum_bounding_circle::Circle;
+use crate::{Coordinate, LineString, MultiPolygon, Point, Polygon, Rect};
+use geo_types::private_utils::line_segment_distance;
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Find the largest circle inside an area, whose center is the pole of inaccessibility: the
+/// point inside the area furthest from its boundary. It's a better place for a label than the
+/// centroid or an [interior point](../interior_point/trait.InteriorPoint.html), as the label has
+/// the most room around it.
+pub trait LargestInscribedCircle<T: Float> {
+    /// Return the largest circle inside the area, to within `tolerance`, or `None` if there's no
+    /// area.
+    ///
+    /// This uses the "polylabel" algorithm: starting from a square cell covering the bounding
+    /// rectangle, the cells which could contain a better center than the best found so far are
+    /// split into quarters, until none could improve the radius by more than `tolerance`. A
+    /// `tolerance` which isn't positive, or is too small for the precision of `T`, is raised to
+    /// a small fraction of the size of the area. At most 65536 cells are split, so the circle
+    /// found in a very thin area, along which every cell could improve on it, may be further than
+    /// `tolerance` from the largest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::largest_inscribed_circle::LargestInscribedCircle;
+    /// use geo::polygon;
+    ///
+    /// // a "C" shape, whose largest circle is in an outer corner, touching an inner corner
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 6., y: 0.),
+    ///     (x: 6., y: 2.),
+    ///     (x: 2., y: 2.),
+    ///     (x: 2., y: 4.),
+    ///     (x: 6., y: 4.),
+    ///     (x: 6., y: 6.),
+    ///     (x: 0., y: 6.),
+    /// ];
+    ///
+    /// let circle = polygon.largest_inscribed_circle(0.01_f64).unwrap();
+    /// let radius = 4. - 2. * 2_f64.sqrt();
+    /// assert!((circle.center.x() - radius).abs() < 0.01);
+    /// assert!((circle.radius - radius).abs() < 0.01);
+    /// ```
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<Circle<T>>;
+}
+
+impl<T: Float> LargestInscribedCircle<T> for Polygon<T> {
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<Circle<T>> {
+        let rings: Vec<&LineString<T>> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        polylabel(
+            &rings,
+            self.exterior().bounding_rect()?,
+            self.interior_point()?,
+            tolerance,
+        )
+    }
+}
+
+impl<T: Float> LargestInscribedCircle<T> for MultiPolygon<T> {
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<Circle<T>> {
+        let rings: Vec<&LineString<T>> = self
+            .0
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        let bounds = MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| Polygon::new(polygon.exterior().clone(), vec![]))
+                .collect(),
+        )
+        .bounding_rect()?;
+        polylabel(&rings, bounds, self.interior_point()?, tolerance)
+    }
+}
+
+// A square cell, ordered by the greatest distance from the boundary of any point in it
+struct Cell<T: Float> {
+    center: Coordinate<T>,
+    half_size: T,
+    distance: T,
+    max_distance: T,
+}
+
+impl<T: Float> Cell<T> {
+    fn new(center: Coordinate<T>, half_size: T, rings: &[&LineString<T>]) -> Self {
+        let distance = signed_distance(center, rings);
+        Cell {
+            center,
+            half_size,
+            distance,
+            max_distance: distance + half_size * (T::one() + T::one()).sqrt(),
+        }
+    }
+}
+
+impl<T: Float> PartialEq for Cell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance == other.max_distance
+    }
+}
+
+impl<T: Float> Eq for Cell<T> {}
+
+impl<T: Float> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Cell<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance
+            .partial_cmp(&other.max_distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// The most cells which are split, bounding the time taken by areas much longer than they are wide
+const MAX_SPLITS: usize = 1 << 16;
+
+fn polylabel<T: Float>(
+    rings: &[&LineString<T>],
+    bounds: Rect<T>,
+    start: Point<T>,
+    tolerance: T,
+) -> Option<Circle<T>> {
+    let two = T::one() + T::one();
+    let (width, height) = (bounds.width(), bounds.height());
+    if !(width.is_finite() && height.is_finite()) || width.min(height) <= T::zero() {
+        return None;
+    }
+    let cell_size = width.max(height);
+    let tolerance = tolerance.max(cell_size * T::epsilon().sqrt());
+
+    // start from one cell covering the bounds, so that thin areas aren't covered with a great
+    // many cells of their width
+    let mut best = Cell::new(start.0, T::zero(), rings);
+    let mut cells = BinaryHeap::new();
+    let center = Coordinate {
+        x: bounds.min().x + width / two,
+        y: bounds.min().y + height / two,
+    };
+    cells.push(Cell::new(center, cell_size / two, rings));
+
+    let mut splits = 0;
+    while let Some(cell) = cells.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(cell.center, T::zero(), rings);
+        }
+        if cell.max_distance - best.distance <= tolerance {
+            // the cells are popped in decreasing order of their greatest distance
+            break;
+        }
+        if splits == MAX_SPLITS {
+            break;
+        }
+        let half_size = cell.half_size / two;
+        if cell.center.x + half_size == cell.center.x || cell.center.y + half_size == cell.center.y
+        {
+            // the cell is too small to split at this magnitude
+            continue;
+        }
+        splits += 1;
+        for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)].iter() {
+            let center = Coordinate {
+                x: cell.center.x + half_size * T::from(*dx).unwrap(),
+                y: cell.center.y + half_size * T::from(*dy).unwrap(),
+            };
+            cells.push(Cell::new(center, half_size, rings));
+        }
+    }
+
+    Some(Circle {
+        center: Point(best.center),
+        radius: best.distance,
+    })
+}
+
+// The distance from a coordinate to the nearest ring, positive inside the rings, and negative
+// outside
+fn signed_distance<T: Float>(coord: Coordinate<T>, rings: &[&LineString<T>]) -> T {
+    let mut inside = false;
+    let mut distance = T::infinity();
+    for ring in rings {
+        for line in ring.lines() {
+            if (line.start.y > coord.y) != (line.end.y > coord.y)
+                && coord.x < line.start.x + (coord.y - line.start.y) * line.dx() / line.dy()
+            {
+                inside = !inside;
+            }
+            distance = distance.min(line_segment_distance(
+                Point(coord),
+                line.start_point(),
+                line.end_point(),
+            ));
+        }
+    }
+    if inside {
+        distance
+    } else {
+        -distance
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn square() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let circle = polygon.largest_inscribed_circle(1e-6).unwrap();
+        assert_relative_eq!(circle.center.x(), 2., epsilon = 1e-6);
+        assert_relative_eq!(circle.center.y(), 2., epsilon = 1e-6);
+        assert_relative_eq!(circle.radius, 2., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn triangle() {
+        // the incircle of a 3-4-5 triangle has radius 1, centered at (1, 1)
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)];
+        let circle = polygon.largest_inscribed_circle(1e-4).unwrap();
+        assert!(circle.radius > 1. - 1e-4 && circle.radius <= 1.);
+        assert_relative_eq!(circle.center.x(), 1., epsilon = 1e-2);
+        assert_relative_eq!(circle.center.y(), 1., epsilon = 1e-2);
+    }
+
+    #[test]
+    fn holes_and_multi_polygons() {
+        // a square ring, whose circle is in its wider side
+        let ring = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 9.), (x: 6., y: 9.), (x: 6., y: 1.)]],
+        ];
+        let circle = ring.largest_inscribed_circle(1e-3).unwrap();
+        assert!(circle.radius > 2. - 1e-3 && circle.radius <= 2.);
+        assert_relative_eq!(circle.center.x(), 8., epsilon = 1e-3);
+
+        let small = polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.), (x: 20., y: 1.)];
+        let multi_polygon = MultiPolygon(vec![small, ring]);
+        let circle = multi_polygon.largest_inscribed_circle(1e-3).unwrap();
+        assert!(circle.radius > 2. - 1e-3 && circle.radius <= 2.);
+        assert!(circle.center.x() < 10.);
+    }
+
+    #[test]
+    fn without_area() {
+        let flat = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(flat.largest_inscribed_circle(0.1), None);
+        assert_eq!(
+            MultiPolygon::<f64>(vec![]).largest_inscribed_circle(0.1),
+            None
+        );
+        // a tolerance of zero still finishes
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)];
+        let circle = polygon.largest_inscribed_circle(0.).unwrap();
+        assert_relative_eq!(circle.radius, 1., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn thin_rectangles() {
+        for &length in &[1e7, 1e9] {
+            let polygon = polygon![
+                (x: 0., y: 0.), (x: length, y: 0.), (x: length, y: 1.), (x: 0., y: 1.)
+            ];
+            let circle = polygon.largest_inscribed_circle(1e-3).unwrap();
+            assert_relative_eq!(circle.radius, 0.5, epsilon = 1e-3);
+            assert_relative_eq!(circle.center.y(), 0.5, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn non_finite_bounds() {
+        let polygon = polygon![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 0., y: 3.)];
+        assert_eq!(polygon.largest_inscribed_circle(0.1), None);
+        let polygon = polygon![(x: 0., y: 0.), (x: f64::INFINITY, y: 0.), (x: 0., y: 3.)];
+        assert_eq!(polygon.largest_inscribed_circle(0.1), None);
+
+        // a square so far from the origin that its cells can't be split much
+        let polygon = polygon![
+            (x: 1e15, y: 0.), (x: 1e15 + 4., y: 0.), (x: 1e15 + 4., y: 4.), (x: 1e15, y: 4.)
+        ];
+        let circle = polygon.largest_inscribed_circle(0.).unwrap();
+        assert_relative_eq!(circle.radius, 2., epsilon = 1e-6);
+    }
+}
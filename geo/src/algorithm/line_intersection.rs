@@ -0,0 +1,440 @@
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::{Coordinate, CoordinateType, Line};
+
+/// How two `Line`s intersect, as found by [`line_intersection`](fn.line_intersection.html).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineIntersection<T: CoordinateType> {
+    /// The lines meet at a single point. The intersection is proper if it's inside both lines,
+    /// rather than at an endpoint of either of them.
+    SinglePoint {
+        intersection: Coordinate<T>,
+        is_proper: bool,
+    },
+    /// The lines are collinear, and overlap along a part of positive length, running in the
+    /// direction of the first line.
+    Collinear { intersection: Line<T> },
+}
+
+/// Find where two `Line`s intersect, if they do, and classify the intersection.
+///
+/// Lines which cross inside both of them intersect at a proper `SinglePoint`. Lines which only
+/// touch, with an endpoint of one on the other, or which are collinear and share just an
+/// endpoint, intersect at an improper `SinglePoint`, at that endpoint. Collinear lines which
+/// overlap intersect `Collinear`ly, along the part they share. A `Line` whose ends are the same
+/// is treated as a point.
+///
+/// The orientation of each endpoint relative to the other line is decided exactly, using
+/// [robust predicates](../predicates/index.html), so the classification is never wrong due to
+/// rounding; only the position of a proper intersection is rounded, and it's kept within the
+/// bounding boxes of both lines. That position is found in `f64` where the products of
+/// coordinates it needs would overflow `T`, as for large integers.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_intersection::{line_intersection, LineIntersection};
+/// use geo::{Coordinate, Line};
+///
+/// let line = Line::from([(0., 0.), (4., 4.)]);
+///
+/// // crossing inside both lines
+/// assert_eq!(
+///     line_intersection(line, Line::from([(0., 4.), (4., 0.)])),
+///     Some(LineIntersection::SinglePoint {
+///         intersection: Coordinate { x: 2., y: 2. },
+///         is_proper: true,
+///     })
+/// );
+///
+/// // an endpoint of one line touching the other
+/// assert_eq!(
+///     line_intersection(line, Line::from([(1., 1.), (3., 0.)])),
+///     Some(LineIntersection::SinglePoint {
+///         intersection: Coordinate { x: 1., y: 1. },
+///         is_proper: false,
+///     })
+/// );
+///
+/// // collinear lines sharing a segment
+/// assert_eq!(
+///     line_intersection(line, Line::from([(6., 6.), (2., 2.)])),
+///     Some(LineIntersection::Collinear {
+///         intersection: Line::from([(2., 2.), (4., 4.)]),
+///     })
+/// );
+///
+/// assert_eq!(line_intersection(line, Line::from([(1., 0.), (5., 4.)])), None);
+/// ```
+pub fn line_intersection<T: CoordinateType>(a: Line<T>, b: Line<T>) -> Option<LineIntersection<T>> {
+    if !boxes_overlap(&a, &b) {
+        return None;
+    }
+    let collinear = Orientation::Collinear;
+    let o1 = orient2d(a.start, a.end, b.start);
+    let o2 = orient2d(a.start, a.end, b.end);
+    let o3 = orient2d(b.start, b.end, a.start);
+    let o4 = orient2d(b.start, b.end, a.end);
+
+    if o1 == collinear && o2 == collinear && o3 == collinear && o4 == collinear {
+        // the endpoints of each line on the other are the ends of the shared part
+        let mut shared: Vec<Coordinate<T>> = Vec::with_capacity(2);
+        for &(c, line) in &[(b.start, &a), (b.end, &a), (a.start, &b), (a.end, &b)] {
+            if in_box(line, c) && !shared.contains(&c) {
+                shared.push(c);
+            }
+        }
+        return match shared.as_slice() {
+            [] => None,
+            [intersection] => Some(LineIntersection::SinglePoint {
+                intersection: *intersection,
+                is_proper: false,
+            }),
+            [first, second, ..] => {
+                // the points are on `a`, so their order along it is that of one coordinate
+                let before = if a.start.x != a.end.x {
+                    (first.x < second.x) == (a.start.x < a.end.x)
+                } else {
+                    (first.y < second.y) == (a.start.y < a.end.y)
+                };
+                let (start, end) = if before {
+                    (*first, *second)
+                } else {
+                    (*second, *first)
+                };
+                Some(LineIntersection::Collinear {
+                    intersection: Line::new(start, end),
+                })
+            }
+        };
+    }
+
+    for &(o, c, line) in &[
+        (o1, b.start, &a),
+        (o2, b.end, &a),
+        (o3, a.start, &b),
+        (o4, a.end, &b),
+    ] {
+        if o == collinear && in_box(line, c) {
+            return Some(LineIntersection::SinglePoint {
+                intersection: c,
+                is_proper: false,
+            });
+        }
+    }
+    if o1 == o2 || o3 == o4 || [o1, o2, o3, o4].contains(&collinear) {
+        return None;
+    }
+
+    // the lines cross properly, somewhere in the overlap of their bounding boxes
+    let low = Coordinate {
+        x: max(min(a.start.x, a.end.x), min(b.start.x, b.end.x)),
+        y: max(min(a.start.y, a.end.y), min(b.start.y, b.end.y)),
+    };
+    let high = Coordinate {
+        x: min(max(a.start.x, a.end.x), max(b.start.x, b.end.x)),
+        y: min(max(a.start.y, a.end.y), max(b.start.y, b.end.y)),
+    };
+    // finding the crossing multiplies up to three differences of coordinates, each at most
+    // twice the largest coordinate, so it's found in `T` if that can't overflow, with room for
+    // rounding, and in `f64` otherwise
+    let largest = [a.start, a.end, b.start, b.end]
+        .iter()
+        .flat_map(|c| vec![c.x, c.y])
+        .map(|value| value.to_f64().map_or(f64::INFINITY, f64::abs))
+        .fold(0., f64::max);
+    let intersection = if T::from(2. * 16. * largest.powi(3)).is_some() {
+        crossing(a, b, low, high)
+    } else {
+        let to_f64 = |c: Coordinate<T>| Coordinate {
+            x: c.x.to_f64().unwrap(),
+            y: c.y.to_f64().unwrap(),
+        };
+        let crossing = crossing(
+            Line::new(to_f64(a.start), to_f64(a.end)),
+            Line::new(to_f64(b.start), to_f64(b.end)),
+            to_f64(low),
+            to_f64(high),
+        );
+        // rounded back into the overlap, which is in range of `T`
+        let from_f64 =
+            |value: f64, low: T, high: T| min(max(T::from(value).unwrap_or(low), low), high);
+        Coordinate {
+            x: from_f64(crossing.x, low.x, high.x),
+            y: from_f64(crossing.y, low.y, high.y),
+        }
+    };
+    Some(LineIntersection::SinglePoint {
+        intersection,
+        is_proper: true,
+    })
+}
+
+// Where lines which cross properly do, kept between `low` and `high`, the corners of the
+// overlap of their bounding boxes
+fn crossing<T: CoordinateType>(
+    a: Line<T>,
+    b: Line<T>,
+    low: Coordinate<T>,
+    high: Coordinate<T>,
+) -> Coordinate<T> {
+    let (adx, ady) = (a.end.x - a.start.x, a.end.y - a.start.y);
+    let (bdx, bdy) = (b.end.x - b.start.x, b.end.y - b.start.y);
+    // the fraction of the way along `a`, as a numerator and denominator, so that the division
+    // comes last for integer coordinates
+    let numerator = (b.start.x - a.start.x) * bdy - (b.start.y - a.start.y) * bdx;
+    let denominator = adx * bdy - ady * bdx;
+    if denominator == T::zero() {
+        // the lines are too close to parallel for the rounded determinant to tell where they
+        // cross, but it's somewhere in the overlap
+        let two = T::one() + T::one();
+        Coordinate {
+            x: low.x + (high.x - low.x) / two,
+            y: low.y + (high.y - low.y) / two,
+        }
+    } else {
+        // keep the computed point inside the overlap, despite rounding
+        Coordinate {
+            x: min(
+                max(a.start.x + adx * numerator / denominator, low.x),
+                high.x,
+            ),
+            y: min(
+                max(a.start.y + ady * numerator / denominator, low.y),
+                high.y,
+            ),
+        }
+    }
+}
+
+fn min<T: CoordinateType>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: CoordinateType>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn in_box<T: CoordinateType>(line: &Line<T>, c: Coordinate<T>) -> bool {
+    c.x >= min(line.start.x, line.end.x)
+        && c.x <= max(line.start.x, line.end.x)
+        && c.y >= min(line.start.y, line.end.y)
+        && c.y <= max(line.start.y, line.end.y)
+}
+
+fn boxes_overlap<T: CoordinateType>(a: &Line<T>, b: &Line<T>) -> bool {
+    max(a.start.x, a.end.x) >= min(b.start.x, b.end.x)
+        && max(b.start.x, b.end.x) >= min(a.start.x, a.end.x)
+        && max(a.start.y, a.end.y) >= min(b.start.y, b.end.y)
+        && max(b.start.y, b.end.y) >= min(a.start.y, a.end.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn single_point(x: f64, y: f64, is_proper: bool) -> Option<LineIntersection<f64>> {
+        Some(LineIntersection::SinglePoint {
+            intersection: Coordinate { x, y },
+            is_proper,
+        })
+    }
+
+    #[test]
+    fn proper_intersections() {
+        let a = Line::from([(0., 0.), (10., 5.)]);
+        let b = Line::from([(2., 4.), (6., -2.)]);
+        for intersection in &[line_intersection(a, b), line_intersection(b, a)] {
+            match intersection {
+                Some(LineIntersection::SinglePoint {
+                    intersection,
+                    is_proper: true,
+                }) => {
+                    assert_relative_eq!(intersection.x, 3.5);
+                    assert_relative_eq!(intersection.y, 1.75);
+                }
+                other => panic!("expected a proper intersection, found {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn nearly_parallel() {
+        // the lines cross, but they're so close to parallel that the determinant rounds to zero
+        let a = Line::from([
+            (0.30366819691408575, 0.8681279751001347),
+            (3.0963318030859144, 3.731872024899865),
+        ]);
+        let b = Line::from([
+            (-1.7908295077147856, -1.2796800622496631),
+            (5.190829507714786, 5.879680062249663),
+        ]);
+        match line_intersection(a, b) {
+            Some(LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            }) => {
+                assert_relative_eq!(intersection.x, 1.7, epsilon = 1e-6);
+                assert_relative_eq!(intersection.y, 2.3, epsilon = 1e-6);
+            }
+            other => panic!("expected a proper intersection, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn touching() {
+        let a = Line::from([(0., 0.), (4., 0.)]);
+        // an endpoint in the middle of the other line
+        assert_eq!(
+            line_intersection(a, Line::from([(2., 0.), (2., 3.)])),
+            single_point(2., 0., false)
+        );
+        assert_eq!(
+            line_intersection(Line::from([(2., 3.), (2., 0.)]), a),
+            single_point(2., 0., false)
+        );
+        // sharing an endpoint
+        assert_eq!(
+            line_intersection(a, Line::from([(4., 0.), (5., 5.)])),
+            single_point(4., 0., false)
+        );
+        // collinear, sharing only an endpoint
+        assert_eq!(
+            line_intersection(a, Line::from([(4., 0.), (7., 0.)])),
+            single_point(4., 0., false)
+        );
+    }
+
+    #[test]
+    fn collinear_overlaps() {
+        let a = Line::from([(0., 0.), (4., 2.)]);
+        let overlap = |x0, y0, x1, y1| {
+            Some(LineIntersection::Collinear {
+                intersection: Line::from([(x0, y0), (x1, y1)]),
+            })
+        };
+        assert_eq!(
+            line_intersection(a, Line::from([(2., 1.), (6., 3.)])),
+            overlap(2., 1., 4., 2.)
+        );
+        // the overlap runs along the first line
+        assert_eq!(
+            line_intersection(Line::from([(6., 3.), (2., 1.)]), a),
+            overlap(4., 2., 2., 1.)
+        );
+        // one inside the other, and equal lines
+        assert_eq!(
+            line_intersection(a, Line::from([(3., 1.5), (1., 0.5)])),
+            overlap(1., 0.5, 3., 1.5)
+        );
+        assert_eq!(line_intersection(a, a), overlap(0., 0., 4., 2.));
+    }
+
+    #[test]
+    fn disjoint() {
+        let a = Line::from([(0., 0.), (4., 0.)]);
+        assert_eq!(line_intersection(a, Line::from([(0., 1.), (4., 1.)])), None);
+        assert_eq!(line_intersection(a, Line::from([(5., 0.), (7., 0.)])), None);
+        assert_eq!(
+            line_intersection(a, Line::from([(1., 1.), (3., 0.1)])),
+            None
+        );
+        // the line through the other's segment crosses it, but not the segment itself
+        assert_eq!(
+            line_intersection(a, Line::from([(5., -1.), (6., 1.)])),
+            None
+        );
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        let a = Line::from([(0., 0.), (4., 4.)]);
+        let point = Line::from([(1., 1.), (1., 1.)]);
+        assert_eq!(line_intersection(a, point), single_point(1., 1., false));
+        assert_eq!(line_intersection(point, a), single_point(1., 1., false));
+        assert_eq!(line_intersection(point, point), single_point(1., 1., false));
+        assert_eq!(line_intersection(a, Line::from([(1., 2.), (1., 2.)])), None);
+    }
+
+    #[test]
+    fn integers() {
+        let a = Line::from([(0, 0), (4, 4)]);
+        assert_eq!(
+            line_intersection(a, Line::from([(0, 4), (4, 0)])),
+            Some(LineIntersection::SinglePoint {
+                intersection: Coordinate { x: 2, y: 2 },
+                is_proper: true,
+            })
+        );
+        // products of these differences would overflow
+        let a = Line::from([(0, 0), (400_000, 400_000)]);
+        assert_eq!(
+            line_intersection(a, Line::from([(0, 400_000), (400_000, 0)])),
+            Some(LineIntersection::SinglePoint {
+                intersection: Coordinate {
+                    x: 200_000,
+                    y: 200_000
+                },
+                is_proper: true,
+            })
+        );
+    }
+
+    #[test]
+    fn large_integers() {
+        let half = i64::MAX / 2 + 1;
+        let a = Line::from([(-half, -half), (half, half)]);
+        assert_eq!(
+            line_intersection(a, Line::from([(-half, half), (half, -half)])),
+            Some(LineIntersection::SinglePoint {
+                intersection: Coordinate { x: 0, y: 0 },
+                is_proper: true,
+            })
+        );
+        let a = Line::from([(0, 0), (half, half)]);
+        assert_eq!(
+            line_intersection(a, Line::from([(half, 0), (0, half)])),
+            Some(LineIntersection::SinglePoint {
+                intersection: Coordinate {
+                    x: half / 2,
+                    y: half / 2
+                },
+                is_proper: true,
+            })
+        );
+        assert_eq!(
+            line_intersection(Line::from([(half, half), (-half, -half)]), a),
+            Some(LineIntersection::Collinear {
+                intersection: Line::from([(half, half), (0, 0)]),
+            })
+        );
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI64F64;
+        use num_traits::NumCast;
+
+        let fixed = |x: f64| <FixedI64F64 as NumCast>::from(x).unwrap();
+        let a = Line::from([(fixed(0.), fixed(0.)), (fixed(3.), fixed(1.5))]);
+        let b = Line::from([(fixed(0.), fixed(1.5)), (fixed(3.), fixed(0.))]);
+        assert_eq!(
+            line_intersection(a, b),
+            Some(LineIntersection::SinglePoint {
+                intersection: Coordinate {
+                    x: fixed(1.5),
+                    y: fixed(0.75),
+                },
+                is_proper: true,
+            })
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::algorithm::relate::topology_graph::cmp_coords;
+use crate::{Coordinate, CoordinateType, LineString, MultiLineString};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Merge line strings which meet end to end, such as the segments of a road network, into as
+/// few line strings as can be made without branching.
+pub trait LineMerge<T: CoordinateType> {
+    /// Merge the line strings into the longest ones which pass through no branches.
+    ///
+    /// The line strings are nodes of a graph only at their ends: two of them are merged where
+    /// they meet, and nothing else does. Where three or more meet, or one ends on its own, the
+    /// merged line strings end too. Line strings which meet in a loop are merged into a closed
+    /// line string. Line strings meeting other than at their ends, such as where the end of one
+    /// is a vertex in the middle of another, aren't merged there.
+    ///
+    /// Each merged line string runs in the direction most of the line strings it's made of
+    /// run, or if just as many run each way, in the direction of the first of them. They're
+    /// returned in the order of the first line string of each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_merge::LineMerge;
+    /// use geo::{line_string, MultiLineString};
+    ///
+    /// let road = MultiLineString(vec![
+    ///     line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+    ///     // digitized the other way around
+    ///     line_string![(x: 3., y: 1.), (x: 2., y: 0.)],
+    ///     // a junction, with a branch
+    ///     line_string![(x: 3., y: 1.), (x: 4., y: 1.)],
+    ///     line_string![(x: 3., y: 1.), (x: 3., y: 2.)],
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     road.line_merge(),
+    ///     MultiLineString(vec![
+    ///         line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 1.)],
+    ///         line_string![(x: 3., y: 1.), (x: 4., y: 1.)],
+    ///         line_string![(x: 3., y: 1.), (x: 3., y: 2.)],
+    ///     ])
+    /// );
+    /// ```
+    fn line_merge(&self) -> MultiLineString<T>;
+
+    /// Merge the line strings into the longest ones which pass through no branches, as
+    /// `line_merge` does, but only where one ends and the next starts, so the merged line
+    /// strings keep the direction of every one they're made of.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_merge::LineMerge;
+    /// use geo::{line_string, MultiLineString};
+    ///
+    /// let one_way = MultiLineString(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+    ///     line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+    ///     // against the flow
+    ///     line_string![(x: 3., y: 0.), (x: 2., y: 0.)],
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     one_way.line_merge_directed(),
+    ///     MultiLineString(vec![
+    ///         line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)],
+    ///         line_string![(x: 3., y: 0.), (x: 2., y: 0.)],
+    ///     ])
+    /// );
+    /// ```
+    fn line_merge_directed(&self) -> MultiLineString<T>;
+}
+
+impl<T: CoordinateType> LineMerge<T> for [LineString<T>] {
+    fn line_merge(&self) -> MultiLineString<T> {
+        line_merge(self, false)
+    }
+
+    fn line_merge_directed(&self) -> MultiLineString<T> {
+        line_merge(self, true)
+    }
+}
+
+impl<T: CoordinateType> LineMerge<T> for MultiLineString<T> {
+    fn line_merge(&self) -> MultiLineString<T> {
+        line_merge(&self.0, false)
+    }
+
+    fn line_merge_directed(&self) -> MultiLineString<T> {
+        line_merge(&self.0, true)
+    }
+}
+
+fn line_merge<T: CoordinateType>(
+    line_strings: &[LineString<T>],
+    directed: bool,
+) -> MultiLineString<T> {
+    let lines: Vec<&[Coordinate<T>]> = line_strings
+        .iter()
+        .map(|line_string| line_string.0.as_slice())
+        .filter(|coords| coords.len() > 1)
+        .collect();
+    let first = |line: usize| lines[line][0];
+    let last = |line: usize| lines[line][lines[line].len() - 1];
+
+    // the ends of the lines, sorted by their coordinates
+    let mut ends: Vec<(Coordinate<T>, usize)> = (0..lines.len())
+        .flat_map(|line| vec![(first(line), line), (last(line), line)])
+        .collect();
+    ends.sort_by(|a, b| cmp_coords(&a.0, &b.0));
+    let at = |node: Coordinate<T>| -> Range<usize> {
+        let begin = ends.partition_point(|e| cmp_coords(&e.0, &node) == Ordering::Less);
+        let end = ends.partition_point(|e| cmp_coords(&e.0, &node) != Ordering::Greater);
+        begin..end
+    };
+
+    let mut used = vec![false; lines.len()];
+    // the line which continues through `node`, if just two lines end there and the other one
+    // is unused, and whether it starts there
+    let next = |node: Coordinate<T>, used: &mut Vec<bool>| {
+        let range = at(node);
+        if range.len() != 2 {
+            return None;
+        }
+        let line = ends[range].iter().map(|e| e.1).find(|line| !used[*line])?;
+        used[line] = true;
+        Some((line, first(line) == node))
+    };
+
+    let mut merged = vec![];
+    for line in 0..lines.len() {
+        if used[line] {
+            continue;
+        }
+        used[line] = true;
+
+        // each line, and whether it runs from the start of the merged line to the end
+        let mut pieces = VecDeque::new();
+        pieces.push_back((line, true));
+        let mut node = last(line);
+        while let Some((other, starts)) = next(node, &mut used) {
+            if directed && !starts {
+                used[other] = false;
+                break;
+            }
+            pieces.push_back((other, starts));
+            node = if starts { last(other) } else { first(other) };
+        }
+        let mut node = first(line);
+        while let Some((other, starts)) = next(node, &mut used) {
+            if directed && starts {
+                used[other] = false;
+                break;
+            }
+            pieces.push_front((other, !starts));
+            node = if starts { last(other) } else { first(other) };
+        }
+
+        let mut coords: Vec<Coordinate<T>> = vec![];
+        for &(line, forward) in &pieces {
+            // the first coordinate of each line is the last of the one before
+            let skip = if coords.is_empty() { 0 } else { 1 };
+            if forward {
+                coords.extend(lines[line].iter().skip(skip));
+            } else {
+                coords.extend(lines[line].iter().rev().skip(skip));
+            }
+        }
+        let backward = pieces.iter().filter(|(_, forward)| !forward).count();
+        if backward * 2 > pieces.len() {
+            coords.reverse();
+        }
+        merged.push(LineString(coords));
+    }
+    MultiLineString(merged)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn loops() {
+        let triangle = MultiLineString(vec![
+            line_string![(x: 0, y: 0), (x: 1, y: 0)],
+            line_string![(x: 0, y: 1), (x: 0, y: 0)],
+            line_string![(x: 1, y: 0), (x: 0, y: 1)],
+        ]);
+        assert_eq!(
+            triangle.line_merge(),
+            MultiLineString(vec![line_string![
+                (x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1), (x: 0, y: 0)
+            ]])
+        );
+        assert_eq!(triangle.line_merge_directed(), triangle.line_merge());
+
+        // a closed line string on its own, and a lollipop
+        let lines = MultiLineString(vec![
+            line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 0)],
+            line_string![(x: 5, y: 5), (x: 6, y: 5), (x: 6, y: 6), (x: 5, y: 5)],
+            line_string![(x: 4, y: 4), (x: 5, y: 5)],
+        ]);
+        assert_eq!(lines.line_merge(), lines);
+    }
+
+    #[test]
+    fn majority_direction() {
+        let lines = MultiLineString(vec![
+            line_string![(x: 1, y: 0), (x: 0, y: 0)],
+            line_string![(x: 1, y: 0), (x: 2, y: 0)],
+            line_string![(x: 3, y: 0), (x: 2, y: 0)],
+        ]);
+        assert_eq!(
+            lines.line_merge(),
+            MultiLineString(vec![line_string![
+                (x: 3, y: 0), (x: 2, y: 0), (x: 1, y: 0), (x: 0, y: 0)
+            ]])
+        );
+        // just as many each way
+        assert_eq!(
+            lines.0[..2].line_merge(),
+            MultiLineString(vec![line_string![(x: 2, y: 0), (x: 1, y: 0), (x: 0, y: 0)]])
+        );
+        assert_eq!(
+            lines.line_merge_directed(),
+            MultiLineString(vec![
+                line_string![(x: 1, y: 0), (x: 0, y: 0)],
+                line_string![(x: 1, y: 0), (x: 2, y: 0)],
+                line_string![(x: 3, y: 0), (x: 2, y: 0)],
+            ])
+        );
+    }
+
+    #[test]
+    fn directed_chains() {
+        let lines = MultiLineString(vec![
+            line_string![(x: 2, y: 0), (x: 3, y: 0)],
+            line_string![(x: 0, y: 0), (x: 1, y: 0)],
+            line_string![(x: 1, y: 0), (x: 2, y: 0)],
+            // not a line at all
+            LineString(vec![(5, 5).into()]),
+        ]);
+        assert_eq!(
+            lines.line_merge_directed(),
+            MultiLineString(vec![line_string![
+                (x: 0, y: 0), (x: 1, y: 0), (x: 2, y: 0), (x: 3, y: 0)
+            ]])
+        );
+        assert!(MultiLineString::<f64>(vec![]).line_merge().0.is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{Coordinate, LineString, Point};
+use num_traits::Float;
+
+/// Split a `LineString` at a point along it, or extract part of it, measuring positions as
+/// fractions of its planar length.
+///
+/// These return `None` if the `LineString` has no length, or a position isn't on it.
+pub trait LineSplit<T: Float> {
+    /// Split a `LineString` into the parts before and after a fraction of its length.
+    ///
+    /// Returns `None` unless `fraction` is strictly between 0 and 1, as splitting at either end
+    /// would leave a part without length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_split::LineSplit;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+    /// let (before, after) = line_string.split_at_fraction(0.75).unwrap();
+    /// assert_eq!(before, line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)]);
+    /// assert_eq!(after, line_string![(x: 4., y: 2.), (x: 4., y: 4.)]);
+    /// assert!(line_string.split_at_fraction(1.).is_none());
+    /// ```
+    fn split_at_fraction(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)>;
+
+    /// Split a `LineString` at the closest point on it to `point`, if that is within
+    /// `tolerance`.
+    ///
+    /// Both parts contain the closest point, rather than `point` itself, so they stay on the
+    /// original line. If several places are equally close, the first along the line is used.
+    /// Returns `None` if the closest point is further than `tolerance`, or at either end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_split::LineSplit;
+    /// use geo::{line_string, point};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+    /// let (before, after) = line_string
+    ///     .split_at_point(&point!(x: 1., y: 0.1), 0.2)
+    ///     .unwrap();
+    /// assert_eq!(before, line_string![(x: 0., y: 0.), (x: 1., y: 0.)]);
+    /// assert_eq!(after, line_string![(x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)]);
+    /// assert!(line_string.split_at_point(&point!(x: 1., y: 1.), 0.2).is_none());
+    /// ```
+    fn split_at_point(
+        &self,
+        point: &Point<T>,
+        tolerance: T,
+    ) -> Option<(LineString<T>, LineString<T>)>;
+
+    /// Extract the part of a `LineString` between two fractions of its length.
+    ///
+    /// Returns `None` unless `0 <= start_fraction <= end_fraction <= 1`. If the fractions are
+    /// equal, the result has two equal coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_split::LineSplit;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+    /// assert_eq!(
+    ///     line_string.substring(0.25, 0.625).unwrap(),
+    ///     line_string![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.)]
+    /// );
+    /// ```
+    fn substring(&self, start_fraction: T, end_fraction: T) -> Option<LineString<T>>;
+}
+
+// A position on a `LineString`: the index of the segment it's on, and its coordinate
+type Location<T> = (usize, Coordinate<T>);
+
+fn segment_lengths<T: Float>(line_string: &LineString<T>) -> Vec<T> {
+    line_string
+        .0
+        .windows(2)
+        .map(|pair| (pair[1].x - pair[0].x).hypot(pair[1].y - pair[0].y))
+        .collect()
+}
+
+fn total_length<T: Float>(lengths: &[T]) -> Option<T> {
+    let total = lengths
+        .iter()
+        .fold(T::zero(), |total, length| total + *length);
+    if total > T::zero() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+fn lerp<T: Float>(start: Coordinate<T>, end: Coordinate<T>, t: T) -> Coordinate<T> {
+    if t <= T::zero() {
+        start
+    } else if t >= T::one() {
+        end
+    } else {
+        Coordinate {
+            x: start.x + (end.x - start.x) * t,
+            y: start.y + (end.y - start.y) * t,
+        }
+    }
+}
+
+// Find the position a distance along a `LineString`, on the first segment reaching it
+fn locate<T: Float>(line_string: &LineString<T>, lengths: &[T], distance: T) -> Location<T> {
+    let mut travelled = T::zero();
+    for (index, length) in lengths.iter().enumerate() {
+        if *length > T::zero() && travelled + *length >= distance {
+            let t = (distance - travelled) / *length;
+            return (
+                index,
+                lerp(line_string.0[index], line_string.0[index + 1], t),
+            );
+        }
+        travelled = travelled + *length;
+    }
+    // rounding may leave the total length short of the distance to its end
+    (lengths.len() - 1, *line_string.0.last().unwrap())
+}
+
+// Split a `LineString` at a position, unless that leaves a part with a single coordinate
+fn split_at<T: Float>(
+    line_string: &LineString<T>,
+    (index, coord): Location<T>,
+) -> Option<(LineString<T>, LineString<T>)> {
+    let mut before = line_string.0[..=index].to_vec();
+    if before.last() != Some(&coord) {
+        before.push(coord);
+    }
+    let rest = &line_string.0[index + 1..];
+    let rest = if rest.first() == Some(&coord) {
+        &rest[1..]
+    } else {
+        rest
+    };
+    let mut after = vec![coord];
+    after.extend_from_slice(rest);
+    if before.len() < 2 || after.len() < 2 {
+        return None;
+    }
+    Some((LineString(before), LineString(after)))
+}
+
+fn is_fraction<T: Float>(fraction: T) -> bool {
+    fraction >= T::zero() && fraction <= T::one()
+}
+
+impl<T> LineSplit<T> for LineString<T>
+where
+    T: Float,
+{
+    fn split_at_fraction(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)> {
+        let lengths = segment_lengths(self);
+        let total = total_length(&lengths)?;
+        if !is_fraction(fraction) {
+            return None;
+        }
+        split_at(self, locate(self, &lengths, fraction * total))
+    }
+
+    fn split_at_point(
+        &self,
+        point: &Point<T>,
+        tolerance: T,
+    ) -> Option<(LineString<T>, LineString<T>)> {
+        let lengths = segment_lengths(self);
+        total_length(&lengths)?;
+        let mut closest: Option<(T, Location<T>)> = None;
+        for (index, length) in lengths.iter().enumerate() {
+            let (start, end) = (self.0[index], self.0[index + 1]);
+            let t = if *length > T::zero() {
+                let (dx, dy) = (end.x - start.x, end.y - start.y);
+                ((point.x() - start.x) * dx + (point.y() - start.y) * dy) / (*length * *length)
+            } else {
+                T::zero()
+            };
+            let t = t.max(T::zero()).min(T::one());
+            let coord = lerp(start, end, t);
+            let distance = (point.x() - coord.x).hypot(point.y() - coord.y);
+            let is_closer = match closest {
+                Some((closest_distance, _)) => distance < closest_distance,
+                None => true,
+            };
+            if is_closer {
+                closest = Some((distance, (index, coord)));
+            }
+        }
+        let (distance, location) = closest?;
+        if distance > tolerance {
+            return None;
+        }
+        split_at(self, location)
+    }
+
+    fn substring(&self, start_fraction: T, end_fraction: T) -> Option<LineString<T>> {
+        let lengths = segment_lengths(self);
+        let total = total_length(&lengths)?;
+        if !is_fraction(start_fraction)
+            || !is_fraction(end_fraction)
+            || start_fraction > end_fraction
+        {
+            return None;
+        }
+        let (start_index, start) = locate(self, &lengths, start_fraction * total);
+        let (end_index, end) = locate(self, &lengths, end_fraction * total);
+        let mut coords = vec![start];
+        coords.extend(
+            self.0[start_index + 1..=end_index]
+                .iter()
+                .enumerate()
+                .filter(|(i, coord)| !(*i == 0 && **coord == start))
+                .map(|(_, coord)| *coord),
+        );
+        if coords.len() == 1 || coords.last() != Some(&end) {
+            coords.push(end);
+        }
+        Some(LineString(coords))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn split_at_fraction() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let (before, after) = line_string.split_at_fraction(0.5).unwrap();
+        // at a vertex, both parts end there
+        assert_eq!(before, line_string![(x: 0., y: 0.), (x: 2., y: 0.)]);
+        assert_eq!(after, line_string![(x: 2., y: 0.), (x: 2., y: 2.)]);
+        let (before, after) = line_string.split_at_fraction(0.25).unwrap();
+        assert_eq!(before, line_string![(x: 0., y: 0.), (x: 1., y: 0.)]);
+        assert_eq!(
+            after,
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]
+        );
+        assert!(line_string.split_at_fraction(0.).is_none());
+        assert!(line_string.split_at_fraction(1.).is_none());
+        assert!(line_string.split_at_fraction(1.5).is_none());
+        assert!(line_string.split_at_fraction(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = LineString(vec![]);
+        assert!(empty.split_at_fraction(0.5).is_none());
+        assert!(empty.substring(0., 1.).is_none());
+        let point = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert!(point.split_at_fraction(0.5).is_none());
+        assert!(point.split_at_point(&point!(x: 1., y: 1.), 1.).is_none());
+        assert!(point.substring(0., 1.).is_none());
+        // segments without length are skipped
+        let repeated = line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 2., y: 0.)];
+        let (before, after) = repeated.split_at_fraction(0.5).unwrap();
+        assert_eq!(
+            before,
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 0.)]
+        );
+        assert_eq!(after, line_string![(x: 1., y: 0.), (x: 2., y: 0.)]);
+    }
+
+    #[test]
+    fn split_at_point() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let (before, after) = line_string
+            .split_at_point(&point!(x: 2.5, y: -0.5), 1.)
+            .unwrap();
+        assert_eq!(before, line_string![(x: 0., y: 0.), (x: 2., y: 0.)]);
+        assert_eq!(after, line_string![(x: 2., y: 0.), (x: 2., y: 2.)]);
+        let (before, after) = line_string
+            .split_at_point(&point!(x: 1.5, y: 0.5), 1.)
+            .unwrap();
+        // equally close to both segments, so split on the first
+        assert_eq!(before, line_string![(x: 0., y: 0.), (x: 1.5, y: 0.)]);
+        assert_eq!(
+            after,
+            line_string![(x: 1.5, y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]
+        );
+        assert!(line_string
+            .split_at_point(&point!(x: 1.5, y: 0.5), 0.4)
+            .is_none());
+        // the ends don't split
+        assert!(line_string
+            .split_at_point(&point!(x: -1., y: 0.), 2.)
+            .is_none());
+        assert!(line_string
+            .split_at_point(&point!(x: 2., y: 3.), 2.)
+            .is_none());
+        let ring = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 0.)];
+        assert!(ring.split_at_point(&point!(x: 0., y: 0.), 0.).is_none());
+    }
+
+    #[test]
+    fn substring() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert_eq!(line_string.substring(0., 1.).unwrap(), line_string);
+        assert_eq!(
+            line_string.substring(0., 0.5).unwrap(),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)]
+        );
+        assert_eq!(
+            line_string.substring(0.5, 0.75).unwrap(),
+            line_string![(x: 2., y: 0.), (x: 2., y: 1.)]
+        );
+        assert_eq!(
+            line_string.substring(0.25, 0.25).unwrap(),
+            line_string![(x: 1., y: 0.), (x: 1., y: 0.)]
+        );
+        assert!(line_string.substring(0.75, 0.25).is_none());
+        assert!(line_string.substring(-0.5, 0.25).is_none());
+        assert!(line_string.substring(0.5, f64::NAN).is_none());
+    }
+}
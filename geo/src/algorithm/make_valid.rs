@@ -0,0 +1,548 @@
+use crate::algorithm::contains::{get_position, PositionPoint};
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::algorithm::sweep_line::segment_intersections;
+use crate::algorithm::validation::RingRole;
+use crate::{Coordinate, Line, LineString, MultiPolygon, Point, Polygon};
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A change made by [`MakeValid`](trait.MakeValid.html)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Repair<T: Float> {
+    /// An unclosed ring was closed
+    ClosedRing { ring: RingRole },
+    /// This coordinate, which is NaN or infinite, was removed
+    RemovedNonFinitePoint {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A consecutive repetition of this coordinate was removed
+    RemovedDuplicatePoint {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A spike, where the ring turned back on itself at this coordinate, was removed
+    RemovedSpike {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A ring touching or crossing itself at this coordinate was split in two
+    SplitRing {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A ring (or a piece of one) was reversed, so that exteriors are counter-clockwise and
+    /// interiors are clockwise
+    ReorientedRing { ring: RingRole },
+    /// A ring (or a piece of one) without any area was removed
+    DroppedRing { ring: RingRole },
+    /// An interior ring (or a piece of one) that isn't inside any exterior ring was removed
+    DroppedHole { ring: RingRole },
+    /// This change was made to the member at `index` of a `MultiPolygon`
+    Member {
+        index: usize,
+        repair: Box<Repair<T>>,
+    },
+}
+
+impl<T: Float + fmt::Display> fmt::Display for Repair<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Repair::ClosedRing { ring } => write!(f, "closed {}", ring),
+            Repair::RemovedNonFinitePoint { ring, coordinate } => write!(
+                f,
+                "removed a non-finite point ({} {}) from {}",
+                coordinate.x, coordinate.y, ring
+            ),
+            Repair::RemovedDuplicatePoint { ring, coordinate } => write!(
+                f,
+                "removed a repeated point ({} {}) from {}",
+                coordinate.x, coordinate.y, ring
+            ),
+            Repair::RemovedSpike { ring, coordinate } => write!(
+                f,
+                "removed a spike at ({} {}) from {}",
+                coordinate.x, coordinate.y, ring
+            ),
+            Repair::SplitRing { ring, coordinate } => {
+                write!(f, "split {} at ({} {})", ring, coordinate.x, coordinate.y)
+            }
+            Repair::ReorientedRing { ring } => write!(f, "reversed {}", ring),
+            Repair::DroppedRing { ring } => write!(f, "removed a part of {} without area", ring),
+            Repair::DroppedHole { ring } => {
+                write!(f, "removed a part of {} outside the exterior", ring)
+            }
+            Repair::Member { index, repair } => write!(f, "member {}: {}", index, repair),
+        }
+    }
+}
+
+/// Repair common validity problems of a geometry.
+///
+/// Rings are closed, NaN or infinite coordinates, consecutive duplicate coordinates and spikes
+/// are removed, rings that touch
+/// or cross themselves are split into separate rings at those points, rings (or pieces of them)
+/// without any area are dropped, and rings are oriented with counter-clockwise exteriors and
+/// clockwise interiors. Interior rings that end up outside every exterior ring are dropped.
+///
+/// Since splitting an exterior ring produces several polygons, the result is a `MultiPolygon`.
+/// Problems between different rings, such as a hole crossing the exterior, or overlapping
+/// polygons of a `MultiPolygon`, aren't repaired; use
+/// [`Validation`](../validation/trait.Validation.html) to check the result.
+pub trait MakeValid<T: Float> {
+    /// Return the repaired geometry and a list of the changes that were made, which is empty if
+    /// nothing needed repairing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::make_valid::{MakeValid, Repair};
+    /// use geo::algorithm::validation::{RingRole, Validation};
+    /// use geo::{polygon, Coordinate};
+    ///
+    /// // a "bowtie", whose exterior crosses itself at (1, 1)
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+    /// let (repaired, repairs) = polygon.make_valid();
+    ///
+    /// assert_eq!(repaired.0.len(), 2);
+    /// assert!(repaired.is_valid());
+    /// assert!(repairs.contains(&Repair::SplitRing {
+    ///     ring: RingRole::Exterior,
+    ///     coordinate: Coordinate { x: 1., y: 1. },
+    /// }));
+    /// ```
+    fn make_valid(&self) -> (MultiPolygon<T>, Vec<Repair<T>>);
+}
+
+fn signed_area<T: Float>(vertices: &[Coordinate<T>]) -> T {
+    let n = vertices.len();
+    (0..n).fold(T::zero(), |acc, i| {
+        let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+        acc + (a.x * b.y - b.x * a.y)
+    }) / (T::one() + T::one())
+}
+
+/// Remove cyclically-consecutive duplicates, without recording them
+fn dedup_cyclic<T: Float>(vertices: &mut Vec<Coordinate<T>>) {
+    vertices.dedup();
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+}
+
+fn remove_spikes<T: Float>(
+    vertices: &mut Vec<Coordinate<T>>,
+    ring: RingRole,
+    repairs: &mut Vec<Repair<T>>,
+) {
+    while vertices.len() >= 3 {
+        let n = vertices.len();
+        let spike = (0..n).find(|&i| {
+            let (prev, cur, next) = (
+                vertices[(i + n - 1) % n],
+                vertices[i],
+                vertices[(i + 1) % n],
+            );
+            let dot = (cur.x - prev.x) * (next.x - cur.x) + (cur.y - prev.y) * (next.y - cur.y);
+            orient2d(prev, cur, next) == Orientation::Collinear && dot < T::zero()
+        });
+        match spike {
+            Some(i) => {
+                let coordinate = vertices.remove(i);
+                repairs.push(Repair::RemovedSpike { ring, coordinate });
+                dedup_cyclic(vertices);
+            }
+            None => break,
+        }
+    }
+}
+
+/// Add the points where non-adjacent segments of the ring touch or cross as vertices
+fn node<T: Float>(vertices: &[Coordinate<T>]) -> Vec<Coordinate<T>> {
+    let n = vertices.len();
+    let lines: Vec<_> = (0..n)
+        .map(|i| Line::new(vertices[i], vertices[(i + 1) % n]))
+        .collect();
+    let mut inserts: Vec<Vec<Coordinate<T>>> = vec![vec![]; n];
+    for (i, j, intersection) in segment_intersections(&lines) {
+        if j == i + 1 || (i == 0 && j == n - 1) {
+            continue;
+        }
+        let c = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => intersection,
+            _ => continue,
+        };
+        for &k in &[i, j] {
+            if c != lines[k].start && c != lines[k].end {
+                inserts[k].push(c);
+            }
+        }
+    }
+
+    let mut noded = Vec::with_capacity(n);
+    for (line, mut points) in lines.iter().zip(inserts) {
+        let distance = |c: &Coordinate<T>| (c.x - line.start.x).hypot(c.y - line.start.y);
+        points.sort_by(|a, b| {
+            distance(a)
+                .partial_cmp(&distance(b))
+                .unwrap_or(Ordering::Equal)
+        });
+        noded.push(line.start);
+        noded.extend(points);
+    }
+    noded.dedup();
+    noded
+}
+
+/// Split the ring at every repeated vertex, until no vertex is repeated
+fn split<T: Float>(
+    vertices: Vec<Coordinate<T>>,
+    ring: RingRole,
+    repairs: &mut Vec<Repair<T>>,
+    pieces: &mut Vec<Vec<Coordinate<T>>>,
+) {
+    let repeated = (0..vertices.len()).find_map(|a| {
+        (a + 1..vertices.len())
+            .find(|&b| vertices[a] == vertices[b])
+            .map(|b| (a, b))
+    });
+    match repeated {
+        Some((a, b)) => {
+            repairs.push(Repair::SplitRing {
+                ring,
+                coordinate: vertices[a],
+            });
+            let inner = vertices[a..b].to_vec();
+            let outer = vertices[b..]
+                .iter()
+                .chain(&vertices[..a])
+                .copied()
+                .collect();
+            split(inner, ring, repairs, pieces);
+            split(outer, ring, repairs, pieces);
+        }
+        None => pieces.push(vertices),
+    }
+}
+
+/// The valid, oriented pieces of a ring, as open vertex lists
+fn clean_ring<T: Float>(
+    ring: &LineString<T>,
+    role: RingRole,
+    repairs: &mut Vec<Repair<T>>,
+) -> Vec<Vec<Coordinate<T>>> {
+    let mut coords = Vec::with_capacity(ring.0.len());
+    for &c in &ring.0 {
+        if c.x.is_finite() && c.y.is_finite() {
+            coords.push(c);
+        } else {
+            repairs.push(Repair::RemovedNonFinitePoint {
+                ring: role,
+                coordinate: c,
+            });
+        }
+    }
+    if coords.is_empty() {
+        return vec![];
+    }
+    if coords.first() != coords.last() {
+        repairs.push(Repair::ClosedRing { ring: role });
+    }
+
+    let mut vertices: Vec<Coordinate<T>> = Vec::with_capacity(coords.len());
+    for c in coords {
+        if vertices.last() == Some(&c) {
+            repairs.push(Repair::RemovedDuplicatePoint {
+                ring: role,
+                coordinate: c,
+            });
+        } else {
+            vertices.push(c);
+        }
+    }
+    dedup_cyclic(&mut vertices);
+    remove_spikes(&mut vertices, role, repairs);
+
+    let mut pieces = vec![];
+    if vertices.len() >= 3 {
+        split(node(&vertices), role, repairs, &mut pieces);
+    } else {
+        pieces.push(vertices);
+    }
+
+    let counter_clockwise = role == RingRole::Exterior;
+    pieces
+        .into_iter()
+        .filter_map(|mut piece| {
+            remove_spikes(&mut piece, role, repairs);
+            let area = if piece.len() < 3 {
+                T::zero()
+            } else {
+                signed_area(&piece)
+            };
+            if area == T::zero() {
+                repairs.push(Repair::DroppedRing { ring: role });
+                return None;
+            }
+            if (area > T::zero()) != counter_clockwise {
+                piece.reverse();
+                repairs.push(Repair::ReorientedRing { ring: role });
+            }
+            Some(piece)
+        })
+        .collect()
+}
+
+fn closed<T: Float>(mut vertices: Vec<Coordinate<T>>) -> LineString<T> {
+    vertices.push(vertices[0]);
+    LineString(vertices)
+}
+
+impl<T: Float> MakeValid<T> for Polygon<T> {
+    fn make_valid(&self) -> (MultiPolygon<T>, Vec<Repair<T>>) {
+        let mut repairs = vec![];
+        let shells: Vec<LineString<T>> =
+            clean_ring(self.exterior(), RingRole::Exterior, &mut repairs)
+                .into_iter()
+                .map(closed)
+                .collect();
+        let mut holes: Vec<Vec<LineString<T>>> = vec![vec![]; shells.len()];
+
+        for (i, interior) in self.interiors().iter().enumerate() {
+            let role = RingRole::Interior(i);
+            for piece in clean_ring(interior, role, &mut repairs) {
+                let shell = shells.iter().position(|shell| {
+                    piece
+                        .iter()
+                        .map(|&c| get_position(Point(c), shell))
+                        .find(|position| *position != PositionPoint::OnBoundary)
+                        == Some(PositionPoint::Inside)
+                });
+                match shell {
+                    Some(shell) => holes[shell].push(closed(piece)),
+                    None => repairs.push(Repair::DroppedHole { ring: role }),
+                }
+            }
+        }
+
+        let polygons = shells
+            .into_iter()
+            .zip(holes)
+            .map(|(shell, holes)| Polygon::new(shell, holes))
+            .collect();
+        (MultiPolygon(polygons), repairs)
+    }
+}
+
+impl<T: Float> MakeValid<T> for MultiPolygon<T> {
+    fn make_valid(&self) -> (MultiPolygon<T>, Vec<Repair<T>>) {
+        let mut polygons = vec![];
+        let mut repairs = vec![];
+        for (index, polygon) in self.0.iter().enumerate() {
+            let (repaired, polygon_repairs) = polygon.make_valid();
+            polygons.extend(repaired);
+            repairs.extend(polygon_repairs.into_iter().map(|repair| Repair::Member {
+                index,
+                repair: Box::new(repair),
+            }));
+        }
+        (MultiPolygon(polygons), repairs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::validation::Validation;
+    use crate::polygon;
+
+    fn c(x: f64, y: f64) -> Coordinate<f64> {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn valid_polygon_unchanged() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        );
+        let (repaired, repairs) = polygon.make_valid();
+        assert_eq!(repairs, vec![]);
+        assert_eq!(repaired, MultiPolygon(vec![polygon]));
+    }
+
+    #[test]
+    fn duplicates_spikes_and_orientation() {
+        // clockwise, with a repeated point and a spike at (2, 3)
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 3.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+        ];
+        let (repaired, repairs) = polygon.make_valid();
+        assert_eq!(
+            repairs,
+            vec![
+                Repair::RemovedDuplicatePoint {
+                    ring: RingRole::Exterior,
+                    coordinate: c(0., 2.),
+                },
+                Repair::RemovedSpike {
+                    ring: RingRole::Exterior,
+                    coordinate: c(2., 3.),
+                },
+                Repair::ReorientedRing {
+                    ring: RingRole::Exterior
+                },
+            ]
+        );
+        assert_eq!(
+            repaired,
+            MultiPolygon(vec![
+                polygon![(x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)]
+            ])
+        );
+        assert!(repaired.is_valid());
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        // `Polygon::new` closes its rings, so test the ring repair itself
+        let ring = LineString(vec![c(0., 0.), c(1., 0.), c(1., 1.)]);
+        let mut repairs = vec![];
+        let pieces = clean_ring(&ring, RingRole::Exterior, &mut repairs);
+        assert_eq!(pieces, vec![vec![c(0., 0.), c(1., 0.), c(1., 1.)]]);
+        assert_eq!(
+            repairs,
+            vec![Repair::ClosedRing {
+                ring: RingRole::Exterior
+            }]
+        );
+    }
+
+    #[test]
+    fn non_finite_points() {
+        let polygon = Polygon::new(
+            LineString(vec![
+                c(0., 0.),
+                c(1., 0.),
+                c(f64::INFINITY, 0.5),
+                c(1., 1.),
+                c(f64::NAN, f64::NAN),
+                c(0., 1.),
+                c(0., 0.),
+            ]),
+            vec![],
+        );
+        let (repaired, repairs) = polygon.make_valid();
+        assert_eq!(
+            repaired,
+            MultiPolygon(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)]
+            ])
+        );
+        assert_eq!(repairs.len(), 2);
+        assert!(repairs.iter().all(|repair| matches!(
+            repair,
+            Repair::RemovedNonFinitePoint { ring: RingRole::Exterior, coordinate }
+                if !coordinate.x.is_finite()
+        )));
+
+        // a bowtie, whose crossing is found among the finite vertices
+        let bowtie = Polygon::new(
+            LineString(vec![
+                c(0., 0.),
+                c(2., 2.),
+                c(f64::NAN, 1.),
+                c(2., 0.),
+                c(0., 2.),
+                c(f64::NEG_INFINITY, f64::NAN),
+            ]),
+            vec![],
+        );
+        let (repaired, _) = bowtie.make_valid();
+        assert_eq!(repaired.0.len(), 2);
+        assert!(repaired.is_valid());
+    }
+
+    #[test]
+    fn self_touching_ring() {
+        // two squares touching at (1, 1), traced as one ring
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: 1.),
+            (x: 2., y: 2.),
+            (x: 1., y: 2.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        assert!(!polygon.is_valid());
+        let (repaired, repairs) = polygon.make_valid();
+        assert_eq!(repaired.0.len(), 2);
+        assert!(repaired.is_valid());
+        assert_eq!(
+            repairs,
+            vec![Repair::SplitRing {
+                ring: RingRole::Exterior,
+                coordinate: c(1., 1.),
+            }]
+        );
+    }
+
+    #[test]
+    fn degenerate_pieces_and_holes() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 20., y: 20.), (x: 21., y: 20.), (x: 21., y: 21.)],
+                [(x: 1., y: 1.), (x: 2., y: 2.), (x: 3., y: 3.)],
+                [(x: 4., y: 4.), (x: 4., y: 5.), (x: 5., y: 5.)],
+            ],
+        );
+        let (repaired, repairs) = polygon.make_valid();
+        assert_eq!(
+            repairs,
+            vec![
+                Repair::ReorientedRing {
+                    ring: RingRole::Interior(0)
+                },
+                Repair::DroppedHole {
+                    ring: RingRole::Interior(0)
+                },
+                Repair::RemovedSpike {
+                    ring: RingRole::Interior(1),
+                    coordinate: c(1., 1.),
+                },
+                Repair::DroppedRing {
+                    ring: RingRole::Interior(1)
+                },
+            ]
+        );
+        assert_eq!(repaired.0[0].interiors().len(), 1);
+        assert!(repaired.is_valid());
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let bowtie = polygon![(x: 2., y: 0.), (x: 4., y: 2.), (x: 4., y: 0.), (x: 2., y: 2.)];
+        let (repaired, repairs) = MultiPolygon(vec![square, bowtie]).make_valid();
+        assert_eq!(repaired.0.len(), 3);
+        assert!(repaired.is_valid());
+        assert!(repairs
+            .iter()
+            .all(|r| matches!(r, Repair::Member { index: 1, .. })));
+        assert_eq!(
+            repairs[0].to_string(),
+            "member 1: split exterior ring at (3 1)"
+        );
+    }
+}
@@ -35,8 +35,8 @@
 //! ```
 
 use crate::{
-    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect,
+    Coordinate, CoordinateM, CoordinateType, Geometry, GeometryCollection, Line, LineString,
+    LineStringM, MultiLineString, MultiPoint, MultiPolygon, Point, PointM, Polygon, Rect, Triangle,
 };
 use std::error::Error;
 
@@ -416,6 +416,8 @@ impl<T: CoordinateType, NT: CoordinateType> MapCoords<T, NT> for Geometry<T> {
             Geometry::MultiLineString(ref x) => Geometry::MultiLineString(x.map_coords(func)),
             Geometry::MultiPolygon(ref x) => Geometry::MultiPolygon(x.map_coords(func)),
             Geometry::GeometryCollection(ref x) => Geometry::GeometryCollection(x.map_coords(func)),
+            Geometry::Rect(ref x) => Geometry::Rect(x.map_coords(func)),
+            Geometry::Triangle(ref x) => Geometry::Triangle(x.map_coords(func)),
         }
     }
 }
@@ -440,6 +442,8 @@ impl<T: CoordinateType, NT: CoordinateType> TryMapCoords<T, NT> for Geometry<T>
             Geometry::GeometryCollection(ref x) => {
                 Ok(Geometry::GeometryCollection(x.try_map_coords(func)?))
             }
+            Geometry::Rect(ref x) => Ok(Geometry::Rect(x.try_map_coords(func)?)),
+            Geometry::Triangle(ref x) => Ok(Geometry::Triangle(x.try_map_coords(func)?)),
         }
     }
 }
@@ -455,6 +459,8 @@ impl<T: CoordinateType> MapCoordsInplace<T> for Geometry<T> {
             Geometry::MultiLineString(ref mut x) => x.map_coords_inplace(func),
             Geometry::MultiPolygon(ref mut x) => x.map_coords_inplace(func),
             Geometry::GeometryCollection(ref mut x) => x.map_coords_inplace(func),
+            Geometry::Rect(ref mut x) => x.map_coords_inplace(func),
+            Geometry::Triangle(ref mut x) => x.map_coords_inplace(func),
         }
     }
 }
@@ -545,6 +551,110 @@ impl<T: CoordinateType> MapCoordsInplace<T> for Rect<T> {
     }
 }
 
+impl<T: CoordinateType, NT: CoordinateType> MapCoords<T, NT> for Triangle<T> {
+    type Output = Triangle<NT>;
+
+    fn map_coords(&self, func: impl Fn(&(T, T)) -> (NT, NT) + Copy) -> Self::Output {
+        let [a, b, c] = self.to_array();
+        Triangle(
+            func(&a.x_y()).into(),
+            func(&b.x_y()).into(),
+            func(&c.x_y()).into(),
+        )
+    }
+}
+
+impl<T: CoordinateType, NT: CoordinateType> TryMapCoords<T, NT> for Triangle<T> {
+    type Output = Triangle<NT>;
+
+    fn try_map_coords(
+        &self,
+        func: impl Fn(&(T, T)) -> Result<(NT, NT), Box<dyn Error + Send + Sync>>,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        let [a, b, c] = self.to_array();
+        Ok(Triangle(
+            func(&a.x_y())?.into(),
+            func(&b.x_y())?.into(),
+            func(&c.x_y())?.into(),
+        ))
+    }
+}
+
+impl<T: CoordinateType> MapCoordsInplace<T> for Triangle<T> {
+    fn map_coords_inplace(&mut self, func: impl Fn(&(T, T)) -> (T, T)) {
+        self.0 = func(&self.0.x_y()).into();
+        self.1 = func(&self.1.x_y()).into();
+        self.2 = func(&self.2.x_y()).into();
+    }
+}
+
+// Measures are carried over unchanged, so the coordinate type can't change
+impl<T: CoordinateType> MapCoords<T, T> for PointM<T> {
+    type Output = PointM<T>;
+
+    fn map_coords(&self, func: impl Fn(&(T, T)) -> (T, T) + Copy) -> Self::Output {
+        let new_point = func(&self.0.x_y());
+        PointM::new(new_point.0, new_point.1, self.m())
+    }
+}
+
+impl<T: CoordinateType> TryMapCoords<T, T> for PointM<T> {
+    type Output = PointM<T>;
+
+    fn try_map_coords(
+        &self,
+        func: impl Fn(&(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>>,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        let new_point = func(&self.0.x_y())?;
+        Ok(PointM::new(new_point.0, new_point.1, self.m()))
+    }
+}
+
+impl<T: CoordinateType> MapCoordsInplace<T> for PointM<T> {
+    fn map_coords_inplace(&mut self, func: impl Fn(&(T, T)) -> (T, T)) {
+        let new_point = func(&self.0.x_y());
+        self.0.x = new_point.0;
+        self.0.y = new_point.1;
+    }
+}
+
+impl<T: CoordinateType> MapCoords<T, T> for LineStringM<T> {
+    type Output = LineStringM<T>;
+
+    fn map_coords(&self, func: impl Fn(&(T, T)) -> (T, T) + Copy) -> Self::Output {
+        LineStringM(
+            self.points_iter()
+                .map(|p| p.map_coords(func).0)
+                .collect::<Vec<CoordinateM<T>>>(),
+        )
+    }
+}
+
+impl<T: CoordinateType> TryMapCoords<T, T> for LineStringM<T> {
+    type Output = LineStringM<T>;
+
+    fn try_map_coords(
+        &self,
+        func: impl Fn(&(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>> + Copy,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        Ok(LineStringM(
+            self.points_iter()
+                .map(|p| p.try_map_coords(func).map(|p| p.0))
+                .collect::<Result<Vec<_>, Box<dyn Error + Send + Sync>>>()?,
+        ))
+    }
+}
+
+impl<T: CoordinateType> MapCoordsInplace<T> for LineStringM<T> {
+    fn map_coords_inplace(&mut self, func: impl Fn(&(T, T)) -> (T, T)) {
+        for p in &mut self.0 {
+            let new_coords = func(&(p.x, p.y));
+            p.x = new_coords.0;
+            p.y = new_coords.1;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -595,6 +705,16 @@ mod test {
         assert_eq!(another_rect.max(), Coordinate { x: 30, y: 40 });
     }
 
+    #[test]
+    fn triangle_map_coords() {
+        let triangle = Triangle((0, 0).into(), (10, 0).into(), (0, 10).into());
+        let geometry = Geometry::Triangle(triangle).map_coords(|&(x, y)| (x + 1, y * 2));
+        assert_eq!(
+            geometry,
+            Geometry::Triangle(Triangle((1, 0).into(), (11, 0).into(), (1, 20).into()))
+        );
+    }
+
     #[test]
     fn rect_try_map_coords() {
         let rect = Rect::new((10, 10), (20, 20));
@@ -765,6 +885,86 @@ mod test {
         );
     }
 
+    #[test]
+    fn geometrycollection_inplace() {
+        let mut gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Line(Line::new(
+                (0., 0.),
+                (1., 2.),
+            ))])),
+        ]);
+        gc.map_coords_inplace(|&(x, y)| (x + 10., y + 100.));
+
+        assert_eq!(
+            gc,
+            GeometryCollection(vec![
+                Geometry::Point(Point::new(20., 110.)),
+                Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Line(Line::new(
+                    (10., 100.),
+                    (11., 102.)
+                ))])),
+            ])
+        );
+    }
+
+    #[test]
+    fn geometrycollection_try_map_coords_short_circuits() {
+        use std::cell::Cell;
+
+        let gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(1., 1.)),
+            Geometry::LineString(LineString::from(vec![(2., 2.), (-1., -1.), (3., 3.)])),
+            Geometry::Point(Point::new(4., 4.)),
+        ]);
+        let calls = Cell::new(0);
+        let calls = &calls;
+        let result = gc.try_map_coords(|&(x, y)| {
+            calls.set(calls.get() + 1);
+            if x < 0. {
+                Err("negative coordinate".into())
+            } else {
+                Ok((x, y))
+            }
+        });
+
+        assert_eq!(result.unwrap_err().to_string(), "negative coordinate");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn linestring_m() {
+        let track = LineStringM(vec![
+            CoordinateM {
+                x: 0.,
+                y: 0.,
+                m: 0.,
+            },
+            CoordinateM {
+                x: 5.,
+                y: 1.,
+                m: 60.,
+            },
+        ]);
+        let expected = LineStringM(vec![
+            CoordinateM {
+                x: 10.,
+                y: 0.,
+                m: 0.,
+            },
+            CoordinateM {
+                x: 15.,
+                y: 1.,
+                m: 60.,
+            },
+        ]);
+        assert_eq!(track.map_coords(|&(x, y)| (x + 10., y)), expected);
+
+        let mut track = track;
+        track.map_coords_inplace(|&(x, y)| (x + 10., y));
+        assert_eq!(track, expected);
+    }
+
     #[test]
     fn convert_type() {
         let p1: Point<f64> = Point::new(1., 2.);
@@ -829,4 +1029,20 @@ mod test {
             .into()
         );
     }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn convert_to_and_from_fixed_point() {
+        use geo_types::fixed_point::FixedI64F64;
+
+        let poly = polygon![(x: 0.5, y: 0.), (x: 2., y: 0.25), (x: 1., y: 3.)];
+        let fixed = poly.map_coords(|&(x, y)| {
+            (
+                FixedI64F64::from_f64(x).unwrap(),
+                FixedI64F64::from_f64(y).unwrap(),
+            )
+        });
+        assert_eq!(fixed.exterior()[1].x, FixedI64F64::from_f64(2.).unwrap());
+        assert_eq!(fixed.map_coords(|&(x, y)| (x.to_f64(), y.to_f64())), poly);
+    }
 }
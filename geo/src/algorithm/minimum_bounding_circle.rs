@@ -0,0 +1,234 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+
+/// A circle, given by its center and radius.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Circle<T: Float> {
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+/// Find the smallest circle enclosing a geometry, for sizing labels and symbols, or as a shape
+/// descriptor.
+pub trait MinimumBoundingCircle<T: Float> {
+    /// Return the smallest circle containing every coordinate of the geometry, or `None` if it's
+    /// empty.
+    ///
+    /// This uses Welzl's algorithm, taking the coordinates in a fixed pseudo-random order, so
+    /// that it takes expected linear time, and gives the same circle every time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::minimum_bounding_circle::{Circle, MinimumBoundingCircle};
+    /// use geo::{line_string, point};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 4., y: 0.), (x: 2., y: 1.)];
+    /// assert_eq!(
+    ///     line_string.minimum_bounding_circle(),
+    ///     Some(Circle { center: point!(x: 2., y: 0.), radius: 2. })
+    /// );
+    /// ```
+    fn minimum_bounding_circle(&self) -> Option<Circle<T>>;
+}
+
+macro_rules! minimum_bounding_circle_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T> MinimumBoundingCircle<T> for $type<T>
+            where
+                T: Float,
+            {
+                fn minimum_bounding_circle(&self) -> Option<Circle<T>> {
+                    // the interior rings of polygons are inside their exteriors
+                    welzl(self.exterior_coords_iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+minimum_bounding_circle_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+fn welzl<T: Float>(mut coords: Vec<Coordinate<T>>) -> Option<Circle<T>> {
+    shuffle(&mut coords);
+    let mut circle = from_diameter(*coords.first()?, coords[0]);
+    for i in 1..coords.len() {
+        if contains(&circle, coords[i]) {
+            continue;
+        }
+        circle = from_diameter(coords[i], coords[i]);
+        for j in 0..i {
+            if contains(&circle, coords[j]) {
+                continue;
+            }
+            circle = from_diameter(coords[i], coords[j]);
+            for k in 0..j {
+                if !contains(&circle, coords[k]) {
+                    circle = from_boundary(coords[i], coords[j], coords[k]);
+                }
+            }
+        }
+    }
+    Some(Circle {
+        center: Point(circle.0),
+        radius: circle.1,
+    })
+}
+
+// A Fisher–Yates shuffle, with a fixed xorshift generator
+fn shuffle<T>(items: &mut [T]) {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+fn distance<T: Float>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+// Whether a coordinate is inside a circle, allowing for rounding in computing the circle
+fn contains<T: Float>(circle: &(Coordinate<T>, T), coord: Coordinate<T>) -> bool {
+    let tolerance = T::epsilon() * (T::one() + T::one()).powi(4);
+    distance(circle.0, coord) <= circle.1 * (T::one() + tolerance)
+}
+
+fn from_diameter<T: Float>(a: Coordinate<T>, b: Coordinate<T>) -> (Coordinate<T>, T) {
+    let two = T::one() + T::one();
+    let center = Coordinate {
+        x: (a.x + b.x) / two,
+        y: (a.y + b.y) / two,
+    };
+    (center, distance(a, b) / two)
+}
+
+// The circle through three coordinates, or, if they're collinear, the circle whose diameter is
+// the furthest apart of them
+fn from_boundary<T: Float>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+) -> (Coordinate<T>, T) {
+    let (bx, by) = (b.x - a.x, b.y - a.y);
+    let (cx, cy) = (c.x - a.x, c.y - a.y);
+    let d = (T::one() + T::one()) * (bx * cy - by * cx);
+    if d != T::zero() {
+        let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+        let center = Coordinate {
+            x: a.x + (cy * b2 - by * c2) / d,
+            y: a.y + (bx * c2 - cx * b2) / d,
+        };
+        let radius = distance(center, a)
+            .max(distance(center, b))
+            .max(distance(center, c));
+        if radius.is_finite() {
+            return (center, radius);
+        }
+    }
+    [(a, b), (a, c), (b, c)]
+        .iter()
+        .map(|(start, end)| from_diameter(*start, *end))
+        .fold((a, T::zero()), |widest, circle| {
+            if circle.1 > widest.1 {
+                circle
+            } else {
+                widest
+            }
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn assert_encloses<T: Float + std::fmt::Debug>(circle: Circle<T>, coords: &[Coordinate<T>]) {
+        for coord in coords {
+            assert!(
+                contains(&(circle.center.0, circle.radius), *coord),
+                "{:?}",
+                coord
+            );
+        }
+    }
+
+    #[test]
+    fn triangles() {
+        // acute, so the circle passes through all three vertices
+        let acute = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 3.)];
+        let circle = acute.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x(), 2.);
+        assert_relative_eq!(circle.center.y(), 5. / 6.);
+        assert_relative_eq!(circle.radius, 13. / 6.);
+        // obtuse, so the longest side is a diameter
+        let obtuse = Triangle::from([(0., 0.), (4., 0.), (2., 1.)]);
+        assert_eq!(
+            obtuse.minimum_bounding_circle(),
+            Some(Circle {
+                center: point!(x: 2., y: 0.),
+                radius: 2.
+            })
+        );
+    }
+
+    #[test]
+    fn many_points() {
+        // points on and inside the circle of radius 5 around (1, 2)
+        let coords: Vec<Coordinate<f64>> = (0..200)
+            .map(|i| {
+                let angle = i as f64 * 0.7;
+                let radius = if i % 3 == 0 { 5. } else { (i % 7) as f64 * 0.6 };
+                Coordinate {
+                    x: 1. + radius * angle.cos(),
+                    y: 2. + radius * angle.sin(),
+                }
+            })
+            .collect();
+        let multi_point: MultiPoint<f64> = coords.iter().map(|coord| Point(*coord)).collect();
+        let circle = multi_point.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x(), 1., epsilon = 1e-9);
+        assert_relative_eq!(circle.center.y(), 2., epsilon = 1e-9);
+        assert_relative_eq!(circle.radius, 5., epsilon = 1e-9);
+        assert_encloses(circle, &coords);
+    }
+
+    #[test]
+    fn degenerate() {
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(
+            point.minimum_bounding_circle(),
+            Some(Circle {
+                center: point,
+                radius: 0.
+            })
+        );
+        let collinear =
+            line_string![(x: 0., y: 0.), (x: 3., y: 3.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let circle = collinear.minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, point!(x: 1.5, y: 1.5));
+        assert_relative_eq!(circle.radius, 4.5.sqrt());
+        assert_eq!(LineString::<f64>(vec![]).minimum_bounding_circle(), None);
+        let geometry = Geometry::GeometryCollection(GeometryCollection::<f64>(vec![]));
+        assert_eq!(geometry.minimum_bounding_circle(), None);
+    }
+}
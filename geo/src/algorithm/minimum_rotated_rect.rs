@@ -0,0 +1,188 @@
+use crate::algorithm::convexhull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+
+/// Find the rectangle of least area enclosing a geometry, at any rotation, for orienting labels
+/// along a shape, or as a shape descriptor.
+pub trait MinimumRotatedRect<T: Float> {
+    /// Return the rectangle of least area containing every coordinate of the geometry, as a
+    /// counter-clockwise `Polygon`, or `None` if the geometry is empty.
+    ///
+    /// One side of the rectangle is always along an edge of the convex hull, so each edge of the
+    /// hull is tried in turn. If the geometry has no area, the rectangle is flat, along its
+    /// longest extent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+    /// use geo::polygon;
+    ///
+    /// // a diamond, whose minimum rotated rectangle is a square rather than its bounding box
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 3., y: -1.), (x: 4., y: 2.), (x: 1., y: 3.)];
+    /// let rect = polygon.minimum_rotated_rect().unwrap();
+    /// assert!((rect.unsigned_area() - 10_f64).abs() < 1e-12);
+    /// assert_eq!(rect.exterior().0.len(), 5);
+    /// ```
+    fn minimum_rotated_rect(&self) -> Option<Polygon<T>>;
+}
+
+macro_rules! minimum_rotated_rect_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T> MinimumRotatedRect<T> for $type<T>
+            where
+                T: Float,
+            {
+                fn minimum_rotated_rect(&self) -> Option<Polygon<T>> {
+                    // the interior rings of polygons are inside their exteriors
+                    minimum_rotated_rect(self.exterior_coords_iter().map(Point).collect())
+                }
+            }
+        )*
+    };
+}
+
+minimum_rotated_rect_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+fn minimum_rotated_rect<T: Float>(mut points: Vec<Point<T>>) -> Option<Polygon<T>> {
+    let hull: Vec<Coordinate<T>> = quick_hull(&mut points).into_iter().map(|p| p.0).collect();
+    let first = *hull.first()?;
+    // the origin, direction, and extents of the smallest rectangle so far
+    let mut best = None;
+    let mut best_area = T::infinity();
+    for window in hull.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let length = (end.x - start.x).hypot(end.y - start.y);
+        if length == T::zero() {
+            continue;
+        }
+        let direction = Coordinate {
+            x: (end.x - start.x) / length,
+            y: (end.y - start.y) / length,
+        };
+        let mut extents = [T::zero(); 4];
+        for coord in &hull {
+            let (dx, dy) = (coord.x - start.x, coord.y - start.y);
+            let along = dx * direction.x + dy * direction.y;
+            let across = dy * direction.x - dx * direction.y;
+            extents[0] = extents[0].min(along);
+            extents[1] = extents[1].max(along);
+            extents[2] = extents[2].min(across);
+            extents[3] = extents[3].max(across);
+        }
+        let area = (extents[1] - extents[0]) * (extents[3] - extents[2]);
+        if best.is_none() || area < best_area {
+            best = Some((start, direction, extents));
+            best_area = area;
+        }
+    }
+    let (origin, direction, [min_along, max_along, min_across, max_across]) = match best {
+        Some(best) => best,
+        None => (
+            first,
+            Coordinate {
+                x: T::one(),
+                y: T::zero(),
+            },
+            [T::zero(); 4],
+        ),
+    };
+    let corner = |along: T, across: T| Coordinate {
+        x: origin.x + direction.x * along - direction.y * across,
+        y: origin.y + direction.y * along + direction.x * across,
+    };
+    Some(Polygon::new(
+        LineString(vec![
+            corner(min_along, min_across),
+            corner(max_along, min_across),
+            corner(max_along, max_across),
+            corner(min_along, max_across),
+            corner(min_along, min_across),
+        ]),
+        vec![],
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn rotated_square() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 1. });
+        assert_eq!(rect.minimum_rotated_rect().unwrap().unsigned_area(), 2.);
+        let angle = 0.4_f64;
+        let rotated: MultiPoint<f64> = vec![(0., 0.), (4., 0.), (4., 1.), (0., 1.), (1., 0.5)]
+            .into_iter()
+            .map(|(x, y)| {
+                (
+                    x * angle.cos() - y * angle.sin(),
+                    x * angle.sin() + y * angle.cos(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let rect = rotated.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.signed_area(), 4., epsilon = 1e-12);
+        assert!(rect.contains(&rotated.0[4]));
+        for corner in &rect.exterior().0 {
+            assert!(rotated.0.iter().any(|point| {
+                (point.x() - corner.x).abs() < 1e-12 && (point.y() - corner.y).abs() < 1e-12
+            }));
+        }
+    }
+
+    #[test]
+    fn concave() {
+        // an L shape, whose rectangle is its bounding box
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let rect = polygon.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.signed_area(), 16.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 1., y: 1.)];
+        let rect = line_string.minimum_rotated_rect().unwrap();
+        assert_eq!(rect.unsigned_area(), 0.);
+        let bounds = rect.exterior().bounding_rect().unwrap();
+        assert_relative_eq!(bounds.min().x, 0., epsilon = 1e-12);
+        assert_relative_eq!(bounds.min().y, 0., epsilon = 1e-12);
+        assert_relative_eq!(bounds.max().x, 2., epsilon = 1e-12);
+        assert_relative_eq!(bounds.max().y, 2., epsilon = 1e-12);
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(
+            point.minimum_rotated_rect(),
+            Some(Polygon::new(LineString(vec![point.0; 5]), vec![]))
+        );
+        assert_eq!(MultiPoint::<f64>(vec![]).minimum_rotated_rect(), None);
+    }
+}
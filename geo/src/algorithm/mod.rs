@@ -1,30 +1,72 @@
+/// Apply affine transformations, composed from translations, rotations, scales, and skews, to a `Geometry`.
+pub mod affine_ops;
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
 /// Calculate the bearing to another `Point`, in degrees.
 pub mod bearing;
+/// Compute the union, intersection, difference, and symmetric difference of `Polygon`s and `MultiPolygon`s.
+pub mod bool_ops;
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
+/// Compute the area within a distance of a geometry, with configurable caps and joins.
+pub mod buffer;
+/// Build geometries a ring, or a coordinate, at a time, checking or cleaning them up as they're built.
+pub mod builder;
+/// Put a geometry into a canonical form, and remove repeated members of collections.
+pub mod canonicalize;
+/// Resample a `LineString` or `Polygon` along a Catmull–Rom spline through its vertices.
+pub mod catmull_rom;
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
+/// Smooth a `LineString` or `Polygon` by cutting off its corners, using Chaikin's algorithm.
+pub mod chaikin_smoothing;
 /// Calculate the signed approximate geodesic area of a `Geometry`.
 pub mod chamberlain_duquette_area;
+/// Clip a geometry to a `Rect`, much faster than the general boolean operations.
+pub mod clip_to_rect;
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
+/// Calculate a concave hull of a geometry, following the outline of its points more tightly.
+pub mod concave_hull;
 /// Determine whether `Geometry` `A` is completely enclosed by `Geometry` `B`.
 pub mod contains;
 /// Calculate the convex hull of a `Geometry`.
 pub mod convexhull;
+/// Iterate over the `Coordinates` of a `Geometry`.
+pub mod coords_iter;
+/// Check that polygons tile a region, finding the overlaps and gaps between them.
+pub mod coverage;
+/// Tag geometries with their coordinate reference system, so geographic and projected ones can't be mixed.
+pub mod crs;
+/// Insert vertices into a geometry, so that no segment is longer than a given length.
+pub mod densify;
+/// Find the dimensions of a `Geometry`, to detect degenerate geometries.
+pub mod dimensions;
+/// Tessellate a `Polygon` into triangles, as indices into a flat buffer of vertices.
+pub mod earcut;
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
 /// Calculate the length of a planar line between two `Geometries`.
 pub mod euclidean_length;
-/// Calculate the extreme indices of a `Polygon`, `MultiPolygon`, or `MultiPoint`.
+/// Calculate the extreme vertices of a `Geometry`, and the extreme indices of a `Polygon`, `MultiPolygon`, or `MultiPoint`.
 pub mod extremes;
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
 /// Produces a `Geometry` from PostGIS.
 #[cfg(feature = "postgis-integration")]
 pub mod from_postgis;
+/// Calculate the geodesic area of a `Geometry` on the WGS84 ellipsoid.
+#[cfg(feature = "geographiclib")]
+pub mod geodesic_area;
+/// Calculate a destination `Point` on the WGS84 ellipsoid, given a distance and a bearing.
+#[cfg(feature = "geographiclib")]
+pub mod geodesic_destination;
+/// Calculate the geodesic distance between two `Point`s on the WGS84 ellipsoid.
+pub mod geodesic_distance;
+/// Calculate the geodesic length of a `Line`, `LineString`, or `MultiLineString`.
+pub mod geodesic_length;
+/// Determine the similarity between two geometries using the Hausdorff distance.
+pub mod hausdorff_distance;
 /// Calculate a destination `Point`, given a distance and a bearing.
 pub mod haversine_destination;
 /// Calculate the Haversine distance between two `Geometries`.
@@ -33,31 +75,98 @@ pub mod haversine_distance;
 pub mod haversine_intermediate;
 /// Calculate the Haversine length of a Line.
 pub mod haversine_length;
+/// Report the memory used, and the coordinates stored, by a geometry.
+pub mod heap_size;
+/// Bin geometries into a hierarchical grid of hexagons, in the style of H3.
+pub mod hex_grid;
+/// Find a point strictly inside a `Polygon` or `MultiPolygon`, unlike its centroid.
+pub mod interior_point;
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
+/// Find the `k` points nearest to a point, using a k-d tree.
+pub mod k_nearest;
+/// Find the largest circle inside a `Polygon` or `MultiPolygon`, centered on its pole of inaccessibility.
+pub mod largest_inscribed_circle;
+/// Find where two `Line`s intersect, classified as a proper crossing, a touch, or a collinear overlap.
+pub mod line_intersection;
+/// Merge line strings which meet end to end into the longest ones without branches.
+pub mod line_merge;
+/// Split a `LineString` at a fraction of its length or a point, or extract part of it.
+pub mod line_split;
+/// Repair common validity problems of a `Polygon` or `MultiPolygon`.
+pub mod make_valid;
 /// Apply a function to all `Coordinates` of a `Geometry`.
 pub mod map_coords;
+/// Find the smallest circle enclosing a geometry.
+pub mod minimum_bounding_circle;
+/// Find the rectangle of least area enclosing a geometry, at any rotation.
+pub mod minimum_rotated_rect;
+/// Encode geometries as the geometry of Mapbox Vector Tile features.
+pub mod mvt;
+/// Compute the parallel curve at a distance from a line, removing its self-intersections.
+pub mod offset;
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
+/// Split geometries into the points, line strings, and polygons they're made of.
+pub(crate) mod parts;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
 pub(crate) mod polygon_distance_fast_path;
+/// Build the polygons enclosed by a set of lines.
+pub mod polygonize;
+/// Round coordinates onto a grid, and compute boolean operations on it.
+pub mod precision;
+/// Robust geometric predicates: orientation and in-circle tests.
+pub mod predicates;
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
+/// Project geometries to and from Web Mercator and UTM, without PROJ.
+pub mod projection;
+/// Topologically relate two `Geometries`, computing their DE-9IM intersection matrix.
+pub mod relate;
+/// Remove consecutive repeated coordinates from a `Geometry`.
+pub mod remove_repeated_points;
 /// Rotate a `Geometry` around either its centroid or a `Point` by an angle given in degrees.
 pub mod rotate;
+/// Calculate the diameter and minimum width of a `Geometry` with rotating calipers.
+pub mod rotating_calipers;
+/// Name cells of a hierarchy dividing the sphere by 64-bit ids, and cover regions with them.
+pub mod s2_cell;
+/// Scale a `Geometry` about its centroid or a `Point`, by factors in each direction.
+pub mod scale;
+/// Find where a `LineString` or `Polygon` crosses or touches itself.
+pub mod self_intersections;
+/// Transform coordinate buffers, and find their bounding rectangles and lengths, with SIMD.
+#[cfg(feature = "simd")]
+pub mod simd;
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplifyvw;
+/// Skew a `Geometry` about its centroid or a `Point`, by angles given in degrees.
+pub mod skew;
+/// Snap the vertices of a geometry onto the vertices and lines of another.
+pub mod snap;
+/// Calculate haversine distances, bearings, and destinations, also for fixed-point coordinates.
+pub mod spherical;
+/// Find every intersection among many `Line`s with a Bentley–Ottmann sweep line.
+pub mod sweep_line;
+/// Find the tiles of a slippy map holding geometries, and convert to and from Web Mercator.
+pub mod tile;
 /// Convert a `Geometry` into a PostGIS.
 #[cfg(feature = "postgis-integration")]
 pub mod to_postgis;
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
+/// Triangulate the coordinates of a geometry, or the area of a `Polygon` following its edges.
+pub mod triangulation;
+/// Check whether a `Geometry` is valid, and why it isn't.
+pub mod validation;
 /// Calculate the Vincenty distance between two `Point`s.
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a `LineString`.
 pub mod vincenty_length;
+/// Compute the Voronoi cells of a set of `Point`s, clipped to a `Rect`.
+pub mod voronoi;
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
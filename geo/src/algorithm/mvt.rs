@@ -0,0 +1,713 @@
+//! Encode geometries as the geometry of [Mapbox Vector Tile] features.
+//!
+//! Geometries, in longitudes (`x`) and latitudes (`y`) in degrees, are projected into the
+//! coordinates of a [`Tile`](../tile/struct.Tile.html), which run from `0` to the tile's
+//! extent to the east and the south. They are then clipped to the tile and a buffer around
+//! it, simplified, rounded to integers, and encoded as the zigzag- and delta-encoded
+//! `MoveTo`, `LineTo` and `ClosePath` commands of a feature's `geometry` field.
+//!
+//! Rings are oriented after they are clipped and rounded, as the specification requires:
+//! exterior rings have a positive area in tile coordinates (so they appear clockwise, as `y`
+//! points down), and interior rings a negative one. Parts which collapse when they are
+//! rounded, such as rings without area, are dropped.
+//!
+//! [Mapbox Vector Tile]: https://github.com/mapbox/vector-tile-spec/tree/master/2.1
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::mvt::{MvtGeometryType, ToMvt};
+//! use geo::algorithm::tile::Tile;
+//! use geo::point;
+//!
+//! // the middle of the only tile at zoom level 0
+//! let geometry = point!(x: 0., y: 0.).to_mvt(&Tile::new(0, 0, 0).unwrap()).unwrap();
+//! assert_eq!(geometry.geometry_type, MvtGeometryType::Point);
+//! // MoveTo(2048, 2048)
+//! assert_eq!(geometry.commands, vec![9, 4096, 4096]);
+//! ```
+use crate::algorithm::clip_to_rect::ClipToRect;
+use crate::algorithm::simplify::Simplify;
+use crate::algorithm::tile::{tile_position, Tile};
+use crate::algorithm::winding_order::twice_signed_ring_area;
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// The type of an MVT feature's geometry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MvtGeometryType {
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+}
+
+/// The geometry of an MVT feature: its type, and the command integers of its `geometry` field
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MvtGeometry {
+    pub geometry_type: MvtGeometryType,
+    pub commands: Vec<u32>,
+}
+
+/// The parameters of an MVT encoding
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::mvt::MvtOptions;
+///
+/// let options = MvtOptions {
+///     extent: 512,
+///     ..MvtOptions::default()
+/// };
+/// assert_eq!(options.buffer, 64);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MvtOptions {
+    /// The width and height of the tile, in tile coordinates
+    pub extent: u32,
+    /// How far beyond the edges of the tile geometries are kept, in tile coordinates, so that
+    /// lines and outlines drawn across the edges join up with those of the next tile
+    pub buffer: u32,
+    /// The distance, in tile coordinates, within which lines and rings are simplified with
+    /// [`Simplify`](../simplify/trait.Simplify.html), or `0` to keep all of their vertices
+    pub tolerance: f64,
+}
+
+impl Default for MvtOptions {
+    fn default() -> Self {
+        MvtOptions {
+            extent: 4096,
+            buffer: 64,
+            tolerance: 1.,
+        }
+    }
+}
+
+/// Encode a geometry as the geometry of an MVT feature in a tile.
+///
+/// A single geometry is encoded as `None` if nothing of it is left in the tile. A
+/// `GeometryCollection`, or a `Geometry` which may be one, is encoded as one `MvtGeometry` of
+/// each type its members have in the tile, in the order points, lines, polygons, so that each
+/// can be written as a feature.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::mvt::{MvtOptions, ToMvt};
+/// use geo::algorithm::tile::Tile;
+/// use geo::polygon;
+///
+/// // the eastern half of the map at zoom level 1 is the tiles with `x` of 1
+/// let tile = Tile::new(1, 0, 1).unwrap();
+/// let polygon = polygon![
+///     (x: -10., y: 10.),
+///     (x: 10., y: 10.),
+///     (x: 10., y: 20.),
+///     (x: -10., y: 20.),
+/// ];
+/// let options = MvtOptions {
+///     extent: 256,
+///     buffer: 0,
+///     tolerance: 0.,
+/// };
+/// let geometry = polygon.to_mvt_with_options(&tile, &options).unwrap();
+/// // clipped at the western edge of the tile, and clockwise in tile coordinates:
+/// // MoveTo(0, 242), LineTo(0, 227), (14, 227), (14, 242), ClosePath
+/// assert_eq!(geometry.commands, vec![9, 0, 484, 26, 0, 29, 28, 0, 0, 30, 15]);
+/// ```
+pub trait ToMvt<T: Float> {
+    type Output;
+
+    /// Encode the geometry in `tile` with the default [`MvtOptions`](struct.MvtOptions.html):
+    /// an extent of 4096, a buffer of 64, and a tolerance of 1.
+    fn to_mvt(&self, tile: &Tile) -> Self::Output {
+        self.to_mvt_with_options(tile, &MvtOptions::default())
+    }
+
+    fn to_mvt_with_options(&self, tile: &Tile, options: &MvtOptions) -> Self::Output;
+}
+
+macro_rules! impl_to_mvt {
+    ($($type:ident),*) => {
+        $(
+            impl<T: Float> ToMvt<T> for $type<T> {
+                type Output = Option<MvtGeometry>;
+
+                fn to_mvt_with_options(
+                    &self,
+                    tile: &Tile,
+                    options: &MvtOptions,
+                ) -> Option<MvtGeometry> {
+                    let mut parts = Parts::default();
+                    self.encode(&Encoder::new(tile, options), &mut parts);
+                    parts.finish().pop()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_mvt!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle
+);
+
+impl<T: Float> ToMvt<T> for GeometryCollection<T> {
+    type Output = Vec<MvtGeometry>;
+
+    fn to_mvt_with_options(&self, tile: &Tile, options: &MvtOptions) -> Vec<MvtGeometry> {
+        let mut parts = Parts::default();
+        self.encode(&Encoder::new(tile, options), &mut parts);
+        parts.finish()
+    }
+}
+
+impl<T: Float> ToMvt<T> for Geometry<T> {
+    type Output = Vec<MvtGeometry>;
+
+    fn to_mvt_with_options(&self, tile: &Tile, options: &MvtOptions) -> Vec<MvtGeometry> {
+        let mut parts = Parts::default();
+        self.encode(&Encoder::new(tile, options), &mut parts);
+        parts.finish()
+    }
+}
+
+// A position in tile coordinates
+type TilePoint = [i32; 2];
+
+// The parts of a geometry in a tile, rounded to tile coordinates
+#[derive(Default)]
+struct Parts {
+    points: Vec<TilePoint>,
+    lines: Vec<Vec<TilePoint>>,
+    // closed, and oriented with each exterior followed by its interiors
+    rings: Vec<Vec<TilePoint>>,
+}
+
+impl Parts {
+    fn finish(self) -> Vec<MvtGeometry> {
+        let mut geometries = vec![];
+        if !self.points.is_empty() {
+            let mut commands = Commands::default();
+            commands.command(MOVE_TO, &self.points);
+            geometries.push(commands.finish(MvtGeometryType::Point));
+        }
+        if !self.lines.is_empty() {
+            let mut commands = Commands::default();
+            for line in &self.lines {
+                commands.command(MOVE_TO, &line[..1]);
+                commands.command(LINE_TO, &line[1..]);
+            }
+            geometries.push(commands.finish(MvtGeometryType::LineString));
+        }
+        if !self.rings.is_empty() {
+            let mut commands = Commands::default();
+            for ring in &self.rings {
+                // The closing point is implied by ClosePath
+                commands.command(MOVE_TO, &ring[..1]);
+                commands.command(LINE_TO, &ring[1..ring.len() - 1]);
+                commands.command(CLOSE_PATH, &[]);
+            }
+            geometries.push(commands.finish(MvtGeometryType::Polygon));
+        }
+        geometries
+    }
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+// The command integers of a geometry, with the cursor the parameters are relative to
+#[derive(Default)]
+struct Commands {
+    commands: Vec<u32>,
+    cursor: TilePoint,
+}
+
+impl Commands {
+    // A command with its parameters, or a ClosePath, which has none
+    fn command(&mut self, id: u32, points: &[TilePoint]) {
+        let count = if id == CLOSE_PATH { 1 } else { points.len() };
+        self.commands.push((id & 0x7) | ((count as u32) << 3));
+        for point in points {
+            for (value, cursor) in point.iter().zip(self.cursor.iter_mut()) {
+                self.commands.push(zigzag(value - *cursor));
+                *cursor = *value;
+            }
+        }
+    }
+
+    fn finish(self, geometry_type: MvtGeometryType) -> MvtGeometry {
+        MvtGeometry {
+            geometry_type,
+            commands: self.commands,
+        }
+    }
+}
+
+struct Encoder {
+    tile: Tile,
+    extent: f64,
+    clip: Rect<f64>,
+    tolerance: f64,
+}
+
+impl Encoder {
+    fn new(tile: &Tile, options: &MvtOptions) -> Self {
+        let extent = f64::from(options.extent);
+        let buffer = f64::from(options.buffer);
+        Encoder {
+            tile: *tile,
+            extent,
+            clip: Rect::new(
+                Coordinate {
+                    x: -buffer,
+                    y: -buffer,
+                },
+                Coordinate {
+                    x: extent + buffer,
+                    y: extent + buffer,
+                },
+            ),
+            tolerance: options.tolerance,
+        }
+    }
+
+    fn project<T: Float>(&self, coord: Coordinate<T>) -> Coordinate<f64> {
+        let coord = Coordinate {
+            x: coord.x.to_f64().unwrap(),
+            y: coord.y.to_f64().unwrap(),
+        };
+        let (x, y) = tile_position(coord, self.tile.zoom());
+        Coordinate {
+            x: (x - f64::from(self.tile.x())) * self.extent,
+            y: (y - f64::from(self.tile.y())) * self.extent,
+        }
+    }
+
+    fn project_line_string<T: Float>(&self, coords: &[Coordinate<T>]) -> LineString<f64> {
+        coords.iter().map(|coord| self.project(*coord)).collect()
+    }
+
+    // Simplify `line_string`, then round it to tile coordinates, dropping repeated points
+    fn round(&self, line_string: &LineString<f64>) -> Vec<TilePoint> {
+        let simplified;
+        let line_string = if self.tolerance > 0. {
+            simplified = line_string.simplify(&self.tolerance);
+            &simplified
+        } else {
+            line_string
+        };
+        let mut points: Vec<TilePoint> = line_string
+            .0
+            .iter()
+            .map(|coord| [coord.x.round() as i32, coord.y.round() as i32])
+            .collect();
+        points.dedup();
+        points
+    }
+
+    fn point<T: Float>(&self, coord: Coordinate<T>, parts: &mut Parts) {
+        let coord = self.project(coord);
+        let (min, max) = (self.clip.min(), self.clip.max());
+        if coord.x >= min.x && coord.x <= max.x && coord.y >= min.y && coord.y <= max.y {
+            parts
+                .points
+                .push([coord.x.round() as i32, coord.y.round() as i32]);
+        }
+    }
+
+    fn line_string<T: Float>(&self, coords: &[Coordinate<T>], parts: &mut Parts) {
+        let projected = self.project_line_string(coords);
+        for part in projected.clip_to_rect(&self.clip).0 {
+            let part = self.round(&part);
+            if part.len() >= 2 {
+                parts.lines.push(part);
+            }
+        }
+    }
+
+    fn polygon<T: Float>(&self, polygon: &Polygon<T>, parts: &mut Parts) {
+        let projected = Polygon::new(
+            self.project_line_string(&polygon.exterior().0),
+            polygon
+                .interiors()
+                .iter()
+                .map(|interior| self.project_line_string(&interior.0))
+                .collect(),
+        );
+        let clipped = match projected.clip_to_rect(&self.clip) {
+            Some(clipped) => clipped,
+            None => return,
+        };
+        let exterior = match self.ring(clipped.exterior(), true) {
+            Some(exterior) => exterior,
+            None => return,
+        };
+        parts.rings.push(exterior);
+        for interior in clipped.interiors() {
+            if let Some(interior) = self.ring(interior, false) {
+                parts.rings.push(interior);
+            }
+        }
+    }
+
+    // Round a ring, and orient it with a positive area in tile coordinates if it's an exterior,
+    // or negative if not, or return `None` if it has no area left
+    fn ring(&self, ring: &LineString<f64>, exterior: bool) -> Option<Vec<TilePoint>> {
+        let mut ring = self.round(ring);
+        if ring.len() < 4 {
+            return None;
+        }
+        let area = twice_signed_ring_area(&LineString(
+            ring.iter()
+                .map(|&[x, y]| Coordinate {
+                    x: i64::from(x),
+                    y: i64::from(y),
+                })
+                .collect(),
+        ));
+        if area == 0 {
+            return None;
+        }
+        if (area > 0) != exterior {
+            ring.reverse();
+        }
+        Some(ring)
+    }
+}
+
+// Add the parts of a geometry in the encoder's tile
+trait Encode<T: Float> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts);
+}
+
+impl<T: Float> Encode<T> for Point<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.point(self.0, parts);
+    }
+}
+
+impl<T: Float> Encode<T> for Line<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.line_string(&[self.start, self.end], parts);
+    }
+}
+
+impl<T: Float> Encode<T> for LineString<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.line_string(&self.0, parts);
+    }
+}
+
+impl<T: Float> Encode<T> for Polygon<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.polygon(self, parts);
+    }
+}
+
+impl<T: Float> Encode<T> for MultiPoint<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        for point in &self.0 {
+            encoder.point(point.0, parts);
+        }
+    }
+}
+
+impl<T: Float> Encode<T> for MultiLineString<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        for line_string in &self.0 {
+            encoder.line_string(&line_string.0, parts);
+        }
+    }
+}
+
+impl<T: Float> Encode<T> for MultiPolygon<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        for polygon in &self.0 {
+            encoder.polygon(polygon, parts);
+        }
+    }
+}
+
+impl<T: Float> Encode<T> for Rect<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.polygon(&Polygon::from(*self), parts);
+    }
+}
+
+impl<T: Float> Encode<T> for Triangle<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        encoder.polygon(&Polygon::from(*self), parts);
+    }
+}
+
+impl<T: Float> Encode<T> for GeometryCollection<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        for geometry in &self.0 {
+            geometry.encode(encoder, parts);
+        }
+    }
+}
+
+impl<T: Float> Encode<T> for Geometry<T> {
+    fn encode(&self, encoder: &Encoder, parts: &mut Parts) {
+        match self {
+            Geometry::Point(g) => g.encode(encoder, parts),
+            Geometry::Line(g) => g.encode(encoder, parts),
+            Geometry::LineString(g) => g.encode(encoder, parts),
+            Geometry::Polygon(g) => g.encode(encoder, parts),
+            Geometry::MultiPoint(g) => g.encode(encoder, parts),
+            Geometry::MultiLineString(g) => g.encode(encoder, parts),
+            Geometry::MultiPolygon(g) => g.encode(encoder, parts),
+            Geometry::GeometryCollection(g) => g.encode(encoder, parts),
+            Geometry::Rect(g) => g.encode(encoder, parts),
+            Geometry::Triangle(g) => g.encode(encoder, parts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    // Decode the rings of a polygon geometry
+    fn decode_rings(commands: &[u32]) -> Vec<Vec<TilePoint>> {
+        let mut rings = vec![];
+        let mut cursor = [0, 0];
+        let mut commands = commands.iter();
+        while let Some(command) = commands.next() {
+            let (id, count) = (command & 0x7, command >> 3);
+            if id == CLOSE_PATH {
+                continue;
+            }
+            if id == MOVE_TO {
+                rings.push(vec![]);
+            }
+            for _ in 0..count {
+                for value in cursor.iter_mut() {
+                    let parameter = *commands.next().unwrap();
+                    *value += ((parameter >> 1) as i32) ^ -((parameter & 1) as i32);
+                }
+                rings.last_mut().unwrap().push(cursor);
+            }
+        }
+        rings
+    }
+
+    fn area(ring: &[TilePoint]) -> i64 {
+        (0..ring.len())
+            .map(|i| {
+                let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+                i64::from(a[0]) * i64::from(b[1]) - i64::from(b[0]) * i64::from(a[1])
+            })
+            .sum()
+    }
+
+    #[test]
+    fn specification_examples() {
+        let commands = |parts: Parts| parts.finish().pop().unwrap().commands;
+        assert_eq!(
+            commands(Parts {
+                points: vec![[25, 17]],
+                ..Parts::default()
+            }),
+            vec![9, 50, 34]
+        );
+        assert_eq!(
+            commands(Parts {
+                points: vec![[5, 7], [3, 2]],
+                ..Parts::default()
+            }),
+            vec![17, 10, 14, 3, 9]
+        );
+        assert_eq!(
+            commands(Parts {
+                lines: vec![vec![[2, 2], [2, 10], [10, 10]], vec![[1, 1], [3, 5]]],
+                ..Parts::default()
+            }),
+            vec![9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8]
+        );
+        assert_eq!(
+            commands(Parts {
+                rings: vec![vec![[3, 6], [8, 12], [20, 34], [3, 6]]],
+                ..Parts::default()
+            }),
+            vec![9, 6, 12, 18, 10, 12, 24, 44, 15]
+        );
+        assert_eq!(
+            commands(Parts {
+                rings: vec![
+                    vec![[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]],
+                    vec![[11, 11], [20, 11], [20, 20], [11, 20], [11, 11]],
+                    vec![[13, 13], [13, 17], [17, 17], [17, 13], [13, 13]],
+                ],
+                ..Parts::default()
+            }),
+            vec![
+                9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4,
+                13, 26, 0, 8, 8, 0, 0, 7, 15
+            ]
+        );
+    }
+
+    #[test]
+    fn points() {
+        let tile = Tile::new(0, 0, 0).unwrap();
+        let points = MultiPoint(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 90., y: 0.),
+            point!(x: 180., y: 0.),
+        ]);
+        // MoveTo(2048, 2048), (3072, 2048), (4096, 2048)
+        assert_eq!(
+            points.to_mvt(&tile),
+            Some(MvtGeometry {
+                geometry_type: MvtGeometryType::Point,
+                commands: vec![25, 4096, 4096, 2048, 0, 2048, 0],
+            })
+        );
+        // the points of the tile to the east are in its buffer, or beyond it
+        let tile = Tile::new(0, 0, 1).unwrap();
+        assert_eq!(
+            point!(x: 0., y: 0.).to_mvt(&tile).unwrap().commands,
+            vec![9, 8192, 8192]
+        );
+        assert_eq!(point!(x: 5., y: 5.).to_mvt(&tile), None);
+    }
+
+    #[test]
+    fn lines() {
+        let tile = Tile::new(0, 0, 0).unwrap();
+        let options = MvtOptions {
+            extent: 360,
+            buffer: 0,
+            tolerance: 1.,
+        };
+        // many nearly collinear points are simplified away, and the line clipped to the tile
+        let line_string: LineString<f64> = (0..=200)
+            .map(|i| {
+                (
+                    f64::from(i) * 2. - 100.,
+                    if i % 2 == 0 { 0. } else { 0.001 },
+                )
+            })
+            .chain(vec![(300., 0.)])
+            .collect();
+        // MoveTo(80, 180), LineTo(360, 180)
+        assert_eq!(
+            line_string.to_mvt_with_options(&tile, &options),
+            Some(MvtGeometry {
+                geometry_type: MvtGeometryType::LineString,
+                commands: vec![9, 160, 360, 10, 560, 0],
+            })
+        );
+        let options = MvtOptions {
+            tolerance: 0.,
+            ..options
+        };
+        let commands = line_string
+            .to_mvt_with_options(&tile, &options)
+            .unwrap()
+            .commands;
+        // without simplification, every vertex in the tile is kept
+        assert_eq!(commands[3], LINE_TO | (140 << 3));
+
+        // a line beyond the buffer of the tile
+        let line = Line::new((-50., 10.), (-40., 20.));
+        assert_eq!(line.to_mvt(&Tile::new(1, 0, 1).unwrap()), None);
+    }
+
+    #[test]
+    fn polygon_winding() {
+        let tile = Tile::new(0, 0, 0).unwrap();
+        let counter_clockwise = polygon!(
+            exterior: [(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)],
+            interiors: [[(x: -5., y: -5.), (x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: -5.)]],
+        );
+        let clockwise = polygon!(
+            exterior: [(x: -10., y: -10.), (x: -10., y: 10.), (x: 10., y: 10.), (x: 10., y: -10.)],
+            interiors: [[(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)]],
+        );
+        for polygon in &[counter_clockwise, clockwise] {
+            let geometry = polygon.to_mvt(&tile).unwrap();
+            assert_eq!(geometry.geometry_type, MvtGeometryType::Polygon);
+            let rings = decode_rings(&geometry.commands);
+            assert_eq!(rings.len(), 2);
+            assert!(area(&rings[0]) > 0);
+            assert!(area(&rings[1]) < 0);
+        }
+    }
+
+    #[test]
+    fn clipped_polygons() {
+        // a polygon across the edges of four tiles at zoom level 1, which are each clipped to
+        // their buffer, and oriented in their own tile coordinates
+        let polygon = polygon!(
+            exterior: [(x: -20., y: -20.), (x: -20., y: 20.), (x: 20., y: 20.), (x: 20., y: -20.)],
+            interiors: [[(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)]],
+        );
+        for &(x, y) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let geometry = polygon.to_mvt(&Tile::new(x, y, 1).unwrap()).unwrap();
+            let rings = decode_rings(&geometry.commands);
+            assert_eq!(rings.len(), 2);
+            assert!(area(&rings[0]) > 0);
+            assert!(area(&rings[1]) < 0);
+            for point in rings.iter().flatten() {
+                assert!(point.iter().all(|value| (-64..=4096 + 64).contains(value)));
+            }
+        }
+
+        // a polygon too small for the tile, and one outside it
+        let tile = Tile::new(1, 0, 1).unwrap();
+        let tiny = polygon![(x: 10., y: 10.), (x: 10.001, y: 10.), (x: 10.001, y: 10.001)];
+        assert_eq!(tiny.to_mvt(&tile), None);
+        let outside = polygon![(x: -20., y: 10.), (x: -10., y: 10.), (x: -10., y: 20.)];
+        assert_eq!(outside.to_mvt(&tile), None);
+        let rect = Rect::new((1., 1.), (2., 2.));
+        assert_eq!(
+            rect.to_mvt(&tile).unwrap().geometry_type,
+            MvtGeometryType::Polygon
+        );
+    }
+
+    #[test]
+    fn geometry_collections() {
+        let tile = Tile::new(0, 0, 0).unwrap();
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]),
+            Geometry::Point(point!(x: 0., y: 0.)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 10., y: 10.)]),
+            Geometry::Point(point!(x: 90., y: 0.)),
+        ]);
+        let geometries = gc.to_mvt(&tile);
+        let types: Vec<_> = geometries.iter().map(|g| g.geometry_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                MvtGeometryType::Point,
+                MvtGeometryType::LineString,
+                MvtGeometryType::Polygon
+            ]
+        );
+        assert_eq!(geometries[0].commands, vec![17, 4096, 4096, 2048, 0]);
+        assert_eq!(Geometry::GeometryCollection(gc).to_mvt(&tile), geometries);
+        assert!(GeometryCollection::<f64>(vec![]).to_mvt(&tile).is_empty());
+    }
+}
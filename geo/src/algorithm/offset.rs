@@ -0,0 +1,571 @@
+use crate::algorithm::buffer::{join_interior, offset, BufferStyle};
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::utils::{cmp_values, is_finite_coord};
+use crate::{Coordinate, Line, LineString, MultiLineString};
+use num_traits::Float;
+
+/// Compute the parallel curve at a distance from a line.
+///
+/// A positive distance offsets to the left of the line, in the direction it's drawn, and a
+/// negative one to its right. The offset segments are connected on the outside of each corner by
+/// the `join_style` of the [`BufferStyle`](../buffer/struct.BufferStyle.html) (its `cap_style`
+/// is ignored), and the parts of the curve closer to the line than the distance, such as the
+/// loops formed on the inside of corners, are removed, which may split the curve into several
+/// line strings. A closed line string gives closed curves.
+///
+/// A line string with a NaN or infinite coordinate has no offset curve, nor does any line
+/// string for a NaN or infinite distance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::buffer::{BufferStyle, JoinStyle};
+/// use geo::algorithm::offset::Offset;
+/// use geo::{line_string, MultiLineString};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+///
+/// // on the inside of the corner, the offset segments are trimmed where they cross
+/// assert_eq!(
+///     line_string.offset(1.),
+///     MultiLineString(vec![line_string![(x: 0., y: 1.), (x: 3., y: 1.), (x: 3., y: 4.)]])
+/// );
+///
+/// // on the outside, they're joined
+/// let mitre = BufferStyle {
+///     join_style: JoinStyle::Mitre,
+///     ..BufferStyle::default()
+/// };
+/// assert_eq!(
+///     line_string.offset_with_style(-1., &mitre),
+///     MultiLineString(vec![line_string![(x: 0., y: -1.), (x: 5., y: -1.), (x: 5., y: 4.)]])
+/// );
+/// ```
+pub trait Offset<T: Float> {
+    /// The parallel curve with round joins
+    fn offset(&self, distance: T) -> MultiLineString<T> {
+        self.offset_with_style(distance, &BufferStyle::default())
+    }
+
+    fn offset_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T>;
+}
+
+impl<T: Float> Offset<T> for Line<T> {
+    fn offset_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T> {
+        LineString(vec![self.start, self.end]).offset_with_style(distance, style)
+    }
+}
+
+impl<T: Float> Offset<T> for LineString<T> {
+    fn offset_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T> {
+        if !distance.is_finite() || !self.0.iter().all(is_finite_coord) {
+            return MultiLineString(vec![]);
+        }
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 {
+            return MultiLineString(vec![]);
+        }
+        if distance == T::zero() {
+            return MultiLineString(vec![LineString(coords)]);
+        }
+        let edges = raw_offset(&coords, distance, style);
+        let pieces = node(&edges);
+        let kept = pieces
+            .into_iter()
+            .flat_map(|piece| far_parts(piece, &coords, distance.abs()));
+        let closed = coords.len() > 2 && coords.first() == coords.last();
+        MultiLineString(chain(kept, closed))
+    }
+}
+
+impl<T: Float> Offset<T> for MultiLineString<T> {
+    fn offset_with_style(&self, distance: T, style: &BufferStyle<T>) -> MultiLineString<T> {
+        MultiLineString(
+            self.0
+                .iter()
+                .flat_map(|line_string| line_string.offset_with_style(distance, style).0)
+                .collect(),
+        )
+    }
+}
+
+/// What an edge of the raw offset curve was made from
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Source {
+    /// The offset of a segment of the line
+    Segment(usize),
+    /// The join on the outside of the corner at the start of a segment
+    OuterJoin(usize),
+    /// The connection on the inside of the corner at the start of a segment
+    InnerJoin(usize),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Edge<T: Float> {
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    source: Source,
+}
+
+/// The offset curve before it's cleaned up: the offset segments, connected by joins
+fn raw_offset<T: Float>(
+    coords: &[Coordinate<T>],
+    distance: T,
+    style: &BufferStyle<T>,
+) -> Vec<Edge<T>> {
+    let directions: Vec<(T, T)> = coords
+        .windows(2)
+        .map(|pair| {
+            let (dx, dy) = (pair[1].x - pair[0].x, pair[1].y - pair[0].y);
+            let length = dx.hypot(dy);
+            (dx / length, dy / length)
+        })
+        .collect();
+    let normal = |(dx, dy): (T, T)| (-dy, dx);
+    let closed = coords.len() > 2 && coords.first() == coords.last();
+
+    let mut edges = vec![];
+    let mut push = |points: &[Coordinate<T>], source: Source| {
+        for pair in points.windows(2) {
+            if pair[0] != pair[1] {
+                edges.push(Edge {
+                    start: pair[0],
+                    end: pair[1],
+                    source,
+                });
+            }
+        }
+    };
+    let segments = directions.len();
+    for (index, direction) in directions.iter().enumerate() {
+        let n = normal(*direction);
+        let start = offset(coords[index], n, distance);
+        let end = offset(coords[index + 1], n, distance);
+        push(&[start, end], Source::Segment(index));
+
+        // the join at the end of this segment, including the closing one of a ring
+        let next = match (index + 1 < segments, closed) {
+            (true, _) => index + 1,
+            (false, true) => 0,
+            (false, false) => continue,
+        };
+        let outgoing = directions[next];
+        let to = offset(coords[index + 1], normal(outgoing), distance);
+        let cross = direction.0 * outgoing.1 - direction.1 * outgoing.0;
+        let dot = direction.0 * outgoing.0 + direction.1 * outgoing.1;
+        if cross == T::zero() && dot < T::zero() {
+            // back the way it came, so round the end; the sign of zero picks the side to sweep
+            let side = if distance > T::zero() {
+                -T::zero()
+            } else {
+                T::zero()
+            };
+            let mut points = vec![end];
+            points.extend(join_interior(coords[index + 1], end, to, side, dot, style));
+            points.push(to);
+            push(&points, Source::OuterJoin(next));
+        } else if cross * distance < T::zero() {
+            let mut points = vec![end];
+            points.extend(join_interior(coords[index + 1], end, to, cross, dot, style));
+            points.push(to);
+            push(&points, Source::OuterJoin(next));
+        } else {
+            push(&[end, to], Source::InnerJoin(next));
+        }
+    }
+    edges
+}
+
+/// Split the edges where they cross or touch each other
+fn node<T: Float>(edges: &[Edge<T>]) -> Vec<Edge<T>> {
+    let min = |a: T, b: T| if b < a { b } else { a };
+    let max = |a: T, b: T| if b > a { b } else { a };
+    let min_x = |e: &Edge<T>| min(e.start.x, e.end.x);
+    let max_x = |e: &Edge<T>| max(e.start.x, e.end.x);
+    let box_contains = |e: &Edge<T>, c: Coordinate<T>| {
+        c.x >= min_x(e)
+            && c.x <= max_x(e)
+            && c.y >= min(e.start.y, e.end.y)
+            && c.y <= max(e.start.y, e.end.y)
+    };
+
+    let mut splits: Vec<Vec<Coordinate<T>>> = edges.iter().map(|_| vec![]).collect();
+    // sweep the edges in order of their least x, only comparing those whose bounding boxes
+    // overlap
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|a, b| cmp_values(min_x(&edges[*a]), min_x(&edges[*b])));
+    for (position, &i) in order.iter().enumerate() {
+        let a = &edges[i];
+        for &j in &order[position + 1..] {
+            let b = &edges[j];
+            if min_x(b) > max_x(a) {
+                break;
+            }
+            let o1 = orient2d(a.start, a.end, b.start);
+            let o2 = orient2d(a.start, a.end, b.end);
+            let o3 = orient2d(b.start, b.end, a.start);
+            let o4 = orient2d(b.start, b.end, a.end);
+            let collinear = Orientation::Collinear;
+            // endpoints of one edge on the other, including where collinear edges overlap
+            for (o, edge, split, c) in [
+                (o1, a, i, b.start),
+                (o2, a, i, b.end),
+                (o3, b, j, a.start),
+                (o4, b, j, a.end),
+            ]
+            .iter()
+            {
+                if *o == collinear && box_contains(edge, *c) {
+                    splits[*split].push(*c);
+                }
+            }
+            if o1 != collinear
+                && o2 != collinear
+                && o3 != collinear
+                && o4 != collinear
+                && o1 != o2
+                && o3 != o4
+            {
+                let (adx, ady) = (a.end.x - a.start.x, a.end.y - a.start.y);
+                let (bdx, bdy) = (b.end.x - b.start.x, b.end.y - b.start.y);
+                let t = ((b.start.x - a.start.x) * bdy - (b.start.y - a.start.y) * bdx)
+                    / (adx * bdy - ady * bdx);
+                let crossing = Coordinate {
+                    x: a.start.x + t * adx,
+                    y: a.start.y + t * ady,
+                };
+                splits[i].push(crossing);
+                splits[j].push(crossing);
+            }
+        }
+    }
+
+    let mut pieces = vec![];
+    for (edge, mut split) in edges.iter().zip(splits) {
+        let (dx, dy) = (edge.end.x - edge.start.x, edge.end.y - edge.start.y);
+        let along = |c: &Coordinate<T>| (c.x - edge.start.x) * dx + (c.y - edge.start.y) * dy;
+        split.retain(|c| *c != edge.start && *c != edge.end);
+        split.sort_by(|a, b| cmp_values(along(a), along(b)));
+        split.dedup();
+        let points = std::iter::once(edge.start)
+            .chain(split)
+            .chain(std::iter::once(edge.end));
+        let mut previous = edge.start;
+        for point in points.skip(1) {
+            pieces.push(Edge {
+                start: previous,
+                end: point,
+                source: edge.source,
+            });
+            previous = point;
+        }
+    }
+    pieces
+}
+
+/// The parts of a piece of the raw offset curve at least `distance` from the line, other than
+/// from the segments it was made from, which it's at the distance of by construction
+fn far_parts<T: Float>(piece: Edge<T>, coords: &[Coordinate<T>], distance: T) -> Vec<Edge<T>> {
+    let segments = coords.len() - 1;
+    let excluded = |index: usize| match piece.source {
+        Source::Segment(segment) => index == segment,
+        // the segments on either side of the corner
+        Source::OuterJoin(segment) => index == segment || (index + 1) % segments == segment,
+        Source::InnerJoin(_) => false,
+    };
+    let at = |t: T| Coordinate {
+        x: piece.start.x + t * (piece.end.x - piece.start.x),
+        y: piece.start.y + t * (piece.end.y - piece.start.y),
+    };
+    // allow for rounding in the computation of the offset
+    let tolerance = distance * T::from(1e-9).unwrap();
+    let threshold = distance - tolerance;
+    let (min_x, max_x) = (
+        piece.start.x.min(piece.end.x),
+        piece.start.x.max(piece.end.x),
+    );
+    let (min_y, max_y) = (
+        piece.start.y.min(piece.end.y),
+        piece.start.y.max(piece.end.y),
+    );
+
+    // the distance to a segment is convex along the piece, so the part of the piece too close
+    // to each segment is an interval, found by searching for the nearest point and then for
+    // where the distance crosses the threshold on either side of it
+    let mut too_close: Vec<(T, T)> = vec![];
+    for (_, pair) in coords.windows(2).enumerate().filter(|(i, _)| !excluded(*i)) {
+        if pair[0].x.min(pair[1].x) - distance > max_x
+            || pair[0].x.max(pair[1].x) + distance < min_x
+            || pair[0].y.min(pair[1].y) - distance > max_y
+            || pair[0].y.max(pair[1].y) + distance < min_y
+        {
+            continue;
+        }
+        let f = |t: T| segment_distance(at(t), pair[0], pair[1]);
+        let three = T::from(3).unwrap();
+        let (mut low, mut high) = (T::zero(), T::one());
+        for _ in 0..SEARCH_STEPS {
+            let (t1, t2) = (low + (high - low) / three, high - (high - low) / three);
+            if f(t1) < f(t2) {
+                high = t2;
+            } else {
+                low = t1;
+            }
+        }
+        let nearest = (low + high) / (T::one() + T::one());
+        if f(nearest) >= threshold {
+            continue;
+        }
+        // where the piece crosses into the segment's reach, snapped to its ends
+        let crossing = |mut inside: T, mut outside: T| {
+            for _ in 0..SEARCH_STEPS {
+                let t = (inside + outside) / (T::one() + T::one());
+                if f(t) < distance {
+                    inside = t;
+                } else {
+                    outside = t;
+                }
+            }
+            inside
+        };
+        let start = crossing(nearest, T::zero());
+        let end = crossing(nearest, T::one());
+        let start = if distance_between(at(start), piece.start) <= tolerance {
+            T::zero()
+        } else {
+            start
+        };
+        let end = if distance_between(at(end), piece.end) <= tolerance {
+            T::one()
+        } else {
+            end
+        };
+        too_close.push((start, end));
+    }
+
+    too_close.sort_by(|a, b| cmp_values(a.0, b.0));
+    let mut parts = vec![];
+    let mut from = T::zero();
+    for (start, end) in too_close {
+        if start > from {
+            parts.push((from, start));
+        }
+        from = from.max(end);
+    }
+    if from < T::one() {
+        parts.push((from, T::one()));
+    }
+    let point = |t: T| match t {
+        t if t == T::zero() => piece.start,
+        t if t == T::one() => piece.end,
+        t => at(t),
+    };
+    parts
+        .into_iter()
+        .map(|(start, end)| Edge {
+            start: point(start),
+            end: point(end),
+            source: piece.source,
+        })
+        .filter(|part| distance_between(part.start, part.end) > tolerance)
+        .collect()
+}
+
+fn distance_between<T: Float>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+const SEARCH_STEPS: usize = 100;
+
+fn segment_distance<T: Float>(c: Coordinate<T>, start: Coordinate<T>, end: Coordinate<T>) -> T {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length_squared = dx * dx + dy * dy;
+    let t = ((c.x - start.x) * dx + (c.y - start.y) * dy) / length_squared;
+    let t = t.max(T::zero()).min(T::one());
+    (c.x - (start.x + t * dx)).hypot(c.y - (start.y + t * dy))
+}
+
+/// Join the kept pieces, in order along the curve, into line strings wherever one ends where
+/// the next starts, also across the closing point of a closed curve, dropping the vertices
+/// between collinear pieces
+fn chain<T: Float>(pieces: impl Iterator<Item = Edge<T>>, closed: bool) -> Vec<LineString<T>> {
+    let mut line_strings: Vec<Vec<Coordinate<T>>> = vec![];
+    for piece in pieces {
+        match line_strings.last_mut() {
+            Some(coords) if coords.last() == Some(&piece.start) => extend(coords, piece.end),
+            _ => line_strings.push(vec![piece.start, piece.end]),
+        }
+    }
+    if closed && line_strings.len() > 1 {
+        let first = line_strings[0][0];
+        if line_strings.last().and_then(|coords| coords.last()) == Some(&first) {
+            let mut last = line_strings.pop().unwrap();
+            for coord in line_strings[0].drain(1..) {
+                extend(&mut last, coord);
+            }
+            line_strings[0] = last;
+        }
+    }
+    line_strings.into_iter().map(LineString).collect()
+}
+
+fn extend<T: Float>(coords: &mut Vec<Coordinate<T>>, coord: Coordinate<T>) {
+    if let [.., before, last] = coords[..] {
+        let straight = (last.x - before.x) * (coord.x - last.x)
+            + (last.y - before.y) * (coord.y - last.y)
+            > T::zero();
+        if straight && orient2d(before, last, coord) == Orientation::Collinear {
+            coords.pop();
+        }
+    }
+    coords.push(coord);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::buffer::JoinStyle;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::{line_string, Point};
+
+    fn style(join_style: JoinStyle) -> BufferStyle<f64> {
+        BufferStyle {
+            join_style,
+            ..BufferStyle::default()
+        }
+    }
+
+    // every vertex of the offset is at least the distance from the line
+    fn assert_far_enough(
+        offset: &MultiLineString<f64>,
+        line_string: &LineString<f64>,
+        distance: f64,
+    ) {
+        for coord in offset.0.iter().flat_map(|l| l.0.iter()) {
+            let to_line = Point(*coord).euclidean_distance(line_string);
+            assert!(to_line > distance - 1e-9, "{:?} is {} away", coord, to_line);
+        }
+    }
+
+    #[test]
+    fn straight_line() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 0. });
+        assert_eq!(
+            line.offset(1.),
+            MultiLineString(vec![line_string![(x: 0., y: 1.), (x: 4., y: 1.)]])
+        );
+        assert_eq!(
+            line.offset(-1.),
+            MultiLineString(vec![line_string![(x: 0., y: -1.), (x: 4., y: -1.)]])
+        );
+        assert_eq!(
+            line.offset(0.),
+            MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 4., y: 0.)]])
+        );
+        assert!(LineString::<f64>(vec![]).offset(1.).0.is_empty());
+    }
+
+    #[test]
+    fn joins() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: -4.)];
+        assert_eq!(
+            line_string.offset_with_style(1., &style(JoinStyle::Bevel)),
+            MultiLineString(vec![line_string![
+                (x: 0., y: 1.),
+                (x: 4., y: 1.),
+                (x: 5., y: 0.),
+                (x: 5., y: -4.),
+            ]])
+        );
+        let round = line_string.offset(1.);
+        assert_eq!(round.0.len(), 1);
+        // eight segments approximate the quarter circle
+        assert_eq!(round.0[0].0.len(), 2 + 9);
+        let quarter = 8. * 2. * (std::f64::consts::PI / 32.).sin();
+        assert_relative_eq!(round.euclidean_length(), 8. + quarter, epsilon = 1e-9);
+        assert_far_enough(&round, &line_string, 1.);
+
+        // a sharp corner exceeds the mitre limit, so is bevelled
+        let sharp = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 0.5)];
+        assert_eq!(
+            sharp.offset_with_style(-1., &style(JoinStyle::Mitre)),
+            sharp.offset_with_style(-1., &style(JoinStyle::Bevel))
+        );
+    }
+
+    #[test]
+    fn loops_are_removed() {
+        // the offset inside a U narrower than twice the distance vanishes
+        let u = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 0., y: 1.)];
+        assert!(u.offset(1.).0.is_empty());
+        // and outside it, the mitred corners are kept
+        assert_eq!(
+            u.offset_with_style(-1., &style(JoinStyle::Mitre)),
+            MultiLineString(vec![line_string![
+                (x: 0., y: -1.),
+                (x: 5., y: -1.),
+                (x: 5., y: 2.),
+                (x: 0., y: 2.),
+            ]])
+        );
+
+        // a zigzag, whose inner offsets cross their neighbours
+        let zigzag: LineString<f64> = (0..10)
+            .map(|i| (i as f64, if i % 2 == 0 { 0. } else { 3. }))
+            .collect::<Vec<_>>()
+            .into();
+        for distance in [0.5, -0.5, 2., -2.].iter() {
+            let offset = zigzag.offset_with_style(*distance, &style(JoinStyle::Mitre));
+            assert!(!offset.0.is_empty());
+            assert_far_enough(&offset, &zigzag, distance.abs());
+            // the chords of round joins cut inside the circle
+            let offset = zigzag.offset(*distance);
+            assert!(!offset.0.is_empty());
+            let sagitta = (std::f64::consts::PI / 32.).cos();
+            assert_far_enough(&offset, &zigzag, distance.abs() * sagitta);
+        }
+
+        // a line crossing itself splits its offset on either side of the crossing, with the
+        // first and last pieces meeting where the offsets of the crossing segments do
+        let crossing = line_string![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        let offset = crossing.offset_with_style(0.5, &style(JoinStyle::Mitre));
+        assert_eq!(offset.0.len(), 3);
+        assert_far_enough(&offset, &crossing, 0.5);
+        assert_eq!(offset.0[0].0.last(), offset.0[2].0.first());
+    }
+
+    #[test]
+    fn closed_rings() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        // to the left of a counter-clockwise ring is inside
+        let inside = square.offset(1.);
+        assert_eq!(inside.0.len(), 1);
+        assert_eq!(inside.0[0].0.first(), inside.0[0].0.last());
+        assert_eq!(inside.euclidean_length(), 8.);
+        let outside = square.offset_with_style(-1., &style(JoinStyle::Mitre));
+        assert_eq!(outside.0.len(), 1);
+        assert_eq!(outside.0[0].0.first(), outside.0[0].0.last());
+        assert_eq!(outside.euclidean_length(), 24.);
+        assert!(square.offset(2.).0.is_empty());
+    }
+
+    #[test]
+    fn non_finite_input() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        assert!(line_string.offset(f64::NAN).0.is_empty());
+        assert!(line_string.offset(f64::INFINITY).0.is_empty());
+        let broken = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 4., y: 4.)];
+        assert!(broken.offset(1.).0.is_empty());
+        assert!(broken.offset(0.).0.is_empty());
+        let lines = MultiLineString(vec![broken, line_string.clone()]);
+        assert_eq!(lines.offset(1.), line_string.offset(1.));
+    }
+}
@@ -6,7 +6,8 @@ pub trait Orient<T> {
     /// Orients a Polygon's exterior and interior rings according to convention
     ///
     /// By default, the exterior ring of a Polygon is oriented counter-clockwise, and any interior
-    /// rings are oriented clockwise.
+    /// rings are oriented clockwise, following the OGC Simple Features convention.
+    /// `Direction::Reversed` gives the opposite (for example, ESRI Shapefile) convention.
     ///
     /// # Examples
     ///
@@ -0,0 +1,102 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use std::borrow::Cow;
+
+/// Receives the points, line strings, and polygons which a geometry is made of, so that an
+/// algorithm can handle every kind of geometry by handling just those three.
+pub(crate) trait PartsSink<'a, T: CoordinateType> {
+    fn add_point(&mut self, coord: Coordinate<T>);
+
+    fn add_line_string(&mut self, coords: &[Coordinate<T>]);
+
+    /// `Rect`s and `Triangle`s are added as owned polygons, and other polygons are borrowed.
+    fn add_polygon(&mut self, polygon: Cow<'a, Polygon<T>>);
+}
+
+/// Splits a geometry into points, line strings, and polygons; collections are split into the
+/// parts of their members.
+pub(crate) trait ToParts<T: CoordinateType> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S);
+
+    fn parts<'a, S: PartsSink<'a, T> + Default>(&'a self) -> S {
+        let mut sink = S::default();
+        self.add_parts(&mut sink);
+        sink
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for Point<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_point(self.0);
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for Line<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_line_string(&[self.start, self.end]);
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for LineString<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_line_string(&self.0);
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for Polygon<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_polygon(Cow::Borrowed(self));
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for Rect<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_polygon(Cow::Owned(Polygon::from(*self)));
+    }
+}
+
+impl<T: CoordinateType> ToParts<T> for Triangle<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        sink.add_polygon(Cow::Owned(Polygon::from(*self)));
+    }
+}
+
+macro_rules! impl_to_parts_for_collection {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordinateType> ToParts<T> for $type<T> {
+                fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+                    for member in &self.0 {
+                        member.add_parts(sink);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_to_parts_for_collection!(
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: CoordinateType> ToParts<T> for Geometry<T> {
+    fn add_parts<'a, S: PartsSink<'a, T>>(&'a self, sink: &mut S) {
+        match self {
+            Geometry::Point(g) => g.add_parts(sink),
+            Geometry::Line(g) => g.add_parts(sink),
+            Geometry::LineString(g) => g.add_parts(sink),
+            Geometry::Polygon(g) => g.add_parts(sink),
+            Geometry::MultiPoint(g) => g.add_parts(sink),
+            Geometry::MultiLineString(g) => g.add_parts(sink),
+            Geometry::MultiPolygon(g) => g.add_parts(sink),
+            Geometry::GeometryCollection(g) => g.add_parts(sink),
+            Geometry::Rect(g) => g.add_parts(sink),
+            Geometry::Triangle(g) => g.add_parts(sink),
+        }
+    }
+}
@@ -0,0 +1,537 @@
+use crate::algorithm::bool_ops::{clockwise_cmp, split_at_repeated_vertices};
+use crate::algorithm::relate::topology_graph::{cmp_coords, ring_position, split_segments};
+use crate::algorithm::relate::CoordPos;
+use crate::algorithm::winding_order::twice_signed_ring_area;
+use crate::utils::{cmp_values, is_finite_coord};
+use crate::{
+    Coordinate, CoordinateType, FractionalCoordinateType, LineString, MultiLineString,
+    MultiPolygon, Polygon,
+};
+use std::cmp::Ordering;
+use std::{error, fmt};
+
+/// The polygons enclosed by a set of lines, with the parts of the lines which don't bound any of
+/// them
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygonization<T: CoordinateType> {
+    /// The polygons, one for each area enclosed by the lines, with the areas enclosed within
+    /// them as their interiors
+    pub polygons: MultiPolygon<T>,
+    /// The parts of the lines with a free end, which don't lead around any area
+    pub dangles: MultiLineString<T>,
+    /// The parts of the lines bridging between areas, which have the same area on both sides
+    pub cut_edges: MultiLineString<T>,
+}
+
+/// Build the polygons enclosed by a set of line strings, such as the boundaries of parcels.
+pub trait Polygonize<T: FractionalCoordinateType> {
+    /// Build the polygons enclosed by the line strings, treating each line between their
+    /// vertices as an edge of a planar graph whose nodes are the vertices.
+    ///
+    /// The lines are noded first: they're split exactly where they cross, touch, or overlap each
+    /// other, so that edges only meet at their endpoints, and edges given more than once, in
+    /// either direction, are only used once. Every area enclosed by the edges then becomes a
+    /// polygon, and an area inside it, enclosed by edges which don't connect to those around it,
+    /// becomes one of its interiors, as well as a polygon of its own. The vertices of the edges
+    /// are kept, even where they're collinear, so neighbouring polygons share them.
+    ///
+    /// Edges with a NaN or infinite vertex can't enclose anything, so they're reported as
+    /// dangles, without being noded.
+    ///
+    /// The crossings are computed in the coordinate type, which must therefore be a
+    /// [`FractionalCoordinateType`](../../trait.FractionalCoordinateType.html). If rounding them
+    /// leaves edges which still cross or overlap, so that the faces can't be traced, a
+    /// [`FailedToTraceError`](struct.FailedToTraceError.html) is returned.
+    ///
+    /// The parts of the line strings which don't bound any polygon are reported too: dangles,
+    /// which lead to a free end, and cut edges, such as a bridge between two areas, which have
+    /// the same area on both sides. Each unbroken run of such edges along one of the input line
+    /// strings is reported as a line string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::polygonize::Polygonize;
+    /// use geo::{line_string, polygon, MultiLineString};
+    ///
+    /// let boundaries = MultiLineString(vec![
+    ///     // two parcels sharing a border
+    ///     line_string![
+    ///         (x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.),
+    ///         (x: 0., y: 1.), (x: 0., y: 0.)
+    ///     ],
+    ///     line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+    ///     // a driveway
+    ///     line_string![(x: 2., y: 1.), (x: 3., y: 2.)],
+    /// ]);
+    /// let polygonization = boundaries.polygonize().unwrap();
+    ///
+    /// assert_eq!(
+    ///     polygonization.polygons.0,
+    ///     vec![
+    ///         polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+    ///         polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)],
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     polygonization.dangles.0,
+    ///     vec![line_string![(x: 2., y: 1.), (x: 3., y: 2.)]]
+    /// );
+    /// assert!(polygonization.cut_edges.0.is_empty());
+    /// ```
+    fn polygonize(&self) -> Result<Polygonization<T>, FailedToTraceError>;
+}
+
+impl<T: FractionalCoordinateType> Polygonize<T> for [LineString<T>] {
+    fn polygonize(&self) -> Result<Polygonization<T>, FailedToTraceError> {
+        polygonize(self)
+    }
+}
+
+impl<T: FractionalCoordinateType> Polygonize<T> for MultiLineString<T> {
+    fn polygonize(&self) -> Result<Polygonization<T>, FailedToTraceError> {
+        polygonize(&self.0)
+    }
+}
+
+/// The faces of the graph couldn't be traced, as the crossings of its lines, rounded to the
+/// coordinate type, left edges meeting other than at their endpoints
+#[derive(Debug)]
+pub struct FailedToTraceError;
+
+impl fmt::Display for FailedToTraceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to trace the faces enclosed by the lines")
+    }
+}
+
+impl error::Error for FailedToTraceError {
+    fn description(&self) -> &str {
+        "failed to trace the faces enclosed by the lines"
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Status {
+    Bounding,
+    Dangle,
+    Cut,
+}
+
+// An edge of the graph, and where it came from
+struct Edge<T: CoordinateType> {
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    line: usize,
+    status: Status,
+}
+
+fn polygonize<T: FractionalCoordinateType>(
+    lines: &[LineString<T>],
+) -> Result<Polygonization<T>, FailedToTraceError> {
+    let segments: Vec<(usize, Coordinate<T>, Coordinate<T>)> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(line, line_string)| {
+            line_string
+                .lines()
+                .filter(|segment| segment.start != segment.end)
+                .map(move |segment| (line, segment.start, segment.end))
+        })
+        .collect();
+    let is_finite = |&(_, start, end): &(usize, Coordinate<T>, Coordinate<T>)| {
+        is_finite_coord(&start) && is_finite_coord(&end)
+    };
+
+    // only the finite segments are part of the graph, noded so that edges only meet at their
+    // endpoints
+    let finite: Vec<_> = segments
+        .iter()
+        .filter(|segment| is_finite(segment))
+        .map(|&(_, start, end)| (start, end))
+        .collect();
+    let mut splits = split_segments(&finite).into_iter();
+    let mut edges: Vec<Edge<T>> = vec![];
+    for segment in &segments {
+        let (line, start, end) = *segment;
+        if !is_finite(segment) {
+            edges.push(Edge {
+                start,
+                end,
+                line,
+                status: Status::Dangle,
+            });
+            continue;
+        }
+        // there's a split for each finite segment
+        let split = splits.next().unwrap();
+        for pair in split.windows(2).filter(|pair| pair[0] != pair[1]) {
+            edges.push(Edge {
+                start: pair[0],
+                end: pair[1],
+                line,
+                status: Status::Bounding,
+            });
+        }
+    }
+    let finite: Vec<usize> = (0..edges.len())
+        .filter(|&i| edges[i].status == Status::Bounding)
+        .collect();
+
+    // the index of the first of each edge's duplicates, so they share its status
+    let key = |edge: &Edge<T>| match cmp_coords(&edge.start, &edge.end) {
+        Ordering::Greater => (edge.end, edge.start),
+        _ => (edge.start, edge.end),
+    };
+    let mut order = finite.clone();
+    order.sort_by(|&i, &j| {
+        let (a, b) = (key(&edges[i]), key(&edges[j]));
+        cmp_coords(&a.0, &b.0)
+            .then(cmp_coords(&a.1, &b.1))
+            .then(i.cmp(&j))
+    });
+    let mut original: Vec<usize> = (0..edges.len()).collect();
+    for pair in order.windows(2) {
+        if key(&edges[pair[0]]) == key(&edges[pair[1]]) {
+            original[pair[1]] = original[pair[0]];
+        }
+    }
+    let unique: Vec<usize> = finite.into_iter().filter(|&i| original[i] == i).collect();
+
+    // each unique edge in both directions, sorted by their starting coordinate
+    let mut directed: Vec<(Coordinate<T>, Coordinate<T>, usize)> = unique
+        .iter()
+        .flat_map(|&i| {
+            let edge = &edges[i];
+            vec![(edge.start, edge.end, i), (edge.end, edge.start, i)]
+        })
+        .collect();
+    directed.sort_by(|a, b| cmp_coords(&a.0, &b.0).then(cmp_coords(&a.1, &b.1)));
+    let mut directions = vec![[None, None]; edges.len()];
+    for (d, &(_, _, i)) in directed.iter().enumerate() {
+        let slot = if directions[i][0].is_none() { 0 } else { 1 };
+        directions[i][slot] = Some(d);
+    }
+    let leaving = |node: Coordinate<T>| {
+        let begin = directed.partition_point(|e| cmp_coords(&e.0, &node) == Ordering::Less);
+        let end = directed.partition_point(|e| cmp_coords(&e.0, &node) != Ordering::Greater);
+        begin..end
+    };
+
+    // remove dangles, until every node is on at least two edges
+    let mut nodes: Vec<Coordinate<T>> = directed.iter().map(|e| e.0).collect();
+    while let Some(node) = nodes.pop() {
+        let mut remaining =
+            leaving(node).filter(|&d| edges[directed[d].2].status == Status::Bounding);
+        if let (Some(d), None) = (remaining.next(), remaining.next()) {
+            edges[directed[d].2].status = Status::Dangle;
+            nodes.push(directed[d].1);
+        }
+    }
+
+    // trace the faces, then remove the cut edges and trace them again, until there are none
+    let faces = loop {
+        let bounding = |d: usize| edges[directed[d].2].status == Status::Bounding;
+        let mut face_of: Vec<Option<usize>> = vec![None; directed.len()];
+        let mut faces = vec![];
+        for first in 0..directed.len() {
+            if face_of[first].is_some() || !bounding(first) {
+                continue;
+            }
+            let mut coords = vec![directed[first].0];
+            let mut current = first;
+            // every directed edge is on one face, so a trace longer than that has gone wrong,
+            // as where two edges leave a node in the same direction
+            for _ in 0..=directed.len() {
+                face_of[current] = Some(faces.len());
+                let (from, to, _) = directed[current];
+                coords.push(to);
+                // turn as far left as possible, so that the face is on the left; the edge back
+                // is always there, as every node is on at least two bounding edges
+                current = leaving(to)
+                    .filter(|&d| bounding(d))
+                    .min_by(|&a, &b| clockwise_cmp(to, from, directed[a].1, directed[b].1))
+                    .unwrap();
+                if current == first {
+                    break;
+                }
+            }
+            if current != first {
+                return Err(FailedToTraceError);
+            }
+            faces.push(coords);
+        }
+
+        // the same face is on the left of both directions of a cut edge
+        let mut found_cut = false;
+        for &i in &unique {
+            if let [Some(a), Some(b)] = directions[i] {
+                if face_of[a].is_some() && face_of[a] == face_of[b] {
+                    edges[i].status = Status::Cut;
+                    found_cut = true;
+                }
+            }
+        }
+        if !found_cut {
+            break faces;
+        }
+    };
+
+    // a face wound counter-clockwise is enclosed by its edges, and one wound clockwise is
+    // outside a connected part of the graph, so the part is an interior of the smallest
+    // enclosed face around it, if there is one
+    let mut polygons: Vec<(LineString<T>, T, Vec<LineString<T>>)> = vec![];
+    let mut outsides = vec![];
+    for face in faces {
+        let face = LineString(face);
+        if twice_signed_ring_area(&face) > T::zero() {
+            let mut rings: Vec<(LineString<T>, T)> = split_at_repeated_vertices(face.0)
+                .into_iter()
+                .map(|coords| {
+                    let ring = LineString(coords);
+                    let area = twice_signed_ring_area(&ring);
+                    (ring, area)
+                })
+                .collect();
+            let exterior = (0..rings.len())
+                .max_by(|&a, &b| cmp_values(rings[a].1, rings[b].1))
+                .unwrap();
+            let (exterior, area) = rings.remove(exterior);
+            let interiors = rings
+                .into_iter()
+                .filter(|(_, area)| *area < T::zero())
+                .map(|(ring, _)| ring)
+                .collect();
+            polygons.push((exterior, area, interiors));
+        } else {
+            outsides.push(face);
+        }
+    }
+    for outside in outsides {
+        // the parts of the graph don't meet, so the vertices of this one are either inside or
+        // outside each face of another
+        let vertex = outside.0[0];
+        let around = polygons
+            .iter_mut()
+            .filter(|(exterior, _, _)| ring_position(exterior, vertex) == CoordPos::Inside)
+            .min_by(|a, b| cmp_values(a.1, b.1));
+        if let Some((_, _, interiors)) = around {
+            interiors.extend(
+                split_at_repeated_vertices(outside.0)
+                    .into_iter()
+                    .map(LineString)
+                    .filter(|ring| twice_signed_ring_area(ring) < T::zero()),
+            );
+        }
+    }
+
+    let mut dangles = vec![];
+    let mut cut_edges = vec![];
+    for run in runs(&edges, &original) {
+        match run {
+            (Status::Dangle, coords) => dangles.push(LineString(coords)),
+            (Status::Cut, coords) => cut_edges.push(LineString(coords)),
+            (Status::Bounding, _) => {}
+        }
+    }
+    Ok(Polygonization {
+        polygons: MultiPolygon(
+            polygons
+                .into_iter()
+                .map(|(exterior, _, interiors)| Polygon::new(exterior, interiors))
+                .collect(),
+        ),
+        dangles: MultiLineString(dangles),
+        cut_edges: MultiLineString(cut_edges),
+    })
+}
+
+// The unbroken runs of edges of one status along each input line
+fn runs<T: CoordinateType>(
+    edges: &[Edge<T>],
+    original: &[usize],
+) -> Vec<(Status, Vec<Coordinate<T>>)> {
+    let mut runs: Vec<(usize, Status, Vec<Coordinate<T>>)> = vec![];
+    for (i, edge) in edges.iter().enumerate() {
+        let status = edges[original[i]].status;
+        match runs.last_mut() {
+            Some((line, run_status, coords))
+                if *line == edge.line
+                    && *run_status == status
+                    && coords.last() == Some(&edge.start) =>
+            {
+                coords.push(edge.end)
+            }
+            _ => runs.push((edge.line, status, vec![edge.start, edge.end])),
+        }
+    }
+    runs.into_iter()
+        .map(|(_, status, coords)| (status, coords))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn island_and_bridge() {
+        let outer = line_string![
+            (x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.), (x: 0., y: 0.)
+        ];
+        let island = line_string![
+            (x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.), (x: 2., y: 2.)
+        ];
+        let expected = vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+                interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.)]],
+            ],
+            polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+        ];
+
+        let polygonization = MultiLineString(vec![outer.clone(), island.clone()])
+            .polygonize()
+            .unwrap();
+        assert_eq!(polygonization.polygons.0, expected);
+        assert!(polygonization.cut_edges.0.is_empty());
+
+        // a bridge from the outer ring to the island, along two edges
+        let bridge = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let bridged = MultiLineString(vec![outer, island, bridge.clone()])
+            .polygonize()
+            .unwrap();
+        assert_eq!(bridged.polygons.0, expected);
+        assert_eq!(bridged.cut_edges.0, vec![bridge]);
+        assert!(bridged.dangles.0.is_empty());
+    }
+
+    #[test]
+    fn dangles_and_duplicates() {
+        let lines = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)],
+            // the same border, the other way around, and a repeated vertex
+            line_string![(x: 2., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.)],
+            line_string![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)],
+            // a branching dangle, from a vertex in the middle of the first line
+            line_string![(x: 1., y: 0.), (x: 1., y: -1.), (x: 1., y: -2.)],
+            line_string![(x: 1., y: -1.), (x: 2., y: -1.)],
+        ]);
+        let polygonization = lines.polygonize().unwrap();
+        assert_eq!(
+            polygonization.polygons.0,
+            vec![polygon![
+                (x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)
+            ]]
+        );
+        assert_eq!(
+            polygonization.dangles.0,
+            vec![
+                line_string![(x: 1., y: 0.), (x: 1., y: -1.), (x: 1., y: -2.)],
+                line_string![(x: 1., y: -1.), (x: 2., y: -1.)],
+            ]
+        );
+        assert!(polygonization.cut_edges.0.is_empty());
+    }
+
+    #[test]
+    fn non_finite_vertices() {
+        let square = line_string![
+            (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)
+        ];
+        // a ring broken by a NaN vertex, sharing an edge with the square
+        let broken = line_string![
+            (x: 1., y: 0.), (x: 2., y: 0.), (x: f64::NAN, y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)
+        ];
+        let polygonization = MultiLineString(vec![square, broken]).polygonize().unwrap();
+        assert_eq!(
+            polygonization.polygons.0,
+            vec![polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)]]
+        );
+        let dangles = &polygonization.dangles.0;
+        assert_eq!(dangles.len(), 2);
+        assert_eq!(dangles[0].0[..2], [(1., 0.).into(), (2., 0.).into()]);
+        assert!(dangles[0].0[2].x.is_nan());
+        assert!(dangles[1].0[0].x.is_nan());
+        assert_eq!(dangles[1].0[1], (1., 1.).into());
+        assert!(polygonization.cut_edges.0.is_empty());
+    }
+
+    #[test]
+    fn no_areas() {
+        let empty = Vec::<LineString<f64>>::new().polygonize().unwrap();
+        assert!(empty.polygons.0.is_empty() && empty.dangles.0.is_empty());
+
+        let path = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let polygonization = MultiLineString(vec![path.clone()]).polygonize().unwrap();
+        assert!(polygonization.polygons.0.is_empty());
+        assert_eq!(polygonization.dangles.0, vec![path]);
+    }
+
+    #[test]
+    fn overlapping_edges() {
+        // triangles sharing part of an edge, which leave the origin in the same direction
+        let lines = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: -1.), (x: 0., y: 0.)],
+        ]);
+        let polygonization = lines.polygonize().unwrap();
+        assert_eq!(
+            polygonization.polygons.0,
+            vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: -1.), (x: 1., y: 0.)],
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)],
+            ]
+        );
+        assert!(polygonization.dangles.0.is_empty());
+
+        // a ring doubling back along one of its own edges, from (7.5, 4) to (7, 3)
+        let ring = line_string![
+            (x: 8., y: 7.), (x: 4.5, y: 2.), (x: 7.5, y: 4.), (x: 7., y: 3.), (x: 10.5, y: 10.),
+            (x: 0.5, y: 9.), (x: 8., y: 7.)
+        ];
+        let polygonization = MultiLineString(vec![ring]).polygonize().unwrap();
+        assert_eq!(
+            polygonization.polygons.0,
+            vec![polygon![
+                (x: 0.5, y: 9.), (x: 8., y: 7.), (x: 4.5, y: 2.), (x: 7.5, y: 4.), (x: 10.5, y: 10.)
+            ]]
+        );
+        assert_eq!(
+            polygonization.dangles.0,
+            vec![line_string![(x: 7.5, y: 4.), (x: 7., y: 3.), (x: 7.5, y: 4.)]]
+        );
+    }
+
+    #[test]
+    fn crossing_edges() {
+        let bowtie = line_string![
+            (x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.), (x: 0., y: 0.)
+        ];
+        let polygonization = MultiLineString(vec![bowtie]).polygonize().unwrap();
+        assert_eq!(
+            polygonization.polygons.0,
+            vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 2.)],
+                polygon![(x: 1., y: 1.), (x: 2., y: 0.), (x: 2., y: 2.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn outside_is_not_an_interior() {
+        // the outside of the ring was taken for an interior of it, as the midpoint of one of its
+        // edges was rounded into it
+        let ring = line_string![
+            (x: 8., y: 4.), (x: 3., y: 6.), (x: -1., y: 4.000000000000001), (x: 3., y: 3.),
+            (x: 8., y: 4.)
+        ];
+        let polygonization = MultiLineString(vec![ring]).polygonize().unwrap();
+        let polygons = &polygonization.polygons.0;
+        assert_eq!(polygons.len(), 1);
+        assert!(polygons[0].interiors().is_empty());
+        assert_eq!(polygons[0].unsigned_area(), 13.5);
+    }
+}
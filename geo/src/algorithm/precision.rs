@@ -0,0 +1,435 @@
+use crate::algorithm::area::get_linestring_area;
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::map_coords::MapCoordsInplace;
+use crate::algorithm::remove_repeated_points::RemoveRepeatedPoints;
+use crate::algorithm::validation::Validation;
+use crate::{Coordinate, LineString, MultiPolygon, Polygon};
+use num_traits::Float;
+
+/// The precision of coordinates: either the full precision of the coordinate type, or a grid
+/// which every coordinate is rounded onto.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::precision::PrecisionModel;
+///
+/// let cents = PrecisionModel::decimal_places(2);
+/// assert_eq!(cents.make_precise(1.23456), 1.23);
+/// assert_eq!(cents.grid_size(), Some(0.01));
+///
+/// let fives = PrecisionModel::grid(5.);
+/// assert_eq!(fives.make_precise(12.6), 15.);
+///
+/// assert_eq!(PrecisionModel::floating().make_precise(1.23456), 1.23456);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PrecisionModel<T: Float> {
+    grid: Grid<T>,
+}
+
+// Grids finer than a unit are stored by their scale, the number of cells in a unit, so that
+// rounding to decimal places multiplies and divides by a whole number, which is exact
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Grid<T> {
+    Floating,
+    Scale(T),
+    Size(T),
+}
+
+impl<T: Float> PrecisionModel<T> {
+    /// The full precision of the coordinate type, which leaves coordinates as they are
+    pub fn floating() -> Self {
+        PrecisionModel {
+            grid: Grid::Floating,
+        }
+    }
+
+    /// A grid of squares of side `size`, whose corners are the multiples of `size`.
+    ///
+    /// # Panics
+    ///
+    /// If `size` isn't positive and finite.
+    pub fn grid(size: T) -> Self {
+        assert!(
+            size > T::zero() && size.is_finite(),
+            "the grid size must be positive and finite"
+        );
+        let scale = size.recip();
+        let grid = if size < T::one() && (scale - scale.round()).abs() <= scale * T::epsilon() {
+            Grid::Scale(scale.round())
+        } else {
+            Grid::Size(size)
+        };
+        PrecisionModel { grid }
+    }
+
+    /// A grid of `places` decimal places, so `2` rounds to hundredths, and `-3` to thousands
+    ///
+    /// # Panics
+    ///
+    /// If `10` to the power of `places`, or of `-places`, isn't finite in `T`, as for more than
+    /// 308 places either way in `f64`, or 38 in `f32`.
+    pub fn decimal_places(places: i32) -> Self {
+        let ten = T::from(10).unwrap();
+        let power = ten.powi(places.saturating_abs());
+        assert!(
+            power.is_finite(),
+            "there must be few enough decimal places for a power of 10 to be finite"
+        );
+        let grid = if places >= 0 {
+            Grid::Scale(power)
+        } else {
+            Grid::Size(power)
+        };
+        PrecisionModel { grid }
+    }
+
+    /// The side of the squares of the grid, or `None` for full precision
+    pub fn grid_size(&self) -> Option<T> {
+        match self.grid {
+            Grid::Floating => None,
+            Grid::Scale(scale) => Some(scale.recip()),
+            Grid::Size(size) => Some(size),
+        }
+    }
+
+    /// Whether this is the full precision of the coordinate type
+    pub fn is_floating(&self) -> bool {
+        self.grid == Grid::Floating
+    }
+
+    /// Round `value` to the nearest line of the grid, with halves rounded away from zero
+    pub fn make_precise(&self, value: T) -> T {
+        match self.grid {
+            Grid::Floating => value,
+            // if scaling overflows, the value is too large to have digits as fine as the grid
+            Grid::Scale(scale) if !(value * scale).is_finite() => value,
+            Grid::Scale(scale) => (value * scale).round() / scale,
+            Grid::Size(size) => (value / size).round() * size,
+        }
+    }
+
+    /// Round a coordinate to the nearest corner of the grid
+    pub fn make_precise_coord(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        Coordinate {
+            x: self.make_precise(coord.x),
+            y: self.make_precise(coord.y),
+        }
+    }
+}
+
+impl<T: Float> Default for PrecisionModel<T> {
+    fn default() -> Self {
+        PrecisionModel::floating()
+    }
+}
+
+/// Round the coordinates of a geometry onto the grid of a [`PrecisionModel`](struct.PrecisionModel.html).
+pub trait SnapToGrid<T: Float> {
+    /// Round every coordinate to the nearest corner of the grid of `model`, in place, and remove
+    /// the consecutive coordinates which become the same.
+    ///
+    /// Parts narrower than the grid may collapse: a `Polygon` thinner than a grid square can be
+    /// left without area. Use the boolean operations of
+    /// [`PreciseBooleanOps`](trait.PreciseBooleanOps.html) to get valid polygons on the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::precision::{PrecisionModel, SnapToGrid};
+    /// use geo::line_string;
+    ///
+    /// let mut line_string = line_string![
+    ///     (x: 0.0000001, y: 0.), (x: 1.0049, y: 0.), (x: 0.9999999, y: 0.), (x: 2., y: 1.99999)
+    /// ];
+    /// line_string.snap_to_grid_mut(&PrecisionModel::decimal_places(2));
+    ///
+    /// assert_eq!(line_string, line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 2.)]);
+    /// ```
+    fn snap_to_grid_mut(&mut self, model: &PrecisionModel<T>);
+
+    /// Return the geometry with every coordinate rounded to the nearest corner of the grid of
+    /// `model`; see `snap_to_grid_mut`.
+    fn snap_to_grid(&self, model: &PrecisionModel<T>) -> Self
+    where
+        Self: Clone,
+    {
+        let mut geometry = self.clone();
+        geometry.snap_to_grid_mut(model);
+        geometry
+    }
+}
+
+impl<T, G> SnapToGrid<T> for G
+where
+    T: Float,
+    G: MapCoordsInplace<T> + RemoveRepeatedPoints<T>,
+{
+    fn snap_to_grid_mut(&mut self, model: &PrecisionModel<T>) {
+        if model.is_floating() {
+            return;
+        }
+        self.map_coords_inplace(|&(x, y)| (model.make_precise(x), model.make_precise(y)));
+        self.remove_repeated_points_mut();
+    }
+}
+
+// How many times a result is re-noded after being rounded onto the grid, if rounding has made it
+// invalid
+const MAX_RENODINGS: usize = 4;
+
+/// Boolean operations on the areas of `Polygon`s and `MultiPolygon`s, computed within a
+/// [`PrecisionModel`](struct.PrecisionModel.html), so that every coordinate of the result is on
+/// its grid.
+///
+/// The inputs are rounded onto the grid first, then combined as
+/// [`BooleanOps`](../bool_ops/trait.BooleanOps.html) does, and the result is rounded too, without
+/// the rings and polygons which rounding leaves without area. Rounding the points where edges
+/// cross can make the result invalid, such as by moving a vertex across an edge; if it does, the
+/// result is noded against itself and rounded again, until it's valid, or for a few rounds. So
+/// vertices computed a rounding error apart come out equal, and equal inputs give equal results.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::precision::{PreciseBooleanOps, PrecisionModel};
+/// use geo::polygon;
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)];
+/// let b = polygon![(x: 1., y: 1.), (x: 4., y: 2.), (x: 1., y: 3.5)];
+///
+/// let intersection = a.intersection_with_precision(&b, &PrecisionModel::decimal_places(1));
+/// assert_eq!(
+///     intersection.0[0],
+///     polygon![(x: 1., y: 1.), (x: 3., y: 1.7), (x: 3., y: 2.5), (x: 2., y: 3.), (x: 1., y: 3.)]
+/// );
+/// ```
+pub trait PreciseBooleanOps<T: Float, Rhs> {
+    /// The area inside both `self` and `other`, on the grid of `model`
+    fn intersection_with_precision(
+        &self,
+        other: &Rhs,
+        model: &PrecisionModel<T>,
+    ) -> MultiPolygon<T>;
+
+    /// The area inside `self` or `other`, on the grid of `model`
+    fn union_with_precision(&self, other: &Rhs, model: &PrecisionModel<T>) -> MultiPolygon<T>;
+
+    /// The area inside `self` but not `other`, on the grid of `model`
+    fn difference_with_precision(&self, other: &Rhs, model: &PrecisionModel<T>) -> MultiPolygon<T>;
+
+    /// The area inside exactly one of `self` and `other`, on the grid of `model`
+    fn xor_with_precision(&self, other: &Rhs, model: &PrecisionModel<T>) -> MultiPolygon<T>;
+}
+
+impl<T, A, B> PreciseBooleanOps<T, B> for A
+where
+    T: Float,
+    A: BooleanOps<T, B> + SnapToGrid<T> + Clone,
+    B: SnapToGrid<T> + Clone,
+{
+    fn intersection_with_precision(&self, other: &B, model: &PrecisionModel<T>) -> MultiPolygon<T> {
+        overlay_with_precision(self, other, model, BooleanOps::intersection)
+    }
+
+    fn union_with_precision(&self, other: &B, model: &PrecisionModel<T>) -> MultiPolygon<T> {
+        overlay_with_precision(self, other, model, BooleanOps::union)
+    }
+
+    fn difference_with_precision(&self, other: &B, model: &PrecisionModel<T>) -> MultiPolygon<T> {
+        overlay_with_precision(self, other, model, BooleanOps::difference)
+    }
+
+    fn xor_with_precision(&self, other: &B, model: &PrecisionModel<T>) -> MultiPolygon<T> {
+        overlay_with_precision(self, other, model, BooleanOps::xor)
+    }
+}
+
+fn overlay_with_precision<T, A, B>(
+    a: &A,
+    b: &B,
+    model: &PrecisionModel<T>,
+    op: fn(&A, &B) -> MultiPolygon<T>,
+) -> MultiPolygon<T>
+where
+    T: Float,
+    A: SnapToGrid<T> + Clone,
+    B: SnapToGrid<T> + Clone,
+{
+    let mut result = op(&a.snap_to_grid(model), &b.snap_to_grid(model));
+    if model.is_floating() {
+        return result;
+    }
+    for _ in 0..MAX_RENODINGS {
+        result.snap_to_grid_mut(model);
+        remove_collapsed(&mut result);
+        if result.is_valid() {
+            break;
+        }
+        result = result.union(&MultiPolygon(vec![]));
+    }
+    result
+}
+
+// Remove the interiors, and then the polygons, left without area
+fn remove_collapsed<T: Float>(multi_polygon: &mut MultiPolygon<T>) {
+    let has_area =
+        |ring: &LineString<T>| ring.0.len() >= 4 && get_linestring_area(ring) != T::zero();
+    multi_polygon.0 = std::mem::take(&mut multi_polygon.0)
+        .into_iter()
+        .filter(|polygon| has_area(polygon.exterior()))
+        .map(|polygon| {
+            let (exterior, mut interiors) = polygon.into_inner();
+            interiors.retain(has_area);
+            Polygon::new(exterior, interiors)
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{point, polygon, Geometry, Point, Rect};
+
+    #[test]
+    fn grids() {
+        let hundredths = PrecisionModel::decimal_places(2);
+        assert_eq!(hundredths, PrecisionModel::grid(0.01));
+        assert_eq!(hundredths.make_precise(-0.125), -0.13);
+        assert_eq!(hundredths.make_precise(2.675), 2.68);
+        assert_eq!(PrecisionModel::decimal_places(-2).make_precise(149.), 100.);
+        assert_eq!(PrecisionModel::grid(0.25).make_precise(1.3), 1.25);
+        assert_eq!(
+            PrecisionModel::grid(0.3).make_precise(1.),
+            0.8999999999999999
+        );
+        assert_eq!(PrecisionModel::<f64>::default(), PrecisionModel::floating());
+        assert_eq!(PrecisionModel::<f64>::floating().grid_size(), None);
+        assert_eq!(
+            PrecisionModel::grid(2.).make_precise_coord((2.9, -1.1).into()),
+            Coordinate { x: 2., y: -2. }
+        );
+        assert_eq!(
+            PrecisionModel::<f32>::decimal_places(1).make_precise(0.26),
+            0.3
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_grid() {
+        PrecisionModel::grid(0.);
+    }
+
+    #[test]
+    fn extreme_decimal_places() {
+        let finest = PrecisionModel::<f64>::decimal_places(308);
+        assert!(finest.grid_size().unwrap() > 0.);
+        assert_eq!(finest.make_precise(1.5), 1.5);
+        assert_eq!(finest.make_precise(-1e300), -1e300);
+        let coarsest = PrecisionModel::<f64>::decimal_places(-308);
+        assert!(coarsest.grid_size().unwrap().is_finite());
+        assert_eq!(coarsest.make_precise(1e300), 0.);
+        assert!(
+            PrecisionModel::<f32>::decimal_places(38)
+                .grid_size()
+                .unwrap()
+                > 0.
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_decimal_places() {
+        PrecisionModel::<f64>::decimal_places(400);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_decimal_places() {
+        PrecisionModel::<f64>::decimal_places(-400);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_decimal_places_for_f32() {
+        PrecisionModel::<f32>::decimal_places(39);
+    }
+
+    #[test]
+    fn snapping() {
+        let model = PrecisionModel::grid(1.);
+        assert_eq!(
+            point!(x: 0.4, y: 0.6).snap_to_grid(&model),
+            point!(x: 0., y: 1.)
+        );
+        let polygon = polygon![(x: 0., y: 0.), (x: 0.2, y: 0.1), (x: 3., y: 0.), (x: 3., y: 3.)];
+        assert_eq!(
+            polygon.snap_to_grid(&model),
+            polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.)]
+        );
+        let geometry = Geometry::Rect(Rect::new((0.1, 0.2), (2.7, 3.5)));
+        assert_eq!(
+            geometry.snap_to_grid(&model),
+            Geometry::Rect(Rect::new((0., 0.), (3., 4.)))
+        );
+        let line_string: LineString<f64> = vec![(0.1, 0.1), (0.4, 0.2)].into();
+        assert_eq!(line_string.snap_to_grid(&model).0.len(), 1);
+        let empty = Point::<f64>::empty().snap_to_grid(&model);
+        assert!(empty.is_empty());
+        assert_eq!(
+            line_string.snap_to_grid(&PrecisionModel::floating()),
+            line_string
+        );
+    }
+
+    #[test]
+    fn precise_boolean_ops() {
+        let model = PrecisionModel::decimal_places(1);
+        let on_grid = |multi_polygon: &MultiPolygon<f64>| {
+            multi_polygon.0.iter().all(|polygon| {
+                polygon
+                    .exterior()
+                    .0
+                    .iter()
+                    .all(|c| model.make_precise_coord(*c) == *c)
+            })
+        };
+
+        // a wedge whose crossing points aren't on the grid
+        let a = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)];
+        let b = polygon![(x: -1., y: 1.), (x: 4., y: 1.3), (x: -1., y: 1.6)];
+        for result in [
+            a.intersection_with_precision(&b, &model),
+            a.union_with_precision(&b, &model),
+            a.difference_with_precision(&b, &model),
+            a.xor_with_precision(&b, &model),
+        ]
+        .iter()
+        {
+            assert!(on_grid(result));
+            assert!(result.is_valid());
+        }
+
+        // a sliver thinner than the grid disappears
+        let sliver = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 0.01), (x: 0., y: 0.01)];
+        let difference = a.difference_with_precision(&sliver, &model);
+        assert_eq!(difference.0.len(), 1);
+        assert_eq!(difference.unsigned_area(), 9.);
+        assert!(sliver
+            .intersection_with_precision(&sliver, &model)
+            .0
+            .is_empty());
+
+        // dust left by floating point arithmetic
+        let dusty: Polygon<f64> = polygon![
+            (x: 0.1 + 0.2, y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0.30000000000000004, y: 3.)
+        ];
+        assert_eq!(
+            dusty.union_with_precision(&a, &model),
+            a.union_with_precision(&a, &model)
+        );
+    }
+}
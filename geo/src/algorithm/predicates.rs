@@ -0,0 +1,173 @@
+use crate::{Coordinate, CoordinateType};
+
+/// The orientation of three points: which way the path from the first through the second to the
+/// third one turns
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+/// The position of a point relative to a circle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CirclePosition {
+    Inside,
+    Outside,
+    OnCircle,
+}
+
+fn to_robust<T: CoordinateType>(c: Coordinate<T>) -> Option<robust::Coord<f64>> {
+    Some(robust::Coord {
+        x: c.x.to_f64()?,
+        y: c.y.to_f64()?,
+    })
+}
+
+fn sign<T: CoordinateType>(value: T) -> i8 {
+    if value > T::zero() {
+        1
+    } else if value < T::zero() {
+        -1
+    } else {
+        0
+    }
+}
+
+fn orientation_sign<T: CoordinateType>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> i8 {
+    match (to_robust(a), to_robust(b), to_robust(c)) {
+        (Some(a), Some(b), Some(c)) => sign(robust::orient2d(a, b, c)),
+        // fall back to the (non-robust) determinant for coordinates that don't fit an f64
+        _ => sign((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)),
+    }
+}
+
+/// Return the orientation of `c` with respect to the directed line from `a` to `b`:
+/// counter-clockwise if `c` lies to the left of it, clockwise if it lies to the right.
+///
+/// This uses Shewchuk's adaptive-precision predicate (from the
+/// [`robust`](https://docs.rs/robust) crate), so the result is exact instead of being subject to
+/// floating-point rounding. Coordinates are converted to `f64` for this, which is exact for `f64`,
+/// `f32`, and integers of up to 32 bits.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::predicates::{orient2d, Orientation};
+/// use geo::Coordinate;
+///
+/// let a = Coordinate { x: 0.5, y: 0.5 };
+/// let b = Coordinate { x: 12., y: 12. };
+/// // very slightly below the line through `a` and `b`, which the naive determinant misses
+/// let c = Coordinate { x: 28.46686479390819, y: 28.466864793908186 };
+///
+/// assert_eq!(orient2d(a, b, c), Orientation::Clockwise);
+/// assert_eq!(orient2d(a, b, Coordinate { x: 24., y: 24. }), Orientation::Collinear);
+/// ```
+pub fn orient2d<T: CoordinateType>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+) -> Orientation {
+    match orientation_sign(a, b, c) {
+        1 => Orientation::CounterClockwise,
+        -1 => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+/// Return whether `d` is inside, outside, or on the circle through `a`, `b`, and `c`, which
+/// may be in either order. If `a`, `b`, and `c` are collinear, there's no such circle, and
+/// `OnCircle` is returned.
+///
+/// Like [`orient2d`](fn.orient2d.html), this is exact.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::predicates::{incircle, CirclePosition};
+/// use geo::Coordinate;
+///
+/// let (a, b, c) = (
+///     Coordinate { x: 1., y: 0. },
+///     Coordinate { x: 0., y: 1. },
+///     Coordinate { x: -1., y: 0. },
+/// );
+///
+/// assert_eq!(incircle(a, b, c, Coordinate { x: 0., y: 0.5 }), CirclePosition::Inside);
+/// assert_eq!(incircle(c, b, a, Coordinate { x: 0., y: -1. }), CirclePosition::OnCircle);
+/// assert_eq!(incircle(a, b, c, Coordinate { x: 2., y: 0. }), CirclePosition::Outside);
+/// ```
+pub fn incircle<T: CoordinateType>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+    d: Coordinate<T>,
+) -> CirclePosition {
+    let value = match (to_robust(a), to_robust(b), to_robust(c), to_robust(d)) {
+        (Some(a), Some(b), Some(c), Some(d)) => sign(robust::incircle(a, b, c, d)),
+        _ => {
+            let (adx, ady) = (a.x - d.x, a.y - d.y);
+            let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+            let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+            let alift = adx * adx + ady * ady;
+            let blift = bdx * bdx + bdy * bdy;
+            let clift = cdx * cdx + cdy * cdy;
+            let positive = alift * (bdx * cdy) + blift * (cdx * ady) + clift * (adx * bdy);
+            let negative = alift * (cdx * bdy) + blift * (adx * cdy) + clift * (bdx * ady);
+            if positive > negative {
+                1
+            } else if positive < negative {
+                -1
+            } else {
+                0
+            }
+        }
+    };
+    match value * orientation_sign(a, b, c) {
+        1 => CirclePosition::Inside,
+        -1 => CirclePosition::Outside,
+        _ => CirclePosition::OnCircle,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nearly_collinear() {
+        // the naive determinant of these points is zero
+        let a = Coordinate { x: 0.5, y: 0.5 };
+        let b = Coordinate { x: 12., y: 12. };
+        let c = Coordinate {
+            x: 28.46686479390819,
+            y: 28.466864793908186,
+        };
+        let naive = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+        assert_eq!(naive, 0.);
+        assert_eq!(orient2d(a, b, c), Orientation::Clockwise);
+        assert_eq!(orient2d(b, a, c), Orientation::CounterClockwise);
+    }
+
+    #[test]
+    fn integers() {
+        let (a, b) = (Coordinate { x: 0, y: 0 }, Coordinate { x: 2, y: 2 });
+        assert_eq!(
+            orient2d(a, b, Coordinate { x: 1, y: 1 }),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orient2d(a, b, Coordinate { x: 0, y: 1 }),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            incircle(a, b, Coordinate { x: 0, y: 2 }, Coordinate { x: 1, y: 1 }),
+            CirclePosition::Inside
+        );
+        assert_eq!(
+            incircle(a, b, Coordinate { x: 1, y: 1 }, Coordinate { x: 5, y: 5 }),
+            CirclePosition::OnCircle
+        );
+    }
+}
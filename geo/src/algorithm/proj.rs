@@ -1 +1,238 @@
-pub use proj::Proj;
+//! Reproject geometries between coordinate reference systems using
+//! [PROJ](https://proj.org), and measure geographic geometries in a local projection.
+//!
+//! A [`ProjTransform`](struct.ProjTransform.html) converts between any two systems PROJ knows,
+//! such as EPSG codes, in longitude and latitude order for geographic systems, and
+//! [`LocalArea`](trait.LocalArea.html) and [`LocalLength`](trait.LocalLength.html) measure
+//! geometries of longitudes and latitudes in metres, projecting them to a system suited to
+//! where they are first.
+use crate::algorithm::area::Area;
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::map_coords::TryMapCoords;
+use crate::algorithm::projection::{Transform, Utm};
+use crate::{Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect};
+pub use proj::{Proj, ProjError};
+use std::error::Error;
+
+/// A conversion between two coordinate reference systems, in both directions, using PROJ.
+///
+/// As a [`Transform`](../projection/trait.Transform.html), it converts from `from` to `to` in
+/// [`project`](../projection/trait.Transform.html#tymethod.project), and back in
+/// [`unproject`](../projection/trait.Transform.html#tymethod.unproject), so it can be used with
+/// [`Project`](../projection/trait.Project.html) to reproject any geometry. `Transform` can't
+/// fail, so coordinates which PROJ can't convert become NaN: use
+/// [`convert`](#method.convert) to get PROJ's errors instead.
+pub struct ProjTransform {
+    forward: Proj,
+    inverse: Proj,
+}
+
+impl ProjTransform {
+    /// Return the conversion from `from` to `to`, which may be any coordinate reference system
+    /// PROJ accepts, such as `"EPSG:4326"`, `"WGS84"`, or a PROJ string, or `None` if PROJ
+    /// can't convert between them.
+    ///
+    /// Coordinates are in longitude and latitude or easting and northing order, whatever the
+    /// axis order of the systems' definitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::proj::ProjTransform;
+    /// use geo::point;
+    ///
+    /// // from WGS 84 to UTM zone 32N
+    /// let to_utm = ProjTransform::new("EPSG:4326", "EPSG:32632").unwrap();
+    /// let projected = to_utm.convert(&point!(x: 9., y: 45.)).unwrap();
+    ///
+    /// assert!((projected.x() - 500_000.).abs() < 1e-2);
+    /// assert!((projected.y() - 4_982_950.400).abs() < 1e-2);
+    /// ```
+    pub fn new(from: &str, to: &str) -> Option<ProjTransform> {
+        Some(ProjTransform {
+            forward: Proj::new_known_crs(from, to, None)?,
+            inverse: Proj::new_known_crs(to, from, None)?,
+        })
+    }
+
+    /// Convert every coordinate of a geometry from `from` to `to`, or return the first error
+    /// from PROJ.
+    pub fn convert<G>(&self, geometry: &G) -> Result<G, Box<dyn Error + Send + Sync>>
+    where
+        G: TryMapCoords<f64, f64, Output = G>,
+    {
+        convert_with(&self.forward, geometry)
+    }
+
+    /// Convert every coordinate of a geometry from `to` back to `from`, or return the first
+    /// error from PROJ.
+    pub fn convert_inverse<G>(&self, geometry: &G) -> Result<G, Box<dyn Error + Send + Sync>>
+    where
+        G: TryMapCoords<f64, f64, Output = G>,
+    {
+        convert_with(&self.inverse, geometry)
+    }
+}
+
+fn convert_with<G>(proj: &Proj, geometry: &G) -> Result<G, Box<dyn Error + Send + Sync>>
+where
+    G: TryMapCoords<f64, f64, Output = G>,
+{
+    geometry.try_map_coords(|&(x, y)| {
+        let converted = proj.convert((x, y))?;
+        Ok((converted.x(), converted.y()))
+    })
+}
+
+fn convert_coord(proj: &Proj, coord: Coordinate<f64>) -> Coordinate<f64> {
+    match proj.convert((coord.x, coord.y)) {
+        Ok(converted) => Coordinate {
+            x: converted.x(),
+            y: converted.y(),
+        },
+        Err(_) => Coordinate {
+            x: f64::NAN,
+            y: f64::NAN,
+        },
+    }
+}
+
+impl Transform<f64> for ProjTransform {
+    fn project(&self, coord: Coordinate<f64>) -> Coordinate<f64> {
+        convert_coord(&self.forward, coord)
+    }
+
+    fn unproject(&self, coord: Coordinate<f64>) -> Coordinate<f64> {
+        convert_coord(&self.inverse, coord)
+    }
+}
+
+// WGS 84, in the longitude and latitude order of geometries
+const GEOGRAPHIC: &str = "EPSG:4326";
+
+// The centre of the bounding rectangle of a geometry
+fn center(rect: Rect<f64>) -> Point<f64> {
+    Point::new(
+        (rect.min().x + rect.max().x) / 2.,
+        (rect.min().y + rect.max().y) / 2.,
+    )
+}
+
+// Project a geometry of longitudes and latitudes to the system PROJ defines by `to`
+fn project_to<G>(geometry: &G, to: &str) -> Result<G, Box<dyn Error + Send + Sync>>
+where
+    G: TryMapCoords<f64, f64, Output = G>,
+{
+    let transform = ProjTransform::new(GEOGRAPHIC, to).ok_or_else(|| {
+        ProjError::Projection(format!("PROJ can't convert from {} to {}", GEOGRAPHIC, to))
+    })?;
+    transform.convert(geometry)
+}
+
+/// Measure the area of a geometry of longitudes (`x`) and latitudes (`y`), in degrees, by
+/// projecting it with PROJ.
+pub trait LocalArea {
+    /// The unsigned area of the geometry in square metres, measured in a Lambert azimuthal
+    /// equal-area projection of WGS 84 centred on the geometry's bounding rectangle, or the
+    /// error from PROJ.
+    ///
+    /// The projection preserves areas everywhere, so the area is accurate however large the
+    /// geometry, apart from its edges being taken to be straight in the projection rather than
+    /// in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geodesic_area::GeodesicArea;
+    /// use geo::algorithm::proj::LocalArea;
+    /// use geo::polygon;
+    ///
+    /// let field = polygon![
+    ///     (x: 8.5400, y: 47.3700),
+    ///     (x: 8.5410, y: 47.3700),
+    ///     (x: 8.5410, y: 47.3710),
+    ///     (x: 8.5400, y: 47.3710),
+    /// ];
+    /// let area = field.local_area().unwrap();
+    ///
+    /// assert!((area / field.geodesic_area() - 1.).abs() < 1e-4);
+    /// ```
+    fn local_area(&self) -> Result<f64, Box<dyn Error + Send + Sync>>;
+}
+
+macro_rules! impl_local_area {
+    ($($type:ident),*) => {
+        $(
+            impl LocalArea for $type<f64> {
+                fn local_area(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+                    let center = match self.bounding_rect() {
+                        Some(rect) => center(rect),
+                        None => return Ok(0.),
+                    };
+                    let to = format!(
+                        "+proj=laea +lat_0={} +lon_0={} +datum=WGS84 +units=m +no_defs",
+                        center.y(),
+                        center.x()
+                    );
+                    Ok(project_to(self, &to)?.unsigned_area())
+                }
+            }
+        )*
+    };
+}
+
+impl_local_area!(Polygon, MultiPolygon);
+
+/// Measure the length of a geometry of longitudes (`x`) and latitudes (`y`), in degrees, by
+/// projecting it with PROJ.
+pub trait LocalLength {
+    /// The length of the geometry in metres, measured in the UTM zone of the centre of its
+    /// bounding rectangle, or in an azimuthal equidistant projection of WGS 84 centred there
+    /// beyond the latitudes UTM covers, or the error from PROJ.
+    ///
+    /// UTM distorts lengths by less than a tenth of a percent within its zones, so the length
+    /// is accurate for geometries spanning a few zones at most, apart from its segments being
+    /// taken to be straight in the projection rather than in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geodesic_length::GeodesicLength;
+    /// use geo::algorithm::proj::LocalLength;
+    /// use geo::line_string;
+    ///
+    /// let route = line_string![(x: 8.54, y: 47.37), (x: 8.55, y: 47.38), (x: 8.56, y: 47.38)];
+    /// let length = route.local_length().unwrap();
+    ///
+    /// assert!((length / route.geodesic_length() - 1.).abs() < 1e-3);
+    /// ```
+    fn local_length(&self) -> Result<f64, Box<dyn Error + Send + Sync>>;
+}
+
+macro_rules! impl_local_length {
+    ($($type:ident),*) => {
+        $(
+            impl LocalLength for $type<f64> {
+                fn local_length(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+                    let rect: Option<Rect<f64>> = self.bounding_rect().into();
+                    let center = match rect {
+                        Some(rect) => center(rect),
+                        None => return Ok(0.),
+                    };
+                    let to = match Utm::for_point(&center) {
+                        Some(utm) if utm.is_north() => format!("EPSG:326{:02}", utm.zone()),
+                        Some(utm) => format!("EPSG:327{:02}", utm.zone()),
+                        None => format!(
+                            "+proj=aeqd +lat_0={} +lon_0={} +datum=WGS84 +units=m +no_defs",
+                            center.y(),
+                            center.x()
+                        ),
+                    };
+                    Ok(project_to(self, &to)?.euclidean_length())
+                }
+            }
+        )*
+    };
+}
+
+impl_local_length!(Line, LineString, MultiLineString);
@@ -0,0 +1,448 @@
+//! Project geometries between longitudes and latitudes and the [Web Mercator] and [UTM] map
+//! projections of the WGS 84 ellipsoid, in pure Rust, without `PROJ`.
+//!
+//! A projection implements [`Transform`](trait.Transform.html), converting single coordinates,
+//! and the [`Project`](trait.Project.html) trait applies it to every coordinate of a geometry
+//! using [`MapCoords`](../map_coords/trait.MapCoords.html).
+//!
+//! # Determinism
+//!
+//! The projections use only IEEE 754 arithmetic in the coordinate type, in its default
+//! round-to-nearest-even mode, and a fixed number of iterations, so the same input on the same
+//! platform always projects to the same output. The trigonometric, hyperbolic, and logarithmic
+//! functions come from the platform's math library, though, which may round their last bit
+//! differently: results can differ across platforms by a few units in the last place, far
+//! below a micrometre.
+//!
+//! [Web Mercator]: https://epsg.io/3857
+//! [UTM]: https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::projection::{Project, Utm};
+//! use geo::{point, polygon};
+//!
+//! let field = polygon![
+//!     (x: 8.540f64, y: 47.370),
+//!     (x: 8.541, y: 47.370),
+//!     (x: 8.541, y: 47.371),
+//! ];
+//! let utm = Utm::for_point(&point!(x: 8.540, y: 47.370)).unwrap();
+//! assert_eq!((utm.zone(), utm.is_north()), (32, true));
+//!
+//! // in metres, for measuring areas and distances
+//! let projected = field.project(&utm);
+//! let round_trip = projected.unproject(&utm);
+//! for (a, b) in field.exterior().0.iter().zip(&round_trip.exterior().0) {
+//!     assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+//! }
+//! ```
+use crate::algorithm::map_coords::MapCoords;
+use crate::{Coordinate, Point, EARTH_FLATTENING, EQUATORIAL_EARTH_RADIUS};
+use num_traits::Float;
+
+/// A map projection, between coordinates of longitude (`x`) and latitude (`y`) in degrees and
+/// projected coordinates.
+pub trait Transform<T: Float> {
+    /// Project a coordinate of longitude and latitude, in degrees.
+    fn project(&self, coord: Coordinate<T>) -> Coordinate<T>;
+
+    /// Return the longitude and latitude, in degrees, of a projected coordinate.
+    fn unproject(&self, coord: Coordinate<T>) -> Coordinate<T>;
+}
+
+/// Apply a [`Transform`](trait.Transform.html) to every coordinate of a geometry.
+pub trait Project<T: Float> {
+    /// Project a geometry of longitudes (`x`) and latitudes (`y`), in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::projection::{Project, WebMercator};
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0f64, y: 0.), (x: 180., y: 0.)];
+    /// let projected = line_string.project(&WebMercator);
+    ///
+    /// assert!((projected.0[1].x - 20_037_508.342_789_244).abs() < 1e-6);
+    /// ```
+    fn project<P: Transform<T>>(&self, projection: &P) -> Self;
+
+    /// Return a projected geometry in longitudes (`x`) and latitudes (`y`), in degrees.
+    fn unproject<P: Transform<T>>(&self, projection: &P) -> Self;
+}
+
+impl<T, G> Project<T> for G
+where
+    T: Float,
+    G: MapCoords<T, T, Output = G>,
+{
+    fn project<P: Transform<T>>(&self, projection: &P) -> Self {
+        self.map_coords(|&(x, y)| projection.project(Coordinate { x, y }).x_y())
+    }
+
+    fn unproject<P: Transform<T>>(&self, projection: &P) -> Self {
+        self.map_coords(|&(x, y)| projection.unproject(Coordinate { x, y }).x_y())
+    }
+}
+
+/// The spherical Web Mercator projection ([EPSG:3857](https://epsg.io/3857)) of web maps, in
+/// metres from where the equator meets the prime meridian.
+///
+/// Latitudes past about ±85.0511° are projected beyond the square of web maps, and the poles
+/// to infinity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct WebMercator;
+
+impl<T: Float> Transform<T> for WebMercator {
+    fn project(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let radius = T::from(EQUATORIAL_EARTH_RADIUS).unwrap();
+        let quarter_pi = T::from(std::f64::consts::FRAC_PI_4).unwrap();
+        let half = T::from(0.5).unwrap();
+        Coordinate {
+            x: radius * coord.x.to_radians(),
+            y: radius * (quarter_pi + coord.y.to_radians() * half).tan().ln(),
+        }
+    }
+
+    fn unproject(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let radius = T::from(EQUATORIAL_EARTH_RADIUS).unwrap();
+        Coordinate {
+            x: (coord.x / radius).to_degrees(),
+            y: (coord.y / radius).sinh().atan().to_degrees(),
+        }
+    }
+}
+
+/// The scale factor at the central meridian of each UTM zone.
+const UTM_SCALE: f64 = 0.9996;
+/// The easting of the central meridian of each UTM zone, in metres.
+const UTM_FALSE_EASTING: f64 = 500_000.;
+/// The northing of the equator in the zones of the southern hemisphere, in metres.
+const UTM_FALSE_NORTHING: f64 = 10_000_000.;
+
+/// A zone of the Universal Transverse Mercator projection of the WGS 84 ellipsoid, with
+/// eastings (`x`) and northings (`y`) in metres.
+///
+/// Points are projected using Krüger's series to the sixth order, as given by
+/// [Karney (2011)](https://arxiv.org/abs/1002.1417), which is accurate to about 5 nanometres
+/// within 3,900 km of the zone's central meridian, far beyond the zone itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Utm {
+    zone: u8,
+    north: bool,
+}
+
+impl Utm {
+    /// Return the UTM zone numbered `zone`, from `1` to `60`, in the northern or southern
+    /// hemisphere, or `None` if there's no such zone.
+    pub fn new(zone: u8, north: bool) -> Option<Utm> {
+        if (1..=60).contains(&zone) {
+            Some(Utm { zone, north })
+        } else {
+            None
+        }
+    }
+
+    /// Return the UTM zone for a point of longitude (`x`) and latitude (`y`), in degrees,
+    /// including the wider zones of Norway and Svalbard, or `None` if it's outside the
+    /// latitudes from 80° south to 84° north which UTM covers, or isn't finite.
+    ///
+    /// Longitudes outside `-180` to `180` are wrapped, and points on the boundary between
+    /// zones are in the zone to the east.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::projection::Utm;
+    /// use geo::point;
+    ///
+    /// assert_eq!(Utm::for_point(&point!(x: -74.0, y: 40.7)), Utm::new(18, true));
+    /// assert_eq!(Utm::for_point(&point!(x: 151.2, y: -33.9)), Utm::new(56, false));
+    /// // Bergen is in the wider zone 32
+    /// assert_eq!(Utm::for_point(&point!(x: 5.3, y: 60.4)), Utm::new(32, true));
+    /// assert_eq!(Utm::for_point(&point!(x: 0., y: 85.)), None);
+    /// ```
+    pub fn for_point<T: Float>(point: &Point<T>) -> Option<Utm> {
+        let (lon, lat) = (point.x().to_f64()?, point.y().to_f64()?);
+        if !lon.is_finite() || !(-80. ..=84.).contains(&lat) {
+            return None;
+        }
+        let lon = (lon + 180.).rem_euclid(360.) - 180.;
+        let mut zone = (((lon + 180.) / 6.).floor() as u8 + 1).min(60);
+        if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lon) {
+            zone = 32;
+        } else if lat >= 72. && (0. ..42.).contains(&lon) {
+            zone = match lon {
+                lon if lon < 9. => 31,
+                lon if lon < 21. => 33,
+                lon if lon < 33. => 35,
+                _ => 37,
+            };
+        }
+        Utm::new(zone, lat >= 0.)
+    }
+
+    /// The number of the zone, from `1` to `60`.
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Whether the zone is in the northern hemisphere, with northings from the equator rather
+    /// than from 10,000 km south of it.
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// The longitude, in degrees, of the zone's central meridian.
+    pub fn central_meridian<T: Float>(&self) -> T {
+        T::from(6 * self.zone as i32 - 183).unwrap()
+    }
+
+    fn false_northing<T: Float>(&self) -> T {
+        if self.north {
+            T::zero()
+        } else {
+            T::from(UTM_FALSE_NORTHING).unwrap()
+        }
+    }
+}
+
+// The constants of Krüger's series for the WGS 84 ellipsoid: the eccentricity, the radius of
+// the rectifying sphere times the scale factor, and the coefficients of the forward and
+// inverse series
+struct Kruger<T> {
+    eccentricity: T,
+    scaled_radius: T,
+    alpha: [T; 6],
+    beta: [T; 6],
+}
+
+impl<T: Float> Kruger<T> {
+    fn wgs84() -> Self {
+        let f = EARTH_FLATTENING;
+        let n = f / (2. - f);
+        let (n2, n3) = (n * n, n * n * n);
+        let (n4, n5, n6) = (n3 * n, n3 * n2, n3 * n3);
+        let radius = EQUATORIAL_EARTH_RADIUS / (1. + n) * (1. + n2 / 4. + n4 / 64. + n6 / 256.);
+        let alpha = [
+            n / 2. - 2. / 3. * n2 + 5. / 16. * n3 + 41. / 180. * n4 - 127. / 288. * n5
+                + 7891. / 37800. * n6,
+            13. / 48. * n2 - 3. / 5. * n3 + 557. / 1440. * n4 + 281. / 630. * n5
+                - 1983433. / 1935360. * n6,
+            61. / 240. * n3 - 103. / 140. * n4 + 15061. / 26880. * n5 + 167603. / 181440. * n6,
+            49561. / 161280. * n4 - 179. / 168. * n5 + 6601661. / 7257600. * n6,
+            34729. / 80640. * n5 - 3418889. / 1995840. * n6,
+            212378941. / 319334400. * n6,
+        ];
+        let beta = [
+            n / 2. - 2. / 3. * n2 + 37. / 96. * n3 - 1. / 360. * n4 - 81. / 512. * n5
+                + 96199. / 604800. * n6,
+            1. / 48. * n2 + 1. / 15. * n3 - 437. / 1440. * n4 + 46. / 105. * n5
+                - 1118711. / 3870720. * n6,
+            17. / 480. * n3 - 37. / 840. * n4 - 209. / 4480. * n5 + 5569. / 90720. * n6,
+            4397. / 161280. * n4 - 11. / 504. * n5 - 830251. / 7257600. * n6,
+            4583. / 161280. * n5 - 108847. / 3991680. * n6,
+            20648693. / 638668800. * n6,
+        ];
+        let convert = |value: f64| T::from(value).unwrap();
+        Kruger {
+            eccentricity: convert((f * (2. - f)).sqrt()),
+            scaled_radius: convert(UTM_SCALE * radius),
+            alpha: [
+                convert(alpha[0]),
+                convert(alpha[1]),
+                convert(alpha[2]),
+                convert(alpha[3]),
+                convert(alpha[4]),
+                convert(alpha[5]),
+            ],
+            beta: [
+                convert(beta[0]),
+                convert(beta[1]),
+                convert(beta[2]),
+                convert(beta[3]),
+                convert(beta[4]),
+                convert(beta[5]),
+            ],
+        }
+    }
+
+    // The tangent of the conformal latitude, given the tangent of the latitude
+    fn conformal(&self, tau: T) -> T {
+        let e = self.eccentricity;
+        let sigma = (e * (e * tau / (T::one() + tau * tau).sqrt()).atanh()).sinh();
+        tau * (T::one() + sigma * sigma).sqrt() - sigma * (T::one() + tau * tau).sqrt()
+    }
+
+    // Add the terms of a series to the angles (ξ, η) on the sphere, or subtract them
+    fn series(&self, coefficients: &[T; 6], xi: T, eta: T, sign: T) -> (T, T) {
+        let (mut new_xi, mut new_eta) = (xi, eta);
+        for (j, &coefficient) in coefficients.iter().enumerate() {
+            let k = T::from(2 * (j + 1)).unwrap();
+            new_xi = new_xi + sign * coefficient * (k * xi).sin() * (k * eta).cosh();
+            new_eta = new_eta + sign * coefficient * (k * xi).cos() * (k * eta).sinh();
+        }
+        (new_xi, new_eta)
+    }
+}
+
+impl<T: Float> Transform<T> for Utm {
+    fn project(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let kruger = Kruger::<T>::wgs84();
+        let lon = (coord.x - self.central_meridian()).to_radians();
+        let tau = coord.y.to_radians().tan();
+        let tau_prime = kruger.conformal(tau);
+        let xi_prime = tau_prime.atan2(lon.cos());
+        let eta_prime = (lon.sin() / (T::one() + tau_prime * tau_prime).sqrt()).atanh();
+        let (xi, eta) = kruger.series(&kruger.alpha, xi_prime, eta_prime, T::one());
+        Coordinate {
+            x: T::from(UTM_FALSE_EASTING).unwrap() + kruger.scaled_radius * eta,
+            y: self.false_northing::<T>() + kruger.scaled_radius * xi,
+        }
+    }
+
+    fn unproject(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let kruger = Kruger::<T>::wgs84();
+        let xi = (coord.y - self.false_northing()) / kruger.scaled_radius;
+        let eta = (coord.x - T::from(UTM_FALSE_EASTING).unwrap()) / kruger.scaled_radius;
+        let (xi_prime, eta_prime) = kruger.series(&kruger.beta, xi, eta, -T::one());
+        let tau_prime = xi_prime.sin() / (eta_prime.sinh().powi(2) + xi_prime.cos().powi(2)).sqrt();
+        let lon = eta_prime.sinh().atan2(xi_prime.cos());
+
+        // solve for the tangent of the latitude by Newton's method, which converges to full
+        // precision in a fixed number of steps
+        let one_minus_e2 = T::one() - kruger.eccentricity * kruger.eccentricity;
+        let mut tau = tau_prime;
+        for _ in 0..5 {
+            let estimate = kruger.conformal(tau);
+            let slope = one_minus_e2
+                * (T::one() + estimate * estimate).sqrt()
+                * (T::one() + tau * tau).sqrt()
+                / (T::one() + one_minus_e2 * tau * tau);
+            tau = tau + (tau_prime - estimate) / slope;
+        }
+        Coordinate {
+            x: lon.to_degrees() + self.central_meridian(),
+            y: tau.atan().to_degrees(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::map_coords::MapCoords;
+    use crate::{line_string, point, polygon, Geometry, GeometryCollection};
+
+    #[test]
+    fn utm_matches_known_coordinates() {
+        // from the independent series of Snyder's "Map Projections: A Working Manual", which is
+        // accurate to about a millimetre within the zones
+        let cases = [
+            (
+                (-74.0445, 40.6892),
+                (18, true),
+                (580_735.871, 4_504_695.165),
+            ),
+            ((2.2945, 48.8584), (31, true), (448_252.001, 5_411_954.910)),
+            (
+                (151.2153, -33.8568),
+                (56, false),
+                (334_900.570, 6_252_288.753),
+            ),
+            ((-70.0, -80.0), (19, false), (480_615.197, 1_118_247.584)),
+            ((0.0, 0.0), (31, true), (166_021.443, 0.0)),
+        ];
+        for &((lon, lat), (zone, north), (easting, northing)) in &cases {
+            let utm = Utm::for_point(&point!(x: lon, y: lat)).unwrap();
+            assert_eq!((utm.zone(), utm.is_north()), (zone, north));
+            let projected = utm.project(Coordinate { x: lon, y: lat });
+            assert_relative_eq!(projected.x, easting, epsilon = 2e-3);
+            assert_relative_eq!(projected.y, northing, epsilon = 2e-3);
+            let round_trip = utm.unproject(projected);
+            assert_relative_eq!(round_trip.x, lon, epsilon = 1e-10);
+            assert_relative_eq!(round_trip.y, lat, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn utm_round_trips_far_from_the_central_meridian() {
+        let utm = Utm::new(33, false).unwrap();
+        for &lon in &[-10., 0., 15., 30., 40.] {
+            for &lat in &[-79., -45., -10., 0., 20., 60., 83.] {
+                let round_trip = utm.unproject(utm.project(Coordinate { x: lon, y: lat }));
+                assert_relative_eq!(round_trip.x, lon, epsilon = 1e-9);
+                assert_relative_eq!(round_trip.y, lat, epsilon = 1e-9);
+            }
+        }
+        // the same point projects the same way every time
+        let coord = Coordinate {
+            x: 17.123,
+            y: -31.456,
+        };
+        assert_eq!(utm.project(coord), utm.project(coord));
+    }
+
+    #[test]
+    fn zone_selection() {
+        assert_eq!(Utm::new(0, true), None);
+        assert_eq!(Utm::new(61, true), None);
+        assert_eq!(Utm::for_point(&point!(x: -180., y: 0.)), Utm::new(1, true));
+        assert_eq!(Utm::for_point(&point!(x: 180., y: 0.)), Utm::new(1, true));
+        assert_eq!(
+            Utm::for_point(&point!(x: 179.9, y: -0.1)),
+            Utm::new(60, false)
+        );
+        assert_eq!(Utm::for_point(&point!(x: 6., y: 0.)), Utm::new(32, true));
+        assert_eq!(Utm::for_point(&point!(x: 2.9, y: 60.)), Utm::new(31, true));
+        assert_eq!(Utm::for_point(&point!(x: 10., y: 78.)), Utm::new(33, true));
+        assert_eq!(Utm::for_point(&point!(x: 40., y: 80.)), Utm::new(37, true));
+        assert_eq!(Utm::for_point(&point!(x: 10., y: -81.)), None);
+        assert_eq!(Utm::for_point(&point!(x: f64::NAN, y: 0.)), None);
+        assert_relative_eq!(Utm::new(1, true).unwrap().central_meridian::<f64>(), -177.);
+    }
+
+    #[test]
+    fn web_mercator() {
+        let corner = WebMercator.project(Coordinate {
+            x: -180.,
+            y: 85.051_128_779_806_59,
+        });
+        assert_relative_eq!(corner.x, -20_037_508.342_789_244, epsilon = 1e-6);
+        assert_relative_eq!(corner.y, 20_037_508.342_789_244, epsilon = 1e-6);
+        let round_trip = WebMercator.unproject(corner);
+        assert_relative_eq!(round_trip.x, -180., epsilon = 1e-12);
+        assert_relative_eq!(round_trip.y, 85.051_128_779_806_59, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn project_geometries() {
+        let utm = Utm::new(32, true).unwrap();
+        let polygon = polygon![(x: 9., y: 45.), (x: 9.01, y: 45.), (x: 9.01, y: 45.01)];
+        let projected = polygon.project(&utm);
+        // the central meridian maps to the false easting
+        assert_relative_eq!(projected.exterior().0[0].x, 500_000., epsilon = 1e-6);
+        assert_eq!(
+            projected,
+            polygon.map_coords(|&(x, y)| utm.project(Coordinate { x, y }).x_y())
+        );
+
+        let collection = GeometryCollection(vec![
+            Geometry::from(point!(x: 9., y: 45.)),
+            line_string![(x: 8., y: 44.), (x: 10., y: 46.)].into(),
+        ]);
+        let round_trip = collection.project(&WebMercator).unproject(&WebMercator);
+        if let Geometry::LineString(line_string) = &round_trip.0[1] {
+            assert_relative_eq!(line_string.0[1].x, 10., epsilon = 1e-12);
+            assert_relative_eq!(line_string.0[1].y, 46., epsilon = 1e-12);
+        } else {
+            panic!("expected a LineString");
+        }
+
+        let single: Point<f32> = point!(x: 9.5f32, y: 45.5);
+        let round_trip = single.project(&utm).unproject(&utm);
+        assert_relative_eq!(round_trip.x(), 9.5, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,230 @@
+pub use crate::algorithm::dimensions::Dimensions;
+use std::{error, fmt};
+
+/// The position of a point relative to a geometry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CoordPos {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+impl CoordPos {
+    fn index(self) -> usize {
+        match self {
+            CoordPos::Inside => 0,
+            CoordPos::OnBoundary => 1,
+            CoordPos::Outside => 2,
+        }
+    }
+}
+
+impl Dimensions {
+    fn symbol(self) -> char {
+        match self {
+            Dimensions::Empty => 'F',
+            Dimensions::ZeroDimensional => '0',
+            Dimensions::OneDimensional => '1',
+            Dimensions::TwoDimensional => '2',
+        }
+    }
+}
+
+/// A [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) matrix, holding the dimensions of the
+/// intersections of the interiors, boundaries, and exteriors of two geometries.
+///
+/// Returned by [`Relate`](trait.Relate.html), it provides the named spatial predicates of the
+/// OGC Simple Features specification, and can be matched against any other pattern.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::relate::{CoordPos, Dimensions, Relate};
+/// use geo::{line_string, Point};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+/// let matrix = Point::new(1., 1.).relate(&line_string);
+///
+/// assert_eq!(
+///     matrix.get(CoordPos::Inside, CoordPos::Inside),
+///     Dimensions::ZeroDimensional
+/// );
+/// assert_eq!(matrix.to_string(), "0FFFFF102");
+/// assert!(matrix.within());
+/// assert_eq!(matrix.matches("T*F**F***"), Ok(true));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IntersectionMatrix([[Dimensions; 3]; 3]);
+
+impl IntersectionMatrix {
+    /// A matrix in which nothing intersects, not even the exteriors
+    pub(crate) fn empty() -> Self {
+        IntersectionMatrix([[Dimensions::Empty; 3]; 3])
+    }
+
+    /// Raise the dimension of the intersection of `lhs` and `rhs` to at least `dimensions`
+    pub(crate) fn set_at_least(&mut self, lhs: CoordPos, rhs: CoordPos, dimensions: Dimensions) {
+        let entry = &mut self.0[lhs.index()][rhs.index()];
+        if *entry < dimensions {
+            *entry = dimensions;
+        }
+    }
+
+    /// The dimension of the intersection of the part `lhs` of the first geometry with the part
+    /// `rhs` of the second
+    pub fn get(&self, lhs: CoordPos, rhs: CoordPos) -> Dimensions {
+        self.0[lhs.index()][rhs.index()]
+    }
+
+    /// Whether the matrix matches a DE-9IM pattern of nine characters, in row-major order, each
+    /// one of:
+    ///
+    /// - `T`: the parts intersect
+    /// - `F`: the parts don't intersect
+    /// - `*`: anything
+    /// - `0`, `1`, `2`: the parts intersect with exactly this dimension
+    pub fn matches(&self, pattern: &str) -> Result<bool, InvalidPatternError> {
+        let symbols: Vec<char> = pattern.chars().collect();
+        if symbols.len() != 9 {
+            return Err(InvalidPatternError);
+        }
+        let mut matches = true;
+        for (symbol, dimensions) in symbols.iter().zip(self.0.iter().flatten()) {
+            matches &= match symbol {
+                '*' => true,
+                'T' | 't' => *dimensions != Dimensions::Empty,
+                'F' | 'f' => *dimensions == Dimensions::Empty,
+                '0' => *dimensions == Dimensions::ZeroDimensional,
+                '1' => *dimensions == Dimensions::OneDimensional,
+                '2' => *dimensions == Dimensions::TwoDimensional,
+                _ => return Err(InvalidPatternError),
+            };
+        }
+        Ok(matches)
+    }
+
+    // Infallible, as the patterns below are all valid
+    fn matches_any(&self, patterns: &[&str]) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| self.matches(pattern).unwrap_or(false))
+    }
+
+    // The dimensions of the geometries, which are those of their interiors
+    fn lhs_dimensions(&self) -> Dimensions {
+        self.0[0].iter().copied().max().unwrap_or(Dimensions::Empty)
+    }
+
+    fn rhs_dimensions(&self) -> Dimensions {
+        self.0
+            .iter()
+            .map(|row| row[0])
+            .max()
+            .unwrap_or(Dimensions::Empty)
+    }
+
+    /// The geometries have no point in common
+    pub fn disjoint(&self) -> bool {
+        self.matches_any(&["FF*FF****"])
+    }
+
+    /// The geometries have at least one point in common
+    pub fn intersects(&self) -> bool {
+        !self.disjoint()
+    }
+
+    /// No point of the first geometry is outside the second, and their interiors intersect
+    pub fn within(&self) -> bool {
+        self.matches_any(&["T*F**F***"])
+    }
+
+    /// No point of the second geometry is outside the first, and their interiors intersect
+    pub fn contains(&self) -> bool {
+        self.matches_any(&["T*****FF*"])
+    }
+
+    /// No point of the second geometry is outside the first, which isn't empty
+    pub fn covers(&self) -> bool {
+        self.matches_any(&["T*****FF*", "*T****FF*", "***T**FF*", "****T*FF*"])
+    }
+
+    /// No point of the first geometry is outside the second, which isn't empty
+    pub fn covered_by(&self) -> bool {
+        self.matches_any(&["T*F**F***", "*TF**F***", "**FT*F***", "**F*TF***"])
+    }
+
+    /// The geometries have some but not all interior points in common, and the dimension of
+    /// their intersection is less than that of at least one of them
+    pub fn crosses(&self) -> bool {
+        let lhs = self.lhs_dimensions();
+        let rhs = self.rhs_dimensions();
+        if lhs == Dimensions::OneDimensional && rhs == Dimensions::OneDimensional {
+            self.matches_any(&["0********"])
+        } else if lhs < rhs {
+            self.matches_any(&["T*T******"])
+        } else if lhs > rhs {
+            self.matches_any(&["T*****T**"])
+        } else {
+            false
+        }
+    }
+
+    /// The geometries have at least one point in common, but their interiors don't intersect
+    pub fn touches(&self) -> bool {
+        if self.lhs_dimensions() == Dimensions::ZeroDimensional
+            && self.rhs_dimensions() == Dimensions::ZeroDimensional
+        {
+            return false;
+        }
+        self.matches_any(&["FT*******", "F**T*****", "F***T****"])
+    }
+
+    /// The geometries have the same dimension, and their intersection has that dimension, but
+    /// neither covers the other
+    pub fn overlaps(&self) -> bool {
+        match (self.lhs_dimensions(), self.rhs_dimensions()) {
+            (Dimensions::OneDimensional, Dimensions::OneDimensional) => {
+                self.matches_any(&["1*T***T**"])
+            }
+            (Dimensions::ZeroDimensional, Dimensions::ZeroDimensional)
+            | (Dimensions::TwoDimensional, Dimensions::TwoDimensional) => {
+                self.matches_any(&["T*T***T**"])
+            }
+            _ => false,
+        }
+    }
+
+    /// The geometries are topologically equal: they have the same points
+    pub fn equal_topo(&self) -> bool {
+        self.matches_any(&["T*F**FFF*"])
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for dimensions in self.0.iter().flatten() {
+            write!(f, "{}", dimensions.symbol())?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IntersectionMatrix({})", self)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidPatternError;
+
+impl fmt::Display for InvalidPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a DE-9IM pattern has nine characters, each one of T, F, *, 0, 1, or 2"
+        )
+    }
+}
+
+impl error::Error for InvalidPatternError {}
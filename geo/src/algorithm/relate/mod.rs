@@ -0,0 +1,402 @@
+pub use self::intersection_matrix::{
+    CoordPos, Dimensions, IntersectionMatrix, InvalidPatternError,
+};
+
+use self::topology_graph::TopologyGraph;
+use crate::algorithm::parts::ToParts;
+use crate::{
+    FractionalCoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+mod intersection_matrix;
+pub(crate) mod topology_graph;
+
+/// Topologically relate two geometries, computing their
+/// [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) intersection matrix.
+///
+/// The matrix holds the dimensions of the intersections of the interiors, boundaries, and
+/// exteriors of the two geometries, from which all the named spatial predicates follow:
+/// [`within`](struct.IntersectionMatrix.html#method.within),
+/// [`covers`](struct.IntersectionMatrix.html#method.covers),
+/// [`touches`](struct.IntersectionMatrix.html#method.touches), and so on.
+///
+/// The boundary of a polygon is its rings, and the boundary of a line string is its endpoints,
+/// unless it's closed. Where several line strings of a geometry meet, an endpoint is on the
+/// boundary if an odd number of them end there (the "mod-2" rule). Points have no boundary.
+///
+/// The geometries are first noded against each other: every segment is split wherever it meets
+/// another. The resulting edges and nodes are then located relative to both geometries, using
+/// robust orientation predicates, and the locations of the edges' sides give the intersections
+/// of the geometries' areas. Unlike [`BooleanOps`](../bool_ops/trait.BooleanOps.html), nothing
+/// is snapped together, however close, so `Relate` agrees with the exact predicates, such as
+/// [`Intersects`](../intersects/trait.Intersects.html). Points, line strings, and polygons with a NaN or infinite
+/// coordinate are treated as empty, as an empty point, with NaN coordinates, is.
+///
+/// As the points at which segments cross are generally between the integers, `Relate` is only
+/// implemented for a [`FractionalCoordinateType`](../../trait.FractionalCoordinateType.html),
+/// such as floats or fixed-point numbers, and not for integer coordinates:
+///
+/// ```compile_fail
+/// use geo::algorithm::relate::Relate;
+/// use geo::line_string;
+///
+/// let a = line_string![(x: 0, y: 0), (x: 3, y: 2)];
+/// let b = line_string![(x: 0, y: 2), (x: 3, y: 0)];
+/// a.relate(&b);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::relate::Relate;
+/// use geo::{line_string, polygon};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+///     (x: 0., y: 0.),
+/// ];
+/// let line_string = line_string![(x: 2., y: 2.), (x: 6., y: 2.)];
+///
+/// let matrix = line_string.relate(&square);
+/// assert_eq!(matrix.to_string(), "1010F0212");
+/// assert!(matrix.crosses());
+/// assert!(!matrix.within());
+///
+/// let edge = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+/// assert!(edge.relate(&square).touches());
+/// assert!(edge.relate(&square).covered_by());
+/// ```
+pub trait Relate<T, Rhs = Self> {
+    fn relate(&self, other: &Rhs) -> IntersectionMatrix;
+}
+
+macro_rules! impl_relate {
+    ($from:ident => [$($to:ident),*]) => {
+        $(
+            impl<T> Relate<T, $to<T>> for $from<T>
+            where
+                T: FractionalCoordinateType,
+            {
+                fn relate(&self, other: &$to<T>) -> IntersectionMatrix {
+                    TopologyGraph::new(self.parts(), other.parts())
+                        .compute_matrix()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_relate_all {
+    ($($from:ident),*) => {
+        $(
+            impl_relate!($from => [
+                Point,
+                Line,
+                LineString,
+                Polygon,
+                MultiPoint,
+                MultiLineString,
+                MultiPolygon,
+                Rect,
+                Triangle,
+                Geometry,
+                GeometryCollection
+            ]);
+        )*
+    };
+}
+
+impl_relate_all!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    Geometry,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Coordinate};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+            (x: x, y: y),
+        ]
+    }
+
+    #[test]
+    fn point_point() {
+        let p = Point::new(1., 1.);
+        assert_eq!(p.relate(&p).to_string(), "0FFFFFFF2");
+        assert_eq!(p.relate(&Point::new(2., 1.)).to_string(), "FF0FFF0F2");
+        assert!(p.relate(&p).equal_topo());
+        assert!(p.relate(&Point::new(2., 1.)).disjoint());
+        assert!(!p.relate(&p).touches());
+    }
+
+    #[test]
+    fn point_polygon() {
+        let polygon = square(0., 0., 4.);
+        assert_eq!(Point::new(2., 2.).relate(&polygon).to_string(), "0FFFFF212");
+        assert_eq!(Point::new(4., 2.).relate(&polygon).to_string(), "F0FFFF212");
+        assert_eq!(Point::new(5., 2.).relate(&polygon).to_string(), "FF0FFF212");
+        assert!(Point::new(4., 2.).relate(&polygon).touches());
+        assert!(Point::new(4., 2.).relate(&polygon).covered_by());
+        assert!(!Point::new(4., 2.).relate(&polygon).within());
+        assert!(polygon.relate(&Point::new(2., 2.)).contains());
+    }
+
+    #[test]
+    fn line_string_line_string() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let crossing = line_string![(x: 0., y: 2.), (x: 2., y: 0.)];
+        assert_eq!(a.relate(&crossing).to_string(), "0F1FF0102");
+        assert!(a.relate(&crossing).crosses());
+
+        let overlapping = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_eq!(a.relate(&overlapping).to_string(), "1010F0102");
+        assert!(a.relate(&overlapping).overlaps());
+
+        let touching = line_string![(x: 2., y: 2.), (x: 3., y: 0.)];
+        assert_eq!(a.relate(&touching).to_string(), "FF1F00102");
+        assert!(a.relate(&touching).touches());
+
+        let reversed = line_string![(x: 2., y: 2.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(a.relate(&reversed).equal_topo());
+    }
+
+    #[test]
+    fn closed_line_string_has_no_boundary() {
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(Point::new(0., 0.).relate(&ring).to_string(), "0FFFFF1F2");
+    }
+
+    #[test]
+    fn mod_2_boundary_rule() {
+        // three line strings ending at the origin, and two at (1, 0)
+        let multi_line_string = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+        ]);
+        let origin = Point::new(0., 0.).relate(&multi_line_string);
+        assert_eq!(
+            origin.get(CoordPos::Inside, CoordPos::OnBoundary),
+            Dimensions::ZeroDimensional
+        );
+        let end = Point::new(1., 0.).relate(&multi_line_string);
+        assert_eq!(
+            end.get(CoordPos::Inside, CoordPos::Inside),
+            Dimensions::ZeroDimensional
+        );
+    }
+
+    #[test]
+    fn polygon_polygon() {
+        let a = square(0., 0., 4.);
+        assert_eq!(a.relate(&a).to_string(), "2FFF1FFF2");
+        assert!(a.relate(&a).equal_topo());
+
+        let overlapping = square(2., 2., 4.);
+        assert_eq!(a.relate(&overlapping).to_string(), "212101212");
+        assert!(a.relate(&overlapping).overlaps());
+
+        let touching_edge = square(4., 0., 4.);
+        assert_eq!(a.relate(&touching_edge).to_string(), "FF2F11212");
+        assert!(a.relate(&touching_edge).touches());
+
+        let touching_corner = square(4., 4., 1.);
+        assert_eq!(a.relate(&touching_corner).to_string(), "FF2F01212");
+
+        let inner = square(1., 1., 2.);
+        assert_eq!(inner.relate(&a).to_string(), "2FF1FF212");
+        assert!(inner.relate(&a).within());
+        assert!(a.relate(&inner).covers());
+
+        let sharing_an_edge = square(0., 0., 2.);
+        assert_eq!(sharing_an_edge.relate(&a).to_string(), "2FF11F212");
+        assert!(sharing_an_edge.relate(&a).within());
+
+        let disjoint = square(10., 10., 1.);
+        assert!(a.relate(&disjoint).disjoint());
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.), (x: 0., y: 0.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.)]],
+        ];
+        assert!(Point::new(3., 3.).relate(&polygon).disjoint());
+        assert!(Point::new(2., 3.).relate(&polygon).touches());
+
+        // fills the hole exactly
+        let hole = square(2., 2., 2.);
+        assert_eq!(hole.relate(&polygon).to_string(), "FF2F1F212");
+
+        let line_string = line_string![(x: 1., y: 3.), (x: 5., y: 3.)];
+        assert_eq!(line_string.relate(&polygon).to_string(), "1010FF212");
+    }
+
+    #[test]
+    fn line_polygon() {
+        let polygon = square(0., 0., 4.);
+        let inside = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 3., y: 3. });
+        assert_eq!(inside.relate(&polygon).to_string(), "1FF0FF212");
+        let along_boundary = Line::new(Coordinate { x: 1., y: 0. }, Coordinate { x: 3., y: 0. });
+        assert_eq!(along_boundary.relate(&polygon).to_string(), "F1FF0F212");
+        let crossing = Line::new(Coordinate { x: -1., y: 1. }, Coordinate { x: 5., y: 1. });
+        assert_eq!(crossing.relate(&polygon).to_string(), "101FF0212");
+        assert!(crossing.relate(&polygon).crosses());
+    }
+
+    #[test]
+    fn multi_polygon_and_collection() {
+        let multi_polygon = MultiPolygon(vec![square(0., 0., 1.), square(2., 0., 1.)]);
+        let line_string = line_string![(x: 0.5, y: 0.5), (x: 2.5, y: 0.5)];
+        let matrix = line_string.relate(&multi_polygon);
+        assert_eq!(matrix.to_string(), "1010FF212");
+
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(square(0., 0., 4.)),
+            Geometry::LineString(line_string![(x: 4., y: 0.), (x: 8., y: 0.)]),
+        ]);
+        assert!(Point::new(6., 0.).relate(&collection).within());
+        assert!(Point::new(2., 2.).relate(&collection).within());
+        assert!(Point::new(8., 0.).relate(&collection).touches());
+        assert!(Rect::new((1., 1.), (2., 2.)).relate(&collection).within());
+    }
+
+    #[test]
+    fn rect_triangle() {
+        let rect = Rect::new((0., 0.), (2., 2.));
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (2., 2.)]);
+        assert!(triangle.relate(&rect).covered_by());
+        assert!(triangle.relate(&rect).within());
+        assert!(rect.relate(&triangle).covers());
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.relate(&empty).to_string(), "FFFFFFFF2");
+        assert_eq!(empty.relate(&Point::new(0., 0.)).to_string(), "FFFFFF0F2");
+    }
+
+    #[test]
+    fn non_finite_coordinates() {
+        // as empty as the empty point
+        let square = square(0., 0., 4.);
+        let broken = polygon![(x: 1., y: 1.), (x: f64::NAN, y: 2.), (x: 2., y: 3.), (x: 1., y: 1.)];
+        assert_eq!(broken.relate(&square).to_string(), "FFFFFF212");
+        assert_eq!(square.relate(&broken).to_string(), "FF2FF1FF2");
+        assert_eq!(
+            Point::<f64>::empty().relate(&square),
+            Point::new(f64::INFINITY, 1.).relate(&square)
+        );
+        let line = line_string![(x: 1., y: 1.), (x: f64::NAN, y: f64::NAN), (x: 3., y: 3.)];
+        assert!(line.relate(&square).disjoint());
+    }
+
+    #[test]
+    fn patterns() {
+        let matrix = square(0., 0., 4.).relate(&square(2., 2., 4.));
+        assert_eq!(matrix.matches("2*2***2**"), Ok(true));
+        assert_eq!(matrix.matches("T*F******"), Ok(false));
+        assert_eq!(matrix.matches("T*F"), Err(InvalidPatternError));
+        assert_eq!(matrix.matches("T*F*****X"), Err(InvalidPatternError));
+    }
+
+    #[test]
+    fn integer_vertices() {
+        // crossing between the integers
+        let a = line_string![(x: 0., y: 0.), (x: 3., y: 2.)];
+        let b = line_string![(x: 0., y: 2.), (x: 3., y: 0.)];
+        assert_eq!(a.relate(&b).to_string(), "0F1FF0102");
+        assert!(a.relate(&b).crosses());
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI64F64;
+        use num_traits::NumCast;
+
+        let fixed = |x: f64| <FixedI64F64 as NumCast>::from(x).unwrap();
+        let a = LineString::from(vec![(fixed(0.), fixed(0.)), (fixed(3.), fixed(2.))]);
+        let b = LineString::from(vec![(fixed(0.), fixed(2.)), (fixed(3.), fixed(0.))]);
+        assert_eq!(a.relate(&b).to_string(), "0F1FF0102");
+    }
+
+    #[test]
+    fn nearly_touching() {
+        use crate::algorithm::intersects::Intersects;
+
+        // a rounding error apart, without touching
+        let a = polygon![
+            (x: 7., y: 1.), (x: 4., y: 6.), (x: 2., y: 1.0000000000000002), (x: 4., y: 0.)
+        ];
+        let b = polygon![
+            (x: 9., y: 7.), (x: 6., y: 9.), (x: 2., y: 7.000000000000001), (x: 6., y: 5.)
+        ];
+        assert!(!a.intersects(&b));
+        assert_eq!(a.relate(&b).to_string(), "FF2FF1212");
+        assert!(a.relate(&b).disjoint());
+
+        // a vertex a rounding error off an edge
+        let square = square(0., 0., 2.);
+        for tip in [2. - f64::EPSILON, 2. + 2. * f64::EPSILON].iter() {
+            let triangle =
+                polygon![(x: *tip, y: 1.), (x: 4., y: 0.), (x: 4., y: 2.), (x: *tip, y: 1.)];
+            assert_eq!(
+                triangle.relate(&square).intersects(),
+                triangle.intersects(&square)
+            );
+        }
+    }
+
+    #[test]
+    fn agrees_with_intersects() {
+        use crate::algorithm::intersects::Intersects;
+
+        let geometries: Vec<Geometry<f64>> = vec![
+            Point::new(1., 1.).into(),
+            Point::new(4., 2.).into(),
+            Line::new(Coordinate { x: 0., y: 5. }, Coordinate { x: 5., y: 0. }).into(),
+            line_string![(x: -1., y: 2.), (x: 2., y: 2.), (x: 2., y: 7.)].into(),
+            square(0., 0., 4.).into(),
+            square(4., 4., 2.).into(),
+            square(1., 1., 1.).into(),
+            Rect::new((3., -1.), (5., 1.)).into(),
+            Triangle::from([(6., 0.), (8., 0.), (7., 3.)]).into(),
+            MultiPoint(vec![Point::new(7., 1.), Point::new(10., 10.)]).into(),
+        ];
+        for a in &geometries {
+            for b in &geometries {
+                let matrix = a.relate(b);
+                assert_eq!(matrix.intersects(), a.intersects(b), "{:?} {:?}", a, b);
+                assert_eq!(matrix.within(), b.relate(a).contains(), "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
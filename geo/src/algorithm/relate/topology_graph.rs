@@ -0,0 +1,809 @@
+use super::intersection_matrix::{CoordPos, Dimensions, IntersectionMatrix};
+use crate::algorithm::parts::PartsSink;
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::algorithm::winding_order::twice_signed_ring_area;
+use crate::utils::{cmp_values, is_finite_coord};
+use crate::{Coordinate, CoordinateType, LineString, Polygon};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// The points, segments, and areas of one of the geometries being related. Points, line strings,
+/// and polygons with a NaN or infinite coordinate are left out, as though they were empty
+pub(crate) struct GeometryParts<'a, T: CoordinateType> {
+    points: Vec<Coordinate<T>>,
+    lines: Vec<(Coordinate<T>, Coordinate<T>)>,
+    // the endpoints of the line strings, which are on the boundary if there are an odd number of
+    // them at a coordinate (the "mod-2" rule)
+    line_endpoints: Vec<Coordinate<T>>,
+    polygons: Vec<Cow<'a, Polygon<T>>>,
+}
+
+impl<'a, T: CoordinateType> Default for GeometryParts<'a, T> {
+    fn default() -> Self {
+        GeometryParts {
+            points: vec![],
+            lines: vec![],
+            line_endpoints: vec![],
+            polygons: vec![],
+        }
+    }
+}
+
+impl<'a, T: CoordinateType> PartsSink<'a, T> for GeometryParts<'a, T> {
+    fn add_point(&mut self, point: Coordinate<T>) {
+        if is_finite_coord(&point) {
+            self.points.push(point);
+        }
+    }
+
+    fn add_line_string(&mut self, coords: &[Coordinate<T>]) {
+        if !coords.iter().all(is_finite_coord) {
+            return;
+        }
+        let lines = coords.windows(2).filter(|pair| pair[0] != pair[1]);
+        let len = self.lines.len();
+        self.lines.extend(lines.map(|pair| (pair[0], pair[1])));
+        if self.lines.len() == len {
+            // a line string of zero length is the point it collapses to
+            if let Some(coord) = coords.first() {
+                self.points.push(*coord);
+            }
+        } else {
+            self.line_endpoints.push(coords[0]);
+            self.line_endpoints.push(coords[coords.len() - 1]);
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: Cow<'a, Polygon<T>>) {
+        let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+        if !rings.flat_map(|ring| &ring.0).all(is_finite_coord) {
+            return;
+        }
+        let has_area = polygon.exterior().0.len() > 3
+            && twice_signed_ring_area(polygon.exterior()) != T::zero();
+        if has_area {
+            self.polygons.push(polygon);
+        } else {
+            // a polygon without area is the line string or point it collapses to
+            let coords = polygon.exterior().0.clone();
+            self.add_line_string(&coords);
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum SegmentKind {
+    Line,
+    Ring {
+        polygon: usize,
+        interior_on_left: bool,
+    },
+}
+
+#[derive(Copy, Clone)]
+struct Segment<T: CoordinateType> {
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    geometry: usize,
+    kind: SegmentKind,
+}
+
+impl<T: CoordinateType> Segment<T> {
+    fn min_x(&self) -> T {
+        min(self.start.x, self.end.x)
+    }
+
+    fn max_x(&self) -> T {
+        max(self.start.x, self.end.x)
+    }
+
+    fn min_y(&self) -> T {
+        min(self.start.y, self.end.y)
+    }
+
+    fn max_y(&self) -> T {
+        max(self.start.y, self.end.y)
+    }
+
+    fn box_contains(&self, c: Coordinate<T>) -> bool {
+        c.x >= self.min_x() && c.x <= self.max_x() && c.y >= self.min_y() && c.y <= self.max_y()
+    }
+
+    fn contains(&self, c: Coordinate<T>) -> bool {
+        orient2d(self.start, self.end, c) == Orientation::Collinear && self.box_contains(c)
+    }
+
+    // Whether `c` is within `tolerance` of the segment, other than beyond its ends
+    fn is_near(&self, c: Coordinate<T>, tolerance: T) -> bool {
+        let (dx, dy) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        let (cx, cy) = (c.x - self.start.x, c.y - self.start.y);
+        let along = cx * dx + cy * dy;
+        let length_squared = dx * dx + dy * dy;
+        let cross = dx * cy - dy * cx;
+        along >= T::zero()
+            && along <= length_squared
+            && cross * cross <= tolerance * tolerance * length_squared
+    }
+}
+
+/// What is known about a noded edge, or the sides of it, from one of the geometries
+#[derive(Clone, Default)]
+struct EdgeLabel {
+    // the edge is part of one of the geometry's lines
+    line: bool,
+    // the polygons with a ring containing the edge
+    rings: Vec<usize>,
+    // the interior of one of the polygons is on this side of the edge
+    interior_on_left: bool,
+    interior_on_right: bool,
+}
+
+struct Edge<T: CoordinateType> {
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+    // whether the start and end are vertices of the geometries, rather than computed
+    // intersections, so can be located exactly
+    exact: [bool; 2],
+    labels: [EdgeLabel; 2],
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Order coordinates by `x` and then `y`, totally, with NaNs last, consistent with `TotalEq`
+pub(crate) fn cmp_coords<T: CoordinateType>(a: &Coordinate<T>, b: &Coordinate<T>) -> Ordering {
+    cmp_values(a.x, b.x).then_with(|| cmp_values(a.y, b.y))
+}
+
+/// The nodes and edges of the planar graph formed by two geometries, in which no two edges
+/// intersect other than at their endpoints, labelled with their positions relative to each
+/// geometry
+pub(crate) struct TopologyGraph<'a, T: CoordinateType> {
+    parts: [GeometryParts<'a, T>; 2],
+    segments: Vec<Segment<T>>,
+    // whether vertices and crossings a rounding error apart are merged
+    snap: bool,
+}
+
+impl<'a, T: CoordinateType> TopologyGraph<'a, T> {
+    /// The graph of two geometries, noded exactly, so that it agrees with the robust predicates
+    pub(crate) fn new(lhs: GeometryParts<'a, T>, rhs: GeometryParts<'a, T>) -> Self {
+        Self::with_snapping(lhs, rhs, false)
+    }
+
+    /// The graph of two geometries, in which vertices closer to each other, or to an edge, than
+    /// about 10<sup>-12</sup> of the magnitude of the coordinates are snapped together, so that
+    /// the coordinates computed by earlier operations don't leave slivers
+    pub(crate) fn snapped(lhs: GeometryParts<'a, T>, rhs: GeometryParts<'a, T>) -> Self {
+        Self::with_snapping(lhs, rhs, true)
+    }
+
+    fn with_snapping(lhs: GeometryParts<'a, T>, rhs: GeometryParts<'a, T>, snap: bool) -> Self {
+        let parts = [lhs, rhs];
+        let mut segments = vec![];
+        for (geometry, parts) in parts.iter().enumerate() {
+            for &(start, end) in &parts.lines {
+                segments.push(Segment {
+                    start,
+                    end,
+                    geometry,
+                    kind: SegmentKind::Line,
+                });
+            }
+            for (index, polygon) in parts.polygons.iter().enumerate() {
+                let rings = std::iter::once((polygon.exterior(), true))
+                    .chain(polygon.interiors().iter().map(|ring| (ring, false)));
+                for (ring, is_exterior) in rings {
+                    let counter_clockwise = twice_signed_ring_area(ring) > T::zero();
+                    for line in ring.lines().filter(|line| line.start != line.end) {
+                        segments.push(Segment {
+                            start: line.start,
+                            end: line.end,
+                            geometry,
+                            kind: SegmentKind::Ring {
+                                polygon: index,
+                                interior_on_left: counter_clockwise == is_exterior,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+        if snap {
+            snap_vertices(&mut segments);
+        }
+        TopologyGraph {
+            parts,
+            segments,
+            snap,
+        }
+    }
+
+    /// The coordinates at which each segment must be split
+    fn node_segments(&self) -> Vec<Vec<Coordinate<T>>> {
+        let segments = &self.segments;
+        let mut splits: Vec<Vec<Coordinate<T>>> =
+            segments.iter().map(|s| vec![s.start, s.end]).collect();
+        let mut overlaps = vec![];
+
+        // sweep the segments in order of their least x, only comparing those whose bounding
+        // boxes overlap
+        let mut order: Vec<usize> = (0..segments.len()).collect();
+        order.sort_by(|a, b| cmp_values(segments[*a].min_x(), segments[*b].min_x()));
+        for (position, &i) in order.iter().enumerate() {
+            let a = &segments[i];
+            for &j in &order[position + 1..] {
+                let b = &segments[j];
+                if b.min_x() > a.max_x() {
+                    break;
+                }
+                if a.max_y() < b.min_y() || b.max_y() < a.min_y() {
+                    continue;
+                }
+                match intersection(a, b, self.snap) {
+                    Intersection::None => {}
+                    Intersection::Point(c) => {
+                        splits[i].push(c);
+                        splits[j].push(c);
+                    }
+                    Intersection::Collinear(coords) => {
+                        splits[i].extend(&coords);
+                        splits[j].extend(&coords);
+                        overlaps.push((i, j));
+                    }
+                }
+            }
+        }
+
+        // overlapping segments must be split at the same coordinates, including those computed
+        // for their intersections with other segments
+        for (i, j) in overlaps {
+            let (a, b) = (segments[i], segments[j]);
+            let from_i: Vec<_> = splits[i]
+                .iter()
+                .copied()
+                .filter(|c| b.box_contains(*c))
+                .collect();
+            let from_j: Vec<_> = splits[j]
+                .iter()
+                .copied()
+                .filter(|c| a.box_contains(*c))
+                .collect();
+            splits[i].extend(from_j);
+            splits[j].extend(from_i);
+        }
+
+        // isolated points split the segments they lie on
+        for parts in &self.parts {
+            for point in &parts.points {
+                for (segment, split) in segments.iter().zip(splits.iter_mut()) {
+                    if segment.contains(*point) {
+                        split.push(*point);
+                    }
+                }
+            }
+        }
+        splits
+    }
+
+    /// The noded edges, with the coincident edges of both geometries merged
+    fn edges(&self) -> Vec<Edge<T>> {
+        let mut edges = vec![];
+        for (segment, split) in self.segments.iter().zip(self.node_segments()) {
+            for pair in sorted_along(segment, split).windows(2) {
+                let (mut start, mut end) = (pair[0], pair[1]);
+                if start == end {
+                    continue;
+                }
+                let mut exact = [start == segment.start, end == segment.end];
+                let mut label = EdgeLabel::default();
+                match segment.kind {
+                    SegmentKind::Line => label.line = true,
+                    SegmentKind::Ring {
+                        polygon,
+                        interior_on_left,
+                    } => {
+                        label.rings.push(polygon);
+                        label.interior_on_left = interior_on_left;
+                        label.interior_on_right = !interior_on_left;
+                    }
+                }
+                // orient edges consistently, so that coincident ones can be merged
+                if cmp_coords(&start, &end) == Ordering::Greater {
+                    std::mem::swap(&mut start, &mut end);
+                    exact.swap(0, 1);
+                    std::mem::swap(&mut label.interior_on_left, &mut label.interior_on_right);
+                }
+                let mut labels = [EdgeLabel::default(), EdgeLabel::default()];
+                labels[segment.geometry] = label;
+                edges.push(Edge {
+                    start,
+                    end,
+                    exact,
+                    labels,
+                });
+            }
+        }
+        edges.sort_by(|a, b| cmp_coords(&a.start, &b.start).then(cmp_coords(&a.end, &b.end)));
+
+        let mut merged: Vec<Edge<T>> = vec![];
+        for edge in edges {
+            match merged.last_mut() {
+                Some(last) if last.start == edge.start && last.end == edge.end => {
+                    last.exact[0] |= edge.exact[0];
+                    last.exact[1] |= edge.exact[1];
+                    for (label, other) in last.labels.iter_mut().zip(edge.labels.iter()) {
+                        label.line |= other.line;
+                        label.rings.extend(&other.rings);
+                        label.interior_on_left |= other.interior_on_left;
+                        label.interior_on_right |= other.interior_on_right;
+                    }
+                }
+                _ => merged.push(edge),
+            }
+        }
+        merged
+    }
+
+    // Whether `c` is inside one of the polygons of a geometry, other than those in `excluded`
+    fn in_area(&self, geometry: usize, c: Coordinate<T>, excluded: &[usize]) -> bool {
+        self.parts[geometry]
+            .polygons
+            .iter()
+            .enumerate()
+            .any(|(index, polygon)| !excluded.contains(&index) && polygon_contains(polygon, c))
+    }
+
+    // Whether the interior of `edge` is inside one of the polygons of a geometry, other than
+    // those in `excluded`. An endpoint that's a vertex, and isn't one of the nodes on the
+    // geometry's rings, is located exactly, whereas the midpoint is rounded, and may be rounded
+    // across the boundary when the edge is nearly collinear with it
+    fn edge_in_area(
+        &self,
+        geometry: usize,
+        edge: &Edge<T>,
+        mid: Coordinate<T>,
+        excluded: &[usize],
+        ring_nodes: &[Coordinate<T>],
+    ) -> bool {
+        let ends = [edge.start, edge.end];
+        let off_rings = |end: &usize| {
+            edge.exact[*end]
+                && ring_nodes
+                    .binary_search_by(|node| cmp_coords(node, &ends[*end]))
+                    .is_err()
+        };
+        self.parts[geometry]
+            .polygons
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !excluded.contains(index))
+            .any(|(_, polygon)| {
+                let exact = (0..2)
+                    .filter(off_rings)
+                    .map(|end| polygon_position(polygon, ends[end]))
+                    .find(|position| *position != CoordPos::OnBoundary);
+                exact.unwrap_or_else(|| polygon_position(polygon, mid)) == CoordPos::Inside
+            })
+    }
+
+    /// The noded edges, located relative to both geometries
+    pub(crate) fn labelled_edges(&self) -> Vec<LabelledEdge<T>> {
+        let two = T::one() + T::one();
+        let edges = self.edges();
+        let ring_nodes: Vec<Vec<Coordinate<T>>> = (0..2)
+            .map(|geometry| {
+                let mut nodes: Vec<_> = edges
+                    .iter()
+                    .filter(|edge| !edge.labels[geometry].rings.is_empty())
+                    .flat_map(|edge| vec![edge.start, edge.end])
+                    .collect();
+                nodes.sort_by(cmp_coords);
+                nodes.dedup();
+                nodes
+            })
+            .collect();
+        edges
+            .into_iter()
+            .map(|edge| {
+                let mid = Coordinate {
+                    x: (edge.start.x + edge.end.x) / two,
+                    y: (edge.start.y + edge.end.y) / two,
+                };
+                let mut labelled = LabelledEdge {
+                    start: edge.start,
+                    end: edge.end,
+                    positions: [CoordPos::Outside; 2],
+                    left_inside: [false; 2],
+                    right_inside: [false; 2],
+                    on_ring: [false; 2],
+                    on_line: [false; 2],
+                };
+                for (geometry, nodes) in ring_nodes.iter().enumerate() {
+                    let label = &edge.labels[geometry];
+                    let in_area = self.edge_in_area(geometry, &edge, mid, &label.rings, nodes);
+                    let left_inside = label.interior_on_left || in_area;
+                    let right_inside = label.interior_on_right || in_area;
+                    labelled.positions[geometry] = if left_inside && right_inside {
+                        CoordPos::Inside
+                    } else if left_inside || right_inside {
+                        CoordPos::OnBoundary
+                    } else if label.line {
+                        CoordPos::Inside
+                    } else {
+                        CoordPos::Outside
+                    };
+                    labelled.left_inside[geometry] = left_inside;
+                    labelled.right_inside[geometry] = right_inside;
+                    labelled.on_ring[geometry] = !label.rings.is_empty();
+                    labelled.on_line[geometry] = label.line;
+                }
+                labelled
+            })
+            .collect()
+    }
+
+    /// Compute the DE-9IM matrix of the two geometries
+    pub(crate) fn compute_matrix(&self) -> IntersectionMatrix {
+        let mut matrix = IntersectionMatrix::empty();
+        matrix.set_at_least(
+            CoordPos::Outside,
+            CoordPos::Outside,
+            Dimensions::TwoDimensional,
+        );
+
+        let edges = self.labelled_edges();
+        let mut nodes: Vec<Coordinate<T>> =
+            edges.iter().flat_map(|e| vec![e.start, e.end]).collect();
+        for parts in &self.parts {
+            nodes.extend(&parts.points);
+        }
+        nodes.sort_by(cmp_coords);
+        nodes.dedup();
+
+        // for each node and geometry: whether it's on a ring, whether it's on a ring but not in
+        // the interior, and whether it's on a line
+        let mut incidences = vec![[(false, false, false); 2]; nodes.len()];
+
+        let side = |inside: bool| {
+            if inside {
+                CoordPos::Inside
+            } else {
+                CoordPos::Outside
+            }
+        };
+        for edge in &edges {
+            for end in [edge.start, edge.end].iter() {
+                if let Ok(node) = nodes.binary_search_by(|n| cmp_coords(n, end)) {
+                    for (geometry, incidence) in incidences[node].iter_mut().enumerate() {
+                        if edge.on_ring[geometry] {
+                            incidence.0 = true;
+                            incidence.1 |= edge.positions[geometry] != CoordPos::Inside;
+                        }
+                        incidence.2 |= edge.on_line[geometry];
+                    }
+                }
+            }
+            matrix.set_at_least(
+                edge.positions[0],
+                edge.positions[1],
+                Dimensions::OneDimensional,
+            );
+            matrix.set_at_least(
+                side(edge.left_inside[0]),
+                side(edge.left_inside[1]),
+                Dimensions::TwoDimensional,
+            );
+            matrix.set_at_least(
+                side(edge.right_inside[0]),
+                side(edge.right_inside[1]),
+                Dimensions::TwoDimensional,
+            );
+        }
+
+        for (node, incidence) in nodes.iter().zip(incidences.iter()) {
+            let mut positions = [CoordPos::Outside; 2];
+            for geometry in 0..2 {
+                let parts = &self.parts[geometry];
+                let (on_ring, on_boundary, on_line) = incidence[geometry];
+                let endpoints = parts.line_endpoints.iter().filter(|c| *c == node).count();
+                positions[geometry] = if on_ring {
+                    if on_boundary {
+                        CoordPos::OnBoundary
+                    } else {
+                        CoordPos::Inside
+                    }
+                } else if self.in_area(geometry, *node, &[]) {
+                    CoordPos::Inside
+                } else if endpoints % 2 == 1 {
+                    CoordPos::OnBoundary
+                } else if on_line || parts.points.contains(node) {
+                    CoordPos::Inside
+                } else {
+                    CoordPos::Outside
+                };
+            }
+            matrix.set_at_least(positions[0], positions[1], Dimensions::ZeroDimensional);
+        }
+        matrix
+    }
+}
+
+/// Split line segments exactly where they cross, touch, or overlap each other, so that the
+/// pieces only meet at their endpoints. The coordinates at which each segment is split are
+/// returned in order from its start to its end, including both of those
+pub(crate) fn split_segments<T: CoordinateType>(
+    segments: &[(Coordinate<T>, Coordinate<T>)],
+) -> Vec<Vec<Coordinate<T>>> {
+    let parts = GeometryParts {
+        lines: segments.to_vec(),
+        ..GeometryParts::default()
+    };
+    let graph = TopologyGraph::new(parts, GeometryParts::default());
+    graph
+        .segments
+        .iter()
+        .zip(graph.node_segments())
+        .map(|(segment, split)| sorted_along(segment, split))
+        .collect()
+}
+
+// The distinct coordinates at which a segment is split, in order along it
+fn sorted_along<T: CoordinateType>(
+    segment: &Segment<T>,
+    mut split: Vec<Coordinate<T>>,
+) -> Vec<Coordinate<T>> {
+    let dx = segment.end.x - segment.start.x;
+    let dy = segment.end.y - segment.start.y;
+    let along = |c: &Coordinate<T>| (c.x - segment.start.x) * dx + (c.y - segment.start.y) * dy;
+    split.sort_by(|a, b| cmp_values(along(a), along(b)));
+    split.dedup();
+    split
+}
+
+enum Intersection<T: CoordinateType> {
+    None,
+    Point(Coordinate<T>),
+    // the endpoints of each segment which lie on the other
+    Collinear(Vec<Coordinate<T>>),
+}
+
+fn intersection<T: CoordinateType>(a: &Segment<T>, b: &Segment<T>, snap: bool) -> Intersection<T> {
+    let o1 = orient2d(a.start, a.end, b.start);
+    let o2 = orient2d(a.start, a.end, b.end);
+    let o3 = orient2d(b.start, b.end, a.start);
+    let o4 = orient2d(b.start, b.end, a.end);
+    let collinear = Orientation::Collinear;
+
+    if o1 == collinear && o2 == collinear {
+        let coords: Vec<_> = [(a, b.start), (a, b.end), (b, a.start), (b, a.end)]
+            .iter()
+            .filter(|(segment, c)| segment.box_contains(*c))
+            .map(|(_, c)| *c)
+            .collect();
+        return match coords.len() {
+            0 => Intersection::None,
+            _ => Intersection::Collinear(coords),
+        };
+    }
+    // the segments touch at an endpoint
+    for (o, segment, c) in [
+        (o1, a, b.start),
+        (o2, a, b.end),
+        (o3, b, a.start),
+        (o4, b, a.end),
+    ]
+    .iter()
+    {
+        if *o == collinear && segment.box_contains(*c) {
+            return Intersection::Point(*c);
+        }
+    }
+    // when snapping, an endpoint indistinguishable from a point of the other segment splits it
+    // there, as though it were exactly on it, rather than the segments crossing or passing a
+    // rounding error apart
+    if let Some(tolerance) = snap_tolerance(&[a.start, a.end, b.start, b.end]).filter(|_| snap) {
+        let near: Vec<_> = [(a, b.start), (a, b.end), (b, a.start), (b, a.end)]
+            .iter()
+            .filter(|(segment, c)| segment.is_near(*c, tolerance))
+            .map(|(_, c)| *c)
+            .collect();
+        match near.len() {
+            0 => {}
+            1 => return Intersection::Point(near[0]),
+            _ => return Intersection::Collinear(near),
+        }
+    }
+    if o1 == o2 || o3 == o4 {
+        return Intersection::None;
+    }
+
+    // the segments cross properly
+    let (adx, ady) = (a.end.x - a.start.x, a.end.y - a.start.y);
+    let (bdx, bdy) = (b.end.x - b.start.x, b.end.y - b.start.y);
+    let t =
+        ((b.start.x - a.start.x) * bdy - (b.start.y - a.start.y) * bdx) / (adx * bdy - ady * bdx);
+    // keep the computed point inside both segments' bounding boxes, despite rounding
+    let clamp = |value: T, a_min: T, a_max: T, b_min: T, b_max: T| {
+        min(max(value, max(a_min, b_min)), min(a_max, b_max))
+    };
+    let crossing = Coordinate {
+        x: clamp(
+            a.start.x + t * adx,
+            a.min_x(),
+            a.max_x(),
+            b.min_x(),
+            b.max_x(),
+        ),
+        y: clamp(
+            a.start.y + t * ady,
+            a.min_y(),
+            a.max_y(),
+            b.min_y(),
+            b.max_y(),
+        ),
+    };
+    if snap {
+        Intersection::Point(snap_to_endpoint(crossing, a, b))
+    } else {
+        Intersection::Point(crossing)
+    }
+}
+
+fn abs<T: CoordinateType>(value: T) -> T {
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+// The greatest difference of the coordinates of `a` and `b`
+fn distance<T: CoordinateType>(a: &Coordinate<T>, b: &Coordinate<T>) -> T {
+    max(abs(a.x - b.x), abs(a.y - b.y))
+}
+
+/// How far apart coordinates may be, yet still be indistinguishable at the scale of `coords`
+fn snap_tolerance<'c, T: CoordinateType + 'c>(
+    coords: impl IntoIterator<Item = &'c Coordinate<T>>,
+) -> Option<T> {
+    let scale = coords
+        .into_iter()
+        .fold(T::one(), |scale, c| max(scale, max(abs(c.x), abs(c.y))));
+    T::from(1e-12).map(|relative| scale * relative)
+}
+
+/// Move the endpoints of segments which are indistinguishable from an earlier endpoint, in
+/// order of x, onto it, so that nearly coincident vertices (such as those computed for the same
+/// crossing in earlier operations) become a single node
+fn snap_vertices<T: CoordinateType>(segments: &mut Vec<Segment<T>>) {
+    let mut coords: Vec<Coordinate<T>> =
+        segments.iter().flat_map(|s| vec![s.start, s.end]).collect();
+    let tolerance = match snap_tolerance(&coords) {
+        Some(tolerance) => tolerance,
+        None => return,
+    };
+    coords.sort_by(cmp_coords);
+    coords.dedup();
+    let mut nodes: Vec<Coordinate<T>> = vec![];
+    let mut snapped: Vec<(Coordinate<T>, Coordinate<T>)> = Vec::with_capacity(coords.len());
+    for coord in coords {
+        let node = nodes
+            .iter()
+            .rev()
+            .take_while(|node| coord.x - node.x <= tolerance)
+            .find(|node| distance(*node, &coord) <= tolerance)
+            .copied();
+        match node {
+            Some(node) => snapped.push((coord, node)),
+            None => {
+                nodes.push(coord);
+                snapped.push((coord, coord));
+            }
+        }
+    }
+    let snap = |c: Coordinate<T>| match snapped.binary_search_by(|(from, _)| cmp_coords(from, &c)) {
+        Ok(index) => snapped[index].1,
+        Err(_) => c,
+    };
+    for segment in segments.iter_mut() {
+        segment.start = snap(segment.start);
+        segment.end = snap(segment.end);
+    }
+    segments.retain(|segment| segment.start != segment.end);
+}
+
+/// Replace a computed crossing by the nearest endpoint of the segments if it's indistinguishable
+/// from it at the scale of their coordinates, which happens when one segment ends almost on the
+/// other. Otherwise the segment would be split a rounding error away from its end, leaving a
+/// sliver, rather than exactly at it.
+fn snap_to_endpoint<T: CoordinateType>(
+    crossing: Coordinate<T>,
+    a: &Segment<T>,
+    b: &Segment<T>,
+) -> Coordinate<T> {
+    let ends = [a.start, a.end, b.start, b.end];
+    let tolerance = match snap_tolerance(&ends) {
+        Some(tolerance) => tolerance,
+        None => return crossing,
+    };
+    ends.iter()
+        .filter(|c| distance(*c, &crossing) <= tolerance)
+        .min_by(|x, y| cmp_values(distance(*x, &crossing), distance(*y, &crossing)))
+        .copied()
+        .unwrap_or(crossing)
+}
+
+/// A noded edge, with its position relative to each geometry, whether it's on one of their
+/// rings or lines, and whether their interiors are just to its left and right
+pub(crate) struct LabelledEdge<T: CoordinateType> {
+    pub(crate) start: Coordinate<T>,
+    pub(crate) end: Coordinate<T>,
+    pub(crate) positions: [CoordPos; 2],
+    pub(crate) left_inside: [bool; 2],
+    pub(crate) right_inside: [bool; 2],
+    pub(crate) on_ring: [bool; 2],
+    pub(crate) on_line: [bool; 2],
+}
+
+/// Whether `c` is strictly inside `polygon`, neither on one of its rings nor in a hole
+pub(crate) fn polygon_contains<T: CoordinateType>(polygon: &Polygon<T>, c: Coordinate<T>) -> bool {
+    polygon_position(polygon, c) == CoordPos::Inside
+}
+
+fn polygon_position<T: CoordinateType>(polygon: &Polygon<T>, c: Coordinate<T>) -> CoordPos {
+    let mut position = ring_position(polygon.exterior(), c);
+    for ring in polygon.interiors() {
+        if position != CoordPos::Inside {
+            break;
+        }
+        position = match ring_position(ring, c) {
+            CoordPos::Inside => CoordPos::Outside,
+            CoordPos::OnBoundary => CoordPos::OnBoundary,
+            CoordPos::Outside => CoordPos::Inside,
+        };
+    }
+    position
+}
+
+/// The position of `c` relative to the area enclosed by `ring`, found by counting the edges
+/// crossing a ray from `c` in the positive x direction
+pub(crate) fn ring_position<T: CoordinateType>(ring: &LineString<T>, c: Coordinate<T>) -> CoordPos {
+    let mut crossings = 0;
+    for line in ring.lines() {
+        let orientation = orient2d(line.start, line.end, c);
+        if orientation == Orientation::Collinear
+            && c.x >= min(line.start.x, line.end.x)
+            && c.x <= max(line.start.x, line.end.x)
+            && c.y >= min(line.start.y, line.end.y)
+            && c.y <= max(line.start.y, line.end.y)
+        {
+            return CoordPos::OnBoundary;
+        }
+        let upward = line.start.y <= c.y && line.end.y > c.y;
+        let downward = line.end.y <= c.y && line.start.y > c.y;
+        if (upward && orientation == Orientation::CounterClockwise)
+            || (downward && orientation == Orientation::Clockwise)
+        {
+            crossings += 1;
+        }
+    }
+    if crossings % 2 == 1 {
+        CoordPos::Inside
+    } else {
+        CoordPos::Outside
+    }
+}
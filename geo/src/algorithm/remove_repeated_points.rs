@@ -0,0 +1,251 @@
+use crate::{
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Remove consecutive repeated coordinates from a geometry.
+pub trait RemoveRepeatedPoints<T: CoordinateType>: Sized + Clone {
+    /// Remove the coordinates within `tolerance` of the coordinate kept before them, in place.
+    ///
+    /// Closed rings, and closed `LineString`s, stay closed: if the closing coordinate is removed,
+    /// the last coordinate kept is replaced by the first. The points of a `MultiPoint` are only
+    /// compared with the point before them too, and `Point`s, `Line`s, `Rect`s, and `Triangle`s
+    /// are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::remove_repeated_points::RemoveRepeatedPoints;
+    /// use geo::polygon;
+    ///
+    /// let mut polygon = polygon![
+    ///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 0.1), (x: 4., y: 4.), (x: 0., y: 0.05)
+    /// ];
+    /// polygon.remove_repeated_points_within_mut(0.2);
+    ///
+    /// assert_eq!(polygon, polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)]);
+    /// ```
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T);
+
+    /// Return the geometry without the coordinates within `tolerance` of the coordinate kept
+    /// before them; see `remove_repeated_points_within_mut`.
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        let mut geometry = self.clone();
+        geometry.remove_repeated_points_within_mut(tolerance);
+        geometry
+    }
+
+    /// Return the geometry without the coordinates identical to the one before them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::remove_repeated_points::RemoveRepeatedPoints;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0, y: 0), (x: 0, y: 0), (x: 5, y: 2), (x: 0, y: 0)];
+    ///
+    /// assert_eq!(
+    ///     line_string.remove_repeated_points(),
+    ///     line_string![(x: 0, y: 0), (x: 5, y: 2), (x: 0, y: 0)]
+    /// );
+    /// ```
+    fn remove_repeated_points(&self) -> Self {
+        self.remove_repeated_points_within(T::zero())
+    }
+
+    /// Remove the coordinates identical to the one before them, in place.
+    fn remove_repeated_points_mut(&mut self) {
+        self.remove_repeated_points_within_mut(T::zero())
+    }
+}
+
+// Whether two coordinates are within `tolerance` of each other, comparing squares so integer
+// coordinates work too
+fn within<T: CoordinateType>(a: Coordinate<T>, b: Coordinate<T>, tolerance: T) -> bool {
+    let difference = |a: T, b: T| if a > b { a - b } else { b - a };
+    let (dx, dy) = (difference(a.x, b.x), difference(a.y, b.y));
+    dx * dx + dy * dy <= tolerance * tolerance
+}
+
+fn remove_repeated<T: CoordinateType>(coords: &mut Vec<Coordinate<T>>, tolerance: T) {
+    let closed = coords.len() > 1 && coords.first() == coords.last();
+    coords.dedup_by(|coord, kept| within(*kept, *coord, tolerance));
+    if closed && coords.first() != coords.last() {
+        // the closing coordinate was removed, for being close to the last one kept
+        let (first, last) = (coords.len() - 1, coords[0]);
+        coords[first] = last;
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Point<T> {
+    fn remove_repeated_points_within_mut(&mut self, _tolerance: T) {}
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Line<T> {
+    fn remove_repeated_points_within_mut(&mut self, _tolerance: T) {}
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Rect<T> {
+    fn remove_repeated_points_within_mut(&mut self, _tolerance: T) {}
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Triangle<T> {
+    fn remove_repeated_points_within_mut(&mut self, _tolerance: T) {}
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for LineString<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        remove_repeated(&mut self.0, tolerance)
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Polygon<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        self.exterior_mut(|ring| ring.remove_repeated_points_within_mut(tolerance));
+        self.interiors_mut(|rings| {
+            for ring in rings {
+                ring.remove_repeated_points_within_mut(tolerance);
+            }
+        });
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for MultiPoint<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        self.0
+            .dedup_by(|point, kept| within(kept.0, point.0, tolerance));
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for MultiLineString<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        for line_string in &mut self.0 {
+            line_string.remove_repeated_points_within_mut(tolerance);
+        }
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for MultiPolygon<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        for polygon in &mut self.0 {
+            polygon.remove_repeated_points_within_mut(tolerance);
+        }
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for GeometryCollection<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        for geometry in &mut self.0 {
+            geometry.remove_repeated_points_within_mut(tolerance);
+        }
+    }
+}
+
+impl<T: CoordinateType> RemoveRepeatedPoints<T> for Geometry<T> {
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        match self {
+            Geometry::Point(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::Line(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::LineString(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::Polygon(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::MultiPoint(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::MultiLineString(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::MultiPolygon(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::GeometryCollection(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::Rect(g) => g.remove_repeated_points_within_mut(tolerance),
+            Geometry::Triangle(g) => g.remove_repeated_points_within_mut(tolerance),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn line_strings() {
+        let line_string = line_string![
+            (x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.),
+            (x: 2., y: 0.)
+        ];
+        assert_eq!(
+            line_string.remove_repeated_points(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+        );
+        // coordinates are compared with the last one kept, not the one before them
+        let drifting: LineString<f64> = (0..10).map(|i| (i as f64 * 0.3, 0.)).collect();
+        assert_eq!(drifting.remove_repeated_points_within(0.5).0.len(), 5);
+        assert_eq!(
+            line_string![(x: 3, y: 3), (x: 3, y: 3)].remove_repeated_points(),
+            line_string![(x: 3, y: 3)]
+        );
+        assert_eq!(
+            LineString::<u32>(vec![]).remove_repeated_points(),
+            LineString(vec![])
+        );
+        // unsigned coordinates going down don't overflow
+        assert_eq!(
+            line_string![(x: 5u32, y: 5), (x: 4, y: 5), (x: 0, y: 0)]
+                .remove_repeated_points_within(1),
+            line_string![(x: 5u32, y: 5), (x: 0, y: 0)]
+        );
+    }
+
+    #[test]
+    fn rings_stay_closed() {
+        let mut ring = line_string![
+            (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0.1, y: 0.), (x: 0., y: 0.)
+        ];
+        ring.remove_repeated_points_within_mut(0.2);
+        assert_eq!(
+            ring,
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)]
+        );
+
+        let polygon = polygon!(
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 4, y: 4), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)]],
+        );
+        let expected = polygon!(
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+        );
+        assert_eq!(polygon.remove_repeated_points(), expected);
+        assert_eq!(
+            Geometry::MultiPolygon(MultiPolygon(vec![polygon])).remove_repeated_points(),
+            Geometry::MultiPolygon(MultiPolygon(vec![expected]))
+        );
+    }
+
+    #[test]
+    fn other_geometries() {
+        let points = MultiPoint(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 0., y: 0.),
+        ]);
+        assert_eq!(points.remove_repeated_points().0.len(), 3);
+
+        let collection = GeometryCollection(vec![
+            Geometry::Line(Line::new((0., 0.), (0., 0.))),
+            Geometry::Triangle(Triangle::from([(0., 0.), (0., 0.), (1., 1.)])),
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 0., y: 0.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+            ])),
+        ]);
+        let mut removed = collection.clone();
+        removed.remove_repeated_points_mut();
+        assert_eq!(removed.0[..2], collection.0[..2]);
+        assert_eq!(
+            removed.0[2],
+            Geometry::MultiLineString(MultiLineString(vec![
+                line_string![(x: 0., y: 0.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+            ]))
+        );
+    }
+}
@@ -128,6 +128,51 @@ where
     }
 }
 
+/// Rotate a whole geometry around its centroid by an angle, in degrees.
+///
+/// Unlike [`Rotate`](trait.Rotate.html), which turns each part of a multi-geometry about its own
+/// centroid, this turns every part about the centroid of the whole geometry, keeping the parts
+/// in place relative to each other.
+pub trait RotateCentroid<T> {
+    /// Rotate the geometry around its centroid, or the origin if it has none, by an angle, in
+    /// degrees
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// # Units
+    ///
+    /// - `angle`: degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::rotate::RotateCentroid;
+    /// use geo::{point, MultiPoint};
+    ///
+    /// let points = MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 4., y: 0.)]);
+    /// let rotated = points.rotate_around_centroid(180.);
+    ///
+    /// assert_eq!(rotated.0[0].x(), 4.);
+    /// assert_eq!(rotated.0[1].x(), 0.);
+    /// ```
+    fn rotate_around_centroid(&self, angle: T) -> Self
+    where
+        T: Float;
+}
+
+impl<T, G> RotateCentroid<T> for G
+where
+    T: Float,
+    G: Centroid<T> + MapCoords<T, T, Output = G>,
+    <G as Centroid<T>>::Output: Into<Option<Point<T>>>,
+{
+    fn rotate_around_centroid(&self, angle: T) -> Self {
+        let centroid: Option<Point<T>> = self.centroid().into();
+        let centroid = centroid.unwrap_or_else(|| Point::new(T::zero(), T::zero()));
+        self.rotate_around_point(angle, centroid)
+    }
+}
+
 impl<T> Rotate<T> for Point<T>
 where
     T: Float,
@@ -335,6 +380,29 @@ mod test {
         assert_eq!(line0.rotate(90.), line1);
     }
     #[test]
+    fn test_rotate_around_centroid() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let far = polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.), (x: 4., y: 1.)];
+        let multi_polygon = MultiPolygon(vec![square.clone(), far.clone()]);
+        // each part is rotated about the centroid of the whole, at (2.5, 0.5)
+        let rotated = multi_polygon.rotate_around_centroid(180.);
+        for (rotated, original) in rotated.0.iter().zip(&[far, square]) {
+            for coord in &rotated.exterior().0 {
+                assert!(original
+                    .exterior()
+                    .0
+                    .iter()
+                    .any(|other| (coord.x - other.x).abs() < 1e-12
+                        && (coord.y - other.y).abs() < 1e-12));
+            }
+        }
+        // a single polygon turns about its own centroid, like `rotate`
+        let polygon = polygon![(x: 5., y: 1.), (x: 4., y: 2.), (x: 7., y: 3.)];
+        assert_eq!(polygon.rotate_around_centroid(-15.), polygon.rotate(-15.));
+        let empty = MultiPoint::<f64>(vec![]);
+        assert_eq!(empty.rotate_around_centroid(10.), empty);
+    }
+    #[test]
     fn test_rotate_line_around_point() {
         let line0 = Line::new(Point::new(0., 0.), Point::new(0., 2.));
         let line1 = Line::new(
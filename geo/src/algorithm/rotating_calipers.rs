@@ -0,0 +1,280 @@
+use crate::algorithm::convexhull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+
+/// The width of a geometry across one of the edges of its convex hull: the distance between the
+/// line through `edge`, and the parallel line through `vertex`, which between them enclose the
+/// geometry.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct CaliperWidth<T>
+where
+    T: Float,
+{
+    pub width: T,
+    pub edge: Line<T>,
+    pub vertex: Point<T>,
+}
+
+impl<T: Float> CaliperWidth<T> {
+    /// The unit vector across `edge`, toward `vertex`, in which the geometry is `width` wide.
+    ///
+    /// If `vertex` is on the line through `edge`, the vector is the left normal of `edge`, and if
+    /// the geometry is a single point, it's zero.
+    pub fn direction(&self) -> Coordinate<T> {
+        let (dx, dy) = (self.edge.dx(), self.edge.dy());
+        let length = dx.hypot(dy);
+        if length == T::zero() {
+            return Coordinate {
+                x: T::zero(),
+                y: T::zero(),
+            };
+        }
+        let normal = Coordinate {
+            x: -dy / length,
+            y: dx / length,
+        };
+        let (to_x, to_y) = (
+            self.vertex.x() - self.edge.start.x,
+            self.vertex.y() - self.edge.start.y,
+        );
+        if to_x * normal.x + to_y * normal.y < T::zero() {
+            Coordinate {
+                x: -normal.x,
+                y: -normal.y,
+            }
+        } else {
+            normal
+        }
+    }
+}
+
+/// Measure a geometry's convex hull with rotating calipers: pairs of parallel lines turned around
+/// the hull, each pair always touching it on opposite sides.
+pub trait RotatingCalipers<T: Float> {
+    /// Return the two vertices of the geometry farthest apart, as a `Line`, or `None` if the
+    /// geometry is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::euclidean_length::EuclideanLength;
+    /// use geo::algorithm::rotating_calipers::RotatingCalipers;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 1., y: 1.), (x: 0., y: 3.)];
+    ///
+    /// assert_eq!(polygon.diameter().unwrap().euclidean_length(), 5.);
+    /// ```
+    fn diameter(&self) -> Option<Line<T>>;
+
+    /// Return the least width of the geometry in any direction, or `None` if the geometry is
+    /// empty.
+    ///
+    /// The geometry is narrowest across one of the edges of its convex hull. A geometry with no
+    /// area has a width of zero, along the hull's edge between its two vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::rotating_calipers::RotatingCalipers;
+    /// use geo::polygon;
+    ///
+    /// // a parallelogram, narrowest between its longer sides
+    /// let polygon = polygon![(x: 0_f64, y: 0.), (x: 6., y: 0.), (x: 9., y: 4.), (x: 3., y: 4.)];
+    /// let width = polygon.minimum_width().unwrap();
+    ///
+    /// assert_eq!(width.width, 4.);
+    /// assert_eq!(width.direction().y.abs(), 1.);
+    /// ```
+    fn minimum_width(&self) -> Option<CaliperWidth<T>>;
+}
+
+macro_rules! rotating_calipers_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T> RotatingCalipers<T> for $type<T>
+            where
+                T: Float,
+            {
+                fn diameter(&self) -> Option<Line<T>> {
+                    rotating_calipers(self).map(|(diameter, _)| diameter)
+                }
+
+                fn minimum_width(&self) -> Option<CaliperWidth<T>> {
+                    rotating_calipers(self).map(|(_, width)| width)
+                }
+            }
+        )*
+    };
+}
+
+rotating_calipers_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+// twice the area of the triangle of an edge and a vertex
+fn doubled_area<T: Float>(start: Coordinate<T>, end: Coordinate<T>, vertex: Coordinate<T>) -> T {
+    ((end.x - start.x) * (vertex.y - start.y) - (end.y - start.y) * (vertex.x - start.x)).abs()
+}
+
+fn rotating_calipers<'a, T, G>(geometry: &'a G) -> Option<(Line<T>, CaliperWidth<T>)>
+where
+    T: Float,
+    G: CoordsIter<'a, T>,
+{
+    // the interior rings of polygons are inside their exteriors
+    let mut points: Vec<Point<T>> = geometry.exterior_coords_iter().map(Point).collect();
+    let mut hull: Vec<Coordinate<T>> = quick_hull(&mut points).into_iter().map(|p| p.0).collect();
+    hull.dedup();
+    if hull.len() > 1 && hull.first() == hull.last() {
+        hull.pop();
+    }
+    let first = *hull.first()?;
+    if hull.len() == 1 {
+        let width = CaliperWidth {
+            width: T::zero(),
+            edge: Line::new(first, first),
+            vertex: Point(first),
+        };
+        return Some((Line::new(first, first), width));
+    }
+
+    let n = hull.len();
+    let mut diameter = (T::zero(), Line::new(first, first));
+    let mut check_diameter = |start: Coordinate<T>, end: Coordinate<T>| {
+        let length = (end.x - start.x).hypot(end.y - start.y);
+        if length > diameter.0 {
+            diameter = (length, Line::new(start, end));
+        }
+    };
+    let mut width: Option<CaliperWidth<T>> = None;
+    // the vertex farthest from the current edge, which only ever moves forward around the hull
+    let mut j = 1;
+    for i in 0..n {
+        let (start, end) = (hull[i], hull[(i + 1) % n]);
+        while doubled_area(start, end, hull[(j + 1) % n]) > doubled_area(start, end, hull[j]) {
+            j = (j + 1) % n;
+            check_diameter(start, hull[j]);
+        }
+        // comparing a pair which isn't antipodal does no harm, and covers parallel edges
+        for &vertex in &[hull[j], hull[(j + 1) % n]] {
+            check_diameter(start, vertex);
+            check_diameter(end, vertex);
+        }
+        let length = (end.x - start.x).hypot(end.y - start.y);
+        let across = doubled_area(start, end, hull[j]) / length;
+        let narrower = match width {
+            Some(width) => across < width.width,
+            None => true,
+        };
+        if narrower {
+            width = Some(CaliperWidth {
+                width: across,
+                edge: Line::new(start, end),
+                vertex: Point(hull[j]),
+            });
+        }
+    }
+    width.map(|width| (diameter.1, width))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::{line_string, point};
+
+    // widths and diameters found by comparing every pair of vertices, and every vertex with every
+    // hull edge
+    fn brute_force(points: &[Point<f64>]) -> (f64, f64) {
+        let mut hull = quick_hull(&mut points.to_vec());
+        hull.dedup();
+        let mut diameter = 0_f64;
+        for a in points {
+            for b in points {
+                diameter = diameter.max(a.euclidean_distance(b));
+            }
+        }
+        let width = hull
+            .windows(2)
+            .map(|edge| {
+                let line = Line::new(edge[0], edge[1]);
+                points
+                    .iter()
+                    .map(|point| {
+                        doubled_area(line.start, line.end, point.0) / line.euclidean_length()
+                    })
+                    .fold(0., f64::max)
+            })
+            .fold(f64::INFINITY, f64::min);
+        (diameter, width)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // a linear congruential generator, for reproducible point clouds
+        let mut seed = 12345_u64;
+        let mut random = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as f64 / (1_u64 << 31) as f64
+        };
+        for size in 3..40 {
+            let points: Vec<Point<f64>> = (0..size)
+                .map(|_| point!(x: random() * 10., y: random() * 3.))
+                .collect();
+            let (diameter, width) = brute_force(&points);
+            let multi_point = MultiPoint(points);
+            let found = multi_point.diameter().unwrap();
+            assert_relative_eq!(found.euclidean_length(), diameter, epsilon = 1e-9);
+            let found = multi_point.minimum_width().unwrap();
+            assert_relative_eq!(found.width, width, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn rectangle() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 3. });
+        assert_eq!(rect.diameter().unwrap().euclidean_length(), 5.);
+        let width = rect.minimum_width().unwrap();
+        assert_eq!(width.width, 3.);
+        assert_relative_eq!(width.direction().x.abs(), 0.);
+        assert_relative_eq!(width.direction().y.abs(), 1.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.), (x: 2., y: 2.)];
+        let diameter = line_string.diameter().unwrap();
+        assert_eq!(diameter.euclidean_length(), 18_f64.sqrt());
+        let width = line_string.minimum_width().unwrap();
+        assert_eq!(width.width, 0.);
+        assert_relative_eq!(width.direction().x * width.direction().y, -0.5);
+
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(point.diameter(), Some(Line::new(point.0, point.0)));
+        let width = MultiPoint(vec![point, point]).minimum_width().unwrap();
+        assert_eq!(width.width, 0.);
+        assert_eq!(width.direction(), Coordinate { x: 0., y: 0. });
+
+        assert_eq!(MultiPoint::<f64>(vec![]).diameter(), None);
+        assert_eq!(GeometryCollection::<f64>(vec![]).minimum_width(), None);
+    }
+}
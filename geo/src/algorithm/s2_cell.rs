@@ -0,0 +1,653 @@
+//! Divide the sphere into a hierarchy of cells named by 64-bit ids, in the style of
+//! [S2](https://s2geometry.io/), and cover regions with them.
+//!
+//! The sphere is projected onto the six faces of a cube, and each face is divided into a
+//! quadtree: the whole face is the cell at level `0`, and each cell has four children at the
+//! next level, down to [`MAX_LEVEL`](constant.MAX_LEVEL.html), where cells are about a
+//! centimetre across. Like S2, the faces are projected using a quadratic transform, so that
+//! cells at the same level have similar areas, and ids store the face in their top three bits,
+//! followed by two bits per level and a trailing `1`, so a cell's descendants have ids in a
+//! contiguous range around its own. Unlike S2, the children of a cell are numbered in Z-order
+//! rather than along a Hilbert curve, so the ids aren't interchangeable with S2's.
+//!
+//! Geometries are in longitudes (`x`) and latitudes (`y`), in degrees.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::s2_cell::{CellId, Covering};
+//! use geo::{point, polygon};
+//!
+//! let cell = CellId::from_point(&point!(x: 8.54, y: 47.37), 12);
+//! assert_eq!(cell.level(), 12);
+//! assert!(cell.parent().unwrap().contains(&cell));
+//!
+//! let area = polygon![(x: 8., y: 47.), (x: 9., y: 47.), (x: 9., y: 48.), (x: 8., y: 48.)];
+//! let covering = area.covering(8, 0..=30);
+//! assert!(covering.iter().any(|covering_cell| covering_cell.contains(&cell)));
+//! ```
+use crate::algorithm::contains::Contains;
+use crate::algorithm::intersects::Intersects;
+use crate::{Coordinate, Line, LineString, MultiPolygon, Point, Polygon, Rect};
+use num_traits::Float;
+use std::ops::RangeInclusive;
+
+/// The finest level of cells.
+pub const MAX_LEVEL: u8 = 30;
+
+const POSITION_BITS: u32 = 2 * MAX_LEVEL as u32 + 1;
+const MAX_SIZE: u32 = 1 << MAX_LEVEL;
+
+/// A cell of the [hierarchy](index.html), named by its 64-bit id.
+///
+/// Ids are ordered so that each cell's descendants come between the descendants of the cells
+/// before and after it at its level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CellId(u64);
+
+// The point on the cube of a face and its (u, v) coordinates
+fn face_uv_to_xyz<T: Float>(face: u8, u: T, v: T) -> [T; 3] {
+    let one = T::one();
+    match face {
+        0 => [one, u, v],
+        1 => [-u, one, v],
+        2 => [-u, -v, one],
+        3 => [-one, -v, -u],
+        4 => [v, -one, -u],
+        _ => [v, u, -one],
+    }
+}
+
+// The face a point on the sphere projects onto, and its (u, v) coordinates there
+fn xyz_to_face_uv<T: Float>([x, y, z]: [T; 3]) -> (u8, T, T) {
+    let axis = if x.abs() >= y.abs() && x.abs() >= z.abs() {
+        0
+    } else if y.abs() >= z.abs() {
+        1
+    } else {
+        2
+    };
+    let face = if [x, y, z][axis] < T::zero() {
+        axis + 3
+    } else {
+        axis
+    };
+    let (u, v) = match face {
+        0 => (y / x, z / x),
+        1 => (-x / y, z / y),
+        2 => (-x / z, -y / z),
+        3 => (z / x, y / x),
+        4 => (z / y, -x / y),
+        _ => (-y / z, -x / z),
+    };
+    (face as u8, u, v)
+}
+
+// The quadratic transform S2 uses between cube coordinates in -1..1 and face coordinates in
+// 0..1, so that cells are closer to the same size
+fn uv_to_st<T: Float>(u: T) -> T {
+    let (half, three) = (T::from(0.5).unwrap(), T::from(3).unwrap());
+    if u >= T::zero() {
+        half * (T::one() + three * u).sqrt()
+    } else {
+        T::one() - half * (T::one() - three * u).sqrt()
+    }
+}
+
+fn st_to_uv<T: Float>(s: T) -> T {
+    let (half, three, four) = (
+        T::from(0.5).unwrap(),
+        T::from(3).unwrap(),
+        T::from(4).unwrap(),
+    );
+    if s >= half {
+        (four * s * s - T::one()) / three
+    } else {
+        (T::one() - four * (T::one() - s) * (T::one() - s)) / three
+    }
+}
+
+// The position of a face coordinate among the cells at the finest level
+fn st_to_ij<T: Float>(s: T) -> u32 {
+    let size = T::from(MAX_SIZE).unwrap();
+    (s * size)
+        .floor()
+        .max(T::zero())
+        .min(size - T::one())
+        .to_u32()
+        .unwrap_or(0)
+}
+
+fn lon_lat_to_xyz<T: Float>(point: &Point<T>) -> [T; 3] {
+    let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+// Interleave the bits of `i` and `j`, with those of `i` first
+fn interleave(i: u32, j: u32) -> u64 {
+    let spread = |value: u32| {
+        let mut value = value as u64;
+        value = (value | (value << 16)) & 0x0000_ffff_0000_ffff;
+        value = (value | (value << 8)) & 0x00ff_00ff_00ff_00ff;
+        value = (value | (value << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+        (value | (value << 1)) & 0x5555_5555_5555_5555
+    };
+    (spread(i) << 1) | spread(j)
+}
+
+fn deinterleave(bits: u64) -> (u32, u32) {
+    let compact = |value: u64| {
+        let mut value = value & 0x5555_5555_5555_5555;
+        value = (value | (value >> 1)) & 0x3333_3333_3333_3333;
+        value = (value | (value >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+        value = (value | (value >> 4)) & 0x00ff_00ff_00ff_00ff;
+        value = (value | (value >> 8)) & 0x0000_ffff_0000_ffff;
+        ((value | (value >> 16)) & 0x0000_0000_ffff_ffff) as u32
+    };
+    (compact(bits >> 1), compact(bits))
+}
+
+impl CellId {
+    /// Return the cell covering a whole face of the cube, from `0` to `5`, or `None` if there's
+    /// no such face.
+    pub fn from_face(face: u8) -> Option<CellId> {
+        if face > 5 {
+            return None;
+        }
+        Some(CellId(
+            ((face as u64) << POSITION_BITS) | (1 << (POSITION_BITS - 1)),
+        ))
+    }
+
+    // The cell at `level` holding the finest cell at (i, j) of a face
+    fn from_face_ij(face: u8, i: u32, j: u32, level: u8) -> CellId {
+        let leaf = CellId(((face as u64) << POSITION_BITS) | (interleave(i, j) << 1) | 1);
+        leaf.ancestor(level)
+    }
+
+    /// Return the cell at `level` holding `point`, whose `x` is its longitude and `y` its
+    /// latitude in degrees. Levels past [`MAX_LEVEL`](constant.MAX_LEVEL.html) are treated
+    /// as the finest level.
+    pub fn from_point<T: Float>(point: &Point<T>, level: u8) -> CellId {
+        let (face, u, v) = xyz_to_face_uv(lon_lat_to_xyz(point));
+        let (i, j) = (st_to_ij(uv_to_st(u)), st_to_ij(uv_to_st(v)));
+        CellId::from_face_ij(face, i, j, level.min(MAX_LEVEL))
+    }
+
+    /// Return the cell named by an id, or `None` if it isn't a valid id.
+    pub fn from_id(id: u64) -> Option<CellId> {
+        let zeros = id.trailing_zeros();
+        let valid = id >> POSITION_BITS <= 5 && zeros < POSITION_BITS && zeros & 1 == 0;
+        if valid {
+            Some(CellId(id))
+        } else {
+            None
+        }
+    }
+
+    /// The 64-bit id naming the cell.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    /// The face of the cube the cell is on, from `0` to `5`.
+    pub fn face(&self) -> u8 {
+        (self.0 >> POSITION_BITS) as u8
+    }
+
+    /// The level of the cell, from `0` for a whole face to [`MAX_LEVEL`](constant.MAX_LEVEL.html).
+    pub fn level(&self) -> u8 {
+        MAX_LEVEL - (self.0.trailing_zeros() / 2) as u8
+    }
+
+    // The lowest set bit of the id, which marks its level
+    fn lsb(&self) -> u64 {
+        1 << self.0.trailing_zeros()
+    }
+
+    // The cell at `level` holding this one, which must be at `level` or finer
+    fn ancestor(&self, level: u8) -> CellId {
+        let lsb = 1u64 << (2 * (MAX_LEVEL - level) as u32);
+        CellId((self.0 & lsb.wrapping_neg()) | lsb)
+    }
+
+    /// The cell at the next coarser level holding this one, or `None` for a whole face.
+    pub fn parent(&self) -> Option<CellId> {
+        let level = self.level().checked_sub(1)?;
+        Some(self.ancestor(level))
+    }
+
+    /// The four cells at the next finer level dividing this one, or `None` at
+    /// [`MAX_LEVEL`](constant.MAX_LEVEL.html).
+    pub fn children(&self) -> Option<[CellId; 4]> {
+        if self.level() == MAX_LEVEL {
+            return None;
+        }
+        let lsb = self.lsb();
+        let first = self.0 - lsb + (lsb >> 2);
+        let step = lsb >> 1;
+        Some([
+            CellId(first),
+            CellId(first + step),
+            CellId(first + 2 * step),
+            CellId(first + 3 * step),
+        ])
+    }
+
+    /// Whether `other` is this cell or one of its descendants.
+    pub fn contains(&self, other: &CellId) -> bool {
+        let lsb = self.lsb();
+        self.0 - (lsb - 1) <= other.0 && other.0 <= self.0 + (lsb - 1)
+    }
+
+    // The face coordinates of the cell's corners, from 0 to 1
+    fn st_bounds<T: Float>(&self) -> (T, T, T, T) {
+        let leaf_bits = self.0 & ((1 << POSITION_BITS) - 1);
+        let (i, j) = deinterleave(leaf_bits >> 1);
+        let size = 1u32 << (MAX_LEVEL - self.level());
+        let (i, j) = (i & !(size - 1), j & !(size - 1));
+        let scale = T::from(MAX_SIZE).unwrap();
+        let st = |value: u32| T::from(value).unwrap() / scale;
+        (st(i), st(j), st(i + size), st(j + size))
+    }
+
+    /// The cell's boundary, as a `Polygon` of longitudes and latitudes in degrees.
+    ///
+    /// The edges of cells are great circle arcs, which are approximated by points along them,
+    /// for coarse cells. Longitudes are continuous along the boundary, so a cell crossing the
+    /// antimeridian has longitudes beyond `180` or `-180`, and a cell around a pole follows
+    /// its boundary once around the pole, then the line of latitude at the pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::s2_cell::CellId;
+    /// use geo::point;
+    ///
+    /// let cell = CellId::from_point(&point!(x: -0.1276, y: 51.5072), 10);
+    /// let polygon = cell.to_polygon::<f64>();
+    ///
+    /// assert!(polygon.contains(&point!(x: -0.1276, y: 51.5072)));
+    /// ```
+    pub fn to_polygon<T: Float>(&self) -> Polygon<T> {
+        let (s0, t0, s1, t1) = self.st_bounds::<T>();
+        let pieces = (16 >> self.level().min(4)).max(1);
+        let corners = [(s0, t0), (s1, t0), (s1, t1), (s0, t1), (s0, t0)];
+        // the boundary in face coordinates, with `None` longitudes at a pole
+        let mut boundary: Vec<(Option<T>, T)> = Vec::with_capacity(4 * pieces);
+        for window in corners.windows(2) {
+            let ((sa, ta), (sb, tb)) = (window[0], window[1]);
+            for piece in 0..pieces {
+                let fraction = T::from(piece).unwrap() / T::from(pieces).unwrap();
+                let s = sa + (sb - sa) * fraction;
+                let t = ta + (tb - ta) * fraction;
+                let [x, y, z] = face_uv_to_xyz(self.face(), st_to_uv(s), st_to_uv(t));
+                let lat = z.atan2(x.hypot(y)).to_degrees();
+                let lon = if x == T::zero() && y == T::zero() {
+                    None
+                } else {
+                    Some(y.atan2(x).to_degrees())
+                };
+                boundary.push((lon, lat));
+            }
+        }
+        // start away from any pole, so that its longitude can come from its neighbors
+        let start = boundary.iter().position(|(lon, _)| lon.is_some()).unwrap();
+        boundary.rotate_left(start);
+
+        let full_turn = T::from(360).unwrap();
+        let half_turn = T::from(180).unwrap();
+        let unwrap = |lon: T, previous: T| {
+            let mut lon = lon;
+            while lon - previous > half_turn {
+                lon = lon - full_turn;
+            }
+            while previous - lon > half_turn {
+                lon = lon + full_turn;
+            }
+            lon
+        };
+        let mut ring: Vec<Coordinate<T>> = Vec::with_capacity(boundary.len() + 4);
+        let mut at_pole = None;
+        for &(lon, lat) in boundary.iter().chain(boundary.first()) {
+            match lon {
+                None => {
+                    // go to the pole along the previous meridian, and leave along the next
+                    let previous = ring[ring.len() - 1].x;
+                    ring.push(Coordinate {
+                        x: previous,
+                        y: lat,
+                    });
+                    at_pole = Some(lat);
+                }
+                Some(lon) => {
+                    let lon = match ring.last() {
+                        Some(previous) => unwrap(lon, previous.x),
+                        None => lon,
+                    };
+                    if let Some(lat) = at_pole.take() {
+                        ring.push(Coordinate { x: lon, y: lat });
+                    }
+                    ring.push(Coordinate { x: lon, y: lat });
+                }
+            }
+        }
+        let (first, last) = (ring[0], ring[ring.len() - 1]);
+        if (last.x - first.x).abs() > half_turn {
+            // the boundary went around a pole
+            let [_, _, z] = face_uv_to_xyz(self.face(), T::zero(), T::zero());
+            let quarter_turn = half_turn / (T::one() + T::one());
+            let pole = if z > T::zero() {
+                quarter_turn
+            } else {
+                -quarter_turn
+            };
+            ring.push(Coordinate { x: last.x, y: pole });
+            ring.push(Coordinate {
+                x: first.x,
+                y: pole,
+            });
+            ring.push(first);
+        }
+        Polygon::new(LineString(ring), vec![])
+    }
+
+    // The cell's boundary, and copies of it shifted by a turn to bring any longitudes beyond
+    // the antimeridian back between -180 and 180
+    fn polygons<T: Float>(&self) -> Vec<Polygon<T>> {
+        let polygon = self.to_polygon::<T>();
+        let rect = polygon.bounding_rect().unwrap();
+        let (full_turn, half_turn) = (T::from(360).unwrap(), T::from(180).unwrap());
+        let mut shifts = vec![T::zero()];
+        if rect.min().x < -half_turn {
+            shifts.push(full_turn);
+        }
+        if rect.max().x > half_turn {
+            shifts.push(-full_turn);
+        }
+        shifts
+            .into_iter()
+            .map(|shift| {
+                let ring = polygon
+                    .exterior()
+                    .0
+                    .iter()
+                    .map(|c| Coordinate {
+                        x: c.x + shift,
+                        y: c.y,
+                    })
+                    .collect();
+                Polygon::new(LineString(ring), vec![])
+            })
+            .collect()
+    }
+}
+
+/// Cover a geometry of longitudes and latitudes with [cells](index.html), for sharding data by
+/// region.
+///
+/// Cells are tested against the geometry using their approximate
+/// [boundary](struct.CellId.html#method.to_polygon) in degrees, and the edges of the geometry
+/// are taken to be straight in degrees, so the covering is approximate near the edges of cells.
+pub trait Covering<T: Float> {
+    /// Return cells between the levels in `levels` which together cover the geometry, sorted
+    /// by id, trying to use no more than `max_cells` of them.
+    ///
+    /// Starting from the faces of the cube, each cell which intersects the geometry is divided
+    /// into its children, unless it's inside the geometry, it's at the finest level allowed,
+    /// or dividing it would take the covering over `max_cells`. The cells at the coarsest level
+    /// allowed are always divided, so `max_cells` may be exceeded for geometries crossing many
+    /// of them. A point is covered by the single cell at the finest level allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::s2_cell::Covering;
+    /// use geo::polygon;
+    ///
+    /// let area = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    ///
+    /// let coarse = area.covering(4, 0..=30);
+    /// assert!(coarse.len() <= 4);
+    /// let fine = area.covering(100, 0..=30);
+    /// assert!(fine.len() > 4 && fine.len() <= 100);
+    /// // each fine cell is inside one of the coarse cells
+    /// assert!(fine
+    ///     .iter()
+    ///     .all(|cell| coarse.iter().any(|coarse| coarse.contains(cell))));
+    /// ```
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId>;
+}
+
+// Cover a region, given whether it intersects and contains a polygon
+fn cover<T, I, C>(
+    max_cells: usize,
+    levels: RangeInclusive<u8>,
+    intersects: I,
+    contains: C,
+) -> Vec<CellId>
+where
+    T: Float,
+    I: Fn(&Polygon<T>) -> bool,
+    C: Fn(&Polygon<T>) -> bool,
+{
+    let (min_level, max_level) = (*levels.start(), (*levels.end()).min(MAX_LEVEL));
+    let mut covering = vec![];
+    if min_level > max_level {
+        return covering;
+    }
+    let intersects_cell = |cell: &CellId| cell.polygons().iter().any(&intersects);
+    let mut cells: Vec<CellId> = (0..6)
+        .filter_map(CellId::from_face)
+        .filter(|cell| intersects_cell(cell))
+        .collect();
+    while !cells.is_empty() {
+        let mut next = vec![];
+        for (position, cell) in cells.iter().enumerate() {
+            let level = cell.level();
+            if level >= min_level && (level == max_level || contains(&cell.to_polygon::<T>())) {
+                covering.push(*cell);
+                continue;
+            }
+            let children: Vec<CellId> = cell
+                .children()
+                .unwrap()
+                .iter()
+                .copied()
+                .filter(|child| intersects_cell(child))
+                .collect();
+            let count = covering.len() + next.len() + (cells.len() - position - 1);
+            // keep the cell if its boundary's approximation misses the children's
+            if children.is_empty() || (level >= min_level && count + children.len() > max_cells) {
+                covering.push(*cell);
+            } else {
+                next.extend(children);
+            }
+        }
+        cells = next;
+    }
+    covering.sort();
+    covering
+}
+
+impl<T: Float> Covering<T> for Point<T> {
+    fn covering(&self, _max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        if levels.start() > levels.end() || *levels.start() > MAX_LEVEL {
+            return vec![];
+        }
+        vec![CellId::from_point(self, *levels.end())]
+    }
+}
+
+impl<T: Float> Covering<T> for Line<T> {
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        cover(max_cells, levels, |cell| self.intersects(cell), |_| false)
+    }
+}
+
+impl<T: Float> Covering<T> for LineString<T> {
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        cover(max_cells, levels, |cell| self.intersects(cell), |_| false)
+    }
+}
+
+impl<T: Float> Covering<T> for Polygon<T> {
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        cover(
+            max_cells,
+            levels,
+            |cell| self.intersects(cell),
+            |cell| self.contains(cell),
+        )
+    }
+}
+
+impl<T: Float> Covering<T> for MultiPolygon<T> {
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        cover(
+            max_cells,
+            levels,
+            |cell| self.0.iter().any(|polygon| polygon.intersects(cell)),
+            |cell| self.0.iter().any(|polygon| polygon.contains(cell)),
+        )
+    }
+}
+
+impl<T: Float> Covering<T> for Rect<T> {
+    fn covering(&self, max_cells: usize, levels: RangeInclusive<u8>) -> Vec<CellId> {
+        Polygon::from(*self).covering(max_cells, levels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    // deterministic pseudo-random points, spread over the sphere
+    fn random_points(count: usize) -> Vec<Point<f64>> {
+        let mut seed = 12345u64;
+        let mut random = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count)
+            .map(|_| {
+                let lon = random() * 360. - 180.;
+                let lat = (random() * 2. - 1.).asin().to_degrees();
+                point!(x: lon, y: lat)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hierarchy() {
+        for point in random_points(200) {
+            let leaf = CellId::from_point(&point, MAX_LEVEL);
+            assert_eq!(leaf.level(), MAX_LEVEL);
+            assert_eq!(leaf.children(), None);
+            let mut cell = leaf;
+            while let Some(parent) = cell.parent() {
+                assert_eq!(parent.level(), cell.level() - 1);
+                assert!(parent.contains(&cell) && parent.contains(&leaf));
+                assert!(parent.children().unwrap().contains(&cell));
+                assert_eq!(CellId::from_point(&point, parent.level()), parent);
+                cell = parent;
+            }
+            assert_eq!(cell, CellId::from_face(leaf.face()).unwrap());
+            assert_eq!(CellId::from_id(leaf.id()), Some(leaf));
+        }
+        let face = CellId::from_face(3).unwrap();
+        let children = face.children().unwrap();
+        assert!(children.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(!children[0].contains(&children[1]));
+        assert_eq!(CellId::from_face(6), None);
+        assert_eq!(CellId::from_id(0), None);
+        assert_eq!(CellId::from_id(face.id() << 1), None);
+        assert_eq!(CellId::from_id(u64::MAX), None);
+    }
+
+    #[test]
+    fn polygons_hold_their_points() {
+        for point in random_points(500) {
+            let (_, u, v) = xyz_to_face_uv(lon_lat_to_xyz(&point));
+            for &level in &[3, 8, 16] {
+                let cell = CellId::from_point(&point, level);
+                // the boundary is approximate, so skip points next to it
+                let (s0, t0, s1, t1) = cell.st_bounds::<f64>();
+                let margin = (s1 - s0) / 20.;
+                let (s, t) = (uv_to_st(u), uv_to_st(v));
+                if s - s0 < margin || s1 - s < margin || t - t0 < margin || t1 - t < margin {
+                    continue;
+                }
+                assert!(
+                    cell.polygons::<f64>()
+                        .iter()
+                        .any(|polygon| polygon.intersects(&point)),
+                    "{:?} isn't in {:?}",
+                    point,
+                    cell
+                );
+            }
+        }
+        // the faces around the poles, whose longitudes go once around
+        let contains = |cell: CellId, point: Point<f64>| {
+            cell.polygons::<f64>()
+                .iter()
+                .any(|polygon| polygon.contains(&point))
+        };
+        let north = CellId::from_point(&point!(x: 0., y: 90.), 0);
+        assert!(contains(north, point!(x: 123., y: 89.)));
+        assert!(contains(north, point!(x: -170., y: 60.)));
+        assert!(!contains(north, point!(x: 123., y: 30.)));
+        let south = CellId::from_point(&point!(x: 0., y: -90.), 0);
+        assert!(contains(south, point!(x: -57., y: -89.)));
+        assert!(contains(south, point!(x: 179., y: -70.)));
+        // a cell with a corner at the pole
+        let corner = CellId::from_point(&point!(x: 40., y: 89.9), 3).to_polygon();
+        assert!(corner.contains(&point!(x: 40., y: 89.)));
+    }
+
+    #[test]
+    fn coverings_contain_the_geometry() {
+        let polygon = polygon![
+            (x: -20., y: 30.),
+            (x: 40., y: 35.),
+            (x: 30., y: 70.),
+            (x: -10., y: 60.),
+        ];
+        for &max_cells in &[1, 8, 50] {
+            let covering = polygon.covering(max_cells, 2..=20);
+            assert!(!covering.is_empty());
+            assert!(covering.iter().all(|cell| cell.level() >= 2));
+            for point in random_points(2000) {
+                if polygon.contains(&point) {
+                    let leaf = CellId::from_point(&point, MAX_LEVEL);
+                    assert!(covering.iter().any(|cell| cell.contains(&leaf)));
+                }
+            }
+        }
+        assert!(polygon.covering(50, 0..=30).len() <= 50);
+        assert!(polygon.covering(8, 31..=31).is_empty());
+
+        // across the antimeridian, and next to the pole
+        let far =
+            polygon![(x: 175., y: 80.), (x: 180., y: 80.), (x: 180., y: 89.), (x: 175., y: 89.)];
+        let covering = far.covering(20, 0..=12);
+        let leaf = CellId::from_point(&point!(x: 179.9, y: 88.), MAX_LEVEL);
+        assert!(covering.iter().any(|cell| cell.contains(&leaf)));
+
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(
+            point.covering(10, 0..=9),
+            vec![CellId::from_point(&point, 9)]
+        );
+        let line_string = LineString::from(vec![(0., 0.), (10., 10.)]);
+        let covering = line_string.covering(10, 0..=30);
+        assert!(covering.len() <= 10);
+        let leaf = CellId::from_point(&point!(x: 5., y: 5.), MAX_LEVEL);
+        assert!(covering.iter().any(|cell| cell.contains(&leaf)));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::algorithm::affine_ops::{AffineOps, AffineTransform};
+use crate::algorithm::centroid::Centroid;
+use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use crate::Point;
+use num_traits::Float;
+
+/// Scale a geometry, growing or shrinking it about its centroid or a given point.
+///
+/// The geometry is scaled about its centroid, or the origin if it has none, as a whole: the
+/// parts of a multi-geometry move apart or together as they grow or shrink.
+pub trait Scale<T: Float> {
+    /// Scale the geometry by a factor, in both directions, about its centroid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::scale::Scale;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+    ///
+    /// assert_eq!(
+    ///     line_string.scale(2.),
+    ///     line_string![(x: -1., y: -1.), (x: 3., y: 3.)]
+    /// );
+    /// ```
+    fn scale(&self, factor: T) -> Self;
+
+    /// Scale the geometry by `x_factor` horizontally and `y_factor` vertically, about its
+    /// centroid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::scale::Scale;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+    ///
+    /// assert_eq!(
+    ///     line_string.scale_xy(2., 0.5),
+    ///     line_string![(x: -1., y: 0.5), (x: 3., y: 1.5)]
+    /// );
+    /// ```
+    fn scale_xy(&self, x_factor: T, y_factor: T) -> Self;
+
+    /// Scale the geometry by `x_factor` horizontally and `y_factor` vertically, about `origin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::scale::Scale;
+    /// use geo::{line_string, point};
+    ///
+    /// let line_string = line_string![(x: 1., y: 1.), (x: 2., y: 2.)];
+    ///
+    /// assert_eq!(
+    ///     line_string.scale_around_point(3., 2., point!(x: 1., y: 0.)),
+    ///     line_string![(x: 1., y: 2.), (x: 4., y: 4.)]
+    /// );
+    /// ```
+    fn scale_around_point(&self, x_factor: T, y_factor: T, origin: Point<T>) -> Self;
+}
+
+impl<T, G> Scale<T> for G
+where
+    T: Float,
+    G: Centroid<T> + MapCoords<T, T, Output = G> + MapCoordsInplace<T>,
+    <G as Centroid<T>>::Output: Into<Option<Point<T>>>,
+{
+    fn scale(&self, factor: T) -> Self {
+        self.scale_xy(factor, factor)
+    }
+
+    fn scale_xy(&self, x_factor: T, y_factor: T) -> Self {
+        let origin: Option<Point<T>> = self.centroid().into();
+        let origin = origin.unwrap_or_else(|| Point::new(T::zero(), T::zero()));
+        self.scale_around_point(x_factor, y_factor, origin)
+    }
+
+    fn scale_around_point(&self, x_factor: T, y_factor: T, origin: Point<T>) -> Self {
+        let transform = AffineTransform::identity()
+            .translate(-origin.x(), -origin.y())
+            .scale(x_factor, y_factor)
+            .translate(origin.x(), origin.y());
+        self.affine_transform(&transform)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{point, polygon, MultiPoint, MultiPolygon};
+
+    #[test]
+    fn scales_about_the_centroid() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+        let scaled = polygon.scale(0.5);
+        assert_eq!(
+            scaled,
+            polygon![(x: 1., y: 0.5), (x: 3., y: 0.5), (x: 3., y: 1.5), (x: 1., y: 1.5)]
+        );
+        assert_eq!(scaled.unsigned_area(), polygon.unsigned_area() / 4.);
+        assert_eq!(polygon.scale(1.), polygon);
+        // a negative factor mirrors the geometry
+        let mirrored = polygon.scale_xy(-1., 1.);
+        assert_eq!(mirrored.signed_area(), -polygon.signed_area());
+    }
+
+    #[test]
+    fn multi_geometries_scale_as_a_whole() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let far = polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.), (x: 4., y: 1.)];
+        let scaled = MultiPolygon(vec![square, far]).scale(2.);
+        assert_eq!(
+            scaled.0[0],
+            polygon![(x: -2.5, y: -0.5), (x: -0.5, y: -0.5), (x: -0.5, y: 1.5), (x: -2.5, y: 1.5)]
+        );
+        assert_eq!(
+            scaled.0[1],
+            polygon![(x: 5.5, y: -0.5), (x: 7.5, y: -0.5), (x: 7.5, y: 1.5), (x: 5.5, y: 1.5)]
+        );
+    }
+
+    #[test]
+    fn points_and_empty() {
+        let point = point!(x: 3., y: 4.);
+        assert_eq!(point.scale(10.), point);
+        assert_eq!(
+            point.scale_around_point(2., 2., point!(x: 0., y: 0.)),
+            point!(x: 6., y: 8.)
+        );
+        let empty = MultiPoint::<f64>(vec![]);
+        assert_eq!(empty.scale(2.), empty);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::sweep_line::segment_intersections;
+use crate::{Coordinate, Line, LineString, MultiPolygon, Polygon};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// Find where a geometry crosses or touches itself, to reject invalid boundaries with a report
+/// of where the problems are.
+///
+/// A `LineString` intersects itself wherever two of its segments meet, other than neighbouring
+/// segments at the vertex they share; if it's closed, its first and last segments are
+/// neighbours too. Repeated vertices are ignored, but neighbouring segments which double back
+/// over each other intersect along their overlap. The rings of `Polygon`s and `MultiPolygon`s
+/// intersect themselves in the same way, and also intersect where two rings cross or share a
+/// segment; rings touching at single points are allowed, as for valid polygons.
+///
+/// The intersections are found using a [sweep line](../sweep_line/fn.segment_intersections.html),
+/// so large geometries are checked quickly.
+pub trait SelfIntersections<T: Float> {
+    /// Return the points where the geometry intersects itself, sorted by `x`, then `y`, without
+    /// duplicates. Where it overlaps itself, both ends of the overlap are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::self_intersections::SelfIntersections;
+    /// use geo::{line_string, polygon, Coordinate};
+    ///
+    /// // a bow tie, crossing itself in the middle
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+    /// assert_eq!(polygon.self_intersections(), vec![Coordinate { x: 2., y: 2. }]);
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+    /// assert!(line_string.self_intersections().is_empty());
+    /// ```
+    fn self_intersections(&self) -> Vec<Coordinate<T>>;
+
+    /// Return whether the geometry intersects itself anywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::self_intersections::SelfIntersections;
+    /// use geo::line_string;
+    ///
+    /// // a loop, which crosses itself where it touches its first segment
+    /// let line_string = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 4., y: 0.),
+    ///     (x: 4., y: 2.),
+    ///     (x: 2., y: 0.),
+    /// ];
+    /// assert!(line_string.has_self_intersections());
+    /// ```
+    fn has_self_intersections(&self) -> bool {
+        !self.self_intersections().is_empty()
+    }
+}
+
+impl<T: Float> SelfIntersections<T> for LineString<T> {
+    fn self_intersections(&self) -> Vec<Coordinate<T>> {
+        self_intersections(&[self])
+    }
+}
+
+impl<T: Float> SelfIntersections<T> for Polygon<T> {
+    fn self_intersections(&self) -> Vec<Coordinate<T>> {
+        let rings: Vec<&LineString<T>> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        self_intersections(&rings)
+    }
+}
+
+impl<T: Float> SelfIntersections<T> for MultiPolygon<T> {
+    fn self_intersections(&self) -> Vec<Coordinate<T>> {
+        let rings: Vec<&LineString<T>> = self
+            .0
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        self_intersections(&rings)
+    }
+}
+
+// The segments of a line string or ring, among those of all of them
+struct Part {
+    first: usize,
+    count: usize,
+    closed: bool,
+}
+
+fn self_intersections<T: Float>(line_strings: &[&LineString<T>]) -> Vec<Coordinate<T>> {
+    let mut lines = vec![];
+    let mut parts = vec![];
+    // the index of the part each segment is in
+    let mut part_of = vec![];
+    for line_string in line_strings {
+        let mut coords = line_string.0.clone();
+        coords.dedup();
+        let first = lines.len();
+        lines.extend(
+            coords
+                .windows(2)
+                .map(|window| Line::new(window[0], window[1])),
+        );
+        part_of.resize(lines.len(), parts.len());
+        parts.push(Part {
+            first,
+            count: lines.len() - first,
+            closed: coords.len() > 3 && coords.first() == coords.last(),
+        });
+    }
+
+    let mut points = vec![];
+    for (i, j, intersection) in segment_intersections(&lines) {
+        let same_part = part_of[i] == part_of[j];
+        let part = &parts[part_of[i]];
+        let neighbours = same_part
+            && (j == i + 1 || (part.closed && i == part.first && j == part.first + part.count - 1));
+        match intersection {
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper,
+            } => {
+                // neighbours always share a vertex, and rings may touch at points
+                if !neighbours && (same_part || is_proper) {
+                    points.push(intersection);
+                }
+            }
+            LineIntersection::Collinear { intersection } => {
+                points.push(intersection.start);
+                points.push(intersection.end);
+            }
+        }
+    }
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn line_strings() {
+        let crossing = line_string![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        assert_eq!(
+            crossing.self_intersections(),
+            vec![Coordinate { x: 2., y: 2. }]
+        );
+        // touching itself at a vertex
+        let touching = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 0.),
+            (x: 2., y: -2.),
+        ];
+        assert_eq!(
+            touching.self_intersections(),
+            vec![Coordinate { x: 2., y: 0. }]
+        );
+        // repeated vertices are fine, and so are the ends of a closed ring
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        assert!(!square.has_self_intersections());
+        let empty = LineString::<f64>(vec![]);
+        assert!(!empty.has_self_intersections());
+    }
+
+    #[test]
+    fn doubling_back() {
+        let spike = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(
+            spike.self_intersections(),
+            vec![Coordinate { x: 2., y: 0. }, Coordinate { x: 4., y: 0. }]
+        );
+        let back_and_forth = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(back_and_forth.has_self_intersections());
+    }
+
+    #[test]
+    fn polygons() {
+        let bow_tie = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        assert_eq!(
+            bow_tie.self_intersections(),
+            vec![Coordinate { x: 2., y: 2. }]
+        );
+        // a hole touching the exterior at a point is allowed, but one crossing it isn't
+        let touching_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.), (x: 0., y: 8.)],
+            interiors: [[(x: 0., y: 4.), (x: 2., y: 2.), (x: 2., y: 6.)]],
+        ];
+        assert!(!touching_hole.has_self_intersections());
+        let crossing_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.), (x: 0., y: 8.)],
+            interiors: [[(x: -1., y: 4.), (x: 2., y: 2.), (x: 2., y: 6.)]],
+        ];
+        let points = crossing_hole.self_intersections();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|point| point.x == 0.));
+
+        // polygons of a multi polygon sharing an edge
+        let left = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let right = polygon![(x: 2., y: 1.), (x: 4., y: 1.), (x: 4., y: 3.), (x: 2., y: 3.)];
+        assert_eq!(
+            MultiPolygon(vec![left, right]).self_intersections(),
+            vec![Coordinate { x: 2., y: 1. }, Coordinate { x: 2., y: 2. }]
+        );
+    }
+}
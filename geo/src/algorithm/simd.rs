@@ -0,0 +1,355 @@
+//! Affine transforms, bounding rectangles, and lengths of contiguous buffers of coordinates, such
+//! as the `Vec` of a `LineString`, using the widest SIMD instructions the CPU supports.
+//!
+//! The `_buffer` variants take a [`CoordBuffer`](../../struct.CoordBuffer.html), whose x values
+//! and y values are in separate `Vec`s, so whole registers of them can be loaded at once.
+//!
+//! Each function is compiled for AVX2 and for the baseline of the target, and which to use is
+//! decided when it's called. Without AVX2 (or on other architectures) the baseline still uses
+//! whatever vector instructions the target guarantees, such as SSE2 on `x86_64`. The coordinates
+//! are processed several at a time, in independent lanes, which is what lets the compiler
+//! vectorize them.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::affine_ops::AffineTransform;
+//! use geo::algorithm::simd;
+//! use geo::{line_string, Rect};
+//!
+//! let mut line_string = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 4.)];
+//! simd::affine_transform(&mut line_string.0, &AffineTransform::identity().scale(2., 1.));
+//!
+//! assert_eq!(simd::euclidean_length(&line_string.0), 10.);
+//! assert_eq!(
+//!     simd::bounding_rect(&line_string.0),
+//!     Some(Rect::new((0., 0.), (6., 4.)))
+//! );
+//!
+//! let buffer = geo::CoordBuffer::from(line_string);
+//! assert_eq!(simd::euclidean_length_buffer(&buffer), 10.);
+//! ```
+use crate::algorithm::affine_ops::AffineTransform;
+use crate::{CoordBuffer, Coordinate, Rect};
+use num_traits::Float;
+
+// The number of independent lanes in the reductions, enough to fill an AVX2 register with `f64`s
+const LANES: usize = 4;
+
+/// Call the AVX2 build of a kernel if the CPU supports it, or the baseline build otherwise
+macro_rules! dispatch {
+    ($kernel:ident, $avx2:ident, $($arg:expr),*) => {{
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safety: the CPU supports AVX2, as was just checked
+                return unsafe { $avx2($($arg),*) };
+            }
+        }
+        $kernel($($arg),*)
+    }};
+}
+
+/// Transform every coordinate in `coords` in place, as
+/// [`AffineOps::affine_transform_mut`](../affine_ops/trait.AffineOps.html#tymethod.affine_transform_mut)
+/// does. The results are exactly the same.
+pub fn affine_transform<T: Float>(coords: &mut [Coordinate<T>], transform: &AffineTransform<T>) {
+    dispatch!(affine_kernel, affine_avx2, coords, transform)
+}
+
+/// Return the smallest `Rect` containing every coordinate in `coords`, or `None` if there are
+/// none, as [`BoundingRect`](../bounding_rect/trait.BoundingRect.html) does.
+pub fn bounding_rect<T: Float>(coords: &[Coordinate<T>]) -> Option<Rect<T>> {
+    dispatch!(bounding_rect_kernel, bounding_rect_avx2, coords)
+}
+
+/// Return the length of the line string through `coords`, as
+/// [`EuclideanLength`](../euclidean_length/trait.EuclideanLength.html) does.
+///
+/// The lengths of the segments are summed in a different order, and without `hypot`'s care for
+/// overflow, so the result may differ from `EuclideanLength`'s in its last bits.
+pub fn euclidean_length<T: Float>(coords: &[Coordinate<T>]) -> T {
+    dispatch!(euclidean_length_kernel, euclidean_length_avx2, coords)
+}
+
+/// Transform every coordinate in `buffer` in place, as
+/// [`affine_transform`](fn.affine_transform.html) does.
+pub fn affine_transform_buffer<T: Float>(
+    buffer: &mut CoordBuffer<T>,
+    transform: &AffineTransform<T>,
+) {
+    let (xs, ys) = buffer.parts_mut();
+    dispatch!(affine_soa_kernel, affine_soa_avx2, xs, ys, transform)
+}
+
+/// Return the smallest `Rect` containing every coordinate in `buffer`, or `None` if there are
+/// none, as [`bounding_rect`](fn.bounding_rect.html) does.
+pub fn bounding_rect_buffer<T: Float>(buffer: &CoordBuffer<T>) -> Option<Rect<T>> {
+    let (xs, ys) = (buffer.xs(), buffer.ys());
+    let (min_x, max_x) = extent(xs)?;
+    let (min_y, max_y) = extent(ys)?;
+    Some(Rect::new(
+        Coordinate { x: min_x, y: min_y },
+        Coordinate { x: max_x, y: max_y },
+    ))
+}
+
+// The least and greatest of some values
+fn extent<T: Float>(values: &[T]) -> Option<(T, T)> {
+    dispatch!(extent_kernel, extent_avx2, values)
+}
+
+/// Return the length of the line string through the coordinates in `buffer`, as
+/// [`euclidean_length`](fn.euclidean_length.html) does.
+pub fn euclidean_length_buffer<T: Float>(buffer: &CoordBuffer<T>) -> T {
+    let (xs, ys) = (buffer.xs(), buffer.ys());
+    dispatch!(
+        euclidean_length_soa_kernel,
+        euclidean_length_soa_avx2,
+        xs,
+        ys
+    )
+}
+
+#[inline(always)]
+fn affine_kernel<T: Float>(coords: &mut [Coordinate<T>], transform: &AffineTransform<T>) {
+    let [a, b, xoff, d, e, yoff] = transform.coefficients();
+    for coord in coords {
+        let (x, y) = (coord.x, coord.y);
+        coord.x = a * x + b * y + xoff;
+        coord.y = d * x + e * y + yoff;
+    }
+}
+
+// Comparisons which ignore NaN, unless it's the first value, as `BoundingRect` does
+#[inline(always)]
+fn min<T: Float>(value: T, min: T) -> T {
+    if value < min {
+        value
+    } else {
+        min
+    }
+}
+
+#[inline(always)]
+fn max<T: Float>(value: T, max: T) -> T {
+    if value > max {
+        value
+    } else {
+        max
+    }
+}
+
+#[inline(always)]
+fn bounding_rect_kernel<T: Float>(coords: &[Coordinate<T>]) -> Option<Rect<T>> {
+    let first = coords.first()?;
+    let (mut min_x, mut min_y) = ([first.x; LANES], [first.y; LANES]);
+    let (mut max_x, mut max_y) = ([first.x; LANES], [first.y; LANES]);
+    let chunks = coords.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for lane in 0..LANES {
+            let coord = chunk[lane];
+            min_x[lane] = min(coord.x, min_x[lane]);
+            min_y[lane] = min(coord.y, min_y[lane]);
+            max_x[lane] = max(coord.x, max_x[lane]);
+            max_y[lane] = max(coord.y, max_y[lane]);
+        }
+    }
+    for (lane, coord) in remainder.iter().enumerate() {
+        min_x[lane] = min(coord.x, min_x[lane]);
+        min_y[lane] = min(coord.y, min_y[lane]);
+        max_x[lane] = max(coord.x, max_x[lane]);
+        max_y[lane] = max(coord.y, max_y[lane]);
+    }
+    let reduce =
+        |lanes: [T; LANES], f: fn(T, T) -> T| lanes[1..].iter().fold(lanes[0], |a, &b| f(b, a));
+    Some(Rect::new(
+        Coordinate {
+            x: reduce(min_x, min),
+            y: reduce(min_y, min),
+        },
+        Coordinate {
+            x: reduce(max_x, max),
+            y: reduce(max_y, max),
+        },
+    ))
+}
+
+#[inline(always)]
+fn euclidean_length_kernel<T: Float>(coords: &[Coordinate<T>]) -> T {
+    let mut lengths = [T::zero(); LANES];
+    let segments = coords.len().saturating_sub(1);
+    let whole = segments - segments % LANES;
+    for start in (0..whole).step_by(LANES) {
+        for lane in 0..LANES {
+            let (a, b) = (coords[start + lane], coords[start + lane + 1]);
+            let (dx, dy) = (b.x - a.x, b.y - a.y);
+            lengths[lane] = lengths[lane] + (dx * dx + dy * dy).sqrt();
+        }
+    }
+    for i in whole..segments {
+        let (a, b) = (coords[i], coords[i + 1]);
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        lengths[0] = lengths[0] + (dx * dx + dy * dy).sqrt();
+    }
+    lengths
+        .iter()
+        .fold(T::zero(), |total, &length| total + length)
+}
+
+#[inline(always)]
+fn affine_soa_kernel<T: Float>(xs: &mut [T], ys: &mut [T], transform: &AffineTransform<T>) {
+    let [a, b, xoff, d, e, yoff] = transform.coefficients();
+    for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+        let (x0, y0) = (*x, *y);
+        *x = a * x0 + b * y0 + xoff;
+        *y = d * x0 + e * y0 + yoff;
+    }
+}
+
+#[inline(always)]
+fn extent_kernel<T: Float>(values: &[T]) -> Option<(T, T)> {
+    // twice as many lanes, as each value is only one of a coordinate's
+    const WIDE: usize = 2 * LANES;
+    let first = *values.first()?;
+    let (mut mins, mut maxs) = ([first; WIDE], [first; WIDE]);
+    let chunks = values.chunks_exact(WIDE);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for ((min_lane, max_lane), &value) in mins.iter_mut().zip(maxs.iter_mut()).zip(chunk) {
+            *min_lane = min(value, *min_lane);
+            *max_lane = max(value, *max_lane);
+        }
+    }
+    for (lane, &value) in remainder.iter().enumerate() {
+        mins[lane] = min(value, mins[lane]);
+        maxs[lane] = max(value, maxs[lane]);
+    }
+    let reduce =
+        |lanes: [T; WIDE], f: fn(T, T) -> T| lanes[1..].iter().fold(lanes[0], |a, &b| f(b, a));
+    Some((reduce(mins, min), reduce(maxs, max)))
+}
+
+#[inline(always)]
+fn euclidean_length_soa_kernel<T: Float>(xs: &[T], ys: &[T]) -> T {
+    let mut lengths = [T::zero(); LANES];
+    let segments = xs.len().saturating_sub(1);
+    let whole = segments - segments % LANES;
+    for start in (0..whole).step_by(LANES) {
+        let (x0, x1) = (&xs[start..start + LANES], &xs[start + 1..start + LANES + 1]);
+        let (y0, y1) = (&ys[start..start + LANES], &ys[start + 1..start + LANES + 1]);
+        for (lane, length) in lengths.iter_mut().enumerate() {
+            let (dx, dy) = (x1[lane] - x0[lane], y1[lane] - y0[lane]);
+            *length = *length + (dx * dx + dy * dy).sqrt();
+        }
+    }
+    for i in whole..segments {
+        let (dx, dy) = (xs[i + 1] - xs[i], ys[i + 1] - ys[i]);
+        lengths[0] = lengths[0] + (dx * dx + dy * dy).sqrt();
+    }
+    lengths
+        .iter()
+        .fold(T::zero(), |total, &length| total + length)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn affine_avx2<T: Float>(coords: &mut [Coordinate<T>], transform: &AffineTransform<T>) {
+    affine_kernel(coords, transform)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn bounding_rect_avx2<T: Float>(coords: &[Coordinate<T>]) -> Option<Rect<T>> {
+    bounding_rect_kernel(coords)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn euclidean_length_avx2<T: Float>(coords: &[Coordinate<T>]) -> T {
+    euclidean_length_kernel(coords)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn affine_soa_avx2<T: Float>(xs: &mut [T], ys: &mut [T], transform: &AffineTransform<T>) {
+    affine_soa_kernel(xs, ys, transform)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn extent_avx2<T: Float>(values: &[T]) -> Option<(T, T)> {
+    extent_kernel(values)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn euclidean_length_soa_avx2<T: Float>(xs: &[T], ys: &[T]) -> T {
+    euclidean_length_soa_kernel(xs, ys)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::affine_ops::AffineOps;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::LineString;
+
+    // a linear congruential generator, for reproducible coordinates
+    fn random_line_string(seed: &mut u64, size: usize) -> LineString<f64> {
+        let mut random = || {
+            *seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (*seed >> 33) as f64 / (1_u64 << 31) as f64 * 200. - 100.
+        };
+        (0..size).map(|_| (random(), random())).collect()
+    }
+
+    #[test]
+    fn matches_scalar_algorithms() {
+        let mut seed = 1;
+        let transform = AffineTransform::identity()
+            .rotate(30.)
+            .scale(2., 0.5)
+            .translate(3., -1.);
+        for size in 0..40 {
+            let line_string = random_line_string(&mut seed, size);
+
+            let mut transformed = line_string.clone();
+            affine_transform(&mut transformed.0, &transform);
+            assert_eq!(transformed, line_string.affine_transform(&transform));
+
+            assert_eq!(bounding_rect(&line_string.0), line_string.bounding_rect());
+
+            assert_relative_eq!(
+                euclidean_length(&line_string.0),
+                line_string.euclidean_length(),
+                max_relative = 1e-12
+            );
+
+            let mut buffer = CoordBuffer::from(&line_string);
+            assert_eq!(bounding_rect_buffer(&buffer), line_string.bounding_rect());
+            assert_eq!(
+                euclidean_length_buffer(&buffer),
+                euclidean_length(&line_string.0)
+            );
+            affine_transform_buffer(&mut buffer, &transform);
+            assert_eq!(LineString::from(buffer), transformed);
+        }
+    }
+
+    #[test]
+    fn f32_and_nan() {
+        let coords: Vec<Coordinate<f32>> = vec![(1., 2.).into(), (f32::NAN, -1.).into()];
+        assert_eq!(bounding_rect(&coords), Some(Rect::new((1., -1.), (1., 2.))));
+        assert_eq!(euclidean_length::<f32>(&[]), 0.);
+        assert_eq!(bounding_rect::<f32>(&[]), None);
+        assert_eq!(
+            bounding_rect_buffer(&CoordBuffer::from(coords)),
+            Some(Rect::new((1., -1.), (1., 2.)))
+        );
+        assert_eq!(bounding_rect_buffer(&CoordBuffer::<f32>::new()), None);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::algorithm::euclidean_distance::EuclideanDistance;
-use crate::{Line, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+use crate::{
+    Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon,
+};
 use num_traits::Float;
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
@@ -21,7 +23,7 @@ where
 {
     compute_rdp(
         &points
-            .into_iter()
+            .iter()
             .enumerate()
             .map(|(idx, point)| RdpIndex {
                 index: idx,
@@ -70,15 +72,106 @@ where
         }
     }
     if dmax > *epsilon {
-        let mut intermediate = compute_rdp(&points[..=index], &*epsilon);
+        let mut intermediate = compute_rdp(&points[..=index], epsilon);
         intermediate.pop();
-        intermediate.extend_from_slice(&compute_rdp(&points[index..], &*epsilon));
+        intermediate.extend_from_slice(&compute_rdp(&points[index..], epsilon));
         intermediate
     } else {
         vec![*points.first().unwrap(), *points.last().unwrap()]
     }
 }
 
+// The greatest tolerance with which the RDP algorithm retains each point: simplifying with a
+// smaller one keeps it. The endpoints are always retained, and points which are never the furthest
+// from a segment, or only at no distance, are never.
+fn rdp_tolerances<T>(points: &[Point<T>]) -> Vec<T>
+where
+    T: Float,
+{
+    let mut tolerances = vec![T::zero(); points.len()];
+    if let Some(last) = points.len().checked_sub(1) {
+        tolerances[0] = T::infinity();
+        tolerances[last] = T::infinity();
+        compute_rdp_tolerances(points, 0, last, T::infinity(), &mut tolerances);
+    }
+    tolerances
+}
+
+// Mirrors `compute_rdp`, recording the tolerance below which each split is made, which is the
+// least distance of the splits leading to it
+fn compute_rdp_tolerances<T>(
+    points: &[Point<T>],
+    start: usize,
+    end: usize,
+    bound: T,
+    tolerances: &mut [T],
+) where
+    T: Float,
+{
+    let mut dmax = T::zero();
+    let mut index: usize = 0;
+    let line = Line::new(points[start], points[end]);
+    for (i, point) in points.iter().enumerate().take(end).skip(start + 1) {
+        let distance = point.euclidean_distance(&line);
+        if distance > dmax {
+            index = i;
+            dmax = distance;
+        }
+    }
+    if dmax > T::zero() {
+        let tolerance = dmax.min(bound);
+        tolerances[index] = tolerance;
+        compute_rdp_tolerances(points, start, index, tolerance, tolerances);
+        compute_rdp_tolerances(points, index, end, tolerance, tolerances);
+    }
+}
+
+// Simplify line strings together, with the smallest tolerance that leaves at most `max_coords`
+// coordinates between them
+fn rdp_to_count<T>(line_strings: &[&LineString<T>], max_coords: usize) -> Vec<LineString<T>>
+where
+    T: Float,
+{
+    let tolerances: Vec<Vec<T>> = line_strings
+        .iter()
+        .map(|line_string| rdp_tolerances(&line_string.points_iter().collect::<Vec<_>>()))
+        .collect();
+    let mut candidates: Vec<T> = tolerances
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|tolerance| tolerance.is_finite() && *tolerance > T::zero())
+        .collect();
+    let fixed = tolerances
+        .iter()
+        .flatten()
+        .filter(|t| t.is_infinite())
+        .count();
+    let available = max_coords.saturating_sub(fixed);
+    // a tolerance keeps the points with greater ones, so the smallest which keeps at most the
+    // available number is the one after them
+    let epsilon = if candidates.len() <= available {
+        T::zero()
+    } else {
+        candidates.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        candidates[available]
+    };
+    line_strings
+        .iter()
+        .zip(tolerances)
+        .map(|(line_string, tolerances)| {
+            line_string
+                .0
+                .iter()
+                .zip(tolerances)
+                .filter(|(_, tolerance)| *tolerance > epsilon)
+                .map(|(coord, _)| *coord)
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .collect()
+}
+
 /// Simplifies a geometry.
 ///
 /// The [Ramer–Douglas–Peucker
@@ -86,7 +179,8 @@ where
 /// linestring. Polygons are simplified by running the RDP algorithm on all their constituent
 /// rings. This may result in invalid Polygons, and has no guarantee of preserving topology.
 ///
-/// Multi* objects are simplified by simplifing all their constituent geometries individually.
+/// Multi* objects and `GeometryCollection`s are simplified by simplifing all their constituent
+/// geometries individually.
 pub trait Simplify<T, Epsilon = T> {
     /// Returns the simplified representation of a geometry, using the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm
     ///
@@ -150,6 +244,47 @@ pub trait SimplifyIdx<T, Epsilon = T> {
         T: Float;
 }
 
+/// Simplifies a geometry to at most a given number of coordinates, for when the size of the
+/// result is limited rather than its deviation from the original.
+///
+/// This uses the [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm),
+/// relaxing the tolerance as little as needed: the result is that of
+/// [`simplify`](trait.Simplify.html#tymethod.simplify) with the smallest `epsilon` that leaves at
+/// most `max_coords` coordinates. The budget is shared between all the rings of a Polygon and the
+/// members of a Multi* object, which are simplified with the same `epsilon`.
+///
+/// The first and last coordinates of each `LineString` and ring are always retained, so the
+/// result exceeds a budget smaller than twice their number. Like `simplify`, this may result in
+/// invalid Polygons.
+pub trait SimplifyToCount<T> {
+    /// Returns the simplified representation of a geometry, with at most `max_coords`
+    /// coordinates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::simplify::{Simplify, SimplifyToCount};
+    /// use geo::line_string;
+    ///
+    /// let linestring = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 4.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 17.3, y: 3.2),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    /// let simplified = linestring.simplify_to_count(4);
+    /// assert_eq!(
+    ///     simplified,
+    ///     line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 4.0), (x: 11.0, y: 5.5), (x: 27.8, y: 0.1)]
+    /// );
+    /// assert_eq!(simplified, linestring.simplify(&1.0));
+    /// ```
+    fn simplify_to_count(&self, max_coords: usize) -> Self
+    where
+        T: Float;
+}
+
 impl<T> Simplify<T> for LineString<T>
 where
     T: Float,
@@ -208,6 +343,88 @@ where
     }
 }
 
+impl<T> Simplify<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        GeometryCollection(self.0.iter().map(|g| g.simplify(epsilon)).collect())
+    }
+}
+
+impl<T> Simplify<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        match self {
+            Geometry::LineString(g) => Geometry::LineString(g.simplify(epsilon)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.simplify(epsilon)),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplify(epsilon)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplify(epsilon)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.simplify(epsilon)),
+            // there's nothing to remove from the others
+            g => g.clone(),
+        }
+    }
+}
+
+impl<T> SimplifyToCount<T> for LineString<T>
+where
+    T: Float,
+{
+    fn simplify_to_count(&self, max_coords: usize) -> Self {
+        rdp_to_count(&[self], max_coords).remove(0)
+    }
+}
+
+impl<T> SimplifyToCount<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    fn simplify_to_count(&self, max_coords: usize) -> Self {
+        MultiLineString(rdp_to_count(&self.0.iter().collect::<Vec<_>>(), max_coords))
+    }
+}
+
+impl<T> SimplifyToCount<T> for Polygon<T>
+where
+    T: Float,
+{
+    fn simplify_to_count(&self, max_coords: usize) -> Self {
+        let rings: Vec<_> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        let mut rings = rdp_to_count(&rings, max_coords);
+        let exterior = rings.remove(0);
+        Polygon::new(exterior, rings)
+    }
+}
+
+impl<T> SimplifyToCount<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    fn simplify_to_count(&self, max_coords: usize) -> Self {
+        let rings: Vec<_> = self
+            .0
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        let mut rings = rdp_to_count(&rings, max_coords).into_iter();
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| {
+                    let exterior = rings.next().unwrap();
+                    let interiors = rings.by_ref().take(polygon.interiors().len()).collect();
+                    Polygon::new(exterior, interiors)
+                })
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -215,17 +432,19 @@ mod test {
 
     #[test]
     fn rdp_test() {
-        let mut vec = Vec::new();
-        vec.push(Point::new(0.0, 0.0));
-        vec.push(Point::new(5.0, 4.0));
-        vec.push(Point::new(11.0, 5.5));
-        vec.push(Point::new(17.3, 3.2));
-        vec.push(Point::new(27.8, 0.1));
-        let mut compare = Vec::new();
-        compare.push(Point::new(0.0, 0.0));
-        compare.push(Point::new(5.0, 4.0));
-        compare.push(Point::new(11.0, 5.5));
-        compare.push(Point::new(27.8, 0.1));
+        let vec = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 4.0),
+            Point::new(11.0, 5.5),
+            Point::new(17.3, 3.2),
+            Point::new(27.8, 0.1),
+        ];
+        let compare = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 4.0),
+            Point::new(11.0, 5.5),
+            Point::new(27.8, 0.1),
+        ];
         let simplified = rdp(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
@@ -238,12 +457,8 @@ mod test {
     }
     #[test]
     fn rdp_test_two_point_linestring() {
-        let mut vec = Vec::new();
-        vec.push(Point::new(0.0, 0.0));
-        vec.push(Point::new(27.8, 0.1));
-        let mut compare = Vec::new();
-        compare.push(Point::new(0.0, 0.0));
-        compare.push(Point::new(27.8, 0.1));
+        let vec = vec![Point::new(0.0, 0.0), Point::new(27.8, 0.1)];
+        let compare = vec![Point::new(0.0, 0.0), Point::new(27.8, 0.1)];
         let simplified = rdp(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
@@ -320,4 +535,91 @@ mod test {
             ]]),
         );
     }
+
+    #[test]
+    fn geometry_collection() {
+        let ls = LineString::from(vec![
+            (0.0, 0.0),
+            (5.0, 4.0),
+            (11.0, 5.5),
+            (17.3, 3.2),
+            (27.8, 0.1),
+        ]);
+        let simplified = LineString::from(vec![(0.0, 0.0), (5.0, 4.0), (11.0, 5.5), (27.8, 0.1)]);
+        let point = Point::new(1.0, 2.0);
+        let gc = GeometryCollection(vec![ls.into(), point.into()]);
+        assert_eq!(
+            gc.simplify(&1.0),
+            GeometryCollection(vec![simplified.into(), point.into()])
+        );
+    }
+
+    #[test]
+    fn to_count() {
+        let points = include!("test_fixtures/vw_orig.rs");
+        let ls: LineString<f64> = points.iter().map(|e| Point::new(e[0], e[1])).collect();
+        let mut previous = 0;
+        for max_coords in (2..ls.0.len() + 10).step_by(7) {
+            let simplified = ls.simplify_to_count(max_coords);
+            assert!(simplified.0.len() <= max_coords);
+            assert!(simplified.0.len() >= previous);
+            previous = simplified.0.len();
+            let (first, last) = (ls.0[0], *ls.0.last().unwrap());
+            assert_eq!(simplified.0[0], first);
+            assert_eq!(*simplified.0.last().unwrap(), last);
+        }
+        assert_eq!(ls.simplify_to_count(ls.0.len()), ls.simplify(&0.0));
+        assert_eq!(ls.simplify_to_count(0).0.len(), 2);
+    }
+
+    #[test]
+    fn to_count_matches_tolerance() {
+        let points = include!("test_fixtures/vw_orig.rs");
+        let ls: LineString<f64> = points.iter().map(|e| Point::new(e[0], e[1])).collect();
+        let tolerances = rdp_tolerances(&ls.clone().into_points());
+        for epsilon in [0.0001, 0.0005, 0.001, 0.01].iter() {
+            let simplified = ls.simplify(epsilon);
+            assert_eq!(ls.simplify_to_count(simplified.0.len()), simplified);
+            // the tolerances say which points `simplify` keeps
+            let kept = tolerances.iter().filter(|t| *t > epsilon).count();
+            assert_eq!(kept, simplified.0.len());
+        }
+    }
+
+    #[test]
+    fn polygon_to_count() {
+        let poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 5., y: 0.5),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 9.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [[
+                (x: 2., y: 2.),
+                (x: 5., y: 3.),
+                (x: 8., y: 2.),
+                (x: 8., y: 8.),
+                (x: 2., y: 8.),
+                (x: 2., y: 2.),
+            ]],
+        ];
+        // the budget leaves room for the corners, but not for the points just off the edges
+        let simplified = poly.simplify_to_count(11);
+        assert_eq!(
+            simplified,
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+                interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.), (x: 2., y: 2.)]],
+            ]
+        );
+        assert_eq!(simplified, poly.simplify(&1.0));
+        let mpoly = MultiPolygon(vec![poly.clone(), poly]);
+        let simplified = mpoly.simplify_to_count(20);
+        assert_eq!(simplified.0[0], simplified.0[1]);
+        assert_eq!(simplified.0[0].exterior().0.len(), 5);
+    }
 }
@@ -1,6 +1,8 @@
+use crate::algorithm::predicates::{orient2d, Orientation};
 use crate::prelude::*;
 use crate::{
-    Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Triangle,
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPolygon,
+    Point, Polygon, Triangle,
 };
 use num_traits::Float;
 use std::cmp::Ordering;
@@ -11,7 +13,7 @@ use rstar::{RTree, RTreeNum};
 /// Store triangle information
 // current is the candidate point for removal
 #[derive(Debug)]
-struct VScore<T, I>
+struct VScore<T>
 where
     T: Float,
 {
@@ -19,36 +21,34 @@ where
     current: usize,
     right: usize,
     area: T,
-    // `visvalingam_preserve` uses `intersector`, `visvalingam` does not
-    intersector: I,
 }
 
 // These impls give us a min-heap
-impl<T, I> Ord for VScore<T, I>
+impl<T> Ord for VScore<T>
 where
     T: Float,
 {
-    fn cmp(&self, other: &VScore<T, I>) -> Ordering {
+    fn cmp(&self, other: &VScore<T>) -> Ordering {
         other.area.partial_cmp(&self.area).unwrap()
     }
 }
 
-impl<T, I> PartialOrd for VScore<T, I>
+impl<T> PartialOrd for VScore<T>
 where
     T: Float,
 {
-    fn partial_cmp(&self, other: &VScore<T, I>) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &VScore<T>) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T, I> Eq for VScore<T, I> where T: Float {}
+impl<T> Eq for VScore<T> where T: Float {}
 
-impl<T, I> PartialEq for VScore<T, I>
+impl<T> PartialEq for VScore<T>
 where
     T: Float,
 {
-    fn eq(&self, other: &VScore<T, I>) -> bool
+    fn eq(&self, other: &VScore<T>) -> bool
     where
         T: Float,
     {
@@ -65,14 +65,10 @@ enum GeomType {
 
 /// Settings for Ring and Line geometries
 // initial min: if we ever have fewer than these, stop immediately
-// min_points: if we detect a self-intersection before point removal, and we only
-// have min_points left, stop: since a self-intersection causes removal of the spatially previous
-// point, THAT could lead to a further self-intersection without the possibility of removing
-// more points, potentially leaving the geometry in an invalid state.
+// geomtype: rings mustn't be reduced to a triangle without area
 #[derive(Debug, Clone, Copy)]
 struct GeomSettings {
     initial_min: usize,
-    min_points: usize,
     geomtype: GeomType,
 }
 
@@ -126,13 +122,12 @@ where
         .triangles()
         .enumerate()
         .map(|(i, triangle)| VScore {
-            area: triangle.area().abs(),
+            area: triangle.unsigned_area(),
             current: i + 1,
             left: i,
             right: i + 2,
-            intersector: (),
         })
-        .collect::<BinaryHeap<VScore<T, ()>>>();
+        .collect::<BinaryHeap<VScore<T>>>();
     // While there are still points for which the associated triangle
     // has an area below the epsilon
     while let Some(smallest) = pq.pop() {
@@ -144,7 +139,7 @@ where
         let (left, right) = adjacent[smallest.current];
         // A point in this triangle has been removed since this VScore
         // was created, so skip it
-        if left as i32 != smallest.left as i32 || right as i32 != smallest.right as i32 {
+        if left != smallest.left as i32 || right != smallest.right as i32 {
             continue;
         }
         // We've got a valid triangle, and its area is smaller than epsilon, so
@@ -153,7 +148,7 @@ where
         let (_, rr) = adjacent[right as usize];
         adjacent[left as usize] = (ll, right);
         adjacent[right as usize] = (left, rr);
-        adjacent[smallest.current as usize] = (0, 0);
+        adjacent[smallest.current] = (0, 0);
 
         // Now recompute the adjacent triangle(s), using left and right adjacent points
         let choices = [(ll, left, right), (left, right, rr)];
@@ -167,14 +162,13 @@ where
                 orig.0[current_point as usize],
                 orig.0[bi as usize],
             )
-            .area()
+            .signed_area()
             .abs();
             pq.push(VScore {
                 area,
                 current: current_point as usize,
                 left: ai as usize,
                 right: bi as usize,
-                intersector: (),
             });
         }
     }
@@ -229,13 +223,11 @@ where
     );
 
     // Simplify shell
-    rings.push(visvalingam_preserve(
-        geomtype, &exterior, epsilon, &mut tree,
-    ));
+    rings.push(visvalingam_preserve(geomtype, exterior, epsilon, &mut tree));
     // Simplify interior rings, if any
     if let Some(interior_rings) = interiors {
         for ring in interior_rings {
-            rings.push(visvalingam_preserve(geomtype, &ring, epsilon, &mut tree))
+            rings.push(visvalingam_preserve(geomtype, ring, epsilon, &mut tree))
         }
     }
     rings
@@ -255,12 +247,11 @@ where
     if orig.0.len() < 3 {
         return orig.0.to_vec();
     }
-    let max = orig.0.len();
     let mut counter = orig.0.len();
 
     // Adjacent retained points. Simulating the points in a
     // linked list with indices into `orig`. Big number (larger than or equal to
-    // `max`) means no next element, and (0, 0) means deleted element.
+    // the number of points) means no next element, and (0, 0) means deleted element.
     let mut adjacent: Vec<_> = (0..orig.0.len())
         .map(|i| {
             if i == 0 {
@@ -281,17 +272,16 @@ where
         .triangles()
         .enumerate()
         .map(|(i, triangle)| VScore {
-            area: triangle.area().abs(),
+            area: triangle.unsigned_area(),
             current: i + 1,
             left: i,
             right: i + 2,
-            intersector: false,
         })
-        .collect::<BinaryHeap<VScore<T, bool>>>();
+        .collect::<BinaryHeap<VScore<T>>>();
 
     // While there are still points for which the associated triangle
     // has an area below the epsilon
-    while let Some(mut smallest) = pq.pop() {
+    while let Some(smallest) = pq.pop() {
         if smallest.area > *epsilon {
             continue;
         }
@@ -302,68 +292,26 @@ where
         let (left, right) = adjacent[smallest.current];
         // A point in this triangle has been removed since this VScore
         // was created, so skip it
-        if left as i32 != smallest.left as i32 || right as i32 != smallest.right as i32 {
+        if left != smallest.left as i32 || right != smallest.right as i32 {
             continue;
         }
-        // if removal of this point causes a self-intersection, we also remove the previous point
-        // that removal alters the geometry, removing the self-intersection
-        // HOWEVER if we're within 2 points of the absolute minimum, we can't remove this point or the next
-        // because we could then no longer form a valid geometry if removal of next also caused an intersection.
-        // The simplification process is thus over.
-        smallest.intersector = tree_intersect(tree, &smallest, &orig.0);
-        if smallest.intersector && counter <= geomtype.min_points {
-            break;
+        let chain = [left as usize, smallest.current, right as usize];
+        if removable(geomtype, &chain, counter, &orig.0, &adjacent, tree) {
+            unlink(smallest.current, &orig.0, &mut adjacent, tree, &mut pq);
+            counter -= 1;
+            continue;
         }
-        // We've got a valid triangle, and its area is smaller than epsilon, so
-        // remove it from the simulated "linked list"
-        adjacent[smallest.current as usize] = (0, 0);
-        counter -= 1;
-        // Remove stale segments from R* tree
-        let left_point = Point(orig.0[left as usize]);
-        let middle_point = Point(orig.0[smallest.current]);
-        let right_point = Point(orig.0[right as usize]);
-
-        let line_1 = Line::new(left_point, middle_point);
-        let line_2 = Line::new(middle_point, right_point);
-        assert!(tree.remove(&line_1).is_some());
-        assert!(tree.remove(&line_2).is_some());
-
-        // Restore continous line segment
-        tree.insert(Line::new(left_point, right_point));
-
-        // Now recompute the adjacent triangle(s), using left and right adjacent points
+        // if removal of this point causes a self-intersection, removing the previous point as
+        // well may avoid it, and alters the geometry less than keeping both
         let (ll, _) = adjacent[left as usize];
-        let (_, rr) = adjacent[right as usize];
-        adjacent[left as usize] = (ll, right);
-        adjacent[right as usize] = (left, rr);
-        let choices = [(ll, left, right), (left, right, rr)];
-        for &(ai, current_point, bi) in &choices {
-            if ai as usize >= max || bi as usize >= max {
-                // Out of bounds, i.e. we're on one edge
-                continue;
-            }
-            let new = Triangle(
-                orig.0[ai as usize],
-                orig.0[current_point as usize],
-                orig.0[bi as usize],
-            );
-            // The current point causes a self-intersection, and this point precedes it
-            // we ensure it gets removed next by demoting its area to negative epsilon
-            let temp_area = if smallest.intersector && (current_point as usize) < smallest.current {
-                -*epsilon
-            } else {
-                new.area().abs()
-            };
-            let new_triangle = VScore {
-                area: temp_area,
-                current: current_point as usize,
-                left: ai as usize,
-                right: bi as usize,
-                intersector: false,
-            };
-
-            // push re-computed triangle onto heap
-            pq.push(new_triangle);
+        if ll < 0 || counter < geomtype.initial_min + 2 {
+            continue;
+        }
+        let chain = [ll as usize, left as usize, smallest.current, right as usize];
+        if removable(geomtype, &chain, counter, &orig.0, &adjacent, tree) {
+            unlink(smallest.current, &orig.0, &mut adjacent, tree, &mut pq);
+            unlink(left as usize, &orig.0, &mut adjacent, tree, &mut pq);
+            counter -= 2;
         }
     }
     // Filter out the points that have been deleted, returning remaining points
@@ -374,12 +322,131 @@ where
         .collect()
 }
 
+/// Remove a point from the simulated linked list and the R* tree, recomputing the triangles of
+/// its neighbours
+fn unlink<T>(
+    current: usize,
+    orig: &[Coordinate<T>],
+    adjacent: &mut [(i32, i32)],
+    tree: &mut RTree<Line<T>>,
+    pq: &mut BinaryHeap<VScore<T>>,
+) where
+    T: Float + RTreeNum,
+{
+    let max = orig.len();
+    let (left, right) = adjacent[current];
+    adjacent[current] = (0, 0);
+    // Remove stale segments from R* tree
+    let left_point = Point(orig[left as usize]);
+    let middle_point = Point(orig[current]);
+    let right_point = Point(orig[right as usize]);
+
+    let line_1 = Line::new(left_point, middle_point);
+    let line_2 = Line::new(middle_point, right_point);
+    assert!(tree.remove(&line_1).is_some());
+    assert!(tree.remove(&line_2).is_some());
+
+    // Restore continous line segment
+    tree.insert(Line::new(left_point, right_point));
+
+    // Now recompute the adjacent triangle(s), using left and right adjacent points
+    let (ll, _) = adjacent[left as usize];
+    let (_, rr) = adjacent[right as usize];
+    adjacent[left as usize] = (ll, right);
+    adjacent[right as usize] = (left, rr);
+    let choices = [(ll, left, right), (left, right, rr)];
+    for &(ai, current_point, bi) in &choices {
+        if ai as usize >= max || bi as usize >= max {
+            // Out of bounds, i.e. we're on one edge
+            continue;
+        }
+        let new = Triangle(
+            orig[ai as usize],
+            orig[current_point as usize],
+            orig[bi as usize],
+        );
+        // push re-computed triangle onto heap
+        pq.push(VScore {
+            area: new.unsigned_area(),
+            current: current_point as usize,
+            left: ai as usize,
+            right: bi as usize,
+        });
+    }
+}
+
+/// Whether the points between the ends of `chain`, a run of retained points, can be removed
+/// without the new segment joining its ends crossing or touching another segment, or sweeping
+/// over one, which would put it on the other side, such as an interior ring outside its shell
+fn removable<T>(
+    geomtype: &GeomSettings,
+    chain: &[usize],
+    counter: usize,
+    orig: &[Coordinate<T>],
+    adjacent: &[(i32, i32)],
+    tree: &RTree<Line<T>>,
+) -> bool
+where
+    T: Float + RTreeNum,
+{
+    let start = orig[chain[0]];
+    let end = orig[chain[chain.len() - 1]];
+    let replaced: Vec<Line<T>> = chain
+        .windows(2)
+        .map(|pair| Line::new(orig[pair[0]], orig[pair[1]]))
+        .collect();
+    // the area swept by the removal
+    let swept = Polygon::new(
+        chain.iter().map(|i| orig[*i]).collect::<Vec<_>>().into(),
+        vec![],
+    );
+    let bounding_rect = swept.exterior().bounding_rect().unwrap();
+    let br = Point::new(bounding_rect.min().x, bounding_rect.min().y);
+    let tl = Point::new(bounding_rect.max().x, bounding_rect.max().y);
+    let inside = |c: Coordinate<T>| c != start && c != end && swept.intersects(&Point(c));
+    let blocked = tree
+        .locate_in_envelope_intersecting(&rstar::AABB::from_corners(br, tl))
+        .filter(|line| !replaced.contains(line))
+        .any(|line| {
+            let shares_end = [line.start, line.end]
+                .iter()
+                .any(|c| *c == start || *c == end);
+            inside(line.start)
+                || inside(line.end)
+                || (!shares_end
+                    && cartesian_intersect(
+                        line.start_point(),
+                        line.end_point(),
+                        Point(start),
+                        Point(end),
+                    ))
+        });
+    if blocked {
+        return false;
+    }
+    if let GeomType::Ring = geomtype.geomtype {
+        // a ring reduced to a triangle mustn't be left without area
+        if counter - (chain.len() - 2) == geomtype.initial_min {
+            let mut remaining = vec![];
+            let mut index = 0;
+            while remaining.len() < 3 {
+                if !chain[1..chain.len() - 1].contains(&index) {
+                    remaining.push(orig[index]);
+                }
+                index = adjacent[index].1 as usize;
+            }
+            return orient2d(remaining[0], remaining[1], remaining[2]) != Orientation::Collinear;
+        }
+    }
+    true
+}
+
 /// is p1 -> p2 -> p3 wound counterclockwise?
 fn ccw<T>(p1: Point<T>, p2: Point<T>, p3: Point<T>) -> bool
 where
     T: Float,
 {
-    (p3.y() - p1.y()) * (p2.x() - p1.x()) > (p2.y() - p1.y()) * (p3.x() - p1.x())
+    orient2d(p1.0, p2.0, p3.0) == Orientation::CounterClockwise
 }
 
 /// checks whether line segments with p1-p4 as their start and endpoints touch or cross
@@ -390,42 +457,12 @@ where
     (ccw(p1, p3, p4) ^ ccw(p2, p3, p4)) & (ccw(p1, p2, p3) ^ ccw(p1, p2, p4))
 }
 
-/// check whether a triangle's edges intersect with any other edges of the LineString
-fn tree_intersect<T>(
-    tree: &RTree<Line<T>>,
-    triangle: &VScore<T, bool>,
-    orig: &[Coordinate<T>],
-) -> bool
-where
-    T: Float + RTreeNum,
-{
-    let point_a = orig[triangle.left];
-    let point_c = orig[triangle.right];
-    let bounding_rect = Triangle(
-        orig[triangle.left],
-        orig[triangle.current],
-        orig[triangle.right],
-    )
-    .bounding_rect();
-    let br = Point::new(bounding_rect.min().x, bounding_rect.min().y);
-    let tl = Point::new(bounding_rect.max().x, bounding_rect.max().y);
-    tree.locate_in_envelope_intersecting(&rstar::AABB::from_corners(br, tl))
-        .any(|c| {
-            // triangle start point, end point
-            let (ca, cb) = c.points();
-            ca.0 != point_a
-                && ca.0 != point_c
-                && cb.0 != point_a
-                && cb.0 != point_c
-                && cartesian_intersect(ca, cb, Point(point_a), Point(point_c))
-        })
-}
-
 /// Simplifies a geometry.
 ///
 /// Polygons are simplified by running the algorithm on all their constituent rings.  This may
-/// result in invalid Polygons, and has no guarantee of preserving topology. Multi* objects are
-/// simplified by simplifying all their constituent geometries individually.
+/// result in invalid Polygons, and has no guarantee of preserving topology. Multi* objects and
+/// `GeometryCollection`s are simplified by simplifying all their constituent geometries
+/// individually.
 pub trait SimplifyVW<T, Epsilon = T> {
     /// Returns the simplified representation of a geometry, using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
     ///
@@ -491,7 +528,11 @@ pub trait SimplifyVwIdx<T, Epsilon = T> {
         T: Float;
 }
 
-/// Simplifies a geometry, preserving its topology by removing self-intersections
+/// Simplifies a geometry, preserving its topology by never introducing self-intersections
+///
+/// Multi* objects and `GeometryCollection`s are simplified by simplifying all their constituent
+/// geometries individually, so while each of them stays valid, they may come to intersect each
+/// other.
 pub trait SimplifyVWPreserve<T, Epsilon = T> {
     /// Returns the simplified representation of a geometry, using a topology-preserving variant of the
     /// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm.
@@ -500,20 +541,21 @@ pub trait SimplifyVWPreserve<T, Epsilon = T> {
     ///
     /// The topology-preserving algorithm uses an [R* tree](../../../rstar/struct.RTree.html) to
     /// efficiently find candidate line segments which are tested for intersection with a given triangle.
-    /// If intersections are found, the previous point (i.e. the left component of the current triangle)
-    /// is also removed, altering the geometry and removing the intersection.
+    /// A point is only removed if the segment replacing it doesn't cross or touch any other
+    /// segment of the geometry, including those of a Polygon's other rings, and the triangle
+    /// it cuts off contains none of their points, so an interior ring can't be displaced outside
+    /// its shell. If it would, removing the previous point (i.e. the left component of the current
+    /// triangle) as well is tried, removing the intersection, and otherwise the point is retained.
+    /// The orientations are computed with robust predicates, so the result never intersects
+    /// itself, and its rings never intersect each other, unless the input did.
     ///
     /// In the example below, `(135.0, 68.0)` would be retained by the standard algorithm,
     /// forming triangle `(0, 1, 3),` which intersects with the segments `(280.0, 19.0),
     /// (117.0, 48.0)` and `(117.0, 48.0), (300,0, 40.0)`. By removing it,
     /// a new triangle with indices `(0, 3, 4)` is formed, which does not cause a self-intersection.
     ///
-    /// **Note**: it is possible for the simplification algorithm to displace a Polygon's interior ring outside its shell.
-    ///
-    /// **Note**: if removal of a point causes a self-intersection, but the geometry only has `n + 2`
-    /// points remaining (4 for a `LineString`, 6 for a `Polygon`), the point is retained and the
-    /// simplification process ends. This is because there is no guarantee that removal of two points will remove
-    /// the intersection, but removal of further points would leave too few points to form a valid geometry.
+    /// **Note**: Polygons' rings are never reduced to fewer than four points (a triangle), or to a
+    /// triangle without area, and `LineString`s to fewer than two.
     ///
     /// # Examples
     ///
@@ -554,7 +596,6 @@ where
     fn simplifyvw_preserve(&self, epsilon: &T) -> LineString<T> {
         let gt = GeomSettings {
             initial_min: 2,
-            min_points: 4,
             geomtype: GeomType::Line,
         };
         let mut simplified = vwp_wrapper(&gt, self, None, epsilon);
@@ -583,7 +624,6 @@ where
     fn simplifyvw_preserve(&self, epsilon: &T) -> Polygon<T> {
         let gt = GeomSettings {
             initial_min: 4,
-            min_points: 6,
             geomtype: GeomType::Ring,
         };
         let mut simplified = vwp_wrapper(&gt, self.exterior(), Some(self.interiors()), epsilon);
@@ -607,6 +647,41 @@ where
     }
 }
 
+impl<T> SimplifyVWPreserve<T> for GeometryCollection<T>
+where
+    T: Float + RTreeNum,
+{
+    fn simplifyvw_preserve(&self, epsilon: &T) -> GeometryCollection<T> {
+        GeometryCollection(
+            self.0
+                .iter()
+                .map(|g| g.simplifyvw_preserve(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyVWPreserve<T> for Geometry<T>
+where
+    T: Float + RTreeNum,
+{
+    fn simplifyvw_preserve(&self, epsilon: &T) -> Geometry<T> {
+        match self {
+            Geometry::LineString(g) => Geometry::LineString(g.simplifyvw_preserve(epsilon)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.simplifyvw_preserve(epsilon)),
+            Geometry::MultiLineString(g) => {
+                Geometry::MultiLineString(g.simplifyvw_preserve(epsilon))
+            }
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplifyvw_preserve(epsilon)),
+            Geometry::GeometryCollection(g) => {
+                Geometry::GeometryCollection(g.simplifyvw_preserve(epsilon))
+            }
+            // there's nothing to remove from the others
+            g => g.clone(),
+        }
+    }
+}
+
 impl<T> SimplifyVW<T> for LineString<T>
 where
     T: Float,
@@ -658,15 +733,43 @@ where
     }
 }
 
+impl<T> SimplifyVW<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    fn simplifyvw(&self, epsilon: &T) -> GeometryCollection<T> {
+        GeometryCollection(self.0.iter().map(|g| g.simplifyvw(epsilon)).collect())
+    }
+}
+
+impl<T> SimplifyVW<T> for Geometry<T>
+where
+    T: Float,
+{
+    fn simplifyvw(&self, epsilon: &T) -> Geometry<T> {
+        match self {
+            Geometry::LineString(g) => Geometry::LineString(g.simplifyvw(epsilon)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.simplifyvw(epsilon)),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplifyvw(epsilon)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplifyvw(epsilon)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.simplifyvw(epsilon)),
+            // there's nothing to remove from the others
+            g => g.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod test {
     use super::{
         cartesian_intersect, visvalingam, vwp_wrapper, GeomSettings, GeomType, SimplifyVW,
         SimplifyVWPreserve,
     };
+    use crate::algorithm::validation::Validation;
     use crate::{
-        line_string, point, polygon, Coordinate, LineString, MultiLineString, MultiPolygon, Point,
-        Polygon,
+        line_string, point, polygon, Coordinate, GeometryCollection, LineString, MultiLineString,
+        MultiPolygon, Point, Polygon,
     };
 
     #[test]
@@ -680,7 +783,7 @@ mod test {
             (x: 10.0, y: 10.0)
         ];
 
-        let correct = vec![(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)];
+        let correct = [(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)];
         let correct_ls: Vec<_> = correct
             .iter()
             .map(|e| Coordinate::from((e.0, e.1)))
@@ -697,13 +800,13 @@ mod test {
         let c = point!(x: 3., y: 3.);
         let d = point!(x: 1., y: 1.);
         // cw + ccw
-        assert_eq!(cartesian_intersect(a, b, c, d), true);
+        assert!(cartesian_intersect(a, b, c, d));
         // ccw + ccw
-        assert_eq!(cartesian_intersect(b, a, c, d), true);
+        assert!(cartesian_intersect(b, a, c, d));
         // cw + cw
-        assert_eq!(cartesian_intersect(a, b, d, c), true);
+        assert!(cartesian_intersect(a, b, d, c));
         // ccw + cw
-        assert_eq!(cartesian_intersect(b, a, d, c), true);
+        assert!(cartesian_intersect(b, a, d, c));
     }
     #[test]
     fn simple_vwp_test() {
@@ -725,12 +828,11 @@ mod test {
         ];
         let gt = &GeomSettings {
             initial_min: 2,
-            min_points: 4,
             geomtype: GeomType::Line,
         };
-        let simplified = vwp_wrapper(&gt, &ls, None, &668.6);
+        let simplified = vwp_wrapper(gt, &ls, None, &668.6);
         // this is the correct, non-intersecting LineString
-        let correct = vec![
+        let correct = [
             (10., 60.),
             (126., 31.),
             (280., 19.),
@@ -801,16 +903,69 @@ mod test {
         assert_eq!(simplified.interiors()[0], correct_inner);
     }
     #[test]
+    fn interior_stays_inside_vwp_test() {
+        // removing the tip of the spike would leave the interior ring, which is inside it,
+        // outside the shell
+        let outer = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5., y: 14.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.)
+        ];
+        let inner = line_string![
+            (x: 4.9, y: 12.),
+            (x: 5.1, y: 12.),
+            (x: 5., y: 12.5),
+            (x: 4.9, y: 12.)
+        ];
+        let poly = Polygon::new(outer.clone(), vec![inner]);
+        assert_eq!(poly.simplifyvw(&22.).exterior().0.len(), 5);
+        let simplified = poly.simplifyvw_preserve(&22.);
+        assert_eq!(simplified.exterior(), &outer);
+        assert!(simplified.is_valid());
+    }
+    #[test]
+    fn robust_intersection_vwp_test() {
+        // removing (5, 1) would join (0, 0) and (10, 0) with a segment through (5, 0), which
+        // touches the line where it turns back
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 1.),
+            (x: 10., y: 0.),
+            (x: 10., y: -10.),
+            (x: 5., y: 0.),
+            (x: 5., y: -20.)
+        ];
+        assert_eq!(ls.simplifyvw(&10.).0.len(), 5);
+        assert_eq!(ls.simplifyvw_preserve(&10.), ls);
+    }
+    #[test]
+    fn geometry_collection() {
+        let ls = line_string![
+            (x: 5.0, y: 2.0),
+            (x: 3.0, y: 8.0),
+            (x: 6.0, y: 20.0),
+            (x: 7.0, y: 25.0),
+            (x: 10.0, y: 10.0)
+        ];
+        let simplified = line_string![(x: 5.0, y: 2.0), (x: 7.0, y: 25.0), (x: 10.0, y: 10.0)];
+        let gc = GeometryCollection(vec![ls.into(), point!(x: 1., y: 2.).into()]);
+        let correct = GeometryCollection(vec![simplified.into(), point!(x: 1., y: 2.).into()]);
+        assert_eq!(gc.simplifyvw(&30.), correct);
+        assert_eq!(gc.simplifyvw_preserve(&30.), correct);
+    }
+    #[test]
     fn very_long_vwp_test() {
         // simplify an 8k-point LineString, eliminating self-intersections
         let points = include!("test_fixtures/norway_main.rs");
         let points_ls: Vec<_> = points.iter().map(|e| Point::new(e[0], e[1])).collect();
         let gt = &GeomSettings {
             initial_min: 2,
-            min_points: 4,
             geomtype: GeomType::Line,
         };
-        let simplified = vwp_wrapper(&gt, &points_ls.into(), None, &0.0005);
+        let simplified = vwp_wrapper(gt, &points_ls.into(), None, &0.0005);
         assert_eq!(simplified[0].len(), 3278);
     }
 
@@ -834,7 +989,7 @@ mod test {
         let points_ls: LineString<_> = points.iter().map(|e| Point::new(e[0], e[1])).collect();
         let correct = include!("test_fixtures/vw_simplified.rs");
         let correct_ls: Vec<_> = correct.iter().map(|e| Point::new(e[0], e[1])).collect();
-        let simplified = LineString::from(points_ls).simplifyvw_preserve(&0.0005);
+        let simplified = points_ls.simplifyvw_preserve(&0.0005);
         assert_eq!(simplified, LineString::from(correct_ls));
     }
     #[test]
@@ -846,12 +1001,8 @@ mod test {
     }
     #[test]
     fn visvalingam_test_two_point_linestring() {
-        let mut vec = Vec::new();
-        vec.push(Point::new(0.0, 0.0));
-        vec.push(Point::new(27.8, 0.1));
-        let mut compare = Vec::new();
-        compare.push(Coordinate::from((0.0, 0.0)));
-        compare.push(Coordinate::from((27.8, 0.1)));
+        let vec = vec![Point::new(0.0, 0.0), Point::new(27.8, 0.1)];
+        let compare = vec![Coordinate::from((0.0, 0.0)), Coordinate::from((27.8, 0.1))];
         let simplified = visvalingam(&LineString::from(vec), &1.0);
         assert_eq!(simplified, compare);
     }
@@ -859,7 +1010,7 @@ mod test {
     #[test]
     fn multilinestring() {
         // this is the PostGIS example
-        let points = vec![
+        let points = [
             (5.0, 2.0),
             (3.0, 8.0),
             (6.0, 20.0),
@@ -868,7 +1019,7 @@ mod test {
         ];
         let points_ls: Vec<_> = points.iter().map(|e| Point::new(e.0, e.1)).collect();
 
-        let correct = vec![(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)];
+        let correct = [(5.0, 2.0), (7.0, 25.0), (10.0, 10.0)];
         let correct_ls: Vec<_> = correct.iter().map(|e| Point::new(e.0, e.1)).collect();
 
         let mline = MultiLineString(vec![LineString::from(points_ls)]);
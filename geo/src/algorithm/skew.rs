@@ -0,0 +1,114 @@
+use crate::algorithm::affine_ops::{AffineOps, AffineTransform};
+use crate::algorithm::centroid::Centroid;
+use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use crate::Point;
+use num_traits::Float;
+
+/// Skew a geometry, shearing it by angles given in degrees, about its centroid or a given point.
+///
+/// Skewing by a positive `x_degrees` tilts vertical lines to the right, and a positive
+/// `y_degrees` tilts horizontal lines upwards. As with [`Scale`](../scale/trait.Scale.html), the
+/// geometry is skewed about its centroid, or the origin if it has none, as a whole.
+pub trait Skew<T: Float> {
+    /// Skew the geometry by the same angle horizontally and vertically, about its centroid.
+    ///
+    /// # Units
+    ///
+    /// - `degrees`: degrees
+    fn skew(&self, degrees: T) -> Self;
+
+    /// Skew the geometry by `x_degrees` horizontally and `y_degrees` vertically, about its
+    /// centroid.
+    ///
+    /// # Units
+    ///
+    /// - `x_degrees`, `y_degrees`: degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::skew::Skew;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// // lean the square into a parallelogram
+    /// let skewed = square.skew_xy(45., 0.);
+    ///
+    /// let top_right = skewed.exterior().0[2];
+    /// assert_relative_eq!(top_right.x, 3., epsilon = 1e-12);
+    /// assert_relative_eq!(top_right.y, 2., epsilon = 1e-12);
+    /// let bottom_left = skewed.exterior().0[0];
+    /// assert_relative_eq!(bottom_left.x, -1., epsilon = 1e-12);
+    /// assert_relative_eq!(bottom_left.y, 0., epsilon = 1e-12);
+    /// ```
+    fn skew_xy(&self, x_degrees: T, y_degrees: T) -> Self;
+
+    /// Skew the geometry by `x_degrees` horizontally and `y_degrees` vertically, about `origin`.
+    ///
+    /// # Units
+    ///
+    /// - `x_degrees`, `y_degrees`: degrees
+    fn skew_around_point(&self, x_degrees: T, y_degrees: T, origin: Point<T>) -> Self;
+}
+
+impl<T, G> Skew<T> for G
+where
+    T: Float,
+    G: Centroid<T> + MapCoords<T, T, Output = G> + MapCoordsInplace<T>,
+    <G as Centroid<T>>::Output: Into<Option<Point<T>>>,
+{
+    fn skew(&self, degrees: T) -> Self {
+        self.skew_xy(degrees, degrees)
+    }
+
+    fn skew_xy(&self, x_degrees: T, y_degrees: T) -> Self {
+        let origin: Option<Point<T>> = self.centroid().into();
+        let origin = origin.unwrap_or_else(|| Point::new(T::zero(), T::zero()));
+        self.skew_around_point(x_degrees, y_degrees, origin)
+    }
+
+    fn skew_around_point(&self, x_degrees: T, y_degrees: T, origin: Point<T>) -> Self {
+        let transform = AffineTransform::identity()
+            .translate(-origin.x(), -origin.y())
+            .skew(x_degrees, y_degrees)
+            .translate(origin.x(), origin.y());
+        self.affine_transform(&transform)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, point, polygon, MultiPoint};
+
+    #[test]
+    fn skew_keeps_area() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+        let skewed = polygon.skew_xy(30., 0.);
+        assert_relative_eq!(skewed.signed_area(), 8., epsilon = 1e-12);
+        // the horizontal sides stay horizontal, but move sideways
+        let (bottom, top) = (skewed.exterior().0[0], skewed.exterior().0[3]);
+        assert_relative_eq!(bottom.y, 0., epsilon = 1e-12);
+        assert_relative_eq!(top.y, 2., epsilon = 1e-12);
+        assert_relative_eq!(top.x - bottom.x, 2. * 30_f64.to_radians().tan());
+    }
+
+    #[test]
+    fn skew_around_point() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 0., y: 2.)];
+        let skewed = line_string.skew_around_point(45., 0., point!(x: 0., y: 2.));
+        assert_relative_eq!(skewed.0[0].x, -2., epsilon = 1e-12);
+        assert_relative_eq!(skewed.0[0].y, 0., epsilon = 1e-12);
+        assert_relative_eq!(skewed.0[1].x, 0., epsilon = 1e-12);
+        assert_relative_eq!(skewed.0[1].y, 2., epsilon = 1e-12);
+        // both directions at once
+        let skewed = line_string.skew(45.);
+        assert_relative_eq!(skewed.0[0].x, -1., epsilon = 1e-12);
+        assert_relative_eq!(skewed.0[1].x, 1., epsilon = 1e-12);
+        let empty = MultiPoint::<f64>(vec![]);
+        assert_eq!(empty.skew(20.), empty);
+    }
+}
@@ -0,0 +1,427 @@
+use crate::algorithm::parts::{PartsSink, ToParts};
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+use rstar::{PointDistance, RTree, RTreeNum, AABB};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// Snap the vertices of a geometry onto the vertices and lines of another, to heal borders which
+/// were meant to be shared but don't quite line up, such as those of administrative boundaries
+/// digitized separately.
+pub trait Snap<T, Rhs = Self> {
+    /// Return the geometry with its vertices moved onto the geometry `other`, wherever they're
+    /// within `tolerance` of it.
+    ///
+    /// Each vertex within `tolerance` of a vertex of `other` is moved onto the nearest such
+    /// vertex; otherwise, a vertex within `tolerance` of a line of `other` is moved onto the
+    /// nearest point of the nearest such line. Then each vertex of `other` within `tolerance` of
+    /// a line of the geometry, other than at its ends, is inserted into the nearest such line,
+    /// so that both geometries have vertices wherever their borders meet. Vertices which become
+    /// the same as the one before them are removed.
+    ///
+    /// A `Line` has no room for more vertices, so only its ends are snapped, and a `Rect` or
+    /// `Triangle` in a `Geometry` is snapped as a `Polygon`. Parts of the geometry narrower than
+    /// `tolerance` may collapse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::bool_ops::BooleanOps;
+    /// use geo::algorithm::snap::Snap;
+    /// use geo::polygon;
+    ///
+    /// let west = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 2.), (x: 0., y: 2.)];
+    /// // the border with `west`, digitized slightly differently
+    /// let east = polygon![
+    ///     (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 1., y: 2.), (x: 1.001, y: 1.)
+    /// ];
+    ///
+    /// // the sliver between them keeps the union in two parts
+    /// assert_eq!(west.union(&east).0.len(), 2);
+    ///
+    /// let east = east.snap(&west, 0.01);
+    /// assert_eq!(
+    ///     east,
+    ///     polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 1., y: 2.), (x: 1., y: 1.)]
+    /// );
+    /// let union = west.union(&east);
+    /// assert_eq!(
+    ///     union.0,
+    ///     vec![polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]]
+    /// );
+    /// ```
+    fn snap(&self, other: &Rhs, tolerance: T) -> Self;
+}
+
+// The vertices and lines of a geometry being snapped to
+struct TargetParts<T: Float> {
+    vertices: Vec<Point<T>>,
+    lines: Vec<Line<T>>,
+}
+
+impl<T: Float> Default for TargetParts<T> {
+    fn default() -> Self {
+        TargetParts {
+            vertices: vec![],
+            lines: vec![],
+        }
+    }
+}
+
+impl<'a, T: Float> PartsSink<'a, T> for TargetParts<T> {
+    fn add_point(&mut self, coord: Coordinate<T>) {
+        self.vertices.push(Point(coord));
+    }
+
+    fn add_line_string(&mut self, coords: &[Coordinate<T>]) {
+        self.vertices
+            .extend(coords.iter().map(|&coord| Point(coord)));
+        self.lines
+            .extend(coords.windows(2).map(|pair| Line::new(pair[0], pair[1])));
+    }
+
+    fn add_polygon(&mut self, polygon: Cow<'a, Polygon<T>>) {
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            self.add_line_string(&ring.0);
+        }
+    }
+}
+
+// The vertices and lines of the geometry being snapped to, indexed
+struct Target<T: Float + RTreeNum> {
+    vertices: RTree<Point<T>>,
+    lines: RTree<Line<T>>,
+    tolerance: T,
+}
+
+impl<T: Float + RTreeNum> Target<T> {
+    fn new<G: ToParts<T>>(geometry: &G, tolerance: T) -> Self {
+        let parts: TargetParts<T> = geometry.parts();
+        Target {
+            vertices: RTree::bulk_load(parts.vertices),
+            lines: RTree::bulk_load(parts.lines),
+            tolerance,
+        }
+    }
+
+    // The nearest vertex within the tolerance, or else the nearest point of the nearest line
+    // within it, or else the vertex itself
+    fn snap_vertex(&self, coord: Coordinate<T>) -> Coordinate<T> {
+        let point = Point(coord);
+        let tolerance_2 = self.tolerance * self.tolerance;
+        if let Some(vertex) = self.vertices.nearest_neighbor(&point) {
+            if vertex.distance_2(&point) <= tolerance_2 {
+                return vertex.0;
+            }
+        }
+        if let Some(line) = self.lines.nearest_neighbor(&point) {
+            if line.distance_2(&point) <= tolerance_2 {
+                let (fraction, _) = project(line.start, line.end, coord);
+                let fraction = fraction.max(T::zero()).min(T::one());
+                return Coordinate {
+                    x: line.start.x + (line.end.x - line.start.x) * fraction,
+                    y: line.start.y + (line.end.y - line.start.y) * fraction,
+                };
+            }
+        }
+        coord
+    }
+
+    // Snap the vertices of a line string or ring, then insert the vertices of the target near
+    // its lines
+    fn snap_coords(&self, coords: &[Coordinate<T>]) -> Vec<Coordinate<T>> {
+        let snapped: Vec<_> = coords
+            .iter()
+            .map(|&coord| self.snap_vertex(coord))
+            .collect();
+        let tolerance_2 = self.tolerance * self.tolerance;
+
+        // the line each insertion is into, how far along it, how far from it, and the vertex
+        let mut insertions: Vec<(usize, T, T, Coordinate<T>)> = vec![];
+        for (i, pair) in snapped.windows(2).enumerate() {
+            let (start, end) = (pair[0], pair[1]);
+            if start == end {
+                continue;
+            }
+            let envelope = AABB::from_corners(
+                Point::new(
+                    start.x.min(end.x) - self.tolerance,
+                    start.y.min(end.y) - self.tolerance,
+                ),
+                Point::new(
+                    start.x.max(end.x) + self.tolerance,
+                    start.y.max(end.y) + self.tolerance,
+                ),
+            );
+            for vertex in self.vertices.locate_in_envelope_intersecting(&envelope) {
+                if vertex.0 == start || vertex.0 == end {
+                    continue;
+                }
+                let (fraction, distance_2) = project(start, end, vertex.0);
+                if fraction > T::zero() && fraction < T::one() && distance_2 <= tolerance_2 {
+                    insertions.push((i, fraction, distance_2, vertex.0));
+                }
+            }
+        }
+
+        // a vertex near a corner is only inserted into the nearer of its lines
+        insertions.sort_by(|a, b| {
+            compare_coords(a.3, b.3).then(a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal))
+        });
+        insertions.dedup_by(|later, earlier| later.3 == earlier.3);
+        insertions.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+        });
+
+        let mut result = Vec::with_capacity(snapped.len() + insertions.len());
+        let mut insertions = insertions.into_iter().peekable();
+        for (i, coord) in snapped.into_iter().enumerate() {
+            result.push(coord);
+            while let Some(&(_, _, _, vertex)) =
+                insertions.peek().filter(|insertion| insertion.0 == i)
+            {
+                result.push(vertex);
+                insertions.next();
+            }
+        }
+        result.dedup();
+        result
+    }
+}
+
+// How far along the line from `start` to `end` the nearest point to `coord` on its extension
+// is, as a fraction of its length, and the square of the distance to the line itself
+fn project<T: Float>(start: Coordinate<T>, end: Coordinate<T>, coord: Coordinate<T>) -> (T, T) {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length_2 = dx * dx + dy * dy;
+    let fraction = if length_2 == T::zero() {
+        T::zero()
+    } else {
+        ((coord.x - start.x) * dx + (coord.y - start.y) * dy) / length_2
+    };
+    let along = fraction.max(T::zero()).min(T::one());
+    let (away_x, away_y) = (
+        coord.x - (start.x + dx * along),
+        coord.y - (start.y + dy * along),
+    );
+    (fraction, away_x * away_x + away_y * away_y)
+}
+
+fn compare_coords<T: Float>(a: Coordinate<T>, b: Coordinate<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+// Snapping a geometry against the index of the target
+trait SnapTo<T: Float + RTreeNum> {
+    fn snap_to(&self, target: &Target<T>) -> Self;
+}
+
+impl<T: Float + RTreeNum> SnapTo<T> for Point<T> {
+    fn snap_to(&self, target: &Target<T>) -> Self {
+        Point(target.snap_vertex(self.0))
+    }
+}
+
+impl<T: Float + RTreeNum> SnapTo<T> for Line<T> {
+    fn snap_to(&self, target: &Target<T>) -> Self {
+        Line::new(target.snap_vertex(self.start), target.snap_vertex(self.end))
+    }
+}
+
+impl<T: Float + RTreeNum> SnapTo<T> for LineString<T> {
+    fn snap_to(&self, target: &Target<T>) -> Self {
+        LineString(target.snap_coords(&self.0))
+    }
+}
+
+impl<T: Float + RTreeNum> SnapTo<T> for Polygon<T> {
+    fn snap_to(&self, target: &Target<T>) -> Self {
+        Polygon::new(
+            self.exterior().snap_to(target),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.snap_to(target))
+                .collect(),
+        )
+    }
+}
+
+macro_rules! impl_snap_to_for_collection {
+    ($type:ident) => {
+        impl<T: Float + RTreeNum> SnapTo<T> for $type<T> {
+            fn snap_to(&self, target: &Target<T>) -> Self {
+                $type(self.0.iter().map(|member| member.snap_to(target)).collect())
+            }
+        }
+    };
+}
+
+impl_snap_to_for_collection!(MultiPoint);
+impl_snap_to_for_collection!(MultiLineString);
+impl_snap_to_for_collection!(MultiPolygon);
+impl_snap_to_for_collection!(GeometryCollection);
+
+impl<T: Float + RTreeNum> SnapTo<T> for Geometry<T> {
+    fn snap_to(&self, target: &Target<T>) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.snap_to(target)),
+            Geometry::Line(g) => Geometry::Line(g.snap_to(target)),
+            Geometry::LineString(g) => Geometry::LineString(g.snap_to(target)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.snap_to(target)),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.snap_to(target)),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.snap_to(target)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.snap_to(target)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.snap_to(target)),
+            Geometry::Rect(g) => Geometry::Polygon(Polygon::from(*g).snap_to(target)),
+            Geometry::Triangle(g) => Geometry::Polygon(Polygon::from(*g).snap_to(target)),
+        }
+    }
+}
+
+macro_rules! impl_snap {
+    ([$($type:ident),*], $targets:tt) => {
+        $(
+            impl_snap!(@ $type, $targets);
+        )*
+    };
+    (@ $type:ident, [$($rhs:ident),*]) => {
+        $(
+            impl<T> Snap<T, $rhs<T>> for $type<T>
+            where
+                T: Float + RTreeNum,
+            {
+                fn snap(&self, other: &$rhs<T>, tolerance: T) -> Self {
+                    self.snap_to(&Target::new(other, tolerance))
+                }
+            }
+        )*
+    };
+}
+
+impl_snap!(
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Geometry,
+        GeometryCollection
+    ],
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        Geometry,
+        GeometryCollection
+    ]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn vertices_before_lines() {
+        let target = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        // nearer to the line than to its end, but within the tolerance of both
+        assert_eq!(
+            point!(x: 9.5, y: 0.1).snap(&target, 1.),
+            point!(x: 10., y: 0.)
+        );
+        assert_eq!(
+            point!(x: 5., y: 0.1).snap(&target, 1.),
+            point!(x: 5., y: 0.)
+        );
+        assert_eq!(point!(x: 5., y: 2.).snap(&target, 1.), point!(x: 5., y: 2.));
+        // past the end of the line
+        assert_eq!(
+            point!(x: 12., y: 0.).snap(&target, 1.),
+            point!(x: 12., y: 0.)
+        );
+        assert_eq!(
+            Line::new((0.1, 0.1), (4., 1.)).snap(&target, 0.5),
+            Line::new((0., 0.), (4., 1.))
+        );
+        assert_eq!(
+            point!(x: 1., y: 1.).snap(&MultiPoint::<f64>(vec![]), 1.),
+            point!(x: 1., y: 1.)
+        );
+    }
+
+    #[test]
+    fn inserts_vertices() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let target = MultiPoint(vec![
+            point!(x: 7., y: 0.1),
+            point!(x: 3., y: -0.1),
+            // near the corner, nearer to the second line
+            point!(x: 9.8, y: 0.5),
+            // too far
+            point!(x: 5., y: 2.),
+        ]);
+        assert_eq!(
+            line_string.snap(&target, 0.5),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 3., y: -0.1),
+                (x: 7., y: 0.1),
+                (x: 10., y: 0.),
+                (x: 9.8, y: 0.5),
+                (x: 10., y: 10.)
+            ]
+        );
+    }
+
+    #[test]
+    fn removes_repeated_vertices() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 0.1, y: 0.), (x: 5., y: 0.)];
+        assert_eq!(
+            line_string.snap(&point!(x: 0.05, y: 0.), 0.1),
+            line_string![(x: 0.05, y: 0.), (x: 5., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn geometries() {
+        let rect = Rect::new((0., 0.), (1., 1.));
+        let polygon = polygon![
+            (x: 1.01, y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1.01, y: 1.)
+        ];
+        let snapped = Geometry::Polygon(polygon).snap(&Geometry::Rect(rect), 0.1);
+        assert_eq!(
+            snapped,
+            Geometry::Polygon(polygon![
+                (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)
+            ])
+        );
+        assert_eq!(
+            Geometry::Rect(Rect::new((0., 0.), (0.95, 1.))).snap(&rect, 0.1),
+            Geometry::Polygon(rect.into())
+        );
+
+        let collection = GeometryCollection(vec![Geometry::Point(point!(x: 0.01, y: 1.02))]);
+        assert_eq!(
+            collection.snap(&Triangle::from([(0., 0.), (1., 0.), (0., 1.)]), 0.1),
+            GeometryCollection(vec![Geometry::Point(point!(x: 0., y: 1.))])
+        );
+
+        let multi_polygon = MultiPolygon(vec![rect.into()]);
+        assert_eq!(multi_polygon.snap(&multi_polygon, 0.5), multi_polygon);
+    }
+}
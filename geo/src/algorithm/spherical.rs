@@ -0,0 +1,468 @@
+//! Distances, bearings, and destinations on a spherical earth, using the [haversine formula],
+//! for `Point`s whose `x` is the longitude and `y` the latitude, in degrees.
+//!
+//! Unlike the [`HaversineDistance`](../haversine_distance/trait.HaversineDistance.html),
+//! [`Bearing`](../bearing/trait.Bearing.html),
+//! [`HaversineDestination`](../haversine_destination/trait.HaversineDestination.html), and
+//! [`HaversineIntermediate`](../haversine_intermediate/trait.HaversineIntermediate.html)
+//! traits, these functions aren't limited to floats: they work with any
+//! [`SphericalNum`](trait.SphericalNum.html), which includes the fixed-point
+//! [`Fixed`](https://docs.rs/geo-types/latest/geo_types/fixed_point/struct.Fixed.html)
+//! coordinates of the `fixed-point` feature. The trigonometric functions of `f32` and `f64` may
+//! give slightly different results on different platforms, while those of `Fixed` only use
+//! fixed-point arithmetic, so their results are the same everywhere, which makes them suitable
+//! for consensus-critical code such as a blockchain runtime.
+//!
+//! [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::spherical::{haversine_destination, haversine_distance};
+//! use geo::Point;
+//!
+//! // New York City
+//! let p1 = Point::<f64>::from((-74.006, 40.7128));
+//! // London
+//! let p2 = Point::<f64>::from((-0.1278, 51.5074));
+//!
+//! assert_eq!(haversine_distance(p1, p2).round(), 5_570_222.); // meters
+//!
+//! let p3 = haversine_destination(p1, 90., 1_000.);
+//! assert_eq!(haversine_distance(p1, p3).round(), 1_000.);
+//! ```
+use crate::{CoordinateType, Point, MEAN_EARTH_RADIUS};
+use num_traits::{Float, Signed};
+
+/// A number which supports the trigonometric functions needed for spherical geometry.
+///
+/// Angles are in radians.
+pub trait SphericalNum: CoordinateType + Signed {
+    /// π
+    fn pi() -> Self;
+
+    /// The square root, which is zero for negative fixed-point numbers.
+    fn sqrt(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    /// The arcsine, in the range [-π/2, π/2].
+    fn asin(self) -> Self;
+
+    /// The four quadrant arctangent of `self` (`y`) and `x`, in the range [-π, π].
+    fn atan2(self, x: Self) -> Self;
+
+    /// Convert degrees to radians.
+    fn to_radians(self) -> Self {
+        self * Self::pi() / Self::from(180).unwrap()
+    }
+
+    /// Convert radians to degrees.
+    fn to_degrees(self) -> Self {
+        self * Self::from(180).unwrap() / Self::pi()
+    }
+}
+
+macro_rules! float_spherical_num_impl {
+    ($($type:ident),*) => {
+        $(
+            impl SphericalNum for $type {
+                fn pi() -> Self {
+                    std::$type::consts::PI
+                }
+
+                fn sqrt(self) -> Self {
+                    Float::sqrt(self)
+                }
+
+                fn sin(self) -> Self {
+                    Float::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    Float::cos(self)
+                }
+
+                fn asin(self) -> Self {
+                    Float::asin(self)
+                }
+
+                fn atan2(self, x: Self) -> Self {
+                    Float::atan2(self, x)
+                }
+
+                fn to_radians(self) -> Self {
+                    Float::to_radians(self)
+                }
+
+                fn to_degrees(self) -> Self {
+                    Float::to_degrees(self)
+                }
+            }
+        )*
+    };
+}
+
+float_spherical_num_impl!(f32, f64);
+
+#[cfg(feature = "fixed-point")]
+mod fixed_point {
+    use super::SphericalNum;
+    use geo_types::fixed_point::fixed::{consts, traits::FixedSigned};
+    use geo_types::fixed_point::Fixed;
+    use num_traits::{NumCast, One, Signed, Zero};
+
+    fn int<F: FixedSigned>(n: i32) -> Fixed<F> {
+        <Fixed<F> as NumCast>::from(n).expect("fixed-point type can't represent the integer")
+    }
+
+    /// These only use fixed-point arithmetic, so they are deterministic. Their error is a few
+    /// units in the last place of the fixed-point type.
+    ///
+    /// # Panics
+    ///
+    /// Like the fixed-point arithmetic, they panic if a value overflows. The wrapped type needs at
+    /// least 3 integer bits to represent π, and more to represent large angles or distances: e.g.
+    /// `I32F32` covers distances on the earth in meters.
+    impl<F> SphericalNum for Fixed<F>
+    where
+        F: FixedSigned,
+    {
+        fn pi() -> Self {
+            Fixed(F::from_num(consts::PI))
+        }
+
+        fn sqrt(self) -> Self {
+            Fixed(self.0.checked_sqrt().unwrap_or(F::ZERO))
+        }
+
+        fn sin(self) -> Self {
+            let pi = Self::pi();
+            let two_pi = pi + pi;
+            let half_pi = pi / int(2);
+            // reduce to [-π, π], then to [-π/2, π/2], where the Taylor series converges quickly
+            let mut x = self - two_pi * Fixed((self / two_pi).0.round());
+            if x > half_pi {
+                x = pi - x;
+            } else if x < -half_pi {
+                x = -pi - x;
+            }
+            let (magnitude, negative) = (x.abs(), x < Self::zero());
+            let square = magnitude * magnitude;
+            // the series alternates, so sum positive terms, which truncate towards zero
+            let mut term = magnitude;
+            let mut sum = magnitude;
+            let mut n = 1;
+            while n < MAX_SERIES_TERMS {
+                term *= square;
+                term /= int((n + 1) * (n + 2));
+                if term.is_zero() {
+                    break;
+                }
+                if n % 4 == 1 {
+                    sum -= term;
+                } else {
+                    sum += term;
+                }
+                n += 2;
+            }
+            if negative {
+                -sum
+            } else {
+                sum
+            }
+        }
+
+        fn cos(self) -> Self {
+            let pi = Self::pi();
+            // cos(x) = sin(π/2 - |x|), which keeps the argument small for small x
+            (pi / int(2) - self.abs()).sin()
+        }
+
+        fn asin(self) -> Self {
+            let one = Self::one();
+            let x = if self > one {
+                one
+            } else if self < -one {
+                -one
+            } else {
+                self
+            };
+            x.atan2(((one - x) * (one + x)).sqrt())
+        }
+
+        fn atan2(self, x: Self) -> Self {
+            let y = self;
+            let pi = Self::pi();
+            let half_pi = pi / int(2);
+            if x.is_zero() && y.is_zero() {
+                Self::zero()
+            } else if x.abs() >= y.abs() {
+                let angle = fixed_atan(y / x);
+                if x > Self::zero() {
+                    angle
+                } else if y >= Self::zero() {
+                    angle + pi
+                } else {
+                    angle - pi
+                }
+            } else {
+                // the ratio y / x could overflow
+                let angle = fixed_atan(x / y);
+                if y > Self::zero() {
+                    half_pi - angle
+                } else {
+                    -half_pi - angle
+                }
+            }
+        }
+    }
+
+    // An upper bound for the number of terms of a Taylor series, which is never reached with up
+    // to 128 fractional bits
+    const MAX_SERIES_TERMS: i32 = 200;
+
+    // The arctangent of a number in [-1, 1]
+    fn fixed_atan<F>(x: Fixed<F>) -> Fixed<F>
+    where
+        F: FixedSigned,
+    {
+        let one = Fixed::<F>::one();
+        let (magnitude, negative) = (x.abs(), x < Fixed::zero());
+        // atan(x) = 2 atan(x / (1 + sqrt(1 + x²))) halves the angle, to at most π/8
+        let reduced = magnitude / (one + (one + magnitude * magnitude).sqrt());
+        let square = reduced * reduced;
+        // as in `sin`, sum positive terms
+        let mut power = reduced;
+        let mut sum = reduced;
+        let mut n = 1;
+        while n < MAX_SERIES_TERMS {
+            power *= square;
+            let term = power / int(n + 2);
+            if term.is_zero() {
+                break;
+            }
+            if n % 4 == 1 {
+                sum -= term;
+            } else {
+                sum += term;
+            }
+            n += 2;
+        }
+        let angle = sum + sum;
+        if negative {
+            -angle
+        } else {
+            angle
+        }
+    }
+}
+
+/// The radius of the earth used by these functions, in meters.
+fn earth_radius<T: SphericalNum>() -> T {
+    T::from(MEAN_EARTH_RADIUS).unwrap()
+}
+
+/// Determine the distance between two points, in meters, using the [haversine formula].
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub fn haversine_distance<T: SphericalNum>(origin: Point<T>, destination: Point<T>) -> T {
+    central_angle(origin, destination) * earth_radius()
+}
+
+// The angle between two points, in radians, seen from the center of the earth
+fn central_angle<T: SphericalNum>(origin: Point<T>, destination: Point<T>) -> T {
+    let two = T::one() + T::one();
+    let theta1 = origin.y().to_radians();
+    let theta2 = destination.y().to_radians();
+    let delta_theta = (destination.y() - origin.y()).to_radians();
+    let delta_lambda = (destination.x() - origin.x()).to_radians();
+    let sin_half_theta = (delta_theta / two).sin();
+    let sin_half_lambda = (delta_lambda / two).sin();
+    let a = sin_half_theta * sin_half_theta
+        + theta1.cos() * theta2.cos() * sin_half_lambda * sin_half_lambda;
+    two * a.sqrt().asin()
+}
+
+/// Determine the initial bearing from one point to another, in degrees, where North is 0° and
+/// East is 90°.
+///
+/// Bullock, R.: Great Circle Distances and Bearings Between Two Locations, 2007.
+/// (https://dtcenter.org/met/users/docs/write_ups/gc_simple.pdf)
+pub fn haversine_bearing<T: SphericalNum>(origin: Point<T>, destination: Point<T>) -> T {
+    let (lng_a, lat_a) = (origin.x().to_radians(), origin.y().to_radians());
+    let (lng_b, lat_b) = (destination.x().to_radians(), destination.y().to_radians());
+    let delta_lng = lng_b - lng_a;
+    let s = lat_b.cos() * delta_lng.sin();
+    let c = lat_a.cos() * lat_b.sin() - lat_a.sin() * lat_b.cos() * delta_lng.cos();
+
+    s.atan2(c).to_degrees()
+}
+
+/// Determine the point at a distance, in meters, and a bearing, in degrees, from an origin.
+pub fn haversine_destination<T: SphericalNum>(
+    origin: Point<T>,
+    bearing: T,
+    distance: T,
+) -> Point<T> {
+    let center_lng = origin.x().to_radians();
+    let center_lat = origin.y().to_radians();
+    let bearing_rad = bearing.to_radians();
+
+    let rad = distance / earth_radius();
+
+    let lat =
+        (center_lat.sin() * rad.cos() + center_lat.cos() * rad.sin() * bearing_rad.cos()).asin();
+    let lng = (bearing_rad.sin() * rad.sin() * center_lat.cos())
+        .atan2(rad.cos() - center_lat.sin() * lat.sin())
+        + center_lng;
+
+    Point::new(lng.to_degrees(), lat.to_degrees())
+}
+
+/// Determine the point at a `fraction` of the great circle route from an origin to a
+/// destination: `0` is the origin and `1` the destination.
+pub fn haversine_intermediate<T: SphericalNum>(
+    origin: Point<T>,
+    destination: Point<T>,
+    fraction: T,
+) -> Point<T> {
+    let d = central_angle(origin, destination);
+    if d.is_zero() {
+        return origin;
+    }
+
+    let lat1 = origin.y().to_radians();
+    let lon1 = origin.x().to_radians();
+    let lat2 = destination.y().to_radians();
+    let lon2 = destination.x().to_radians();
+
+    let a = ((T::one() - fraction) * d).sin() / d.sin();
+    let b = (fraction * d).sin() / d.sin();
+
+    let x = a * lat1.cos() * lon1.cos() + b * lat2.cos() * lon2.cos();
+    let y = a * lat1.cos() * lon1.sin() + b * lat2.cos() * lon2.sin();
+    let z = a * lat1.sin() + b * lat2.sin();
+
+    let lat = z.atan2((x * x + y * y).sqrt());
+    let lon = y.atan2(x);
+
+    Point::new(lon.to_degrees(), lat.to_degrees())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::bearing::Bearing;
+    use crate::algorithm::haversine_destination::HaversineDestination;
+    use crate::algorithm::haversine_distance::HaversineDistance;
+    use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+
+    fn assert_points_near(a: Point<f64>, b: Point<f64>, epsilon: f64) {
+        assert_relative_eq!(a.x(), b.x(), epsilon = epsilon);
+        assert_relative_eq!(a.y(), b.y(), epsilon = epsilon);
+    }
+
+    #[test]
+    fn matches_float_traits() {
+        let p1 = Point::new(9.177789688110352f64, 48.776781529534965);
+        let p2 = Point::new(-72.1235, 42.3521);
+        assert_relative_eq!(haversine_distance(p1, p2), p1.haversine_distance(&p2));
+        assert_relative_eq!(haversine_bearing(p1, p2), p1.bearing(p2));
+        assert_points_near(
+            haversine_destination(p1, 45., 10000.),
+            p1.haversine_destination(45., 10000.),
+            1e-12,
+        );
+        assert_points_near(
+            haversine_intermediate(p1, p2, 0.3),
+            p1.haversine_intermediate(&p2, 0.3),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn intermediate_ends() {
+        let p1 = Point::new(10.0f64, 20.0);
+        let p2 = Point::new(125.0, 25.0);
+        assert_points_near(haversine_intermediate(p1, p2, 0.), p1, 1e-10);
+        assert_points_near(haversine_intermediate(p1, p2, 1.), p2, 1e-10);
+        assert_eq!(haversine_intermediate(p1, p1, 0.5), p1);
+    }
+
+    #[cfg(feature = "fixed-point")]
+    mod fixed_point {
+        use super::*;
+        use geo_types::fixed_point::{FixedI32F32, FixedI64F64};
+
+        fn f(value: f64) -> FixedI64F64 {
+            FixedI64F64::from_f64(value).unwrap()
+        }
+
+        #[test]
+        fn functions() {
+            let mut x = -10.;
+            while x < 10. {
+                assert_relative_eq!(f(x).sin().to_f64(), x.sin(), epsilon = 1e-15);
+                assert_relative_eq!(f(x).cos().to_f64(), x.cos(), epsilon = 1e-15);
+                assert_relative_eq!(f(x).abs().sqrt().to_f64(), x.abs().sqrt(), epsilon = 1e-15);
+                assert_relative_eq!(f(x).atan2(f(0.7)).to_f64(), x.atan2(0.7), epsilon = 1e-15);
+                assert_relative_eq!(
+                    f(0.3).atan2(f(x)).to_f64(),
+                    0.3f64.atan2(x),
+                    epsilon = 1e-15
+                );
+                let y = x / 10.;
+                assert_relative_eq!(f(y).asin().to_f64(), y.asin(), epsilon = 1e-15);
+                x += 0.37;
+            }
+            assert_eq!(f(-1.).sqrt(), f(0.));
+            assert_eq!(f(0.).atan2(f(0.)), f(0.));
+            assert_relative_eq!(f(1.).atan2(f(0.)).to_f64(), std::f64::consts::FRAC_PI_2);
+            assert_relative_eq!(f(0.).atan2(f(-1.)).to_f64(), std::f64::consts::PI);
+        }
+
+        #[test]
+        fn haversine() {
+            let fixed = |p: Point<f64>| Point::new(f(p.x()), f(p.y()));
+            let p1 = Point::new(9.177789688110352, 48.776781529534965);
+            let p2 = Point::new(-72.1235, 42.3521);
+            let (q1, q2) = (fixed(p1), fixed(p2));
+            assert_relative_eq!(
+                haversine_distance(q1, q2).to_f64(),
+                haversine_distance(p1, p2),
+                epsilon = 1e-6
+            );
+            assert_relative_eq!(
+                haversine_bearing(q1, q2).to_f64(),
+                haversine_bearing(p1, p2),
+                epsilon = 1e-12
+            );
+            let destination = haversine_destination(q1, f(45.), f(10000.));
+            assert_points_near(
+                Point::new(destination.x().to_f64(), destination.y().to_f64()),
+                haversine_destination(p1, 45., 10000.),
+                1e-12,
+            );
+            let intermediate = haversine_intermediate(q1, q2, f(0.3));
+            assert_points_near(
+                Point::new(intermediate.x().to_f64(), intermediate.y().to_f64()),
+                haversine_intermediate(p1, p2, 0.3),
+                1e-12,
+            );
+        }
+
+        #[test]
+        fn narrow_fixed_point() {
+            let f = |value: f64| FixedI32F32::from_f64(value).unwrap();
+            // New York City to London
+            let distance = haversine_distance(
+                Point::new(f(-74.006), f(40.7128)),
+                Point::new(f(-0.1278), f(51.5074)),
+            );
+            assert_relative_eq!(distance.to_f64(), 5_570_222., epsilon = 1.);
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::{Coordinate, Line};
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+/// Find every intersection among a set of `Line`s, using the [Bentley–Ottmann] sweep line
+/// algorithm.
+///
+/// Each intersection is returned once, as the indices `(i, j)` of the two lines, with `i < j`,
+/// and how they intersect, as found by
+/// [`line_intersection`](../line_intersection/fn.line_intersection.html). They're sorted by `i`,
+/// then `j`. Lines with non-finite coordinates are skipped.
+///
+/// A vertical line sweeps across the plane, keeping the lines it crosses in order from bottom to
+/// top, and only lines which become neighbours in this order are tested for crossings; lines
+/// which meet at an endpoint are found where the sweep reaches it. For `n` lines with `k`
+/// intersections, this takes `O((n + k) log n)` comparisons, far fewer than testing all pairs
+/// when most lines are far apart, as for the rings of large polygons. The order of the lines is
+/// decided by exact [predicates](../predicates/index.html), and the rounded positions of
+/// crossings are only used to schedule them, so every intersection returned is exact.
+///
+/// [Bentley–Ottmann]: https://en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_intersection::LineIntersection;
+/// use geo::algorithm::sweep_line::segment_intersections;
+/// use geo::{Coordinate, Line};
+///
+/// let lines = vec![
+///     Line::from([(0., 0.), (4., 4.)]),
+///     Line::from([(0., 4.), (4., 0.)]),
+///     Line::from([(4., 0.), (6., 0.)]),
+///     Line::from([(10., 10.), (11., 10.)]),
+/// ];
+///
+/// let intersections = segment_intersections(&lines);
+/// assert_eq!(
+///     intersections,
+///     vec![
+///         (
+///             0,
+///             1,
+///             LineIntersection::SinglePoint {
+///                 intersection: Coordinate { x: 2., y: 2. },
+///                 is_proper: true,
+///             }
+///         ),
+///         (
+///             1,
+///             2,
+///             LineIntersection::SinglePoint {
+///                 intersection: Coordinate { x: 4., y: 0. },
+///                 is_proper: false,
+///             }
+///         ),
+///     ]
+/// );
+/// ```
+pub fn segment_intersections<T: Float>(
+    lines: &[Line<T>],
+) -> Vec<(usize, usize, LineIntersection<T>)> {
+    let mut sweep = Sweep::new(lines);
+    while let Some(event) = sweep.events.pop() {
+        let point = event.point;
+        let mut batch = vec![event.kind];
+        while let Some(event) = sweep.events.peek() {
+            if event.point != point {
+                break;
+            }
+            batch.push(sweep.events.pop().unwrap().kind);
+        }
+        sweep.handle(point, batch);
+    }
+    let mut intersections = sweep.intersections;
+    intersections.sort_by_key(|&(i, j, _)| (i, j));
+    intersections
+}
+
+#[derive(Clone, Copy, Debug)]
+enum EventKind {
+    Start(usize),
+    End(usize),
+    // the lines, from bottom to top, swap places where they cross
+    Cross(usize, usize),
+}
+
+struct Event<T: Float> {
+    point: Coordinate<T>,
+    kind: EventKind,
+}
+
+impl<T: Float> PartialEq for Event<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<T: Float> Eq for Event<T> {}
+
+impl<T: Float> PartialOrd for Event<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Event<T> {
+    // reversed, so that the heap pops the leftmost, then lowest, point first
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(other.point, self.point)
+    }
+}
+
+// The order in which the sweep reaches coordinates
+fn compare<T: Float>(a: Coordinate<T>, b: Coordinate<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+struct Sweep<'a, T: Float> {
+    original: &'a [Line<T>],
+    // the lines, each starting at the end the sweep reaches first
+    lines: Vec<Line<T>>,
+    events: BinaryHeap<Event<T>>,
+    // the lines the sweep is crossing, from bottom to top
+    active: Vec<usize>,
+    // the pairs of lines with a crossing scheduled
+    crossings: HashSet<(usize, usize)>,
+    reported: HashSet<(usize, usize)>,
+    intersections: Vec<(usize, usize, LineIntersection<T>)>,
+}
+
+impl<'a, T: Float> Sweep<'a, T> {
+    fn new(original: &'a [Line<T>]) -> Self {
+        let lines: Vec<Line<T>> = original
+            .iter()
+            .map(|line| {
+                if compare(line.start, line.end) == Ordering::Greater {
+                    Line::new(line.end, line.start)
+                } else {
+                    *line
+                }
+            })
+            .collect();
+        let mut events = BinaryHeap::with_capacity(lines.len() * 2);
+        for (index, line) in lines.iter().enumerate() {
+            let finite = [line.start, line.end]
+                .iter()
+                .all(|c| c.x.is_finite() && c.y.is_finite());
+            if !finite {
+                continue;
+            }
+            events.push(Event {
+                point: line.start,
+                kind: EventKind::Start(index),
+            });
+            if line.start != line.end {
+                events.push(Event {
+                    point: line.end,
+                    kind: EventKind::End(index),
+                });
+            }
+        }
+        Sweep {
+            original,
+            lines,
+            events,
+            active: vec![],
+            crossings: HashSet::new(),
+            reported: HashSet::new(),
+            intersections: vec![],
+        }
+    }
+
+    fn report(&mut self, a: usize, b: usize) {
+        let pair = (a.min(b), a.max(b));
+        if a == b || !self.reported.insert(pair) {
+            return;
+        }
+        if let Some(intersection) = line_intersection(self.original[pair.0], self.original[pair.1])
+        {
+            self.intersections.push((pair.0, pair.1, intersection));
+        }
+    }
+
+    // Test neighbouring lines, `lower` just below `upper`, for a crossing ahead of `point`
+    fn check(&mut self, lower: usize, upper: usize, point: Coordinate<T>) {
+        if self.crossings.contains(&(lower, upper)) {
+            return;
+        }
+        let (lower_line, upper_line) = (self.lines[lower], self.lines[upper]);
+        if let Some(LineIntersection::SinglePoint {
+            intersection,
+            is_proper: true,
+        }) = line_intersection(lower_line, upper_line)
+        {
+            self.report(lower, upper);
+            // only lines which haven't crossed yet, judged exactly, swap places
+            if orient2d(lower_line.start, lower_line.end, upper_line.end) == Orientation::Clockwise
+            {
+                self.crossings.insert((lower, upper));
+                let at = if compare(intersection, point) == Ordering::Less {
+                    point
+                } else {
+                    intersection
+                };
+                self.events.push(Event {
+                    point: at,
+                    kind: EventKind::Cross(lower, upper),
+                });
+            }
+        }
+    }
+
+    fn check_around(&mut self, from: usize, to: usize, point: Coordinate<T>) {
+        if from > 0 && from < self.active.len() {
+            self.check(self.active[from - 1], self.active[from], point);
+        }
+        if to > from && to < self.active.len() {
+            self.check(self.active[to - 1], self.active[to], point);
+        }
+    }
+
+    fn handle(&mut self, point: Coordinate<T>, batch: Vec<EventKind>) {
+        let mut starting = vec![];
+        let mut ending = vec![];
+        for kind in batch {
+            match kind {
+                EventKind::Cross(lower, upper) => {
+                    // if it's stale, the crossing is scheduled again once they're neighbours
+                    self.crossings.remove(&(lower, upper));
+                    let position = self.active.iter().position(|&index| index == lower);
+                    if let Some(position) = position {
+                        // a crossing is stale once the lines are no longer neighbours
+                        if self.active.get(position + 1) == Some(&upper) {
+                            self.active.swap(position, position + 1);
+                            self.check_around(position, position + 2, point);
+                        }
+                    }
+                }
+                EventKind::Start(index) => starting.push(index),
+                EventKind::End(index) => ending.push(index),
+            }
+        }
+        if starting.is_empty() && ending.is_empty() {
+            return;
+        }
+
+        // the active lines through the point are together, between those below and above it
+        let lines = &self.lines;
+        let side = |index: &usize| orient2d(lines[*index].start, lines[*index].end, point);
+        let mut below = self
+            .active
+            .partition_point(|index| side(index) == Orientation::CounterClockwise);
+        let through =
+            self.active[below..].partition_point(|index| side(index) == Orientation::Collinear);
+        let mut at_point: Vec<usize> = self.active.drain(below..below + through).collect();
+        for &index in &ending {
+            if !at_point.contains(&index) {
+                if let Some(position) = self.active.iter().position(|&other| other == index) {
+                    self.active.remove(position);
+                    if position < below {
+                        below -= 1;
+                    }
+                }
+                at_point.push(index);
+            }
+        }
+
+        let mut continuing: Vec<usize> = at_point
+            .iter()
+            .copied()
+            .filter(|&index| self.lines[index].end != point)
+            .collect();
+        for &index in &starting {
+            if self.lines[index].end != point {
+                continuing.push(index);
+            }
+            at_point.push(index);
+        }
+        for (i, &a) in at_point.iter().enumerate() {
+            for &b in &at_point[i + 1..] {
+                self.report(a, b);
+            }
+        }
+
+        // beyond the point, the lines through it are in the order of their directions
+        let lines = &self.lines;
+        continuing.sort_by(|&a, &b| match orient2d(point, lines[a].end, lines[b].end) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => a.cmp(&b),
+        });
+        let count = continuing.len();
+        self.active.splice(below..below, continuing);
+        self.check_around(below, below + count, point);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Every intersecting pair, by testing them all
+    fn all_pairs<T: Float>(lines: &[Line<T>]) -> Vec<(usize, usize, LineIntersection<T>)> {
+        let mut intersections = vec![];
+        for i in 0..lines.len() {
+            for j in i + 1..lines.len() {
+                if let Some(intersection) = line_intersection(lines[i], lines[j]) {
+                    intersections.push((i, j, intersection));
+                }
+            }
+        }
+        intersections
+    }
+
+    #[test]
+    fn grid() {
+        let mut lines = vec![];
+        for i in 0..10 {
+            let offset = i as f64;
+            lines.push(Line::from([(offset, -1.), (offset + 0.5, 10.)]));
+            lines.push(Line::from([(-1., offset), (10., offset - 0.3)]));
+        }
+        let intersections = segment_intersections(&lines);
+        assert_eq!(intersections.len(), 100);
+        assert_eq!(intersections, all_pairs(&lines));
+    }
+
+    #[test]
+    fn shared_points_and_overlaps() {
+        let lines = vec![
+            // a star, meeting at the origin
+            Line::from([(-2., -2.), (2., 2.)]),
+            Line::from([(-2., 2.), (2., -2.)]),
+            Line::from([(0., -3.), (0., 3.)]),
+            Line::from([(-3., 0.), (0., 0.)]),
+            // overlapping the vertical line, and a point on it
+            Line::from([(0., 1.), (0., 5.)]),
+            Line::from([(0., 4.), (0., 4.)]),
+            // a polyline's shared vertices
+            Line::from([(5., 0.), (6., 1.)]),
+            Line::from([(6., 1.), (7., 0.)]),
+            Line::from([(7., 0.), (5., 0.)]),
+        ];
+        assert_eq!(segment_intersections(&lines), all_pairs(&lines));
+        assert_eq!(segment_intersections(&lines[6..]).len(), 3);
+    }
+
+    #[test]
+    fn many_crossings_through_one_point() {
+        let lines: Vec<Line<f64>> = (0..12)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 12.;
+                let (sin, cos) = angle.sin_cos();
+                Line::from([
+                    (1. - 3. * cos, 2. - 3. * sin),
+                    (1. + 3. * cos, 2. + 3. * sin),
+                ])
+            })
+            .collect();
+        let intersections = segment_intersections(&lines);
+        assert_eq!(intersections.len(), 66);
+        assert_eq!(
+            intersections
+                .iter()
+                .map(|&(i, j, _)| (i, j))
+                .collect::<Vec<_>>(),
+            all_pairs(&lines)
+                .iter()
+                .map(|&(i, j, _)| (i, j))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn matches_all_pairs() {
+        // pseudo-random lines, with many crossings and some shared endpoints
+        let mut seed = 7_u64;
+        let mut random = move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % 64) as f64 / 4.
+        };
+        for _ in 0..20 {
+            let lines: Vec<Line<f64>> = (0..60)
+                .map(|_| Line::from([(random(), random()), (random(), random())]))
+                .collect();
+            assert_eq!(segment_intersections(&lines), all_pairs(&lines));
+        }
+    }
+
+    #[test]
+    fn empty_and_non_finite() {
+        assert!(segment_intersections::<f64>(&[]).is_empty());
+        let lines = vec![
+            Line::from([(0., 0.), (1., 1.)]),
+            Line::from([(0., 1.), (f64::NAN, 0.)]),
+            Line::from([(0., 1.), (1., 0.)]),
+        ];
+        assert_eq!(segment_intersections(&lines).len(), 1);
+    }
+}
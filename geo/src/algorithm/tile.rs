@@ -0,0 +1,460 @@
+//! Find the tiles of a slippy map holding geometries, and convert between longitudes and
+//! latitudes and [Web Mercator] coordinates.
+//!
+//! Tiles are numbered using the XYZ scheme of most web maps: at zoom level `z`, the world
+//! between the latitudes of ±85.0511° is divided into `2^z` by `2^z` square tiles in Web
+//! Mercator, the tile with `x` of `0` is at the antimeridian and `x` increases eastward, and the
+//! tile with `y` of `0` is at the top, in the north, with `y` increasing southward. The [TMS]
+//! scheme numbers rows from the south instead: use [`Tile::from_tms`] and [`Tile::tms_y`] to
+//! convert.
+//!
+//! Geometries are in longitudes (`x`) and latitudes (`y`), in degrees.
+//!
+//! [Web Mercator]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+//! [TMS]: https://wiki.osgeo.org/wiki/Tile_Map_Service_Specification
+//! [`Tile::from_tms`]: struct.Tile.html#method.from_tms
+//! [`Tile::tms_y`]: struct.Tile.html#method.tms_y
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::tile::{Tile, ToTile};
+//! use geo::point;
+//!
+//! let tile = point!(x: 13.4, y: 52.52).to_tile(10).unwrap();
+//! assert_eq!((tile.x(), tile.y(), tile.zoom()), (550, 335, 10));
+//! assert_eq!(tile.tms_y(), 688);
+//! assert_eq!(Tile::from_tms(550, 688, 10), Some(tile));
+//! ```
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::projection::{Transform, WebMercator};
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+
+/// The deepest zoom level, at which there are `2^31` tiles in each direction.
+pub const MAX_ZOOM: u8 = 31;
+
+/// The latitude, in degrees, of the northern edge of the tiles, where Web Mercator's `y`
+/// equals half its circumference, so that the whole map is square.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// A tile of a slippy map, in the XYZ scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Tile {
+    zoom: u8,
+    y: u32,
+    x: u32,
+}
+
+impl Tile {
+    /// Return the tile at column `x` and row `y`, counted from the north, at `zoom`, or `None`
+    /// if there's no such tile.
+    pub fn new(x: u32, y: u32, zoom: u8) -> Option<Tile> {
+        if zoom > MAX_ZOOM || x >> zoom != 0 || y >> zoom != 0 {
+            return None;
+        }
+        Some(Tile { zoom, y, x })
+    }
+
+    /// Return the tile at column `x` and row `y`, counted from the south as in TMS, at `zoom`,
+    /// or `None` if there's no such tile.
+    pub fn from_tms(x: u32, y: u32, zoom: u8) -> Option<Tile> {
+        if zoom > MAX_ZOOM || y >> zoom != 0 {
+            return None;
+        }
+        Tile::new(x, Tile::last(zoom) - y, zoom)
+    }
+
+    // The last column or row at `zoom`
+    fn last(zoom: u8) -> u32 {
+        ((1u64 << zoom) - 1) as u32
+    }
+
+    /// The column of the tile, counted from the antimeridian eastward.
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// The row of the tile, counted from the north.
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// The row of the tile, counted from the south as in TMS.
+    pub fn tms_y(&self) -> u32 {
+        Tile::last(self.zoom) - self.y
+    }
+
+    /// The zoom level of the tile.
+    pub fn zoom(&self) -> u8 {
+        self.zoom
+    }
+
+    /// The tile at the next lower zoom level holding this one, or `None` at zoom `0`.
+    pub fn parent(&self) -> Option<Tile> {
+        let zoom = self.zoom.checked_sub(1)?;
+        Tile::new(self.x >> 1, self.y >> 1, zoom)
+    }
+
+    /// The four tiles at the next higher zoom level dividing this one, in rows from the
+    /// north-west, or `None` at [`MAX_ZOOM`](constant.MAX_ZOOM.html).
+    pub fn children(&self) -> Option<[Tile; 4]> {
+        if self.zoom == MAX_ZOOM {
+            return None;
+        }
+        let (x, y, zoom) = (self.x << 1, self.y << 1, self.zoom + 1);
+        Some([
+            Tile { zoom, y, x },
+            Tile { zoom, y, x: x + 1 },
+            Tile { zoom, y: y + 1, x },
+            Tile {
+                zoom,
+                y: y + 1,
+                x: x + 1,
+            },
+        ])
+    }
+
+    /// The longitudes and latitudes, in degrees, of the tile's edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::tile::{Tile, MAX_LATITUDE};
+    ///
+    /// let tile = Tile::new(1, 0, 1).unwrap();
+    /// let bounds = tile.bounds::<f64>();
+    ///
+    /// assert_eq!((bounds.min().x, bounds.max().x), (0., 180.));
+    /// assert_eq!(bounds.min().y, 0.);
+    /// assert!((bounds.max().y - MAX_LATITUDE).abs() < 1e-9);
+    /// ```
+    pub fn bounds<T: Float>(&self) -> Rect<T> {
+        let n = T::from(1u64 << self.zoom).unwrap();
+        let lon = |x: u32| T::from(x).unwrap() / n * T::from(360).unwrap() - T::from(180).unwrap();
+        let lat = |y: u32| {
+            let pi = T::from(std::f64::consts::PI).unwrap();
+            let two = T::one() + T::one();
+            (pi * (T::one() - two * T::from(y).unwrap() / n))
+                .sinh()
+                .atan()
+                .to_degrees()
+        };
+        Rect::new(
+            Coordinate {
+                x: lon(self.x),
+                y: lat(self.y + 1),
+            },
+            Coordinate {
+                x: lon(self.x + 1),
+                y: lat(self.y),
+            },
+        )
+    }
+
+    /// The Web Mercator coordinates, in metres, of the tile's edges.
+    pub fn mercator_bounds<T: Float>(&self) -> Rect<T> {
+        let bounds = self.bounds::<T>();
+        Rect::new(
+            to_web_mercator(&bounds.min().into()),
+            to_web_mercator(&bounds.max().into()),
+        )
+    }
+
+    // The tile's bounds, reaching to the poles along the top and bottom rows, so that they
+    // hold the points beyond the map there
+    fn covered_bounds<T: Float>(&self) -> Rect<T> {
+        let bounds = self.bounds::<T>();
+        let quarter_turn = T::from(90).unwrap();
+        let (mut min, mut max) = (bounds.min(), bounds.max());
+        if self.y == Tile::last(self.zoom) {
+            min.y = -quarter_turn;
+        }
+        if self.y == 0 {
+            max.y = quarter_turn;
+        }
+        Rect::new(min, max)
+    }
+}
+
+// The column and row, possibly past the edges of the map, at `zoom` of a longitude and
+// latitude
+pub(crate) fn tile_position<T: Float>(coord: Coordinate<T>, zoom: u8) -> (T, T) {
+    let n = T::from(1u64 << zoom).unwrap();
+    let max_latitude = T::from(MAX_LATITUDE).unwrap();
+    let lat = coord.y.max(-max_latitude).min(max_latitude).to_radians();
+    let (half, turn) = (T::from(0.5).unwrap(), T::from(360).unwrap());
+    let pi = T::from(std::f64::consts::PI).unwrap();
+    let x = (coord.x / turn + half) * n;
+    let y = (half - (lat.tan() + T::one() / lat.cos()).ln() / (pi + pi)) * n;
+    (x, y)
+}
+
+// The column or row holding a position along an axis, clamped to the map
+fn clamp_to_map<T: Float>(position: T, zoom: u8) -> u32 {
+    position
+        .floor()
+        .max(T::zero())
+        .min(T::from(Tile::last(zoom)).unwrap())
+        .to_u32()
+        .unwrap_or(0)
+}
+
+/// Find the [tile](struct.Tile.html) of a slippy map holding a point.
+pub trait ToTile<T: Float> {
+    /// Return the tile at `zoom` holding the point, whose `x` is its longitude and `y` its
+    /// latitude in degrees, or `None` if `zoom` is past [`MAX_ZOOM`](constant.MAX_ZOOM.html)
+    /// or a coordinate is NaN.
+    ///
+    /// A point on the edge between tiles is in the tile to its east or south. Points beyond
+    /// the edges of the map, in longitude or past [`MAX_LATITUDE`](constant.MAX_LATITUDE.html),
+    /// are in the tiles along those edges.
+    fn to_tile(&self, zoom: u8) -> Option<Tile>;
+}
+
+impl<T: Float> ToTile<T> for Point<T> {
+    fn to_tile(&self, zoom: u8) -> Option<Tile> {
+        if zoom > MAX_ZOOM || self.x().is_nan() || self.y().is_nan() {
+            return None;
+        }
+        let (x, y) = tile_position(self.0, zoom);
+        Tile::new(clamp_to_map(x, zoom), clamp_to_map(y, zoom), zoom)
+    }
+}
+
+/// Find the [tiles](struct.Tile.html) of a slippy map which a geometry intersects.
+pub trait CoveringTiles<T: Float> {
+    /// Return the tiles at `zoom` which the geometry, of longitudes and latitudes in degrees,
+    /// intersects, including those it only touches, ordered by row, then column, or an empty
+    /// `Vec` if `zoom` is past [`MAX_ZOOM`](constant.MAX_ZOOM.html).
+    ///
+    /// Parts of the geometry beyond the edges of the map are covered by the tiles along those
+    /// edges. The number of tiles grows fourfold with each zoom level, so large geometries
+    /// should be covered at moderate zooms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::tile::CoveringTiles;
+    /// use geo::line_string;
+    ///
+    /// let route = line_string![(x: -10., y: 10.), (x: 10., y: -10.)];
+    /// let tiles = route.covering_tiles(2);
+    ///
+    /// let positions: Vec<_> = tiles.iter().map(|tile| (tile.x(), tile.y())).collect();
+    /// assert_eq!(positions, vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+    /// ```
+    fn covering_tiles(&self, zoom: u8) -> Vec<Tile>;
+}
+
+macro_rules! impl_covering_tiles {
+    ($($type:ident),*) => {
+        $(
+            impl<T: Float> CoveringTiles<T> for $type<T> {
+                fn covering_tiles(&self, zoom: u8) -> Vec<Tile> {
+                    let rect: Option<Rect<T>> = self.bounding_rect().into();
+                    match rect {
+                        Some(rect) if zoom <= MAX_ZOOM => covering_tiles(rect, zoom, |bounds| {
+                            self.intersects(bounds)
+                        }),
+                        _ => vec![],
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_covering_tiles!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+// The tiles in the range covering `rect` whose bounds the geometry intersects
+fn covering_tiles<T, F>(rect: Rect<T>, zoom: u8, intersects: F) -> Vec<Tile>
+where
+    T: Float,
+    F: Fn(&Rect<T>) -> bool,
+{
+    let (min_x, max_y) = tile_position(rect.min(), zoom);
+    let (max_x, min_y) = tile_position(rect.max(), zoom);
+    if [min_x, min_y, max_x, max_y]
+        .iter()
+        .any(|value| value.is_nan())
+    {
+        return vec![];
+    }
+    let (min_x, max_x) = (clamp_to_map(min_x, zoom), clamp_to_map(max_x, zoom));
+    let (min_y, max_y) = (clamp_to_map(min_y, zoom), clamp_to_map(max_y, zoom));
+    let mut tiles = vec![];
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let tile = Tile { zoom, y, x };
+            if intersects(&tile.covered_bounds()) {
+                tiles.push(tile);
+            }
+        }
+    }
+    tiles
+}
+
+/// Project a point of longitude (`x`) and latitude (`y`), in degrees, to [Web Mercator]
+/// coordinates, in metres from where the equator meets the prime meridian.
+///
+/// Latitudes past [`MAX_LATITUDE`](constant.MAX_LATITUDE.html) are projected beyond the map,
+/// and the poles to infinity.
+///
+/// [Web Mercator]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::tile::{from_web_mercator, to_web_mercator};
+/// use geo::point;
+///
+/// let projected = to_web_mercator(&point!(x: 180f64, y: 0.));
+/// assert!((projected.x() - 20_037_508.342_789_244).abs() < 1e-6);
+/// assert!(projected.y().abs() < 1e-6);
+///
+/// let berlin = point!(x: 13.4f64, y: 52.52);
+/// let round_trip = from_web_mercator(&to_web_mercator(&berlin));
+/// assert!((round_trip.x() - 13.4).abs() < 1e-9 && (round_trip.y() - 52.52).abs() < 1e-9);
+/// ```
+pub fn to_web_mercator<T: Float>(point: &Point<T>) -> Point<T> {
+    WebMercator.project(point.0).into()
+}
+
+/// Convert [Web Mercator] coordinates, in metres, to a point of longitude (`x`) and latitude
+/// (`y`) in degrees, the inverse of [`to_web_mercator`](fn.to_web_mercator.html).
+///
+/// [Web Mercator]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+pub fn from_web_mercator<T: Float>(point: &Point<T>) -> Point<T> {
+    WebMercator.unproject(point.0).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn points_to_tiles() {
+        let origin = point!(x: 0., y: 0.);
+        assert_eq!(origin.to_tile(0), Tile::new(0, 0, 0));
+        // on an edge, the tile to the east and south
+        assert_eq!(origin.to_tile(1), Tile::new(1, 1, 1));
+        assert_eq!(point!(x: -180., y: 85.06).to_tile(3), Tile::new(0, 0, 3));
+        assert_eq!(point!(x: 180., y: -90.).to_tile(3), Tile::new(7, 7, 3));
+        assert_eq!(point!(x: 200., y: 10.).to_tile(3), Tile::new(7, 3, 3));
+        assert_eq!(point!(x: f64::NAN, y: 10.).to_tile(3), None);
+        assert_eq!(origin.to_tile(MAX_ZOOM + 1), None);
+        let deepest = point!(x: 180., y: -90.).to_tile(MAX_ZOOM).unwrap();
+        assert_eq!((deepest.x(), deepest.y()), (u32::MAX >> 1, u32::MAX >> 1));
+
+        // points are inside the bounds of their tiles, at every zoom
+        let berlin = point!(x: 13.4, y: 52.52);
+        for zoom in 0..=24 {
+            let tile = berlin.to_tile(zoom).unwrap();
+            let bounds = tile.bounds::<f64>();
+            assert!(bounds.min().x <= 13.4 && 13.4 < bounds.max().x);
+            assert!(bounds.min().y < 52.52 && 52.52 <= bounds.max().y);
+            if let Some(parent) = tile.parent() {
+                assert_eq!(berlin.to_tile(zoom - 1), Some(parent));
+                assert!(parent.children().unwrap().contains(&tile));
+            }
+        }
+    }
+
+    #[test]
+    fn tile_numbering() {
+        assert_eq!(Tile::new(2, 0, 1), None);
+        assert_eq!(Tile::new(0, 2, 1), None);
+        assert_eq!(Tile::new(0, 0, MAX_ZOOM + 1), None);
+        assert_eq!(Tile::from_tms(0, 2, 1), None);
+        let tile = Tile::new(3, 1, 2).unwrap();
+        assert_eq!(tile.tms_y(), 2);
+        assert_eq!(Tile::from_tms(3, 2, 2), Some(tile));
+        assert_eq!(tile.parent(), Tile::new(1, 0, 1));
+        assert_eq!(Tile::new(0, 0, 0).unwrap().parent(), None);
+        assert_eq!(Tile::new(0, 0, MAX_ZOOM).unwrap().tms_y(), u32::MAX >> 1);
+
+        let bounds = Tile::new(0, 0, 0).unwrap().bounds::<f64>();
+        assert_relative_eq!(bounds.min().x, -180.);
+        assert_relative_eq!(bounds.max().x, 180.);
+        assert_relative_eq!(bounds.min().y, -MAX_LATITUDE);
+        assert_relative_eq!(bounds.max().y, MAX_LATITUDE);
+        let mercator = Tile::new(0, 0, 0).unwrap().mercator_bounds::<f64>();
+        assert_relative_eq!(mercator.min().x, -20_037_508.342_789_244, epsilon = 1e-6);
+        assert_relative_eq!(mercator.max().y, 20_037_508.342_789_244, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn covering_tiles() {
+        let square = polygon![
+            (x: 0.5, y: 0.5),
+            (x: 10., y: 0.5),
+            (x: 10., y: 10.),
+            (x: 0.5, y: 10.),
+        ];
+        let tiles = square.covering_tiles(3);
+        assert_eq!(tiles, vec![Tile::new(4, 3, 3).unwrap()]);
+        for zoom in 0..=8 {
+            let tiles = square.covering_tiles(zoom);
+            assert!(tiles
+                .windows(2)
+                .all(|pair| (pair[0].y, pair[0].x) < (pair[1].y, pair[1].x)));
+            for corner in square.exterior().points_iter() {
+                assert!(tiles.contains(&corner.to_tile(zoom).unwrap()));
+            }
+        }
+
+        // a diagonal line misses the corners of its bounding box
+        let line = line_string![(x: 0.1, y: 0.1), (x: 89., y: 66.)];
+        let tiles = line.covering_tiles(4);
+        assert!(!tiles.contains(&point!(x: 89., y: 0.1).to_tile(4).unwrap()));
+        assert!(tiles.contains(&point!(x: 45., y: 45.).to_tile(4).unwrap()));
+
+        // beyond the edge of the map, near a pole
+        let arctic = point!(x: 10., y: 89.);
+        assert_eq!(arctic.covering_tiles(5), vec![arctic.to_tile(5).unwrap()]);
+
+        let collection = GeometryCollection(vec![
+            point!(x: -100., y: -40.).into(),
+            point!(x: 100., y: 40.).into(),
+        ]);
+        assert_eq!(collection.covering_tiles(1).len(), 2);
+        assert!(GeometryCollection::<f64>(vec![])
+            .covering_tiles(1)
+            .is_empty());
+        assert!(square.covering_tiles(MAX_ZOOM + 1).is_empty());
+    }
+
+    #[test]
+    fn web_mercator_round_trip() {
+        for &(x, y) in &[
+            (0., 0.),
+            (-179.9, 85.),
+            (12.3, -45.6),
+            (180., -MAX_LATITUDE),
+        ] {
+            let projected = to_web_mercator(&point!(x: x, y: y));
+            let round_trip = from_web_mercator(&projected);
+            assert_relative_eq!(round_trip.x(), x, epsilon = 1e-9);
+            assert_relative_eq!(round_trip.y(), y, epsilon = 1e-9);
+        }
+        let corner = to_web_mercator(&point!(x: -180., y: MAX_LATITUDE));
+        assert_relative_eq!(corner.x(), -corner.y(), epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,828 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::predicates::{incircle, orient2d, CirclePosition, Orientation};
+use crate::utils::is_finite_coord;
+use crate::{
+    Coordinate, CoordinateType, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Polygon, Triangle,
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Triangulate the coordinates of a geometry, such that no coordinate is inside the
+/// circumcircle of any triangle.
+///
+/// The triangles cover the convex hull of the coordinates, are oriented counter-clockwise, and
+/// have the coordinates as their vertices; repeated coordinates are used once, and those which
+/// are NaN or infinite are ignored. Of the triangulations of four or more coordinates on a
+/// circle, an arbitrary one is chosen. If all the coordinates are collinear, there are no
+/// triangles.
+///
+/// This uses exact predicates rather than constructing any coordinates, so it supports any
+/// `CoordinateType`, including integer and fixed-point coordinates.
+pub trait DelaunayTriangulation<T: CoordinateType> {
+    /// Return the Delaunay triangulation of the coordinates of a geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::triangulation::DelaunayTriangulation;
+    /// use geo::{Coordinate, MultiPoint};
+    ///
+    /// let points: MultiPoint<i32> = vec![(0, 0), (4, 0), (4, 3), (0, 3), (2, 1)].into();
+    ///
+    /// let triangles = points.delaunay_triangulation();
+    /// // the point inside the rectangle is joined to each of its corners
+    /// assert_eq!(triangles.len(), 4);
+    /// for triangle in triangles {
+    ///     assert!(triangle.to_array().contains(&Coordinate { x: 2, y: 1 }));
+    /// }
+    /// ```
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>>;
+}
+
+/// Triangulate the area of a geometry, with its edges as edges of the triangles.
+///
+/// The triangulation is the constrained Delaunay triangulation of the geometry's coordinates and
+/// edges, without the triangles outside it: apart from the edges of the geometry, no triangle's
+/// circumcircle contains a coordinate which is visible from inside the triangle. So the
+/// triangles are as close to equilateral as the edges allow, which suits rendering and sampling
+/// points with uniform density. They are oriented counter-clockwise.
+///
+/// Vertices which are NaN or infinite are left out of their rings. Rings may touch, but the
+/// result is only meaningful for geometries whose rings don't cross;
+/// see [`Validation`](../validation/trait.Validation.html). Like
+/// [`DelaunayTriangulation`](trait.DelaunayTriangulation.html), this is exact, and supports any
+/// `CoordinateType`.
+pub trait ConstrainedTriangulation<T: CoordinateType> {
+    /// Return the constrained Delaunay triangulation of the area of a geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::triangulation::ConstrainedTriangulation;
+    /// use geo::polygon;
+    ///
+    /// // an L shape
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 2., y: 0.),
+    ///     (x: 2., y: 1.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 1., y: 2.),
+    ///     (x: 0., y: 2.),
+    /// ];
+    ///
+    /// let triangles = polygon.constrained_triangulation();
+    /// assert_eq!(triangles.len(), 4);
+    /// let area: f64 = triangles.iter().map(|triangle| triangle.signed_area()).sum();
+    /// assert_eq!(area, polygon.unsigned_area());
+    /// ```
+    fn constrained_triangulation(&self) -> Vec<Triangle<T>>;
+}
+
+fn lexicographic<T: CoordinateType>(a: &Coordinate<T>, b: &Coordinate<T>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+// A triangulation of unique, finite coordinates, sorted lexicographically, with its triangles stored
+// counter-clockwise and looked up by their directed edges
+struct Triangulation<T: CoordinateType> {
+    coords: Vec<Coordinate<T>>,
+    triangles: Vec<[usize; 3]>,
+    edges: HashMap<(usize, usize), usize>,
+    constraints: HashSet<(usize, usize)>,
+}
+
+impl<T: CoordinateType> Triangulation<T> {
+    fn new(mut coords: Vec<Coordinate<T>>) -> Self {
+        coords.retain(is_finite_coord);
+        coords.sort_by(lexicographic);
+        coords.dedup();
+        let mut triangulation = Triangulation {
+            coords,
+            triangles: vec![],
+            edges: HashMap::new(),
+            constraints: HashSet::new(),
+        };
+        triangulation.sweep();
+        triangulation.make_delaunay();
+        triangulation
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        orient2d(self.coords[a], self.coords[b], self.coords[c])
+    }
+
+    fn add(&mut self, slot: Option<usize>, [a, b, c]: [usize; 3]) {
+        let triangle = if self.orient(a, b, c) == Orientation::Clockwise {
+            [b, a, c]
+        } else {
+            [a, b, c]
+        };
+        let index = match slot {
+            Some(index) => {
+                self.triangles[index] = triangle;
+                index
+            }
+            None => {
+                self.triangles.push(triangle);
+                self.triangles.len() - 1
+            }
+        };
+        for i in 0..3 {
+            self.edges
+                .insert((triangle[i], triangle[(i + 1) % 3]), index);
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        let triangle = self.triangles[index];
+        for i in 0..3 {
+            self.edges.remove(&(triangle[i], triangle[(i + 1) % 3]));
+        }
+    }
+
+    // The vertex of a triangle opposite one of its directed edges
+    fn apex(&self, index: usize, (a, b): (usize, usize)) -> usize {
+        let triangle = self.triangles[index];
+        *triangle
+            .iter()
+            .find(|vertex| **vertex != a && **vertex != b)
+            .unwrap()
+    }
+
+    // Triangulate the coordinates in order, each outside the hull of the previous ones, joining
+    // it to the hull edges which face it
+    fn sweep(&mut self) {
+        let n = self.coords.len();
+        let first = match (2..n).find(|&i| self.orient(0, 1, i) != Orientation::Collinear) {
+            Some(first) => first,
+            None => return,
+        };
+        for i in 0..first - 1 {
+            self.add(None, [i, i + 1, first]);
+        }
+        // the hull is counter-clockwise, and keeps collinear coordinates
+        let mut hull: Vec<usize> = (0..=first).collect();
+        if self.orient(0, 1, first) == Orientation::Clockwise {
+            hull.reverse();
+        }
+        for point in first + 1..n {
+            let facing: Vec<bool> = (0..hull.len())
+                .map(|i| {
+                    let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+                    self.orient(a, b, point) == Orientation::Clockwise
+                })
+                .collect();
+            // as the point is outside the hull, the edges facing it are consecutive
+            let start = match (0..hull.len())
+                .find(|&i| facing[i] && !facing[(i + hull.len() - 1) % hull.len()])
+            {
+                Some(start) => start,
+                // each point comes after the hull's in lexicographic order, so it's outside the
+                // hull and some edge faces it, but leave it out rather than panicking otherwise
+                None => continue,
+            };
+            hull.rotate_left(start);
+            let count = facing[start..]
+                .iter()
+                .chain(&facing[..start])
+                .take_while(|facing| **facing)
+                .count();
+            for i in 0..count {
+                self.add(None, [hull[i + 1], hull[i], point]);
+            }
+            hull.splice(1..count, std::iter::once(point));
+        }
+    }
+
+    // Flip edges until every edge which isn't a constraint is locally Delaunay
+    fn legalize(&mut self, mut stack: Vec<(usize, usize)>) {
+        while let Some((a, b)) = stack.pop() {
+            let (first, second) = match (self.edges.get(&(a, b)), self.edges.get(&(b, a))) {
+                (Some(first), Some(second)) => (*first, *second),
+                _ => continue,
+            };
+            if self.constraints.contains(&(a.min(b), a.max(b))) {
+                continue;
+            }
+            let c = self.apex(first, (a, b));
+            let d = self.apex(second, (b, a));
+            if incircle(
+                self.coords[a],
+                self.coords[b],
+                self.coords[c],
+                self.coords[d],
+            ) != CirclePosition::Inside
+            {
+                continue;
+            }
+            self.remove(first);
+            self.remove(second);
+            self.add(Some(first), [a, d, c]);
+            self.add(Some(second), [d, b, c]);
+            stack.extend(&[(a, d), (d, b), (b, c), (c, a)]);
+        }
+    }
+
+    fn make_delaunay(&mut self) {
+        let stack = self.edges.keys().filter(|(a, b)| a < b).copied().collect();
+        self.legalize(stack);
+    }
+
+    // Whether `b` lies on the segment from `a` to `c`, given that the three are collinear
+    fn is_between(&self, a: usize, b: usize, c: usize) -> bool {
+        a.min(c) < b && b < a.max(c)
+    }
+
+    // Make the segment between two coordinates an edge, splitting it at any coordinates on it
+    fn insert_constraint(&mut self, mut u: usize, v: usize) {
+        while u != v {
+            u = self.insert_constraint_part(u, v);
+        }
+    }
+
+    // Make the segment from `u` towards `v` an edge, up to the first coordinate on it, which is
+    // returned
+    fn insert_constraint_part(&mut self, u: usize, v: usize) -> usize {
+        if self.edges.contains_key(&(u, v)) || self.edges.contains_key(&(v, u)) {
+            self.constraints.insert((u.min(v), u.max(v)));
+            return v;
+        }
+        // find the triangle around `u` which the segment leaves through its opposite edge
+        let around: Vec<usize> = self
+            .edges
+            .iter()
+            .filter(|((a, _), _)| *a == u)
+            .map(|(_, index)| *index)
+            .collect();
+        let mut crossed = None;
+        for index in around {
+            let b = self.triangles[index][(self.position(index, u) + 1) % 3];
+            let c = self.triangles[index][(self.position(index, u) + 2) % 3];
+            match (self.orient(u, b, v), self.orient(u, c, v)) {
+                (Orientation::Collinear, _) if self.is_between(u, b, v) => {
+                    self.constraints.insert((u.min(b), u.max(b)));
+                    return b;
+                }
+                (_, Orientation::Collinear) if self.is_between(u, c, v) => {
+                    self.constraints.insert((u.min(c), u.max(c)));
+                    return c;
+                }
+                (Orientation::CounterClockwise, Orientation::Clockwise) => {
+                    crossed = Some((c, b));
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let (mut left, mut right) = match crossed {
+            Some(edge) => edge,
+            // only when the coordinates are all collinear, so there's nothing to constrain
+            None => return v,
+        };
+        let mut removed = vec![self.edges[&(right, left)]];
+        let mut left_chain = vec![left];
+        let mut right_chain = vec![right];
+        let end = loop {
+            let index = match self.edges.get(&(left, right)) {
+                Some(index) => *index,
+                None => break v,
+            };
+            removed.push(index);
+            let w = self.apex(index, (left, right));
+            if w == v {
+                break v;
+            }
+            match self.orient(u, v, w) {
+                Orientation::CounterClockwise => {
+                    left = w;
+                    left_chain.push(w);
+                }
+                Orientation::Clockwise => {
+                    right = w;
+                    right_chain.push(w);
+                }
+                Orientation::Collinear => break w,
+            }
+        };
+        // the constraints crossed, if the rings cross, are lost
+        for index in &removed {
+            self.remove(*index);
+        }
+        let mut slots = removed.into_iter();
+        self.fill(&mut slots, u, end, &left_chain);
+        self.fill(&mut slots, u, end, &right_chain);
+        self.constraints.insert((u.min(end), u.max(end)));
+        end
+    }
+
+    fn position(&self, index: usize, vertex: usize) -> usize {
+        self.triangles[index]
+            .iter()
+            .position(|other| *other == vertex)
+            .unwrap()
+    }
+
+    // Triangulate the polygon formed by the edge from `a` to `b` and a chain of coordinates on
+    // one side of it, choosing the coordinate whose triangle with the edge has an empty
+    // circumcircle and recursing on either side of it (Anglada, 1997)
+    fn fill(
+        &mut self,
+        slots: &mut impl Iterator<Item = usize>,
+        a: usize,
+        b: usize,
+        chain: &[usize],
+    ) {
+        if chain.is_empty() {
+            return;
+        }
+        let mut chosen = 0;
+        for (i, d) in chain.iter().enumerate().skip(1) {
+            let c = chain[chosen];
+            if incircle(
+                self.coords[a],
+                self.coords[b],
+                self.coords[c],
+                self.coords[*d],
+            ) == CirclePosition::Inside
+            {
+                chosen = i;
+            }
+        }
+        let c = chain[chosen];
+        self.add(slots.next(), [a, b, c]);
+        self.fill(slots, a, c, &chain[..chosen]);
+        self.fill(slots, c, b, &chain[chosen + 1..]);
+    }
+
+    fn insert_ring(&mut self, ring: &LineString<T>) {
+        // the coordinates which aren't finite aren't triangulated
+        let indices: Vec<usize> = ring
+            .0
+            .iter()
+            .filter_map(|coord| lexicographic_index(&self.coords, coord))
+            .collect();
+        for pair in indices.windows(2) {
+            self.insert_constraint(pair[0], pair[1]);
+        }
+    }
+
+    // The triangles inside the constraints: those separated from outside the hull by an odd
+    // number of them
+    fn inside(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        let mut crossings = vec![usize::MAX; self.triangles.len()];
+        let mut queue = VecDeque::new();
+        for (&(a, b), &index) in &self.edges {
+            if !self.edges.contains_key(&(b, a)) {
+                let crossing = self.constraints.contains(&(a.min(b), a.max(b))) as usize;
+                queue.push_back((index, crossing));
+            }
+        }
+        // breadth first, crossing constraints last, so each triangle is reached with the fewest
+        while let Some((index, crossing)) = queue.pop_front() {
+            if crossings[index] <= crossing {
+                continue;
+            }
+            crossings[index] = crossing;
+            let triangle = self.triangles[index];
+            for i in 0..3 {
+                let (a, b) = (triangle[(i + 1) % 3], triangle[i]);
+                if let Some(&neighbour) = self.edges.get(&(a, b)) {
+                    if self.constraints.contains(&(a.min(b), a.max(b))) {
+                        queue.push_back((neighbour, crossing + 1));
+                    } else {
+                        queue.push_front((neighbour, crossing));
+                    }
+                }
+            }
+        }
+        self.triangles
+            .iter()
+            .zip(crossings)
+            .filter(|(_, crossing)| crossing % 2 == 1)
+            .map(|(triangle, _)| *triangle)
+    }
+
+    fn triangle(&self, [a, b, c]: [usize; 3]) -> Triangle<T> {
+        Triangle(self.coords[a], self.coords[b], self.coords[c])
+    }
+
+    fn into_triangles(self) -> Vec<Triangle<T>> {
+        self.triangles
+            .iter()
+            .map(|triangle| self.triangle(*triangle))
+            .collect()
+    }
+}
+
+fn delaunay<T: CoordinateType>(coords: impl Iterator<Item = Coordinate<T>>) -> Vec<Triangle<T>> {
+    Triangulation::new(coords.collect()).into_triangles()
+}
+
+/// Return the unique coordinates, sorted lexicographically, and the indices of each one's
+/// neighbours in their Delaunay triangulation, or along the line if they're all collinear
+pub(crate) fn delaunay_neighbours<T: CoordinateType>(
+    coords: Vec<Coordinate<T>>,
+) -> (Vec<Coordinate<T>>, Vec<Vec<usize>>) {
+    let triangulation = Triangulation::new(coords);
+    let n = triangulation.coords.len();
+    let mut neighbours = vec![vec![]; n];
+    if triangulation.triangles.is_empty() {
+        for i in 1..n {
+            neighbours[i - 1].push(i);
+            neighbours[i].push(i - 1);
+        }
+    } else {
+        for &(a, b) in triangulation.edges.keys() {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+        for list in &mut neighbours {
+            list.sort_unstable();
+            list.dedup();
+        }
+    }
+    (triangulation.coords, neighbours)
+}
+
+pub(crate) fn lexicographic_index<T: CoordinateType>(
+    coords: &[Coordinate<T>],
+    coord: &Coordinate<T>,
+) -> Option<usize> {
+    coords
+        .binary_search_by(|other| lexicographic(other, coord))
+        .ok()
+}
+
+fn constrained<'a, T: CoordinateType + 'a>(
+    polygons: impl Iterator<Item = &'a Polygon<T>> + Clone,
+) -> Vec<Triangle<T>> {
+    let coords = polygons
+        .clone()
+        .flat_map(|polygon| polygon.coords_iter())
+        .collect();
+    let mut triangulation = Triangulation::new(coords);
+    for polygon in polygons {
+        triangulation.insert_ring(polygon.exterior());
+        for interior in polygon.interiors() {
+            triangulation.insert_ring(interior);
+        }
+    }
+    triangulation
+        .inside()
+        .map(|triangle| triangulation.triangle(triangle))
+        .collect()
+}
+
+impl<T> DelaunayTriangulation<T> for MultiPoint<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> DelaunayTriangulation<T> for LineString<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> DelaunayTriangulation<T> for MultiLineString<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> DelaunayTriangulation<T> for Polygon<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> DelaunayTriangulation<T> for MultiPolygon<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> DelaunayTriangulation<T> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        delaunay(self.coords_iter())
+    }
+}
+
+impl<T> ConstrainedTriangulation<T> for Polygon<T>
+where
+    T: CoordinateType,
+{
+    fn constrained_triangulation(&self) -> Vec<Triangle<T>> {
+        constrained(std::iter::once(self))
+    }
+}
+
+impl<T> ConstrainedTriangulation<T> for MultiPolygon<T>
+where
+    T: CoordinateType,
+{
+    fn constrained_triangulation(&self) -> Vec<Triangle<T>> {
+        constrained(self.0.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{polygon, Point};
+
+    // Check that the triangles are counter-clockwise, and that none of the coordinates is inside
+    // any of their circumcircles, apart from those across a constraint
+    fn assert_delaunay<T: CoordinateType>(triangles: &[Triangle<T>], coords: &[Coordinate<T>]) {
+        for triangle in triangles {
+            let [a, b, c] = triangle.to_array();
+            assert_eq!(orient2d(a, b, c), Orientation::CounterClockwise);
+            for coord in coords {
+                assert_ne!(incircle(a, b, c, *coord), CirclePosition::Inside);
+            }
+        }
+    }
+
+    fn edges<T: CoordinateType>(triangle: &Triangle<T>) -> Vec<crate::Line<T>> {
+        let [a, b, c] = triangle.to_array();
+        vec![
+            crate::Line::new(a, b),
+            crate::Line::new(b, c),
+            crate::Line::new(c, a),
+        ]
+    }
+
+    // Twice the signed area, which is exact for integers
+    fn doubled_area(triangles: &[Triangle<i64>]) -> i64 {
+        triangles
+            .iter()
+            .map(|Triangle(a, b, c)| (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x))
+            .sum()
+    }
+
+    fn grid(size: i32) -> Vec<Coordinate<f64>> {
+        (0..size)
+            .flat_map(|x| {
+                (0..size).map(move |y| Coordinate {
+                    x: x as f64,
+                    y: y as f64,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn delaunay() {
+        // a deterministic scatter
+        let coords: Vec<Coordinate<f64>> = (0..200)
+            .map(|i| {
+                let i = i as f64;
+                Coordinate {
+                    x: (i * 0.618_034).fract() * 100.,
+                    y: (i * 0.414_214).fract() * 100.,
+                }
+            })
+            .collect();
+        let points: MultiPoint<f64> = coords.iter().map(|coord| Point(*coord)).collect();
+        let triangles = points.delaunay_triangulation();
+        assert_delaunay(&triangles, &coords);
+        let area: f64 = triangles
+            .iter()
+            .map(|triangle| triangle.unsigned_area())
+            .sum();
+        let hull_area =
+            crate::algorithm::convexhull::ConvexHull::convex_hull(&points).unsigned_area();
+        assert_relative_eq!(area, hull_area, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn cocircular() {
+        // every four neighbouring points of a grid are on a circle
+        let coords = grid(10);
+        let points: MultiPoint<f64> = coords.iter().map(|coord| Point(*coord)).collect();
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2 * 9 * 9);
+        assert_delaunay(&triangles, &coords);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert!(empty.delaunay_triangulation().is_empty());
+        let collinear: MultiPoint<i64> = vec![(0, 0), (2, 2), (1, 1), (3, 3)].into();
+        assert!(collinear.delaunay_triangulation().is_empty());
+        // collinear points, then one which isn't
+        let fan: MultiPoint<i64> = vec![(0, 0), (1, 0), (2, 0), (3, 0), (1, 5), (1, 0)].into();
+        let triangles = fan.delaunay_triangulation();
+        assert_eq!(triangles.len(), 3);
+        assert_eq!(doubled_area(&triangles), 15);
+    }
+
+    #[test]
+    fn non_finite() {
+        let square: Vec<Coordinate<f64>> = vec![
+            (0., 0.).into(),
+            (1., 0.).into(),
+            (1., 1.).into(),
+            (0., 1.).into(),
+        ];
+        for bad in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let mut coords = square.clone();
+            coords.insert(2, (*bad, 0.5).into());
+            coords.push((0.5, *bad).into());
+            let points: MultiPoint<f64> = coords.iter().map(|&coord| Point(coord)).collect();
+            let triangles = points.delaunay_triangulation();
+            assert_eq!(triangles.len(), 2);
+            assert_delaunay(&triangles, &square);
+
+            let polygon = Polygon::new(LineString(coords), vec![]);
+            let area: f64 = polygon
+                .constrained_triangulation()
+                .iter()
+                .map(|triangle| triangle.signed_area())
+                .sum();
+            assert_relative_eq!(area, 1.);
+        }
+    }
+
+    #[test]
+    fn constrained_polygon() {
+        // a thin diagonal slot, across which the Delaunay triangulation would join the sides
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[
+                (x: 1., y: 1.),
+                (x: 9., y: 8.),
+                (x: 9., y: 9.),
+                (x: 8., y: 9.),
+            ]],
+        ];
+        let triangles = polygon.constrained_triangulation();
+        let area: f64 = triangles
+            .iter()
+            .map(|triangle| triangle.signed_area())
+            .sum();
+        assert_relative_eq!(area, polygon.unsigned_area());
+        // every edge of the polygon is an edge of a triangle
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            for line in ring.lines() {
+                assert!(triangles
+                    .iter()
+                    .flat_map(edges)
+                    .any(|edge| edge == line || (edge.start, edge.end) == (line.end, line.start)));
+            }
+        }
+        for triangle in &triangles {
+            let [a, b, c] = triangle.to_array();
+            assert_eq!(orient2d(a, b, c), Orientation::CounterClockwise);
+        }
+    }
+
+    #[test]
+    fn constrained_fixed_edges() {
+        // a spiral of constraints through a grid, which crosses many Delaunay edges
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 9., y: 0.),
+            (x: 9., y: 9.),
+            (x: 1., y: 9.),
+            (x: 1., y: 2.),
+            (x: 7., y: 2.),
+            (x: 7., y: 7.),
+            (x: 3., y: 7.),
+            (x: 3., y: 4.),
+            (x: 5., y: 4.),
+            (x: 5., y: 5.),
+            (x: 4., y: 5.),
+            (x: 4., y: 6.),
+            (x: 6., y: 6.),
+            (x: 6., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 8.),
+            (x: 8., y: 8.),
+            (x: 8., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let triangles = polygon.constrained_triangulation();
+        let area: f64 = triangles
+            .iter()
+            .map(|triangle| triangle.signed_area())
+            .sum();
+        assert_relative_eq!(area, polygon.unsigned_area());
+        // no edge crosses the polygon's boundary
+        for triangle in &triangles {
+            for edge in edges(triangle) {
+                for line in polygon.exterior().lines() {
+                    let crosses = orient2d(edge.start, edge.end, line.start)
+                        != orient2d(edge.start, edge.end, line.end)
+                        && orient2d(line.start, line.end, edge.start)
+                            != orient2d(line.start, line.end, edge.end)
+                        && [line.start, line.end]
+                            .iter()
+                            .all(|c| orient2d(edge.start, edge.end, *c) != Orientation::Collinear)
+                        && [edge.start, edge.end]
+                            .iter()
+                            .all(|c| orient2d(line.start, line.end, *c) != Orientation::Collinear);
+                    assert!(!crosses);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn constrained_integer() {
+        // a hole touching the exterior, and a vertex of a ring on another edge
+        let polygon: Polygon<i64> = polygon![
+            exterior: [(x: 0, y: 0), (x: 8, y: 0), (x: 8, y: 8), (x: 0, y: 8)],
+            interiors: [[(x: 4, y: 0), (x: 6, y: 4), (x: 2, y: 4)]],
+        ];
+        let triangles = polygon.constrained_triangulation();
+        assert_eq!(doubled_area(&triangles), 2 * (64 - 8));
+        let multi = MultiPolygon(vec![
+            polygon,
+            polygon![(x: 10, y: 0), (x: 12, y: 0), (x: 11, y: 2)],
+        ]);
+        let triangles = multi.constrained_triangulation();
+        assert_eq!(doubled_area(&triangles), 2 * (64 - 8 + 2));
+    }
+
+    #[test]
+    fn collinear_constraint() {
+        // the exterior passes through coordinates of the grid between its vertices
+        let ring: Vec<Coordinate<f64>> = vec![
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 4., y: 0. },
+            Coordinate { x: 4., y: 4. },
+            Coordinate { x: 0., y: 0. },
+        ];
+        let coords: Vec<Coordinate<f64>> = grid(5).into_iter().chain(ring.clone()).collect();
+        let mut triangulation = Triangulation::new(coords);
+        triangulation.insert_ring(&LineString(ring));
+        let triangles: Vec<_> = triangulation
+            .inside()
+            .map(|triangle| triangulation.triangle(triangle))
+            .collect();
+        let area: f64 = triangles
+            .iter()
+            .map(|triangle| triangle.signed_area())
+            .sum();
+        assert_eq!(area, 8.);
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn fixed_point() {
+        use geo_types::fixed_point::FixedI32F32;
+        use num_traits::NumCast;
+
+        let fixed = |x: f64| <FixedI32F32 as NumCast>::from(x).unwrap();
+        let polygon = Polygon::new(
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (1.0, 0.5), (0.0, 2.0)]
+                .into_iter()
+                .map(|(x, y)| Coordinate::from((fixed(x), fixed(y))))
+                .collect(),
+            vec![],
+        );
+        let triangles = polygon.constrained_triangulation();
+        assert_eq!(triangles.len(), 3);
+        for triangle in &triangles {
+            let [a, b, c] = triangle.to_array();
+            assert_eq!(orient2d(a, b, c), Orientation::CounterClockwise);
+            // no triangle covers the notch
+            assert!(
+                !triangle.to_array().contains(&polygon.exterior().0[2])
+                    || !triangle.to_array().contains(&polygon.exterior().0[4])
+            );
+        }
+    }
+}
@@ -0,0 +1,672 @@
+use crate::algorithm::contains::{get_position, Contains, PositionPoint};
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::predicates::{orient2d, Orientation};
+use crate::algorithm::sweep_line::segment_intersections;
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Which ring of a `Polygon` a [`ValidationError`](enum.ValidationError.html) refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RingRole {
+    Exterior,
+    /// The interior ring at this index of `Polygon::interiors`
+    Interior(usize),
+}
+
+impl fmt::Display for RingRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RingRole::Exterior => write!(f, "exterior ring"),
+            RingRole::Interior(i) => write!(f, "interior ring {}", i),
+        }
+    }
+}
+
+/// A reason why a geometry is not valid, following the
+/// [OGC Simple Features](https://www.ogc.org/standards/sfa) rules.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError<T: Float> {
+    /// A coordinate is NaN or infinite
+    NonFiniteCoordinate { coordinate: Coordinate<T> },
+    /// A `Line` or `LineString` has fewer than two distinct points
+    TooFewPoints,
+    /// A ring has fewer than three distinct points
+    RingTooFewPoints { ring: RingRole },
+    /// The last coordinate of a ring (given here) is not the same as the first. `Polygon::new`
+    /// closes its rings, but a `Polygon` that was deserialized or decoded may not be closed.
+    RingNotClosed {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A ring repeats this coordinate consecutively
+    DuplicatePoint {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// A ring turns back on itself at this coordinate
+    Spike {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// Two non-adjacent segments of a ring meet at this coordinate
+    SelfIntersection {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// Two rings of a `Polygon` cross, or share a segment, at this coordinate
+    RingsIntersect {
+        rings: [RingRole; 2],
+        coordinate: Coordinate<T>,
+    },
+    /// An interior ring has this coordinate outside the exterior ring
+    HoleOutsideShell {
+        ring: RingRole,
+        coordinate: Coordinate<T>,
+    },
+    /// The interiors of the `Polygon`s at these indices of a `MultiPolygon` overlap at this
+    /// coordinate
+    PolygonsIntersect {
+        polygons: [usize; 2],
+        coordinate: Coordinate<T>,
+    },
+    /// The member at this index of a multi-geometry or `GeometryCollection` is not valid
+    Member {
+        index: usize,
+        error: Box<ValidationError<T>>,
+    },
+}
+
+impl<T: Float + fmt::Display> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = |c: &Coordinate<T>| format!("({} {})", c.x, c.y);
+        match self {
+            ValidationError::NonFiniteCoordinate { coordinate } => {
+                write!(f, "non-finite coordinate {}", c(coordinate))
+            }
+            ValidationError::TooFewPoints => write!(f, "fewer than two distinct points"),
+            ValidationError::RingTooFewPoints { ring } => {
+                write!(f, "{} has fewer than three distinct points", ring)
+            }
+            ValidationError::RingNotClosed { ring, coordinate } => {
+                write!(f, "{} is not closed at {}", ring, c(coordinate))
+            }
+            ValidationError::DuplicatePoint { ring, coordinate } => {
+                write!(f, "{} repeats the point {}", ring, c(coordinate))
+            }
+            ValidationError::Spike { ring, coordinate } => {
+                write!(f, "{} has a spike at {}", ring, c(coordinate))
+            }
+            ValidationError::SelfIntersection { ring, coordinate } => {
+                write!(f, "{} intersects itself at {}", ring, c(coordinate))
+            }
+            ValidationError::RingsIntersect {
+                rings: [a, b],
+                coordinate,
+            } => write!(f, "{} and {} intersect at {}", a, b, c(coordinate)),
+            ValidationError::HoleOutsideShell { ring, coordinate } => write!(
+                f,
+                "{} lies outside the exterior ring at {}",
+                ring,
+                c(coordinate)
+            ),
+            ValidationError::PolygonsIntersect {
+                polygons: [a, b],
+                coordinate,
+            } => write!(f, "polygons {} and {} overlap at {}", a, b, c(coordinate)),
+            ValidationError::Member { index, error } => write!(f, "member {}: {}", index, error),
+        }
+    }
+}
+
+impl<T: Float + fmt::Debug + fmt::Display> std::error::Error for ValidationError<T> {}
+
+/// Check whether a geometry is valid, and why it isn't.
+///
+/// Polygons are checked against the [OGC Simple Features](https://www.ogc.org/standards/sfa)
+/// rules (the ones PostGIS' `ST_IsValid` applies): every ring must be closed and have at least
+/// three distinct points, must not repeat a point, turn back on itself, or intersect itself,
+/// rings may only touch each other at points, and interior rings must lie inside the exterior
+/// ring. The polygons of a `MultiPolygon` must not overlap. `Line`s and `LineString`s need two
+/// distinct points, and every coordinate must be finite.
+///
+/// Intersections are found by comparing every pair of segments, so checking takes `O(n²)` time
+/// in the number of points of a polygon.
+pub trait Validation<T: Float> {
+    /// Return every reason this geometry is not valid, or an empty `Vec` if it is valid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::validation::{RingRole, Validation, ValidationError};
+    /// use geo::{polygon, Coordinate};
+    ///
+    /// // a "bowtie", whose exterior crosses itself at (1, 1)
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+    ///
+    /// assert_eq!(
+    ///     polygon.validation_errors(),
+    ///     vec![ValidationError::SelfIntersection {
+    ///         ring: RingRole::Exterior,
+    ///         coordinate: Coordinate { x: 1., y: 1. },
+    ///     }]
+    /// );
+    /// ```
+    fn validation_errors(&self) -> Vec<ValidationError<T>>;
+
+    /// Return `true` if this geometry is valid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::validation::Validation;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    /// assert!(square.is_valid());
+    ///
+    /// let spike = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// assert!(!spike.is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+}
+
+fn non_finite<T: Float>(coords: &[Coordinate<T>], errors: &mut Vec<ValidationError<T>>) -> bool {
+    let before = errors.len();
+    errors.extend(
+        coords
+            .iter()
+            .filter(|c| !c.x.is_finite() || !c.y.is_finite())
+            .map(|&coordinate| ValidationError::NonFiniteCoordinate { coordinate }),
+    );
+    errors.len() > before
+}
+
+/// The distinct vertices of a ring, without the closing coordinate, if it has at least three
+fn ring_vertices<T: Float>(
+    ring: &LineString<T>,
+    role: RingRole,
+    errors: &mut Vec<ValidationError<T>>,
+) -> Option<Vec<Coordinate<T>>> {
+    let coords = &ring.0;
+    if non_finite(coords, errors) {
+        return None;
+    }
+    if let (Some(first), Some(&last)) = (coords.first(), coords.last()) {
+        if *first != last {
+            errors.push(ValidationError::RingNotClosed {
+                ring: role,
+                coordinate: last,
+            });
+        }
+    }
+
+    let mut vertices: Vec<Coordinate<T>> = Vec::with_capacity(coords.len());
+    for window in coords.windows(2) {
+        if window[0] == window[1] {
+            errors.push(ValidationError::DuplicatePoint {
+                ring: role,
+                coordinate: window[0],
+            });
+        }
+    }
+    for &c in coords {
+        if vertices.last() != Some(&c) {
+            vertices.push(c);
+        }
+    }
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    if vertices.len() < 3 {
+        errors.push(ValidationError::RingTooFewPoints { ring: role });
+        return None;
+    }
+    Some(vertices)
+}
+
+fn ring_lines<T: Float>(vertices: &[Coordinate<T>]) -> Vec<Line<T>> {
+    (0..vertices.len())
+        .map(|i| Line::new(vertices[i], vertices[(i + 1) % vertices.len()]))
+        .collect()
+}
+
+fn check_ring<T: Float>(vertices: &[Coordinate<T>], role: RingRole) -> Vec<ValidationError<T>> {
+    let mut errors = vec![];
+    let n = vertices.len();
+    for i in 0..n {
+        let (prev, cur, next) = (
+            vertices[(i + n - 1) % n],
+            vertices[i],
+            vertices[(i + 1) % n],
+        );
+        let dot = (cur.x - prev.x) * (next.x - cur.x) + (cur.y - prev.y) * (next.y - cur.y);
+        if orient2d(prev, cur, next) == Orientation::Collinear && dot < T::zero() {
+            errors.push(ValidationError::Spike {
+                ring: role,
+                coordinate: cur,
+            });
+        }
+    }
+
+    let lines = ring_lines(vertices);
+    for (i, j, intersection) in segment_intersections(&lines) {
+        // skip the neighbouring segments, which always share an endpoint
+        if j == i + 1 || (i == 0 && j == n - 1) {
+            continue;
+        }
+        let coordinate = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => intersection,
+            LineIntersection::Collinear { intersection } => intersection.start,
+        };
+        errors.push(ValidationError::SelfIntersection {
+            ring: role,
+            coordinate,
+        });
+    }
+    errors
+}
+
+/// The first point where two rings cross or share a segment; touching at points is allowed
+fn rings_intersection<T: Float>(a: &[Line<T>], b: &[Line<T>]) -> Option<Coordinate<T>> {
+    let lines: Vec<Line<T>> = a.iter().chain(b).copied().collect();
+    segment_intersections(&lines)
+        .into_iter()
+        .filter(|&(i, j, _)| i < a.len() && j >= a.len())
+        .find_map(|(_, _, intersection)| match intersection {
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            } => Some(intersection),
+            LineIntersection::Collinear { intersection } => Some(intersection.start),
+            _ => None,
+        })
+}
+
+fn closed_ring<T: Float>(vertices: &[Coordinate<T>]) -> LineString<T> {
+    let mut coords = vertices.to_vec();
+    coords.push(vertices[0]);
+    LineString(coords)
+}
+
+impl<T: Float> Validation<T> for Point<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let mut errors = vec![];
+        non_finite(&[self.0], &mut errors);
+        errors
+    }
+}
+
+impl<T: Float> Validation<T> for Line<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let mut errors = vec![];
+        if !non_finite(&[self.start, self.end], &mut errors) && self.start == self.end {
+            errors.push(ValidationError::TooFewPoints);
+        }
+        errors
+    }
+}
+
+impl<T: Float> Validation<T> for LineString<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let mut errors = vec![];
+        // an empty LineString is valid
+        if !non_finite(&self.0, &mut errors)
+            && !self.0.is_empty()
+            && self.0.iter().all(|c| *c == self.0[0])
+        {
+            errors.push(ValidationError::TooFewPoints);
+        }
+        errors
+    }
+}
+
+impl<T: Float> Validation<T> for Polygon<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let mut errors = vec![];
+        // an empty Polygon is valid
+        if self.exterior().0.is_empty() && self.interiors().is_empty() {
+            return errors;
+        }
+
+        let exterior = ring_vertices(self.exterior(), RingRole::Exterior, &mut errors);
+        let interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .enumerate()
+            .map(|(i, ring)| ring_vertices(ring, RingRole::Interior(i), &mut errors))
+            .collect();
+
+        let rings: Vec<(RingRole, Vec<Coordinate<T>>)> = exterior
+            .iter()
+            .map(|vertices| (RingRole::Exterior, vertices.clone()))
+            .chain(
+                interiors
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| v.clone().map(|v| (RingRole::Interior(i), v))),
+            )
+            .collect();
+        for (role, vertices) in &rings {
+            errors.extend(check_ring(vertices, *role));
+        }
+
+        if let Some(exterior) = &exterior {
+            let shell = closed_ring(exterior);
+            for (role, vertices) in rings.iter().skip(1) {
+                if let Some(&coordinate) = vertices
+                    .iter()
+                    .find(|&&c| get_position(Point(c), &shell) == PositionPoint::Outside)
+                {
+                    errors.push(ValidationError::HoleOutsideShell {
+                        ring: *role,
+                        coordinate,
+                    });
+                }
+            }
+        }
+
+        let lines: Vec<_> = rings.iter().map(|(_, v)| ring_lines(v)).collect();
+        for i in 0..rings.len() {
+            for j in i + 1..rings.len() {
+                if let Some(coordinate) = rings_intersection(&lines[i], &lines[j]) {
+                    errors.push(ValidationError::RingsIntersect {
+                        rings: [rings[i].0, rings[j].0],
+                        coordinate,
+                    });
+                }
+            }
+        }
+        errors
+    }
+}
+
+fn member_errors<'a, T: Float + 'a, G: Validation<T> + 'a>(
+    members: impl IntoIterator<Item = &'a G>,
+) -> Vec<ValidationError<T>> {
+    members
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, member)| {
+            member
+                .validation_errors()
+                .into_iter()
+                .map(move |error| ValidationError::Member {
+                    index,
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+impl<T: Float> Validation<T> for MultiPoint<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        member_errors(&self.0)
+    }
+}
+
+impl<T: Float> Validation<T> for MultiLineString<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        member_errors(&self.0)
+    }
+}
+
+impl<T: Float> Validation<T> for MultiPolygon<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        let mut errors = member_errors(&self.0);
+        if !errors.is_empty() {
+            return errors;
+        }
+
+        let polygons: Vec<_> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !p.exterior().0.is_empty())
+            .collect();
+        let lines: Vec<Vec<Line<T>>> = polygons
+            .iter()
+            .map(|(_, p)| {
+                p.exterior()
+                    .lines()
+                    .chain(p.interiors().iter().flat_map(|ring| ring.lines()))
+                    .collect()
+            })
+            .collect();
+        for i in 0..polygons.len() {
+            for j in i + 1..polygons.len() {
+                let (a, b) = (polygons[i].1, polygons[j].1);
+                let coordinate = rings_intersection(&lines[i], &lines[j]).or_else(|| {
+                    let inside = |p: &Polygon<T>, other: &Polygon<T>| {
+                        p.exterior()
+                            .0
+                            .iter()
+                            .find(|&&c| other.contains(&Point(c)))
+                            .copied()
+                    };
+                    inside(a, b).or_else(|| inside(b, a))
+                });
+                if let Some(coordinate) = coordinate {
+                    errors.push(ValidationError::PolygonsIntersect {
+                        polygons: [polygons[i].0, polygons[j].0],
+                        coordinate,
+                    });
+                }
+            }
+        }
+        errors
+    }
+}
+
+impl<T: Float> Validation<T> for Rect<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        Polygon::from(*self).validation_errors()
+    }
+}
+
+impl<T: Float> Validation<T> for Triangle<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        Polygon::from(*self).validation_errors()
+    }
+}
+
+impl<T: Float> Validation<T> for Geometry<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        match self {
+            Geometry::Point(g) => g.validation_errors(),
+            Geometry::Line(g) => g.validation_errors(),
+            Geometry::LineString(g) => g.validation_errors(),
+            Geometry::Polygon(g) => g.validation_errors(),
+            Geometry::MultiPoint(g) => g.validation_errors(),
+            Geometry::MultiLineString(g) => g.validation_errors(),
+            Geometry::MultiPolygon(g) => g.validation_errors(),
+            Geometry::GeometryCollection(g) => g.validation_errors(),
+            Geometry::Rect(g) => g.validation_errors(),
+            Geometry::Triangle(g) => g.validation_errors(),
+        }
+    }
+}
+
+impl<T: Float> Validation<T> for GeometryCollection<T> {
+    fn validation_errors(&self) -> Vec<ValidationError<T>> {
+        member_errors(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn c(x: f64, y: f64) -> Coordinate<f64> {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn valid_polygons() {
+        let with_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                // touches the exterior at a single point
+                [(x: 5., y: 0.), (x: 6., y: 1.), (x: 4., y: 1.)],
+            ],
+        );
+        assert_eq!(with_hole.validation_errors(), vec![]);
+        assert!(Polygon::<f64>::new(LineString(vec![]), vec![]).is_valid());
+        assert!(Rect::new((0., 0.), (1., 1.)).is_valid());
+        assert!(Triangle(c(0., 0.), c(1., 0.), c(0., 1.)).is_valid());
+    }
+
+    #[test]
+    fn ring_not_closed() {
+        // `Polygon::new` closes its rings, but decoded polygons may not be closed
+        let ring = LineString(vec![c(0., 0.), c(1., 0.), c(1., 1.), c(0., 1.)]);
+        let mut errors = vec![];
+        let vertices = ring_vertices(&ring, RingRole::Exterior, &mut errors);
+        assert_eq!(vertices.map(|v| v.len()), Some(4));
+        assert_eq!(
+            errors,
+            vec![ValidationError::RingNotClosed {
+                ring: RingRole::Exterior,
+                coordinate: c(0., 1.),
+            }]
+        );
+    }
+
+    #[test]
+    fn duplicate_points_and_too_few_points() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(
+            polygon.validation_errors(),
+            vec![ValidationError::DuplicatePoint {
+                ring: RingRole::Exterior,
+                coordinate: c(1., 0.),
+            }]
+        );
+
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            polygon.validation_errors(),
+            vec![ValidationError::RingTooFewPoints {
+                ring: RingRole::Exterior
+            }]
+        );
+    }
+
+    #[test]
+    fn spike() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 2., y: 3.), (x: 2., y: 1.5), (x: 0., y: 2.)];
+        let errors = polygon.validation_errors();
+        assert!(errors.contains(&ValidationError::Spike {
+            ring: RingRole::Exterior,
+            coordinate: c(2., 3.),
+        }));
+    }
+
+    #[test]
+    fn hole_outside_shell_and_crossing_rings() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 20., y: 20.), (x: 21., y: 20.), (x: 21., y: 21.)],
+                [(x: 8., y: 5.), (x: 12., y: 5.), (x: 12., y: 6.), (x: 8., y: 6.)],
+            ],
+        );
+        assert_eq!(
+            polygon.validation_errors(),
+            vec![
+                ValidationError::HoleOutsideShell {
+                    ring: RingRole::Interior(0),
+                    coordinate: c(20., 20.),
+                },
+                ValidationError::HoleOutsideShell {
+                    ring: RingRole::Interior(1),
+                    coordinate: c(12., 5.),
+                },
+                ValidationError::RingsIntersect {
+                    rings: [RingRole::Exterior, RingRole::Interior(1)],
+                    coordinate: c(10., 5.),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let b = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+        let c_ = polygon![(x: 0.5, y: 0.5), (x: 1.5, y: 0.5), (x: 1.5, y: 1.5)];
+        // sharing an edge is invalid, as is lying inside another polygon
+        let errors = MultiPolygon(vec![a.clone(), b]).validation_errors();
+        assert!(matches!(
+            errors[..],
+            [ValidationError::PolygonsIntersect {
+                polygons: [0, 1],
+                ..
+            }]
+        ));
+        assert_eq!(
+            MultiPolygon(vec![a.clone(), c_]).validation_errors(),
+            vec![ValidationError::PolygonsIntersect {
+                polygons: [0, 1],
+                coordinate: c(0.5, 0.5),
+            }]
+        );
+
+        let far = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)];
+        assert!(MultiPolygon(vec![a, far]).is_valid());
+    }
+
+    #[test]
+    fn collections_and_other_geometries() {
+        let bowtie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        let gc = GeometryCollection(vec![
+            point!(x: 0., y: 0.).into(),
+            Geometry::Polygon(bowtie),
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)].into(),
+        ]);
+        assert_eq!(
+            gc.validation_errors(),
+            vec![
+                ValidationError::Member {
+                    index: 1,
+                    error: Box::new(ValidationError::SelfIntersection {
+                        ring: RingRole::Exterior,
+                        coordinate: c(1., 1.),
+                    }),
+                },
+                ValidationError::Member {
+                    index: 2,
+                    error: Box::new(ValidationError::TooFewPoints),
+                },
+            ]
+        );
+        assert!(matches!(
+            point!(x: f64::NAN, y: 0.).validation_errors()[..],
+            [ValidationError::NonFiniteCoordinate { .. }]
+        ));
+        assert!(!Line::new((1., 1.), (1., 1.)).is_valid());
+        assert!(LineString::<f64>(vec![]).is_valid());
+    }
+
+    #[test]
+    fn display() {
+        let error = ValidationError::Member {
+            index: 2,
+            error: Box::new(ValidationError::Spike {
+                ring: RingRole::Interior(0),
+                coordinate: c(1., 2.5),
+            }),
+        };
+        assert_eq!(
+            error.to_string(),
+            "member 2: interior ring 0 has a spike at (1 2.5)"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::algorithm::triangulation::{delaunay_neighbours, lexicographic_index};
+use crate::{Coordinate, LineString, MultiPoint, MultiPolygon, Polygon, Rect};
+use num_traits::Float;
+
+/// Compute the Voronoi diagram of a set of points: for each point, the cell of the plane which is
+/// closer to it than to any other point.
+pub trait Voronoi<T: Float> {
+    /// Return the Voronoi cell of each point, in the same order as the points, clipped to `clip`.
+    ///
+    /// The cells are convex, and oriented counter-clockwise. Points at the same coordinates share
+    /// a cell, and the cell of a point whose cell is outside `clip` has an empty exterior.
+    ///
+    /// The cells at the outside of the diagram extend without bound, so without `clip`, they're
+    /// clipped to the bounding rectangle of the points, grown by half its larger side on every
+    /// side (or by 1, if all the points are the same).
+    ///
+    /// The neighbours of each cell are found from the
+    /// [`DelaunayTriangulation`](../triangulation/trait.DelaunayTriangulation.html) of the points,
+    /// and the cell clipped by the bisector of each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::voronoi::Voronoi;
+    /// use geo::{polygon, Coordinate, MultiPoint, Rect};
+    ///
+    /// let points: MultiPoint<f64> = vec![(1., 1.), (3., 1.), (2., 3.)].into();
+    /// let clip = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. });
+    ///
+    /// let cells = points.voronoi_cells(Some(clip));
+    /// assert_eq!(
+    ///     cells.0[0],
+    ///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.75), (x: 0., y: 2.75)]
+    /// );
+    /// let area: f64 = cells.0.iter().map(|cell| cell.unsigned_area()).sum();
+    /// assert_eq!(area, 16.);
+    /// ```
+    fn voronoi_cells(&self, clip: Option<Rect<T>>) -> MultiPolygon<T>;
+}
+
+impl<T> Voronoi<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    fn voronoi_cells(&self, clip: Option<Rect<T>>) -> MultiPolygon<T> {
+        let clip = match clip.or_else(|| self.bounding_rect().map(grow)) {
+            Some(clip) => clip,
+            None => return MultiPolygon(vec![]),
+        };
+        let (coords, neighbours) =
+            delaunay_neighbours(self.0.iter().map(|point| point.0).collect());
+        let cells: Vec<Polygon<T>> = coords
+            .iter()
+            .zip(&neighbours)
+            .map(|(site, neighbours)| {
+                let mut cell = Polygon::from(clip).exterior().0.clone();
+                cell.pop();
+                for neighbour in neighbours {
+                    cell = clip_to_bisector(&cell, *site, coords[*neighbour]);
+                }
+                if !cell.is_empty() {
+                    cell.push(cell[0]);
+                }
+                Polygon::new(LineString(cell), vec![])
+            })
+            .collect();
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|point| cells[lexicographic_index(&coords, &point.0).unwrap()].clone())
+                .collect(),
+        )
+    }
+}
+
+fn grow<T: Float>(rect: Rect<T>) -> Rect<T> {
+    let two = T::one() + T::one();
+    let margin = rect.width().max(rect.height()) / two;
+    let margin = if margin > T::zero() { margin } else { T::one() };
+    let (min, max) = (rect.min(), rect.max());
+    Rect::new(
+        Coordinate {
+            x: min.x - margin,
+            y: min.y - margin,
+        },
+        Coordinate {
+            x: max.x + margin,
+            y: max.y + margin,
+        },
+    )
+}
+
+// Clip a convex ring (without its closing coordinate) to the half of the plane closer to `site`
+// than to `other`
+fn clip_to_bisector<T: Float>(
+    ring: &[Coordinate<T>],
+    site: Coordinate<T>,
+    other: Coordinate<T>,
+) -> Vec<Coordinate<T>> {
+    let two = T::one() + T::one();
+    let middle = Coordinate {
+        x: (site.x + other.x) / two,
+        y: (site.y + other.y) / two,
+    };
+    let direction = Coordinate {
+        x: other.x - site.x,
+        y: other.y - site.y,
+    };
+    // positive on the side of `other`
+    let side = |coord: Coordinate<T>| {
+        (coord.x - middle.x) * direction.x + (coord.y - middle.y) * direction.y
+    };
+    let mut clipped = Vec::with_capacity(ring.len() + 1);
+    for (i, start) in ring.iter().enumerate() {
+        let end = ring[(i + 1) % ring.len()];
+        let (start_side, end_side) = (side(*start), side(end));
+        if start_side <= T::zero() {
+            clipped.push(*start);
+        }
+        if (start_side < T::zero() && end_side > T::zero())
+            || (start_side > T::zero() && end_side < T::zero())
+        {
+            let t = start_side / (start_side - end_side);
+            clipped.push(Coordinate {
+                x: start.x + (end.x - start.x) * t,
+                y: start.y + (end.y - start.y) * t,
+            });
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::Point;
+
+    #[test]
+    fn nearest() {
+        // a deterministic scatter, and a grid of positions to check
+        let points: MultiPoint<f64> = (0..50)
+            .map(|i| {
+                let i = i as f64;
+                ((i * 0.618_034).fract() * 10., (i * 0.414_214).fract() * 10.)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let clip = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 10. });
+        let cells = points.voronoi_cells(Some(clip));
+        assert_eq!(cells.0.len(), 50);
+        let area: f64 = cells.0.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 100., epsilon = 1e-9);
+        for x in 0..20 {
+            for y in 0..20 {
+                let position = Point::new(x as f64 / 2. + 0.25, y as f64 / 2. + 0.25);
+                let nearest = (0..50)
+                    .min_by(|a, b| {
+                        let a = points.0[*a].euclidean_distance(&position);
+                        let b = points.0[*b].euclidean_distance(&position);
+                        a.partial_cmp(&b).unwrap()
+                    })
+                    .unwrap();
+                assert!(cells.0[nearest].contains(&position));
+            }
+        }
+        for cell in &cells.0 {
+            assert!(cell.signed_area() > 0.);
+        }
+    }
+
+    #[test]
+    fn default_clip() {
+        let points: MultiPoint<f64> = vec![(0., 0.), (2., 0.), (0., 2.), (2., 2.)].into();
+        let cells = points.voronoi_cells(None);
+        // clipped to the square from (-1, -1) to (3, 3)
+        for cell in &cells.0 {
+            assert_eq!(cell.unsigned_area(), 4.);
+        }
+        let single: MultiPoint<f64> = vec![(1., 1.)].into();
+        assert_eq!(single.voronoi_cells(None).0[0].unsigned_area(), 4.);
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert!(empty.voronoi_cells(None).0.is_empty());
+    }
+
+    #[test]
+    fn collinear_and_repeated() {
+        let points: MultiPoint<f64> = vec![(0., 0.), (2., 0.), (1., 0.), (2., 0.)].into();
+        let clip = Rect::new(Coordinate { x: -1., y: -1. }, Coordinate { x: 3., y: 1. });
+        let cells = points.voronoi_cells(Some(clip));
+        assert_eq!(cells.0.len(), 4);
+        assert_eq!(cells.0[0].unsigned_area(), 3.);
+        assert_eq!(cells.0[1].unsigned_area(), 3.);
+        assert_eq!(cells.0[2].unsigned_area(), 2.);
+        assert_eq!(cells.0[1], cells.0[3]);
+    }
+
+    #[test]
+    fn outside_clip() {
+        let points: MultiPoint<f64> = vec![(0., 0.), (10., 0.)].into();
+        let clip = Rect::new(Coordinate { x: -1., y: -1. }, Coordinate { x: 1., y: 1. });
+        let cells = points.voronoi_cells(Some(clip));
+        assert_eq!(cells.0[0].unsigned_area(), 4.);
+        assert!(cells.0[1].exterior().0.is_empty());
+    }
+}
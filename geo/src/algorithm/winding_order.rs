@@ -1,6 +1,8 @@
+use crate::algorithm::predicates::{orient2d, Orientation};
 use crate::utils::EitherIter;
 use crate::{CoordinateType, LineString, Point};
 use geo_types::PointsIter;
+use std::cmp::Ordering;
 use std::iter::Rev;
 
 pub(crate) fn twice_signed_ring_area<T>(linestring: &LineString<T>) -> T
@@ -18,6 +20,37 @@ where
     tmp
 }
 
+// The orientation of a ring at its lowest, leftmost vertex, or `None` if it can't be found that
+// way (too few distinct points, or a collinear vertex)
+fn robust_orientation<T>(linestring: &LineString<T>) -> Option<Orientation>
+where
+    T: CoordinateType,
+{
+    let mut coords = &linestring.0[..];
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords = &coords[..coords.len() - 1];
+    }
+    let n = coords.len();
+    let lowest = (0..n).min_by(|&a, &b| {
+        let (a, b) = (coords[a], coords[b]);
+        (a.y, a.x)
+            .partial_cmp(&(b.y, b.x))
+            .unwrap_or(Ordering::Equal)
+    })?;
+    let cur = coords[lowest];
+    let prev = (1..n)
+        .map(|k| coords[(lowest + n - k) % n])
+        .find(|&c| c != cur)?;
+    let next = (1..n)
+        .map(|k| coords[(lowest + k) % n])
+        .find(|&c| c != cur)?;
+
+    match orient2d(prev, cur, next) {
+        Orientation::Collinear => None,
+        orientation => Some(orientation),
+    }
+}
+
 /// Iterates through a list of `Point`s
 pub struct Points<'a, T>(EitherIter<Point<T>, PointsIter<'a, T>, Rev<PointsIter<'a, T>>>)
 where
@@ -63,13 +96,13 @@ where
     ///
     /// The object isn't changed, and the points are returned either in order, or in reverse
     /// order, so that the resultant order makes it appear clockwise
-    fn points_cw(&self) -> Points<T>;
+    fn points_cw(&self) -> Points<'_, T>;
 
     /// Iterate over the points in a counter-clockwise order
     ///
     /// The object isn't changed, and the points are returned either in order, or in reverse
     /// order, so that the resultant order makes it appear counter-clockwise
-    fn points_ccw(&self) -> Points<T>;
+    fn points_ccw(&self) -> Points<'_, T>;
 
     /// Change this objects's points so they are in clockwise winding order
     fn make_cw_winding(&mut self);
@@ -102,17 +135,24 @@ where
 {
     /// Returns the winding order of this line
     /// None if the winding order is undefined.
+    ///
+    /// The orientation is decided at the lowest (and then leftmost) vertex, which is always
+    /// convex, using the robust [`orient2d`](../predicates/fn.orient2d.html) predicate, so rings
+    /// with nearly collinear points are given a consistent winding order.
     fn winding_order(&self) -> Option<WindingOrder> {
-        let shoelace = twice_signed_ring_area(self);
-        if shoelace < T::zero() {
-            Some(WindingOrder::Clockwise)
-        } else if shoelace > T::zero() {
-            Some(WindingOrder::CounterClockwise)
-        } else if shoelace == T::zero() {
-            None
-        } else {
-            // make compiler stop complaining
-            unreachable!()
+        match robust_orientation(self) {
+            Some(Orientation::Clockwise) => Some(WindingOrder::Clockwise),
+            Some(_) => Some(WindingOrder::CounterClockwise),
+            None => {
+                let shoelace = twice_signed_ring_area(self);
+                if shoelace < T::zero() {
+                    Some(WindingOrder::Clockwise)
+                } else if shoelace > T::zero() {
+                    Some(WindingOrder::CounterClockwise)
+                } else {
+                    None
+                }
+            }
         }
     }
 
@@ -120,7 +160,7 @@ where
     ///
     /// The Linestring isn't changed, and the points are returned either in order, or in reverse
     /// order, so that the resultant order makes it appear clockwise
-    fn points_cw(&self) -> Points<T> {
+    fn points_cw(&self) -> Points<'_, T> {
         match self.winding_order() {
             Some(WindingOrder::CounterClockwise) => Points(EitherIter::B(self.points_iter().rev())),
             _ => Points(EitherIter::A(self.points_iter())),
@@ -131,7 +171,7 @@ where
     ///
     /// The Linestring isn't changed, and the points are returned either in order, or in reverse
     /// order, so that the resultant order makes it appear counter-clockwise
-    fn points_ccw(&self) -> Points<T> {
+    fn points_ccw(&self) -> Points<'_, T> {
         match self.winding_order() {
             Some(WindingOrder::Clockwise) => Points(EitherIter::B(self.points_iter().rev())),
             _ => Points(EitherIter::A(self.points_iter())),
@@ -170,14 +210,14 @@ mod test {
         let ccw_line = LineString::from(vec![a.0, b.0, c.0, a.0]);
 
         assert_eq!(cw_line.winding_order(), Some(WindingOrder::Clockwise));
-        assert_eq!(cw_line.is_cw(), true);
-        assert_eq!(cw_line.is_ccw(), false);
+        assert!(cw_line.is_cw());
+        assert!(!cw_line.is_ccw());
         assert_eq!(
             ccw_line.winding_order(),
             Some(WindingOrder::CounterClockwise)
         );
-        assert_eq!(ccw_line.is_cw(), false);
-        assert_eq!(ccw_line.is_ccw(), true);
+        assert!(!ccw_line.is_cw());
+        assert!(ccw_line.is_ccw());
 
         let cw_points1: Vec<_> = cw_line.points_cw().collect();
         assert_eq!(cw_points1.len(), 4);
@@ -220,4 +260,27 @@ mod test {
         assert_ne!(new_line2, cw_line);
         assert_eq!(new_line2, ccw_line);
     }
+
+    #[test]
+    fn nearly_collinear() {
+        // the shoelace sum of this thin clockwise triangle rounds to zero
+        let a = (1000000.1343642441, 1000000.1343642441);
+        let b = (1000001.1343642441, 1000001.1343642442);
+        let c = (1000002.1343642441, 1000002.1343642441);
+        let mut ring = LineString::from(vec![a, b, c, a]);
+        assert_eq!(twice_signed_ring_area(&ring), 0.);
+        assert_eq!(ring.winding_order(), Some(WindingOrder::Clockwise));
+
+        ring.make_ccw_winding();
+        assert_eq!(ring, LineString::from(vec![a, c, b, a]));
+        assert_eq!(ring.winding_order(), Some(WindingOrder::CounterClockwise));
+    }
+
+    #[test]
+    fn integer_and_unclosed_rings() {
+        let ring = LineString::from(vec![(0, 0), (0, 0), (2, 0), (1, 2)]);
+        assert_eq!(ring.winding_order(), Some(WindingOrder::CounterClockwise));
+        let collinear = LineString::from(vec![(0, 0), (1, 1), (2, 2), (0, 0)]);
+        assert_eq!(collinear.winding_order(), None);
+    }
 }
@@ -28,6 +28,15 @@
 //! - `from-postgis`: convert `Geometry` types to and from [`PostGIS`](https://docs.rs/postgis) types.
 //! - `use-proj`: enable coordinate conversion and transformation of `Point` geometries using the [`proj`](https://docs.rs/proj) crate
 //! - `use-serde`: enable serialisation of geometries using `serde`.
+//! - `scale-codec`: enable SCALE encoding and decoding of geometries using [`parity-scale-codec`](https://docs.rs/parity-scale-codec)
+//! - `fixed-point`: enable deterministic fixed-point coordinates, backed by the [`fixed`](https://docs.rs/fixed) crate. See [`geo_types::fixed_point`](https://docs.rs/geo-types/latest/geo_types/fixed_point/index.html)
+//! - `smallvec`: store the first few coordinates of each ring inline, so that small polygons, such as rectangles, don't allocate. See [`geo_types::small`](https://docs.rs/geo-types/latest/geo_types/small/index.html)
+//! - `bumpalo`: build line strings and polygons in a bump arena, freeing them all at once. See [`geo_types::arena`](https://docs.rs/geo-types/latest/geo_types/arena/index.html)
+//! - `wkt`: read and write geometries as WKT, via `FromStr` and `Display`. See [`geo_types::wkt`](https://docs.rs/geo-types/latest/geo_types/wkt/index.html)
+//! - `wkb`: read and write geometries as WKB and PostGIS EWKB. See [`geo_types::wkb`](https://docs.rs/geo-types/latest/geo_types/wkb/index.html)
+//! - `geojson`: read and write geometries and features as GeoJSON, preserving the coordinate type. See [`geo_types::geojson`](https://docs.rs/geo-types/latest/geo_types/geojson/index.html)
+//! - `geographiclib` (on by default): compute geodesic areas and destinations, and densify geometries along geodesics, with [`geographiclib-rs`](https://docs.rs/geographiclib-rs). `GeodesicDistance` and `GeodesicLength` don't need it
+//! - `simd`: transform coordinate buffers, and find their bounding rectangles and lengths, using AVX2 where the CPU has it. See [`algorithm::simd`](algorithm/simd/index.html)
 //!
 //! ## GeoJSON
 //! If you wish to read or write `GeoJSON`, use the [`geojson`](https://docs.rs/geojson) crate, with the `geo-types` feature activated.
@@ -37,6 +46,7 @@
 //! and conversion **from** `geo-types` primitives to `geojson`
 //! `Value` structs using the [`From`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) trait.
 
+extern crate alloc;
 extern crate geo_types;
 extern crate num_traits;
 #[cfg(feature = "use-serde")]
@@ -54,8 +64,12 @@ pub use crate::traits::ToGeo;
 pub use crate::types::*;
 
 pub use geo_types::{
-    line_string, point, polygon, Coordinate, CoordinateType, Geometry, GeometryCollection, Line,
-    LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    coord, geometry_collection, line_string, multi_line_string, multi_point, multi_polygon, point,
+    polygon, rect, triangle, CoordBuffer, Coordinate, CoordinateM, CoordinateType, CoordinateZ,
+    FractionalCoordinateType, Geometry, GeometryCollection, GeometryCollectionZ, GeometryType,
+    GeometryVisitor, GeometryZ, Line, LineString, LineStringM, LineStringZ, MultiLineString,
+    MultiLineStringZ, MultiPoint, MultiPointZ, MultiPolygon, MultiPolygonZ, Point, PointM, PointZ,
+    Polygon, PolygonZ, Rect, SharedGeometry, Total, TotalEq, Triangle,
 };
 
 /// This module includes all the functions of geometric calculations
@@ -84,35 +98,84 @@ const EARTH_FLATTENING: f64 =
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
+    pub use crate::algorithm::affine_ops::AffineOps;
     pub use crate::algorithm::area::Area;
     pub use crate::algorithm::bearing::Bearing;
+    pub use crate::algorithm::bool_ops::BooleanOps;
     pub use crate::algorithm::bounding_rect::BoundingRect;
+    pub use crate::algorithm::buffer::Buffer;
+    pub use crate::algorithm::canonicalize::{Canonicalize, Dedup};
+    pub use crate::algorithm::catmull_rom::CatmullRom;
     pub use crate::algorithm::centroid::Centroid;
+    pub use crate::algorithm::chaikin_smoothing::ChaikinSmoothing;
     pub use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    pub use crate::algorithm::clip_to_rect::ClipToRect;
     pub use crate::algorithm::closest_point::ClosestPoint;
+    pub use crate::algorithm::concave_hull::ConcaveHull;
     pub use crate::algorithm::contains::Contains;
     pub use crate::algorithm::convexhull::ConvexHull;
+    pub use crate::algorithm::coords_iter::CoordsIter;
+    pub use crate::algorithm::coverage::CoverageValidation;
+    #[cfg(feature = "geographiclib")]
+    pub use crate::algorithm::densify::DensifyGeodesic;
+    pub use crate::algorithm::densify::{Densify, DensifyHaversine};
+    pub use crate::algorithm::dimensions::HasDimensions;
+    pub use crate::algorithm::earcut::Earcut;
     pub use crate::algorithm::euclidean_distance::EuclideanDistance;
     pub use crate::algorithm::euclidean_length::EuclideanLength;
-    pub use crate::algorithm::extremes::ExtremePoints;
+    pub use crate::algorithm::extremes::{ExtremePoints, ExtremeVertices};
     pub use crate::algorithm::frechet_distance::FrechetDistance;
     #[cfg(feature = "postgis-integration")]
     pub use crate::algorithm::from_postgis::FromPostgis;
+    #[cfg(feature = "geographiclib")]
+    pub use crate::algorithm::geodesic_area::GeodesicArea;
+    #[cfg(feature = "geographiclib")]
+    pub use crate::algorithm::geodesic_destination::GeodesicDestination;
+    pub use crate::algorithm::geodesic_distance::GeodesicDistance;
+    pub use crate::algorithm::geodesic_length::GeodesicLength;
+    pub use crate::algorithm::hausdorff_distance::HausdorffDistance;
     pub use crate::algorithm::haversine_destination::HaversineDestination;
     pub use crate::algorithm::haversine_distance::HaversineDistance;
     pub use crate::algorithm::haversine_intermediate::HaversineIntermediate;
     pub use crate::algorithm::haversine_length::HaversineLength;
+    pub use crate::algorithm::heap_size::HeapSize;
+    pub use crate::algorithm::hex_grid::Polyfill;
+    pub use crate::algorithm::interior_point::InteriorPoint;
     pub use crate::algorithm::intersects::Intersects;
+    pub use crate::algorithm::k_nearest::KNearest;
+    pub use crate::algorithm::largest_inscribed_circle::LargestInscribedCircle;
+    pub use crate::algorithm::line_merge::LineMerge;
+    pub use crate::algorithm::line_split::LineSplit;
+    pub use crate::algorithm::make_valid::MakeValid;
     pub use crate::algorithm::map_coords::MapCoords;
+    pub use crate::algorithm::minimum_bounding_circle::MinimumBoundingCircle;
+    pub use crate::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+    pub use crate::algorithm::mvt::ToMvt;
+    pub use crate::algorithm::offset::Offset;
     pub use crate::algorithm::orient::Orient;
+    pub use crate::algorithm::polygonize::Polygonize;
+    pub use crate::algorithm::precision::{PreciseBooleanOps, SnapToGrid};
     #[cfg(feature = "use-proj")]
-    pub use crate::algorithm::proj::Proj;
-    pub use crate::algorithm::rotate::{Rotate, RotatePoint};
-    pub use crate::algorithm::simplify::Simplify;
+    pub use crate::algorithm::proj::{LocalArea, LocalLength, Proj};
+    pub use crate::algorithm::projection::Project;
+    pub use crate::algorithm::relate::Relate;
+    pub use crate::algorithm::remove_repeated_points::RemoveRepeatedPoints;
+    pub use crate::algorithm::rotate::{Rotate, RotateCentroid, RotatePoint};
+    pub use crate::algorithm::rotating_calipers::RotatingCalipers;
+    pub use crate::algorithm::s2_cell::Covering;
+    pub use crate::algorithm::scale::Scale;
+    pub use crate::algorithm::self_intersections::SelfIntersections;
+    pub use crate::algorithm::simplify::{Simplify, SimplifyToCount};
     pub use crate::algorithm::simplifyvw::SimplifyVW;
+    pub use crate::algorithm::skew::Skew;
+    pub use crate::algorithm::snap::Snap;
+    pub use crate::algorithm::tile::{CoveringTiles, ToTile};
     #[cfg(feature = "postgis-integration")]
     pub use crate::algorithm::to_postgis::ToPostgis;
     pub use crate::algorithm::translate::Translate;
+    pub use crate::algorithm::triangulation::{ConstrainedTriangulation, DelaunayTriangulation};
+    pub use crate::algorithm::validation::Validation;
     pub use crate::algorithm::vincenty_distance::VincentyDistance;
     pub use crate::algorithm::vincenty_length::VincentyLength;
+    pub use crate::algorithm::voronoi::Voronoi;
 }
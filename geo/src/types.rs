@@ -1,4 +1,4 @@
-use crate::{CoordinateType, Point};
+use crate::{Coordinate, CoordinateType, Point};
 use num_traits::Float;
 
 pub use geo_types::private_utils::COORD_PRECISION;
@@ -37,6 +37,31 @@ where
     pub xmin: Point<T>,
 }
 
+/// An extreme vertex of a geometry: its coordinate, and its index among the geometry's
+/// coordinates, in the order `CoordsIter::coords_iter` yields them.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Extreme<T>
+where
+    T: CoordinateType,
+{
+    pub index: usize,
+    pub coord: Coordinate<T>,
+}
+
+/// The vertices of a geometry with the minimum and maximum `x` and `y` coordinates.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct VertexExtremes<T>
+where
+    T: CoordinateType,
+{
+    pub x_min: Extreme<T>,
+    pub y_min: Extreme<T>,
+    pub x_max: Extreme<T>,
+    pub y_max: Extreme<T>,
+}
+
 /// The result of trying to find the closest spot on an object to a point.
 #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1,5 +1,8 @@
 //! Internal utility functions, types, and data structures.
 
+use crate::{Coordinate, CoordinateType};
+use std::cmp::Ordering;
+
 /// Partition a mutable slice in-place so that it contains all elements for
 /// which `predicate(e)` is `true`, followed by all elements for which
 /// `predicate(e)` is `false`. Returns sub-slices to all predicated and
@@ -29,6 +32,20 @@ where
     }
 }
 
+/// Whether neither value of a coordinate is NaN or infinite, for any coordinate type
+pub(crate) fn is_finite_coord<T: CoordinateType>(coord: &Coordinate<T>) -> bool {
+    [coord.x, coord.y]
+        .iter()
+        .all(|value| value.to_f64().is_some_and(f64::is_finite))
+}
+
+/// A total order of values, for sorting without panicking on NaN: -0 and 0 are equal, and NaNs
+/// are equal to each other, and greater than every other value
+pub(crate) fn cmp_values<T: CoordinateType>(a: T, b: T) -> Ordering {
+    #[allow(clippy::eq_op)]
+    a.partial_cmp(&b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
+
 /// Enumeration that allows for two distinct iterator types that yield the same type.
 pub enum EitherIter<T, I1, I2>
 where